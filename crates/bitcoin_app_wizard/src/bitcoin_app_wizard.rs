@@ -1,4 +1,3 @@
-mod templates;
 mod wizard_modal;
 
 use std::sync::Arc;
@@ -6,7 +5,8 @@ use std::sync::Arc;
 use gpui::{App, actions};
 use workspace::{AppState, Workspace};
 
-pub use wizard_modal::{BitcoinAppWizard, Framework, Template};
+pub use utxix_templates::{Database, Framework, Template, TestFramework};
+pub use wizard_modal::BitcoinAppWizard;
 
 actions!(bitcoin_app_wizard, [NewBitcoinApp]);
 
@@ -1,4 +1,4 @@
-use std::{fs, path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc};
 
 use anyhow::{Context as _, Result};
 use gpui::{
@@ -13,100 +13,41 @@ use ui::{
 };
 use ui_input::InputField;
 use util::ResultExt;
+use utxix_templates::{
+    Branding, DEFAULT_PRIMARY_COLOR, Database, Framework, Manifest, ScaffoldOptions, Template,
+    TestFramework, agent_prompt, parse_color,
+};
 use workspace::{self, OpenOptions, Workspace};
 
-use crate::templates;
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WizardStep {
     AppName,
     Framework,
     Template,
+    Branding,
     Creating,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum Framework {
-    React,
-    Nextjs,
-    Vue,
-    Angular,
-    Svelte,
-}
-
-impl Framework {
-    pub fn all() -> [Framework; 5] {
-        [
-            Framework::React,
-            Framework::Nextjs,
-            Framework::Vue,
-            Framework::Angular,
-            Framework::Svelte,
-        ]
-    }
-
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            Framework::React => "React",
-            Framework::Nextjs => "Next.js",
-            Framework::Vue => "Vue",
-            Framework::Angular => "Angular",
-            Framework::Svelte => "Svelte",
-        }
-    }
-}
-
-impl Default for Framework {
-    fn default() -> Self {
-        Framework::React
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum Template {
-    HelloWorld,
-    Counter,
-    TicTacToe,
-    Auction,
-    Custom,
-}
-
-impl Template {
-    pub fn all() -> [Template; 5] {
-        [
-            Template::HelloWorld,
-            Template::Counter,
-            Template::TicTacToe,
-            Template::Auction,
-            Template::Custom,
-        ]
-    }
-
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            Template::HelloWorld => "Hello World",
-            Template::Counter => "Counter",
-            Template::TicTacToe => "Tic-Tac-Toe",
-            Template::Auction => "Auction",
-            Template::Custom => "Custom",
-        }
-    }
-}
-
-impl Default for Template {
-    fn default() -> Self {
-        Template::HelloWorld
-    }
-}
-
 pub struct BitcoinAppWizard {
     step: WizardStep,
     framework: Framework,
     template: Template,
     generate_docs: bool,
+    include_indexer: bool,
+    include_paymail: bool,
+    include_handcash: bool,
+    state_database: Option<Database>,
+    include_regtest: bool,
+    include_ci: bool,
+    include_fixtures: bool,
+    include_property_tests: bool,
+    test_framework: TestFramework,
     focus_handle: FocusHandle,
     app_name_input: Entity<InputField>,
     custom_description_input: Entity<InputField>,
+    display_name_input: Entity<InputField>,
+    primary_color_input: Entity<InputField>,
+    logo_input: Entity<InputField>,
     workspace: WeakEntity<Workspace>,
     _app_state: Arc<workspace::AppState>,
 }
@@ -129,15 +70,42 @@ impl BitcoinAppWizard {
                 .label("Custom contract description (optional)")
                 .label_min_width(px(96.))
         });
+        let display_name_input = cx.new(|cx| {
+            InputField::new(window, cx, "Defaults to the app name")
+                .label("Display name")
+                .label_min_width(px(96.))
+        });
+        let primary_color_input = cx.new(|cx| {
+            InputField::new(window, cx, DEFAULT_PRIMARY_COLOR)
+                .label("Primary color")
+                .label_min_width(px(96.))
+        });
+        let logo_input = cx.new(|cx| {
+            InputField::new(window, cx, "path/to/logo.png")
+                .label("Logo (optional)")
+                .label_min_width(px(96.))
+        });
 
         Self {
             step: WizardStep::AppName,
             framework: Framework::default(),
             template: Template::default(),
             generate_docs: true,
+            include_indexer: false,
+            include_paymail: false,
+            include_handcash: false,
+            state_database: None,
+            include_regtest: false,
+            include_ci: true,
+            include_fixtures: true,
+            include_property_tests: false,
+            test_framework: TestFramework::default(),
             focus_handle,
             app_name_input,
             custom_description_input,
+            display_name_input,
+            primary_color_input,
+            logo_input,
             workspace,
             _app_state: app_state,
         }
@@ -168,6 +136,69 @@ impl BitcoinAppWizard {
         }
     }
 
+    /// The name shown in page titles, the header and the manifest.
+    fn display_name(&self, cx: &Context<Self>) -> String {
+        let name = self.display_name_input.read(cx).text(cx);
+        let name = name.trim();
+        if !name.is_empty() {
+            return name.to_string();
+        }
+        let app_name = self.app_name(cx);
+        if app_name.trim().is_empty() {
+            "Bitcoin App".to_string()
+        } else {
+            app_name.trim().to_string()
+        }
+    }
+
+    /// `None` while the color field holds something other than a hex color.
+    fn primary_color(&self, cx: &Context<Self>) -> Option<String> {
+        let text = self.primary_color_input.read(cx).text(cx);
+        if text.trim().is_empty() {
+            Some(DEFAULT_PRIMARY_COLOR.to_string())
+        } else {
+            parse_color(&text)
+        }
+    }
+
+    fn logo_path(&self, cx: &Context<Self>) -> Option<PathBuf> {
+        let text = self.logo_input.read(cx).text(cx);
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        let path = PathBuf::from(text);
+        Some(match path.strip_prefix("~") {
+            Ok(rest) => util::paths::home_dir().join(rest),
+            Err(_) => path,
+        })
+    }
+
+    fn browse_logo(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let picker = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: Some(SharedString::from("Select logo image")),
+        });
+        let logo_input = self.logo_input.clone();
+        cx.spawn_in(window, async move |_, cx| {
+            let Ok(Ok(Some(paths))) = picker.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            cx.update(|window, cx| {
+                logo_input.update(cx, |input, cx| {
+                    input.set_text(path.to_string_lossy().to_string(), window, cx);
+                });
+            })
+            .log_err();
+        })
+        .detach();
+    }
+
     fn select_framework(&mut self, framework: Framework, cx: &mut Context<Self>) {
         self.framework = framework;
         cx.notify();
@@ -182,7 +213,8 @@ impl BitcoinAppWizard {
         self.step = match self.step {
             WizardStep::AppName => WizardStep::Framework,
             WizardStep::Framework => WizardStep::Template,
-            WizardStep::Template | WizardStep::Creating => {
+            WizardStep::Template => WizardStep::Branding,
+            WizardStep::Branding | WizardStep::Creating => {
                 self.create_project(window, cx);
                 WizardStep::Creating
             }
@@ -195,7 +227,8 @@ impl BitcoinAppWizard {
             WizardStep::AppName => WizardStep::AppName,
             WizardStep::Framework => WizardStep::AppName,
             WizardStep::Template => WizardStep::Framework,
-            WizardStep::Creating => WizardStep::Template,
+            WizardStep::Branding => WizardStep::Template,
+            WizardStep::Creating => WizardStep::Branding,
         };
         cx.notify();
     }
@@ -205,11 +238,29 @@ impl BitcoinAppWizard {
         cx.notify();
 
         let app_name = self.app_name(cx);
-        let framework = self.framework;
-        let template = self.template;
-        let custom_description = self.custom_description(cx);
         let old_workspace = self.workspace.clone();
-        let generate_docs = self.generate_docs;
+        let options = ScaffoldOptions {
+            framework: self.framework,
+            template: self.template,
+            custom_description: self.custom_description(cx),
+            generate_docs: self.generate_docs,
+            include_indexer: self.include_indexer,
+            include_paymail: self.include_paymail,
+            include_handcash: self.include_handcash,
+            state_database: self.state_database,
+            include_regtest: self.include_regtest,
+            include_ci: self.include_ci,
+            include_fixtures: self.include_fixtures,
+            include_property_tests: self.include_property_tests,
+            test_framework: self.test_framework,
+            branding: Branding {
+                display_name: self.display_name(cx),
+                primary_color: self
+                    .primary_color(cx)
+                    .unwrap_or_else(|| DEFAULT_PRIMARY_COLOR.to_string()),
+                logo: self.logo_path(cx),
+            },
+        };
         let app_state = self._app_state.clone();
 
         let picker = cx.prompt_for_paths(PathPromptOptions {
@@ -219,50 +270,8 @@ impl BitcoinAppWizard {
             prompt: Some(SharedString::from("Select folder for project")),
         });
 
-        let contract_filename = crate::templates::contract_filename(template).to_string();
-
-        let prompt_text = if let Some(ref desc) = custom_description {
-            format!(
-                "The project scaffold is already created with {} frontend and Yours Wallet integration. \
-                DO NOT run any CLI commands like 'npm create' or 'npx create-vue'. \
-                Instead, EDIT the existing files to implement: {}\n\n\
-                CRITICAL RULES:\n\
-                - NEVER import .scrypt.ts files directly into frontend components\n\
-                - Contracts must be compiled: `npx scrypt-cli compile`\n\
-                - Load compiled artifacts dynamically, not via direct import\n\
-                - When adding npm packages, ALSO update package.json dependencies\n\
-                - Use toRaw() when passing Vue reactive contract instances to SDK methods\n\
-                - Use bindTxBuilder() for custom transaction building with ANYONECANPAY_SINGLE\n\n\
-                Focus on:\n\
-                1. Complete the smart contract logic in contracts/{}\n\
-                2. Update the Game component to interact with the COMPILED contract artifact\n\
-                3. Use YoursDirectSigner for settlement transactions\n\n\
-                See AI_RULES.md for full guidelines on tx building, signer patterns, and common pitfalls.",
-                framework.display_name(),
-                desc,
-                contract_filename
-            )
-        } else {
-            format!(
-                "The project scaffold is already created with {} frontend and Yours Wallet integration. \
-                DO NOT run any CLI commands. \
-                EDIT the existing files to complete the {} implementation.\n\n\
-                CRITICAL RULES:\n\
-                - NEVER import .scrypt.ts files directly into frontend components\n\
-                - Contracts must be compiled: `npx scrypt-cli compile`\n\
-                - Load compiled artifacts dynamically, not via direct import\n\
-                - When adding npm packages, ALSO update package.json dependencies\n\
-                - Use toRaw() when passing Vue reactive contract instances to SDK methods\n\
-                - Use bindTxBuilder() for custom transaction building with ANYONECANPAY_SINGLE\n\n\
-                Focus on:\n\
-                1. Complete the smart contract logic in contracts/{}\n\
-                2. Update the Game component to interact with the COMPILED contract artifact\n\n\
-                See AI_RULES.md for full guidelines on tx building, signer patterns, and common pitfalls.",
-                framework.display_name(),
-                template.display_name(),
-                contract_filename
-            )
-        };
+        let contract_filename = options.template.descriptor().contract_file;
+        let prompt_text = agent_prompt(&options);
 
         let task = window.spawn(cx, async move |cx| {
             let paths_result = picker.await;
@@ -307,15 +316,7 @@ impl BitcoinAppWizard {
                 }
             };
 
-            let scaffold_result = write_scaffold(
-                &app_name,
-                selected_base.clone(),
-                framework,
-                template,
-                custom_description.as_deref(),
-                generate_docs,
-                &prompt_text,
-            );
+            let scaffold_result = write_scaffold(&app_name, selected_base.clone(), &options);
 
             let project_path = match scaffold_result {
                 Ok(path) => path,
@@ -365,7 +366,7 @@ impl BitcoinAppWizard {
             };
 
             // Now use the NEW workspace window for opening the contract and agent panel
-            let contract_path = project_path.join("contracts").join(&contract_filename);
+            let contract_path = project_path.join("contracts").join(contract_filename);
 
             let open_contract_result = new_workspace_window.update(cx, |workspace, window, cx| {
                 workspace.open_abs_path(
@@ -415,6 +416,7 @@ impl BitcoinAppWizard {
             ("Name", WizardStep::AppName),
             ("Framework", WizardStep::Framework),
             ("Template", WizardStep::Template),
+            ("Branding", WizardStep::Branding),
         ];
 
         h_flex()
@@ -513,28 +515,170 @@ impl BitcoinAppWizard {
                     |this| this,
                 ),
             )
+            .child(self.toggle_button(
+                "PRD & tasks",
+                self.generate_docs,
+                |this| &mut this.generate_docs,
+                cx,
+            ))
+            .child(self.toggle_button(
+                "UTXO indexer",
+                self.include_indexer,
+                |this| &mut this.include_indexer,
+                cx,
+            ))
+            .child(self.toggle_button(
+                "paymail payments",
+                self.include_paymail,
+                |this| &mut this.include_paymail,
+                cx,
+            ))
+            .child(self.toggle_button(
+                "HandCash Connect",
+                self.include_handcash,
+                |this| &mut this.include_handcash,
+                cx,
+            ))
             .child(
                 Button::new(
-                    "toggle-docs",
-                    if self.generate_docs {
-                        "Include PRD & tasks"
-                    } else {
-                        "Skip PRD & tasks"
+                    "toggle-state-api",
+                    match self.state_database {
+                        None => "Skip state API".to_string(),
+                        Some(database) => format!("Include state API ({})", database.display_name()),
                     },
                 )
-                .style(if self.generate_docs {
+                .style(if self.state_database.is_some() {
                     ButtonStyle::Filled
                 } else {
                     ButtonStyle::Subtle
                 })
                 .on_click(cx.listener(|this, _, _window, cx| {
-                    this.generate_docs = !this.generate_docs;
+                    // Cycles through skipping it and each database
+                    let databases = Database::all();
+                    this.state_database = match this.state_database {
+                        None => databases.first().copied(),
+                        Some(database) => databases
+                            .iter()
+                            .skip_while(|other| **other != database)
+                            .nth(1)
+                            .copied(),
+                    };
+                    cx.notify();
+                })),
+            )
+            .child(self.toggle_button(
+                "regtest node",
+                self.include_regtest,
+                |this| &mut this.include_regtest,
+                cx,
+            ))
+            .child(self.toggle_button(
+                "CI workflow",
+                self.include_ci,
+                |this| &mut this.include_ci,
+                cx,
+            ))
+            .child(self.toggle_button(
+                "test fixtures",
+                self.include_fixtures,
+                |this| &mut this.include_fixtures,
+                cx,
+            ))
+            .child(self.toggle_button(
+                "property tests",
+                self.include_property_tests,
+                |this| &mut this.include_property_tests,
+                cx,
+            ))
+            .child(
+                Button::new(
+                    "toggle-test-framework",
+                    match self.test_framework {
+                        TestFramework::Mocha => "Run tests with Mocha (sCrypt default)".to_string(),
+                        test_framework => {
+                            format!("Run tests with {}", test_framework.display_name())
+                        }
+                    },
+                )
+                .style(ButtonStyle::Filled)
+                .on_click(cx.listener(|this, _, _window, cx| {
+                    // Cycles through the runners, back to Mocha
+                    this.test_framework = TestFramework::all()
+                        .into_iter()
+                        .skip_while(|other| *other != this.test_framework)
+                        .nth(1)
+                        .unwrap_or_default();
                     cx.notify();
                 })),
             )
             .into_any_element()
     }
 
+    /// Includes or skips `what` in the project, filled while it's included.
+    fn toggle_button(
+        &self,
+        what: &'static str,
+        value: bool,
+        field: fn(&mut Self) -> &mut bool,
+        cx: &mut Context<Self>,
+    ) -> Button {
+        Button::new(
+            SharedString::from(format!("toggle-{what}")),
+            if value {
+                format!("Include {what}")
+            } else {
+                format!("Skip {what}")
+            },
+        )
+        .style(if value {
+            ButtonStyle::Filled
+        } else {
+            ButtonStyle::Subtle
+        })
+        .on_click(cx.listener(move |this, _, _window, cx| {
+            let value = field(this);
+            *value = !*value;
+            cx.notify();
+        }))
+    }
+
+    fn render_branding_step(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let invalid_color = self.primary_color(cx).is_none();
+        v_flex()
+            .gap_2()
+            .child(Headline::new("Brand your app").size(HeadlineSize::Small))
+            .child(self.display_name_input.clone())
+            .child(self.primary_color_input.clone())
+            .when(invalid_color, |this| {
+                this.child(
+                    Label::new("Use a hex color like #059669")
+                        .color(Color::Error)
+                        .size(LabelSize::Small),
+                )
+            })
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(div().flex_1().child(self.logo_input.clone()))
+                    .child(
+                        Button::new("browse-logo", "Browse…")
+                            .style(ButtonStyle::Subtle)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.browse_logo(window, cx);
+                            })),
+                    ),
+            )
+            .child(
+                Label::new(
+                    "Used for the page title, header, button color and web app manifest. \
+                     The logo is copied to public/ and becomes the favicon.",
+                )
+                .color(Color::Muted)
+                .size(LabelSize::Small),
+            )
+            .into_any_element()
+    }
+
     fn render_creating_step(&self) -> impl IntoElement {
         v_flex()
             .gap_2()
@@ -545,8 +689,8 @@ impl BitcoinAppWizard {
     fn render_actions(&self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let can_go_back = self.step != WizardStep::AppName && self.step != WizardStep::Creating;
         let primary_label = match self.step {
-            WizardStep::AppName | WizardStep::Framework => "Next",
-            WizardStep::Template => "Create project",
+            WizardStep::AppName | WizardStep::Framework | WizardStep::Template => "Next",
+            WizardStep::Branding => "Create project",
             WizardStep::Creating => "Working...",
         };
 
@@ -564,7 +708,11 @@ impl BitcoinAppWizard {
             .child(
                 Button::new("next", primary_label)
                     .style(ButtonStyle::Filled)
-                    .disabled(self.step == WizardStep::Creating)
+                    .disabled(
+                        self.step == WizardStep::Creating
+                            || (self.step == WizardStep::Branding
+                                && self.primary_color(cx).is_none()),
+                    )
                     .on_click(cx.listener(move |this, _, window, cx| {
                         this.next_step(window, cx);
                     })),
@@ -588,6 +736,7 @@ impl Render for BitcoinAppWizard {
             WizardStep::AppName => self.render_app_name_step(window, cx).into_any_element(),
             WizardStep::Framework => self.render_framework_step(cx).into_any_element(),
             WizardStep::Template => self.render_template_step(cx).into_any_element(),
+            WizardStep::Branding => self.render_branding_step(cx).into_any_element(),
             WizardStep::Creating => self.render_creating_step().into_any_element(),
         };
 
@@ -623,11 +772,7 @@ impl Render for BitcoinAppWizard {
 fn write_scaffold(
     app_name: &str,
     base_dir: PathBuf,
-    framework: Framework,
-    template: Template,
-    custom_description: Option<&str>,
-    generate_docs: bool,
-    prompt_text: &str,
+    options: &ScaffoldOptions,
 ) -> Result<PathBuf> {
     let sanitized = app_name.trim();
     let app_folder = if sanitized.is_empty() {
@@ -637,69 +782,9 @@ fn write_scaffold(
     };
 
     let project_path = base_dir.join(app_folder);
-
-    fs::create_dir_all(&project_path)
-        .with_context(|| format!("create project folder {}", project_path.display()))?;
-    fs::create_dir_all(project_path.join("contracts"))
-        .context("create contracts folder")?;
-    fs::create_dir_all(project_path.join("src/components"))
-        .context("create src/components folder")?;
-    fs::create_dir_all(project_path.join("src/lib"))
-        .context("create src/lib folder")?;
-    fs::create_dir_all(project_path.join("src/services"))
-        .context("create src/services folder")?;
-
-    let contract_filename = templates::contract_filename(template);
-    let contract_source = templates::contract_source(template, custom_description);
-
-    let mut files: Vec<(PathBuf, String)> = vec![
-        (
-            project_path.join(".env.example"),
-            templates::ENV_EXAMPLE.to_string(),
-        ),
-        (
-            project_path.join("README.md"),
-            templates::readme(framework, template),
-        ),
-        (
-            project_path.join("AI_RULES.md"),
-            templates::AI_RULES.to_string(),
-        ),
-        (
-            project_path.join("contracts").join(contract_filename),
-            contract_source,
-        ),
-    ];
-
-    if generate_docs {
-        files.push((
-            project_path.join("PRD.md"),
-            format!("# Project Requirements\n\nPrompt:\n\n{}\n", prompt_text),
-        ));
-        files.push((
-            project_path.join("tasks.md"),
-            "- [ ] Implement sCrypt covenant contract\n- [ ] Wire wallet connect flow\n- [ ] Build game UI and state management\n- [ ] Add transaction signing and broadcasting\n- [ ] Style with Tailwind (customize as needed)\n- [ ] Test on testnet\n- [ ] Update README with deploy instructions\n".to_string(),
-        ));
-    }
-
-    // Frontend files (includes package.json, vite config, components, etc.)
-    for (path, contents) in templates::frontend_files(framework, contract_filename) {
-        files.push((project_path.join(path), contents));
-    }
-
-    // Backend files (OAuth server)
-    for (path, contents) in templates::backend_files() {
-        files.push((project_path.join(path), contents));
-    }
-
-    for (path, contents) in files {
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("create directory {} for file {}", parent.display(), path.display()))?;
-        }
-        fs::write(&path, contents).with_context(|| format!("write file {}", path.display()))?;
-    }
+    Manifest::new(options)
+        .write(&project_path)
+        .with_context(|| format!("create project {}", project_path.display()))?;
 
     Ok(project_path)
 }
-
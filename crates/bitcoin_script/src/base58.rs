@@ -0,0 +1,63 @@
+//! Base58Check, as used by addresses and WIF keys.
+
+use crate::hash::hash256;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes `payload` followed by its 4-byte checksum.
+pub fn encode_check(payload: &[u8]) -> String {
+    let mut data = payload.to_vec();
+    data.extend_from_slice(&hash256(payload)[..4]);
+
+    // Base-58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in &data {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let zeros = data.iter().take_while(|byte| **byte == 0).count();
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|digit| ALPHABET[*digit as usize] as char),
+        )
+        .collect()
+}
+
+/// Decodes and strips the checksum; `None` if the text or checksum is invalid.
+pub fn decode_check(text: &str) -> Option<Vec<u8>> {
+    // Bytes, least significant first
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in text.bytes() {
+        let mut carry = ALPHABET.iter().position(|a| *a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let zeros = text.bytes().take_while(|c| *c == b'1').count();
+    let mut data = vec![0u8; zeros];
+    data.extend(bytes.iter().rev());
+    if data.len() < 4 {
+        return None;
+    }
+    let (payload, checksum) = data.split_at(data.len() - 4);
+    (hash256(payload)[..4] == *checksum).then(|| payload.to_vec())
+}
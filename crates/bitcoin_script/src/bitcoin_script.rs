@@ -0,0 +1,309 @@
+//! Bitcoin Script evaluation for editor tooling: parsing and disassembly,
+//! script numbers, hashing, signature checks against a provided sighash
+//! preimage and a step-wise interpreter (post-Genesis BSV rules), plus the
+//! keys and transactions needed to deploy a contract, and SPV merkle proof checks.
+
+pub mod base58;
+pub mod hash;
+mod interpreter;
+pub mod keys;
+pub mod merkle;
+pub mod secp256k1;
+pub mod transaction;
+
+use num_bigint::{BigInt, Sign};
+
+pub use interpreter::{Interpreter, Phase, ScriptError, Step};
+
+pub const OP_0: u8 = 0x00;
+pub const OP_PUSHDATA1: u8 = 0x4c;
+pub const OP_PUSHDATA2: u8 = 0x4d;
+pub const OP_PUSHDATA4: u8 = 0x4e;
+pub const OP_1NEGATE: u8 = 0x4f;
+pub const OP_1: u8 = 0x51;
+pub const OP_16: u8 = 0x60;
+pub const OP_IF: u8 = 0x63;
+pub const OP_NOTIF: u8 = 0x64;
+pub const OP_ELSE: u8 = 0x67;
+pub const OP_ENDIF: u8 = 0x68;
+pub const OP_RETURN: u8 = 0x6a;
+pub const OP_DROP: u8 = 0x75;
+pub const OP_DUP: u8 = 0x76;
+pub const OP_EQUALVERIFY: u8 = 0x88;
+pub const OP_NUMEQUAL: u8 = 0x9c;
+pub const OP_NUMEQUALVERIFY: u8 = 0x9d;
+pub const OP_HASH160: u8 = 0xa9;
+pub const OP_CHECKSIG: u8 = 0xac;
+
+/// One parsed opcode. Pushes carry their data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: u8,
+    pub data: Option<Vec<u8>>,
+    /// Byte offset of the opcode within its script.
+    pub offset: usize,
+}
+
+impl Instruction {
+    pub fn is_push(&self) -> bool {
+        self.opcode <= OP_16 && self.opcode != 0x50
+    }
+
+    /// ASM form: pushed data as hex, everything else by opcode name.
+    pub fn asm(&self) -> String {
+        match &self.data {
+            Some(data) if !data.is_empty() => hex::encode(data),
+            _ => opcode_name(self.opcode).to_string(),
+        }
+    }
+}
+
+/// Splits a script into instructions.
+pub fn parse(script: &[u8]) -> Result<Vec<Instruction>, ScriptError> {
+    let mut instructions = Vec::new();
+    let mut ix = 0;
+    while ix < script.len() {
+        let offset = ix;
+        let opcode = script[ix];
+        ix += 1;
+
+        let len = match opcode {
+            0x01..=0x4b => Some(opcode as usize),
+            OP_PUSHDATA1 => Some(read_len(script, &mut ix, 1)?),
+            OP_PUSHDATA2 => Some(read_len(script, &mut ix, 2)?),
+            OP_PUSHDATA4 => Some(read_len(script, &mut ix, 4)?),
+            _ => None,
+        };
+        let data = match len {
+            Some(len) => {
+                let end = ix
+                    .checked_add(len)
+                    .filter(|end| *end <= script.len())
+                    .ok_or(ScriptError::TruncatedPush { offset })?;
+                let data = script[ix..end].to_vec();
+                ix = end;
+                Some(data)
+            }
+            None if opcode == OP_0 => Some(Vec::new()),
+            None => None,
+        };
+        instructions.push(Instruction {
+            opcode,
+            data,
+            offset,
+        });
+    }
+    Ok(instructions)
+}
+
+fn read_len(script: &[u8], ix: &mut usize, width: usize) -> Result<usize, ScriptError> {
+    let offset = *ix - 1;
+    let bytes = script
+        .get(*ix..*ix + width)
+        .ok_or(ScriptError::TruncatedPush { offset })?;
+    *ix += width;
+    Ok(bytes
+        .iter()
+        .rev()
+        .fold(0usize, |len, byte| (len << 8) | *byte as usize))
+}
+
+pub fn disassemble(script: &[u8]) -> Result<String, ScriptError> {
+    Ok(parse(script)?
+        .iter()
+        .map(Instruction::asm)
+        .collect::<Vec<_>>()
+        .join(" "))
+}
+
+/// Smallest push of `data`.
+pub fn push_data(data: &[u8]) -> Vec<u8> {
+    let mut script = Vec::with_capacity(data.len() + 5);
+    match data.len() {
+        0 => script.push(OP_0),
+        len @ 1..=0x4b => script.push(len as u8),
+        len @ 0x4c..=0xff => script.extend([OP_PUSHDATA1, len as u8]),
+        len @ 0x100..=0xffff => {
+            script.push(OP_PUSHDATA2);
+            script.extend((len as u16).to_le_bytes());
+        }
+        len => {
+            script.push(OP_PUSHDATA4);
+            script.extend((len as u32).to_le_bytes());
+        }
+    }
+    script.extend_from_slice(data);
+    script
+}
+
+/// Smallest push of `value`, using OP_1NEGATE and OP_1..OP_16 where possible.
+pub fn push_number(value: &BigInt) -> Vec<u8> {
+    if *value == BigInt::from(-1) {
+        return vec![OP_1NEGATE];
+    }
+    if *value >= BigInt::from(1) && *value <= BigInt::from(16) {
+        let small = u8::try_from(value).unwrap_or_default();
+        return vec![OP_1 + small - 1];
+    }
+    push_data(&encode_num(value))
+}
+
+/// Decodes a little-endian sign-magnitude script number.
+pub fn decode_num(bytes: &[u8]) -> BigInt {
+    let Some((last, rest)) = bytes.split_last() else {
+        return BigInt::default();
+    };
+    let mut magnitude = rest.to_vec();
+    magnitude.push(last & 0x7f);
+    let sign = if last & 0x80 != 0 {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    };
+    BigInt::from_bytes_le(sign, &magnitude)
+}
+
+/// Minimal script-number encoding of `value`.
+pub fn encode_num(value: &BigInt) -> Vec<u8> {
+    let (sign, mut bytes) = value.to_bytes_le();
+    if sign == Sign::NoSign {
+        return Vec::new();
+    }
+    let negative = sign == Sign::Minus;
+    match bytes.last_mut() {
+        // The sign goes in the top byte's high bit when it's free
+        Some(last) if *last & 0x80 == 0 => {
+            if negative {
+                *last |= 0x80;
+            }
+        }
+        _ => bytes.push(if negative { 0x80 } else { 0x00 }),
+    }
+    bytes
+}
+
+/// Script truthiness: any non-zero byte, except a lone sign bit (negative zero).
+pub fn cast_to_bool(bytes: &[u8]) -> bool {
+    bytes
+        .iter()
+        .enumerate()
+        .any(|(ix, byte)| *byte != 0 && !(ix == bytes.len() - 1 && *byte == 0x80))
+}
+
+pub fn opcode_name(opcode: u8) -> &'static str {
+    match opcode {
+        0x00 => "OP_0",
+        0x01..=0x4b => "OP_PUSHBYTES",
+        0x4c => "OP_PUSHDATA1",
+        0x4d => "OP_PUSHDATA2",
+        0x4e => "OP_PUSHDATA4",
+        0x4f => "OP_1NEGATE",
+        0x50 => "OP_RESERVED",
+        0x51 => "OP_1",
+        0x52 => "OP_2",
+        0x53 => "OP_3",
+        0x54 => "OP_4",
+        0x55 => "OP_5",
+        0x56 => "OP_6",
+        0x57 => "OP_7",
+        0x58 => "OP_8",
+        0x59 => "OP_9",
+        0x5a => "OP_10",
+        0x5b => "OP_11",
+        0x5c => "OP_12",
+        0x5d => "OP_13",
+        0x5e => "OP_14",
+        0x5f => "OP_15",
+        0x60 => "OP_16",
+        0x61 => "OP_NOP",
+        0x62 => "OP_VER",
+        0x63 => "OP_IF",
+        0x64 => "OP_NOTIF",
+        0x65 => "OP_VERIF",
+        0x66 => "OP_VERNOTIF",
+        0x67 => "OP_ELSE",
+        0x68 => "OP_ENDIF",
+        0x69 => "OP_VERIFY",
+        0x6a => "OP_RETURN",
+        0x6b => "OP_TOALTSTACK",
+        0x6c => "OP_FROMALTSTACK",
+        0x6d => "OP_2DROP",
+        0x6e => "OP_2DUP",
+        0x6f => "OP_3DUP",
+        0x70 => "OP_2OVER",
+        0x71 => "OP_2ROT",
+        0x72 => "OP_2SWAP",
+        0x73 => "OP_IFDUP",
+        0x74 => "OP_DEPTH",
+        0x75 => "OP_DROP",
+        0x76 => "OP_DUP",
+        0x77 => "OP_NIP",
+        0x78 => "OP_OVER",
+        0x79 => "OP_PICK",
+        0x7a => "OP_ROLL",
+        0x7b => "OP_ROT",
+        0x7c => "OP_SWAP",
+        0x7d => "OP_TUCK",
+        0x7e => "OP_CAT",
+        0x7f => "OP_SPLIT",
+        0x80 => "OP_NUM2BIN",
+        0x81 => "OP_BIN2NUM",
+        0x82 => "OP_SIZE",
+        0x83 => "OP_INVERT",
+        0x84 => "OP_AND",
+        0x85 => "OP_OR",
+        0x86 => "OP_XOR",
+        0x87 => "OP_EQUAL",
+        0x88 => "OP_EQUALVERIFY",
+        0x89 => "OP_RESERVED1",
+        0x8a => "OP_RESERVED2",
+        0x8b => "OP_1ADD",
+        0x8c => "OP_1SUB",
+        0x8d => "OP_2MUL",
+        0x8e => "OP_2DIV",
+        0x8f => "OP_NEGATE",
+        0x90 => "OP_ABS",
+        0x91 => "OP_NOT",
+        0x92 => "OP_0NOTEQUAL",
+        0x93 => "OP_ADD",
+        0x94 => "OP_SUB",
+        0x95 => "OP_MUL",
+        0x96 => "OP_DIV",
+        0x97 => "OP_MOD",
+        0x98 => "OP_LSHIFT",
+        0x99 => "OP_RSHIFT",
+        0x9a => "OP_BOOLAND",
+        0x9b => "OP_BOOLOR",
+        0x9c => "OP_NUMEQUAL",
+        0x9d => "OP_NUMEQUALVERIFY",
+        0x9e => "OP_NUMNOTEQUAL",
+        0x9f => "OP_LESSTHAN",
+        0xa0 => "OP_GREATERTHAN",
+        0xa1 => "OP_LESSTHANOREQUAL",
+        0xa2 => "OP_GREATERTHANOREQUAL",
+        0xa3 => "OP_MIN",
+        0xa4 => "OP_MAX",
+        0xa5 => "OP_WITHIN",
+        0xa6 => "OP_RIPEMD160",
+        0xa7 => "OP_SHA1",
+        0xa8 => "OP_SHA256",
+        0xa9 => "OP_HASH160",
+        0xaa => "OP_HASH256",
+        0xab => "OP_CODESEPARATOR",
+        0xac => "OP_CHECKSIG",
+        0xad => "OP_CHECKSIGVERIFY",
+        0xae => "OP_CHECKMULTISIG",
+        0xaf => "OP_CHECKMULTISIGVERIFY",
+        0xb0 => "OP_NOP1",
+        0xb1 => "OP_NOP2",
+        0xb2 => "OP_NOP3",
+        0xb3 => "OP_NOP4",
+        0xb4 => "OP_NOP5",
+        0xb5 => "OP_NOP6",
+        0xb6 => "OP_NOP7",
+        0xb7 => "OP_NOP8",
+        0xb8 => "OP_NOP9",
+        0xb9 => "OP_NOP10",
+        _ => "OP_UNKNOWN",
+    }
+}
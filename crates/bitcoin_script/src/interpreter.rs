@@ -0,0 +1,885 @@
+use num_bigint::BigInt;
+use num_traits::{Signed as _, ToPrimitive as _, Zero as _};
+use thiserror::Error;
+
+use crate::{
+    Instruction, OP_1, OP_1NEGATE, OP_16, cast_to_bool, decode_num, encode_num, hash, opcode_name,
+    parse,
+    secp256k1::{self, PublicKey, Signature},
+    transaction::SIGHASH_FORKID,
+};
+
+/// Combined size of the main and alt stacks, in bytes, that miners accept by
+/// default (`maxstackmemoryusagepolicy`). Consensus has no element or stack
+/// size limit after Genesis, so this is what bounds them.
+pub const MAX_STACK_MEMORY: usize = 100_000_000;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ScriptError {
+    #[error("push at byte {offset} runs past the end of the script")]
+    TruncatedPush { offset: usize },
+    #[error("unlocking script must only push data (found {0})")]
+    UnlockingNotPushOnly(&'static str),
+    #[error("{0} needs more items on the stack")]
+    StackUnderflow(&'static str),
+    #[error("{0} needs more items on the alt stack")]
+    AltStackUnderflow(&'static str),
+    #[error("{0} failed")]
+    VerifyFailed(&'static str),
+    #[error("{0} without a matching OP_IF")]
+    UnbalancedConditional(&'static str),
+    #[error("OP_IF is never closed by OP_ENDIF")]
+    UnterminatedConditional,
+    #[error("{0} is disabled")]
+    Disabled(&'static str),
+    #[error("{0} is not supported by the interpreter")]
+    Unsupported(&'static str),
+    #[error("{0}: division by zero")]
+    DivisionByZero(&'static str),
+    #[error("{op}: {reason}")]
+    InvalidOperand { op: &'static str, reason: String },
+    #[error("{0} needs a sighash preimage to check signatures against")]
+    MissingPreimage(&'static str),
+    #[error("{0}: signature is not strict DER")]
+    InvalidSignatureEncoding(&'static str),
+    #[error("{0}: signature has a high S value (must be low-S)")]
+    HighS(&'static str),
+    #[error("{0}: sighash type 0x{1:02x} lacks SIGHASH_FORKID (0x40)")]
+    MissingForkId(&'static str, u8),
+    #[error(
+        "{op}: signature uses sighash type 0x{signature:02x} but the preimage is for 0x{preimage:02x}"
+    )]
+    SighashMismatch {
+        op: &'static str,
+        signature: u8,
+        preimage: u8,
+    },
+    #[error("{0}: invalid public key encoding")]
+    InvalidPublicKey(&'static str),
+    #[error("{0}: signature does not verify against the preimage and public key")]
+    SignatureFailed(&'static str),
+    #[error("{op}: the stacks would hold {size} bytes, over the default stack memory policy")]
+    StackMemoryExceeded { op: &'static str, size: usize },
+    #[error("script finished with an empty stack")]
+    EmptyStack,
+    #[error("script finished with a false value on top of the stack")]
+    EvalFalse,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Unlocking,
+    Locking,
+}
+
+/// An instruction of the combined program, tagged with the script it came from.
+#[derive(Clone, Debug)]
+pub struct Step {
+    pub phase: Phase,
+    pub instruction: Instruction,
+}
+
+/// Runs an unlocking script followed by a locking script one opcode at a time.
+#[derive(Clone)]
+pub struct Interpreter {
+    program: Vec<Step>,
+    pc: usize,
+    stack: Vec<Vec<u8>>,
+    alt_stack: Vec<Vec<u8>>,
+    /// Branch state of the enclosing OP_IFs; code runs when all are true.
+    conditions: Vec<bool>,
+    /// Serialized sighash preimage that OP_CHECKSIG verifies signatures against.
+    preimage: Option<Vec<u8>>,
+    outcome: Option<Result<(), ScriptError>>,
+}
+
+impl Interpreter {
+    pub fn new(unlocking: &[u8], locking: &[u8]) -> Result<Self, ScriptError> {
+        let unlocking = parse(unlocking)?;
+        if let Some(instruction) = unlocking.iter().find(|instruction| !instruction.is_push()) {
+            return Err(ScriptError::UnlockingNotPushOnly(opcode_name(
+                instruction.opcode,
+            )));
+        }
+        let program = unlocking
+            .into_iter()
+            .map(|instruction| Step {
+                phase: Phase::Unlocking,
+                instruction,
+            })
+            .chain(parse(locking)?.into_iter().map(|instruction| Step {
+                phase: Phase::Locking,
+                instruction,
+            }))
+            .collect();
+
+        Ok(Self {
+            program,
+            pc: 0,
+            stack: Vec::new(),
+            alt_stack: Vec::new(),
+            conditions: Vec::new(),
+            preimage: None,
+            outcome: None,
+        })
+    }
+
+    /// Sets the sighash preimage of the spending transaction. Without one,
+    /// OP_CHECKSIG fails for any non-empty signature.
+    pub fn with_preimage(mut self, preimage: Vec<u8>) -> Self {
+        self.preimage = Some(preimage);
+        self
+    }
+
+    pub fn program(&self) -> &[Step] {
+        &self.program
+    }
+
+    /// Index into `program` of the next step to run.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Main stack, bottom first.
+    pub fn stack(&self) -> &[Vec<u8>] {
+        &self.stack
+    }
+
+    /// Alt stack, bottom first.
+    pub fn alt_stack(&self) -> &[Vec<u8>] {
+        &self.alt_stack
+    }
+
+    /// `None` until the program has finished or failed.
+    pub fn outcome(&self) -> Option<&Result<(), ScriptError>> {
+        self.outcome.as_ref()
+    }
+
+    /// Runs the next opcode. Returns the outcome once the program is over.
+    pub fn step(&mut self) -> Option<&Result<(), ScriptError>> {
+        if self.outcome.is_none() {
+            let result = self.execute_next();
+            match result {
+                Err(err) => self.outcome = Some(Err(err)),
+                Ok(Flow::Stop) => self.outcome = Some(self.final_result()),
+                Ok(Flow::Continue) if self.pc >= self.program.len() => {
+                    self.outcome = Some(if self.conditions.is_empty() {
+                        self.final_result()
+                    } else {
+                        Err(ScriptError::UnterminatedConditional)
+                    });
+                }
+                Ok(Flow::Continue) => {}
+            }
+        }
+        self.outcome.as_ref()
+    }
+
+    /// Runs to completion.
+    pub fn run(&mut self) -> Result<(), ScriptError> {
+        loop {
+            if let Some(outcome) = self.step() {
+                return outcome.clone();
+            }
+        }
+    }
+
+    fn final_result(&self) -> Result<(), ScriptError> {
+        match self.stack.last() {
+            None => Err(ScriptError::EmptyStack),
+            Some(top) if cast_to_bool(top) => Ok(()),
+            Some(_) => Err(ScriptError::EvalFalse),
+        }
+    }
+
+    fn execute_next(&mut self) -> Result<Flow, ScriptError> {
+        let Some(step) = self.program.get(self.pc) else {
+            return Ok(Flow::Continue);
+        };
+        let phase = step.phase;
+        let instruction = step.instruction.clone();
+
+        // Entering the locking script: branches must be closed, the alt stack
+        // does not carry over.
+        if phase == Phase::Locking
+            && self.pc > 0
+            && self.program[self.pc - 1].phase == Phase::Unlocking
+        {
+            if !self.conditions.is_empty() {
+                return Err(ScriptError::UnterminatedConditional);
+            }
+            self.alt_stack.clear();
+        }
+        self.pc += 1;
+        let flow = self.execute(&instruction)?;
+        let size = self.stack_memory();
+        if size > MAX_STACK_MEMORY {
+            return Err(ScriptError::StackMemoryExceeded {
+                op: opcode_name(instruction.opcode),
+                size,
+            });
+        }
+        Ok(flow)
+    }
+
+    fn execute(&mut self, instruction: &Instruction) -> Result<Flow, ScriptError> {
+        let opcode = instruction.opcode;
+        let op = opcode_name(opcode);
+        let executing = self.conditions.iter().all(|condition| *condition);
+
+        match opcode {
+            0x63 | 0x64 => {
+                let mut value = false;
+                if executing {
+                    value = cast_to_bool(&self.pop(op)?);
+                    if opcode == 0x64 {
+                        value = !value;
+                    }
+                }
+                self.conditions.push(value);
+                return Ok(Flow::Continue);
+            }
+            0x67 => {
+                let condition = self
+                    .conditions
+                    .last_mut()
+                    .ok_or(ScriptError::UnbalancedConditional(op))?;
+                *condition = !*condition;
+                return Ok(Flow::Continue);
+            }
+            0x68 => {
+                self.conditions
+                    .pop()
+                    .ok_or(ScriptError::UnbalancedConditional(op))?;
+                return Ok(Flow::Continue);
+            }
+            _ if !executing => return Ok(Flow::Continue),
+            _ => {}
+        }
+
+        if let Some(data) = &instruction.data {
+            self.stack.push(data.clone());
+            return Ok(Flow::Continue);
+        }
+
+        match opcode {
+            OP_1NEGATE => self.push_num(BigInt::from(-1)),
+            OP_1..=OP_16 => self.push_num(BigInt::from(opcode - OP_1 + 1)),
+
+            // Flow control. OP_NOP1..10 (incl. the old CLTV/CSV) are plain NOPs after Genesis.
+            0x61 | 0xb0..=0xb9 => {}
+            0x69 => self.verify(op)?,
+            0x6a => return Ok(Flow::Stop),
+
+            // Stack
+            0x6b => {
+                let item = self.pop(op)?;
+                self.alt_stack.push(item);
+            }
+            0x6c => {
+                let item = self
+                    .alt_stack
+                    .pop()
+                    .ok_or(ScriptError::AltStackUnderflow(op))?;
+                self.stack.push(item);
+            }
+            0x6d => {
+                self.require(2, op)?;
+                self.stack.truncate(self.stack.len() - 2);
+            }
+            0x6e => self.copy_range(2, 2, op)?,
+            0x6f => self.copy_range(3, 3, op)?,
+            0x70 => self.copy_range(4, 2, op)?,
+            0x71 => {
+                self.require(6, op)?;
+                let at = self.stack.len() - 6;
+                let items: Vec<_> = self.stack.drain(at..at + 2).collect();
+                self.stack.extend(items);
+            }
+            0x72 => {
+                self.require(4, op)?;
+                let at = self.stack.len() - 4;
+                let items: Vec<_> = self.stack.drain(at..at + 2).collect();
+                self.stack.extend(items);
+            }
+            0x73 => {
+                let top = self.top(0, op)?.clone();
+                if cast_to_bool(&top) {
+                    self.stack.push(top);
+                }
+            }
+            0x74 => self.push_num(BigInt::from(self.stack.len())),
+            0x75 => {
+                self.pop(op)?;
+            }
+            0x76 => self.copy_range(1, 1, op)?,
+            0x77 => {
+                self.require(2, op)?;
+                let at = self.stack.len() - 2;
+                self.stack.remove(at);
+            }
+            0x78 => self.copy_range(2, 1, op)?,
+            0x79 | 0x7a => {
+                let depth = self.pop_index(op)?;
+                self.require(depth.saturating_add(1), op)?;
+                let at = self.stack.len() - 1 - depth;
+                let item = if opcode == 0x79 {
+                    self.stack[at].clone()
+                } else {
+                    self.stack.remove(at)
+                };
+                self.stack.push(item);
+            }
+            0x7b => {
+                self.require(3, op)?;
+                let at = self.stack.len() - 3;
+                let item = self.stack.remove(at);
+                self.stack.push(item);
+            }
+            0x7c => {
+                self.require(2, op)?;
+                let len = self.stack.len();
+                self.stack.swap(len - 1, len - 2);
+            }
+            0x7d => {
+                self.require(2, op)?;
+                let top = self.stack[self.stack.len() - 1].clone();
+                let at = self.stack.len() - 2;
+                self.stack.insert(at, top);
+            }
+
+            // Splice
+            0x7e => {
+                let b = self.pop(op)?;
+                let mut a = self.pop(op)?;
+                a.extend(b);
+                self.stack.push(a);
+            }
+            0x7f => {
+                let at = self.pop_index(op)?;
+                let data = self.pop(op)?;
+                if at > data.len() {
+                    return Err(invalid(
+                        op,
+                        format!("split at {at} of {} bytes", data.len()),
+                    ));
+                }
+                let (left, right) = data.split_at(at);
+                self.stack.push(left.to_vec());
+                self.stack.push(right.to_vec());
+            }
+            0x80 => {
+                let size = self.pop_index(op)?;
+                // Checked before allocating, as the size comes from the script
+                let total = self.stack_memory().saturating_add(size);
+                if total > MAX_STACK_MEMORY {
+                    return Err(ScriptError::StackMemoryExceeded { op, size: total });
+                }
+                let value = self.pop(op)?;
+                self.stack.push(
+                    num2bin(&value, size).ok_or_else(|| {
+                        invalid(op, format!("value does not fit in {size} bytes"))
+                    })?,
+                );
+            }
+            0x81 => {
+                let value = self.pop(op)?;
+                self.stack.push(encode_num(&decode_num(&value)));
+            }
+            0x82 => {
+                let size = self.top(0, op)?.len();
+                self.push_num(BigInt::from(size));
+            }
+
+            // Bitwise logic
+            0x83 => {
+                let mut value = self.pop(op)?;
+                value.iter_mut().for_each(|byte| *byte = !*byte);
+                self.stack.push(value);
+            }
+            0x84..=0x86 => {
+                let b = self.pop(op)?;
+                let a = self.pop(op)?;
+                if a.len() != b.len() {
+                    return Err(invalid(op, "operands differ in size".into()));
+                }
+                let result = a
+                    .iter()
+                    .zip(&b)
+                    .map(|(a, b)| match opcode {
+                        0x84 => a & b,
+                        0x85 => a | b,
+                        _ => a ^ b,
+                    })
+                    .collect();
+                self.stack.push(result);
+            }
+            0x87 | 0x88 => {
+                let b = self.pop(op)?;
+                let a = self.pop(op)?;
+                self.push_bool(a == b);
+                if opcode == 0x88 {
+                    self.verify(op)?;
+                }
+            }
+
+            // Arithmetic
+            0x8b..=0x92 => {
+                let a = self.pop_num(op)?;
+                let result = match opcode {
+                    0x8b => a + 1,
+                    0x8c => a - 1,
+                    0x8f => -a,
+                    0x90 => a.abs(),
+                    0x91 => BigInt::from(a.is_zero() as u8),
+                    0x92 => BigInt::from(!a.is_zero() as u8),
+                    _ => return Err(ScriptError::Disabled(op)),
+                };
+                self.push_num(result);
+            }
+            0x93..=0x97 | 0x9a..=0xa4 => {
+                let b = self.pop_num(op)?;
+                let a = self.pop_num(op)?;
+                let result = match opcode {
+                    0x93 => a + b,
+                    0x94 => a - b,
+                    0x95 => a * b,
+                    0x96 | 0x97 if b.is_zero() => return Err(ScriptError::DivisionByZero(op)),
+                    // Truncating, like the reference implementation
+                    0x96 => a / b,
+                    0x97 => a % b,
+                    0x9a => BigInt::from((!a.is_zero() && !b.is_zero()) as u8),
+                    0x9b => BigInt::from((!a.is_zero() || !b.is_zero()) as u8),
+                    0x9c | 0x9d => BigInt::from((a == b) as u8),
+                    0x9e => BigInt::from((a != b) as u8),
+                    0x9f => BigInt::from((a < b) as u8),
+                    0xa0 => BigInt::from((a > b) as u8),
+                    0xa1 => BigInt::from((a <= b) as u8),
+                    0xa2 => BigInt::from((a >= b) as u8),
+                    0xa3 => a.min(b),
+                    _ => a.max(b),
+                };
+                self.push_num(result);
+                if opcode == 0x9d {
+                    self.verify(op)?;
+                }
+            }
+            0x98 | 0x99 => {
+                let bits = self.pop_index(op)?;
+                let value = self.pop(op)?;
+                self.stack.push(shift(&value, bits, opcode == 0x98));
+            }
+            0xa5 => {
+                let max = self.pop_num(op)?;
+                let min = self.pop_num(op)?;
+                let x = self.pop_num(op)?;
+                self.push_bool(min <= x && x < max);
+            }
+
+            // Crypto
+            0xa6..=0xaa => {
+                let data = self.pop(op)?;
+                self.stack.push(match opcode {
+                    0xa6 => hash::ripemd160(&data).to_vec(),
+                    0xa7 => hash::sha1(&data).to_vec(),
+                    0xa8 => hash::sha256(&data).to_vec(),
+                    0xa9 => hash::hash160(&data).to_vec(),
+                    _ => hash::hash256(&data).to_vec(),
+                });
+            }
+            // Only affects the script code in the preimage, which is provided as-is
+            0xab => {}
+            0xac | 0xad => {
+                let public_key = self.pop(op)?;
+                let signature = self.pop(op)?;
+                let valid = self.check_signature(&signature, &public_key, op)?;
+                self.push_bool(valid);
+                if opcode == 0xad {
+                    self.verify(op)?;
+                }
+            }
+            0xae | 0xaf => {
+                let valid = self.check_multisig(op)?;
+                self.push_bool(valid);
+                if opcode == 0xaf {
+                    self.verify(op)?;
+                }
+            }
+
+            0x62 | 0x65 | 0x66 | 0x50 | 0x89 | 0x8a | 0xba..=0xff => {
+                return Err(invalid(op, format!("invalid opcode 0x{opcode:02x}")));
+            }
+            _ => return Err(ScriptError::Unsupported(op)),
+        }
+        Ok(Flow::Continue)
+    }
+
+    /// An empty signature is simply false; a non-empty one that doesn't
+    /// verify is an error (NULLFAIL).
+    fn check_signature(
+        &self,
+        signature: &[u8],
+        public_key: &[u8],
+        op: &'static str,
+    ) -> Result<bool, ScriptError> {
+        if signature.is_empty() {
+            return Ok(false);
+        }
+        if self.signature_matches(signature, public_key, op)? {
+            Ok(true)
+        } else {
+            Err(ScriptError::SignatureFailed(op))
+        }
+    }
+
+    /// Stack: <dummy> <sig>... <m> <pubkey>... <n>. Signatures must match keys in order.
+    fn check_multisig(&mut self, op: &'static str) -> Result<bool, ScriptError> {
+        let key_count = self.pop_index(op)?;
+        self.require(key_count, op)?;
+        let keys = self.stack.split_off(self.stack.len() - key_count);
+        let signature_count = self.pop_index(op)?;
+        if signature_count > key_count {
+            return Err(invalid(
+                op,
+                format!("{signature_count} signatures for {key_count} keys"),
+            ));
+        }
+        self.require(signature_count + 1, op)?;
+        let signatures = self.stack.split_off(self.stack.len() - signature_count);
+        let dummy = self.pop(op)?;
+        if !dummy.is_empty() {
+            return Err(invalid(op, "dummy element must be empty".into()));
+        }
+        if signatures.iter().all(|signature| signature.is_empty()) {
+            return Ok(false);
+        }
+
+        // Both lists are consumed top-down, as in the reference implementation
+        let mut keys = keys.iter().rev();
+        for signature in signatures.iter().rev() {
+            let mut matched = false;
+            for key in keys.by_ref() {
+                if !signature.is_empty() && self.signature_matches(signature, key, op)? {
+                    matched = true;
+                    break;
+                }
+            }
+            if !matched {
+                return Err(ScriptError::SignatureFailed(op));
+            }
+        }
+        Ok(true)
+    }
+
+    /// Checks a non-empty `<DER signature><sighash type>` against the preimage.
+    fn signature_matches(
+        &self,
+        signature: &[u8],
+        public_key: &[u8],
+        op: &'static str,
+    ) -> Result<bool, ScriptError> {
+        let preimage = self
+            .preimage
+            .as_ref()
+            .ok_or(ScriptError::MissingPreimage(op))?;
+        let (&sighash_type, der) = signature
+            .split_last()
+            .ok_or(ScriptError::InvalidSignatureEncoding(op))?;
+        let signature =
+            Signature::parse_der(der).ok_or(ScriptError::InvalidSignatureEncoding(op))?;
+        if !signature.is_low_s() {
+            return Err(ScriptError::HighS(op));
+        }
+        if sighash_type & SIGHASH_FORKID == 0 {
+            return Err(ScriptError::MissingForkId(op, sighash_type));
+        }
+        // The preimage ends with the 4-byte little-endian sighash type
+        let preimage_type = preimage.len().checked_sub(4).map(|ix| preimage[ix]);
+        if let Some(preimage_type) = preimage_type.filter(|ty| *ty != sighash_type) {
+            return Err(ScriptError::SighashMismatch {
+                op,
+                signature: sighash_type,
+                preimage: preimage_type,
+            });
+        }
+        let public_key = PublicKey::parse(public_key).ok_or(ScriptError::InvalidPublicKey(op))?;
+        Ok(secp256k1::verify(
+            &public_key,
+            &hash::hash256(preimage),
+            &signature,
+        ))
+    }
+
+    fn require(&self, count: usize, op: &'static str) -> Result<(), ScriptError> {
+        if self.stack.len() < count {
+            Err(ScriptError::StackUnderflow(op))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn top(&self, depth: usize, op: &'static str) -> Result<&Vec<u8>, ScriptError> {
+        self.require(depth.saturating_add(1), op)?;
+        Ok(&self.stack[self.stack.len() - 1 - depth])
+    }
+
+    /// Pushes copies of `count` items starting `depth` items below the top.
+    fn copy_range(
+        &mut self,
+        depth: usize,
+        count: usize,
+        op: &'static str,
+    ) -> Result<(), ScriptError> {
+        self.require(depth, op)?;
+        let at = self.stack.len() - depth;
+        let items = self.stack[at..at + count].to_vec();
+        self.stack.extend(items);
+        Ok(())
+    }
+
+    fn pop(&mut self, op: &'static str) -> Result<Vec<u8>, ScriptError> {
+        self.stack.pop().ok_or(ScriptError::StackUnderflow(op))
+    }
+
+    fn pop_num(&mut self, op: &'static str) -> Result<BigInt, ScriptError> {
+        Ok(decode_num(&self.pop(op)?))
+    }
+
+    fn pop_index(&mut self, op: &'static str) -> Result<usize, ScriptError> {
+        let value = self.pop_num(op)?;
+        value
+            .to_usize()
+            .ok_or_else(|| invalid(op, format!("{value} is not a valid size or index")))
+    }
+
+    /// Bytes held by the main and alt stacks.
+    fn stack_memory(&self) -> usize {
+        self.stack
+            .iter()
+            .chain(&self.alt_stack)
+            .map(|item| item.len())
+            .sum()
+    }
+
+    fn push_num(&mut self, value: BigInt) {
+        self.stack.push(encode_num(&value));
+    }
+
+    fn push_bool(&mut self, value: bool) {
+        self.stack.push(if value { vec![1] } else { Vec::new() });
+    }
+
+    fn verify(&mut self, op: &'static str) -> Result<(), ScriptError> {
+        if cast_to_bool(&self.pop(op)?) {
+            Ok(())
+        } else {
+            Err(ScriptError::VerifyFailed(op))
+        }
+    }
+}
+
+enum Flow {
+    Continue,
+    Stop,
+}
+
+fn invalid(op: &'static str, reason: String) -> ScriptError {
+    ScriptError::InvalidOperand { op, reason }
+}
+
+fn num2bin(value: &[u8], size: usize) -> Option<Vec<u8>> {
+    let mut bytes = encode_num(&decode_num(value));
+    if bytes.len() > size {
+        return None;
+    }
+    if bytes.len() == size {
+        return Some(bytes);
+    }
+    let negative = bytes.last().is_some_and(|last| last & 0x80 != 0);
+    if let Some(last) = bytes.last_mut() {
+        *last &= 0x7f;
+    }
+    bytes.resize(size, 0);
+    if negative {
+        bytes[size - 1] |= 0x80;
+    }
+    Some(bytes)
+}
+
+/// Bit shift over the whole byte string (big-endian bit order), keeping its length.
+fn shift(value: &[u8], bits: usize, left: bool) -> Vec<u8> {
+    let len = value.len();
+    let mut result = vec![0u8; len];
+    let byte_shift = bits / 8;
+    let bit_shift = bits % 8;
+    if byte_shift >= len {
+        return result;
+    }
+    for (ix, slot) in result.iter_mut().enumerate() {
+        let (source, carry) = if left {
+            (ix + byte_shift, ix + byte_shift + 1)
+        } else {
+            match ix.checked_sub(byte_shift) {
+                Some(source) => (source, source.wrapping_sub(1)),
+                None => continue,
+            }
+        };
+        let Some(byte) = value.get(source) else {
+            continue;
+        };
+        let spill = value.get(carry).copied().unwrap_or(0);
+        *slot = if bit_shift == 0 {
+            *byte
+        } else if left {
+            (byte << bit_shift) | (spill >> (8 - bit_shift))
+        } else {
+            (byte >> bit_shift) | (spill << (8 - bit_shift))
+        };
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keys::PrivateKey, push_data};
+
+    fn run(unlocking: &[u8], locking: &[u8]) -> Result<(), ScriptError> {
+        Interpreter::new(unlocking, locking)?.run()
+    }
+
+    #[test]
+    fn test_arithmetic_and_branches() {
+        // 2 3 ADD 5 EQUAL
+        assert_eq!(run(&[0x52, 0x53], &[0x93, 0x55, 0x87]), Ok(()));
+        // 0 IF 2 ELSE 3 ENDIF 3 EQUAL
+        assert_eq!(
+            run(&[0x00], &[0x63, 0x52, 0x67, 0x53, 0x68, 0x53, 0x87]),
+            Ok(())
+        );
+        assert_eq!(
+            run(&[0x52], &[0x53, 0x88]),
+            Err(ScriptError::VerifyFailed("OP_EQUALVERIFY"))
+        );
+    }
+
+    #[test]
+    fn test_stack_memory_policy() {
+        // 1 8 NUM2BIN
+        assert_eq!(run(&[0x51], &[0x58, 0x80, 0x82, 0x58, 0x88]), Ok(()));
+
+        // 1 <2^32> NUM2BIN fails before allocating 4 GB
+        let mut locking = push_data(&encode_num(&BigInt::from(1u64 << 32)));
+        locking.push(0x80);
+        assert_eq!(
+            run(&[0x51], &locking),
+            Err(ScriptError::StackMemoryExceeded {
+                op: "OP_NUM2BIN",
+                size: (1 << 32) + 1,
+            })
+        );
+
+        // 1 <usize::MAX> NUM2BIN doesn't overflow the bound check
+        let mut locking = push_data(&encode_num(&BigInt::from(usize::MAX)));
+        locking.push(0x80);
+        assert_eq!(
+            run(&[0x51], &locking),
+            Err(ScriptError::StackMemoryExceeded {
+                op: "OP_NUM2BIN",
+                size: usize::MAX,
+            })
+        );
+
+        // Growing the stack past the limit fails on the opcode that does it
+        let mut locking = push_data(&encode_num(&BigInt::from(MAX_STACK_MEMORY / 2)));
+        locking.extend([0x80, 0x76, 0x76]);
+        assert_eq!(
+            run(&[0x51], &locking),
+            Err(ScriptError::StackMemoryExceeded {
+                op: "OP_DUP",
+                size: MAX_STACK_MEMORY / 2 * 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_pick_and_roll_depth() {
+        // 1 2 1 PICK leaves 1 2 1
+        assert_eq!(
+            run(&[0x51, 0x52], &[0x51, 0x79, 0x51, 0x88, 0x52, 0x88]),
+            Ok(())
+        );
+        // 1 2 1 ROLL leaves 2 1
+        assert_eq!(
+            run(&[0x51, 0x52], &[0x51, 0x7a, 0x51, 0x88, 0x52, 0x87]),
+            Ok(())
+        );
+
+        // Depths past the stack, up to usize::MAX, underflow
+        for (opcode, op) in [(0x79, "OP_PICK"), (0x7a, "OP_ROLL")] {
+            for depth in [2, usize::MAX] {
+                let mut locking = push_data(&encode_num(&BigInt::from(depth)));
+                locking.push(opcode);
+                assert_eq!(
+                    run(&[0x51, 0x52], &locking),
+                    Err(ScriptError::StackUnderflow(op)),
+                    "{op} {depth}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_opcodes() {
+        let mut locking = vec![0xa9];
+        locking.extend(push_data(
+            &hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap(),
+        ));
+        locking.push(0x87);
+        let unlocking = push_data(
+            &hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap(),
+        );
+        assert_eq!(run(&unlocking, &locking), Ok(()));
+    }
+
+    #[test]
+    fn test_checksig_against_preimage() {
+        let key = PrivateKey::from_secret_bytes(&[0xc0; 32], true).unwrap();
+        let public_key = key.public_key();
+        let preimage = [b"fake preimage".as_slice(), &[0x41, 0, 0, 0]].concat();
+        let mut signature = key.sign(&hash::hash256(&preimage)).to_der();
+        signature.push(0x41);
+
+        let mut locking = push_data(&public_key);
+        locking.push(0xac);
+        let unlocking = push_data(&signature);
+
+        let interpreter = Interpreter::new(&unlocking, &locking).unwrap();
+        assert_eq!(
+            interpreter.clone().run(),
+            Err(ScriptError::MissingPreimage("OP_CHECKSIG"))
+        );
+        assert_eq!(interpreter.with_preimage(preimage.clone()).run(), Ok(()));
+
+        let other = [b"other preimage".as_slice(), &[0x41, 0, 0, 0]].concat();
+        assert_eq!(
+            Interpreter::new(&unlocking, &locking)
+                .unwrap()
+                .with_preimage(other)
+                .run(),
+            Err(ScriptError::SignatureFailed("OP_CHECKSIG"))
+        );
+
+        // An empty signature is false rather than an error
+        let mut locking = push_data(&public_key);
+        locking.extend([0xac, 0x91]);
+        assert_eq!(
+            Interpreter::new(&[0x00], &locking)
+                .unwrap()
+                .with_preimage(preimage)
+                .run(),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! WIF private keys and P2PKH addresses.
+
+use crate::{
+    base58,
+    hash::hash160,
+    secp256k1::{self, PublicKey, SecretKey, Signature},
+};
+
+const MAINNET_ADDRESS: u8 = 0x00;
+const TESTNET_ADDRESS: u8 = 0x6f;
+const MAINNET_WIF: u8 = 0x80;
+const TESTNET_WIF: u8 = 0xef;
+
+#[derive(Clone)]
+pub struct PrivateKey {
+    secret: SecretKey,
+    compressed: bool,
+    testnet: bool,
+}
+
+impl PrivateKey {
+    /// Parses a WIF key (`K…`/`L…`/`5…` on mainnet, `c…`/`9…` on testnet and regtest).
+    pub fn from_wif(wif: &str) -> Option<Self> {
+        let payload = base58::decode_check(wif.trim())?;
+        let testnet = match *payload.first()? {
+            MAINNET_WIF => false,
+            TESTNET_WIF => true,
+            _ => return None,
+        };
+        let compressed = match payload.len() {
+            33 => false,
+            34 if payload[33] == 0x01 => true,
+            _ => return None,
+        };
+        let secret = SecretKey::from_slice(&payload[1..33]).ok()?;
+        Some(Self {
+            secret,
+            compressed,
+            testnet,
+        })
+    }
+
+    /// A compressed key from 32 secret bytes, or `None` if they are out of range.
+    pub fn from_secret_bytes(secret: &[u8; 32], testnet: bool) -> Option<Self> {
+        let secret = SecretKey::from_slice(secret).ok()?;
+        Some(Self {
+            secret,
+            compressed: true,
+            testnet,
+        })
+    }
+
+    pub fn to_wif(&self) -> String {
+        let mut payload = vec![if self.testnet {
+            TESTNET_WIF
+        } else {
+            MAINNET_WIF
+        }];
+        payload.extend_from_slice(&self.secret.to_bytes());
+        if self.compressed {
+            payload.push(0x01);
+        }
+        base58::encode_check(&payload)
+    }
+
+    pub fn is_testnet(&self) -> bool {
+        self.testnet
+    }
+
+    /// The public key in the encoding the WIF asks for.
+    pub fn public_key(&self) -> Vec<u8> {
+        let key = PublicKey::from_secret(&self.secret);
+        if self.compressed {
+            key.to_compressed().to_vec()
+        } else {
+            key.to_uncompressed().to_vec()
+        }
+    }
+
+    pub fn pubkey_hash(&self) -> [u8; 20] {
+        hash160(&self.public_key())
+    }
+
+    pub fn address(&self) -> String {
+        p2pkh_address(&self.pubkey_hash(), self.testnet)
+    }
+
+    /// Signs a 32-byte digest with a deterministic nonce.
+    pub fn sign(&self, digest: &[u8; 32]) -> Signature {
+        secp256k1::sign(&self.secret, digest)
+    }
+}
+
+pub fn p2pkh_address(pubkey_hash: &[u8; 20], testnet: bool) -> String {
+    let mut payload = vec![if testnet {
+        TESTNET_ADDRESS
+    } else {
+        MAINNET_ADDRESS
+    }];
+    payload.extend_from_slice(pubkey_hash);
+    base58::encode_check(&payload)
+}
+
+/// The pubkey hash of a P2PKH address and whether it is a testnet address.
+pub fn decode_address(address: &str) -> Option<([u8; 20], bool)> {
+    let payload = base58::decode_check(address.trim())?;
+    let testnet = match *payload.first()? {
+        MAINNET_ADDRESS => false,
+        TESTNET_ADDRESS => true,
+        _ => return None,
+    };
+    let hash = payload.get(1..)?.try_into().ok()?;
+    Some((hash, testnet))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wif_and_address() {
+        let key =
+            PrivateKey::from_wif("KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn").unwrap();
+        assert!(!key.is_testnet());
+        assert_eq!(key.address(), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+        assert_eq!(
+            key.to_wif(),
+            "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn"
+        );
+        assert_eq!(
+            decode_address("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"),
+            Some((key.pubkey_hash(), false))
+        );
+
+        let mut one = [0; 32];
+        one[31] = 1;
+        assert_eq!(
+            PrivateKey::from_secret_bytes(&one, false).unwrap().to_wif(),
+            key.to_wif()
+        );
+        assert!(PrivateKey::from_secret_bytes(&[0; 32], true).is_none());
+
+        let uncompressed =
+            PrivateKey::from_wif("5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf").unwrap();
+        assert_eq!(uncompressed.address(), "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm");
+
+        assert!(
+            PrivateKey::from_wif("KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWm").is_none()
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! SPV checks: merkle proofs in the TSC format and block header proof of work.
+//!
+//! Hashes are in internal byte order throughout, i.e. reversed from how
+//! explorers display txids and block hashes.
+
+use crate::hash::hash256;
+
+/// A sibling on the path from a transaction to the merkle root.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MerkleNode {
+    Hash([u8; 32]),
+    /// `*` in a TSC proof: the sibling is the node itself, as happens at the
+    /// end of a level with an odd number of nodes.
+    Duplicate,
+}
+
+/// Folds a proof into the merkle root it commits to. `index` is the
+/// transaction's position in the block; its bits say which side each node is on.
+pub fn merkle_root(txid: [u8; 32], index: u64, nodes: &[MerkleNode]) -> [u8; 32] {
+    merkle_path(txid, index, nodes).1
+}
+
+/// The proof as `(sibling, sibling_is_left)` steps with duplicates resolved,
+/// as an on-chain verifier needs it, and the root it leads to.
+pub fn merkle_path(
+    txid: [u8; 32],
+    index: u64,
+    nodes: &[MerkleNode],
+) -> (Vec<([u8; 32], bool)>, [u8; 32]) {
+    let mut hash = txid;
+    let mut path = Vec::with_capacity(nodes.len());
+    for (level, node) in nodes.iter().enumerate() {
+        let sibling = match node {
+            MerkleNode::Hash(sibling) => *sibling,
+            MerkleNode::Duplicate => hash,
+        };
+        let sibling_is_left = (index >> level) & 1 == 1;
+        let pair = if sibling_is_left {
+            [sibling, hash].concat()
+        } else {
+            [hash, sibling].concat()
+        };
+        hash = hash256(&pair);
+        path.push((sibling, sibling_is_left));
+    }
+    (path, hash)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub version: u32,
+    pub prev_block: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub time: u32,
+    /// Compact encoding of the proof-of-work target.
+    pub bits: u32,
+    pub nonce: u32,
+}
+
+impl BlockHeader {
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 80 {
+            return None;
+        }
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        Some(Self {
+            version: u32_at(0),
+            prev_block: bytes[4..36].try_into().ok()?,
+            merkle_root: bytes[36..68].try_into().ok()?,
+            time: u32_at(68),
+            bits: u32_at(72),
+            nonce: u32_at(76),
+        })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = self.version.to_le_bytes().to_vec();
+        bytes.extend(self.prev_block);
+        bytes.extend(self.merkle_root);
+        bytes.extend(self.time.to_le_bytes());
+        bytes.extend(self.bits.to_le_bytes());
+        bytes.extend(self.nonce.to_le_bytes());
+        bytes
+    }
+
+    pub fn hash(&self) -> [u8; 32] {
+        hash256(&self.serialize())
+    }
+
+    /// Whether the header's hash is at or below the target its `bits` claim.
+    pub fn meets_target(&self) -> bool {
+        let Some(target) = target_from_bits(self.bits) else {
+            return false;
+        };
+        let mut hash = self.hash();
+        hash.reverse();
+        hash <= target
+    }
+}
+
+/// Expands compact `bits` into a big-endian 256-bit target, or `None` if
+/// it's negative or overflows.
+pub fn target_from_bits(bits: u32) -> Option<[u8; 32]> {
+    let exponent = (bits >> 24) as usize;
+    let mantissa = bits & 0x007f_ffff;
+    if bits & 0x0080_0000 != 0 && mantissa != 0 {
+        return None;
+    }
+
+    let mut target = [0u8; 32];
+    let mantissa_bytes = &mantissa.to_be_bytes()[1..];
+    for (ix, byte) in mantissa_bytes.iter().enumerate() {
+        // Byte `ix` of the mantissa has weight 256^(exponent - 1 - ix)
+        let Some(weight) = (exponent + 2).checked_sub(ix + 3) else {
+            continue;
+        };
+        match weight {
+            0..32 => target[31 - weight] = *byte,
+            _ if *byte != 0 => return None,
+            _ => {}
+        }
+    }
+    Some(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reversed(hex_hash: &str) -> [u8; 32] {
+        let mut hash: [u8; 32] = hex::decode(hex_hash).unwrap().try_into().unwrap();
+        hash.reverse();
+        hash
+    }
+
+    #[test]
+    fn test_genesis_header() {
+        let header = BlockHeader::parse(&hex::decode(
+            "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c",
+        ).unwrap())
+        .unwrap();
+        assert_eq!(
+            header.hash(),
+            reversed("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f")
+        );
+        assert!(header.meets_target());
+        // The coinbase is the only transaction, so it is the merkle root
+        let coinbase = reversed("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b");
+        assert_eq!(merkle_root(coinbase, 0, &[]), header.merkle_root);
+
+        let mut harder = header.clone();
+        harder.bits = 0x1b00ffff;
+        assert!(!harder.meets_target());
+    }
+
+    #[test]
+    fn test_merkle_root_from_proof() {
+        let leaves = [[1u8; 32], [2; 32], [3; 32]];
+        let pair = |left: &[u8; 32], right: &[u8; 32]| hash256(&[&left[..], &right[..]].concat());
+        let root = pair(&pair(&leaves[0], &leaves[1]), &pair(&leaves[2], &leaves[2]));
+
+        let proof = [
+            MerkleNode::Hash(leaves[0]),
+            MerkleNode::Hash(pair(&leaves[2], &leaves[2])),
+        ];
+        assert_eq!(merkle_root(leaves[1], 1, &proof), root);
+        let proof = [
+            MerkleNode::Duplicate,
+            MerkleNode::Hash(pair(&leaves[0], &leaves[1])),
+        ];
+        assert_eq!(merkle_root(leaves[2], 2, &proof), root);
+        assert_ne!(merkle_root(leaves[2], 0, &proof), root);
+        let (path, _) = merkle_path(leaves[2], 2, &proof);
+        assert_eq!(path[0], (leaves[2], false));
+        assert!(path[1].1);
+    }
+
+    #[test]
+    fn test_target_from_bits() {
+        let target = target_from_bits(0x1d00ffff).unwrap();
+        assert_eq!(
+            hex::encode(target),
+            "00000000ffff0000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(
+            target_from_bits(0x03123456).unwrap()[29..],
+            [0x12, 0x34, 0x56]
+        );
+        assert_eq!(target_from_bits(0x02123456).unwrap()[30..], [0x12, 0x34]);
+        assert_eq!(target_from_bits(0x04923456), None);
+        assert_eq!(target_from_bits(0x23000001), None);
+    }
+}
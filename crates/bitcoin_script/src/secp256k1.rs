@@ -0,0 +1,336 @@
+//! ECDSA over secp256k1.
+//!
+//! Parsing and verification only ever see public data, so they use plain
+//! big-integer arithmetic. Anything touching a secret key - deriving its
+//! public key and signing - goes through the constant-time `k256` crate.
+
+use std::sync::LazyLock;
+
+use k256::ecdsa::{SigningKey, signature::hazmat::PrehashSigner as _};
+use num_bigint::BigUint;
+use num_traits::Zero as _;
+
+pub use k256::SecretKey;
+
+struct Curve {
+    p: BigUint,
+    n: BigUint,
+    g: Point,
+}
+
+static CURVE: LazyLock<Curve> = LazyLock::new(|| {
+    let hex = |digits: &str| BigUint::parse_bytes(digits.as_bytes(), 16).unwrap();
+    Curve {
+        p: hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+        n: hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+        g: Point {
+            x: hex("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            y: hex("483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"),
+        },
+    }
+});
+
+/// An affine curve point; the point at infinity is represented as `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Point {
+    x: BigUint,
+    y: BigUint,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(Point);
+
+impl PublicKey {
+    /// Parses a compressed (33-byte) or uncompressed (65-byte) SEC1 key.
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let p = &CURVE.p;
+        let x = BigUint::from_bytes_be(bytes.get(1..33)?);
+        if &x >= p {
+            return None;
+        }
+        let y_squared = (x.modpow(&BigUint::from(3u8), p) + 7u8) % p;
+
+        let y = match (bytes[0], bytes.len()) {
+            (0x02 | 0x03, 33) => {
+                let y = y_squared.modpow(&((p + 1u8) >> 2), p);
+                if (&y * &y) % p != y_squared {
+                    return None;
+                }
+                if y.bit(0) == (bytes[0] == 0x03) {
+                    y
+                } else {
+                    p - y
+                }
+            }
+            (0x04, 65) => {
+                let y = BigUint::from_bytes_be(&bytes[33..]);
+                if &y >= p || (&y * &y) % p != y_squared {
+                    return None;
+                }
+                y
+            }
+            _ => return None,
+        };
+        Some(Self(Point { x, y }))
+    }
+
+    /// The key for `secret`, i.e. `secret * G`.
+    pub fn from_secret(secret: &SecretKey) -> Self {
+        let point = secret.public_key().to_sec1_bytes();
+        Self::parse(&point).expect("k256 encodes valid points")
+    }
+
+    pub fn to_compressed(&self) -> [u8; 33] {
+        let mut bytes = [0u8; 33];
+        bytes[0] = if self.0.y.bit(0) { 0x03 } else { 0x02 };
+        write_be(&self.0.x, &mut bytes[1..]);
+        bytes
+    }
+
+    pub fn to_uncompressed(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[0] = 0x04;
+        write_be(&self.0.x, &mut bytes[1..33]);
+        write_be(&self.0.y, &mut bytes[33..]);
+        bytes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r: BigUint,
+    s: BigUint,
+}
+
+impl Signature {
+    /// Parses a strict DER signature (without the trailing sighash byte).
+    pub fn parse_der(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 8 || bytes.len() > 72 || bytes[0] != 0x30 {
+            return None;
+        }
+        if bytes[1] as usize != bytes.len() - 2 {
+            return None;
+        }
+        let (r, rest) = parse_der_integer(&bytes[2..])?;
+        let (s, rest) = parse_der_integer(rest)?;
+        rest.is_empty().then_some(Self { r, s })
+    }
+
+    /// Parses the 64-byte `r || s` form used by compact signatures.
+    pub fn from_compact(bytes: &[u8]) -> Option<Self> {
+        (bytes.len() == 64).then(|| Self {
+            r: BigUint::from_bytes_be(&bytes[..32]),
+            s: BigUint::from_bytes_be(&bytes[32..]),
+        })
+    }
+
+    pub fn to_der(&self) -> Vec<u8> {
+        fn integer(value: &BigUint) -> Vec<u8> {
+            let mut bytes = value.to_bytes_be();
+            if bytes[0] & 0x80 != 0 {
+                bytes.insert(0, 0);
+            }
+            let mut encoded = vec![0x02, bytes.len() as u8];
+            encoded.extend(bytes);
+            encoded
+        }
+
+        let body = [integer(&self.r), integer(&self.s)].concat();
+        let mut der = vec![0x30, body.len() as u8];
+        der.extend(body);
+        der
+    }
+
+    /// Whether `s` is in the lower half of the order, as BSV policy requires.
+    pub fn is_low_s(&self) -> bool {
+        self.s <= &CURVE.n >> 1
+    }
+
+    /// The equivalent low-S signature.
+    pub fn normalized(&self) -> Self {
+        if self.is_low_s() {
+            self.clone()
+        } else {
+            Self {
+                r: self.r.clone(),
+                s: &CURVE.n - &self.s,
+            }
+        }
+    }
+}
+
+fn parse_der_integer(bytes: &[u8]) -> Option<(BigUint, &[u8])> {
+    if bytes.len() < 2 || bytes[0] != 0x02 {
+        return None;
+    }
+    let len = bytes[1] as usize;
+    let value = bytes.get(2..2 + len)?;
+    let first = *value.first()?;
+    // Negative, or padded with a zero byte that isn't needed
+    if first & 0x80 != 0 || (len > 1 && first == 0 && value[1] & 0x80 == 0) {
+        return None;
+    }
+    Some((BigUint::from_bytes_be(value), &bytes[2 + len..]))
+}
+
+/// Checks `signature` over the 32-byte `digest` (e.g. a sighash).
+pub fn verify(public_key: &PublicKey, digest: &[u8; 32], signature: &Signature) -> bool {
+    let n = &CURVE.n;
+    let Signature { r, s } = signature;
+    if r.is_zero() || s.is_zero() || r >= n || s >= n {
+        return false;
+    }
+    let e = BigUint::from_bytes_be(digest);
+    let w = s.modpow(&(n - 2u8), n);
+    let u1 = (e * &w) % n;
+    let u2 = (r * &w) % n;
+    let point = add(
+        multiply(&u1, &CURVE.g).as_ref(),
+        multiply(&u2, &public_key.0).as_ref(),
+    );
+    point.is_some_and(|point| &(point.x % n) == r)
+}
+
+/// Signs `digest` with a deterministic RFC 6979 nonce, returning a low-S signature.
+pub fn sign(secret: &SecretKey, digest: &[u8; 32]) -> Signature {
+    let signature: k256::ecdsa::Signature = SigningKey::from(secret)
+        .sign_prehash(digest)
+        .expect("a 32-byte digest is a valid prehash");
+    let signature = signature.normalize_s().unwrap_or(signature);
+    let (r, s) = signature.split_bytes();
+    Signature {
+        r: BigUint::from_bytes_be(&r),
+        s: BigUint::from_bytes_be(&s),
+    }
+}
+
+fn write_be(value: &BigUint, out: &mut [u8]) {
+    let bytes = value.to_bytes_be();
+    let start = out.len() - bytes.len();
+    out[start..].copy_from_slice(&bytes);
+}
+
+fn field_inverse(value: &BigUint) -> BigUint {
+    let p = &CURVE.p;
+    value.modpow(&(p - 2u8), p)
+}
+
+fn field_sub(a: &BigUint, b: &BigUint) -> BigUint {
+    let p = &CURVE.p;
+    (a + p - b) % p
+}
+
+fn add(a: Option<&Point>, b: Option<&Point>) -> Option<Point> {
+    let p = &CURVE.p;
+    let (a, b) = match (a, b) {
+        (None, b) => return b.cloned(),
+        (a, None) => return a.cloned(),
+        (Some(a), Some(b)) => (a, b),
+    };
+    if a.x == b.x {
+        return if ((&a.y + &b.y) % p).is_zero() {
+            None
+        } else {
+            double(a)
+        };
+    }
+    let lambda = (field_sub(&b.y, &a.y) * field_inverse(&field_sub(&b.x, &a.x))) % p;
+    let x = field_sub(&field_sub(&((&lambda * &lambda) % p), &a.x), &b.x);
+    let y = field_sub(&((lambda * field_sub(&a.x, &x)) % p), &a.y);
+    Some(Point { x, y })
+}
+
+fn double(point: &Point) -> Option<Point> {
+    let p = &CURVE.p;
+    if point.y.is_zero() {
+        return None;
+    }
+    let numerator = (BigUint::from(3u8) * &point.x * &point.x) % p;
+    let lambda = (numerator * field_inverse(&((BigUint::from(2u8) * &point.y) % p))) % p;
+    let x = field_sub(
+        &((&lambda * &lambda) % p),
+        &((BigUint::from(2u8) * &point.x) % p),
+    );
+    let y = field_sub(&((lambda * field_sub(&point.x, &x)) % p), &point.y);
+    Some(Point { x, y })
+}
+
+fn multiply(scalar: &BigUint, point: &Point) -> Option<Point> {
+    let mut result: Option<Point> = None;
+    for bit in (0..scalar.bits()).rev() {
+        result = result.as_ref().and_then(double);
+        if scalar.bit(bit) {
+            result = add(result.as_ref(), Some(point));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::sha256;
+
+    fn secret_key(value: u32) -> SecretKey {
+        let mut bytes = [0; 32];
+        bytes[28..].copy_from_slice(&value.to_be_bytes());
+        SecretKey::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_public_key_from_secret() {
+        let key = PublicKey::from_secret(&secret_key(2));
+        assert_eq!(
+            hex::encode(key.to_compressed()),
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"
+        );
+        assert_eq!(PublicKey::parse(&key.to_compressed()), Some(key.clone()));
+        assert_eq!(PublicKey::parse(&key.to_uncompressed()), Some(key));
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let secret = secret_key(0xdead_beef);
+        let key = PublicKey::from_secret(&secret);
+        let digest = sha256(b"utxix");
+        let signature = sign(&secret, &digest);
+
+        assert!(signature.is_low_s());
+        assert_eq!(
+            Signature::parse_der(&signature.to_der()),
+            Some(signature.clone())
+        );
+        assert!(verify(&key, &digest, &signature));
+        assert!(!verify(&key, &sha256(b"other"), &signature));
+        let compact = [signature.r.to_bytes_be(), signature.s.to_bytes_be()]
+            .map(|mut half| {
+                while half.len() < 32 {
+                    half.insert(0, 0);
+                }
+                half
+            })
+            .concat();
+        assert_eq!(Signature::from_compact(&compact), Some(signature.clone()));
+
+        let other_key = PublicKey::from_secret(&secret_key(0xdead_bef0));
+        assert!(!verify(&other_key, &digest, &signature));
+    }
+
+    #[test]
+    fn test_rfc6979_signature() {
+        let signature = sign(&secret_key(1), &sha256(b"Satoshi Nakamoto"));
+        assert_eq!(
+            hex::encode(signature.to_der()),
+            "3045022100934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8\
+             02202442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+        );
+    }
+
+    #[test]
+    fn test_parse_der_rejects_padding() {
+        let mut der = vec![0x30, 0x08, 0x02, 0x02, 0x00, 0x01, 0x02, 0x02, 0x00, 0x01];
+        assert_eq!(Signature::parse_der(&der), None);
+        der = vec![0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01];
+        assert!(Signature::parse_der(&der).is_some());
+    }
+}
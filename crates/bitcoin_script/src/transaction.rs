@@ -0,0 +1,226 @@
+//! Transaction serialization and BIP143-style (FORKID) signature hashes.
+
+use crate::{OP_CHECKSIG, OP_DUP, OP_EQUALVERIFY, OP_HASH160, hash::hash256, push_data};
+
+pub const SIGHASH_ALL: u8 = 0x01;
+pub const SIGHASH_NONE: u8 = 0x02;
+pub const SIGHASH_SINGLE: u8 = 0x03;
+pub const SIGHASH_FORKID: u8 = 0x40;
+pub const SIGHASH_ANYONECANPAY: u8 = 0x80;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transaction {
+    pub version: u32,
+    pub inputs: Vec<TxIn>,
+    pub outputs: Vec<TxOut>,
+    pub lock_time: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxIn {
+    /// In internal byte order, i.e. reversed from the displayed txid.
+    pub prev_txid: [u8; 32],
+    pub prev_vout: u32,
+    pub script_sig: Vec<u8>,
+    pub sequence: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxOut {
+    pub satoshis: u64,
+    pub script: Vec<u8>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self {
+            version: 1,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            lock_time: 0,
+        }
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = self.version.to_le_bytes().to_vec();
+        write_var_int(&mut bytes, self.inputs.len() as u64);
+        for input in &self.inputs {
+            bytes.extend(input.prev_txid);
+            bytes.extend(input.prev_vout.to_le_bytes());
+            write_var_bytes(&mut bytes, &input.script_sig);
+            bytes.extend(input.sequence.to_le_bytes());
+        }
+        write_var_int(&mut bytes, self.outputs.len() as u64);
+        for output in &self.outputs {
+            output.write(&mut bytes);
+        }
+        bytes.extend(self.lock_time.to_le_bytes());
+        bytes
+    }
+
+    /// The txid as displayed by explorers.
+    pub fn txid(&self) -> String {
+        let mut hash = hash256(&self.serialize());
+        hash.reverse();
+        hex::encode(hash)
+    }
+
+    /// The data signed by an input's signature: `script_code` is the locking
+    /// script being spent and `satoshis` its value.
+    pub fn sighash_preimage(
+        &self,
+        input_ix: usize,
+        script_code: &[u8],
+        satoshis: u64,
+        sighash_type: u8,
+    ) -> Vec<u8> {
+        let input = &self.inputs[input_ix];
+        let base_type = sighash_type & 0x1f;
+        let anyone_can_pay = sighash_type & SIGHASH_ANYONECANPAY != 0;
+
+        let hash_prevouts = if anyone_can_pay {
+            [0; 32]
+        } else {
+            let mut outpoints = Vec::new();
+            for input in &self.inputs {
+                outpoints.extend(input.prev_txid);
+                outpoints.extend(input.prev_vout.to_le_bytes());
+            }
+            hash256(&outpoints)
+        };
+        let hash_sequence =
+            if anyone_can_pay || base_type == SIGHASH_SINGLE || base_type == SIGHASH_NONE {
+                [0; 32]
+            } else {
+                let sequences: Vec<u8> = self
+                    .inputs
+                    .iter()
+                    .flat_map(|input| input.sequence.to_le_bytes())
+                    .collect();
+                hash256(&sequences)
+            };
+        let hash_outputs = if base_type != SIGHASH_SINGLE && base_type != SIGHASH_NONE {
+            let mut outputs = Vec::new();
+            for output in &self.outputs {
+                output.write(&mut outputs);
+            }
+            hash256(&outputs)
+        } else if base_type == SIGHASH_SINGLE && input_ix < self.outputs.len() {
+            let mut output = Vec::new();
+            self.outputs[input_ix].write(&mut output);
+            hash256(&output)
+        } else {
+            [0; 32]
+        };
+
+        let mut preimage = self.version.to_le_bytes().to_vec();
+        preimage.extend(hash_prevouts);
+        preimage.extend(hash_sequence);
+        preimage.extend(input.prev_txid);
+        preimage.extend(input.prev_vout.to_le_bytes());
+        write_var_bytes(&mut preimage, script_code);
+        preimage.extend(satoshis.to_le_bytes());
+        preimage.extend(input.sequence.to_le_bytes());
+        preimage.extend(hash_outputs);
+        preimage.extend(self.lock_time.to_le_bytes());
+        preimage.extend((sighash_type as u32).to_le_bytes());
+        preimage
+    }
+}
+
+impl Default for Transaction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TxIn {
+    /// An unsigned input spending `txid:vout`, with a final sequence number.
+    pub fn new(txid: &str, vout: u32) -> Option<Self> {
+        Some(Self {
+            prev_txid: parse_txid(txid)?,
+            prev_vout: vout,
+            script_sig: Vec::new(),
+            sequence: u32::MAX,
+        })
+    }
+}
+
+impl TxOut {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.satoshis.to_le_bytes());
+        write_var_bytes(bytes, &self.script);
+    }
+}
+
+/// Converts a displayed txid to internal byte order.
+pub fn parse_txid(txid: &str) -> Option<[u8; 32]> {
+    let mut bytes: [u8; 32] = hex::decode(txid.trim()).ok()?.try_into().ok()?;
+    bytes.reverse();
+    Some(bytes)
+}
+
+pub fn p2pkh_script(pubkey_hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = vec![OP_DUP, OP_HASH160];
+    script.extend(push_data(pubkey_hash));
+    script.extend([OP_EQUALVERIFY, OP_CHECKSIG]);
+    script
+}
+
+pub fn write_var_int(bytes: &mut Vec<u8>, value: u64) {
+    match value {
+        0..0xfd => bytes.push(value as u8),
+        0xfd..=0xffff => {
+            bytes.push(0xfd);
+            bytes.extend((value as u16).to_le_bytes());
+        }
+        0x10000..=0xffff_ffff => {
+            bytes.push(0xfe);
+            bytes.extend((value as u32).to_le_bytes());
+        }
+        _ => {
+            bytes.push(0xff);
+            bytes.extend(value.to_le_bytes());
+        }
+    }
+}
+
+fn write_var_bytes(bytes: &mut Vec<u8>, data: &[u8]) {
+    write_var_int(bytes, data.len() as u64);
+    bytes.extend_from_slice(data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Interpreter, keys::PrivateKey};
+
+    #[test]
+    fn test_signed_p2pkh_spend_verifies() {
+        let key =
+            PrivateKey::from_wif("cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN87JcbXMTcA").unwrap();
+        let locking = p2pkh_script(&key.pubkey_hash());
+        let txid = "0000000000000000000000000000000000000000000000000000000000000001";
+
+        let mut tx = Transaction::new();
+        tx.inputs.push(TxIn::new(txid, 0).unwrap());
+        tx.outputs.push(TxOut {
+            satoshis: 900,
+            script: locking.clone(),
+        });
+        let sighash_type = SIGHASH_ALL | SIGHASH_FORKID;
+        let preimage = tx.sighash_preimage(0, &locking, 1000, sighash_type);
+        let mut signature = key.sign(&hash256(&preimage)).to_der();
+        signature.push(sighash_type);
+        let mut unlocking = push_data(&signature);
+        unlocking.extend(push_data(&key.public_key()));
+
+        let result = Interpreter::new(&unlocking, &locking)
+            .unwrap()
+            .with_preimage(preimage)
+            .run();
+        assert_eq!(result, Ok(()));
+        assert_eq!(tx.inputs[0].prev_txid[0], 1);
+        assert_eq!(tx.serialize().len(), 10 + 41 + 8 + 1 + 25);
+    }
+}
@@ -0,0 +1,788 @@
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result, anyhow, bail};
+use bitcoin_script::{
+    OP_0, OP_1, OP_1NEGATE, OP_16, OP_DROP, OP_DUP, OP_ELSE, OP_ENDIF, OP_IF, OP_NOTIF,
+    OP_NUMEQUAL, OP_NUMEQUALVERIFY, OP_PUSHDATA1, OP_PUSHDATA2, OP_PUSHDATA4, OP_RETURN,
+    decode_num, hash::sha256, parse, push_data, push_number,
+};
+use num_bigint::BigInt;
+use serde::Deserialize;
+
+/// A compiled sCrypt contract (`artifacts/contracts/*.scrypt.json`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct Artifact {
+    pub contract: String,
+    /// Hash of the contract source the artifact was compiled from.
+    #[serde(default)]
+    pub md5: Option<String>,
+    #[serde(default, rename = "compilerVersion")]
+    pub compiler_version: Option<String>,
+    #[serde(default)]
+    pub abi: Vec<AbiEntry>,
+    /// Locking script template; constructor arguments appear as `<name>`.
+    pub hex: String,
+    #[serde(default, rename = "stateProps")]
+    pub state_props: Vec<Param>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AbiEntry {
+    /// `function` or `constructor`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub index: Option<u32>,
+    #[serde(default)]
+    pub params: Vec<Param>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Param {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// A piece of a disassembled script template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateToken {
+    /// An opcode or push, in ASM form.
+    Asm(String),
+    /// A `<name>` placeholder filled in with a constructor argument.
+    Placeholder(String),
+}
+
+/// Compiled size of a contract, or of the part of it one public method runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScriptSize {
+    pub bytes: usize,
+    pub ops: usize,
+    /// Constructor arguments aren't counted in `bytes`, as they're only
+    /// known at deploy time.
+    pub has_placeholders: bool,
+}
+
+impl ScriptSize {
+    fn of(ops: &[TemplateOp]) -> Self {
+        Self {
+            bytes: ops.iter().map(|op| op.len).sum(),
+            ops: ops.len(),
+            has_placeholders: ops.iter().any(|op| op.opcode.is_none()),
+        }
+    }
+}
+
+/// An instruction of a script template; `opcode` is `None` for a placeholder.
+struct TemplateOp {
+    opcode: Option<u8>,
+    /// ASM, or `<name>` for a placeholder.
+    asm: String,
+    /// The value of a push that reads as a script number.
+    number: Option<BigInt>,
+    len: usize,
+}
+
+/// Splits a stateful contract's locking script into its code part and its
+/// serialized state: `<code> OP_RETURN <state> <state length, 4 bytes LE> <version>`.
+pub fn split_state(script: &[u8]) -> Option<(&[u8], &[u8])> {
+    let suffix = script.len().checked_sub(5)?;
+    let len = u32::from_le_bytes(script[suffix..suffix + 4].try_into().ok()?) as usize;
+    let start = suffix.checked_sub(len)?;
+    let op_return = start.checked_sub(1)?;
+    (script[op_return] == OP_RETURN).then(|| (&script[..op_return], &script[start..suffix]))
+}
+
+/// Compiled artifacts under `artifacts/contracts`, sorted by path.
+pub fn find_artifacts(root: &Path) -> Vec<PathBuf> {
+    let dir = root.join("artifacts").join("contracts");
+    let mut artifacts: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.to_string_lossy().ends_with(".scrypt.json"))
+        .collect();
+    artifacts.sort();
+    artifacts
+}
+
+/// The contract name an artifact path is for.
+pub fn artifact_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| {
+            name.to_string_lossy()
+                .trim_end_matches(".scrypt.json")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// SHA-256 of an artifact file in hex, recorded with deployments to tell
+/// whether the contract was recompiled since.
+pub fn artifact_hash(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    Ok(hex::encode(sha256(&bytes)))
+}
+
+impl Artifact {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn constructor_params(&self) -> &[Param] {
+        self.abi
+            .iter()
+            .find(|entry| entry.kind == "constructor")
+            .map_or(&[][..], |entry| entry.params.as_slice())
+    }
+
+    pub fn public_methods(&self) -> impl Iterator<Item = &AbiEntry> {
+        self.abi.iter().filter(|entry| entry.kind == "function")
+    }
+
+    /// The script template as ASM, keeping its placeholders, and the size in
+    /// bytes of everything but the placeholders.
+    pub fn template_asm(&self) -> (Vec<TemplateToken>, usize) {
+        let mut tokens = Vec::new();
+        let mut size = 0;
+        for (segment, placeholder) in template_segments(&self.hex) {
+            if !segment.is_empty() {
+                let bytes = hex::decode(segment).ok();
+                size += bytes.as_ref().map_or(segment.len() / 2, Vec::len);
+                match bytes.and_then(|bytes| parse(&bytes).ok()) {
+                    Some(instructions) => tokens.extend(
+                        instructions
+                            .iter()
+                            .map(|instruction| TemplateToken::Asm(instruction.asm())),
+                    ),
+                    // Not a whole number of instructions; show the raw bytes
+                    None => tokens.push(TemplateToken::Asm(segment.to_string())),
+                }
+            }
+            tokens.extend(placeholder.map(|name| TemplateToken::Placeholder(name.to_string())));
+        }
+        (tokens, size)
+    }
+
+    /// The template's instructions, or `None` if a segment doesn't parse.
+    fn template_ops(&self) -> Option<Vec<TemplateOp>> {
+        let mut ops = Vec::new();
+        for (segment, placeholder) in template_segments(&self.hex) {
+            let bytes = hex::decode(segment).ok()?;
+            let instructions = parse(&bytes).ok()?;
+            let ends = instructions
+                .iter()
+                .skip(1)
+                .map(|instruction| instruction.offset)
+                .chain([bytes.len()]);
+            for (instruction, end) in instructions.iter().zip(ends) {
+                let number = match (instruction.opcode, &instruction.data) {
+                    (OP_0, _) => Some(BigInt::from(0)),
+                    (OP_1NEGATE, _) => Some(BigInt::from(-1)),
+                    (OP_1..=OP_16, _) => Some(BigInt::from(instruction.opcode - OP_1 + 1)),
+                    (_, Some(data)) if data.len() <= 4 => Some(decode_num(data)),
+                    _ => None,
+                };
+                ops.push(TemplateOp {
+                    opcode: Some(instruction.opcode),
+                    asm: instruction.asm(),
+                    number,
+                    len: end - instruction.offset,
+                });
+            }
+            if let Some(name) = placeholder {
+                ops.push(TemplateOp {
+                    opcode: None,
+                    asm: format!("<{name}>"),
+                    number: None,
+                    len: 0,
+                });
+            }
+        }
+        Some(ops)
+    }
+
+    /// Size of the whole locking script template.
+    pub fn script_size(&self) -> ScriptSize {
+        match self.template_ops() {
+            Some(ops) => ScriptSize::of(&ops),
+            None => {
+                let (tokens, bytes) = self.template_asm();
+                ScriptSize {
+                    bytes,
+                    ops: tokens.len(),
+                    has_placeholders: tokens
+                        .iter()
+                        .any(|token| matches!(token, TemplateToken::Placeholder(_))),
+                }
+            }
+        }
+    }
+
+    /// Size of the branch each public method runs, in ABI index order.
+    ///
+    /// With several public methods the compiler dispatches on the method index
+    /// the unlocking script pushes last: `OP_DUP 0 OP_NUMEQUAL OP_IF OP_DROP
+    /// <method 0> OP_ELSE OP_DUP 1 OP_NUMEQUAL OP_IF ...`, with the last method
+    /// checked by `OP_NUMEQUALVERIFY`. Returns `None` when the template doesn't
+    /// follow that shape.
+    pub fn method_sizes(&self) -> Option<Vec<(String, ScriptSize)>> {
+        let Some(ops) = self.template_ops() else {
+            // A single method runs the whole script, whatever it parses to
+            let mut methods = self.public_methods();
+            let name = methods.next()?.name.clone().unwrap_or_default();
+            return methods
+                .next()
+                .is_none()
+                .then(|| vec![(name, self.script_size())]);
+        };
+        let branches = self.method_branches(&ops)?;
+        Some(
+            branches
+                .into_iter()
+                .map(|(name, range)| (name, ScriptSize::of(&ops[range])))
+                .collect(),
+        )
+    }
+
+    /// The instructions of the branch each public method runs, as ASM with
+    /// placeholders as `<name>`, in ABI index order. See [`Self::method_sizes`].
+    pub fn method_asm(&self) -> Option<Vec<(String, Vec<String>)>> {
+        let ops = self.template_ops()?;
+        let branches = self.method_branches(&ops)?;
+        Some(
+            branches
+                .into_iter()
+                .map(|(name, range)| {
+                    let asm = ops[range].iter().map(|op| op.asm.clone()).collect();
+                    (name, asm)
+                })
+                .collect(),
+        )
+    }
+
+    /// The range of `ops` each public method runs.
+    fn method_branches(&self, ops: &[TemplateOp]) -> Option<Vec<(String, Range<usize>)>> {
+        let mut methods: Vec<&AbiEntry> = self.public_methods().collect();
+        methods.sort_by_key(|entry| entry.index.unwrap_or_default());
+        let names: Vec<String> = methods
+            .iter()
+            .map(|entry| entry.name.clone().unwrap_or_default())
+            .collect();
+        match names.as_slice() {
+            [] => return None,
+            [name] => return Some(vec![(name.clone(), 0..ops.len())]),
+            _ => {}
+        }
+
+        let opcode = |ix: usize| ops.get(ix).and_then(|op| op.opcode);
+        // (start, end) of each selector, and how many of them opened an OP_IF
+        let mut selectors: Vec<(usize, usize)> = Vec::new();
+        let mut opened = 0;
+        let mut depth = 0usize;
+        for (ix, op) in ops.iter().enumerate() {
+            let index = selectors.len();
+            if depth == index && op.number == Some(BigInt::from(index)) {
+                let selector = match opcode(ix + 1) {
+                    Some(OP_NUMEQUAL) if opcode(ix + 2) == Some(OP_IF) => {
+                        let end = if opcode(ix + 3) == Some(OP_DROP) {
+                            ix + 4
+                        } else {
+                            ix + 3
+                        };
+                        Some((end, true))
+                    }
+                    Some(OP_NUMEQUALVERIFY) => Some((ix + 2, false)),
+                    _ => None,
+                };
+                let mut start = ix;
+                if start > 0 && opcode(start - 1) == Some(OP_DUP) {
+                    start -= 1;
+                }
+                let follows_else = start > 0 && opcode(start - 1) == Some(OP_ELSE);
+                if follows_else {
+                    start -= 1;
+                }
+                if let Some((end, opens_if)) = selector.filter(|_| index == 0 || follows_else) {
+                    selectors.push((start, end));
+                    opened += usize::from(opens_if);
+                }
+            }
+            match op.opcode {
+                Some(OP_IF | OP_NOTIF) => depth += 1,
+                Some(OP_ENDIF) => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        if selectors.len() != names.len() {
+            return None;
+        }
+
+        let mut branches = Vec::new();
+        for (ix, name) in names.into_iter().enumerate() {
+            let start = selectors[ix].1;
+            let mut end = selectors.get(ix + 1).map_or(ops.len(), |next| next.0);
+            if ix + 1 == selectors.len() {
+                // The last branch is followed by the OP_ENDIFs of every selector
+                for _ in 0..opened {
+                    if end > start && opcode(end - 1) == Some(OP_ENDIF) {
+                        end -= 1;
+                    }
+                }
+            }
+            branches.push((name, start..end.max(start)));
+        }
+        Some(branches)
+    }
+
+    /// Decodes serialized state into `(name, value)` pairs in `stateProps`
+    /// order, with arrays flattened to `name[i]`. Booleans are one byte and
+    /// everything else is length-prefixed, as the sCrypt compiler writes them.
+    pub fn decode_state(&self, mut state: &[u8]) -> Result<Vec<(String, String)>> {
+        let mut props = Vec::new();
+        for param in &self.state_props {
+            for (name, ty) in flatten_param(&param.name, &param.ty) {
+                let value = if matches!(ty.as_str(), "bool" | "boolean") {
+                    let (byte, rest) = state
+                        .split_first()
+                        .with_context(|| format!("state ends before `{name}`"))?;
+                    state = rest;
+                    (*byte != 0).to_string()
+                } else {
+                    let len = read_var_int(&mut state)
+                        .with_context(|| format!("state ends before `{name}`"))?;
+                    if state.len() < len {
+                        bail!("state ends inside `{name}`");
+                    }
+                    let (data, rest) = state.split_at(len);
+                    state = rest;
+                    match ty.as_str() {
+                        "int" | "bigint" | "PrivKey" => decode_num(data).to_string(),
+                        _ if data.is_empty() => "''".to_string(),
+                        _ => hex::encode(data),
+                    }
+                };
+                props.push((name, value));
+            }
+        }
+        if !state.is_empty() {
+            bail!("{} unexpected byte(s) after the state", state.len());
+        }
+        Ok(props)
+    }
+
+    /// The locking script with constructor `args` substituted, in ABI order.
+    pub fn locking_script(&self, args: &[String]) -> Result<Vec<u8>> {
+        let params = self.constructor_params();
+        if args.len() != params.len() {
+            bail!(
+                "{} takes {} constructor argument(s) ({}), got {}",
+                self.contract,
+                params.len(),
+                describe(params),
+                args.len()
+            );
+        }
+
+        let mut hex_script = self.hex.clone();
+        for (param, arg) in params.iter().zip(args) {
+            let pushes = encode_arg(&param.ty, arg)
+                .with_context(|| format!("constructor argument `{}`", param.name))?;
+            let whole = format!("<{}>", param.name);
+            if hex_script.contains(&whole) {
+                hex_script = hex_script.replace(&whole, &hex::encode(pushes.concat()));
+            } else {
+                for (ix, push) in pushes.iter().enumerate() {
+                    hex_script = hex_script
+                        .replace(&format!("<{}[{}]>", param.name, ix), &hex::encode(push));
+                }
+            }
+        }
+
+        if let Some(start) = hex_script.find('<') {
+            let end = hex_script[start..]
+                .find('>')
+                .map_or(hex_script.len(), |end| start + end + 1);
+            bail!("no value for placeholder {}", &hex_script[start..end]);
+        }
+        hex::decode(&hex_script).context("artifact hex is not valid hex")
+    }
+
+    /// Pushes `args` for `method`, followed by the method index when the
+    /// contract has more than one public method.
+    pub fn unlocking_script(&self, method: &str, args: &[String]) -> Result<Vec<u8>> {
+        let entry = self
+            .public_methods()
+            .find(|entry| entry.name.as_deref() == Some(method))
+            .ok_or_else(|| anyhow!("{} has no public method `{method}`", self.contract))?;
+        if args.len() != entry.params.len() {
+            bail!(
+                "{method} takes {} argument(s) ({}), got {}",
+                entry.params.len(),
+                describe(&entry.params),
+                args.len()
+            );
+        }
+
+        let mut script = Vec::new();
+        for (param, arg) in entry.params.iter().zip(args) {
+            let pushes =
+                encode_arg(&param.ty, arg).with_context(|| format!("argument `{}`", param.name))?;
+            script.extend(pushes.concat());
+        }
+        if self.public_methods().count() > 1 {
+            let index = entry.index.unwrap_or_default();
+            script.extend(push_number(&BigInt::from(index)));
+        }
+        Ok(script)
+    }
+
+    /// Recognizes a locking script built from this artifact, returning its
+    /// constructor arguments, then its state if it has any, as `(name, value)`
+    /// pairs.
+    pub fn match_script(&self, script: &[u8]) -> Option<Vec<(String, String)>> {
+        let params = self.constructor_params();
+        let mut values = Vec::new();
+        let mut rest = script;
+        for (segment, placeholder) in template_segments(&self.hex) {
+            rest = rest.strip_prefix(hex::decode(segment).ok()?.as_slice())?;
+            let Some(name) = placeholder else {
+                continue;
+            };
+            // `<name>` stands for every element of an array, `<name[i]>` for one
+            let elements = match params.iter().find(|param| param.name == name) {
+                Some(param) => flatten_param(&param.name, &param.ty),
+                None => params
+                    .iter()
+                    .flat_map(|param| flatten_param(&param.name, &param.ty))
+                    .filter(|(element, _)| element == name)
+                    .collect(),
+            };
+            if elements.is_empty() {
+                return None;
+            }
+            for (element, ty) in elements {
+                let (data, len) = read_push(rest)?;
+                rest = &rest[len..];
+                let value = match ty.as_str() {
+                    "int" | "bigint" | "PrivKey" => decode_num(&data).to_string(),
+                    "bool" | "boolean" => (!data.is_empty()).to_string(),
+                    _ if data.is_empty() => "''".to_string(),
+                    _ => hex::encode(&data),
+                };
+                values.push((element, value));
+            }
+        }
+        if !rest.is_empty() {
+            let (code, state) = split_state(script)?;
+            if code.len() != script.len() - rest.len() {
+                return None;
+            }
+            values.extend(self.decode_state(state).ok()?);
+        }
+        Some(values)
+    }
+}
+
+/// The data of the push at the start of `script`, with small numbers as
+/// their one-byte encoding, and the length of the push.
+pub fn read_push(script: &[u8]) -> Option<(Vec<u8>, usize)> {
+    let (&opcode, rest) = script.split_first()?;
+    let (len, width) = match opcode {
+        OP_0 => return Some((Vec::new(), 1)),
+        OP_1NEGATE => return Some((vec![0x81], 1)),
+        OP_1..=OP_16 => return Some((vec![opcode - OP_1 + 1], 1)),
+        0x01..=0x4b => (opcode as usize, 0),
+        OP_PUSHDATA1 => (*rest.first()? as usize, 1),
+        OP_PUSHDATA2 => (
+            u16::from_le_bytes(rest.get(..2)?.try_into().ok()?) as usize,
+            2,
+        ),
+        OP_PUSHDATA4 => (
+            u32::from_le_bytes(rest.get(..4)?.try_into().ok()?) as usize,
+            4,
+        ),
+        _ => return None,
+    };
+    let data = rest.get(width..width + len)?;
+    Some((data.to_vec(), 1 + width + len))
+}
+
+/// Splits template hex into runs of hex, each followed by the name of the
+/// placeholder after it, if any.
+fn template_segments(hex: &str) -> Vec<(&str, Option<&str>)> {
+    let mut segments = Vec::new();
+    let mut rest = hex;
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(start) => {
+                let end = rest[start..]
+                    .find('>')
+                    .map_or(rest.len(), |end| start + end + 1);
+                let name = rest[start + 1..end].trim_end_matches('>');
+                segments.push((&rest[..start], Some(name)));
+                rest = &rest[end..];
+            }
+            None => segments.push((std::mem::take(&mut rest), None)),
+        }
+    }
+    segments
+}
+
+/// Expands array types (`int[2][3]`) into one `(name, type)` per element.
+fn flatten_param(name: &str, ty: &str) -> Vec<(String, String)> {
+    let Some((base, len, inner_dims)) = ty.split_once('[').and_then(|(base, dims)| {
+        let (len, inner_dims) = dims.split_once(']')?;
+        Some((base, len.trim().parse::<usize>().ok()?, inner_dims))
+    }) else {
+        return vec![(name.to_string(), ty.to_string())];
+    };
+    let element_ty = format!("{base}{inner_dims}");
+    (0..len)
+        .flat_map(|ix| flatten_param(&format!("{name}[{ix}]"), &element_ty))
+        .collect()
+}
+
+fn read_var_int(bytes: &mut &[u8]) -> Option<usize> {
+    let (&first, rest) = bytes.split_first()?;
+    let width = match first {
+        0xfd => 2,
+        0xfe => 4,
+        0xff => 8,
+        len => {
+            *bytes = rest;
+            return Some(len as usize);
+        }
+    };
+    let value = rest
+        .get(..width)?
+        .iter()
+        .rev()
+        .fold(0u64, |value, byte| (value << 8) | *byte as u64);
+    *bytes = &rest[width..];
+    usize::try_from(value).ok()
+}
+
+fn describe(params: &[Param]) -> String {
+    params
+        .iter()
+        .map(|param| format!("{}: {}", param.name, param.ty))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Splits a comma-separated argument list, keeping `[...]` arrays together.
+pub fn split_args(text: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                args.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() || !args.is_empty() {
+        args.push(current.trim().to_string());
+    }
+    args
+}
+
+/// Encodes an argument as one push per scalar (arrays flatten in order).
+fn encode_arg(ty: &str, value: &str) -> Result<Vec<Vec<u8>>> {
+    let value = value.trim();
+    if let Some((element_ty, _)) = ty.strip_suffix(']').and_then(|ty| ty.split_once('[')) {
+        let inner = value
+            .strip_prefix('[')
+            .and_then(|value| value.strip_suffix(']'))
+            .ok_or_else(|| anyhow!("expected an array like [a, b] for {ty}"))?;
+        let mut pushes = Vec::new();
+        for element in split_args(inner) {
+            pushes.extend(encode_arg(element_ty, &element)?);
+        }
+        return Ok(pushes);
+    }
+
+    let push = match ty {
+        "int" | "bigint" | "PrivKey" => push_number(&parse_int(value)?),
+        "bool" | "boolean" => match value {
+            "true" => push_number(&BigInt::from(1)),
+            "false" => push_data(&[]),
+            _ => bail!("expected true or false, got `{value}`"),
+        },
+        _ => push_data(&parse_bytes(value)?),
+    };
+    Ok(vec![push])
+}
+
+fn parse_int(value: &str) -> Result<BigInt> {
+    let digits = value.strip_suffix('n').unwrap_or(value);
+    let (negative, digits) = match digits.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, digits),
+    };
+    let parsed = match digits.strip_prefix("0x") {
+        Some(hex_digits) => BigInt::parse_bytes(hex_digits.as_bytes(), 16),
+        None => BigInt::parse_bytes(digits.as_bytes(), 10),
+    }
+    .ok_or_else(|| anyhow!("`{value}` is not an integer"))?;
+    Ok(if negative { -parsed } else { parsed })
+}
+
+/// Hex (optionally `0x`-prefixed), or a quoted string for its UTF-8 bytes.
+fn parse_bytes(value: &str) -> Result<Vec<u8>> {
+    for quote in ['"', '\''] {
+        if let Some(text) = value
+            .strip_prefix(quote)
+            .and_then(|value| value.strip_suffix(quote))
+        {
+            return Ok(text.as_bytes().to_vec());
+        }
+    }
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    hex::decode(digits).with_context(|| format!("`{value}` is not hex"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_asm_keeps_placeholders() {
+        let artifact: Artifact = serde_json::from_str(
+            r#"{"contract": "HashLock", "hex": "<hash>a8887c<pubKey>ac", "abi": []}"#,
+        )
+        .unwrap();
+        let (tokens, size) = artifact.template_asm();
+        assert_eq!(
+            tokens,
+            [
+                TemplateToken::Placeholder("hash".into()),
+                TemplateToken::Asm("OP_SHA256".into()),
+                TemplateToken::Asm("OP_EQUALVERIFY".into()),
+                TemplateToken::Asm("OP_SWAP".into()),
+                TemplateToken::Placeholder("pubKey".into()),
+                TemplateToken::Asm("OP_CHECKSIG".into()),
+            ]
+        );
+        assert_eq!(size, 4);
+    }
+
+    #[test]
+    fn test_match_script() {
+        let artifact: Artifact = serde_json::from_str(
+            r#"{
+                "contract": "Listing",
+                "hex": "<seller>ad<price>9c<tags>87",
+                "abi": [{"type": "constructor", "params": [
+                    {"name": "seller", "type": "PubKey"},
+                    {"name": "price", "type": "int"},
+                    {"name": "tags", "type": "bool[2]"}
+                ]}]
+            }"#,
+        )
+        .unwrap();
+        let args = ["02abcd", "10000", "[true, false]"].map(String::from);
+        let script = artifact.locking_script(&args).unwrap();
+        assert_eq!(
+            artifact.match_script(&script),
+            Some(vec![
+                ("seller".to_string(), "02abcd".to_string()),
+                ("price".to_string(), "10000".to_string()),
+                ("tags[0]".to_string(), "true".to_string()),
+                ("tags[1]".to_string(), "false".to_string()),
+            ])
+        );
+        assert_eq!(artifact.match_script(&script[..script.len() - 1]), None);
+        assert_eq!(
+            artifact.match_script(&[script.as_slice(), &[0x51]].concat()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_method_sizes() {
+        // OP_DUP 0 OP_NUMEQUAL OP_IF OP_DROP <pubKey> OP_CHECKSIG
+        // OP_ELSE OP_DUP 1 OP_NUMEQUAL OP_IF OP_DROP 1 OP_ADD 0 OP_NUMEQUAL OP_IF 1 OP_ENDIF
+        // OP_ELSE 2 OP_NUMEQUALVERIFY abcd OP_EQUAL
+        // OP_ENDIF OP_ENDIF
+        let artifact: Artifact = serde_json::from_str(
+            r#"{
+                "contract": "Demo",
+                "hex": "76009c6375<pubKey>ac6776519c63755193009c63516867529d4c02abcd876868",
+                "abi": [
+                    {"type": "function", "name": "c", "index": 2, "params": []},
+                    {"type": "function", "name": "a", "index": 0, "params": []},
+                    {"type": "function", "name": "b", "index": 1, "params": []},
+                    {"type": "constructor", "params": [{"name": "pubKey", "type": "PubKey"}]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let size = |bytes, ops, has_placeholders| ScriptSize {
+            bytes,
+            ops,
+            has_placeholders,
+        };
+        assert_eq!(artifact.script_size(), size(29, 27, true));
+        assert_eq!(
+            artifact.method_sizes().unwrap(),
+            [
+                ("a".to_string(), size(1, 2, true)),
+                ("b".to_string(), size(7, 7, false)),
+                ("c".to_string(), size(5, 2, false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_state() {
+        let artifact: Artifact = serde_json::from_str(
+            r#"{
+                "contract": "TicTacToe",
+                "hex": "",
+                "stateProps": [
+                    {"name": "is_alice_turn", "type": "bool"},
+                    {"name": "board", "type": "int[3]"},
+                    {"name": "winner", "type": "PubKey"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut script = vec![0x51, OP_RETURN];
+        let state = [0x01, 0x01, 0x01, 0x00, 0x01, 0x82, 0x02, 0xab, 0xcd];
+        script.extend(state);
+        script.extend((state.len() as u32).to_le_bytes());
+        script.push(0x00);
+
+        let (code, state) = split_state(&script).unwrap();
+        assert_eq!(code, [0x51]);
+        assert_eq!(
+            artifact.decode_state(state).unwrap(),
+            [
+                ("is_alice_turn", "true"),
+                ("board[0]", "1"),
+                ("board[1]", "0"),
+                ("board[2]", "-2"),
+                ("winner", "abcd"),
+            ]
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+        );
+        assert!(artifact.decode_state(&state[..4]).is_err());
+    }
+}
@@ -0,0 +1,398 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+use gpui::{
+    AnyElement, App, Context, Entity, EventEmitter, FocusHandle, Focusable, FontWeight,
+    HighlightStyle, SharedString, StyledText, Task, Window,
+};
+use project::{Project, ProjectEntryId, ProjectPath};
+use ui::prelude::*;
+use workspace::{
+    Pane,
+    invalid_item_view::InvalidItemView,
+    item::{Item, ProjectItem, TabContentParams},
+};
+
+use crate::artifact::{Artifact, Param, TemplateToken};
+
+/// A compiled contract artifact opened from the project.
+pub struct ArtifactItem {
+    abs_path: PathBuf,
+    project_path: ProjectPath,
+    entry_id: Option<ProjectEntryId>,
+    artifact: Artifact,
+}
+
+/// Artifacts are the `.json` files under an `artifacts` directory, other than
+/// the compiler's auxiliary outputs (`*.transformer.json`, source maps...).
+fn is_artifact_path(path: &ProjectPath) -> bool {
+    let Some(file_name) = path.path.file_name() else {
+        return false;
+    };
+    let Some(stem) = file_name.strip_suffix(".json") else {
+        return false;
+    };
+    let stem = stem.strip_suffix(".scrypt").unwrap_or(stem);
+    !stem.contains('.')
+        && path
+            .path
+            .components()
+            .any(|component| component == "artifacts")
+}
+
+impl project::ProjectItem for ArtifactItem {
+    fn try_open(
+        project: &Entity<Project>,
+        path: &ProjectPath,
+        cx: &mut App,
+    ) -> Option<Task<anyhow::Result<Entity<Self>>>> {
+        if !is_artifact_path(path) {
+            return None;
+        }
+
+        let path = path.clone();
+        let project = project.clone();
+        Some(cx.spawn(async move |cx| {
+            let (abs_path, entry_id) = project.read_with(cx, |project, cx| {
+                (
+                    project.absolute_path(&path, cx),
+                    project.entry_for_path(&path, cx).map(|entry| entry.id),
+                )
+            })?;
+            let abs_path =
+                abs_path.with_context(|| format!("finding the absolute path of {path:?}"))?;
+            let artifact = cx
+                .background_spawn({
+                    let abs_path = abs_path.clone();
+                    async move { Artifact::load(&abs_path) }
+                })
+                .await?;
+            cx.new(|_| ArtifactItem {
+                abs_path,
+                project_path: path,
+                entry_id,
+                artifact,
+            })
+        }))
+    }
+
+    fn entry_id(&self, _: &App) -> Option<ProjectEntryId> {
+        self.entry_id
+    }
+
+    fn project_path(&self, _: &App) -> Option<ProjectPath> {
+        Some(self.project_path.clone())
+    }
+
+    fn is_dirty(&self) -> bool {
+        false
+    }
+}
+
+/// Renders an artifact's ABI, its script template as ASM with the constructor
+/// placeholders highlighted, and its size and source hash.
+pub struct ArtifactView {
+    item: Entity<ArtifactItem>,
+    focus_handle: FocusHandle,
+    error: Option<SharedString>,
+    reload_task: Task<()>,
+}
+
+impl ArtifactView {
+    fn new(
+        project: Entity<Project>,
+        item: Entity<ArtifactItem>,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        // Recompiling rewrites the artifact in place
+        cx.subscribe(&project, |this, _, event, cx| {
+            if let project::Event::WorktreeUpdatedEntries(worktree_id, entries) = event {
+                let project_path = this.item.read(cx).project_path.clone();
+                if *worktree_id == project_path.worktree_id
+                    && entries
+                        .iter()
+                        .any(|(path, _, _)| *path == project_path.path)
+                {
+                    this.reload(cx);
+                }
+            }
+        })
+        .detach();
+        cx.observe(&item, |_, _, cx| cx.notify()).detach();
+
+        Self {
+            item,
+            focus_handle: cx.focus_handle(),
+            error: None,
+            reload_task: Task::ready(()),
+        }
+    }
+
+    fn reload(&mut self, cx: &mut Context<Self>) {
+        let abs_path = self.item.read(cx).abs_path.clone();
+        let load = cx.background_spawn(async move { Artifact::load(&abs_path) });
+        self.reload_task = cx.spawn(async move |this, cx| {
+            let result = load.await;
+            this.update(cx, |this, cx| {
+                match result {
+                    Ok(artifact) => {
+                        this.error = None;
+                        this.item.update(cx, |item, cx| {
+                            item.artifact = artifact;
+                            cx.notify();
+                        });
+                    }
+                    Err(err) => this.error = Some(format!("{err:#}").into()),
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn render_section(
+        &self,
+        title: &'static str,
+        content: impl IntoElement,
+        cx: &Context<Self>,
+    ) -> impl IntoElement {
+        v_flex()
+            .gap_1()
+            .pt_3()
+            .border_t_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(Label::new(title).size(LabelSize::Small).color(Color::Muted))
+            .child(content)
+    }
+
+    fn render_params(params: &[Param], cx: &Context<Self>) -> AnyElement {
+        if params.is_empty() {
+            return Label::new("()")
+                .size(LabelSize::Small)
+                .color(Color::Muted)
+                .buffer_font(cx)
+                .into_any_element();
+        }
+        v_flex()
+            .children(params.iter().map(|param| {
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Label::new(param.name.clone())
+                            .size(LabelSize::Small)
+                            .buffer_font(cx),
+                    )
+                    .child(
+                        Label::new(param.ty.clone())
+                            .size(LabelSize::Small)
+                            .color(Color::Accent)
+                            .buffer_font(cx),
+                    )
+            }))
+            .into_any_element()
+    }
+
+    fn render_template(&self, tokens: &[TemplateToken], cx: &Context<Self>) -> impl IntoElement {
+        let placeholder_style = HighlightStyle {
+            color: Some(cx.theme().colors().text_accent),
+            font_weight: Some(FontWeight::BOLD),
+            ..Default::default()
+        };
+        let mut text = String::new();
+        let mut highlights = Vec::new();
+        for token in tokens {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            match token {
+                TemplateToken::Asm(asm) => text.push_str(asm),
+                TemplateToken::Placeholder(name) => {
+                    let start = text.len();
+                    text.push('<');
+                    text.push_str(name);
+                    text.push('>');
+                    highlights.push((start..text.len(), placeholder_style));
+                }
+            }
+        }
+
+        div()
+            .p_2()
+            .rounded_sm()
+            .bg(cx.theme().colors().element_background)
+            .font_buffer(cx)
+            .text_xs()
+            .child(StyledText::new(text).with_highlights(highlights))
+    }
+}
+
+impl EventEmitter<()> for ArtifactView {}
+
+impl Focusable for ArtifactView {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Item for ArtifactView {
+    type Event = ();
+
+    fn for_each_project_item(
+        &self,
+        cx: &App,
+        f: &mut dyn FnMut(gpui::EntityId, &dyn project::ProjectItem),
+    ) {
+        f(self.item.entity_id(), self.item.read(cx))
+    }
+
+    fn tab_content(&self, params: TabContentParams, _window: &Window, cx: &App) -> AnyElement {
+        Label::new(self.tab_content_text(params.detail.unwrap_or_default(), cx))
+            .single_line()
+            .color(params.text_color())
+            .when(params.preview, |this| this.italic())
+            .into_any_element()
+    }
+
+    fn tab_content_text(&self, _: usize, cx: &App) -> SharedString {
+        let path = &self.item.read(cx).abs_path;
+        path.file_name()
+            .unwrap_or(path.as_os_str())
+            .to_string_lossy()
+            .to_string()
+            .into()
+    }
+
+    fn tab_tooltip_text(&self, cx: &App) -> Option<SharedString> {
+        Some(
+            self.item
+                .read(cx)
+                .abs_path
+                .to_string_lossy()
+                .to_string()
+                .into(),
+        )
+    }
+
+    fn tab_icon(&self, _window: &Window, _cx: &App) -> Option<Icon> {
+        Some(IconName::FileCode.into())
+    }
+
+    fn telemetry_event_text(&self) -> Option<&'static str> {
+        Some("Artifact Viewer Opened")
+    }
+
+    fn show_toolbar(&self) -> bool {
+        false
+    }
+}
+
+impl ProjectItem for ArtifactView {
+    type Item = ArtifactItem;
+
+    fn for_project_item(
+        project: Entity<Project>,
+        _: Option<&Pane>,
+        item: Entity<Self::Item>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        Self::new(project, item, window, cx)
+    }
+
+    fn for_broken_project_item(
+        abs_path: &Path,
+        is_local: bool,
+        e: &anyhow::Error,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Option<InvalidItemView> {
+        Some(InvalidItemView::new(abs_path, is_local, e, window, cx))
+    }
+}
+
+impl Render for ArtifactView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let artifact = self.item.read(cx).artifact.clone();
+        let (tokens, size) = artifact.template_asm();
+        let placeholders = tokens
+            .iter()
+            .filter(|token| matches!(token, TemplateToken::Placeholder(_)))
+            .count();
+        let size = if placeholders == 0 {
+            format!("{size} bytes")
+        } else {
+            format!("{size} bytes + {placeholders} placeholder(s)")
+        };
+
+        let facts = [
+            ("Size", Some(size)),
+            ("md5", artifact.md5.clone()),
+            ("Compiler", artifact.compiler_version.clone()),
+        ];
+        let methods = artifact.public_methods().map(|entry| {
+            let name = entry.name.clone().unwrap_or_default();
+            let index = entry
+                .index
+                .map(|index| format!("#{index}"))
+                .unwrap_or_default();
+            v_flex()
+                .gap_0p5()
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .child(Label::new(name).buffer_font(cx))
+                        .child(
+                            Label::new(index)
+                                .size(LabelSize::XSmall)
+                                .color(Color::Muted),
+                        ),
+                )
+                .child(div().pl_4().child(Self::render_params(&entry.params, cx)))
+        });
+
+        v_flex()
+            .id("artifact-view")
+            .key_context("ArtifactView")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .overflow_y_scroll()
+            .p_4()
+            .gap_3()
+            .bg(cx.theme().colors().editor_background)
+            .child(Headline::new(artifact.contract.clone()).size(HeadlineSize::Medium))
+            .children(
+                self.error
+                    .clone()
+                    .map(|error| Label::new(error).size(LabelSize::Small).color(Color::Error)),
+            )
+            .child(
+                v_flex()
+                    .gap_0p5()
+                    .children(facts.into_iter().filter_map(|(label, value)| {
+                        Some(
+                            h_flex()
+                                .gap_2()
+                                .child(div().w_20().child(
+                                    Label::new(label).size(LabelSize::Small).color(Color::Muted),
+                                ))
+                                .child(Label::new(value?).size(LabelSize::Small).buffer_font(cx)),
+                        )
+                    })),
+            )
+            .child(self.render_section(
+                "Constructor",
+                Self::render_params(artifact.constructor_params(), cx),
+                cx,
+            ))
+            .child(self.render_section("Public Methods", v_flex().gap_2().children(methods), cx))
+            .when(!artifact.state_props.is_empty(), |this| {
+                this.child(self.render_section(
+                    "State Properties",
+                    Self::render_params(&artifact.state_props, cx),
+                    cx,
+                ))
+            })
+            .child(self.render_section("Script Template", self.render_template(&tokens, cx), cx))
+    }
+}
@@ -0,0 +1,699 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use gpui::{App, Entity, WeakEntity};
+use project::Project;
+use serde::{Deserialize, Serialize};
+use workspace::Workspace;
+
+/// Deployments and contract calls, written by the Deploy panel and the
+/// scaffold's `scripts/utils.ts`.
+pub const DEPLOYMENTS_PATH: &str = ".utxix/deployments.json";
+
+/// Extra addresses or script hashes to track, comma-separated.
+const WATCH_KEY: &str = "UTXIX_WATCH";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Network {
+    Testnet,
+    Mainnet,
+}
+
+impl Network {
+    fn from_setting(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "testnet" | "test" => Some(Network::Testnet),
+            "mainnet" | "main" => Some(Network::Mainnet),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Network::Testnet => "testnet",
+            Network::Mainnet => "mainnet",
+        }
+    }
+
+    fn default_api_url(&self) -> &'static str {
+        match self {
+            Network::Testnet => "https://api.whatsonchain.com/v1/bsv/test",
+            Network::Mainnet => "https://api.whatsonchain.com/v1/bsv/main",
+        }
+    }
+
+    fn default_explorer_url(&self) -> &'static str {
+        match self {
+            Network::Testnet => "https://test.whatsonchain.com",
+            Network::Mainnet => "https://whatsonchain.com",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeploymentKind {
+    #[default]
+    Deploy,
+    /// A broadcast spending the contract through one of its public methods.
+    Call,
+}
+
+/// One entry of `.utxix/deployments.json`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deployment {
+    #[serde(default)]
+    pub kind: DeploymentKind,
+    pub contract: String,
+    /// The method a call went through.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    pub txid: String,
+    #[serde(default)]
+    pub vout: u32,
+    pub network: String,
+    #[serde(default)]
+    pub deployer: Option<String>,
+    #[serde(default)]
+    pub timestamp: Option<String>,
+    /// SHA-256 of the artifact the script was built from, in hex.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_hash: Option<String>,
+    /// Constructor arguments of a deploy, as typed in the Deploy panel.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub satoshis: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WatchKind {
+    Address,
+    ScriptHash,
+}
+
+#[derive(Clone, Debug)]
+pub struct WatchTarget {
+    pub label: &'static str,
+    pub kind: WatchKind,
+    pub value: String,
+}
+
+/// A scaffolded project as seen from its `.env` and deployment history.
+#[derive(Clone, Debug)]
+pub struct BitcoinProject {
+    pub root: PathBuf,
+    pub network: Network,
+    pub api_url: String,
+    pub explorer_url: String,
+    /// Deployments on `network`; calls and entries for other networks are skipped.
+    pub deployments: Vec<Deployment>,
+    /// Deployments and contract calls on `network`, oldest first.
+    pub history: Vec<Deployment>,
+    env: BTreeMap<String, String>,
+}
+
+impl BitcoinProject {
+    /// Reads `.env` (falling back to `.env.testnet`) and the deployment
+    /// history. Returns `None` when the folder has neither.
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let env_path = [".env", ".env.testnet"]
+            .iter()
+            .map(|name| root.join(name))
+            .find(|path| path.is_file());
+        let history_path = root.join(DEPLOYMENTS_PATH);
+        if env_path.is_none() && !history_path.is_file() {
+            return Ok(None);
+        }
+
+        let env = match env_path {
+            Some(path) => parse_env(
+                &fs::read_to_string(&path)
+                    .with_context(|| format!("reading {}", path.display()))?,
+            ),
+            None => BTreeMap::new(),
+        };
+
+        // DEPLOY_NETWORK may be `regtest`, which has no explorer - fall back to the app network
+        let network = ["DEPLOY_NETWORK", "VITE_BSV_NETWORK"]
+            .iter()
+            .filter_map(|key| env.get(*key))
+            .find_map(|value| Network::from_setting(value))
+            .unwrap_or(Network::Testnet);
+        let setting = |key: &str, fallback: &str| {
+            env.get(key)
+                .filter(|value| !value.is_empty())
+                .map_or(fallback, String::as_str)
+                .trim_end_matches('/')
+                .to_string()
+        };
+        let api_url = setting("VITE_WOC_API_URL", network.default_api_url());
+        let explorer_url = setting("VITE_EXPLORER_URL", network.default_explorer_url());
+
+        let history: Vec<Deployment> = if history_path.is_file() {
+            let text = fs::read_to_string(&history_path)
+                .with_context(|| format!("reading {}", history_path.display()))?;
+            serde_json::from_str::<Vec<Deployment>>(&text)
+                .with_context(|| format!("parsing {DEPLOYMENTS_PATH}"))?
+                .into_iter()
+                .filter(|deployment| deployment.network == network.name())
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let deployments = history
+            .iter()
+            .filter(|deployment| deployment.kind == DeploymentKind::Deploy)
+            .cloned()
+            .collect();
+
+        Ok(Some(Self {
+            root: root.to_path_buf(),
+            network,
+            api_url,
+            explorer_url,
+            deployments,
+            history,
+            env,
+        }))
+    }
+
+    /// Deployer addresses from the history plus anything listed in `UTXIX_WATCH`.
+    pub fn watch_targets(&self) -> Vec<WatchTarget> {
+        let deployers = self
+            .deployments
+            .iter()
+            .filter_map(|deployment| deployment.deployer.clone())
+            .map(|address| WatchTarget {
+                label: "Deployer",
+                kind: WatchKind::Address,
+                value: address,
+            });
+        let watched = self
+            .env
+            .get(WATCH_KEY)
+            .into_iter()
+            .flat_map(|list| list.split(','))
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                if is_script_hash(item) {
+                    WatchTarget {
+                        label: "Script hash",
+                        kind: WatchKind::ScriptHash,
+                        value: item.to_ascii_lowercase(),
+                    }
+                } else {
+                    WatchTarget {
+                        label: "Watched",
+                        kind: WatchKind::Address,
+                        value: item.to_string(),
+                    }
+                }
+            });
+
+        let mut targets: Vec<WatchTarget> = Vec::new();
+        for target in deployers.chain(watched) {
+            if !targets
+                .iter()
+                .any(|existing| existing.value == target.value)
+            {
+                targets.push(target);
+            }
+        }
+        targets
+    }
+
+    /// Whether the contract carries state (its artifact lists `stateProps`).
+    /// Spends of a stateful contract continue the covenant at output 0.
+    pub fn is_stateful(&self, contract: &str) -> bool {
+        #[derive(Deserialize)]
+        struct Artifact {
+            #[serde(default, rename = "stateProps")]
+            state_props: Vec<serde_json::Value>,
+        }
+
+        let path = self
+            .root
+            .join("artifacts")
+            .join("contracts")
+            .join(format!("{contract}.scrypt.json"));
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<Artifact>(&text).ok())
+            .is_some_and(|artifact| !artifact.state_props.is_empty())
+    }
+
+    pub fn tx_url(&self, txid: &str) -> String {
+        format!("{}/tx/{}", self.explorer_url, txid)
+    }
+
+    /// A non-empty `.env` value.
+    pub fn setting(&self, key: &str) -> Option<&str> {
+        self.env
+            .get(key)
+            .map(String::as_str)
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Sets (or with `None`, removes) a `.env` setting in place, keeping the
+    /// rest of the file. A missing `.env` starts out as a copy of the
+    /// project network's `.env.<network>`, like the scaffold's
+    /// `npm run use:<network>`, so a mainnet project never picks up testnet
+    /// settings.
+    pub fn set_setting(&mut self, key: &str, value: Option<&str>) -> Result<()> {
+        let path = self.root.join(".env");
+        let text = if path.is_file() {
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?
+        } else {
+            fs::read_to_string(self.root.join(format!(".env.{}", self.network.name())))
+                .unwrap_or_default()
+        };
+
+        let mut lines = Vec::new();
+        let mut replaced = false;
+        for line in text.lines() {
+            let trimmed = line.trim_start();
+            let trimmed = trimmed.strip_prefix("export ").unwrap_or(trimmed);
+            let is_key = trimmed
+                .split_once('=')
+                .is_some_and(|(name, _)| name.trim() == key);
+            if !is_key {
+                lines.push(line.to_string());
+            } else if let Some(value) = value.filter(|_| !replaced) {
+                lines.push(format!("{key}={value}"));
+                replaced = true;
+            }
+        }
+        if let Some(value) = value.filter(|_| !replaced) {
+            lines.push(format!("{key}={value}"));
+        }
+        fs::write(&path, lines.join("\n") + "\n")
+            .with_context(|| format!("writing {}", path.display()))?;
+
+        match value {
+            Some(value) => self.env.insert(key.to_string(), value.to_string()),
+            None => self.env.remove(key),
+        };
+        Ok(())
+    }
+
+    /// Points `.env` at `network`, like the scaffold's `npm run use:<network>`:
+    /// the settings of `.env.<network>` replace those in `.env`, and anything
+    /// it doesn't set (the deploy key, personal overrides) is kept. Without
+    /// a `.env.<network>`, only the network settings change.
+    pub fn use_network(&mut self, network: Network) -> Result<()> {
+        let source = self.root.join(format!(".env.{}", network.name()));
+        if !source.is_file() {
+            self.set_setting("VITE_BSV_NETWORK", Some(network.name()))?;
+            self.set_setting("DEPLOY_NETWORK", None)?;
+        } else {
+            let settings = fs::read_to_string(&source)
+                .with_context(|| format!("reading {}", source.display()))?;
+            let defined = parse_env(&settings);
+            let path = self.root.join(".env");
+            let current = fs::read_to_string(&path).unwrap_or_default();
+            let kept: Vec<&str> = current
+                .lines()
+                .filter(|line| {
+                    let line = line.trim_start();
+                    let line = line.strip_prefix("export ").unwrap_or(line);
+                    line.split_once('=').is_some_and(|(key, _)| {
+                        !key.trim().starts_with('#') && !defined.contains_key(key.trim())
+                    })
+                })
+                .collect();
+
+            let mut env = settings;
+            if !env.ends_with('\n') {
+                env.push('\n');
+            }
+            if !kept.is_empty() {
+                env.push_str("\n# Kept from the previous .env\n");
+                env.push_str(&(kept.join("\n") + "\n"));
+            }
+            fs::write(&path, env).with_context(|| format!("writing {}", path.display()))?;
+        }
+
+        *self = Self::load(&self.root)?.context("the project's .env disappeared")?;
+        Ok(())
+    }
+
+    /// `VITE_FEE_PER_KB`, with the same defaults as the scaffold's `src/services/config.ts`.
+    pub fn fee_per_kb(&self) -> u64 {
+        self.setting("VITE_FEE_PER_KB")
+            .and_then(|value| value.parse().ok())
+            .filter(|fee| *fee > 0)
+            .unwrap_or(match self.network {
+                Network::Mainnet => 100,
+                Network::Testnet => 50,
+            })
+    }
+
+    /// Appends a deploy or contract call to the deployment history, keeping
+    /// entries for other networks. Only entries on the project's network
+    /// show up in `deployments` and `history`.
+    pub fn record_deployment(&mut self, deployment: Deployment) -> Result<()> {
+        let path = self.root.join(DEPLOYMENTS_PATH);
+        let mut history: Vec<serde_json::Value> = if path.is_file() {
+            let text =
+                fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+            serde_json::from_str(&text).with_context(|| format!("parsing {DEPLOYMENTS_PATH}"))?
+        } else {
+            Vec::new()
+        };
+        history.push(serde_json::to_value(&deployment)?);
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(&history)? + "\n")
+            .with_context(|| format!("writing {}", path.display()))?;
+        if deployment.network != self.network.name() {
+            return Ok(());
+        }
+        if deployment.kind == DeploymentKind::Deploy {
+            self.deployments.push(deployment.clone());
+        }
+        self.history.push(deployment);
+        Ok(())
+    }
+}
+
+/// The root of the project's first visible worktree, where a scaffolded
+/// project keeps its `.env`.
+pub(crate) fn project_root(project: &Entity<Project>, cx: &App) -> Option<PathBuf> {
+    project
+        .read(cx)
+        .visible_worktrees(cx)
+        .next()
+        .map(|worktree| worktree.read(cx).abs_path().to_path_buf())
+}
+
+/// [`project_root`] of the workspace's project, while the workspace is open.
+pub(crate) fn workspace_project_root(
+    workspace: &WeakEntity<Workspace>,
+    cx: &App,
+) -> Option<PathBuf> {
+    project_root(workspace.upgrade()?.read(cx).project(), cx)
+}
+
+fn is_script_hash(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Parses dotenv-style `KEY=value` lines, dropping comments and quotes.
+fn parse_env(text: &str) -> BTreeMap<String, String> {
+    text.lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            Some((key.trim().to_string(), unquote(value.trim()).to_string()))
+        })
+        .collect()
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote) {
+            return inner.split_once(quote).map_or(inner, |(inner, _)| inner);
+        }
+    }
+    if value.starts_with('#') {
+        return "";
+    }
+    value.split(" #").next().unwrap_or_default().trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deployment(kind: DeploymentKind, contract: &str, txid: &str, network: &str) -> Deployment {
+        Deployment {
+            kind,
+            contract: contract.to_string(),
+            method: None,
+            txid: txid.to_string(),
+            vout: 0,
+            network: network.to_string(),
+            deployer: None,
+            timestamp: None,
+            artifact_hash: None,
+            args: Vec::new(),
+            satoshis: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_env() {
+        let env = parse_env(
+            "# comment\n\
+             \n\
+             PLAIN=value\n\
+             export EXPORTED = spaced \n\
+             DOUBLE=\"quoted # not a comment\"\n\
+             SINGLE='single'\n\
+             INLINE=value # comment\n\
+             EMPTY=\n\
+             COMMENT_ONLY=# nothing\n\
+             not a setting\n",
+        );
+        assert_eq!(env["PLAIN"], "value");
+        assert_eq!(env["EXPORTED"], "spaced");
+        assert_eq!(env["DOUBLE"], "quoted # not a comment");
+        assert_eq!(env["SINGLE"], "single");
+        assert_eq!(env["INLINE"], "value");
+        assert_eq!(env["EMPTY"], "");
+        assert_eq!(env["COMMENT_ONLY"], "");
+        assert_eq!(env.len(), 7);
+    }
+
+    #[test]
+    fn test_load() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(BitcoinProject::load(root.path()).unwrap().is_none());
+
+        // `.env.testnet` stands in for a missing `.env`
+        fs::write(
+            root.path().join(".env.testnet"),
+            "VITE_BSV_NETWORK=testnet\n",
+        )
+        .unwrap();
+        let project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        assert_eq!(project.network, Network::Testnet);
+        assert_eq!(project.api_url, Network::Testnet.default_api_url());
+
+        fs::write(
+            root.path().join(".env"),
+            "DEPLOY_NETWORK=regtest\n\
+             VITE_BSV_NETWORK=mainnet\n\
+             VITE_WOC_API_URL=https://indexer.example.com/v1/bsv/main/\n\
+             VITE_EXPLORER_URL=\n",
+        )
+        .unwrap();
+        fs::create_dir(root.path().join(".utxix")).unwrap();
+        let history = [
+            deployment(DeploymentKind::Deploy, "Counter", "aa", "mainnet"),
+            deployment(DeploymentKind::Call, "Counter", "bb", "mainnet"),
+            deployment(DeploymentKind::Deploy, "Counter", "cc", "testnet"),
+        ];
+        fs::write(
+            root.path().join(DEPLOYMENTS_PATH),
+            serde_json::to_string(&history).unwrap(),
+        )
+        .unwrap();
+
+        let project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        assert_eq!(project.network, Network::Mainnet);
+        assert_eq!(project.api_url, "https://indexer.example.com/v1/bsv/main");
+        assert_eq!(
+            project.explorer_url,
+            Network::Mainnet.default_explorer_url()
+        );
+        let txids = |deployments: &[Deployment]| {
+            deployments
+                .iter()
+                .map(|deployment| deployment.txid.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(txids(&project.history), ["aa", "bb"]);
+        assert_eq!(txids(&project.deployments), ["aa"]);
+    }
+
+    #[test]
+    fn test_set_setting() {
+        let root = tempfile::tempdir().unwrap();
+        let env_path = root.path().join(".env");
+        fs::write(
+            &env_path,
+            "# Deploy settings\nexport DEPLOY_KEY=old\nVITE_BSV_NETWORK=testnet\nDEPLOY_KEY=duplicate\n",
+        )
+        .unwrap();
+        let mut project = BitcoinProject::load(root.path()).unwrap().unwrap();
+
+        project.set_setting("DEPLOY_KEY", Some("new")).unwrap();
+        project
+            .set_setting("UTXIX_WALLET", Some("address"))
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "# Deploy settings\nDEPLOY_KEY=new\nVITE_BSV_NETWORK=testnet\nUTXIX_WALLET=address\n"
+        );
+        assert_eq!(project.setting("DEPLOY_KEY"), Some("new"));
+        assert_eq!(project.setting("UTXIX_WALLET"), Some("address"));
+
+        project.set_setting("DEPLOY_KEY", None).unwrap();
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "# Deploy settings\nVITE_BSV_NETWORK=testnet\nUTXIX_WALLET=address\n"
+        );
+        assert_eq!(project.setting("DEPLOY_KEY"), None);
+        assert_eq!(
+            BitcoinProject::load(root.path())
+                .unwrap()
+                .unwrap()
+                .setting("UTXIX_WALLET"),
+            Some("address")
+        );
+    }
+
+    #[test]
+    fn test_set_setting_seeds_env_for_network() {
+        let root = tempfile::tempdir().unwrap();
+        let env_path = root.path().join(".env");
+        fs::write(
+            root.path().join(".env.testnet"),
+            "VITE_BSV_NETWORK=testnet\nVITE_FEE_PER_KB=1\n",
+        )
+        .unwrap();
+        fs::write(&env_path, "VITE_BSV_NETWORK=mainnet\n").unwrap();
+        let mut project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        assert_eq!(project.network, Network::Mainnet);
+
+        // A mainnet project without a `.env` or `.env.mainnet` doesn't take
+        // the testnet settings
+        fs::remove_file(&env_path).unwrap();
+        project
+            .set_setting("UTXIX_WALLET", Some("address"))
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "UTXIX_WALLET=address\n"
+        );
+
+        fs::remove_file(&env_path).unwrap();
+        fs::write(
+            root.path().join(".env.mainnet"),
+            "VITE_BSV_NETWORK=mainnet\n",
+        )
+        .unwrap();
+        project
+            .set_setting("UTXIX_WALLET", Some("address"))
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "VITE_BSV_NETWORK=mainnet\nUTXIX_WALLET=address\n"
+        );
+
+        // A testnet project starts from `.env.testnet`
+        fs::remove_file(&env_path).unwrap();
+        let mut project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        assert_eq!(project.network, Network::Testnet);
+        project.set_setting("VITE_FEE_PER_KB", Some("2")).unwrap();
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "VITE_BSV_NETWORK=testnet\nVITE_FEE_PER_KB=2\n"
+        );
+    }
+
+    #[test]
+    fn test_use_network() {
+        let root = tempfile::tempdir().unwrap();
+        let env_path = root.path().join(".env");
+        fs::write(
+            &env_path,
+            "VITE_BSV_NETWORK=testnet\nDEPLOY_NETWORK=testnet\nDEPLOY_PRIVATE_KEY=secret\n",
+        )
+        .unwrap();
+        let mut project = BitcoinProject::load(root.path()).unwrap().unwrap();
+
+        // Without `.env.mainnet` only the network changes
+        project.use_network(Network::Mainnet).unwrap();
+        assert_eq!(project.network, Network::Mainnet);
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "VITE_BSV_NETWORK=mainnet\nDEPLOY_PRIVATE_KEY=secret\n"
+        );
+
+        fs::write(
+            root.path().join(".env.testnet"),
+            "# Testnet\nVITE_BSV_NETWORK=testnet\nVITE_FEE_PER_KB=50\n",
+        )
+        .unwrap();
+        project.use_network(Network::Testnet).unwrap();
+        assert_eq!(project.network, Network::Testnet);
+        assert_eq!(project.fee_per_kb(), 50);
+        assert_eq!(project.setting("DEPLOY_PRIVATE_KEY"), Some("secret"));
+        assert_eq!(
+            fs::read_to_string(&env_path).unwrap(),
+            "# Testnet\nVITE_BSV_NETWORK=testnet\nVITE_FEE_PER_KB=50\n\n\
+             # Kept from the previous .env\nDEPLOY_PRIVATE_KEY=secret\n"
+        );
+    }
+
+    #[test]
+    fn test_record_deployment() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join(".env"), "VITE_BSV_NETWORK=testnet\n").unwrap();
+        let mut project = BitcoinProject::load(root.path()).unwrap().unwrap();
+
+        project
+            .record_deployment(deployment(
+                DeploymentKind::Deploy,
+                "Counter",
+                "aa",
+                "testnet",
+            ))
+            .unwrap();
+        project
+            .record_deployment(Deployment {
+                method: Some("increment".to_string()),
+                ..deployment(DeploymentKind::Call, "Counter", "bb", "testnet")
+            })
+            .unwrap();
+        assert_eq!(project.deployments.len(), 1);
+        assert_eq!(project.history.len(), 2);
+
+        // Entries for another network are kept in the file but not listed
+        project
+            .record_deployment(deployment(
+                DeploymentKind::Deploy,
+                "Counter",
+                "cc",
+                "mainnet",
+            ))
+            .unwrap();
+        assert_eq!(project.deployments.len(), 1);
+        assert_eq!(project.history.len(), 2);
+        let recorded: Vec<Deployment> =
+            serde_json::from_str(&fs::read_to_string(root.path().join(DEPLOYMENTS_PATH)).unwrap())
+                .unwrap();
+        assert_eq!(recorded.len(), 3);
+        assert_eq!(recorded[1].method.as_deref(), Some("increment"));
+
+        let project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        assert_eq!(project.history.len(), 2);
+        assert_eq!(project.deployments.len(), 1);
+        assert_eq!(project.history[1].kind, DeploymentKind::Call);
+    }
+}
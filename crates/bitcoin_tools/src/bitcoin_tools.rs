@@ -0,0 +1,134 @@
+mod artifact;
+mod artifact_viewer;
+mod bitcoin_project;
+mod broadcast;
+mod broadcast_command;
+mod chain_links;
+mod chain_provider;
+mod code_actions;
+mod compare;
+mod compare_command;
+mod contract_history;
+mod contract_source;
+mod covenant;
+mod covenant_command;
+mod covenant_timeline;
+mod deploy;
+mod deploy_panel;
+mod deployment_history;
+mod explorer_client;
+mod history_command;
+mod inscription;
+mod inscription_inspector;
+mod keychain;
+mod keys_command;
+mod lint_diagnostics;
+mod locktime;
+mod locktime_command;
+mod method_lens;
+mod network_indicator;
+mod party_keys;
+mod proof_command;
+mod quick_fixes;
+mod regtest;
+mod regtest_actions;
+mod script_debugger;
+mod scrypt_version;
+mod security_lint;
+mod signature_check;
+mod signer;
+mod simulate;
+mod simulate_command;
+mod size_check;
+mod size_history;
+mod spv;
+mod task_list;
+mod tasks_panel;
+mod utxo_panel;
+mod verify;
+mod verify_command;
+mod version_check;
+mod wallet_panel;
+mod whatsonchain;
+
+use assistant_slash_command::SlashCommandRegistry;
+use editor::Editor;
+use gpui::App;
+use workspace::Workspace;
+
+pub use artifact::{AbiEntry, Artifact, Param};
+pub use artifact_viewer::ArtifactView;
+pub use bitcoin_project::{BitcoinProject, Deployment, Network};
+pub use covenant_timeline::CovenantTimelinePanel;
+pub use deploy_panel::DeployPanel;
+pub use deployment_history::DeploymentHistoryPanel;
+pub use inscription_inspector::InscriptionInspector;
+pub use network_indicator::NetworkIndicator;
+pub use script_debugger::ScriptDebuggerPanel;
+pub use tasks_panel::TasksPanel;
+pub use utxo_panel::UtxoPanel;
+pub use wallet_panel::WalletPanel;
+
+/// Register the actions of the Bitcoin tool and task panels, the artifact
+/// viewer and inscription inspector, the explorer links for txids and
+/// addresses, the script sizes, quick fixes and security warnings shown in
+/// contract sources, the checks that run as the project's artifacts and
+/// packages change, the local regtest node, and the assistant's `/covenant`,
+/// `/compare`, `/simulate`, `/keys`, `/history`, `/verify`, `/locktime`,
+/// `/proof` and `/broadcast`.
+pub fn init(cx: &mut App) {
+    let slash_commands = SlashCommandRegistry::global(cx);
+    slash_commands.register_command(covenant_command::CovenantSlashCommand, true);
+    slash_commands.register_command(compare_command::CompareSlashCommand, true);
+    slash_commands.register_command(simulate_command::SimulateSlashCommand, true);
+    slash_commands.register_command(keys_command::KeysSlashCommand, true);
+    slash_commands.register_command(history_command::HistorySlashCommand, true);
+    slash_commands.register_command(verify_command::VerifySlashCommand, true);
+    slash_commands.register_command(locktime_command::LocktimeSlashCommand, true);
+    slash_commands.register_command(proof_command::ProofSlashCommand, true);
+    slash_commands.register_command(broadcast_command::BroadcastSlashCommand, true);
+    workspace::register_project_item::<ArtifactView>(cx);
+    editor::set_link_resolver(chain_links::ChainLinks, cx);
+    cx.observe_new(|editor: &mut Editor, window, cx| {
+        method_lens::register_editor(editor, cx);
+        code_actions::register_editor(editor, window, cx);
+        lint_diagnostics::register_editor(editor, cx);
+    })
+    .detach();
+    cx.observe_new(|workspace: &mut Workspace, _window, cx| {
+        chain_links::register_workspace(workspace, cx);
+        version_check::register_workspace(workspace, cx);
+        size_check::register_workspace(workspace, cx);
+        regtest_actions::register_workspace(workspace);
+        workspace
+            .register_action(|workspace, _: &utxo_panel::ToggleFocus, window, cx| {
+                workspace.toggle_panel_focus::<UtxoPanel>(window, cx);
+            })
+            .register_action(|workspace, _: &script_debugger::ToggleFocus, window, cx| {
+                workspace.toggle_panel_focus::<ScriptDebuggerPanel>(window, cx);
+            })
+            .register_action(|workspace, _: &deploy_panel::ToggleFocus, window, cx| {
+                workspace.toggle_panel_focus::<DeployPanel>(window, cx);
+            })
+            .register_action(|workspace, _: &wallet_panel::ToggleFocus, window, cx| {
+                workspace.toggle_panel_focus::<WalletPanel>(window, cx);
+            })
+            .register_action(
+                |workspace, _: &deployment_history::ToggleFocus, window, cx| {
+                    workspace.toggle_panel_focus::<DeploymentHistoryPanel>(window, cx);
+                },
+            )
+            .register_action(
+                |workspace, _: &covenant_timeline::ToggleFocus, window, cx| {
+                    workspace.toggle_panel_focus::<CovenantTimelinePanel>(window, cx);
+                },
+            )
+            .register_action(|workspace, _: &tasks_panel::ToggleFocus, window, cx| {
+                workspace.toggle_panel_focus::<TasksPanel>(window, cx);
+            })
+            .register_action(|workspace, _: &inscription_inspector::Open, window, cx| {
+                InscriptionInspector::open(workspace, window, cx);
+            });
+    })
+    .detach();
+}
@@ -0,0 +1,482 @@
+//! Broadcasting with fallback: WhatsOnChain first, then the TAAL and
+//! GorillaPool ARC endpoints. Transient failures are retried with backoff and
+//! every provider's errors are mapped onto the same [`Rejection`]s.
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use http_client::{HttpClient, StatusCode};
+use serde::Deserialize;
+
+use crate::{
+    bitcoin_project::{BitcoinProject, Network},
+    explorer_client::ExplorerClient,
+};
+
+/// Optional `.env` key with a TAAL ARC API key.
+pub const ARC_API_KEY: &str = "ARC_API_KEY";
+
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Provider {
+    WhatsOnChain,
+    Taal,
+    GorillaPool,
+    /// A WhatsOnChain-compatible indexer of the user's own.
+    Custom,
+}
+
+impl Provider {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Provider::WhatsOnChain => "WhatsOnChain",
+            Provider::Taal => "TAAL ARC",
+            Provider::GorillaPool => "GorillaPool ARC",
+            Provider::Custom => "custom indexer",
+        }
+    }
+
+    /// The ARC `/v1/tx` endpoint on `network`, for the ARC providers.
+    pub fn arc_url(&self, network: Network) -> Option<&'static str> {
+        match (self, network) {
+            (Provider::Taal, Network::Mainnet) => Some("https://arc.taal.com/v1/tx"),
+            (Provider::Taal, Network::Testnet) => Some("https://arc-test.taal.com/v1/tx"),
+            (Provider::GorillaPool, Network::Mainnet) => Some("https://arc.gorillapool.io/v1/tx"),
+            (Provider::GorillaPool, Network::Testnet) => {
+                Some("https://testnet.arc.gorillapool.io/v1/tx")
+            }
+            (Provider::WhatsOnChain | Provider::Custom, _) => None,
+        }
+    }
+}
+
+/// Why a provider didn't accept a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// An input is already spent by another transaction.
+    DoubleSpend,
+    /// An input is unknown to the provider, e.g. its parent hasn't propagated yet.
+    MissingInputs,
+    FeeTooLow,
+    /// Script or consensus failure; resubmitting won't help.
+    Invalid(String),
+    /// Network errors, rate limits and server errors.
+    Unavailable(String),
+}
+
+impl Rejection {
+    fn is_transient(&self) -> bool {
+        matches!(self, Rejection::Unavailable(_))
+    }
+
+    /// Whether another provider might still accept the transaction.
+    fn try_next_provider(&self) -> bool {
+        matches!(self, Rejection::Unavailable(_) | Rejection::MissingInputs)
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::DoubleSpend => write!(f, "an input is already spent (double spend)"),
+            Rejection::MissingInputs => write!(f, "inputs not found or already spent"),
+            Rejection::FeeTooLow => write!(f, "fee too low"),
+            Rejection::Invalid(message) => write!(f, "invalid transaction: {message}"),
+            Rejection::Unavailable(message) => write!(f, "unavailable: {message}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Broadcast {
+    pub txid: String,
+    pub provider: Provider,
+}
+
+/// Every provider's last answer, in the order they were tried.
+#[derive(Clone, Debug)]
+pub struct BroadcastError {
+    pub attempts: Vec<(Provider, Rejection)>,
+}
+
+impl fmt::Display for BroadcastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "broadcast failed")?;
+        for (provider, rejection) in &self.attempts {
+            write!(f, "; {}: {}", provider.name(), rejection)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BroadcastError {}
+
+struct Endpoint {
+    provider: Provider,
+    url: String,
+    api_key: Option<String>,
+}
+
+pub struct Broadcaster {
+    client: ExplorerClient,
+    endpoints: Vec<Endpoint>,
+    /// Wait before the first retry of a transient failure, doubling after.
+    retry_delay: Duration,
+}
+
+impl Broadcaster {
+    /// Providers for the project's network, using its WhatsOnChain URL and ARC
+    /// key, with `first` tried before the others.
+    pub fn for_project(
+        http: Arc<dyn HttpClient>,
+        project: &BitcoinProject,
+        first: Provider,
+    ) -> Self {
+        let arc_endpoint = |provider: Provider, api_key: Option<&str>| Endpoint {
+            provider,
+            url: provider
+                .arc_url(project.network)
+                .unwrap_or_default()
+                .to_string(),
+            api_key: api_key.map(str::to_string),
+        };
+        let mut endpoints = vec![
+            Endpoint {
+                provider: Provider::WhatsOnChain,
+                url: format!("{}/tx/raw", project.api_url),
+                api_key: None,
+            },
+            arc_endpoint(Provider::Taal, project.setting(ARC_API_KEY)),
+            arc_endpoint(Provider::GorillaPool, None),
+        ];
+        if let Some(ix) = endpoints
+            .iter()
+            .position(|endpoint| endpoint.provider == first)
+        {
+            let endpoint = endpoints.remove(ix);
+            endpoints.insert(0, endpoint);
+        }
+        Self {
+            client: ExplorerClient::new(http),
+            endpoints,
+            retry_delay: RETRY_DELAY,
+        }
+    }
+
+    /// Tries a WhatsOnChain-compatible indexer at `api_url` before the
+    /// public providers.
+    pub fn with_indexer(mut self, api_url: &str) -> Self {
+        self.endpoints.insert(
+            0,
+            Endpoint {
+                provider: Provider::Custom,
+                url: format!("{api_url}/tx/raw"),
+                api_key: None,
+            },
+        );
+        self
+    }
+
+    /// Submits `raw_tx` to each provider in turn until one accepts it.
+    pub async fn broadcast(&self, raw_tx: &[u8]) -> Result<Broadcast, BroadcastError> {
+        let raw_hex = hex::encode(raw_tx);
+        let mut attempts = Vec::new();
+        for endpoint in &self.endpoints {
+            let mut delay = self.retry_delay;
+            let mut attempt = 1;
+            let rejection = loop {
+                match self.submit(endpoint, &raw_hex).await {
+                    Ok(txid) => {
+                        return Ok(Broadcast {
+                            txid,
+                            provider: endpoint.provider,
+                        });
+                    }
+                    Err(rejection) if rejection.is_transient() && attempt < MAX_ATTEMPTS => {
+                        log::warn!(
+                            "{} broadcast attempt {attempt} failed: {rejection}",
+                            endpoint.provider.name()
+                        );
+                        smol::Timer::after(delay).await;
+                        delay *= 2;
+                        attempt += 1;
+                    }
+                    Err(rejection) => break rejection,
+                }
+            };
+            let next = rejection.try_next_provider();
+            attempts.push((endpoint.provider, rejection));
+            if !next {
+                break;
+            }
+        }
+        Err(BroadcastError { attempts })
+    }
+
+    async fn submit(&self, endpoint: &Endpoint, raw_hex: &str) -> Result<String, Rejection> {
+        let body = match endpoint.provider {
+            Provider::WhatsOnChain | Provider::Custom => serde_json::json!({ "txhex": raw_hex }),
+            Provider::Taal | Provider::GorillaPool => serde_json::json!({ "rawTx": raw_hex }),
+        };
+        let (status, body) = self
+            .client
+            .post_json(&endpoint.url, body.to_string(), endpoint.api_key.as_deref())
+            .await
+            .map_err(|err| Rejection::Unavailable(format!("{err:#}")))?;
+
+        match endpoint.provider {
+            Provider::WhatsOnChain | Provider::Custom => {
+                if status.is_success() {
+                    // The txid comes back as a JSON string
+                    return Ok(serde_json::from_slice::<String>(&body)
+                        .unwrap_or_else(|_| String::from_utf8_lossy(&body).trim().to_string()));
+                }
+                classify(
+                    status,
+                    String::from_utf8_lossy(&body).trim(),
+                    txid_of(raw_hex),
+                )
+            }
+            Provider::Taal | Provider::GorillaPool => {
+                let response = serde_json::from_slice::<ArcResponse>(&body).unwrap_or_default();
+                let accepted = status.is_success()
+                    && !matches!(
+                        response.tx_status.as_deref(),
+                        Some("REJECTED" | "DOUBLE_SPEND_ATTEMPTED" | "SEEN_IN_ORPHAN_MEMPOOL")
+                    );
+                if accepted {
+                    return Ok(response.txid.unwrap_or_else(|| txid_of(raw_hex)));
+                }
+                let message = [
+                    response.tx_status,
+                    response.title,
+                    response.detail,
+                    response.extra_info,
+                ]
+                .into_iter()
+                .flatten()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(": ");
+                let message = if message.is_empty() {
+                    String::from_utf8_lossy(&body).trim().to_string()
+                } else {
+                    message
+                };
+                classify(status, &message, txid_of(raw_hex))
+            }
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArcResponse {
+    txid: Option<String>,
+    tx_status: Option<String>,
+    title: Option<String>,
+    detail: Option<String>,
+    extra_info: Option<String>,
+}
+
+fn txid_of(raw_hex: &str) -> String {
+    let mut hash = bitcoin_script::hash::hash256(&hex::decode(raw_hex).unwrap_or_default());
+    hash.reverse();
+    hex::encode(hash)
+}
+
+/// Maps a provider's error onto a [`Rejection`]. A transaction the provider
+/// already has counts as accepted, so that case returns its txid.
+fn classify(status: StatusCode, message: &str, txid: String) -> Result<String, Rejection> {
+    let lower = message.to_ascii_lowercase();
+    let has = |needles: &[&str]| needles.iter().any(|needle| lower.contains(needle));
+
+    if has(&[
+        "txn-already-known",
+        "already known",
+        "already in the mempool",
+        "already_mined",
+    ]) {
+        Ok(txid)
+    } else if has(&[
+        "txn-mempool-conflict",
+        "double_spend",
+        "double spend",
+        "doublespend",
+    ]) {
+        Err(Rejection::DoubleSpend)
+    } else if has(&[
+        "missing inputs",
+        "missingorspent",
+        "missing-inputs",
+        "orphan",
+    ]) {
+        Err(Rejection::MissingInputs)
+    } else if has(&[
+        "insufficient priority",
+        "min relay fee",
+        "mempool min fee",
+        "fee too low",
+    ]) {
+        Err(Rejection::FeeTooLow)
+    } else if status == StatusCode::TOO_MANY_REQUESTS
+        || (status.is_server_error() && !has(&["mandatory-script-verify", "non-mandatory"]))
+    {
+        Err(Rejection::Unavailable(format!("{status}: {message}")))
+    } else {
+        Err(Rejection::Invalid(message.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use http_client::{AsyncBody, FakeHttpClient, Response};
+
+    use super::*;
+
+    const RAW_TX: &[u8] = &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    /// A broadcaster whose providers answer with `respond(host, attempt)`,
+    /// along with the hosts it sent to, in order.
+    fn fake_broadcaster(
+        respond: impl Fn(&str, usize) -> (u16, &'static str) + Send + Sync + 'static,
+    ) -> (Broadcaster, Arc<Mutex<Vec<String>>>) {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join(".env"),
+            "VITE_WOC_API_URL=https://woc.test/v1/bsv/test\n",
+        )
+        .unwrap();
+        let project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let http = FakeHttpClient::create({
+            let requests = requests.clone();
+            move |request| {
+                let host = request.uri().host().unwrap_or_default().to_string();
+                let mut requests = requests.lock().unwrap();
+                let attempt = requests.iter().filter(|sent| **sent == host).count();
+                let (status, body) = respond(&host, attempt);
+                requests.push(host);
+                async move {
+                    Ok(Response::builder()
+                        .status(status)
+                        .body(AsyncBody::from(body.to_string()))
+                        .unwrap())
+                }
+            }
+        });
+        let broadcaster = Broadcaster {
+            retry_delay: Duration::ZERO,
+            ..Broadcaster::for_project(http, &project, Provider::WhatsOnChain)
+        };
+        (broadcaster, requests)
+    }
+
+    #[test]
+    fn test_broadcast_retries_transient_failures() {
+        let (broadcaster, requests) = fake_broadcaster(|_, attempt| match attempt {
+            0 | 1 => (503, "upstream timeout"),
+            _ => (200, "\"ab\""),
+        });
+        let broadcast = smol::block_on(broadcaster.broadcast(RAW_TX)).unwrap();
+        assert_eq!(broadcast.provider, Provider::WhatsOnChain);
+        assert_eq!(broadcast.txid, "ab");
+        assert_eq!(*requests.lock().unwrap(), vec!["woc.test"; 3]);
+    }
+
+    #[test]
+    fn test_broadcast_falls_back_to_arc() {
+        let (broadcaster, requests) = fake_broadcaster(|host, _| match host {
+            "woc.test" => (503, "upstream timeout"),
+            _ => (200, r#"{"txid":"cd","txStatus":"SEEN_ON_NETWORK"}"#),
+        });
+        let broadcast = smol::block_on(broadcaster.broadcast(RAW_TX)).unwrap();
+        assert_eq!(broadcast.provider, Provider::Taal);
+        assert_eq!(broadcast.txid, "cd");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec!["woc.test", "woc.test", "woc.test", "arc-test.taal.com"]
+        );
+    }
+
+    #[test]
+    fn test_broadcast_maps_rejections() {
+        // A double spend won't go through anywhere else, so it isn't retried
+        let (broadcaster, requests) = fake_broadcaster(|_, _| (400, "258: txn-mempool-conflict"));
+        let err = smol::block_on(broadcaster.broadcast(RAW_TX)).unwrap_err();
+        assert_eq!(
+            err.attempts,
+            vec![(Provider::WhatsOnChain, Rejection::DoubleSpend)]
+        );
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        // Missing inputs may just not have reached the first provider
+        let (broadcaster, _) = fake_broadcaster(|host, _| match host {
+            "woc.test" => (400, "Missing inputs"),
+            _ => (
+                200,
+                r#"{"txStatus":"REJECTED","extraInfo":"bad-txns-inputs-missingorspent"}"#,
+            ),
+        });
+        let err = smol::block_on(broadcaster.broadcast(RAW_TX)).unwrap_err();
+        assert_eq!(
+            err.attempts,
+            vec![
+                (Provider::WhatsOnChain, Rejection::MissingInputs),
+                (Provider::Taal, Rejection::MissingInputs),
+                (Provider::GorillaPool, Rejection::MissingInputs),
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "broadcast failed; WhatsOnChain: inputs not found or already spent; \
+             TAAL ARC: inputs not found or already spent; \
+             GorillaPool ARC: inputs not found or already spent"
+        );
+
+        // A transaction the provider already has was broadcast before
+        let (broadcaster, _) = fake_broadcaster(|_, _| (400, "257: txn-already-known"));
+        let broadcast = smol::block_on(broadcaster.broadcast(RAW_TX)).unwrap();
+        assert_eq!(broadcast.txid, txid_of(&hex::encode(RAW_TX)));
+    }
+
+    #[test]
+    fn test_classify_provider_errors() {
+        let txid = || "ab".repeat(32);
+        assert_eq!(
+            classify(
+                StatusCode::BAD_REQUEST,
+                "unexpected response code 500: 257: txn-already-known",
+                txid()
+            ),
+            Ok(txid())
+        );
+        assert_eq!(
+            classify(StatusCode::BAD_REQUEST, "258: txn-mempool-conflict", txid()),
+            Err(Rejection::DoubleSpend)
+        );
+        assert_eq!(
+            classify(StatusCode::BAD_REQUEST, "Missing inputs", txid()),
+            Err(Rejection::MissingInputs)
+        );
+        assert_eq!(
+            classify(StatusCode::from_u16(465).unwrap(), "Fee too low", txid()),
+            Err(Rejection::FeeTooLow)
+        );
+        assert!(matches!(
+            classify(StatusCode::SERVICE_UNAVAILABLE, "upstream timeout", txid()),
+            Err(Rejection::Unavailable(_))
+        ));
+        assert!(matches!(
+            classify(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "16: mandatory-script-verify-flag-failed (Script evaluated without error but finished with a false/empty top stack element)",
+                txid()
+            ),
+            Err(Rejection::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,116 @@
+//! `/broadcast <raw transaction hex>`: sends a transaction through the
+//! project's chain provider, falling back to the other public providers.
+
+use std::sync::{Arc, atomic::AtomicBool};
+
+use anyhow::{Context as _, Result, anyhow};
+use assistant_slash_command::{
+    ArgumentCompletion, SlashCommand, SlashCommandOutput, SlashCommandOutputSection,
+    SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use settings::Settings as _;
+use ui::prelude::*;
+use workspace::Workspace;
+
+use crate::{
+    bitcoin_project::{BitcoinProject, workspace_project_root},
+    chain_provider::{self, ChainProviderSettings},
+};
+
+pub struct BroadcastSlashCommand;
+
+impl SlashCommand for BroadcastSlashCommand {
+    fn name(&self) -> String {
+        "broadcast".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("broadcast", &["<raw transaction hex>"], cx)
+    }
+
+    fn description(&self) -> String {
+        "Broadcast a raw transaction on the project's network".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::ArrowUpRight
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        _arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        _workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        Task::ready(Ok(Vec::new()))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        let Some(root) = workspace_project_root(&workspace, cx) else {
+            return Task::ready(Err(anyhow!("no project is open")));
+        };
+        // Pasted transactions may be wrapped across arguments
+        let raw_tx = match hex::decode(arguments.concat().trim()) {
+            Ok(raw_tx) if !raw_tx.is_empty() => raw_tx,
+            _ => {
+                return Task::ready(Err(anyhow!("usage: /broadcast <raw transaction hex>")));
+            }
+        };
+        let http = cx.http_client();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        cx.background_spawn(async move {
+            let project = BitcoinProject::load(&root)?
+                .context("no .env found - create one from .env.testnet")?;
+            let client = chain_provider::for_project(http, &project, &provider)?;
+            let broadcast = client.broadcast(&raw_tx).await?;
+            let text = format!(
+                "## Transaction Broadcast\n\n\
+                 **txid:** `{}`\n\
+                 **Network:** {}\n\
+                 **Accepted by:** {}\n\n\
+                 {}\n",
+                broadcast.txid,
+                project.network.name(),
+                broadcast.provider.name(),
+                project.tx_url(&broadcast.txid),
+            );
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::ArrowUpRight,
+                    label: format!("Broadcast ({})", project.network.name()).into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
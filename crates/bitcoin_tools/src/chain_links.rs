@@ -0,0 +1,277 @@
+//! Explorer links for transaction ids and addresses that appear in the files
+//! and terminals of a Bitcoin project, with their status on hover.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use bitcoin_script::keys::decode_address;
+use editor::LinkResolver;
+use gpui::{App, AppContext as _, Context, Entity, Global, Task};
+use project::Project;
+use settings::Settings as _;
+use util::ResultExt as _;
+use workspace::Workspace;
+
+use crate::{
+    bitcoin_project::{BitcoinProject, Network},
+    chain_provider::{self, ChainProvider, ChainProviderSettings},
+    network_indicator::ENV_FILES,
+};
+
+pub struct ChainLinks;
+
+/// The Bitcoin projects at the roots of open worktrees, reloaded in the
+/// background as their `.env` files change, so hovers never wait on the file
+/// system.
+#[derive(Default)]
+struct OpenProjects(HashMap<PathBuf, BitcoinProject>);
+
+impl Global for OpenProjects {}
+
+pub fn register_workspace(workspace: &mut Workspace, cx: &mut Context<Workspace>) {
+    let project = workspace.project().clone();
+    cx.subscribe(&project, |_, project, event, cx| {
+        let changed = match event {
+            project::Event::WorktreeAdded(_) => true,
+            project::Event::WorktreeUpdatedEntries(_, entries) => entries
+                .iter()
+                .any(|(path, _, _)| ENV_FILES.contains(&path.as_unix_str())),
+            _ => false,
+        };
+        if changed {
+            load_projects(&project, cx);
+        }
+    })
+    .detach();
+    load_projects(&project, cx);
+}
+
+fn load_projects(project: &Entity<Project>, cx: &mut App) {
+    let roots = project
+        .read(cx)
+        .visible_worktrees(cx)
+        .map(|worktree| worktree.read(cx).abs_path())
+        .collect::<Vec<_>>();
+    cx.spawn(async move |cx| {
+        let loaded = cx
+            .background_spawn(async move {
+                roots
+                    .into_iter()
+                    .map(|root| {
+                        let project = BitcoinProject::load(&root).log_err().flatten();
+                        (root.to_path_buf(), project)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .await;
+        cx.update(|cx| {
+            let open = &mut cx.default_global::<OpenProjects>().0;
+            for (root, project) in loaded {
+                match project {
+                    Some(project) => open.insert(root, project),
+                    None => open.remove(&root),
+                };
+            }
+        })
+        .ok();
+    })
+    .detach();
+}
+
+enum ChainRef {
+    Transaction(String),
+    Address { address: String, testnet: bool },
+}
+
+impl ChainRef {
+    /// Recognizes a txid (also as a `txid:vout` or `txid_vout` outpoint) or
+    /// an address.
+    fn parse(word: &str) -> Option<Self> {
+        let word = word.trim_matches(|ch: char| !ch.is_ascii_alphanumeric());
+        let txid = match word.split_once([':', '_']) {
+            Some((txid, vout))
+                if !vout.is_empty() && vout.bytes().all(|byte| byte.is_ascii_digit()) =>
+            {
+                txid
+            }
+            _ => word,
+        };
+        if txid.len() == 64 && txid.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Some(ChainRef::Transaction(txid.to_ascii_lowercase()));
+        }
+        let (_, testnet) = decode_address(word)?;
+        Some(ChainRef::Address {
+            address: word.to_string(),
+            testnet,
+        })
+    }
+
+    fn url(&self, project: &BitcoinProject) -> String {
+        match self {
+            ChainRef::Transaction(txid) => format!("{}/tx/{txid}", project.explorer_url),
+            ChainRef::Address { address, .. } => {
+                format!("{}/address/{address}", project.explorer_url)
+            }
+        }
+    }
+
+    /// `None` when the explorer doesn't know the transaction, which is what
+    /// most 64-digit hex strings in source files are.
+    async fn describe(
+        &self,
+        client: &dyn ChainProvider,
+        network: Network,
+    ) -> Result<Option<String>> {
+        match self {
+            ChainRef::Transaction(txid) => {
+                let Some(status) = client.tx_status(txid).await? else {
+                    return Ok(None);
+                };
+                let status = match status.confirmations {
+                    0 => "Unconfirmed (in the mempool)".to_string(),
+                    1 => format!("Confirmed in block {} (1 confirmation)", status.blockheight),
+                    confirmations => format!(
+                        "Confirmed in block {} ({confirmations} confirmations)",
+                        status.blockheight
+                    ),
+                };
+                Ok(Some(format!("{} transaction\n{status}", network.name())))
+            }
+            ChainRef::Address { address, .. } => {
+                let balance = client.address_balance(address).await?;
+                let mut description = format!(
+                    "{} address\nBalance: {} sats",
+                    network.name(),
+                    balance.confirmed
+                );
+                if balance.unconfirmed != 0 {
+                    description.push_str(&format!(" ({:+} unconfirmed)", balance.unconfirmed));
+                }
+                Ok(Some(description))
+            }
+        }
+    }
+}
+
+/// The open project `dir` belongs to and what `word` refers to on its
+/// network. Addresses of the other network are left alone, as its explorer
+/// wouldn't know them.
+fn resolve(word: &str, dir: Option<&Path>, cx: &App) -> Option<(BitcoinProject, ChainRef)> {
+    let chain_ref = ChainRef::parse(word)?;
+    let projects = &cx.try_global::<OpenProjects>()?.0;
+    let project = dir?.ancestors().find_map(|dir| projects.get(dir))?;
+    if let ChainRef::Address { testnet, .. } = &chain_ref {
+        if *testnet != (project.network == Network::Testnet) {
+            return None;
+        }
+    }
+    Some((project.clone(), chain_ref))
+}
+
+impl LinkResolver for ChainLinks {
+    fn resolve_link(&self, word: &str, dir: Option<&Path>, cx: &App) -> Option<String> {
+        let (project, chain_ref) = resolve(word, dir, cx)?;
+        Some(chain_ref.url(&project))
+    }
+
+    fn describe_link(&self, word: &str, dir: Option<&Path>, cx: &mut App) -> Task<Option<String>> {
+        let Some((project, chain_ref)) = resolve(word, dir, cx) else {
+            return Task::ready(None);
+        };
+        let provider = ChainProviderSettings::get_global(cx);
+        let Some(client) =
+            chain_provider::for_project(cx.http_client(), &project, provider).log_err()
+        else {
+            return Task::ready(None);
+        };
+        cx.background_spawn(async move {
+            chain_ref
+                .describe(client.as_ref(), project.network)
+                .await
+                .log_err()
+                .flatten()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin_script::keys::p2pkh_address;
+    use gpui::TestAppContext;
+
+    use super::*;
+
+    const TXID: &str = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+
+    #[test]
+    fn test_txids_and_outpoints() {
+        for word in [
+            TXID.to_string(),
+            TXID.to_ascii_uppercase(),
+            format!("{TXID}_0"),
+            format!("\"{TXID}:12\","),
+        ] {
+            assert!(
+                matches!(ChainRef::parse(&word), Some(ChainRef::Transaction(txid)) if txid == TXID),
+                "{word}"
+            );
+        }
+        assert!(ChainRef::parse(&format!("{TXID}:x")).is_none());
+        assert!(ChainRef::parse(&TXID[1..]).is_none());
+    }
+
+    #[test]
+    fn test_addresses() {
+        let testnet = p2pkh_address(&[7; 20], true);
+        assert!(matches!(
+            ChainRef::parse(&testnet),
+            Some(ChainRef::Address { testnet: true, .. })
+        ));
+        let mainnet = p2pkh_address(&[7; 20], false);
+        assert!(matches!(
+            ChainRef::parse(&format!("({mainnet})")),
+            Some(ChainRef::Address { address, testnet: false }) if address == mainnet
+        ));
+        assert!(ChainRef::parse(&mainnet[..mainnet.len() - 1]).is_none());
+        assert!(ChainRef::parse("deadbeef").is_none());
+    }
+
+    #[gpui::test]
+    fn test_resolve_in_open_projects(cx: &mut TestAppContext) {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join(".env"), "VITE_BSV_NETWORK=testnet\n").unwrap();
+        let project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        let contracts = root.path().join("src/contracts");
+
+        cx.update(|cx| {
+            // Nothing resolves until the project is loaded
+            assert!(resolve(TXID, Some(&contracts), cx).is_none());
+
+            cx.default_global::<OpenProjects>()
+                .0
+                .insert(root.path().to_path_buf(), project);
+            let url = ChainLinks.resolve_link(TXID, Some(&contracts), cx).unwrap();
+            assert!(url.ends_with(&format!("/tx/{TXID}")), "{url}");
+            assert!(
+                ChainLinks
+                    .resolve_link(&p2pkh_address(&[7; 20], true), Some(root.path()), cx)
+                    .is_some()
+            );
+            // Addresses of the other network aren't linked
+            assert!(
+                ChainLinks
+                    .resolve_link(&p2pkh_address(&[7; 20], false), Some(root.path()), cx)
+                    .is_none()
+            );
+            assert!(
+                ChainLinks
+                    .resolve_link(TXID, root.path().parent(), cx)
+                    .is_none()
+            );
+            assert!(ChainLinks.resolve_link(TXID, None, cx).is_none());
+        });
+    }
+}
@@ -0,0 +1,354 @@
+//! Where the tools read chain data and broadcast, picked with the
+//! `chain_provider` setting or, per project, `CHAIN_PROVIDER` in `.env`:
+//!
+//! - `whatsonchain` (the default): WhatsOnChain for everything.
+//! - `gorillapool` / `taal`: broadcasts and transaction status through that
+//!   miner's ARC; address, script and block lookups still come from
+//!   WhatsOnChain, which ARC doesn't index.
+//! - `custom`: a WhatsOnChain-compatible indexer at `CHAIN_PROVIDER_URL`, or
+//!   the setting's URL for the project's network.
+//!
+//! Broadcasts fall back to the other public providers either way.
+
+use std::sync::Arc;
+
+use anyhow::{Context as _, Result, bail};
+use async_trait::async_trait;
+use http_client::HttpClient;
+use serde::Deserialize;
+use settings::{ChainProviderName, RegisterSetting, Settings};
+
+use crate::{
+    bitcoin_project::{BitcoinProject, Network},
+    broadcast::{ARC_API_KEY, Broadcast, BroadcastError, Broadcaster, Provider},
+    explorer_client::{ExplorerClient, Freshness},
+    whatsonchain::{
+        Balance, BlockHeaderInfo, HistoryEntry, Spend, TscProof, TxInput, TxOut, TxStatus, Unspent,
+        WhatsOnChain,
+    },
+};
+
+/// `.env` key picking the provider instead of the setting.
+pub const CHAIN_PROVIDER: &str = "CHAIN_PROVIDER";
+/// `.env` key with the base URL of a custom indexer, like
+/// `https://indexer.example.com/v1/bsv/main`, instead of the setting's.
+pub const CHAIN_PROVIDER_URL: &str = "CHAIN_PROVIDER_URL";
+
+#[async_trait]
+pub trait ChainProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Base URL of the API reads go to.
+    fn api_url(&self) -> &str;
+
+    async fn block_height(&self) -> Result<u64>;
+
+    async fn address_unspent(&self, address: &str) -> Result<Vec<Unspent>>;
+
+    async fn address_balance(&self, address: &str) -> Result<Balance>;
+
+    async fn address_history(&self, address: &str) -> Result<Vec<HistoryEntry>>;
+
+    async fn script_unspent(&self, script_hash: &str) -> Result<Vec<Unspent>>;
+
+    async fn script_history(&self, script_hash: &str) -> Result<Vec<HistoryEntry>>;
+
+    /// The transaction spending `txid:vout`, or `None` while it is unspent.
+    async fn spent_by(&self, txid: &str, vout: u32) -> Result<Option<Spend>>;
+
+    async fn tx_status(&self, txid: &str) -> Result<Option<TxStatus>>;
+
+    /// Every output of a transaction, in order.
+    async fn outputs(&self, txid: &str) -> Result<Option<Vec<TxOut>>>;
+
+    /// Every input of a transaction, in order.
+    async fn inputs(&self, txid: &str) -> Result<Option<Vec<TxInput>>>;
+
+    async fn output(&self, txid: &str, vout: u32) -> Result<Option<TxOut>> {
+        Ok(self
+            .outputs(txid)
+            .await?
+            .and_then(|outputs| outputs.into_iter().nth(vout as usize)))
+    }
+
+    async fn merkle_proof(&self, txid: &str) -> Result<Option<TscProof>>;
+
+    async fn block_header(&self, hash: &str) -> Result<Option<BlockHeaderInfo>>;
+
+    async fn broadcast(&self, raw_tx: &[u8]) -> Result<Broadcast, BroadcastError>;
+}
+
+/// The `chain_provider` setting, the default for projects whose `.env`
+/// doesn't pick a provider.
+#[derive(Clone, Debug, Default, RegisterSetting)]
+pub struct ChainProviderSettings {
+    pub provider: ChainProviderName,
+    pub testnet_url: Option<String>,
+    pub mainnet_url: Option<String>,
+}
+
+impl Settings for ChainProviderSettings {
+    fn from_settings(content: &settings::SettingsContent) -> Self {
+        let chain_provider = content.chain_provider.clone().unwrap();
+        Self {
+            provider: chain_provider.provider.unwrap(),
+            testnet_url: chain_provider.testnet_url,
+            mainnet_url: chain_provider.mainnet_url,
+        }
+    }
+}
+
+/// The provider for `project`: the one its `.env` picks, or else the one in
+/// `settings`.
+pub fn for_project(
+    http: Arc<dyn HttpClient>,
+    project: &BitcoinProject,
+    settings: &ChainProviderSettings,
+) -> Result<Arc<dyn ChainProvider>> {
+    let provider = match project.setting(CHAIN_PROVIDER) {
+        Some(name) => match name.trim().to_ascii_lowercase().as_str() {
+            "whatsonchain" | "woc" => ChainProviderName::WhatsOnChain,
+            "gorillapool" => ChainProviderName::GorillaPool,
+            "taal" => ChainProviderName::Taal,
+            "custom" => ChainProviderName::Custom,
+            other => bail!(
+                "{CHAIN_PROVIDER}={other} isn't a provider - use whatsonchain, gorillapool, taal or custom"
+            ),
+        },
+        None => settings.provider,
+    };
+    let whatsonchain = || Indexer {
+        name: Provider::WhatsOnChain.name(),
+        index: WhatsOnChain::new(http.clone(), project.api_url.clone()),
+        api_url: project.api_url.clone(),
+        broadcaster: Broadcaster::for_project(http.clone(), project, Provider::WhatsOnChain),
+    };
+    Ok(match provider {
+        ChainProviderName::WhatsOnChain => Arc::new(whatsonchain()),
+        ChainProviderName::GorillaPool | ChainProviderName::Taal => {
+            let provider = if provider == ChainProviderName::Taal {
+                Provider::Taal
+            } else {
+                Provider::GorillaPool
+            };
+            let api_key = match provider {
+                Provider::Taal => project.setting(ARC_API_KEY).map(str::to_string),
+                _ => None,
+            };
+            Arc::new(ArcProvider {
+                provider,
+                status_url: provider
+                    .arc_url(project.network)
+                    .unwrap_or_default()
+                    .to_string(),
+                api_key,
+                client: ExplorerClient::new(http.clone()),
+                broadcaster: Broadcaster::for_project(http.clone(), project, provider),
+                reads: whatsonchain(),
+            })
+        }
+        ChainProviderName::Custom => {
+            let setting_url = match project.network {
+                Network::Testnet => settings.testnet_url.as_deref(),
+                Network::Mainnet => settings.mainnet_url.as_deref(),
+            };
+            let url = project
+                .setting(CHAIN_PROVIDER_URL)
+                .or(setting_url.filter(|url| !url.trim().is_empty()))
+                .with_context(|| {
+                    format!(
+                        "the custom chain provider needs {CHAIN_PROVIDER_URL} in .env or chain_provider.{}_url in settings",
+                        project.network.name()
+                    )
+                })?
+                .trim()
+                .trim_end_matches('/')
+                .to_string();
+            Arc::new(Indexer {
+                name: Provider::Custom.name(),
+                index: WhatsOnChain::new(http.clone(), url.clone()),
+                broadcaster: Broadcaster::for_project(http, project, Provider::WhatsOnChain)
+                    .with_indexer(&url),
+                api_url: url,
+            })
+        }
+    })
+}
+/// WhatsOnChain, or an indexer with the same API.
+struct Indexer {
+    name: &'static str,
+    index: WhatsOnChain,
+    api_url: String,
+    broadcaster: Broadcaster,
+}
+
+#[async_trait]
+impl ChainProvider for Indexer {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    async fn block_height(&self) -> Result<u64> {
+        self.index.block_height().await
+    }
+
+    async fn address_unspent(&self, address: &str) -> Result<Vec<Unspent>> {
+        self.index.address_unspent(address).await
+    }
+
+    async fn address_balance(&self, address: &str) -> Result<Balance> {
+        self.index.address_balance(address).await
+    }
+
+    async fn address_history(&self, address: &str) -> Result<Vec<HistoryEntry>> {
+        self.index.address_history(address).await
+    }
+
+    async fn script_unspent(&self, script_hash: &str) -> Result<Vec<Unspent>> {
+        self.index.script_unspent(script_hash).await
+    }
+
+    async fn script_history(&self, script_hash: &str) -> Result<Vec<HistoryEntry>> {
+        self.index.script_history(script_hash).await
+    }
+
+    async fn spent_by(&self, txid: &str, vout: u32) -> Result<Option<Spend>> {
+        self.index.spent_by(txid, vout).await
+    }
+
+    async fn tx_status(&self, txid: &str) -> Result<Option<TxStatus>> {
+        self.index.tx_status(txid).await
+    }
+
+    async fn outputs(&self, txid: &str) -> Result<Option<Vec<TxOut>>> {
+        self.index.outputs(txid).await
+    }
+
+    async fn inputs(&self, txid: &str) -> Result<Option<Vec<TxInput>>> {
+        self.index.inputs(txid).await
+    }
+
+    async fn merkle_proof(&self, txid: &str) -> Result<Option<TscProof>> {
+        self.index.merkle_proof(txid).await
+    }
+
+    async fn block_header(&self, hash: &str) -> Result<Option<BlockHeaderInfo>> {
+        self.index.block_header(hash).await
+    }
+
+    async fn broadcast(&self, raw_tx: &[u8]) -> Result<Broadcast, BroadcastError> {
+        self.broadcaster.broadcast(raw_tx).await
+    }
+}
+
+/// A miner's ARC, which knows the transactions it was sent as soon as they
+/// are, ahead of any indexer.
+struct ArcProvider {
+    provider: Provider,
+    /// The ARC `/v1/tx` endpoint; a transaction's status is under it.
+    status_url: String,
+    api_key: Option<String>,
+    client: ExplorerClient,
+    broadcaster: Broadcaster,
+    reads: Indexer,
+}
+
+#[async_trait]
+impl ChainProvider for ArcProvider {
+    fn name(&self) -> &'static str {
+        self.provider.name()
+    }
+
+    fn api_url(&self) -> &str {
+        self.reads.api_url()
+    }
+
+    async fn block_height(&self) -> Result<u64> {
+        self.reads.block_height().await
+    }
+
+    async fn address_unspent(&self, address: &str) -> Result<Vec<Unspent>> {
+        self.reads.address_unspent(address).await
+    }
+
+    async fn address_balance(&self, address: &str) -> Result<Balance> {
+        self.reads.address_balance(address).await
+    }
+
+    async fn address_history(&self, address: &str) -> Result<Vec<HistoryEntry>> {
+        self.reads.address_history(address).await
+    }
+
+    async fn script_unspent(&self, script_hash: &str) -> Result<Vec<Unspent>> {
+        self.reads.script_unspent(script_hash).await
+    }
+
+    async fn script_history(&self, script_hash: &str) -> Result<Vec<HistoryEntry>> {
+        self.reads.script_history(script_hash).await
+    }
+
+    async fn spent_by(&self, txid: &str, vout: u32) -> Result<Option<Spend>> {
+        self.reads.spent_by(txid, vout).await
+    }
+
+    /// From ARC when it has the transaction, otherwise from the indexer.
+    async fn tx_status(&self, txid: &str) -> Result<Option<TxStatus>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ArcStatus {
+            #[serde(default)]
+            block_height: u64,
+            #[serde(default)]
+            tx_status: String,
+        }
+
+        let url = format!("{}/{txid}", self.status_url);
+        let body = self
+            .client
+            .get_authorized(&url, Freshness::Live, self.api_key.as_deref())
+            .await
+            .map_err(|err| log::warn!("{}: {err:#}", self.provider.name()))
+            .ok()
+            .flatten();
+        let status = body.and_then(|body| serde_json::from_slice::<ArcStatus>(&body).ok());
+        match status {
+            Some(status) if status.tx_status == "MINED" && status.block_height > 0 => {
+                let tip = self.reads.block_height().await?;
+                Ok(Some(TxStatus {
+                    confirmations: tip.saturating_sub(status.block_height) + 1,
+                    blockheight: status.block_height,
+                }))
+            }
+            Some(status) if status.tx_status.contains("REJECTED") => Ok(None),
+            Some(_) => Ok(Some(TxStatus {
+                confirmations: 0,
+                blockheight: 0,
+            })),
+            None => self.reads.tx_status(txid).await,
+        }
+    }
+
+    async fn outputs(&self, txid: &str) -> Result<Option<Vec<TxOut>>> {
+        self.reads.outputs(txid).await
+    }
+
+    async fn inputs(&self, txid: &str) -> Result<Option<Vec<TxInput>>> {
+        self.reads.inputs(txid).await
+    }
+
+    async fn merkle_proof(&self, txid: &str) -> Result<Option<TscProof>> {
+        self.reads.merkle_proof(txid).await
+    }
+
+    async fn block_header(&self, hash: &str) -> Result<Option<BlockHeaderInfo>> {
+        self.reads.block_header(hash).await
+    }
+
+    async fn broadcast(&self, raw_tx: &[u8]) -> Result<Broadcast, BroadcastError> {
+        self.broadcaster.broadcast(raw_tx).await
+    }
+}
@@ -0,0 +1,113 @@
+//! Offers the fixes in `quick_fixes` as code actions, in contract sources and
+//! in the scripts and frontend code that use the contracts.
+
+use std::{ops::Range, path::Path, rc::Rc, sync::Arc};
+
+use anyhow::{Context as _, Result};
+use editor::{CodeActionProvider, Editor, ExcerptId};
+use gpui::{App, Context, Entity, Task, Window};
+use language::{Anchor, Buffer, LanguageServerId, OffsetRangeExt as _};
+use project::{CodeAction, LspAction, ProjectTransaction};
+
+use crate::{
+    contract_source::is_contract_file,
+    quick_fixes::{QuickFix, contract_fixes, is_script_file, script_fixes},
+};
+
+const PROVIDER_ID: &str = "bitcoin_quick_fixes";
+
+pub fn register_editor(editor: &mut Editor, window: Option<&mut Window>, cx: &mut Context<Editor>) {
+    let Some(window) = window else {
+        return;
+    };
+    if editor.mode().is_full() {
+        editor.add_code_action_provider(Rc::new(QuickFixProvider), window, cx);
+    }
+}
+
+struct QuickFixProvider;
+
+fn fixes_for(buffer: &Buffer, range: Range<Anchor>, cx: &App) -> Vec<QuickFix> {
+    let Some(file) = buffer.file() else {
+        return Vec::new();
+    };
+    let path = Path::new(file.file_name(cx));
+    let snapshot = buffer.snapshot();
+    let range = range.to_offset(&snapshot);
+    if is_contract_file(path) {
+        contract_fixes(&snapshot.text(), range)
+    } else if is_script_file(path) {
+        script_fixes(&snapshot.text(), range)
+    } else {
+        Vec::new()
+    }
+}
+
+impl CodeActionProvider for QuickFixProvider {
+    fn id(&self) -> Arc<str> {
+        PROVIDER_ID.into()
+    }
+
+    fn code_actions(
+        &self,
+        buffer: &Entity<Buffer>,
+        range: Range<Anchor>,
+        _window: &mut Window,
+        cx: &mut App,
+    ) -> Task<Result<Vec<CodeAction>>> {
+        let actions = fixes_for(buffer.read(cx), range.clone(), cx)
+            .into_iter()
+            .map(|fix| CodeAction {
+                server_id: LanguageServerId(0),
+                range: range.clone(),
+                lsp_action: LspAction::Action(Box::new(lsp::CodeAction {
+                    title: fix.title,
+                    kind: Some(lsp::CodeActionKind::QUICKFIX),
+                    ..Default::default()
+                })),
+                resolved: true,
+            })
+            .collect();
+        Task::ready(Ok(actions))
+    }
+
+    fn apply_code_action(
+        &self,
+        buffer: Entity<Buffer>,
+        action: CodeAction,
+        _excerpt_id: ExcerptId,
+        push_to_history: bool,
+        _window: &mut Window,
+        cx: &mut App,
+    ) -> Task<Result<ProjectTransaction>> {
+        // Work the fix out again, in case the buffer changed since it was offered
+        let title = action.lsp_action.title();
+        let fix = fixes_for(buffer.read(cx), action.range.clone(), cx)
+            .into_iter()
+            .find(|fix| fix.title == title)
+            .with_context(|| format!("`{title}` no longer applies"));
+        let fix = match fix {
+            Ok(fix) => fix,
+            Err(err) => return Task::ready(Err(err)),
+        };
+
+        let transaction = buffer.update(cx, |buffer, cx| {
+            buffer.finalize_last_transaction();
+            buffer.start_transaction();
+            buffer.edit(fix.edits, None, cx);
+            buffer.end_transaction(cx).and_then(|transaction_id| {
+                if push_to_history {
+                    buffer.finalize_last_transaction();
+                    buffer.get_transaction(transaction_id).cloned()
+                } else {
+                    buffer.forget_transaction(transaction_id)
+                }
+            })
+        });
+        let mut project_transaction = ProjectTransaction::default();
+        if let Some(transaction) = transaction {
+            project_transaction.0.insert(buffer, transaction);
+        }
+        Task::ready(Ok(project_transaction))
+    }
+}
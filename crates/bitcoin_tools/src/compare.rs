@@ -0,0 +1,361 @@
+//! Script-level differences between two builds of a contract: what changed in
+//! its ABI, its size, and the opcodes each public method runs.
+
+use std::fmt::Write as _;
+
+use crate::artifact::{Artifact, Param, ScriptSize, TemplateToken};
+
+/// Unchanged instructions shown around each change.
+const CONTEXT: usize = 3;
+/// Diff lines shown per method before the rest is cut off.
+const MAX_DIFF_LINES: usize = 200;
+/// Past this many cells the middle of a diff is shown as replaced outright
+/// rather than aligned.
+const MAX_ALIGN_CELLS: usize = 4_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// A markdown report of how `new` differs from `old`, each named by `label`.
+pub fn report(old: (&str, &Artifact), new: (&str, &Artifact)) -> String {
+    let (old_label, old) = old;
+    let (new_label, new) = new;
+    let mut text = if old.contract == new.contract {
+        format!("## {}: {old_label} → {new_label}\n", new.contract)
+    } else {
+        format!(
+            "## {} ({old_label}) → {} ({new_label})\n",
+            old.contract, new.contract
+        )
+    };
+
+    text.push_str("\n### ABI\n\n");
+    let abi = abi_changes(old, new);
+    if abi.is_empty() {
+        text.push_str("No changes.\n");
+    }
+    for change in abi {
+        writeln!(text, "- {change}").ok();
+    }
+
+    let old_methods = old.method_sizes().unwrap_or_default();
+    let new_methods = new.method_sizes().unwrap_or_default();
+    writeln!(
+        text,
+        "\n### Size\n\n| | {old_label} | {new_label} | Change |\n|---|---:|---:|---:|"
+    )
+    .ok();
+    size_rows(&mut text, "Script", old.script_size(), new.script_size());
+    for (name, new_size) in &new_methods {
+        if let Some((_, old_size)) = old_methods.iter().find(|(old_name, _)| old_name == name) {
+            size_rows(&mut text, &format!("`{name}`"), *old_size, *new_size);
+        }
+    }
+    if old.script_size().has_placeholders || new.script_size().has_placeholders {
+        text.push_str(
+            "\nConstructor arguments aren't counted, as they're only known at deploy time.\n",
+        );
+    }
+
+    text.push_str("\n### Opcodes\n");
+    match (old.method_asm(), new.method_asm()) {
+        (Some(old_asm), Some(new_asm)) => {
+            let mut unchanged = Vec::new();
+            for (name, new_ops) in &new_asm {
+                let Some((_, old_ops)) = old_asm.iter().find(|(old_name, _)| old_name == name)
+                else {
+                    continue;
+                };
+                if old_ops == new_ops {
+                    unchanged.push(format!("`{name}`"));
+                } else {
+                    writeln!(text, "\n#### `{name}`\n").ok();
+                    push_diff(&mut text, old_ops, new_ops, "op");
+                }
+            }
+            if !unchanged.is_empty() {
+                writeln!(text, "\nUnchanged: {}.", unchanged.join(", ")).ok();
+            }
+        }
+        _ => {
+            let old_ops = whole_script_asm(old);
+            let new_ops = whole_script_asm(new);
+            if old_ops == new_ops {
+                text.push_str("\nThe scripts are identical.\n");
+            } else {
+                text.push_str(
+                    "\nThe methods' branches couldn't be told apart, so this is the whole script.\n\n",
+                );
+                push_diff(&mut text, &old_ops, &new_ops, "op");
+            }
+        }
+    }
+    text
+}
+
+fn abi_changes(old: &Artifact, new: &Artifact) -> Vec<String> {
+    let mut changes = Vec::new();
+    let (old_constructor, new_constructor) = (old.constructor_params(), new.constructor_params());
+    if signature(old_constructor) != signature(new_constructor) {
+        changes.push(format!(
+            "constructor: `({})` → `({})`",
+            signature(old_constructor),
+            signature(new_constructor)
+        ));
+    }
+    for entry in old.public_methods() {
+        let name = entry.name.as_deref().unwrap_or_default();
+        let Some(other) = new
+            .public_methods()
+            .find(|other| other.name.as_deref() == Some(name))
+        else {
+            changes.push(format!("removed `{name}({})`", signature(&entry.params)));
+            continue;
+        };
+        if signature(&entry.params) != signature(&other.params) {
+            changes.push(format!(
+                "`{name}`: `({})` → `({})`",
+                signature(&entry.params),
+                signature(&other.params)
+            ));
+        }
+        if entry.index != other.index {
+            changes.push(format!(
+                "`{name}` moved from index {} to {}, changing the index its callers push",
+                entry.index.unwrap_or_default(),
+                other.index.unwrap_or_default()
+            ));
+        }
+    }
+    for entry in new.public_methods() {
+        let name = entry.name.as_deref().unwrap_or_default();
+        if !old
+            .public_methods()
+            .any(|other| other.name.as_deref() == Some(name))
+        {
+            changes.push(format!("added `{name}({})`", signature(&entry.params)));
+        }
+    }
+    let (old_state, new_state) = (signature(&old.state_props), signature(&new.state_props));
+    if old_state != new_state {
+        changes.push(format!("state: `{{{old_state}}}` → `{{{new_state}}}`"));
+    }
+    changes
+}
+
+fn signature(params: &[Param]) -> String {
+    params
+        .iter()
+        .map(|param| format!("{}: {}", param.name, param.ty))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn size_rows(text: &mut String, name: &str, old: ScriptSize, new: ScriptSize) {
+    for (unit, old, new) in [("bytes", old.bytes, new.bytes), ("ops", old.ops, new.ops)] {
+        writeln!(
+            text,
+            "| {name} {unit} | {old} | {new} | {} |",
+            change(old, new)
+        )
+        .ok();
+    }
+}
+
+fn change(old: usize, new: usize) -> String {
+    match new as i64 - old as i64 {
+        0 => "0".to_string(),
+        delta => format!("{delta:+}"),
+    }
+}
+
+fn whole_script_asm(artifact: &Artifact) -> Vec<String> {
+    artifact
+        .template_asm()
+        .0
+        .into_iter()
+        .map(|token| match token {
+            TemplateToken::Asm(asm) => asm,
+            TemplateToken::Placeholder(name) => format!("<{name}>"),
+        })
+        .collect()
+}
+
+/// Writes the changes from `old` to `new` as a fenced `diff`, one
+/// instruction (or source line) per line, with a `@@ <unit> N @@` line
+/// giving the position of each hunk.
+pub(crate) fn push_diff(text: &mut String, old: &[String], new: &[String], unit: &str) {
+    let edits = diff(old, new);
+    let changed: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| !matches!(edit, Edit::Same(_)))
+        .map(|(ix, _)| ix)
+        .collect();
+
+    text.push_str("```diff\n");
+    let mut lines = 0;
+    let mut shown_until = 0;
+    let mut position = 0;
+    let mut ix = 0;
+    while ix < edits.len() {
+        let Some(&next_change) = changed.iter().find(|&&change| change >= ix) else {
+            break;
+        };
+        let start = next_change.saturating_sub(CONTEXT).max(shown_until);
+        // A hunk runs until CONTEXT unchanged lines past its last change
+        let mut end = next_change;
+        for &change in changed.iter().filter(|&&change| change >= next_change) {
+            if change > end + 2 * CONTEXT {
+                break;
+            }
+            end = change;
+        }
+        let end = (end + CONTEXT + 1).min(edits.len());
+
+        position += edits[ix..start]
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Added(_)))
+            .count();
+        if lines >= MAX_DIFF_LINES {
+            let remaining = changed.iter().filter(|&&change| change >= start).count();
+            writeln!(text, "… {remaining} more changed {unit}(s)").ok();
+            break;
+        }
+        writeln!(text, "@@ {unit} {} @@", position + 1).ok();
+        for edit in &edits[start..end] {
+            match edit {
+                Edit::Same(asm) => writeln!(text, "  {asm}"),
+                Edit::Removed(asm) => writeln!(text, "- {asm}"),
+                Edit::Added(asm) => writeln!(text, "+ {asm}"),
+            }
+            .ok();
+        }
+        lines += end - start + 1;
+        position += edits[start..end]
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Added(_)))
+            .count();
+        shown_until = end;
+        ix = end;
+    }
+    text.push_str("```\n");
+}
+
+/// A shortest edit script from `old` to `new`, aligning their longest common
+/// subsequence.
+fn diff<'a>(old: &'a [String], new: &'a [String]) -> Vec<Edit<'a>> {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut edits: Vec<Edit> = old[..prefix].iter().map(|asm| Edit::Same(asm)).collect();
+    if old_middle.len().saturating_mul(new_middle.len()) > MAX_ALIGN_CELLS {
+        edits.extend(old_middle.iter().map(|asm| Edit::Removed(asm)));
+        edits.extend(new_middle.iter().map(|asm| Edit::Added(asm)));
+    } else {
+        // lengths[i][j]: the longest common subsequence of old_middle[i..] and new_middle[j..]
+        let width = new_middle.len() + 1;
+        let mut lengths = vec![0u32; (old_middle.len() + 1) * width];
+        for i in (0..old_middle.len()).rev() {
+            for j in (0..new_middle.len()).rev() {
+                lengths[i * width + j] = if old_middle[i] == new_middle[j] {
+                    lengths[(i + 1) * width + j + 1] + 1
+                } else {
+                    lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < old_middle.len() || j < new_middle.len() {
+            if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+                edits.push(Edit::Same(&old_middle[i]));
+                i += 1;
+                j += 1;
+            } else if j == new_middle.len()
+                || (i < old_middle.len()
+                    && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1])
+            {
+                edits.push(Edit::Removed(&old_middle[i]));
+                i += 1;
+            } else {
+                edits.push(Edit::Added(&new_middle[j]));
+                j += 1;
+            }
+        }
+    }
+    edits.extend(old[old.len() - suffix..].iter().map(|asm| Edit::Same(asm)));
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let old: Artifact = serde_json::from_str(
+            r#"{
+                "contract": "Demo",
+                "hex": "76009c6375<pubKey>ac67519d5168",
+                "abi": [
+                    {"type": "function", "name": "unlock", "index": 0, "params": [{"name": "sig", "type": "Sig"}]},
+                    {"type": "function", "name": "check", "index": 1, "params": []},
+                    {"type": "constructor", "params": [{"name": "pubKey", "type": "PubKey"}]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let new: Artifact = serde_json::from_str(
+            r#"{
+                "contract": "Demo",
+                "hex": "76009c6375<pubKey>ac67519d528768",
+                "abi": [
+                    {"type": "function", "name": "unlock", "index": 0, "params": [{"name": "sig", "type": "Sig"}]},
+                    {"type": "function", "name": "check", "index": 1, "params": [{"name": "x", "type": "bigint"}]},
+                    {"type": "constructor", "params": [{"name": "pubKey", "type": "PubKey"}]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let report = report(("HEAD", &old), ("working copy", &new));
+        assert!(report.starts_with("## Demo: HEAD → working copy\n"));
+        assert!(report.contains("- `check`: `()` → `(x: bigint)`\n"));
+        assert!(report.contains("| Script bytes | 11 | 12 | +1 |\n"));
+        assert!(report.contains("Unchanged: `unlock`."));
+        assert!(
+            report.contains("#### `check`\n\n```diff\n@@ op 1 @@\n- OP_1\n+ OP_2\n+ OP_EQUAL\n")
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let ops = |text: &str| -> Vec<String> { text.split(' ').map(String::from).collect() };
+        let (old, new) = (ops("a b c d e"), ops("a c d x e"));
+        assert_eq!(
+            diff(&old, &new),
+            [
+                Edit::Same("a"),
+                Edit::Removed("b"),
+                Edit::Same("c"),
+                Edit::Same("d"),
+                Edit::Added("x"),
+                Edit::Same("e"),
+            ]
+        );
+    }
+}
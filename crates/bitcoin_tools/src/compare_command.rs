@@ -0,0 +1,321 @@
+//! `/compare <contract> [<other contract> | <git revision>]`: compiles two
+//! versions of a contract and reports what changed at the Script level, so a
+//! reviewer can see a source change's on-chain cost. Without a second
+//! argument the contract is compared with its last commit.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, atomic::AtomicBool},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context as _, Result, anyhow, bail};
+use assistant_slash_command::{
+    AfterCompletion, ArgumentCompletion, SlashCommand, SlashCommandOutput,
+    SlashCommandOutputSection, SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use ui::prelude::*;
+use workspace::Workspace;
+
+use crate::{
+    artifact::Artifact, bitcoin_project::workspace_project_root, compare,
+    contract_source::is_contract_file,
+};
+
+/// Where both versions are copied to be compiled, away from the project's
+/// own sources and artifacts.
+const COMPARE_DIR: &str = ".utxix/compare";
+const DEFAULT_REVISION: &str = "HEAD";
+
+pub struct CompareSlashCommand;
+
+impl SlashCommand for CompareSlashCommand {
+    fn name(&self) -> String {
+        "compare".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("compare", &["<contract>", "<contract or revision>"], cx)
+    }
+
+    fn description(&self) -> String {
+        "Compare the compiled Script of two contract versions".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::Diff
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        let Some(root) = workspace.and_then(|workspace| workspace_project_root(&workspace, cx))
+        else {
+            return Task::ready(Ok(Vec::new()));
+        };
+        let query = arguments.last().cloned().unwrap_or_default();
+        let second = arguments.len() > 1;
+        cx.background_spawn(async move {
+            let mut options = contract_files(&root);
+            if second {
+                options.insert(0, DEFAULT_REVISION.to_string());
+            }
+            Ok(options
+                .into_iter()
+                .filter(|option| option.contains(query.as_str()))
+                .map(|option| ArgumentCompletion {
+                    label: option.clone().into(),
+                    new_text: option,
+                    after_completion: if second {
+                        AfterCompletion::Run
+                    } else {
+                        AfterCompletion::Continue
+                    },
+                    replace_previous_arguments: false,
+                })
+                .collect())
+        })
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        let Some(root) = workspace_project_root(&workspace, cx) else {
+            return Task::ready(Err(anyhow!("no project is open")));
+        };
+        let (path, other) = match arguments {
+            [path] => (path.clone(), DEFAULT_REVISION.to_string()),
+            [path, other] => (path.clone(), other.clone()),
+            _ => {
+                return Task::ready(Err(anyhow!(
+                    "usage: /compare <contract> [<other contract> | <git revision>]"
+                )));
+            }
+        };
+        cx.background_spawn(async move {
+            let text = compare_versions(&root, &path, &other).await?;
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::Diff,
+                    label: format!("Compare {path} with {other}").into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
+
+/// Contract sources under `contracts/`, relative to the project root.
+fn contract_files(root: &Path) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(root.join("contracts"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| is_contract_file(path))
+        .filter_map(|path| {
+            let relative = path.strip_prefix(root).ok()?;
+            Some(relative.to_string_lossy().replace('\\', "/"))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// A version of a contract to compile.
+struct Version {
+    label: String,
+    file_name: String,
+    source: String,
+}
+
+impl Version {
+    fn read(root: &Path, path: &str, label: &str) -> Result<Self> {
+        let file = root.join(path);
+        Ok(Self {
+            label: label.to_string(),
+            file_name: file_name(&file),
+            source: fs::read_to_string(&file)
+                .with_context(|| format!("reading {}", file.display()))?,
+        })
+    }
+}
+
+/// Compiles `path` and `other` - a second contract file, or `path` at a git
+/// revision - and reports how `path` differs from the revision, or the second
+/// file from the first.
+async fn compare_versions(root: &Path, path: &str, other: &str) -> Result<String> {
+    let (old, new) = if root.join(other).is_file() {
+        (
+            Version::read(root, path, path)?,
+            Version::read(root, other, other)?,
+        )
+    } else {
+        let new = Version::read(root, path, "working copy")?;
+        let old = Version {
+            label: other.to_string(),
+            file_name: new.file_name.clone(),
+            source: git_show(root, other, path).await?,
+        };
+        (old, new)
+    };
+
+    let id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default()
+        .to_string();
+    let work_dir = root.join(COMPARE_DIR).join(&id);
+    let result = async {
+        // One at a time, as both compiles write under artifacts/
+        let old_artifact = compile(root, &work_dir.join("old"), &old).await?;
+        let new_artifact = compile(root, &work_dir.join("new"), &new).await?;
+        anyhow::Ok((old_artifact, new_artifact))
+    }
+    .await;
+    fs::remove_dir_all(&work_dir).ok();
+    remove_compiled(&root.join("artifacts"), &id);
+    let (old_artifact, new_artifact) = result?;
+    Ok(compare::report(
+        (&old.label, &old_artifact),
+        (&new.label, &new_artifact),
+    ))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// The source of `path` as of `revision`.
+async fn git_show(root: &Path, revision: &str, path: &str) -> Result<String> {
+    let output = util::command::new_smol_command("git")
+        .args(["show", &format!("{revision}:./{path}")])
+        .current_dir(root)
+        .output()
+        .await
+        .context("running `git show` - is git installed?")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!(
+            "`{revision}` isn't a file in the project, and git has no {path} at that revision: {}",
+            stderr.trim()
+        );
+    }
+    String::from_utf8(output.stdout).with_context(|| format!("{path} at {revision} isn't UTF-8"))
+}
+
+/// Compiles `version` in `dir` with the project's sCrypt CLI and loads the
+/// artifact it produces.
+async fn compile(root: &Path, dir: &Path, version: &Version) -> Result<Artifact> {
+    let file_name = &version.file_name;
+    fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    let file = dir.join(file_name);
+    fs::write(&file, &version.source).with_context(|| format!("writing {}", file.display()))?;
+    let relative = file
+        .strip_prefix(root)
+        .unwrap_or(&file)
+        .to_string_lossy()
+        .to_string();
+    let output = util::command::new_smol_command("npx")
+        .args(["scrypt-cli", "compile", "--include", &relative])
+        .current_dir(root)
+        .output()
+        .await
+        .context("running `npx scrypt-cli compile`")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!(
+            "compiling {file_name} ({}) failed: {}",
+            version.label,
+            stderr.trim()
+        );
+    }
+
+    let artifact_name = file_name.replace(".scrypt.ts", ".scrypt.json");
+    let artifact = find_compiled(&root.join("artifacts"), dir, &artifact_name)
+        .with_context(|| format!("`npx scrypt-cli compile` wrote no artifact for {file_name}"))?;
+    Artifact::load(&artifact)
+}
+
+/// The artifact named `name` that the CLI wrote for a source in `source_dir`,
+/// wherever under `artifacts` it mirrored that directory to.
+fn find_compiled(artifacts: &Path, source_dir: &Path, name: &str) -> Option<PathBuf> {
+    // `<id>/old` or `<id>/new`
+    let components: Vec<_> = source_dir.iter().collect();
+    let tail: PathBuf = components[components.len().saturating_sub(2)..]
+        .iter()
+        .collect();
+    let mut dirs = vec![artifacts.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.file_name().is_some_and(|file| file == name)
+                && path.parent().is_some_and(|parent| parent.ends_with(&tail))
+            {
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+/// Removes the artifact directories mirrored from the compare run `id`.
+fn remove_compiled(artifacts: &Path, id: &str) {
+    let mut dirs = vec![artifacts.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            if path.file_name().is_some_and(|name| name == id) {
+                fs::remove_dir_all(&path).ok();
+                // And the directories mirroring COMPARE_DIR, once empty
+                let mut parent = path.parent();
+                while let Some(dir) = parent.filter(|dir| *dir != artifacts) {
+                    if fs::remove_dir(dir).is_err() {
+                        break;
+                    }
+                    parent = dir.parent();
+                }
+            } else {
+                dirs.push(path);
+            }
+        }
+    }
+}
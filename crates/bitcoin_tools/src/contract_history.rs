@@ -0,0 +1,366 @@
+//! A contract's past as a list of events: the transactions that deployed it,
+//! called it (spent it into a new output of the same contract), and spent it
+//! for good, in chain order, with the method each spend called when the
+//! script matches one of the project's artifacts.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+};
+
+use anyhow::{Result, bail};
+use bitcoin_script::{
+    OP_0, OP_1, OP_1NEGATE, OP_16, decode_num, hash::sha256, keys::decode_address, parse,
+    transaction::p2pkh_script,
+};
+use num_bigint::BigInt;
+
+use crate::{
+    artifact::{Artifact, split_state},
+    whatsonchain::{TxInput, TxOut},
+};
+
+/// What `/history` was asked about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+    /// A P2PKH address, and the script it stands for.
+    Address(String, Vec<u8>),
+    /// A script hash as indexers key them: SHA-256 of the script, reversed.
+    ScriptHash(String),
+}
+
+impl Target {
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        if text.len() == 64 && text.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Ok(Target::ScriptHash(text.to_ascii_lowercase()));
+        }
+        match decode_address(text) {
+            Some((hash, _)) => Ok(Target::Address(text.to_string(), p2pkh_script(&hash))),
+            None => bail!("`{text}` is neither an address nor a 64-character script hash"),
+        }
+    }
+
+    pub fn matches(&self, script: &[u8]) -> bool {
+        match self {
+            Target::Address(_, p2pkh) => script == p2pkh.as_slice(),
+            Target::ScriptHash(hash) => script_hash(script) == *hash,
+        }
+    }
+}
+
+/// The script hash indexers list `script` under.
+pub fn script_hash(script: &[u8]) -> String {
+    let mut hash = sha256(script);
+    hash.reverse();
+    hex::encode(hash)
+}
+
+/// A transaction from the target's history, with its inputs and outputs.
+#[derive(Clone, Debug)]
+pub struct HistoryTx {
+    pub txid: String,
+    /// 0 while unconfirmed.
+    pub height: u64,
+    pub inputs: Vec<TxInput>,
+    pub outputs: Vec<TxOut>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventKind {
+    /// Creates an output of the target without spending one.
+    Deploy,
+    /// Spends an output of the target into a new one, as a stateful
+    /// contract's calls do.
+    Call,
+    /// Spends the target without continuing it.
+    Spend,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub txid: String,
+    pub height: u64,
+    pub kind: EventKind,
+    /// Satoshis locked in the target after the event, or released by a
+    /// final spend; `None` when the spent output predates the history.
+    pub satoshis: Option<u64>,
+    /// `Contract.method` for spends of a script matching an artifact.
+    pub method: Option<String>,
+}
+
+/// The events in `txs`, in chain order, and the target's outputs that are
+/// still unspent as `(txid:vout, satoshis)`.
+pub fn events(
+    target: &Target,
+    txs: &[HistoryTx],
+    artifacts: &[Artifact],
+) -> (Vec<Event>, Vec<(String, u64)>) {
+    // The target's outputs: outpoint to (value, script)
+    let mut outputs: HashMap<(String, u32), (u64, Vec<u8>)> = HashMap::new();
+    for tx in txs {
+        for (vout, output) in tx.outputs.iter().enumerate() {
+            let script = hex::decode(&output.script_hex).unwrap_or_default();
+            if target.matches(&script) {
+                outputs.insert((tx.txid.clone(), vout as u32), (output.satoshis, script));
+            }
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut spent = HashSet::new();
+    for tx in order(txs) {
+        let created: Vec<u64> = tx
+            .outputs
+            .iter()
+            .filter(|output| {
+                hex::decode(&output.script_hex).is_ok_and(|script| target.matches(&script))
+            })
+            .map(|output| output.satoshis)
+            .collect();
+        let spends: Vec<(&TxInput, &(u64, Vec<u8>))> = tx
+            .inputs
+            .iter()
+            .filter_map(|input| {
+                let outpoint = (input.txid.clone(), input.vout);
+                let output = outputs.get(&outpoint)?;
+                spent.insert(outpoint);
+                Some((input, output))
+            })
+            .collect();
+
+        let kind = match (spends.is_empty(), created.is_empty()) {
+            // Spends an output created before the history starts
+            (true, true) => EventKind::Spend,
+            (true, false) => EventKind::Deploy,
+            (false, false) => EventKind::Call,
+            (false, true) => EventKind::Spend,
+        };
+        let satoshis = match (kind, spends.is_empty()) {
+            (EventKind::Spend, true) => None,
+            (EventKind::Spend, false) => {
+                Some(spends.iter().map(|(_, (satoshis, _))| satoshis).sum())
+            }
+            _ => Some(created.iter().sum()),
+        };
+        let method = spends
+            .iter()
+            .find_map(|(input, (_, script))| method_hint(artifacts, script, &input.script_sig_hex));
+        events.push(Event {
+            txid: tx.txid.clone(),
+            height: tx.height,
+            kind,
+            satoshis,
+            method,
+        });
+    }
+
+    let mut unspent: Vec<(String, u64)> = outputs
+        .iter()
+        .filter(|(outpoint, _)| !spent.contains(*outpoint))
+        .map(|((txid, vout), (satoshis, _))| (format!("{txid}:{vout}"), *satoshis))
+        .collect();
+    unspent.sort();
+    (events, unspent)
+}
+
+/// `txs` by block height, unconfirmed last, with each transaction after the
+/// ones in the list it spends from.
+fn order(txs: &[HistoryTx]) -> Vec<&HistoryTx> {
+    let by_txid: HashMap<&str, &HistoryTx> = txs.iter().map(|tx| (tx.txid.as_str(), tx)).collect();
+    fn depth<'a>(
+        tx: &'a HistoryTx,
+        by_txid: &HashMap<&str, &'a HistoryTx>,
+        depths: &mut HashMap<&'a str, usize>,
+        visiting: &mut HashSet<&'a str>,
+    ) -> usize {
+        if let Some(depth) = depths.get(tx.txid.as_str()) {
+            return *depth;
+        }
+        if !visiting.insert(tx.txid.as_str()) {
+            return 0;
+        }
+        let depth = tx
+            .inputs
+            .iter()
+            .filter_map(|input| by_txid.get(input.txid.as_str()))
+            .map(|parent| depth(parent, by_txid, depths, visiting) + 1)
+            .max()
+            .unwrap_or(0);
+        depths.insert(tx.txid.as_str(), depth);
+        depth
+    }
+
+    let mut depths = HashMap::new();
+    let mut visiting = HashSet::new();
+    let mut ordered: Vec<(&HistoryTx, usize)> = txs
+        .iter()
+        .map(|tx| (tx, depth(tx, &by_txid, &mut depths, &mut visiting)))
+        .collect();
+    ordered.sort_by_key(|(tx, depth)| (tx.height == 0, tx.height, *depth));
+    ordered.into_iter().map(|(tx, _)| tx).collect()
+}
+
+/// `Contract.method` for an unlocking script spending `locking`, when the
+/// locking script is an instance of one of `artifacts`. With several public
+/// methods the unlocking script ends with the method's index.
+fn method_hint(artifacts: &[Artifact], locking: &[u8], script_sig_hex: &str) -> Option<String> {
+    let code = split_state(locking).map_or(locking, |(code, _)| code);
+    let artifact = artifacts.iter().find(|artifact| {
+        artifact.match_script(locking).is_some() || artifact.match_script(code).is_some()
+    })?;
+    let mut methods: Vec<_> = artifact.public_methods().collect();
+    methods.sort_by_key(|entry| entry.index.unwrap_or_default());
+    let entry = match methods.as_slice() {
+        [] => return None,
+        [entry] => *entry,
+        _ => {
+            let script_sig = hex::decode(script_sig_hex).ok()?;
+            let last = parse(&script_sig).ok()?.pop()?;
+            let index = match (last.opcode, &last.data) {
+                (OP_0, _) => BigInt::from(0),
+                (OP_1NEGATE, _) => return None,
+                (OP_1..=OP_16, _) => BigInt::from(last.opcode - OP_1 + 1),
+                (_, Some(data)) if data.len() <= 4 => decode_num(data),
+                _ => return None,
+            };
+            *methods
+                .iter()
+                .find(|entry| BigInt::from(entry.index.unwrap_or_default()) == index)?
+        }
+    };
+    Some(format!(
+        "{}.{}",
+        artifact.contract,
+        entry.name.as_deref().unwrap_or_default()
+    ))
+}
+
+/// The markdown for `/history`.
+pub fn report(
+    title: &str,
+    events: &[Event],
+    unspent: &[(String, u64)],
+    tx_url: impl Fn(&str) -> String,
+) -> String {
+    let mut text = format!("## History of {title}\n\n");
+    if events.is_empty() {
+        text.push_str("No deployments, calls or spends found.\n");
+        return text;
+    }
+    text.push_str(
+        "| # | Block | Event | Transaction | Satoshis | Method |\n|---|---|---|---|---:|---|\n",
+    );
+    for (ix, event) in events.iter().enumerate() {
+        let block = match event.height {
+            0 => "unconfirmed".to_string(),
+            height => height.to_string(),
+        };
+        let kind = match event.kind {
+            EventKind::Deploy => "Deployed",
+            EventKind::Call => "Called",
+            EventKind::Spend => "Spent",
+        };
+        let satoshis = event
+            .satoshis
+            .map_or("?".to_string(), |satoshis| satoshis.to_string());
+        let method = event
+            .method
+            .as_deref()
+            .map(|method| format!("`{method}`"))
+            .unwrap_or_default();
+        writeln!(
+            text,
+            "| {} | {block} | {kind} | [{}…]({}) | {satoshis} | {method} |",
+            ix + 1,
+            &event.txid[..event.txid.len().min(12)],
+            tx_url(&event.txid)
+        )
+        .ok();
+    }
+    if unspent.is_empty() {
+        text.push_str("\nNothing is left unspent.\n");
+    } else {
+        text.push_str("\nStill unspent:\n\n");
+        for (outpoint, satoshis) in unspent {
+            writeln!(text, "- `{outpoint}` ({satoshis} sats)").ok();
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events() {
+        let artifact: Artifact = serde_json::from_str(
+            r#"{
+                "contract": "Counter",
+                "hex": "76009c63757567519d6887",
+                "abi": [
+                    {"type": "function", "name": "increment", "index": 0, "params": []},
+                    {"type": "function", "name": "withdraw", "index": 1, "params": []}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let script = hex::decode(&artifact.hex).unwrap();
+        let target = Target::ScriptHash(script_hash(&script));
+        let output = |satoshis, script: &[u8]| TxOut {
+            satoshis,
+            confirmations: 0,
+            height: 0,
+            script_hex: hex::encode(script),
+        };
+        let input = |txid: &str, script_sig: &str| TxInput {
+            txid: txid.to_string(),
+            vout: 0,
+            script_sig_hex: script_sig.to_string(),
+        };
+        // Listed out of order, the call and final spend in the same block
+        let txs = [
+            HistoryTx {
+                txid: "cc".into(),
+                height: 12,
+                inputs: vec![input("bb", "51")],
+                outputs: vec![output(900, &[0x6a])],
+            },
+            HistoryTx {
+                txid: "bb".into(),
+                height: 12,
+                inputs: vec![input("aa", "00")],
+                outputs: vec![output(1000, &script)],
+            },
+            HistoryTx {
+                txid: "aa".into(),
+                height: 10,
+                inputs: vec![input("ff", "")],
+                outputs: vec![output(1000, &script), output(5, &[0x51])],
+            },
+        ];
+        let (events, unspent) = events(&target, &txs, &[artifact]);
+        let summary: Vec<_> = events
+            .iter()
+            .map(|event| {
+                (
+                    event.txid.as_str(),
+                    event.kind,
+                    event.satoshis,
+                    event.method.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("aa", EventKind::Deploy, Some(1000), None),
+                ("bb", EventKind::Call, Some(1000), Some("Counter.increment")),
+                ("cc", EventKind::Spend, Some(1000), Some("Counter.withdraw")),
+            ]
+        );
+        assert!(unspent.is_empty());
+        assert!(Target::parse("not an address").is_err());
+    }
+}
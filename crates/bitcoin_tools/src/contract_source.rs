@@ -0,0 +1,305 @@
+//! Light scanning of sCrypt contract sources (`contracts/*.scrypt.ts`), enough
+//! to place editor decorations next to their methods without a TypeScript parser.
+
+use std::{ops::Range, path::Path};
+
+const MODIFIERS: [&str; 6] = [
+    "public",
+    "private",
+    "protected",
+    "static",
+    "override",
+    "async",
+];
+
+/// A `@method()`-decorated function in a contract source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractMethod {
+    pub name: String,
+    /// Public methods are entry points with their own branch of the script;
+    /// the rest are inlined where they're called.
+    pub public: bool,
+    /// Byte offset of the `@` of the decorator.
+    pub decorator_offset: usize,
+    pub name_offset: usize,
+    /// The body, braces included, unless the source is cut off before it ends.
+    pub body: Option<Range<usize>>,
+}
+
+pub fn is_contract_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().ends_with(".scrypt.ts"))
+}
+
+/// `source` with the contents of comments and string literals replaced by
+/// spaces, keeping quotes, newlines and byte offsets, so it can be searched
+/// for code without matching inside them.
+pub fn mask_source(source: &str) -> String {
+    let mut masked = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let blank = |masked: &mut String, c: char| {
+        if c == '\n' {
+            masked.push('\n');
+        } else {
+            masked.extend(std::iter::repeat_n(' ', c.len_utf8()));
+        }
+    };
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                masked.push_str("  ");
+                chars.next();
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    blank(&mut masked, c);
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                masked.push_str("  ");
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    blank(&mut masked, c);
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '"' | '\'' | '`' => {
+                masked.push(c);
+                let quote = c;
+                while let Some(c) = chars.next() {
+                    if c == quote {
+                        masked.push(c);
+                        break;
+                    }
+                    blank(&mut masked, c);
+                    if c == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            blank(&mut masked, escaped);
+                        }
+                    } else if c == '\n' && quote != '`' {
+                        break;
+                    }
+                }
+            }
+            c => masked.push(c),
+        }
+    }
+    masked
+}
+
+/// The `@method()`s of a contract source, in source order. Decorators in
+/// comments and strings are skipped.
+pub fn find_methods(source: &str) -> Vec<ContractMethod> {
+    let source = mask_source(source);
+    let mut methods = Vec::new();
+    for (offset, _) in source.match_indices("@method(") {
+        let Some(args_end) = closing(&source, offset + "@method".len()) else {
+            continue;
+        };
+        let mut rest = &source[args_end..];
+        let mut public = false;
+        let name = loop {
+            rest = rest.trim_start();
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                .unwrap_or(rest.len());
+            let (word, after) = rest.split_at(len);
+            if MODIFIERS.contains(&word) {
+                public |= word == "public";
+                rest = after;
+                continue;
+            }
+            let is_method = after.trim_start().starts_with(['(', '<']);
+            break (!word.is_empty() && is_method).then_some((word, after));
+        };
+        let Some((name, after)) = name else {
+            continue;
+        };
+        let after_name = source.len() - after.len();
+        let body = source[after_name..]
+            .find('(')
+            .and_then(|params| closing(&source, after_name + params))
+            .and_then(|params_end| {
+                let start = params_end + source[params_end..].find('{')?;
+                Some(start..closing(&source, start)?)
+            });
+        methods.push(ContractMethod {
+            name: name.to_string(),
+            public,
+            decorator_offset: offset,
+            name_offset: after_name - name.len(),
+            body,
+        });
+    }
+    methods
+}
+
+/// Whether the last statement of a masked block body is an `assert(...)`.
+pub fn ends_with_assert(body: &str) -> bool {
+    last_statement(body)
+        .strip_prefix("assert")
+        .is_some_and(|rest| rest.trim_start().starts_with('('))
+}
+
+/// The last statement of a masked block body, trimmed. Statements end at `;`,
+/// at a closing `}`, or at a line break that doesn't continue an expression.
+fn last_statement(body: &str) -> &str {
+    let mut depth = 0usize;
+    let mut statement_start = 0;
+    let mut last = "";
+    for (ix, c) in body.char_indices() {
+        let ends_statement = match c {
+            '(' | '[' | '{' => {
+                depth += 1;
+                false
+            }
+            ')' | ']' => {
+                depth = depth.saturating_sub(1);
+                false
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                depth == 0
+            }
+            ';' => depth == 0,
+            '\n' if depth == 0 => {
+                let statement = body[statement_start..ix].trim();
+                let next = body[ix..].trim_start().chars().next();
+                !statement.is_empty()
+                    && (statement.ends_with("++")
+                        || statement.ends_with("--")
+                        || !statement.ends_with(|c: char| "=+-*/%&|<>?:,(!".contains(c)))
+                    && !next.is_some_and(|c| ".?:+-*/%&|=<>)".contains(c))
+            }
+            _ => false,
+        };
+        if ends_statement {
+            let statement = body[statement_start..ix + c.len_utf8()].trim();
+            if !statement.is_empty() {
+                last = statement;
+            }
+            statement_start = ix + c.len_utf8();
+        }
+    }
+    let rest = body[statement_start..].trim();
+    if rest.is_empty() { last } else { rest }
+}
+
+/// Names of the `@prop(true)` properties, which make up the contract's state.
+pub fn stateful_props(masked: &str) -> Vec<String> {
+    masked
+        .match_indices("@prop(true)")
+        .filter_map(|(offset, decorator)| {
+            let mut rest = masked[offset + decorator.len()..].trim_start();
+            for modifier in MODIFIERS {
+                if let Some(after) = rest.strip_prefix(modifier) {
+                    rest = after.trim_start();
+                }
+            }
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                .unwrap_or(rest.len());
+            (len > 0).then(|| rest[..len].to_string())
+        })
+        .collect()
+}
+
+/// The offset just past the bracket that closes the one at `open`, in masked
+/// source.
+pub fn closing(source: &str, open: usize) -> Option<usize> {
+    let (open_char, close_char) = match source[open..].chars().next()? {
+        '(' => ('(', ')'),
+        '[' => ('[', ']'),
+        '{' => ('{', '}'),
+        _ => return None,
+    };
+    let mut depth = 0;
+    for (ix, c) in source[open..].char_indices() {
+        if c == open_char {
+            depth += 1;
+        } else if c == close_char {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open + ix + 1);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_methods() {
+        let source = r#"
+export class Counter extends SmartContract {
+    @prop(true)
+    count: bigint
+
+    // @method() public disabled() {}
+
+    @method(SigHash.ANYONECANPAY_SINGLE)
+    public increment() {
+        this.count = Counter.add(this.count, 1n)
+        assert(true, "@method() public inString() {")
+    }
+
+    @method()
+    static add(a: bigint, b: bigint): bigint {
+        return a + b
+    }
+
+    @method()
+    private check<T>(x: T) {}
+}
+"#;
+        let methods: Vec<_> = find_methods(source)
+            .into_iter()
+            .map(|method| {
+                (
+                    method.name,
+                    method.public,
+                    &source[method.decorator_offset..][..8],
+                    method.body.map(|body| &source[body]),
+                )
+            })
+            .collect();
+        assert_eq!(
+            methods,
+            [
+                (
+                    "increment".to_string(),
+                    true,
+                    "@method(",
+                    Some(
+                        "{\n        this.count = Counter.add(this.count, 1n)\n        \
+                         assert(true, \"@method() public inString() {\")\n    }"
+                    )
+                ),
+                (
+                    "add".to_string(),
+                    false,
+                    "@method(",
+                    Some("{\n        return a + b\n    }")
+                ),
+                ("check".to_string(), false, "@method(", Some("{}")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mask_source() {
+        let source = "let s = 'a\\'b' // note\nx /* y */ + `t`";
+        let masked = mask_source(source);
+        assert_eq!(masked.len(), source.len());
+        assert_eq!(masked, "let s = '    '        \nx         + ` `");
+    }
+}
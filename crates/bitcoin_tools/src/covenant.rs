@@ -0,0 +1,523 @@
+//! `/covenant`: a starting sCrypt contract for a description, and
+//! refinements of the latest contract in the conversation. Refinements the
+//! command knows (timeout claims, hash locks, multisig) are applied to the
+//! contract's source and shown as a diff; anything else is handed to the
+//! assistant with the current contract, asking for a diff back.
+
+use std::fmt::Write as _;
+
+use crate::compare::push_diff;
+
+/// The first argument that switches `/covenant` to refining.
+pub const REFINE: &str = "refine";
+
+/// The starting template for `/covenant <description>`, with the sCrypt
+/// guide the assistant refines it from.
+pub fn generate(description: &str) -> String {
+    format!(
+        r#"## Generated sCrypt Covenant Template
+
+**Request:** {description}
+
+```typescript
+import {{ prop, method, SmartContract, PubKey, Sig, ByteString, hash256, assert }} from 'scrypt-ts';
+
+/**
+ * {description}
+ * 
+ * This is a template covenant. Modify the logic below to match your requirements.
+ */
+export class CustomCovenant extends SmartContract {{
+    // Immutable properties (set at deployment, cannot change)
+    @prop()
+    readonly owner: PubKey;
+    
+    @prop()
+    readonly lockUntilHeight: bigint;
+
+    // Mutable state (can change between transactions)
+    @prop(true)
+    stateData: ByteString;
+
+    constructor(owner: PubKey, lockUntilHeight: bigint) {{
+        super(...arguments);
+        this.owner = owner;
+        this.lockUntilHeight = lockUntilHeight;
+        this.stateData = ByteString('');
+    }}
+
+    /**
+     * Main unlocking method - customize this logic
+     */
+    @method()
+    public unlock(sig: Sig) {{
+        // Verify signature from owner
+        assert(this.checkSig(sig, this.owner), 'Invalid signature');
+        
+        // Check timelock: nLockTime is only enforced while the input's
+        // sequence isn't final, and must be a height (below 500000000) -
+        // any unix time would pass a height deadline at once
+        assert(this.ctx.sequence < 0xffffffffn, 'nLocktime must be enabled');
+        assert(this.ctx.locktime < 500000000n, 'nLocktime must be a block height');
+        assert(this.ctx.locktime >= this.lockUntilHeight, 'Timelock not expired');
+    }}
+
+    /**
+     * Alternative unlock path - add your custom conditions
+     */
+    @method()
+    public alternateUnlock(preimage: ByteString) {{
+        // Hash-lock example: reveal preimage to unlock
+        assert(hash256(preimage) == this.stateData, 'Invalid preimage');
+    }}
+}}
+```
+
+---
+
+## sCrypt Covenant Guide (AI Context)
+
+Use this reference when refining the contract above:
+
+### Property Decorators
+- `@prop()` - Immutable state, set at deployment
+- `@prop(true)` - Mutable state, can change between transactions
+
+### Method Rules
+- All logic MUST be in `@method()` functions
+- Use `assert(condition, 'message')` for validation (compiles to Bitcoin Script)
+- Methods ending with `public` are unlocking conditions
+
+### Bitcoin Script Context
+- `this.ctx.locktime` - The spending transaction's nLockTime: a block height below 500000000, a unix time otherwise, and only enforced while `this.ctx.sequence < 0xffffffffn`. Only `>=` deadlines hold; run `/locktime <date | height | +duration>` for the values and assertions
+- `this.ctx.sequence` - Input sequence number (BSV doesn't enforce relative locks with it)
+- `this.checkSig(sig, pubkey)` - Verify ECDSA signature
+- `this.checkMultiSig(sigs, pubkeys)` - M-of-N multisig
+
+### Crypto Functions
+- `hash256(data)` - Double SHA256 (Bitcoin's standard)
+- `hash160(data)` - RIPEMD160(SHA256(x)) for addresses
+- `sha256(data)` - Single SHA256
+
+### Critical Constraints
+- NO unbounded loops (max ~10k ops per transaction)
+- NO floating point - use `bigint` only
+- State size affects transaction fees
+- All paths must explicitly `assert()` their conditions
+
+---
+
+Refine this template for: **{description}**
+
+Ask me to add: win detection, ZK proofs, oracle integration, or perceptron AI opponent.
+Or change it in place with `/covenant refine <change>`, e.g. `/covenant refine add a timeout claim path`.
+"#,
+    )
+}
+
+/// The last contract in the conversation: the last fenced TypeScript block
+/// declaring a `SmartContract`, whether `/covenant` or the assistant wrote it.
+pub fn last_contract(conversation: &str) -> Option<String> {
+    let mut contract = None;
+    let mut block: Option<String> = None;
+    for line in conversation.lines() {
+        let trimmed = line.trim();
+        match &mut block {
+            None => {
+                let language = trimmed.strip_prefix("```").map(str::trim);
+                if matches!(language, Some("typescript" | "ts")) {
+                    block = Some(String::new());
+                }
+            }
+            Some(text) => {
+                if trimmed == "```" {
+                    if text.contains("extends SmartContract") {
+                        contract = block.take();
+                    } else {
+                        block = None;
+                    }
+                } else {
+                    text.push_str(line);
+                    text.push('\n');
+                }
+            }
+        }
+    }
+    contract
+}
+
+/// A change `refine` can make to a contract's source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Refinement {
+    /// A path letting a designated key claim the funds once a locktime passes.
+    TimeoutClaim,
+    /// A path unlocking with the preimage of a SHA-256 hash.
+    HashLock,
+    /// A path needing `m` of `n` signatures.
+    Multisig { m: usize, n: usize },
+}
+
+impl Refinement {
+    fn detect(request: &str) -> Vec<Refinement> {
+        let request = request.to_lowercase();
+        let mentions = |words: &[&str]| words.iter().any(|word| request.contains(word));
+        let mut refinements = Vec::new();
+        if mentions(&[
+            "timeout", "time out", "deadline", "expir", "refund", "reclaim",
+        ]) {
+            refinements.push(Refinement::TimeoutClaim);
+        }
+        if mentions(&["hash lock", "hashlock", "hash-lock", "preimage", "secret"]) {
+            refinements.push(Refinement::HashLock);
+        }
+        if mentions(&["multisig", "multi-sig", "multi sig", "-of-"]) {
+            let (m, n) = m_of_n(&request).unwrap_or((2, 3));
+            refinements.push(Refinement::Multisig { m, n });
+        }
+        refinements
+    }
+
+    fn method_name(&self) -> &'static str {
+        match self {
+            Refinement::TimeoutClaim => "claimAfterTimeout",
+            Refinement::HashLock => "unlockWithSecret",
+            Refinement::Multisig { .. } => "unlockMultisig",
+        }
+    }
+
+    fn description(&self) -> String {
+        match self {
+            Refinement::TimeoutClaim => {
+                "a timeout claim path: `timeoutPubKey` can spend once `timeoutLocktime` passes"
+                    .to_string()
+            }
+            Refinement::HashLock => {
+                "a hash lock: anyone revealing the preimage of `hashLock` can spend".to_string()
+            }
+            Refinement::Multisig { m, n } => {
+                format!("a {m}-of-{n} multisig path over `signers`")
+            }
+        }
+    }
+
+    fn imports(&self) -> &'static [&'static str] {
+        match self {
+            Refinement::TimeoutClaim => &["PubKey", "Sig", "assert"],
+            Refinement::HashLock => &["ByteString", "Sha256", "sha256", "assert"],
+            Refinement::Multisig { .. } => &["FixedArray", "PubKey", "Sig", "assert"],
+        }
+    }
+
+    /// `(name, type)` of the `@prop()`s and constructor parameters it adds.
+    fn props(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Refinement::TimeoutClaim => vec![
+                ("timeoutPubKey", "PubKey".to_string()),
+                ("timeoutLocktime", "bigint".to_string()),
+            ],
+            Refinement::HashLock => vec![("hashLock", "Sha256".to_string())],
+            Refinement::Multisig { n, .. } => {
+                vec![("signers", format!("FixedArray<PubKey, {n}>"))]
+            }
+        }
+    }
+
+    /// The method's lines, indented by one level of `indent` per nesting.
+    fn method(&self, indent: &str) -> Vec<String> {
+        let body = match self {
+            Refinement::TimeoutClaim => vec![
+                "/**".to_string(),
+                " * Lets timeoutPubKey take the funds once timeoutLocktime (a block".to_string(),
+                " * height below 500000000, a unix time otherwise) has passed.".to_string(),
+                " */".to_string(),
+                "@method()".to_string(),
+                "public claimAfterTimeout(sig: Sig) {".to_string(),
+                "#// nLocktime is only enforced when the input's sequence isn't final".to_string(),
+                "#assert(this.ctx.sequence < 0xffffffffn, 'Locktime not enabled');".to_string(),
+                "#// A unix time would pass a height deadline at once, so units must match"
+                    .to_string(),
+                "#assert((this.ctx.locktime < 500000000n) == (this.timeoutLocktime < 500000000n), 'Locktime unit mismatch');"
+                    .to_string(),
+                "#assert(this.ctx.locktime >= this.timeoutLocktime, 'Timeout not reached');"
+                    .to_string(),
+                "#assert(this.checkSig(sig, this.timeoutPubKey), 'Invalid signature');".to_string(),
+                "}".to_string(),
+            ],
+            Refinement::HashLock => vec![
+                "/**".to_string(),
+                " * Unlocks with the secret whose SHA-256 is hashLock.".to_string(),
+                " */".to_string(),
+                "@method()".to_string(),
+                "public unlockWithSecret(secret: ByteString) {".to_string(),
+                "#assert(sha256(secret) == this.hashLock, 'Invalid secret');".to_string(),
+                "}".to_string(),
+            ],
+            Refinement::Multisig { m, .. } => vec![
+                "/**".to_string(),
+                format!(" * Unlocks with {m} signatures from signers, in the signers' order."),
+                " */".to_string(),
+                "@method()".to_string(),
+                format!("public unlockMultisig(sigs: FixedArray<Sig, {m}>) {{"),
+                "#assert(this.checkMultiSig(sigs, this.signers), 'Invalid signatures');"
+                    .to_string(),
+                "}".to_string(),
+            ],
+        };
+        body.into_iter()
+            .map(|line| match line.strip_prefix('#') {
+                Some(line) => format!("{indent}{indent}{line}"),
+                None => format!("{indent}{line}"),
+            })
+            .collect()
+    }
+}
+
+/// `(m, n)` from "2-of-3" or "2 of 3".
+fn m_of_n(request: &str) -> Option<(usize, usize)> {
+    let words: Vec<&str> = request
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    words.windows(3).find_map(|window| {
+        let [m, "of", n] = window else {
+            return None;
+        };
+        let (m, n) = (m.parse().ok()?, n.parse().ok()?);
+        (0 < m && m <= n && n <= 20).then_some((m, n))
+    })
+}
+
+/// The markdown for `/covenant refine <request>` applied to `contract`.
+pub fn refine(contract: &str, request: &str) -> String {
+    let mut text = format!("## Refined sCrypt Covenant\n\n**Refinement:** {request}\n\n");
+    let mut refined = contract.to_string();
+    let mut applied = Vec::new();
+    let mut skipped = Vec::new();
+    for refinement in Refinement::detect(request) {
+        match apply(&refined, refinement) {
+            Some(source) => {
+                refined = source;
+                applied.push(refinement);
+            }
+            None => skipped.push(refinement),
+        }
+    }
+
+    for refinement in &skipped {
+        writeln!(
+            text,
+            "The contract already has `{}`, so {} wasn't added again.\n",
+            refinement.method_name(),
+            refinement.description()
+        )
+        .ok();
+    }
+    if applied.is_empty() {
+        text.push_str("Current contract:\n\n```typescript\n");
+        text.push_str(contract);
+        text.push_str(
+            "```\n\n---\n\nRefine the contract above for this request. Reply with a unified \
+             `diff` against it - change only what the request needs, keep every other \
+             method, property and assertion as it is - followed by the complete updated \
+             contract in a `typescript` block so the next `/covenant refine` builds on it.\n",
+        );
+        return text;
+    }
+
+    text.push_str("Added ");
+    let descriptions: Vec<String> = applied.iter().map(Refinement::description).collect();
+    text.push_str(&descriptions.join(", and "));
+    text.push_str(":\n\n");
+    let old: Vec<String> = contract.lines().map(String::from).collect();
+    let new: Vec<String> = refined.lines().map(String::from).collect();
+    push_diff(&mut text, &old, &new, "line");
+    text.push_str("\nUpdated contract:\n\n```typescript\n");
+    text.push_str(&refined);
+    text.push_str("```\n\n---\n\n");
+    text.push_str(
+        "Review the new path with the rest of the contract: update the deploy script and \
+         tests for the new constructor parameters, and check the new method can't be used \
+         to bypass the existing ones.\n",
+    );
+    text
+}
+
+/// `contract` with `refinement` added, or `None` when it already has the
+/// method.
+fn apply(contract: &str, refinement: Refinement) -> Option<String> {
+    if contract.contains(&format!("public {}(", refinement.method_name())) {
+        return None;
+    }
+    let mut lines: Vec<String> = contract.lines().map(String::from).collect();
+    let indent = member_indent(&lines);
+
+    add_imports(&mut lines, refinement.imports());
+
+    // Properties after the last existing one, or at the top of the class
+    let props = refinement.props();
+    let prop_at = match lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with("@prop("))
+    {
+        Some(ix) => lines[ix..]
+            .iter()
+            .position(|line| line.trim_end().ends_with(';'))
+            .map_or(ix + 1, |offset| ix + offset + 1),
+        None => {
+            lines
+                .iter()
+                .position(|line| line.contains("extends SmartContract"))?
+                + 1
+        }
+    };
+    let prop_lines: Vec<String> = props
+        .iter()
+        .flat_map(|(name, ty)| {
+            [
+                String::new(),
+                format!("{indent}@prop()"),
+                format!("{indent}readonly {name}: {ty};"),
+            ]
+        })
+        .collect();
+    lines.splice(prop_at..prop_at, prop_lines);
+
+    // Constructor parameters, and their assignments at the end of its body
+    if let Some(ix) = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("constructor("))
+    {
+        let params: Vec<String> = props
+            .iter()
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect();
+        let close = lines[ix..].iter().position(|line| line.contains(')'))? + ix;
+        let line = &mut lines[close];
+        let paren = line.find(')')?;
+        let empty = line[..paren].trim_end().ends_with('(');
+        let separator = if empty { "" } else { ", " };
+        line.insert_str(paren, &format!("{separator}{}", params.join(", ")));
+
+        let super_line = lines[ix..]
+            .iter()
+            .position(|line| line.trim_start().starts_with("super("))?
+            + ix;
+        let body_indent = leading_whitespace(&lines[super_line]).to_string();
+        let assignments: Vec<String> = props
+            .iter()
+            .map(|(name, _)| format!("{body_indent}this.{name} = {name};"))
+            .collect();
+        let end = lines[super_line..]
+            .iter()
+            .position(|line| line.trim() == "}")
+            .map_or(super_line + 1, |offset| super_line + offset);
+        lines.splice(end..end, assignments);
+    }
+
+    // The method before the class's closing brace
+    let class_end = lines.iter().rposition(|line| line.trim() == "}")?;
+    let mut method = vec![String::new()];
+    method.extend(refinement.method(&indent));
+    if lines[class_end - 1].trim().is_empty() {
+        method.remove(0);
+    }
+    lines.splice(class_end..class_end, method);
+
+    let mut source = lines.join("\n");
+    source.push('\n');
+    Some(source)
+}
+
+/// Adds `names` to the `scrypt-ts` import.
+fn add_imports(lines: &mut [String], names: &[&str]) {
+    let Some(line) = lines
+        .iter_mut()
+        .find(|line| line.starts_with("import") && line.contains("scrypt-ts"))
+    else {
+        return;
+    };
+    let (Some(open), Some(close)) = (line.find('{'), line.find('}')) else {
+        return;
+    };
+    let mut imported: Vec<String> = line[open + 1..close]
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    for name in names {
+        if !imported.iter().any(|imported| imported == name) {
+            imported.push(name.to_string());
+        }
+    }
+    line.replace_range(open..=close, &format!("{{ {} }}", imported.join(", ")));
+}
+
+/// The indentation of the class's members, from its first decorator.
+fn member_indent(lines: &[String]) -> String {
+    lines
+        .iter()
+        .find(|line| {
+            let line = line.trim_start();
+            line.starts_with("@prop(") || line.starts_with("@method(")
+        })
+        .map_or("    ", |line| leading_whitespace(line))
+        .to_string()
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT: &str =
+        "import { prop, method, SmartContract, PubKey, Sig, assert } from 'scrypt-ts';
+
+export class Escrow extends SmartContract {
+  @prop()
+  readonly seller: PubKey;
+
+  constructor(seller: PubKey) {
+    super(...arguments);
+    this.seller = seller;
+  }
+
+  @method()
+  public release(sig: Sig) {
+    assert(this.checkSig(sig, this.seller), 'Invalid signature');
+  }
+}
+";
+
+    #[test]
+    fn test_refine() {
+        let conversation = format!(
+            "/covenant escrow\n```typescript\nold\n```\n```ts\n{CONTRACT}```\n```ts\nconst x = 1;\n```\n"
+        );
+        assert_eq!(last_contract(&conversation).as_deref(), Some(CONTRACT));
+
+        let text = refine(CONTRACT, "add a timeout claim path and a 2 of 3 multisig");
+        assert!(text.contains("@@ line"), "{text}");
+        let refined = last_contract(&text).unwrap();
+        assert!(refined.starts_with(
+            "import { prop, method, SmartContract, PubKey, Sig, assert, FixedArray } from 'scrypt-ts';"
+        ));
+        assert!(refined.contains(
+            "  constructor(seller: PubKey, timeoutPubKey: PubKey, timeoutLocktime: bigint, \
+             signers: FixedArray<PubKey, 3>) {"
+        ));
+        assert!(refined.contains("    this.timeoutLocktime = timeoutLocktime;\n"));
+        assert!(refined.contains("  @prop()\n  readonly signers: FixedArray<PubKey, 3>;\n"));
+        assert!(refined.contains(
+            "  public unlockMultisig(sigs: FixedArray<Sig, 2>) {\n    assert(this.checkMultiSig("
+        ));
+        assert!(refined.ends_with("  }\n}\n"), "{refined}");
+
+        let text = refine(&refined, "extend the timeout");
+        assert!(text.contains("already has `claimAfterTimeout`"));
+        let text = refine(CONTRACT, "rename the seller to merchant");
+        assert!(text.contains("Reply with a unified `diff`"));
+    }
+}
@@ -0,0 +1,163 @@
+//! `/covenant <description>`: a starting sCrypt contract, and
+//! `/covenant refine <change>`: the conversation's last contract with the
+//! change applied, as a diff.
+
+use std::sync::{Arc, atomic::AtomicBool};
+
+use anyhow::{Result, anyhow};
+use assistant_slash_command::{
+    AfterCompletion, ArgumentCompletion, SlashCommand, SlashCommandOutput,
+    SlashCommandOutputSection, SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use ui::prelude::*;
+use workspace::Workspace;
+
+use crate::covenant::{REFINE, generate, last_contract, refine};
+
+/// `(label, arguments)` offered for a new contract.
+const EXAMPLES: [(&str, &str); 4] = [
+    (
+        "time-locked escrow",
+        "time-locked escrow with 2-of-3 multisig",
+    ),
+    ("hash-locked payment", "hash-locked payment channel"),
+    ("tic-tac-toe game", "tic-tac-toe game with timeout escrow"),
+    ("NFT auction", "NFT auction with bid escrow"),
+];
+
+/// Changes offered after `refine`.
+const REFINEMENTS: [&str; 3] = [
+    "add a timeout claim path",
+    "add a hash lock",
+    "add a 2-of-3 multisig path",
+];
+
+pub struct CovenantSlashCommand;
+
+impl SlashCommand for CovenantSlashCommand {
+    fn name(&self) -> String {
+        "covenant".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("covenant", &["<description> | refine <change>"], cx)
+    }
+
+    fn description(&self) -> String {
+        "Generate an sCrypt covenant, or refine the last one".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::FileCode
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        _workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        let completions = match arguments {
+            [] | [_] => {
+                let query = arguments.first().map_or("", String::as_str);
+                let refine = REFINE.starts_with(query).then(|| ArgumentCompletion {
+                    label: REFINE.into(),
+                    new_text: REFINE.to_string(),
+                    after_completion: AfterCompletion::Continue,
+                    replace_previous_arguments: false,
+                });
+                refine
+                    .into_iter()
+                    .chain(
+                        EXAMPLES
+                            .into_iter()
+                            .map(|(label, text)| ArgumentCompletion {
+                                label: label.into(),
+                                new_text: text.to_string(),
+                                after_completion: AfterCompletion::Run,
+                                replace_previous_arguments: true,
+                            }),
+                    )
+                    .collect()
+            }
+            [first, _] if first == REFINE => REFINEMENTS
+                .into_iter()
+                .map(|text| ArgumentCompletion {
+                    label: text.into(),
+                    new_text: text.to_string(),
+                    after_completion: AfterCompletion::Run,
+                    replace_previous_arguments: false,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        Task::ready(Ok(completions))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        context_buffer: BufferSnapshot,
+        _workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        let (refining, words) = match arguments.split_first() {
+            Some((first, rest)) if first == REFINE => (true, rest),
+            _ => (false, arguments),
+        };
+        let request = words.join(" ");
+        if request.is_empty() {
+            return Task::ready(Err(anyhow!(if refining {
+                "describe the change, e.g. `/covenant refine add a timeout claim path`"
+            } else {
+                "describe the covenant you want, e.g. `/covenant time-locked escrow`"
+            })));
+        }
+        let conversation = refining.then(|| context_buffer.text());
+        cx.background_spawn(async move {
+            let (text, label) = match conversation {
+                Some(conversation) => {
+                    let contract = last_contract(&conversation).ok_or_else(|| {
+                        anyhow!(
+                            "no contract in this conversation yet - run /covenant <description> \
+                             first"
+                        )
+                    })?;
+                    (refine(&contract, &request), format!("Refine: {request}"))
+                }
+                None => (generate(&request), format!("sCrypt Covenant: {request}")),
+            };
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::FileCode,
+                    label: label.into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
@@ -0,0 +1,664 @@
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::{Context as _, Result, bail};
+use gpui::{
+    Action, App, AsyncWindowContext, Context, Entity, EventEmitter, FocusHandle, Focusable, Pixels,
+    Render, SharedString, Task, WeakEntity, Window, actions, px,
+};
+use http_client::HttpClient;
+use project::Project;
+use settings::Settings as _;
+use ui::{
+    Button, ButtonStyle, IconButton, IconName, Label, LabelSize, ListSubHeader, Tab, Tooltip,
+    prelude::*,
+};
+use ui_input::InputField;
+use workspace::{
+    Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
+};
+
+use crate::{
+    artifact::{Artifact, artifact_name, find_artifacts, split_state},
+    bitcoin_project::{BitcoinProject, Deployment, project_root},
+    chain_provider::{self, ChainProviderSettings},
+    utxo_panel::short_txid,
+    whatsonchain::TxOut,
+};
+
+const COVENANT_TIMELINE_PANEL_KEY: &str = "CovenantTimelinePanel";
+/// Chains longer than this are cut short; long-running games can have hundreds of moves.
+const MAX_TRANSITIONS: usize = 200;
+
+actions!(
+    covenant_timeline,
+    [
+        /// Toggles focus on the covenant timeline panel.
+        ToggleFocus
+    ]
+);
+
+/// One state of the contract: the output it lives in and its decoded props.
+struct Transition {
+    txid: String,
+    vout: u32,
+    /// 0 while unconfirmed.
+    height: u64,
+    satoshis: u64,
+    /// `(name, value)` per state prop, or why the state couldn't be decoded
+    /// along with its raw hex.
+    state: Result<Vec<(String, String)>, (String, String)>,
+}
+
+enum ChainEnd {
+    /// The latest state is unspent.
+    Live,
+    /// Spent by a transaction that doesn't continue the contract.
+    Spent(String),
+    Truncated,
+}
+
+struct Timeline {
+    contract: String,
+    explorer_url: String,
+    transitions: Vec<Transition>,
+    end: ChainEnd,
+}
+
+/// Follows a stateful contract from its origin outpoint through every spend,
+/// showing how its state changed at each step.
+pub struct CovenantTimelinePanel {
+    focus_handle: FocusHandle,
+    project: Entity<Project>,
+    http_client: Arc<dyn HttpClient>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    /// Stateful contracts with a compiled artifact.
+    contracts: Vec<String>,
+    deployments: Vec<Deployment>,
+    contract: Option<String>,
+    origin: Entity<InputField>,
+    loading: bool,
+    timeline: Option<Timeline>,
+    error: Option<SharedString>,
+    scan_task: Task<()>,
+    track_task: Task<()>,
+}
+
+impl CovenantTimelinePanel {
+    pub fn load(
+        workspace: WeakEntity<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<Entity<Self>>> {
+        cx.spawn(async move |cx| {
+            workspace.update_in(cx, |workspace, window, cx| Self::new(workspace, window, cx))
+        })
+    }
+
+    fn new(
+        workspace: &mut Workspace,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) -> Entity<Self> {
+        let project = workspace.project().clone();
+        let http_client = cx.http_client();
+        cx.new(|cx| {
+            let origin =
+                cx.new(|cx| InputField::new(window, cx, "txid:vout").label("Origin outpoint"));
+            let mut this = Self {
+                focus_handle: cx.focus_handle(),
+                project,
+                http_client,
+                position: DockPosition::Right,
+                width: None,
+                contracts: Vec::new(),
+                deployments: Vec::new(),
+                contract: None,
+                origin,
+                loading: false,
+                timeline: None,
+                error: None,
+                scan_task: Task::ready(()),
+                track_task: Task::ready(()),
+            };
+            this.scan(cx);
+            this
+        })
+    }
+
+    /// Finds the stateful contracts and their recorded deployments on the
+    /// project's network.
+    fn scan(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            self.contracts.clear();
+            self.deployments.clear();
+            cx.notify();
+            return;
+        };
+        self.scan_task = cx.spawn(async move |this, cx| {
+            let (contracts, deployments) = cx
+                .background_spawn(async move {
+                    let contracts: Vec<String> = find_artifacts(&root)
+                        .into_iter()
+                        .filter(|path| {
+                            Artifact::load(path)
+                                .is_ok_and(|artifact| !artifact.state_props.is_empty())
+                        })
+                        .map(|path| artifact_name(&path))
+                        .collect();
+                    let deployments = BitcoinProject::load(&root)
+                        .ok()
+                        .flatten()
+                        .map(|project| {
+                            project
+                                .deployments
+                                .iter()
+                                .filter(|deployment| {
+                                    deployment.network == project.network.name()
+                                        && contracts.contains(&deployment.contract)
+                                })
+                                .cloned()
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (contracts, deployments)
+                })
+                .await;
+            this.update(cx, |this, cx| {
+                if this
+                    .contract
+                    .as_ref()
+                    .is_none_or(|contract| !contracts.contains(contract))
+                {
+                    this.contract = contracts.first().cloned();
+                }
+                this.contracts = contracts;
+                this.deployments = deployments;
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn track_deployment(
+        &mut self,
+        deployment: Deployment,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let origin = format!("{}:{}", deployment.txid, deployment.vout);
+        self.origin.update(cx, |origin_field, cx| {
+            origin_field.set_text(origin, window, cx)
+        });
+        self.contract = Some(deployment.contract);
+        self.track(cx);
+    }
+
+    fn track(&mut self, cx: &mut Context<Self>) {
+        let prepared = self
+            .project_root(cx)
+            .context("open a project first")
+            .and_then(|root| {
+                let contract = self
+                    .contract
+                    .clone()
+                    .context("choose a stateful contract")?;
+                let origin = parse_outpoint(&self.origin.read(cx).text(cx))?;
+                Ok((root, contract, origin))
+            });
+        let (root, contract, origin) = match prepared {
+            Ok(prepared) => prepared,
+            Err(err) => {
+                self.error = Some(format!("{err:#}").into());
+                cx.notify();
+                return;
+            }
+        };
+
+        let http_client = self.http_client.clone();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        self.loading = true;
+        self.error = None;
+        cx.notify();
+        self.track_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_spawn(follow_chain(http_client, provider, root, contract, origin))
+                .await;
+            this.update(cx, |this, cx| {
+                this.loading = false;
+                match result {
+                    Ok(timeline) => this.timeline = Some(timeline),
+                    Err(err) => {
+                        this.timeline = None;
+                        this.error = Some(format!("{err:#}").into());
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .justify_between()
+            .px_2()
+            .py_1()
+            .h(Tab::container_height(cx))
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(Label::new("Covenant Timeline"))
+            .child(
+                IconButton::new("refresh-timeline", IconName::RotateCw)
+                    .icon_size(IconSize::Small)
+                    .disabled(self.loading || self.timeline.is_none())
+                    .tooltip(Tooltip::text("Refresh"))
+                    .on_click(cx.listener(|this, _, _window, cx| this.track(cx))),
+            )
+    }
+
+    fn render_message(message: impl Into<SharedString>) -> impl IntoElement {
+        div().p_4().child(
+            Label::new(message)
+                .color(Color::Muted)
+                .size(LabelSize::Small),
+        )
+    }
+
+    fn render_setup(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let deployment_buttons = self.deployments.iter().enumerate().map(|(ix, deployment)| {
+            let label = format!("{} {}", deployment.contract, short_txid(&deployment.txid));
+            let deployment = deployment.clone();
+            Button::new(
+                SharedString::from(format!("timeline-deployment-{ix}")),
+                label,
+            )
+            .style(ButtonStyle::Subtle)
+            .label_size(LabelSize::Small)
+            .on_click(cx.listener(move |this, _, window, cx| {
+                this.track_deployment(deployment.clone(), window, cx)
+            }))
+        });
+        let contract_buttons = self.contracts.iter().map(|contract| {
+            let selected = self.contract.as_ref() == Some(contract);
+            let contract = contract.clone();
+            Button::new(
+                SharedString::from(format!("timeline-contract-{contract}")),
+                contract.clone(),
+            )
+            .style(if selected {
+                ButtonStyle::Filled
+            } else {
+                ButtonStyle::Subtle
+            })
+            .label_size(LabelSize::Small)
+            .on_click(cx.listener(move |this, _, _window, cx| {
+                this.contract = Some(contract.clone());
+                cx.notify();
+            }))
+        });
+
+        v_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .when(!self.deployments.is_empty(), |this| {
+                this.child(
+                    Label::new("Deployments")
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                )
+                .child(h_flex().flex_wrap().gap_1().children(deployment_buttons))
+            })
+            .child(h_flex().flex_wrap().gap_1().children(contract_buttons))
+            .child(self.origin.clone())
+            .child(
+                Button::new("timeline-track", "Track")
+                    .style(ButtonStyle::Filled)
+                    .disabled(self.loading)
+                    .on_click(cx.listener(|this, _, _window, cx| this.track(cx))),
+            )
+    }
+
+    fn render_timeline(timeline: &Timeline, cx: &mut Context<Self>) -> impl IntoElement {
+        let mut previous: Option<&Vec<(String, String)>> = None;
+        let entries = timeline
+            .transitions
+            .iter()
+            .enumerate()
+            .map(|(ix, transition)| {
+                let url = format!("{}/tx/{}", timeline.explorer_url, transition.txid);
+                let title = if ix == 0 {
+                    "Deployed".to_string()
+                } else {
+                    format!("Transition {ix}")
+                };
+                let height = match transition.height {
+                    0 => "unconfirmed".to_string(),
+                    height => format!("block {height}"),
+                };
+                let state = match &transition.state {
+                    Ok(props) => {
+                        let changed = |ix: usize, value: &String| {
+                            previous.is_some_and(|previous| {
+                                previous.get(ix).is_some_and(|(_, old)| old != value)
+                            })
+                        };
+                        let rows = props
+                            .iter()
+                            .enumerate()
+                            .map(|(prop_ix, (name, value))| {
+                                h_flex()
+                                    .gap_2()
+                                    .child(
+                                        Label::new(name.clone())
+                                            .size(LabelSize::XSmall)
+                                            .color(Color::Muted)
+                                            .buffer_font(cx),
+                                    )
+                                    .child(
+                                        Label::new(value.clone())
+                                            .size(LabelSize::XSmall)
+                                            .color(if changed(prop_ix, value) {
+                                                Color::Accent
+                                            } else {
+                                                Color::Default
+                                            })
+                                            .buffer_font(cx)
+                                            .truncate(),
+                                    )
+                            })
+                            .collect::<Vec<_>>();
+                        previous = Some(props);
+                        v_flex().children(rows).into_any_element()
+                    }
+                    Err((error, raw)) => {
+                        previous = None;
+                        v_flex()
+                            .child(
+                                Label::new(error.clone())
+                                    .size(LabelSize::XSmall)
+                                    .color(Color::Warning),
+                            )
+                            .child(
+                                Label::new(raw.clone())
+                                    .size(LabelSize::XSmall)
+                                    .buffer_font(cx)
+                                    .truncate(),
+                            )
+                            .into_any_element()
+                    }
+                };
+
+                v_flex()
+                    .id(SharedString::from(format!("timeline-entry-{ix}")))
+                    .pl_3()
+                    .pb_2()
+                    .border_l_2()
+                    .border_color(cx.theme().colors().border)
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Label::new(title).size(LabelSize::Small))
+                            .child(Label::new(height).size(LabelSize::XSmall).color(
+                                if transition.height == 0 {
+                                    Color::Warning
+                                } else {
+                                    Color::Muted
+                                },
+                            )),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(
+                                Label::new(format!(
+                                    "{}:{}",
+                                    short_txid(&transition.txid),
+                                    transition.vout
+                                ))
+                                .size(LabelSize::XSmall)
+                                .buffer_font(cx),
+                            )
+                            .child(
+                                Label::new(format!("{} sats", transition.satoshis))
+                                    .size(LabelSize::XSmall)
+                                    .color(Color::Muted),
+                            )
+                            .child(
+                                IconButton::new(
+                                    SharedString::from(format!("timeline-open-{ix}")),
+                                    IconName::ArrowUpRight,
+                                )
+                                .icon_size(IconSize::XSmall)
+                                .tooltip(Tooltip::text("Open in Explorer"))
+                                .on_click(move |_, _window, cx| cx.open_url(&url)),
+                            ),
+                    )
+                    .child(state)
+            })
+            .collect::<Vec<_>>();
+
+        let end = match &timeline.end {
+            ChainEnd::Live => Label::new("Live - waiting for the next spend")
+                .size(LabelSize::Small)
+                .color(Color::Success)
+                .into_any_element(),
+            ChainEnd::Spent(txid) => {
+                let url = format!("{}/tx/{}", timeline.explorer_url, txid);
+                Button::new(
+                    "timeline-final-spend",
+                    format!("Ended: spent by {}", short_txid(txid)),
+                )
+                .label_size(LabelSize::Small)
+                .on_click(move |_, _window, cx| cx.open_url(&url))
+                .into_any_element()
+            }
+            ChainEnd::Truncated => {
+                Label::new(format!("Stopped after {MAX_TRANSITIONS} transitions"))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted)
+                    .into_any_element()
+            }
+        };
+
+        v_flex()
+            .child(ListSubHeader::new(timeline.contract.clone()).inset(true))
+            .child(v_flex().px_3().children(entries).child(end))
+    }
+}
+
+fn parse_outpoint(text: &str) -> Result<(String, u32)> {
+    let text = text.trim();
+    let (txid, vout) = text.split_once(':').unwrap_or((text, "0"));
+    let txid = txid.trim().to_ascii_lowercase();
+    if txid.len() != 64 || !txid.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("expected an outpoint like <txid>:<vout>");
+    }
+    let vout = vout
+        .trim()
+        .parse()
+        .with_context(|| format!("`{vout}` is not an output index"))?;
+    Ok((txid, vout))
+}
+
+/// Walks the spend chain from `origin`. A spend continues the contract when
+/// its output 0 carries the same code with new state.
+async fn follow_chain(
+    http: Arc<dyn HttpClient>,
+    provider: ChainProviderSettings,
+    root: PathBuf,
+    contract: String,
+    (mut txid, mut vout): (String, u32),
+) -> Result<Timeline> {
+    let project =
+        BitcoinProject::load(&root)?.context("no .env found - create one from .env.testnet")?;
+    let artifact = Artifact::load(
+        &root
+            .join("artifacts")
+            .join("contracts")
+            .join(format!("{contract}.scrypt.json")),
+    )?;
+    let client = chain_provider::for_project(http, &project, &provider)?;
+
+    let mut output = client
+        .output(&txid, vout)
+        .await?
+        .with_context(|| format!("{txid}:{vout} not found on {}", project.network.name()))?;
+    let origin_script = hex::decode(&output.script_hex)?;
+    let (code, _) = split_state(&origin_script)
+        .with_context(|| format!("{txid}:{vout} is not a stateful contract output"))?;
+    let code = code.to_vec();
+
+    let mut transitions = Vec::new();
+    let end = loop {
+        transitions.push(transition(&artifact, txid.clone(), vout, &output));
+        if transitions.len() >= MAX_TRANSITIONS {
+            break ChainEnd::Truncated;
+        }
+        let Some(spend) = client.spent_by(&txid, vout).await? else {
+            break ChainEnd::Live;
+        };
+        let next = client.output(&spend.txid, 0).await?.filter(|next| {
+            hex::decode(&next.script_hex).is_ok_and(|script| {
+                split_state(&script).is_some_and(|(next_code, _)| next_code == code)
+            })
+        });
+        match next {
+            Some(next) => {
+                txid = spend.txid;
+                vout = 0;
+                output = next;
+            }
+            None => break ChainEnd::Spent(spend.txid),
+        }
+    };
+
+    Ok(Timeline {
+        contract,
+        explorer_url: project.explorer_url.clone(),
+        transitions,
+        end,
+    })
+}
+
+fn transition(artifact: &Artifact, txid: String, vout: u32, output: &TxOut) -> Transition {
+    let script = hex::decode(&output.script_hex).unwrap_or_default();
+    let state = match split_state(&script) {
+        Some((_, state)) => artifact
+            .decode_state(state)
+            .map_err(|err| (format!("{err:#}"), hex::encode(state))),
+        None => Err(("no state found".to_string(), output.script_hex.clone())),
+    };
+    Transition {
+        txid,
+        vout,
+        height: output.height,
+        satoshis: output.satoshis,
+        state,
+    }
+}
+
+impl Render for CovenantTimelinePanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let body = if let Some(timeline) = &self.timeline {
+            Self::render_timeline(timeline, cx).into_any_element()
+        } else if self.loading {
+            Self::render_message("Following the spend chain…").into_any_element()
+        } else if self.contracts.is_empty() {
+            Self::render_message(
+                "No stateful contracts found. Compile a contract with @prop(true) properties first.",
+            )
+            .into_any_element()
+        } else {
+            Self::render_message(
+                "Pick a deployment, or enter the outpoint a contract was deployed to.",
+            )
+            .into_any_element()
+        };
+
+        v_flex()
+            .key_context("CovenantTimelinePanel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.render_header(cx))
+            .child(self.render_setup(cx))
+            .children(self.error.clone().map(|error| {
+                div()
+                    .px_2()
+                    .py_1()
+                    .child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            }))
+            .child(
+                v_flex()
+                    .id("covenant-timeline")
+                    .flex_1()
+                    .py_2()
+                    .overflow_y_scroll()
+                    .child(body),
+            )
+    }
+}
+
+impl Focusable for CovenantTimelinePanel {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for CovenantTimelinePanel {}
+
+impl Panel for CovenantTimelinePanel {
+    fn persistent_name() -> &'static str {
+        "CovenantTimelinePanel"
+    }
+
+    fn panel_key() -> &'static str {
+        COVENANT_TIMELINE_PANEL_KEY
+    }
+
+    fn position(&self, _: &Window, _: &App) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, _: DockPosition) -> bool {
+        true
+    }
+
+    fn set_position(&mut self, position: DockPosition, _: &mut Window, cx: &mut Context<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &Window, _: &App) -> Pixels {
+        self.width.unwrap_or(px(320.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, _: &mut Window, cx: &mut Context<Self>) {
+        if active {
+            self.scan(cx);
+        }
+    }
+
+    fn icon(&self, _: &Window, _: &App) -> Option<IconName> {
+        (!self.contracts.is_empty()).then_some(IconName::ListTree)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
+        Some("Covenant Timeline")
+    }
+
+    fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        14
+    }
+}
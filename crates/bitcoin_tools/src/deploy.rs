@@ -0,0 +1,214 @@
+use std::cmp::Reverse;
+
+use anyhow::{Context as _, Result, bail};
+use bitcoin_script::{
+    hash::hash256,
+    keys::PrivateKey,
+    push_data,
+    transaction::{SIGHASH_ALL, SIGHASH_FORKID, Transaction, TxIn, TxOut, p2pkh_script},
+};
+
+use crate::{
+    bitcoin_project::{BitcoinProject, Network},
+    whatsonchain::Unspent,
+};
+
+/// `.env` key holding the WIF key that pays for deployments.
+pub const DEPLOY_KEY: &str = "DEPLOY_PRIVATE_KEY";
+
+/// Upper bound for a signed P2PKH input (uncompressed key, 73-byte signature).
+const P2PKH_INPUT_SIZE: u64 = 180;
+const P2PKH_OUTPUT_SIZE: u64 = 34;
+/// Change below this isn't worth an output - it costs about as much to spend
+/// later as it holds, and some nodes won't relay it - so it goes to the fee.
+const DUST_LIMIT: u64 = 546;
+
+/// The project's deploy key from `.env`, checked against the project's network.
+/// Keys kept in the OS keychain are loaded with [`crate::keychain::load_deploy_key`].
+pub fn deploy_key(project: &BitcoinProject) -> Result<PrivateKey> {
+    let wif = project.setting(DEPLOY_KEY).with_context(|| {
+        format!(
+            "set {DEPLOY_KEY} in .env to a WIF key holding {} coins, or generate one in the Wallet panel",
+            project.network.name()
+        )
+    })?;
+    parse_key(wif, project.network, DEPLOY_KEY)
+}
+
+/// Parses a WIF key for `network`; `source` names where it came from in errors.
+pub fn parse_key(wif: &str, network: Network, source: &str) -> Result<PrivateKey> {
+    let key =
+        PrivateKey::from_wif(wif).with_context(|| format!("{source} is not a valid WIF key"))?;
+    let key_network = if key.is_testnet() {
+        Network::Testnet
+    } else {
+        Network::Mainnet
+    };
+    if key_network != network {
+        bail!(
+            "{source} is a {} key but the project targets {}",
+            key_network.name(),
+            network.name()
+        );
+    }
+    Ok(key)
+}
+
+/// A signed transaction locking `satoshis` in `locking_script` at output 0,
+/// funded from the key's `utxos` (largest first) with change back to the key
+/// unless it would be dust.
+pub fn build_deployment(
+    key: &PrivateKey,
+    utxos: &[Unspent],
+    locking_script: Vec<u8>,
+    satoshis: u64,
+    fee_per_kb: u64,
+) -> Result<Transaction> {
+    let mut utxos = utxos.to_vec();
+    utxos.sort_by_key(|utxo| Reverse(utxo.value));
+
+    let mut tx = Transaction::new();
+    tx.outputs.push(TxOut {
+        satoshis,
+        script: locking_script,
+    });
+    let base_size = tx.serialize().len() as u64;
+    let fee = |inputs: usize, outputs_size: u64| {
+        (base_size + outputs_size + inputs as u64 * P2PKH_INPUT_SIZE) * fee_per_kb / 1000 + 1
+    };
+
+    let mut values = Vec::new();
+    for utxo in &utxos {
+        if values.iter().sum::<u64>() >= satoshis + fee(values.len(), 0) {
+            break;
+        }
+        tx.inputs.push(
+            TxIn::new(&utxo.tx_hash, utxo.tx_pos)
+                .with_context(|| format!("invalid txid {}", utxo.tx_hash))?,
+        );
+        values.push(utxo.value);
+    }
+
+    let total: u64 = values.iter().sum();
+    let needed = satoshis + fee(values.len(), 0);
+    if total < needed {
+        bail!(
+            "{} has {total} satoshis but the deployment needs {needed}",
+            key.address()
+        );
+    }
+    // The change output pays for its own bytes; what's left over otherwise is the fee
+    let change = total.saturating_sub(satoshis + fee(values.len(), P2PKH_OUTPUT_SIZE));
+    let key_script = p2pkh_script(&key.pubkey_hash());
+    if change >= DUST_LIMIT {
+        tx.outputs.push(TxOut {
+            satoshis: change,
+            script: key_script.clone(),
+        });
+    }
+
+    // FORKID sighashes don't cover other inputs' scripts, so inputs can be signed in turn
+    let sighash_type = SIGHASH_ALL | SIGHASH_FORKID;
+    for (ix, value) in values.into_iter().enumerate() {
+        let preimage = tx.sighash_preimage(ix, &key_script, value, sighash_type);
+        let mut signature = key.sign(&hash256(&preimage)).to_der();
+        signature.push(sighash_type);
+        let mut script_sig = push_data(&signature);
+        script_sig.extend(push_data(&key.public_key()));
+        tx.inputs[ix].script_sig = script_sig;
+    }
+    Ok(tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utxo(byte: u8, value: u64) -> Unspent {
+        Unspent {
+            tx_hash: hex::encode([byte; 32]),
+            tx_pos: 0,
+            value,
+            height: 1,
+        }
+    }
+
+    fn fee(tx: &Transaction, funded: u64) -> u64 {
+        funded - tx.outputs.iter().map(|output| output.satoshis).sum::<u64>()
+    }
+
+    #[test]
+    fn test_build_deployment_change() {
+        let key = PrivateKey::from_secret_bytes(&[7; 32], true).unwrap();
+        let locking_script = vec![0x51];
+        let fee_per_kb = 1000;
+
+        // Enough left over for a change output, which pays for its own bytes
+        let tx = build_deployment(
+            &key,
+            &[utxo(1, 10_000)],
+            locking_script.clone(),
+            1,
+            fee_per_kb,
+        )
+        .unwrap();
+        assert_eq!(tx.outputs.len(), 2);
+        assert_eq!(tx.outputs[1].script, p2pkh_script(&key.pubkey_hash()));
+        let size = tx.serialize().len() as u64;
+        assert!(fee(&tx, 10_000) >= size);
+        assert!(fee(&tx, 10_000) <= size + P2PKH_INPUT_SIZE);
+
+        // Change under the dust limit goes to the fee instead
+        let no_change_fee = {
+            let tx = build_deployment(
+                &key,
+                &[utxo(1, 10_000)],
+                locking_script.clone(),
+                1,
+                fee_per_kb,
+            )
+            .unwrap();
+            fee(&tx, 10_000) - P2PKH_OUTPUT_SIZE
+        };
+        let funded = 1 + no_change_fee + P2PKH_OUTPUT_SIZE + DUST_LIMIT - 1;
+        let tx = build_deployment(
+            &key,
+            &[utxo(1, funded)],
+            locking_script.clone(),
+            1,
+            fee_per_kb,
+        )
+        .unwrap();
+        assert_eq!(tx.outputs.len(), 1);
+        assert_eq!(
+            fee(&tx, funded),
+            no_change_fee + P2PKH_OUTPUT_SIZE + DUST_LIMIT - 1
+        );
+
+        // Exactly enough for the contract output and fee, without change
+        let tx = build_deployment(
+            &key,
+            &[utxo(1, 1 + no_change_fee)],
+            locking_script.clone(),
+            1,
+            fee_per_kb,
+        )
+        .unwrap();
+        assert_eq!(tx.outputs.len(), 1);
+
+        // Largest UTXOs first, stopping once the deployment is funded
+        let tx = build_deployment(
+            &key,
+            &[utxo(1, 300), utxo(2, 5_000), utxo(3, 600)],
+            locking_script.clone(),
+            4_000,
+            fee_per_kb,
+        )
+        .unwrap();
+        assert_eq!(tx.inputs.len(), 1);
+
+        let error =
+            build_deployment(&key, &[utxo(1, 300)], locking_script, 1_000, fee_per_kb).unwrap_err();
+        assert!(error.to_string().contains("has 300 satoshis"), "{error}");
+    }
+}
@@ -0,0 +1,583 @@
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::{Context as _, Result};
+use bitcoin_script::keys::PrivateKey;
+use chrono::{SecondsFormat, Utc};
+use credentials_provider::CredentialsProvider;
+use gpui::{
+    Action, App, AsyncWindowContext, ClipboardItem, Context, Entity, EventEmitter, FocusHandle,
+    Focusable, Pixels, PromptLevel, Render, SharedString, Task, WeakEntity, Window, actions, px,
+};
+use http_client::HttpClient;
+use project::Project;
+use settings::Settings as _;
+use ui::{Button, ButtonStyle, IconButton, IconName, Label, LabelSize, Tab, Tooltip, prelude::*};
+use ui_input::InputField;
+use workspace::{
+    Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
+};
+
+use crate::{
+    artifact::{Artifact, artifact_hash, artifact_name, find_artifacts},
+    bitcoin_project::{BitcoinProject, Deployment, DeploymentKind, Network, project_root},
+    chain_provider::{self, ChainProviderSettings},
+    deploy::build_deployment,
+    keychain::load_deploy_key,
+};
+
+const DEPLOY_PANEL_KEY: &str = "DeployPanel";
+/// Locked in the contract output when the amount field is left empty.
+const DEFAULT_SATOSHIS: u64 = 1;
+
+actions!(
+    deploy_panel,
+    [
+        /// Toggles focus on the deploy panel.
+        ToggleFocus
+    ]
+);
+
+struct SelectedArtifact {
+    path: PathBuf,
+    artifact: Artifact,
+    /// One field per constructor parameter, in ABI order.
+    args: Vec<Entity<InputField>>,
+}
+
+/// A deployment checked and ready to fund, sign and broadcast.
+struct PreparedDeploy {
+    project: BitcoinProject,
+    contract: String,
+    locking_script: Vec<u8>,
+    satoshis: u64,
+    args: Vec<String>,
+    artifact_hash: Option<String>,
+}
+
+enum DeployStatus {
+    Idle,
+    Deploying,
+    Deployed {
+        contract: String,
+        txid: String,
+        url: String,
+        provider: &'static str,
+    },
+    Failed(SharedString),
+}
+
+/// Deploys a compiled contract with the project's deploy key (from the OS
+/// keychain or `DEPLOY_PRIVATE_KEY`), doing in the editor what the scaffold's
+/// `npm run deploy` scripts do.
+pub struct DeployPanel {
+    focus_handle: FocusHandle,
+    project: Entity<Project>,
+    http_client: Arc<dyn HttpClient>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    artifacts: Vec<PathBuf>,
+    selected: Option<SelectedArtifact>,
+    satoshis: Entity<InputField>,
+    status: DeployStatus,
+    scan_task: Task<()>,
+    deploy_task: Task<()>,
+}
+
+impl DeployPanel {
+    pub fn load(
+        workspace: WeakEntity<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<Entity<Self>>> {
+        cx.spawn(async move |cx| {
+            workspace.update_in(cx, |workspace, window, cx| Self::new(workspace, window, cx))
+        })
+    }
+
+    fn new(
+        workspace: &mut Workspace,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) -> Entity<Self> {
+        let project = workspace.project().clone();
+        let http_client = cx.http_client();
+        cx.new(|cx| {
+            let satoshis = cx.new(|cx| {
+                InputField::new(window, cx, DEFAULT_SATOSHIS.to_string())
+                    .label("Satoshis locked in the contract")
+            });
+            let mut this = Self {
+                focus_handle: cx.focus_handle(),
+                project,
+                http_client,
+                position: DockPosition::Right,
+                width: None,
+                artifacts: Vec::new(),
+                selected: None,
+                satoshis,
+                status: DeployStatus::Idle,
+                scan_task: Task::ready(()),
+                deploy_task: Task::ready(()),
+            };
+            this.scan_artifacts(cx);
+            this
+        })
+    }
+
+    fn scan_artifacts(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            self.artifacts.clear();
+            cx.notify();
+            return;
+        };
+        self.scan_task = cx.spawn(async move |this, cx| {
+            let artifacts = cx
+                .background_spawn(async move { find_artifacts(&root) })
+                .await;
+            this.update(cx, |this, cx| {
+                this.artifacts = artifacts;
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn select_artifact(&mut self, path: PathBuf, window: &mut Window, cx: &mut Context<Self>) {
+        match Artifact::load(&path) {
+            Ok(artifact) => {
+                let args = artifact
+                    .constructor_params()
+                    .iter()
+                    .map(|param| {
+                        let label = format!("{}: {}", param.name, param.ty);
+                        cx.new(|cx| InputField::new(window, cx, param.ty.clone()).label(label))
+                    })
+                    .collect();
+                self.selected = Some(SelectedArtifact {
+                    path,
+                    artifact,
+                    args,
+                });
+                self.status = DeployStatus::Idle;
+            }
+            Err(err) => {
+                self.selected = None;
+                self.status = DeployStatus::Failed(format!("{err:#}").into());
+            }
+        }
+        cx.notify();
+    }
+
+    fn deploy(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if matches!(self.status, DeployStatus::Deploying) {
+            return;
+        }
+        let prepared = match self.prepare(cx) {
+            Ok(prepared) => prepared,
+            Err(err) => {
+                self.status = DeployStatus::Failed(format!("{err:#}").into());
+                cx.notify();
+                return;
+            }
+        };
+
+        let contract = prepared.contract.clone();
+        let confirmation = (prepared.project.network == Network::Mainnet).then(|| {
+            window.prompt(
+                PromptLevel::Warning,
+                &format!("Deploy {contract} to mainnet?"),
+                Some("This spends real coins from the deploy key."),
+                &["Deploy", "Cancel"],
+                cx,
+            )
+        });
+        let http_client = self.http_client.clone();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        let credentials = <dyn CredentialsProvider>::global(cx);
+        self.deploy_task = cx.spawn(async move |this, cx| {
+            if let Some(confirmation) = confirmation {
+                if confirmation.await != Ok(0) {
+                    return;
+                }
+            }
+            this.update(cx, |this, cx| {
+                this.status = DeployStatus::Deploying;
+                cx.notify();
+            })
+            .ok();
+
+            let result = match load_deploy_key(&prepared.project, credentials.as_ref(), cx).await {
+                Ok(key) => {
+                    cx.background_spawn(deploy(http_client, provider, prepared, key))
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+            this.update(cx, |this, cx| {
+                this.status = match result {
+                    Ok((txid, url, provider)) => DeployStatus::Deployed {
+                        contract,
+                        txid,
+                        url,
+                        provider,
+                    },
+                    Err(err) => DeployStatus::Failed(format!("{err:#}").into()),
+                };
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    /// Validates the form: the project settings, the locking script and the amount.
+    fn prepare(&self, cx: &App) -> Result<PreparedDeploy> {
+        let root = project_root(&self.project, cx).context("open a project first")?;
+        let project =
+            BitcoinProject::load(&root)?.context("no .env found - create one from .env.testnet")?;
+        let selected = self.selected.as_ref().context("choose an artifact first")?;
+        let args: Vec<String> = selected
+            .args
+            .iter()
+            .map(|field| field.read(cx).text(cx).trim().to_string())
+            .collect();
+        let locking_script = selected.artifact.locking_script(&args)?;
+
+        let satoshis = self.satoshis.read(cx).text(cx);
+        let satoshis = match satoshis.trim() {
+            "" => DEFAULT_SATOSHIS,
+            text => text
+                .replace('_', "")
+                .parse()
+                .context("satoshis must be a whole number")?,
+        };
+        Ok(PreparedDeploy {
+            project,
+            contract: selected.artifact.contract.clone(),
+            locking_script,
+            satoshis,
+            args,
+            artifact_hash: artifact_hash(&selected.path).ok(),
+        })
+    }
+
+    /// Fills the form in from a recorded deployment and deploys it again. A
+    /// deployment recorded without its constructor arguments is only filled in.
+    pub(crate) fn redeploy(
+        &mut self,
+        deployment: &Deployment,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(root) = project_root(&self.project, cx) else {
+            return;
+        };
+        self.artifacts = find_artifacts(&root);
+        let Some(path) = self
+            .artifacts
+            .iter()
+            .find(|path| artifact_name(path) == deployment.contract)
+            .cloned()
+        else {
+            self.status = DeployStatus::Failed(
+                format!("No artifact for {} - compile it first", deployment.contract).into(),
+            );
+            cx.notify();
+            return;
+        };
+        self.select_artifact(path.clone(), window, cx);
+        let Some(selected) = &self.selected else {
+            return;
+        };
+
+        let satoshis = deployment.satoshis.map(|satoshis| satoshis.to_string());
+        self.satoshis.update(cx, |input, cx| {
+            input.set_text(satoshis.unwrap_or_default(), window, cx)
+        });
+        if selected.args.len() != deployment.args.len() {
+            self.status = DeployStatus::Failed(
+                format!(
+                    "{} takes {} constructor arguments, but {} were recorded - fill them in and deploy",
+                    deployment.contract,
+                    selected.args.len(),
+                    deployment.args.len()
+                )
+                .into(),
+            );
+            cx.notify();
+            return;
+        }
+        for (field, arg) in selected.args.iter().zip(&deployment.args) {
+            field.update(cx, |input, cx| input.set_text(arg.as_str(), window, cx));
+        }
+
+        let recompiled = deployment
+            .artifact_hash
+            .as_ref()
+            .is_some_and(|hash| artifact_hash(&path).ok().as_ref() != Some(hash));
+        if !recompiled {
+            self.deploy(window, cx);
+            return;
+        }
+        let confirmation = window.prompt(
+            PromptLevel::Info,
+            &format!(
+                "{} was recompiled since this deployment",
+                deployment.contract
+            ),
+            Some("Deploy the current build with the same arguments?"),
+            &["Deploy", "Cancel"],
+            cx,
+        );
+        cx.spawn_in(window, async move |this, cx| {
+            if confirmation.await == Ok(0) {
+                this.update_in(cx, |this, window, cx| this.deploy(window, cx))
+                    .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .justify_between()
+            .px_2()
+            .py_1()
+            .h(Tab::container_height(cx))
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(Label::new("Deploy"))
+            .child(
+                IconButton::new("deploy-rescan", IconName::RotateCw)
+                    .icon_size(IconSize::Small)
+                    .tooltip(Tooltip::text("Rescan Artifacts"))
+                    .on_click(cx.listener(|this, _, _window, cx| this.scan_artifacts(cx))),
+            )
+    }
+
+    fn render_form(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let selected_path = self.selected.as_ref().map(|selected| &selected.path);
+        let artifact_buttons = self.artifacts.iter().enumerate().map(|(ix, path)| {
+            let selected = selected_path == Some(path);
+            let path = path.clone();
+            Button::new(
+                SharedString::from(format!("deploy-artifact-{ix}")),
+                artifact_name(&path),
+            )
+            .style(if selected {
+                ButtonStyle::Filled
+            } else {
+                ButtonStyle::Subtle
+            })
+            .label_size(LabelSize::Small)
+            .on_click(cx.listener(move |this, _, window, cx| {
+                this.select_artifact(path.clone(), window, cx)
+            }))
+        });
+        let deploying = matches!(self.status, DeployStatus::Deploying);
+
+        v_flex()
+            .p_2()
+            .gap_2()
+            .map(|this| {
+                if self.artifacts.is_empty() {
+                    this.child(
+                        Label::new("No artifacts found - run `npx scrypt-cli compile`.")
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                } else {
+                    this.child(h_flex().flex_wrap().gap_1().children(artifact_buttons))
+                }
+            })
+            .when_some(self.selected.as_ref(), |this, selected| {
+                this.when(selected.args.is_empty(), |this| {
+                    this.child(
+                        Label::new("No constructor arguments")
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                })
+                .children(selected.args.iter().cloned())
+                .child(self.satoshis.clone())
+                .child(
+                    Button::new("deploy-contract", "Deploy")
+                        .style(ButtonStyle::Filled)
+                        .icon(IconName::ArrowUpRight)
+                        .icon_position(IconPosition::Start)
+                        .icon_size(IconSize::Small)
+                        .disabled(deploying)
+                        .on_click(cx.listener(|this, _, window, cx| this.deploy(window, cx))),
+                )
+            })
+    }
+
+    fn render_status(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let container = v_flex().px_2().py_1().gap_1();
+        match &self.status {
+            DeployStatus::Idle => container,
+            DeployStatus::Deploying => container.child(
+                Label::new("Funding, signing and broadcasting…")
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            ),
+            DeployStatus::Failed(error) => container.child(
+                Label::new(error.clone())
+                    .size(LabelSize::Small)
+                    .color(Color::Error),
+            ),
+            DeployStatus::Deployed {
+                contract,
+                txid,
+                url,
+                provider,
+            } => {
+                let copied_txid = txid.clone();
+                let url = url.clone();
+                container
+                    .child(
+                        Label::new(format!("Deployed {contract} via {provider}"))
+                            .size(LabelSize::Small)
+                            .color(Color::Success),
+                    )
+                    .child(
+                        Label::new(txid.clone())
+                            .size(LabelSize::XSmall)
+                            .buffer_font(cx),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(
+                                Button::new("deploy-open-explorer", "Open in Explorer")
+                                    .label_size(LabelSize::Small)
+                                    .on_click(move |_, _window, cx| cx.open_url(&url)),
+                            )
+                            .child(
+                                Button::new("deploy-copy-txid", "Copy txid")
+                                    .label_size(LabelSize::Small)
+                                    .on_click(move |_, _window, cx| {
+                                        cx.write_to_clipboard(ClipboardItem::new_string(
+                                            copied_txid.clone(),
+                                        ))
+                                    }),
+                            ),
+                    )
+            }
+        }
+    }
+}
+
+/// Funds and signs the deployment, broadcasts it and records it in the
+/// deployment history. Returns the txid, its explorer link and the provider
+/// that accepted it.
+async fn deploy(
+    http: Arc<dyn HttpClient>,
+    provider: ChainProviderSettings,
+    prepared: PreparedDeploy,
+    key: PrivateKey,
+) -> Result<(String, String, &'static str)> {
+    let PreparedDeploy {
+        mut project,
+        contract,
+        locking_script,
+        satoshis,
+        args,
+        artifact_hash,
+    } = prepared;
+    let client = chain_provider::for_project(http, &project, &provider)?;
+    let address = key.address();
+    let utxos = client.address_unspent(&address).await?;
+    let tx = build_deployment(&key, &utxos, locking_script, satoshis, project.fee_per_kb())?;
+    let broadcast = client.broadcast(&tx.serialize()).await?;
+    let txid = broadcast.txid;
+
+    let url = project.tx_url(&txid);
+    project.record_deployment(Deployment {
+        kind: DeploymentKind::Deploy,
+        contract,
+        method: None,
+        txid: txid.clone(),
+        vout: 0,
+        network: project.network.name().to_string(),
+        deployer: Some(address),
+        timestamp: Some(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+        artifact_hash,
+        args,
+        satoshis: Some(satoshis),
+    })?;
+    Ok((txid, url, broadcast.provider.name()))
+}
+
+impl Render for DeployPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .key_context("DeployPanel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.render_header(cx))
+            .child(self.render_form(cx))
+            .child(self.render_status(cx))
+    }
+}
+
+impl Focusable for DeployPanel {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for DeployPanel {}
+
+impl Panel for DeployPanel {
+    fn persistent_name() -> &'static str {
+        "DeployPanel"
+    }
+
+    fn panel_key() -> &'static str {
+        DEPLOY_PANEL_KEY
+    }
+
+    fn position(&self, _: &Window, _: &App) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, _: DockPosition) -> bool {
+        true
+    }
+
+    fn set_position(&mut self, position: DockPosition, _: &mut Window, cx: &mut Context<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &Window, _: &App) -> Pixels {
+        self.width.unwrap_or(px(320.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, _: &mut Window, cx: &mut Context<Self>) {
+        if active {
+            self.scan_artifacts(cx);
+        }
+    }
+
+    fn icon(&self, _: &Window, _: &App) -> Option<IconName> {
+        (!self.artifacts.is_empty()).then_some(IconName::ArrowUpRight)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
+        Some("Deploy")
+    }
+
+    fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        12
+    }
+}
@@ -0,0 +1,565 @@
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use anyhow::{Context as _, Result, bail};
+use gpui::{
+    Action, App, AsyncWindowContext, ClipboardItem, Context, Entity, EventEmitter, FocusHandle,
+    Focusable, Pixels, Render, SharedString, Task, WeakEntity, Window, actions, px,
+};
+use http_client::HttpClient;
+use project::Project;
+use settings::Settings as _;
+use ui::{
+    IconButton, IconName, Label, LabelSize, ListItem, ListItemSpacing, Tab, Tooltip, prelude::*,
+};
+use workspace::{
+    Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
+};
+
+use crate::{
+    DeployPanel,
+    bitcoin_project::{BitcoinProject, DEPLOYMENTS_PATH, Deployment, DeploymentKind, project_root},
+    chain_provider::{self, ChainProvider, ChainProviderSettings},
+    utxo_panel::short_txid,
+    verify::{Verdict, compare_scripts, local_script},
+};
+
+const DEPLOYMENT_HISTORY_PANEL_KEY: &str = "DeploymentHistoryPanel";
+/// Entries whose state is looked up on each refresh, newest first; older
+/// ones are listed without it.
+const MAX_CHECKED_ENTRIES: usize = 20;
+
+actions!(
+    deployment_history,
+    [
+        /// Toggles focus on the deployment history panel.
+        ToggleFocus
+    ]
+);
+
+#[derive(Clone, Debug)]
+enum EntryState {
+    NotFound,
+    Unconfirmed,
+    Confirmed(u64),
+    /// The contract output was spent, by this transaction.
+    Spent(String),
+    Unchecked,
+}
+
+/// Where checking a deploy against the local source stands.
+#[derive(Clone, Debug)]
+enum Verification {
+    Running,
+    Done(Verdict),
+    Failed(SharedString),
+}
+
+struct Snapshot {
+    network: &'static str,
+    explorer_url: String,
+    /// Newest first.
+    entries: Vec<(Deployment, EntryState)>,
+}
+
+/// Every deploy and contract call recorded in `.utxix/deployments.json`, with
+/// where each one stands on chain and a way to deploy it again.
+pub struct DeploymentHistoryPanel {
+    focus_handle: FocusHandle,
+    workspace: WeakEntity<Workspace>,
+    project: Entity<Project>,
+    http_client: Arc<dyn HttpClient>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    is_bitcoin_project: bool,
+    loading: bool,
+    snapshot: Option<Snapshot>,
+    error: Option<SharedString>,
+    /// Keyed by the deploy's txid.
+    verifications: HashMap<String, Verification>,
+    refresh_task: Task<()>,
+    verify_task: Task<()>,
+}
+
+impl DeploymentHistoryPanel {
+    pub fn load(
+        workspace: WeakEntity<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<Entity<Self>>> {
+        cx.spawn(async move |cx| {
+            workspace.update_in(cx, |workspace, _window, cx| Self::new(workspace, cx))
+        })
+    }
+
+    fn new(workspace: &mut Workspace, cx: &mut Context<Workspace>) -> Entity<Self> {
+        let project = workspace.project().clone();
+        let http_client = cx.http_client();
+        let workspace = workspace.weak_handle();
+
+        cx.new(|cx| {
+            // Deploy scripts and the Deploy panel append to the history
+            cx.subscribe(&project, |this: &mut Self, _, event, cx| {
+                if let project::Event::WorktreeUpdatedEntries(_, entries) = event {
+                    if entries
+                        .iter()
+                        .any(|(path, _, _)| path.as_unix_str() == DEPLOYMENTS_PATH)
+                    {
+                        this.refresh(cx);
+                    }
+                }
+            })
+            .detach();
+
+            let mut this = Self {
+                focus_handle: cx.focus_handle(),
+                workspace,
+                project,
+                http_client,
+                position: DockPosition::Right,
+                width: None,
+                is_bitcoin_project: false,
+                loading: false,
+                snapshot: None,
+                error: None,
+                verifications: HashMap::default(),
+                refresh_task: Task::ready(()),
+                verify_task: Task::ready(()),
+            };
+            this.refresh(cx);
+            this
+        })
+    }
+
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            self.is_bitcoin_project = false;
+            self.snapshot = None;
+            cx.notify();
+            return;
+        };
+
+        let client = self.http_client.clone();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        self.loading = true;
+        cx.notify();
+
+        self.refresh_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_spawn(async move { fetch_snapshot(&root, client, &provider).await })
+                .await;
+            this.update(cx, |this, cx| {
+                this.loading = false;
+                match result {
+                    Ok(snapshot) => {
+                        this.is_bitcoin_project = snapshot.is_some();
+                        this.snapshot = snapshot;
+                        this.error = None;
+                    }
+                    Err(err) => {
+                        log::error!("deployment history refresh failed: {err:?}");
+                        this.error = Some(format!("{err:#}").into());
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn redeploy(&mut self, deployment: &Deployment, window: &mut Window, cx: &mut Context<Self>) {
+        self.workspace
+            .update(cx, |workspace, cx| {
+                if let Some(panel) = workspace.focus_panel::<DeployPanel>(window, cx) {
+                    panel.update(cx, |panel, cx| panel.redeploy(deployment, window, cx));
+                }
+            })
+            .ok();
+    }
+
+    /// Recompiles the contracts and checks that `deployment`'s output holds
+    /// the script the local source builds with the recorded arguments.
+    fn verify(&mut self, deployment: &Deployment, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            return;
+        };
+        let client = self.http_client.clone();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        let deployment = deployment.clone();
+        let txid = deployment.txid.clone();
+        self.verifications
+            .insert(txid.clone(), Verification::Running);
+        cx.notify();
+
+        self.verify_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_spawn(async move {
+                    verify_deployment(&root, &deployment, client, &provider).await
+                })
+                .await;
+            this.update(cx, |this, cx| {
+                let verification = match result {
+                    Ok(verdict) => Verification::Done(verdict),
+                    Err(err) => Verification::Failed(format!("{err:#}").into()),
+                };
+                this.verifications.insert(txid, verification);
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let network = self.snapshot.as_ref().map(|snapshot| snapshot.network);
+
+        h_flex()
+            .justify_between()
+            .px_2()
+            .py_1()
+            .h(Tab::container_height(cx))
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new("Deployments"))
+                    .children(network.map(|network| {
+                        Label::new(network)
+                            .size(LabelSize::Small)
+                            .color(Color::Muted)
+                    })),
+            )
+            .child(
+                IconButton::new("refresh-deployment-history", IconName::RotateCw)
+                    .icon_size(IconSize::Small)
+                    .disabled(self.loading)
+                    .tooltip(Tooltip::text("Refresh"))
+                    .on_click(cx.listener(|this, _, _window, cx| this.refresh(cx))),
+            )
+    }
+
+    fn render_message(message: impl Into<SharedString>) -> impl IntoElement {
+        div().p_4().child(
+            Label::new(message)
+                .color(Color::Muted)
+                .size(LabelSize::Small),
+        )
+    }
+
+    fn render_entry(
+        &self,
+        ix: usize,
+        deployment: &Deployment,
+        state: &EntryState,
+        explorer_url: &str,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let title = match (deployment.kind, &deployment.method) {
+            (DeploymentKind::Call, Some(method)) => format!("{}.{method}()", deployment.contract),
+            (DeploymentKind::Call, None) => format!("{} call", deployment.contract),
+            (DeploymentKind::Deploy, _) => deployment.contract.clone(),
+        };
+        let date = deployment
+            .timestamp
+            .as_deref()
+            .map(|timestamp| format!(" · {}", timestamp.split('T').next().unwrap_or(timestamp)))
+            .unwrap_or_default();
+        let (state_label, state_color) = match state {
+            EntryState::NotFound => ("not found".to_string(), Color::Error),
+            EntryState::Unconfirmed => ("unconfirmed".to_string(), Color::Warning),
+            EntryState::Confirmed(1) => ("1 conf".to_string(), Color::Muted),
+            EntryState::Confirmed(n) => (format!("{n} conf"), Color::Muted),
+            EntryState::Spent(txid) => (format!("spent in {}", short_txid(txid)), Color::Muted),
+            EntryState::Unchecked => (String::new(), Color::Muted),
+        };
+        let url = format!("{explorer_url}/tx/{}", deployment.txid);
+        let txid = deployment.txid.clone();
+        let redeploy = (deployment.kind == DeploymentKind::Deploy).then(|| {
+            let deployment = deployment.clone();
+            IconButton::new(
+                SharedString::from(format!("redeploy-{ix}")),
+                IconName::RotateCw,
+            )
+            .icon_size(IconSize::Small)
+            .tooltip(Tooltip::text("Redeploy with Same Arguments"))
+            .on_click(
+                cx.listener(move |this, _, window, cx| this.redeploy(&deployment, window, cx)),
+            )
+        });
+        let verifying = self
+            .verifications
+            .values()
+            .any(|verification| matches!(verification, Verification::Running));
+        let verify = (deployment.kind == DeploymentKind::Deploy).then(|| {
+            let deployment = deployment.clone();
+            IconButton::new(
+                SharedString::from(format!("verify-deployment-{ix}")),
+                IconName::ShieldCheck,
+            )
+            .icon_size(IconSize::Small)
+            .disabled(verifying)
+            .tooltip(Tooltip::text("Verify Against Local Source"))
+            .on_click(cx.listener(move |this, _, _window, cx| this.verify(&deployment, cx)))
+        });
+        let verification = self
+            .verifications
+            .get(&deployment.txid)
+            .map(|verification| match verification {
+                Verification::Running => Label::new("verifying…")
+                    .size(LabelSize::Small)
+                    .color(Color::Muted)
+                    .into_any_element(),
+                Verification::Done(Verdict::Match) => Label::new("source verified")
+                    .size(LabelSize::Small)
+                    .color(Color::Success)
+                    .into_any_element(),
+                Verification::Done(Verdict::StateDiffers) => Label::new("code verified")
+                    .size(LabelSize::Small)
+                    .color(Color::Success)
+                    .into_any_element(),
+                Verification::Done(Verdict::Mismatch { offset }) => {
+                    Label::new(format!("differs at byte {offset}"))
+                        .size(LabelSize::Small)
+                        .color(Color::Error)
+                        .into_any_element()
+                }
+                Verification::Failed(err) => div()
+                    .id(SharedString::from(format!("verify-error-{ix}")))
+                    .child(
+                        Label::new("not verified")
+                            .size(LabelSize::Small)
+                            .color(Color::Error),
+                    )
+                    .tooltip(Tooltip::text(err.clone()))
+                    .into_any_element(),
+            });
+
+        ListItem::new(SharedString::from(format!("deployment-{ix}")))
+            .spacing(ListItemSpacing::Sparse)
+            .inset(true)
+            .child(
+                v_flex()
+                    .child(Label::new(title).size(LabelSize::Small))
+                    .child(
+                        Label::new(format!("{}{date}", short_txid(&deployment.txid)))
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted),
+                    ),
+            )
+            .end_slot(
+                h_flex()
+                    .gap_1()
+                    .children(verification)
+                    .child(
+                        Label::new(state_label)
+                            .size(LabelSize::Small)
+                            .color(state_color),
+                    )
+                    .child(
+                        IconButton::new(
+                            SharedString::from(format!("copy-deployment-txid-{ix}")),
+                            IconName::Copy,
+                        )
+                        .icon_size(IconSize::Small)
+                        .tooltip(Tooltip::text("Copy txid"))
+                        .on_click(move |_, _window, cx| {
+                            cx.write_to_clipboard(ClipboardItem::new_string(txid.clone()))
+                        }),
+                    )
+                    .children(verify)
+                    .children(redeploy),
+            )
+            .tooltip(Tooltip::text("Open in explorer"))
+            .on_click(move |_, _window, cx| cx.open_url(&url))
+    }
+}
+
+async fn fetch_snapshot(
+    root: &Path,
+    http: Arc<dyn HttpClient>,
+    provider: &ChainProviderSettings,
+) -> Result<Option<Snapshot>> {
+    let Some(project) = BitcoinProject::load(root)? else {
+        return Ok(None);
+    };
+    let client = chain_provider::for_project(http, &project, provider)?;
+
+    let mut entries = Vec::new();
+    for (ix, deployment) in project.history.iter().rev().enumerate() {
+        let state = if ix < MAX_CHECKED_ENTRIES {
+            entry_state(client.as_ref(), deployment).await?
+        } else {
+            EntryState::Unchecked
+        };
+        entries.push((deployment.clone(), state));
+    }
+    Ok(Some(Snapshot {
+        network: project.network.name(),
+        explorer_url: project.explorer_url.clone(),
+        entries,
+    }))
+}
+
+async fn entry_state(client: &dyn ChainProvider, deployment: &Deployment) -> Result<EntryState> {
+    let Some(status) = client.tx_status(&deployment.txid).await? else {
+        return Ok(EntryState::NotFound);
+    };
+    if deployment.kind == DeploymentKind::Deploy {
+        if let Some(spend) = client.spent_by(&deployment.txid, deployment.vout).await? {
+            return Ok(EntryState::Spent(spend.txid));
+        }
+    }
+    Ok(match status.confirmations {
+        0 => EntryState::Unconfirmed,
+        n => EntryState::Confirmed(n),
+    })
+}
+
+/// Compiles the project's contracts with the sCrypt CLI, so the check runs
+/// against the source rather than whatever artifact was last built.
+async fn compile_contracts(root: &Path) -> Result<()> {
+    let output = util::command::new_smol_command("npx")
+        .args(["scrypt-cli", "compile"])
+        .current_dir(root)
+        .output()
+        .await
+        .context("running `npx scrypt-cli compile`")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("`npx scrypt-cli compile` failed: {}", stderr.trim());
+    }
+    Ok(())
+}
+
+async fn verify_deployment(
+    root: &Path,
+    deployment: &Deployment,
+    http: Arc<dyn HttpClient>,
+    provider: &ChainProviderSettings,
+) -> Result<Verdict> {
+    let Some(project) = BitcoinProject::load(root)? else {
+        bail!("no .env found in the project root");
+    };
+    compile_contracts(root).await?;
+    let local = local_script(root, deployment)?;
+    let client = chain_provider::for_project(http, &project, provider)?;
+    let output = client
+        .output(&deployment.txid, deployment.vout)
+        .await?
+        .with_context(|| format!("{}:{} not found", deployment.txid, deployment.vout))?;
+    let on_chain = hex::decode(&output.script_hex).context("invalid script hex")?;
+    Ok(compare_scripts(&local, &on_chain))
+}
+
+impl Render for DeploymentHistoryPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let body = if !self.is_bitcoin_project {
+            if self.loading {
+                Self::render_message("Loading deployments…").into_any_element()
+            } else {
+                Self::render_message(
+                    "No Bitcoin project found. The panel reads .env and .utxix/deployments.json from the project root.",
+                )
+                .into_any_element()
+            }
+        } else if let Some(snapshot) = &self.snapshot {
+            let explorer_url = snapshot.explorer_url.clone();
+            let rows: Vec<_> = snapshot
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(ix, (deployment, state))| {
+                    self.render_entry(ix, deployment, state, &explorer_url, cx)
+                        .into_any_element()
+                })
+                .collect();
+            v_flex()
+                .id("deployment-history")
+                .flex_1()
+                .overflow_y_scroll()
+                .when(rows.is_empty(), |this| {
+                    this.child(Self::render_message(
+                        "Nothing deployed yet - use the Deploy panel or run `npm run deploy`.",
+                    ))
+                })
+                .children(rows)
+                .into_any_element()
+        } else {
+            Self::render_message("Loading deployments…").into_any_element()
+        };
+
+        v_flex()
+            .key_context("DeploymentHistoryPanel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.render_header(cx))
+            .children(self.error.clone().map(|error| {
+                div()
+                    .px_2()
+                    .py_1()
+                    .child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            }))
+            .child(body)
+    }
+}
+
+impl Focusable for DeploymentHistoryPanel {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for DeploymentHistoryPanel {}
+
+impl Panel for DeploymentHistoryPanel {
+    fn persistent_name() -> &'static str {
+        "DeploymentHistoryPanel"
+    }
+
+    fn panel_key() -> &'static str {
+        DEPLOYMENT_HISTORY_PANEL_KEY
+    }
+
+    fn position(&self, _: &Window, _: &App) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, position: DockPosition) -> bool {
+        matches!(position, DockPosition::Left | DockPosition::Right)
+    }
+
+    fn set_position(&mut self, position: DockPosition, _: &mut Window, cx: &mut Context<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &Window, _: &App) -> Pixels {
+        self.width.unwrap_or(px(320.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, _: &mut Window, cx: &mut Context<Self>) {
+        if active {
+            self.refresh(cx);
+        }
+    }
+
+    fn icon(&self, _: &Window, _: &App) -> Option<IconName> {
+        self.is_bitcoin_project.then_some(IconName::HistoryRerun)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
+        Some("Deployment History")
+    }
+
+    fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        15
+    }
+}
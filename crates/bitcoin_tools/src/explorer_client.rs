@@ -0,0 +1,350 @@
+//! The way the tools reach explorer and broadcast APIs. Responses to GETs are
+//! cached for a while and shared between panels, requests to each host are
+//! spaced out to stay under its rate limit, and while a host can't be
+//! reached, cached responses are served however old they are.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context as _, Result};
+use futures::AsyncReadExt;
+use http_client::{
+    AsyncBody, HttpClient, HttpRequestExt as _, Method, RedirectPolicy, Request, Response,
+    StatusCode,
+};
+
+/// WhatsOnChain allows 3 requests a second without an API key.
+const WHATSONCHAIN_INTERVAL: Duration = Duration::from_millis(350);
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+/// How long a host that failed is treated as offline before it's tried again.
+const OFFLINE_RETRY: Duration = Duration::from_secs(30);
+/// Waits after a 429 without a `Retry-After`, doubling with each attempt.
+const RATE_LIMITED_DELAY: Duration = Duration::from_secs(1);
+const MAX_ATTEMPTS: u32 = 3;
+const MAX_CACHE_ENTRIES: usize = 1_000;
+
+/// How long a response stays current.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Freshness {
+    /// Changes with every block or broadcast: balances, unspent outputs,
+    /// confirmations.
+    Live,
+    /// Doesn't change once it exists, like a block header.
+    Settled,
+}
+
+impl Freshness {
+    fn ttl(self) -> Duration {
+        match self {
+            Freshness::Live => Duration::from_secs(10),
+            Freshness::Settled => Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+struct CacheEntry {
+    body: Arc<[u8]>,
+    fetched_at: Instant,
+    freshness: Freshness,
+}
+
+#[derive(Default)]
+struct HostState {
+    /// When the next request to the host may go out.
+    next_request: Option<Instant>,
+    offline_until: Option<Instant>,
+}
+
+/// Shared by every client, so panels refreshing the same address reuse one
+/// response and count against one rate limit.
+#[derive(Default)]
+struct State {
+    cache: HashMap<String, CacheEntry>,
+    hosts: HashMap<String, HostState>,
+}
+
+impl State {
+    /// The cached body for `url`: while it's current, or whenever its host is
+    /// offline.
+    fn cached(&self, url: &str, now: Instant) -> Option<Arc<[u8]>> {
+        let entry = self.cache.get(url)?;
+        let current = now.duration_since(entry.fetched_at) < entry.freshness.ttl();
+        (current || self.is_offline(host_of(url), now)).then(|| entry.body.clone())
+    }
+
+    fn store(&mut self, url: &str, body: Arc<[u8]>, freshness: Freshness, now: Instant) {
+        if self.cache.len() >= MAX_CACHE_ENTRIES && !self.cache.contains_key(url) {
+            let oldest = self
+                .cache
+                .iter()
+                .min_by_key(|(_, entry)| entry.fetched_at)
+                .map(|(url, _)| url.clone());
+            if let Some(oldest) = oldest {
+                self.cache.remove(&oldest);
+            }
+        }
+        self.cache.insert(
+            url.to_string(),
+            CacheEntry {
+                body,
+                fetched_at: now,
+                freshness,
+            },
+        );
+    }
+
+    fn is_offline(&self, host: &str, now: Instant) -> bool {
+        self.hosts
+            .get(host)
+            .and_then(|state| state.offline_until)
+            .is_some_and(|until| now < until)
+    }
+
+    /// Takes the next request slot for `host`, returning how long to wait for it.
+    fn reserve(&mut self, host: &str, now: Instant) -> Duration {
+        let interval = if host.contains("whatsonchain.com") {
+            WHATSONCHAIN_INTERVAL
+        } else {
+            DEFAULT_INTERVAL
+        };
+        let state = self.hosts.entry(host.to_string()).or_default();
+        let slot = state.next_request.map_or(now, |next| next.max(now));
+        state.next_request = Some(slot + interval);
+        slot - now
+    }
+
+    /// Holds off every request to `host` for `delay`, after it rate limited us.
+    fn back_off(&mut self, host: &str, now: Instant, delay: Duration) {
+        let state = self.hosts.entry(host.to_string()).or_default();
+        state.next_request = Some(now + delay);
+    }
+
+    fn set_offline(&mut self, host: &str, offline_until: Option<Instant>) {
+        self.hosts
+            .entry(host.to_string())
+            .or_default()
+            .offline_until = offline_until;
+    }
+}
+
+static STATE: LazyLock<Mutex<State>> = LazyLock::new(Mutex::default);
+
+fn state() -> std::sync::MutexGuard<'static, State> {
+    STATE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn host_of(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.split('/').next().unwrap_or(rest)
+}
+
+/// Whether requests to `url`'s host are failing, so what the tools show
+/// comes from the cache.
+pub fn is_offline(url: &str) -> bool {
+    state().is_offline(host_of(url), Instant::now())
+}
+
+#[derive(Clone)]
+pub struct ExplorerClient {
+    http: Arc<dyn HttpClient>,
+}
+
+impl ExplorerClient {
+    pub fn new(http: Arc<dyn HttpClient>) -> Self {
+        Self { http }
+    }
+
+    /// GETs `url`, returning the body of a successful response, or `None` for
+    /// a 404. 404s aren't cached: a transaction that was just broadcast is
+    /// usually not indexed yet.
+    pub async fn get(&self, url: &str, freshness: Freshness) -> Result<Option<Arc<[u8]>>> {
+        self.get_authorized(url, freshness, None).await
+    }
+
+    /// [`Self::get`] with a bearer token, for APIs that want a key.
+    pub async fn get_authorized(
+        &self,
+        url: &str,
+        freshness: Freshness,
+        bearer_token: Option<&str>,
+    ) -> Result<Option<Arc<[u8]>>> {
+        let cached = state().cached(url, Instant::now());
+        if let Some(body) = cached {
+            return Ok(Some(body));
+        }
+
+        let result = self
+            .send(url, || {
+                let mut request = Request::builder()
+                    .method(Method::GET)
+                    .uri(url)
+                    .follow_redirects(RedirectPolicy::FollowAll);
+                if let Some(token) = bearer_token {
+                    request = request.header("Authorization", format!("Bearer {token}"));
+                }
+                Ok(request.body(AsyncBody::default())?)
+            })
+            .await;
+        let (status, body) = match result {
+            Ok(response) => response,
+            Err(err) => return self.fall_back(url, err),
+        };
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            let err = anyhow::anyhow!(
+                "{url} returned {status}: {}",
+                String::from_utf8_lossy(&body).trim()
+            );
+            return self.fall_back(url, err);
+        }
+        anyhow::ensure!(
+            status.is_success(),
+            "{url} returned {status}: {}",
+            String::from_utf8_lossy(&body).trim()
+        );
+        let body: Arc<[u8]> = body.into();
+        state().store(url, body.clone(), freshness, Instant::now());
+        Ok(Some(body))
+    }
+
+    /// POSTs a JSON body, e.g. a transaction to broadcast. Nothing is cached,
+    /// but a successful POST expires the live responses it may have changed.
+    pub async fn post_json(
+        &self,
+        url: &str,
+        body: String,
+        bearer_token: Option<&str>,
+    ) -> Result<(StatusCode, Vec<u8>)> {
+        let (status, response) = self
+            .send(url, || {
+                let mut request = Request::builder()
+                    .method(Method::POST)
+                    .uri(url)
+                    .header("Content-Type", "application/json");
+                if let Some(token) = bearer_token {
+                    request = request.header("Authorization", format!("Bearer {token}"));
+                }
+                Ok(request.body(AsyncBody::from(body.clone()))?)
+            })
+            .await?;
+        if status.is_success() {
+            expire_live();
+        }
+        Ok((status, response))
+    }
+
+    /// Sends the request to `url` built by `request`, waiting for the host's
+    /// rate limit and retrying when it answers 429. Connection failures mark
+    /// the host offline.
+    async fn send(
+        &self,
+        url: &str,
+        request: impl Fn() -> Result<Request<AsyncBody>>,
+    ) -> Result<(StatusCode, Vec<u8>)> {
+        let host = host_of(url);
+        let mut delay = RATE_LIMITED_DELAY;
+        let mut attempt = 1;
+        loop {
+            let wait = state().reserve(host, Instant::now());
+            if !wait.is_zero() {
+                smol::Timer::after(wait).await;
+            }
+
+            let mut response = match self.http.send(request()?).await {
+                Ok(response) => response,
+                Err(err) => {
+                    state().set_offline(host, Some(Instant::now() + OFFLINE_RETRY));
+                    return Err(err).with_context(|| format!("requesting {url}"));
+                }
+            };
+            let status = response.status();
+            if status == StatusCode::TOO_MANY_REQUESTS && attempt < MAX_ATTEMPTS {
+                let retry_after = retry_after(&response).unwrap_or(delay);
+                log::warn!("{host} rate limited request {attempt}, retrying in {retry_after:?}");
+                state().back_off(host, Instant::now(), retry_after);
+                delay *= 2;
+                attempt += 1;
+                continue;
+            }
+
+            let mut body = Vec::new();
+            response.body_mut().read_to_end(&mut body).await?;
+            if !status.is_server_error() {
+                state().set_offline(host, None);
+            }
+            return Ok((status, body));
+        }
+    }
+
+    /// Serves `url` from the cache after `err`, whatever its age, since
+    /// stale data beats none while the host is down.
+    fn fall_back(&self, url: &str, err: anyhow::Error) -> Result<Option<Arc<[u8]>>> {
+        let host = host_of(url);
+        let mut state = state();
+        state.set_offline(host, Some(Instant::now() + OFFLINE_RETRY));
+        match state.cache.get(url) {
+            Some(entry) => {
+                log::warn!("serving cached {url}: {err:#}");
+                Ok(Some(entry.body.clone()))
+            }
+            None => Err(err),
+        }
+    }
+}
+
+/// Drops the cached live responses, e.g. after a broadcast spent some outputs.
+pub fn expire_live() {
+    state()
+        .cache
+        .retain(|_, entry| entry.freshness != Freshness::Live);
+}
+
+fn retry_after(response: &Response<AsyncBody>) -> Option<Duration> {
+    let seconds = response
+        .headers()
+        .get("retry-after")?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_and_rate_limit() {
+        let mut state = State::default();
+        let start = Instant::now();
+        let url = "https://api.whatsonchain.com/v1/bsv/test/chain/info";
+        let host = host_of(url);
+        assert_eq!(host, "api.whatsonchain.com");
+
+        state.store(url, Arc::from(&b"{}"[..]), Freshness::Live, start);
+        assert!(state.cached(url, start + Duration::from_secs(5)).is_some());
+        assert!(state.cached(url, start + Duration::from_secs(11)).is_none());
+        state.set_offline(host, Some(start + Duration::from_secs(60)));
+        assert!(state.cached(url, start + Duration::from_secs(11)).is_some());
+        assert!(state.cached(url, start + Duration::from_secs(61)).is_none());
+
+        assert_eq!(state.reserve(host, start), Duration::ZERO);
+        assert_eq!(state.reserve(host, start), WHATSONCHAIN_INTERVAL);
+        assert_eq!(
+            state.reserve(host, start + Duration::from_millis(100)),
+            WHATSONCHAIN_INTERVAL * 2 - Duration::from_millis(100)
+        );
+        assert_eq!(state.reserve("arc.taal.com", start), Duration::ZERO);
+        state.back_off(host, start, Duration::from_secs(2));
+        assert_eq!(state.reserve(host, start), Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,140 @@
+//! `/history <address|script hash>`: lists the deployments, calls and final
+//! spends of a contract from the project's chain provider.
+
+use std::sync::{Arc, atomic::AtomicBool};
+
+use anyhow::{Context as _, Result, anyhow};
+use assistant_slash_command::{
+    ArgumentCompletion, SlashCommand, SlashCommandOutput, SlashCommandOutputSection,
+    SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use settings::Settings as _;
+use ui::prelude::*;
+use util::ResultExt as _;
+use workspace::Workspace;
+
+use crate::{
+    artifact::{Artifact, find_artifacts},
+    bitcoin_project::{BitcoinProject, workspace_project_root},
+    chain_provider::{self, ChainProviderSettings},
+    contract_history::{HistoryTx, Target, events, report},
+};
+
+/// Transactions fetched at most; long-lived contracts show their latest.
+const MAX_TXS: usize = 50;
+
+pub struct HistorySlashCommand;
+
+impl SlashCommand for HistorySlashCommand {
+    fn name(&self) -> String {
+        "history".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("history", &["<address or script hash>"], cx)
+    }
+
+    fn description(&self) -> String {
+        "List a contract's deployments, calls and spends".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::HistoryRerun
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        _arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        _workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        Task::ready(Ok(Vec::new()))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        let Some(root) = workspace_project_root(&workspace, cx) else {
+            return Task::ready(Err(anyhow!("no project is open")));
+        };
+        let argument = arguments.join("");
+        let target = match Target::parse(&argument) {
+            Ok(target) => target,
+            Err(err) => return Task::ready(Err(err)),
+        };
+        let http = cx.http_client();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        cx.background_spawn(async move {
+            let project = BitcoinProject::load(&root)?
+                .context("no .env found - create one from .env.testnet")?;
+            let client = chain_provider::for_project(http, &project, &provider)?;
+            let mut entries = match &target {
+                Target::Address(address, _) => client.address_history(address).await?,
+                Target::ScriptHash(hash) => client.script_history(hash).await?,
+            };
+            // Unconfirmed last, then the newest blocks
+            entries.sort_by_key(|entry| (entry.height == 0, entry.height));
+            let skipped = entries.len().saturating_sub(MAX_TXS);
+            let mut txs = Vec::new();
+            for entry in entries.into_iter().skip(skipped) {
+                let txid = entry.tx_hash;
+                let outputs = client.outputs(&txid).await?.unwrap_or_default();
+                let inputs = client.inputs(&txid).await?.unwrap_or_default();
+                txs.push(HistoryTx {
+                    txid,
+                    height: entry.height,
+                    inputs,
+                    outputs,
+                });
+            }
+            let artifacts: Vec<Artifact> = find_artifacts(&root)
+                .iter()
+                .filter_map(|path| Artifact::load(path).log_err())
+                .collect();
+
+            let (events, unspent) = events(&target, &txs, &artifacts);
+            let mut text = report(&argument, &events, &unspent, |txid| project.tx_url(txid));
+            if skipped > 0 {
+                text.push_str(&format!(
+                    "\nOnly the latest {MAX_TXS} transactions are shown; {skipped} earlier ones \
+                     were left out.\n"
+                ));
+            }
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::HistoryRerun,
+                    label: format!("History of {argument}").into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
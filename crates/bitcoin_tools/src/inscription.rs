@@ -0,0 +1,215 @@
+//! 1Sat Ordinals inscriptions: an envelope of `OP_0 OP_IF "ord" OP_1
+//! <content type> OP_0 <content> OP_ENDIF` in a 1-satoshi output's locking
+//! script, before or after the script that locks it.
+
+use std::ops::Range;
+
+use bitcoin_script::{OP_ENDIF, keys::p2pkh_address};
+
+use crate::artifact::{Artifact, read_push};
+
+/// `OP_0 OP_IF OP_PUSH3 "ord"`.
+const ENVELOPE_START: [u8; 6] = [0x00, 0x63, 0x03, b'o', b'r', b'd'];
+const CONTENT_TYPE_TAG: &[u8] = &[1];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inscription {
+    pub content_type: String,
+    pub content: Vec<u8>,
+    /// The envelope's bytes in the locking script, `OP_ENDIF` included.
+    pub envelope: Range<usize>,
+}
+
+/// How an inscription's content can be shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Preview {
+    Image,
+    Text(String),
+    /// Pretty-printed.
+    Json(String),
+    Binary,
+}
+
+/// What the rest of the locking script does with the inscribed satoshi.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lock {
+    /// Nothing; whoever can push a true value spends it.
+    Bare,
+    P2pkh {
+        address: String,
+    },
+    /// A contract of the project, with its constructor arguments and state.
+    Contract {
+        name: String,
+        values: Vec<(String, String)>,
+    },
+    Unknown {
+        bytes: usize,
+    },
+}
+
+/// The first inscription in a locking script.
+pub fn find_inscription(script: &[u8]) -> Option<Inscription> {
+    let mut search_from = 0;
+    while let Some(ix) = script[search_from..]
+        .windows(ENVELOPE_START.len())
+        .position(|window| window == ENVELOPE_START)
+    {
+        let start = search_from + ix;
+        search_from = start + 1;
+        if let Some(inscription) = read_envelope(script, start) {
+            return Some(inscription);
+        }
+    }
+    None
+}
+
+fn read_envelope(script: &[u8], start: usize) -> Option<Inscription> {
+    let mut ix = start + ENVELOPE_START.len();
+    let mut content_type = None;
+    let mut content = Vec::new();
+    loop {
+        if *script.get(ix)? == OP_ENDIF {
+            ix += 1;
+            break;
+        }
+        let (tag, len) = read_push(&script[ix..])?;
+        ix += len;
+        if tag.is_empty() {
+            // The content follows as any number of pushes, up to OP_ENDIF
+            while *script.get(ix)? != OP_ENDIF {
+                let (chunk, len) = read_push(&script[ix..])?;
+                content.extend(chunk);
+                ix += len;
+            }
+            continue;
+        }
+        let (value, len) = read_push(&script[ix..])?;
+        ix += len;
+        if tag == CONTENT_TYPE_TAG {
+            content_type = Some(String::from_utf8_lossy(&value).into_owned());
+        }
+    }
+    Some(Inscription {
+        content_type: content_type.unwrap_or_else(|| "application/octet-stream".to_string()),
+        content,
+        envelope: start..ix,
+    })
+}
+
+impl Inscription {
+    pub fn preview(&self) -> Preview {
+        let mime = self
+            .content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        if mime.starts_with("image/") {
+            return Preview::Image;
+        }
+        let Ok(text) = std::str::from_utf8(&self.content) else {
+            return Preview::Binary;
+        };
+        let is_json = mime == "application/json" || mime.ends_with("+json");
+        let json = is_json
+            .then(|| serde_json::from_str::<serde_json::Value>(text).ok())
+            .flatten();
+        if let Some(json) = json {
+            return Preview::Json(serde_json::to_string_pretty(&json).unwrap_or_default());
+        }
+        if mime.starts_with("text/")
+            || matches!(mime, "application/json" | "application/javascript")
+        {
+            return Preview::Text(text.to_string());
+        }
+        Preview::Binary
+    }
+
+    /// The locking script without the envelope.
+    pub fn lock_script(&self, script: &[u8]) -> Vec<u8> {
+        [&script[..self.envelope.start], &script[self.envelope.end..]].concat()
+    }
+}
+
+/// Names the script locking an inscription, trying the project's artifacts
+/// for anything other than P2PKH.
+pub fn describe_lock(lock: &[u8], artifacts: &[Artifact], testnet: bool) -> Lock {
+    if lock.is_empty() {
+        return Lock::Bare;
+    }
+    let pubkey_hash = match lock {
+        [0x76, 0xa9, 0x14, hash @ .., 0x88, 0xac] => <[u8; 20]>::try_from(hash).ok(),
+        _ => None,
+    };
+    if let Some(hash) = pubkey_hash {
+        return Lock::P2pkh {
+            address: p2pkh_address(&hash, testnet),
+        };
+    }
+    artifacts
+        .iter()
+        .find_map(|artifact| {
+            Some(Lock::Contract {
+                name: artifact.contract.clone(),
+                values: artifact.match_script(lock)?,
+            })
+        })
+        .unwrap_or(Lock::Unknown { bytes: lock.len() })
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin_script::{push_data, transaction::p2pkh_script};
+
+    use super::*;
+
+    fn envelope(content_type: &str, chunks: &[&[u8]]) -> Vec<u8> {
+        let mut script = ENVELOPE_START.to_vec();
+        script.push(0x51);
+        script.extend(push_data(content_type.as_bytes()));
+        script.push(0x00);
+        for chunk in chunks {
+            script.extend(push_data(chunk));
+        }
+        script.push(OP_ENDIF);
+        script
+    }
+
+    #[test]
+    fn test_find_inscription() {
+        let lock = p2pkh_script(&[7; 20]);
+        let inscribed = envelope("application/json", &[b"{\"name\":", b"\"utxix\"}"]);
+        let script = [lock.as_slice(), &inscribed].concat();
+
+        let inscription = find_inscription(&script).unwrap();
+        assert_eq!(inscription.content_type, "application/json");
+        assert_eq!(inscription.envelope, lock.len()..script.len());
+        assert_eq!(
+            inscription.preview(),
+            Preview::Json("{\n  \"name\": \"utxix\"\n}".to_string())
+        );
+        assert_eq!(inscription.lock_script(&script), lock);
+        assert_eq!(
+            describe_lock(&inscription.lock_script(&script), &[], true),
+            Lock::P2pkh {
+                address: p2pkh_address(&[7; 20], true)
+            }
+        );
+
+        let image = find_inscription(&envelope("image/png", &[&[0x89, b'P']])).unwrap();
+        assert_eq!(image.preview(), Preview::Image);
+        assert_eq!(image.content, [0x89, b'P']);
+        assert_eq!(
+            describe_lock(
+                &image.lock_script(&envelope("image/png", &[&[0x89, b'P']])),
+                &[],
+                true
+            ),
+            Lock::Bare
+        );
+
+        assert_eq!(find_inscription(&lock), None);
+        assert_eq!(find_inscription(&inscribed[..inscribed.len() - 1]), None);
+    }
+}
@@ -0,0 +1,389 @@
+//! A tab that looks up a transaction or outpoint and shows the 1Sat Ordinals
+//! inscriptions in its outputs: their content, and the script they sit behind.
+
+use std::{path::Path, sync::Arc};
+
+use anyhow::{Context as _, Result, bail};
+use gpui::{
+    App, Context, Entity, EventEmitter, FocusHandle, Focusable, Image, ImageFormat, SharedString,
+    Task, Window, actions, img, px,
+};
+use http_client::HttpClient;
+use project::Project;
+use settings::Settings as _;
+use ui::{Button, ButtonStyle, Label, LabelSize, prelude::*};
+use ui_input::InputField;
+use workspace::{Workspace, item::Item};
+
+use crate::{
+    artifact::{Artifact, find_artifacts},
+    bitcoin_project::{BitcoinProject, Network, project_root},
+    chain_provider::{self, ChainProviderSettings},
+    inscription::{Inscription, Lock, Preview, describe_lock, find_inscription},
+};
+
+/// Text content is cut off after this many bytes.
+const MAX_TEXT_PREVIEW: usize = 16 * 1024;
+
+actions!(
+    inscription_inspector,
+    [
+        /// Opens the inscription inspector.
+        Open
+    ]
+);
+
+struct InscribedOutput {
+    vout: u32,
+    satoshis: u64,
+    inscription: Inscription,
+    /// Worked out up front, like `image`, rather than on every frame.
+    preview: Preview,
+    lock: Lock,
+    image: Option<Arc<Image>>,
+}
+
+enum InspectStatus {
+    Idle,
+    Loading,
+    Failed(SharedString),
+    Done {
+        txid: String,
+        outputs: Vec<InscribedOutput>,
+    },
+}
+
+pub struct InscriptionInspector {
+    focus_handle: FocusHandle,
+    project: Entity<Project>,
+    http_client: Arc<dyn HttpClient>,
+    outpoint: Entity<InputField>,
+    status: InspectStatus,
+    inspect_task: Task<()>,
+}
+
+impl InscriptionInspector {
+    pub fn open(workspace: &mut Workspace, window: &mut Window, cx: &mut Context<Workspace>) {
+        let existing = workspace
+            .active_pane()
+            .read(cx)
+            .items()
+            .find_map(|item| item.downcast::<Self>());
+        if let Some(existing) = existing {
+            workspace.activate_item(&existing, true, true, window, cx);
+            return;
+        }
+        let project = workspace.project().clone();
+        let http_client = cx.http_client();
+        let inspector = cx.new(|cx| Self {
+            focus_handle: cx.focus_handle(),
+            project,
+            http_client,
+            outpoint: cx.new(|cx| {
+                InputField::new(window, cx, "txid or txid:vout").label("Transaction or outpoint")
+            }),
+            status: InspectStatus::Idle,
+            inspect_task: Task::ready(()),
+        });
+        workspace.add_item_to_active_pane(Box::new(inspector), None, true, window, cx);
+    }
+
+    fn inspect(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            self.status = InspectStatus::Failed("Open a Bitcoin project first".into());
+            cx.notify();
+            return;
+        };
+        let text = self.outpoint.read(cx).text(cx);
+        let client = self.http_client.clone();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        self.status = InspectStatus::Loading;
+        cx.notify();
+
+        self.inspect_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_spawn(async move {
+                    fetch_inscriptions(&root, &text, client, &provider).await
+                })
+                .await;
+            this.update(cx, |this, cx| {
+                this.status = match result {
+                    Ok((txid, outputs)) => InspectStatus::Done { txid, outputs },
+                    Err(err) => InspectStatus::Failed(format!("{err:#}").into()),
+                };
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn render_output(&self, output: &InscribedOutput, cx: &Context<Self>) -> impl IntoElement {
+        let inscription = &output.inscription;
+        let content = match (&output.preview, &output.image) {
+            (Preview::Image, Some(image)) => div()
+                .child(img(image.clone()).max_w(px(480.)).max_h(px(480.)))
+                .into_any_element(),
+            (Preview::Text(text) | Preview::Json(text), _) => {
+                let mut text = text.clone();
+                if text.len() > MAX_TEXT_PREVIEW {
+                    let end = (0..=MAX_TEXT_PREVIEW)
+                        .rev()
+                        .find(|ix| text.is_char_boundary(*ix))
+                        .unwrap_or_default();
+                    text.truncate(end);
+                    text.push('…');
+                }
+                div()
+                    .p_2()
+                    .rounded_sm()
+                    .bg(cx.theme().colors().element_background)
+                    .font_buffer(cx)
+                    .text_xs()
+                    .child(text)
+                    .into_any_element()
+            }
+            (Preview::Image | Preview::Binary, _) => Label::new(format!(
+                "{} bytes that can't be shown here",
+                inscription.content.len()
+            ))
+            .size(LabelSize::Small)
+            .color(Color::Muted)
+            .into_any_element(),
+        };
+
+        let lock = match &output.lock {
+            Lock::Bare => v_flex().child(
+                Label::new("No lock script - anyone can spend this output")
+                    .size(LabelSize::Small)
+                    .color(Color::Warning),
+            ),
+            Lock::P2pkh { address } => v_flex().child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new("P2PKH to").size(LabelSize::Small))
+                    .child(
+                        Label::new(address.clone())
+                            .size(LabelSize::Small)
+                            .buffer_font(cx),
+                    ),
+            ),
+            Lock::Contract { name, values } => v_flex()
+                .gap_0p5()
+                .child(
+                    Label::new(format!("{name} contract"))
+                        .size(LabelSize::Small)
+                        .color(Color::Accent),
+                )
+                .children(values.iter().map(|(name, value)| {
+                    h_flex()
+                        .gap_2()
+                        .pl_4()
+                        .child(
+                            Label::new(name.clone())
+                                .size(LabelSize::Small)
+                                .color(Color::Muted),
+                        )
+                        .child(
+                            Label::new(value.clone())
+                                .size(LabelSize::Small)
+                                .buffer_font(cx),
+                        )
+                })),
+            Lock::Unknown { bytes } => v_flex().child(
+                Label::new(format!(
+                    "A {bytes}-byte script that isn't P2PKH or a contract of this project"
+                ))
+                .size(LabelSize::Small)
+                .color(Color::Muted),
+            ),
+        };
+
+        v_flex()
+            .gap_2()
+            .pt_3()
+            .border_t_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new(format!("Output {}", output.vout)))
+                    .child(
+                        Label::new(format!(
+                            "{} · {} bytes · {} sat",
+                            inscription.content_type,
+                            inscription.content.len(),
+                            output.satoshis
+                        ))
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                    ),
+            )
+            .child(content)
+            .child(
+                Label::new("Locked by")
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+            .child(lock)
+    }
+}
+
+/// Looks up `text` (a txid, `txid:vout` or `txid_vout`) and decodes the
+/// inscriptions in it.
+async fn fetch_inscriptions(
+    root: &Path,
+    text: &str,
+    http: Arc<dyn HttpClient>,
+    provider: &ChainProviderSettings,
+) -> Result<(String, Vec<InscribedOutput>)> {
+    let project = BitcoinProject::load(root)?.context("no .env found in the project root")?;
+    let text = text.trim();
+    let (txid, vout) = match text.split_once([':', '_']) {
+        Some((txid, vout)) => (
+            txid,
+            Some(
+                vout.parse::<u32>()
+                    .with_context(|| format!("`{vout}` is not an output index"))?,
+            ),
+        ),
+        None => (text, None),
+    };
+    if txid.len() != 64 || !txid.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        bail!("`{txid}` is not a transaction id");
+    }
+    let txid = txid.to_ascii_lowercase();
+
+    let client = chain_provider::for_project(http, &project, provider)?;
+    let outputs = client
+        .outputs(&txid)
+        .await?
+        .with_context(|| format!("transaction {txid} not found on {}", project.network.name()))?;
+    if let Some(vout) = vout {
+        if vout as usize >= outputs.len() {
+            bail!("{txid} has {} output(s), no output {vout}", outputs.len());
+        }
+    }
+    let artifacts: Vec<Artifact> = find_artifacts(root)
+        .iter()
+        .filter_map(|path| Artifact::load(path).ok())
+        .collect();
+
+    let mut inscribed = Vec::new();
+    for (ix, output) in outputs.into_iter().enumerate() {
+        let ix = ix as u32;
+        if vout.is_some_and(|vout| vout != ix) {
+            continue;
+        }
+        let script = hex::decode(&output.script_hex).context("invalid script hex")?;
+        let Some(inscription) = find_inscription(&script) else {
+            continue;
+        };
+        let lock = describe_lock(
+            &inscription.lock_script(&script),
+            &artifacts,
+            project.network == Network::Testnet,
+        );
+        let preview = inscription.preview();
+        let image = match preview {
+            Preview::Image => ImageFormat::from_mime_type(&inscription.content_type)
+                .map(|format| Arc::new(Image::from_bytes(format, inscription.content.clone()))),
+            _ => None,
+        };
+        inscribed.push(InscribedOutput {
+            vout: ix,
+            satoshis: output.satoshis,
+            inscription,
+            preview,
+            lock,
+            image,
+        });
+    }
+    Ok((txid, inscribed))
+}
+
+impl Render for InscriptionInspector {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let loading = matches!(self.status, InspectStatus::Loading);
+        let body = match &self.status {
+            InspectStatus::Idle => Label::new(
+                "Paste a transaction id to list its inscriptions, or an outpoint for one output.",
+            )
+            .size(LabelSize::Small)
+            .color(Color::Muted)
+            .into_any_element(),
+            InspectStatus::Loading => Label::new("Looking up the transaction…")
+                .size(LabelSize::Small)
+                .color(Color::Muted)
+                .into_any_element(),
+            InspectStatus::Failed(error) => Label::new(error.clone())
+                .size(LabelSize::Small)
+                .color(Color::Error)
+                .into_any_element(),
+            InspectStatus::Done { txid, outputs } if outputs.is_empty() => {
+                Label::new(format!("No inscriptions in {txid}"))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted)
+                    .into_any_element()
+            }
+            InspectStatus::Done { outputs, .. } => v_flex()
+                .gap_3()
+                .children(outputs.iter().map(|output| self.render_output(output, cx)))
+                .into_any_element(),
+        };
+
+        v_flex()
+            .id("inscription-inspector")
+            .key_context("InscriptionInspector")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .overflow_y_scroll()
+            .p_4()
+            .gap_3()
+            .bg(cx.theme().colors().editor_background)
+            .child(Headline::new("Inscriptions").size(HeadlineSize::Medium))
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_end()
+                    .child(div().flex_1().child(self.outpoint.clone()))
+                    .child(
+                        Button::new("inspect-inscription", "Inspect")
+                            .style(ButtonStyle::Filled)
+                            .disabled(loading)
+                            .on_click(cx.listener(|this, _, _window, cx| this.inspect(cx))),
+                    ),
+            )
+            .child(body)
+    }
+}
+
+impl EventEmitter<()> for InscriptionInspector {}
+
+impl Focusable for InscriptionInspector {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Item for InscriptionInspector {
+    type Event = ();
+
+    fn tab_content_text(&self, _: usize, _: &App) -> SharedString {
+        match &self.status {
+            InspectStatus::Done { txid, .. } => format!("Inscriptions {}…", &txid[..8]).into(),
+            _ => "Inscriptions".into(),
+        }
+    }
+
+    fn tab_icon(&self, _window: &Window, _cx: &App) -> Option<Icon> {
+        Some(IconName::Image.into())
+    }
+
+    fn telemetry_event_text(&self) -> Option<&'static str> {
+        Some("Inscription Inspector Opened")
+    }
+
+    fn show_toolbar(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,220 @@
+//! Deploy keys kept in the OS keychain instead of `.env`. The project's `.env`
+//! only names the key by its address (`DEPLOY_KEY_ADDRESS`); the WIF itself is
+//! stored under `utxix-key://<address>`.
+
+use anyhow::{Context as _, Result, ensure};
+use bitcoin_script::keys::PrivateKey;
+use credentials_provider::CredentialsProvider;
+use gpui::AsyncApp;
+
+use crate::{
+    bitcoin_project::{BitcoinProject, Network},
+    deploy::{DEPLOY_KEY, deploy_key, parse_key},
+};
+
+/// `.env` key naming the keychain entry that holds the deploy key.
+pub const DEPLOY_KEY_ADDRESS: &str = "DEPLOY_KEY_ADDRESS";
+
+/// Where a project's deploy key lives. The keychain wins when both are set,
+/// as it does in the scaffold's `scripts/utils.ts`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyLocation {
+    Keychain { address: String },
+    Env,
+    Unset,
+}
+
+impl KeyLocation {
+    pub fn of(project: &BitcoinProject) -> Self {
+        if let Some(address) = project.setting(DEPLOY_KEY_ADDRESS) {
+            KeyLocation::Keychain {
+                address: address.to_string(),
+            }
+        } else if project.setting(DEPLOY_KEY).is_some() {
+            KeyLocation::Env
+        } else {
+            KeyLocation::Unset
+        }
+    }
+}
+
+fn keychain_url(address: &str) -> String {
+    format!("utxix-key://{address}")
+}
+
+pub fn generate_key(network: Network) -> PrivateKey {
+    loop {
+        // Out-of-range secrets are astronomically unlikely, but possible
+        if let Some(key) =
+            PrivateKey::from_secret_bytes(&rand::random(), network == Network::Testnet)
+        {
+            return key;
+        }
+    }
+}
+
+/// The project's deploy key, from the keychain or else from `.env`.
+pub async fn load_deploy_key(
+    project: &BitcoinProject,
+    credentials: &dyn CredentialsProvider,
+    cx: &AsyncApp,
+) -> Result<PrivateKey> {
+    let KeyLocation::Keychain { address } = KeyLocation::of(project) else {
+        return deploy_key(project);
+    };
+    let (_, wif) = credentials
+        .read_credentials(&keychain_url(&address), cx)
+        .await
+        .context("reading the OS keychain")?
+        .with_context(|| {
+            format!("no key for {address} in the OS keychain - generate or import one in the Wallet panel")
+        })?;
+    let wif = String::from_utf8(wif).context("the keychain entry is not a WIF key")?;
+    let key = parse_key(&wif, project.network, DEPLOY_KEY_ADDRESS)?;
+    ensure!(
+        key.address() == address,
+        "the keychain entry for {address} holds the key of {}",
+        key.address()
+    );
+    Ok(key)
+}
+
+/// Saves `key` to the keychain under its address.
+pub async fn save_key(
+    key: &PrivateKey,
+    credentials: &dyn CredentialsProvider,
+    cx: &AsyncApp,
+) -> Result<()> {
+    let address = key.address();
+    credentials
+        .write_credentials(
+            &keychain_url(&address),
+            &address,
+            key.to_wif().as_bytes(),
+            cx,
+        )
+        .await
+        .context("saving the key to the OS keychain")
+}
+
+/// Saves `key` to the keychain and makes it the project's deploy key. A
+/// `DEPLOY_PRIVATE_KEY` in `.env` is removed only when it is this same key,
+/// so moving a key never loses a different one.
+pub async fn adopt_key(
+    project: &mut BitcoinProject,
+    key: &PrivateKey,
+    credentials: &dyn CredentialsProvider,
+    cx: &AsyncApp,
+) -> Result<()> {
+    save_key(key, credentials, cx).await?;
+    let address = key.address();
+    project.set_setting(DEPLOY_KEY_ADDRESS, Some(&address))?;
+    if deploy_key(project).is_ok_and(|env_key| env_key.address() == address) {
+        project.set_setting(DEPLOY_KEY, None)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fs, future::Future, pin::Pin, sync::Mutex};
+
+    use gpui::TestAppContext;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MemoryCredentials(Mutex<HashMap<String, (String, Vec<u8>)>>);
+
+    impl CredentialsProvider for MemoryCredentials {
+        fn read_credentials<'a>(
+            &'a self,
+            url: &'a str,
+            _cx: &'a AsyncApp,
+        ) -> Pin<Box<dyn Future<Output = Result<Option<(String, Vec<u8>)>>> + 'a>> {
+            let credentials = self.0.lock().unwrap().get(url).cloned();
+            Box::pin(async move { Ok(credentials) })
+        }
+
+        fn write_credentials<'a>(
+            &'a self,
+            url: &'a str,
+            username: &'a str,
+            password: &'a [u8],
+            _cx: &'a AsyncApp,
+        ) -> Pin<Box<dyn Future<Output = Result<()>> + 'a>> {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(url.to_string(), (username.to_string(), password.to_vec()));
+            Box::pin(async { Ok(()) })
+        }
+
+        fn delete_credentials<'a>(
+            &'a self,
+            url: &'a str,
+            _cx: &'a AsyncApp,
+        ) -> Pin<Box<dyn Future<Output = Result<()>> + 'a>> {
+            self.0.lock().unwrap().remove(url);
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    #[gpui::test]
+    async fn test_keychain_round_trip(cx: &mut TestAppContext) {
+        let root = tempfile::tempdir().unwrap();
+        let key = generate_key(Network::Testnet);
+        let other = generate_key(Network::Testnet);
+        fs::write(
+            root.path().join(".env"),
+            format!("VITE_BSV_NETWORK=testnet\n{DEPLOY_KEY}={}\n", key.to_wif()),
+        )
+        .unwrap();
+        let mut project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        assert_eq!(KeyLocation::of(&project), KeyLocation::Env);
+        let credentials = MemoryCredentials::default();
+        let cx = cx.to_async();
+
+        // A different key in the keychain leaves the .env key alone
+        adopt_key(&mut project, &other, &credentials, &cx)
+            .await
+            .unwrap();
+        assert_eq!(project.setting(DEPLOY_KEY), Some(key.to_wif().as_str()));
+
+        adopt_key(&mut project, &key, &credentials, &cx)
+            .await
+            .unwrap();
+        let address = key.address();
+        assert_eq!(
+            KeyLocation::of(&project),
+            KeyLocation::Keychain {
+                address: address.clone()
+            }
+        );
+        let env = fs::read_to_string(root.path().join(".env")).unwrap();
+        assert!(env.contains(&format!("{DEPLOY_KEY_ADDRESS}={address}")));
+        assert!(!env.contains(&key.to_wif()));
+
+        let project = BitcoinProject::load(root.path()).unwrap().unwrap();
+        let loaded = load_deploy_key(&project, &credentials, &cx).await.unwrap();
+        assert_eq!(loaded.to_wif(), key.to_wif());
+
+        // An entry holding some other key is refused
+        credentials
+            .write_credentials(
+                &keychain_url(&address),
+                &address,
+                other.to_wif().as_bytes(),
+                &cx,
+            )
+            .await
+            .unwrap();
+        assert!(load_deploy_key(&project, &credentials, &cx).await.is_err());
+
+        credentials
+            .delete_credentials(&keychain_url(&address), &cx)
+            .await
+            .unwrap();
+        assert!(load_deploy_key(&project, &credentials, &cx).await.is_err());
+    }
+}
@@ -0,0 +1,142 @@
+//! `/keys [label...] [--keychain] [--mainnet]`: generates labeled keypairs
+//! for the parties of a contract test and saves them with the project.
+
+use std::sync::{Arc, atomic::AtomicBool};
+
+use anyhow::{Context as _, Result, anyhow};
+use assistant_slash_command::{
+    AfterCompletion, ArgumentCompletion, SlashCommand, SlashCommandOutput,
+    SlashCommandOutputSection, SlashCommandResult, create_label_for_command,
+};
+use credentials_provider::CredentialsProvider;
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use ui::prelude::*;
+use workspace::Workspace;
+
+use crate::{
+    bitcoin_project::{BitcoinProject, workspace_project_root},
+    keychain::{generate_key, save_key},
+    party_keys::{
+        KEYCHAIN_FLAG, MAINNET_FLAG, PartyKey, Storage, existing_setting, parse_args, report,
+        setting_name,
+    },
+};
+
+pub struct KeysSlashCommand;
+
+impl SlashCommand for KeysSlashCommand {
+    fn name(&self) -> String {
+        "keys".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("keys", &["alice bob", KEYCHAIN_FLAG], cx)
+    }
+
+    fn description(&self) -> String {
+        "Generate labeled testnet keys for contract tests".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::UserGroup
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        false
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        _workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        let (query, typed) = match arguments.split_last() {
+            Some((query, typed)) => (query.as_str(), typed),
+            None => ("", &[][..]),
+        };
+        let completions = ["alice", "bob", "oracle", KEYCHAIN_FLAG, MAINNET_FLAG]
+            .into_iter()
+            .filter(|option| option.starts_with(query) && !typed.iter().any(|arg| arg == option))
+            .map(|option| ArgumentCompletion {
+                label: option.into(),
+                new_text: option.to_string(),
+                after_completion: AfterCompletion::Continue,
+                replace_previous_arguments: false,
+            })
+            .collect();
+        Task::ready(Ok(completions))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        let Some(root) = workspace_project_root(&workspace, cx) else {
+            return Task::ready(Err(anyhow!("no project is open")));
+        };
+        let request = match parse_args(arguments) {
+            Ok(request) => request,
+            Err(err) => return Task::ready(Err(err)),
+        };
+        let credentials = <dyn CredentialsProvider>::global(cx);
+        cx.spawn(async move |cx| {
+            let mut project = BitcoinProject::load(&root)?
+                .context("no .env found - create one from .env.testnet")?;
+            let mut generated = Vec::new();
+            let mut kept = Vec::new();
+            for label in &request.labels {
+                if let Some(setting) = existing_setting(&project, label) {
+                    kept.push((label.clone(), setting));
+                    continue;
+                }
+                let key = generate_key(request.network);
+                let value = match request.storage {
+                    Storage::Env => key.to_wif(),
+                    Storage::Keychain => {
+                        save_key(&key, credentials.as_ref(), cx).await?;
+                        key.address()
+                    }
+                };
+                project.set_setting(&setting_name(label, request.storage), Some(&value))?;
+                generated.push(PartyKey {
+                    label: label.clone(),
+                    wif: key.to_wif(),
+                    public_key: hex::encode(key.public_key()),
+                    address: key.address(),
+                });
+            }
+
+            let text = report(&request, project.network, &generated, &kept);
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::UserGroup,
+                    label: format!("Keys: {}", request.labels.join(", ")).into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
@@ -0,0 +1,127 @@
+//! Shows the findings of `security_lint` as diagnostics in contract sources,
+//! next to those of the language server.
+
+use std::{path::PathBuf, time::Duration};
+
+use editor::{Editor, EditorEvent};
+use gpui::{Context, Subscription, Task};
+use language::{
+    Diagnostic, DiagnosticEntry, DiagnosticSeverity, DiagnosticSourceKind, LanguageServerId,
+    Unclipped,
+};
+use lsp::NumberOrString;
+use util::ResultExt as _;
+
+use crate::{
+    contract_source::is_contract_file,
+    security_lint::{Severity, lint},
+};
+
+const LINT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Stands in for a language server in the project's diagnostics, so the
+/// findings can be replaced as a set. `usize::MAX` is taken by the editor.
+const LINT_SERVER_ID: LanguageServerId = LanguageServerId(usize::MAX - 1);
+
+struct SecurityLint {
+    path: PathBuf,
+    lint_task: Task<()>,
+    _subscription: Subscription,
+}
+
+impl editor::Addon for SecurityLint {
+    fn to_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+pub fn register_editor(editor: &mut Editor, cx: &mut Context<Editor>) {
+    if !editor.mode().is_full() || editor.project().is_none() {
+        return;
+    }
+    let Some(path) = editor
+        .buffer()
+        .read(cx)
+        .as_singleton()
+        .and_then(|buffer| Some(buffer.read(cx).file()?.as_local()?.abs_path(cx)))
+    else {
+        return;
+    };
+    if !is_contract_file(&path) {
+        return;
+    }
+
+    let subscription = cx.subscribe(&cx.entity(), |editor, _, event, cx| {
+        if matches!(event, EditorEvent::Saved | EditorEvent::BufferEdited) {
+            relint(editor, cx);
+        }
+    });
+    editor.register_addon(SecurityLint {
+        path,
+        lint_task: Task::ready(()),
+        _subscription: subscription,
+    });
+    relint(editor, cx);
+}
+
+fn relint(editor: &mut Editor, cx: &mut Context<Editor>) {
+    let Some(buffer) = editor.buffer().read(cx).as_singleton() else {
+        return;
+    };
+    let Some(project) = editor.project().cloned() else {
+        return;
+    };
+    let snapshot = buffer.read(cx).snapshot();
+    let Some(addon) = editor.addon_mut::<SecurityLint>() else {
+        return;
+    };
+    let path = addon.path.clone();
+    addon.lint_task = cx.spawn(async move |_, cx| {
+        cx.background_executor().timer(LINT_DEBOUNCE).await;
+        let entries: Vec<_> = cx
+            .background_spawn(async move {
+                lint(&snapshot.text())
+                    .into_iter()
+                    .enumerate()
+                    .map(|(group_id, finding)| DiagnosticEntry {
+                        range: Unclipped(snapshot.offset_to_point_utf16(finding.range.start))
+                            ..Unclipped(snapshot.offset_to_point_utf16(finding.range.end)),
+                        diagnostic: Diagnostic {
+                            source: Some("utxix".to_string()),
+                            code: Some(NumberOrString::String(finding.code.to_string())),
+                            severity: match finding.severity {
+                                Severity::Error => DiagnosticSeverity::ERROR,
+                                Severity::Warning => DiagnosticSeverity::WARNING,
+                            },
+                            message: finding.message,
+                            group_id,
+                            is_primary: true,
+                            source_kind: DiagnosticSourceKind::Other,
+                            underline: true,
+                            ..Diagnostic::default()
+                        },
+                    })
+                    .collect()
+            })
+            .await;
+        project
+            .update(cx, |project, cx| {
+                project.lsp_store().update(cx, |lsp_store, cx| {
+                    lsp_store.update_diagnostic_entries(
+                        LINT_SERVER_ID,
+                        path,
+                        None,
+                        None,
+                        entries,
+                        cx,
+                    )
+                })
+            })
+            .and_then(|result| result)
+            .log_err();
+    });
+}
@@ -0,0 +1,487 @@
+//! Timelock arithmetic for `/locktime`: dates, unix times and block heights
+//! as nLockTime values, durations as BIP68 nSequence values, and the
+//! scrypt-ts assertions that enforce them without mixing up the units.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context as _, Result, bail};
+
+/// nLockTime values below this are block heights, the rest unix times.
+pub const LOCKTIME_THRESHOLD: u64 = 500_000_000;
+/// Target block interval, used to estimate heights from times.
+pub const BLOCK_INTERVAL: u64 = 600;
+
+/// BIP68: the input has no relative lock.
+const SEQUENCE_DISABLE_FLAG: u32 = 1 << 31;
+/// BIP68: the lock counts 512-second units rather than blocks.
+const SEQUENCE_TYPE_FLAG: u32 = 1 << 22;
+const SEQUENCE_MASK: u32 = 0xffff;
+const SEQUENCE_GRANULARITY: u64 = 512;
+
+/// What `/locktime` was asked to convert.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Query {
+    Height(u64),
+    /// Unix time in seconds, from a timestamp or a UTC date.
+    Time(u64),
+    /// A duration from now, for deadlines and relative locks.
+    After(Duration),
+    /// An nSequence value to decode.
+    Sequence(u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Duration {
+    Blocks(u64),
+    Seconds(u64),
+}
+
+impl Duration {
+    fn blocks(&self) -> u64 {
+        match *self {
+            Duration::Blocks(blocks) => blocks,
+            Duration::Seconds(seconds) => seconds.div_ceil(BLOCK_INTERVAL),
+        }
+    }
+
+    fn seconds(&self) -> u64 {
+        match *self {
+            Duration::Blocks(blocks) => blocks * BLOCK_INTERVAL,
+            Duration::Seconds(seconds) => seconds,
+        }
+    }
+}
+
+impl Query {
+    /// Parses a height or unix time (`880000`, `1798675200`), a UTC date
+    /// (`2026-12-31`, `2026-12-31 18:30`), a duration (`+144 blocks`,
+    /// `+3d`, `+12h`) or a hex nSequence (`0x00400010`).
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            let sequence = u32::from_str_radix(hex, 16)
+                .with_context(|| format!("`{text}` isn't a 32-bit hex nSequence"))?;
+            return Ok(Query::Sequence(sequence));
+        }
+        if let Some(duration) = text.strip_prefix('+') {
+            return parse_duration(duration).map(Query::After);
+        }
+        if !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) {
+            let value: u64 = text.parse().context("number out of range")?;
+            if value > u64::from(u32::MAX) {
+                bail!("{value} doesn't fit nLockTime's 32 bits");
+            }
+            return Ok(if value < LOCKTIME_THRESHOLD {
+                Query::Height(value)
+            } else {
+                Query::Time(value)
+            });
+        }
+        parse_date(text).map(Query::Time).with_context(|| {
+            format!(
+                "`{text}` isn't a height, unix time, date (2026-12-31 18:30), \
+                 duration (+3d) or hex nSequence"
+            )
+        })
+    }
+}
+
+fn parse_duration(text: &str) -> Result<Duration> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    let (amount, unit) = text.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .with_context(|| format!("`+{text}` needs an amount, like +3d"))?;
+    let seconds = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" | "block" | "blocks" => return Ok(Duration::Blocks(amount)),
+        "s" | "sec" | "secs" | "second" | "seconds" => 1,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60,
+        "d" | "day" | "days" => 24 * 60 * 60,
+        "w" | "week" | "weeks" => 7 * 24 * 60 * 60,
+        unit => bail!("unknown unit `{unit}` - use blocks, s, m, h, d or w"),
+    };
+    amount
+        .checked_mul(seconds)
+        .map(Duration::Seconds)
+        .context("duration out of range")
+}
+
+/// Seconds since the epoch for `YYYY-MM-DD`, optionally followed by
+/// `HH:MM[:SS]` (after a space or `T`) and `Z` or `UTC`.
+fn parse_date(text: &str) -> Option<u64> {
+    let text = text
+        .trim_end_matches('Z')
+        .trim_end_matches("UTC")
+        .trim_end();
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time.trim())),
+        None => (text, None),
+    };
+    let mut date = date.split('-');
+    let year: i64 = date.next()?.parse().ok()?;
+    let month: u32 = date.next()?.parse().ok()?;
+    let day: u32 = date.next()?.parse().ok()?;
+    if date.next().is_some() || !(1..=12).contains(&month) || day < 1 || day > days_in(year, month)
+    {
+        return None;
+    }
+    let mut seconds = 0;
+    if let Some(time) = time {
+        let parts: Vec<u64> = time
+            .split(':')
+            .map(|part| part.parse().ok())
+            .collect::<Option<_>>()?;
+        let (hours, minutes, secs) = match parts.as_slice() {
+            [hours, minutes] => (*hours, *minutes, 0),
+            [hours, minutes, secs] => (*hours, *minutes, *secs),
+            _ => return None,
+        };
+        if hours > 23 || minutes > 59 || secs > 59 {
+            return None;
+        }
+        seconds = hours * 3600 + minutes * 60 + secs;
+    }
+    let days = u64::try_from(days_from_civil(year, month, day)).ok()?;
+    Some(days * 86_400 + seconds)
+}
+
+fn days_in(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// `YYYY-MM-DD HH:MM:SS UTC` for a unix time.
+pub fn format_time(time: u64) -> String {
+    let days = (time / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    let secs = time % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// "in 3 d 4 h" or "2 h 5 min ago".
+fn format_offset(seconds: i64) -> String {
+    let magnitude = seconds.unsigned_abs();
+    let (days, hours, minutes) = (
+        magnitude / 86_400,
+        magnitude / 3600 % 24,
+        magnitude / 60 % 60,
+    );
+    let text = match (days, hours) {
+        (0, 0) => format!("{minutes} min"),
+        (0, _) => format!("{hours} h {minutes} min"),
+        _ => format!("{days} d {hours} h"),
+    };
+    if seconds >= 0 {
+        format!("in {text}")
+    } else {
+        format!("{text} ago")
+    }
+}
+
+/// The nSequence encoding of a relative lock, rounding times up to BIP68's
+/// 512-second units.
+pub fn relative_sequence(duration: Duration) -> Result<u32> {
+    let (value, flags) = match duration {
+        Duration::Blocks(blocks) => (blocks, 0),
+        Duration::Seconds(seconds) => (seconds.div_ceil(SEQUENCE_GRANULARITY), SEQUENCE_TYPE_FLAG),
+    };
+    if value > u64::from(SEQUENCE_MASK) {
+        bail!(
+            "a relative lock holds at most {SEQUENCE_MASK} blocks or {} days",
+            u64::from(SEQUENCE_MASK) * SEQUENCE_GRANULARITY / 86_400
+        );
+    }
+    Ok(flags | value as u32)
+}
+
+/// The markdown for `/locktime`. `now` is the current unix time and `tip`
+/// the chain's height, when a project's provider could be asked.
+pub fn report(query: Query, now: u64, tip: Option<(u64, &str)>) -> Result<String> {
+    let mut text = String::from("## Locktime\n\n| | |\n|---|---|\n");
+    let estimate = |height: u64| {
+        tip.map(|(tip, _)| now as i64 + (height as i64 - tip as i64) * BLOCK_INTERVAL as i64)
+    };
+    let estimate_height = |time: u64| {
+        tip.map(|(tip, _)| {
+            let blocks = (time as i64 - now as i64).div_euclid(BLOCK_INTERVAL as i64);
+            (tip as i64 + blocks).max(0) as u64
+        })
+    };
+    let tip_note = match tip {
+        Some((tip, network)) => format!(
+            "Estimates assume {}-minute blocks from the {network} tip at height {tip}.\n",
+            BLOCK_INTERVAL / 60
+        ),
+        None => "Open a project with a `.env` to estimate between heights and times from \
+                 its network's tip.\n"
+            .to_string(),
+    };
+
+    let lock = match query {
+        Query::Sequence(sequence) => {
+            describe_sequence(&mut text, sequence);
+            return Ok(text);
+        }
+        Query::Height(height) => {
+            writeln!(text, "| nLockTime | `{height}` (block height) |").ok();
+            writeln!(text, "| Hex | `0x{height:08x}` |").ok();
+            if let Some(time) = estimate(height) {
+                writeln!(
+                    text,
+                    "| Reached | ≈ {} ({}) |",
+                    format_time(time.max(0) as u64),
+                    format_offset(time - now as i64)
+                )
+                .ok();
+            }
+            Lock::Height(height)
+        }
+        Query::Time(time) => {
+            if time > u64::from(u32::MAX) {
+                bail!("{} is past nLockTime's range (2106)", format_time(time));
+            }
+            if time < LOCKTIME_THRESHOLD {
+                bail!(
+                    "{} is before 1985-11-05, so as an nLockTime it would read as block \
+                     height {time}",
+                    format_time(time)
+                );
+            }
+            writeln!(text, "| nLockTime | `{time}` (unix time) |").ok();
+            writeln!(text, "| Hex | `0x{time:08x}` |").ok();
+            writeln!(
+                text,
+                "| Date | {} ({}) |",
+                format_time(time),
+                format_offset(time as i64 - now as i64)
+            )
+            .ok();
+            if let Some(height) = estimate_height(time) {
+                writeln!(text, "| Height | ≈ {height} |").ok();
+            }
+            Lock::Time(time)
+        }
+        Query::After(duration) => {
+            let time = now + duration.seconds();
+            let offset = format_offset(duration.seconds() as i64);
+            writeln!(
+                text,
+                "| Duration | {} blocks ≈ {} |",
+                duration.blocks(),
+                offset.trim_start_matches("in ")
+            )
+            .ok();
+            writeln!(
+                text,
+                "| Deadline (time) | `{time}` = {} |",
+                format_time(time)
+            )
+            .ok();
+            if let Some((tip, _)) = tip {
+                writeln!(
+                    text,
+                    "| Deadline (height) | `{}` |",
+                    tip + duration.blocks()
+                )
+                .ok();
+            }
+            match relative_sequence(duration) {
+                Ok(sequence) => {
+                    writeln!(
+                        text,
+                        "| Relative nSequence | `0x{sequence:08x}` ({sequence}) |"
+                    )
+                    .ok();
+                }
+                Err(err) => {
+                    writeln!(text, "| Relative nSequence | {err} |").ok();
+                }
+            }
+            Lock::After(duration.blocks())
+        }
+    };
+    text.push('\n');
+    text.push_str(&tip_note);
+    text.push_str("\n### scrypt-ts\n\n```typescript\n");
+    text.push_str(&lock.assertions());
+    text.push_str("```\n\n");
+    text.push_str(&lock.notes());
+    Ok(text)
+}
+
+fn describe_sequence(text: &mut String, sequence: u32) {
+    writeln!(text, "| nSequence | `0x{sequence:08x}` ({sequence}) |").ok();
+    let meaning = if sequence == u32::MAX {
+        "final: nLockTime is ignored for the whole transaction unless another input \
+         is non-final"
+            .to_string()
+    } else if sequence & SEQUENCE_DISABLE_FLAG != 0 {
+        "non-final, so nLockTime is enforced; the disable flag (bit 31) means no \
+         relative lock"
+            .to_string()
+    } else if sequence & SEQUENCE_TYPE_FLAG != 0 {
+        let units = sequence & SEQUENCE_MASK;
+        format!(
+            "non-final; as a BIP68 relative lock, {units} × 512 s = {} after the spent \
+             output confirmed",
+            format_offset(i64::from(units) * SEQUENCE_GRANULARITY as i64).trim_start_matches("in ")
+        )
+    } else {
+        format!(
+            "non-final; as a BIP68 relative lock, {} blocks after the spent output confirmed",
+            sequence & SEQUENCE_MASK
+        )
+    };
+    writeln!(text, "| Meaning | {meaning} |").ok();
+    text.push_str(
+        "\nBSV stopped enforcing BIP68 relative locks at Genesis: any nSequence below \
+         `0xffffffff` only switches nLockTime on, and `this.ctx.sequence` is whatever the \
+         spender chose.\n",
+    );
+}
+
+/// What the assertions enforce.
+enum Lock {
+    Height(u64),
+    Time(u64),
+    /// Blocks after a start height recorded in the contract's state.
+    After(u64),
+}
+
+impl Lock {
+    fn assertions(&self) -> String {
+        const ENABLED: &str = "// nLockTime is only enforced while the input's sequence isn't final\n\
+             assert(this.ctx.sequence < 0xffffffffn, 'nLocktime must be enabled')\n";
+        const HEIGHT: &str = "// Timestamps are all above 500000000, so without this a unix time\n\
+             // would pass a height deadline at once\n\
+             assert(this.ctx.locktime < 500000000n, 'nLocktime must be a block height')\n";
+        match self {
+            Lock::Height(height) => format!(
+                "{ENABLED}{HEIGHT}assert(this.ctx.locktime >= {height}n, 'Deadline not reached')\n"
+            ),
+            Lock::Time(time) => format!(
+                "{ENABLED}// {}; block heights are all below 500000000, so can't pass it\n\
+                 assert(this.ctx.locktime >= {time}n, 'Deadline not reached')\n",
+                format_time(*time)
+            ),
+            Lock::After(blocks) => format!(
+                "// BSV doesn't enforce relative locks, so record when the period starts\n\
+                 @prop(true)\n\
+                 start: bigint\n\
+                 \n\
+                 // In the method that starts the period:\n\
+                 {ENABLED}{HEIGHT}this.start = this.ctx.locktime\n\
+                 \n\
+                 // In the method allowed after {blocks} blocks:\n\
+                 {ENABLED}{HEIGHT}assert(this.ctx.locktime >= this.start + {blocks}n, 'Period not over')\n"
+            ),
+        }
+    }
+
+    fn notes(&self) -> String {
+        let mut notes = String::from(
+            "- Spend with `tx.setLockTime(...)` and a non-final sequence, e.g. \
+             `tx.setInputSequence(0, 0)`; miners hold the transaction until the lock \
+             passes.\n\
+             - Only `>=` deadlines are enforced: the spender picks nLockTime, so a \
+             `this.ctx.locktime < deadline` \"before\" check passes for any low value.\n",
+        );
+        match self {
+            Lock::Height(_) => {}
+            Lock::Time(_) => notes.push_str(
+                "- Time locks compare against the median time of the last 11 blocks, \
+                 which trails the clock by about an hour.\n",
+            ),
+            Lock::After(_) => notes.push_str(
+                "- `start` is whatever nLockTime the starting spender picked, which can be any \
+                 past height: make sure only the party the delay protects can start the \
+                 period, or bound it from below (e.g. by the previous `start`).\n\
+                 - On chains that enforce BIP68, the relative nSequence above does this \
+                 without state.\n",
+            ),
+        }
+        notes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locktime() {
+        assert_eq!(Query::parse("880000").unwrap(), Query::Height(880000));
+        assert_eq!(
+            Query::parse("2026-12-31").unwrap(),
+            Query::Time(1_798_675_200)
+        );
+        assert_eq!(
+            Query::parse("2026-12-31T18:30Z").unwrap(),
+            Query::Time(1_798_675_200 + 18 * 3600 + 30 * 60)
+        );
+        assert_eq!(format_time(1_798_741_800), "2026-12-31 18:30:00 UTC");
+        assert_eq!(format_time(951_782_400), "2000-02-29 00:00:00 UTC");
+        assert!(Query::parse("2026-02-29").is_err());
+        assert_eq!(
+            Query::parse("+3 days").unwrap(),
+            Query::After(Duration::Seconds(3 * 86_400))
+        );
+        assert_eq!(
+            Query::parse("+144").unwrap(),
+            Query::After(Duration::Blocks(144))
+        );
+        assert!(Query::parse("+3 fortnights").is_err());
+
+        // 1 day rounds up to 169 units of 512 s
+        assert_eq!(
+            relative_sequence(Duration::Seconds(86_400)).unwrap(),
+            0x0040_00a9
+        );
+        assert_eq!(relative_sequence(Duration::Blocks(144)).unwrap(), 144);
+        assert!(relative_sequence(Duration::Blocks(70_000)).is_err());
+
+        let now = 1_798_675_200;
+        let text = report(Query::Height(880_144), now, Some((880_000, "mainnet"))).unwrap();
+        assert!(text.contains("2027-01-01 00:00:00 UTC"), "{text}");
+        assert!(text.contains("assert(this.ctx.locktime < 500000000n"));
+        assert!(text.contains("assert(this.ctx.locktime >= 880144n"));
+        let text = report(Query::Time(now + 86_400), now, Some((880_000, "mainnet"))).unwrap();
+        assert!(text.contains("| Height | ≈ 880144 |"), "{text}");
+        let text = report(Query::Sequence(0x0040_00a9), now, None).unwrap();
+        assert!(text.contains("169 × 512 s"), "{text}");
+        assert!(report(Query::Time(400_000_000), now, None).is_err());
+    }
+}
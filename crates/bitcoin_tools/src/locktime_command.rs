@@ -0,0 +1,137 @@
+//! `/locktime <date | unix time | height | +duration | 0xsequence>`: converts
+//! between timelock units and prints the scrypt-ts assertions for them.
+
+use std::{
+    sync::{Arc, atomic::AtomicBool},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use assistant_slash_command::{
+    AfterCompletion, ArgumentCompletion, SlashCommand, SlashCommandOutput,
+    SlashCommandOutputSection, SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use settings::Settings as _;
+use ui::prelude::*;
+use util::ResultExt as _;
+use workspace::Workspace;
+
+use crate::{
+    bitcoin_project::{BitcoinProject, workspace_project_root},
+    chain_provider::{self, ChainProviderSettings},
+    locktime::{Query, report},
+};
+
+pub struct LocktimeSlashCommand;
+
+impl SlashCommand for LocktimeSlashCommand {
+    fn name(&self) -> String {
+        "locktime".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command(
+            "locktime",
+            &["<date | unix time | height | +duration | 0xsequence>"],
+            cx,
+        )
+    }
+
+    fn description(&self) -> String {
+        "Convert timelocks between dates, heights and nSequence".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::CountdownTimer
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        _workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        let query = arguments.last().map_or("", String::as_str);
+        let completions = ["+144 blocks", "+1d", "+7d", "+30d"]
+            .into_iter()
+            .filter(|option| option.starts_with(query))
+            .map(|option| ArgumentCompletion {
+                label: option.into(),
+                new_text: option.to_string(),
+                after_completion: AfterCompletion::Run,
+                replace_previous_arguments: true,
+            })
+            .collect();
+        Task::ready(Ok(completions))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        // Dates with a time and durations like `+3 days` span arguments
+        let argument = arguments.join(" ");
+        let query = match Query::parse(&argument) {
+            Ok(query) => query,
+            Err(err) => return Task::ready(Err(err)),
+        };
+        let root = workspace_project_root(&workspace, cx);
+        let http = cx.http_client();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        cx.background_spawn(async move {
+            // Heights and times convert through the project network's tip;
+            // without a project the rest still works
+            let project = root.and_then(|root| BitcoinProject::load(&root).log_err().flatten());
+            let mut tip = None;
+            if let Some(project) = &project {
+                if let Some(client) =
+                    chain_provider::for_project(http, project, &provider).log_err()
+                {
+                    tip = client
+                        .block_height()
+                        .await
+                        .log_err()
+                        .map(|height| (height, project.network.name()));
+                }
+            }
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs());
+            let text = report(query, now, tip)?;
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::CountdownTimer,
+                    label: format!("Locktime {argument}").into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
@@ -0,0 +1,204 @@
+//! A code-lens line above each `@method()` of a contract source, showing how
+//! much script the method compiles to according to the contract's artifact.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use editor::{
+    Editor, EditorEvent,
+    display_map::{BlockContext, BlockPlacement, BlockProperties, BlockStyle, CustomBlockId},
+};
+use gpui::{Context, Subscription, Task};
+use ui::prelude::*;
+
+use crate::{
+    artifact::{Artifact, ScriptSize, artifact_name, find_artifacts},
+    contract_source::{find_methods, is_contract_file},
+};
+
+const REFRESH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+struct MethodLens {
+    path: PathBuf,
+    block_ids: Vec<CustomBlockId>,
+    refresh_task: Task<()>,
+    _subscription: Subscription,
+}
+
+impl editor::Addon for MethodLens {
+    fn to_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+pub fn register_editor(editor: &mut Editor, cx: &mut Context<Editor>) {
+    if !editor.mode().is_full() {
+        return;
+    }
+    let Some(path) = editor
+        .buffer()
+        .read(cx)
+        .as_singleton()
+        .and_then(|buffer| Some(buffer.read(cx).file()?.as_local()?.abs_path(cx)))
+    else {
+        return;
+    };
+    if !is_contract_file(&path) {
+        return;
+    }
+
+    // Focus catches artifacts recompiled outside the editor
+    let subscription = cx.subscribe(&cx.entity(), |editor, _, event, cx| {
+        if matches!(
+            event,
+            EditorEvent::Saved | EditorEvent::BufferEdited | EditorEvent::Focused
+        ) {
+            refresh(editor, cx);
+        }
+    });
+    editor.register_addon(MethodLens {
+        path,
+        block_ids: Vec::new(),
+        refresh_task: Task::ready(()),
+        _subscription: subscription,
+    });
+    refresh(editor, cx);
+}
+
+fn refresh(editor: &mut Editor, cx: &mut Context<Editor>) {
+    let Some(buffer) = editor.buffer().read(cx).as_singleton() else {
+        return;
+    };
+    let snapshot = buffer.read(cx).snapshot();
+    let Some(lens) = editor.addon_mut::<MethodLens>() else {
+        return;
+    };
+    let path = lens.path.clone();
+    lens.refresh_task = cx.spawn(async move |editor, cx| {
+        cx.background_executor().timer(REFRESH_DEBOUNCE).await;
+        let source = snapshot.text();
+        let labels = cx
+            .background_spawn(async move { lens_labels(&path, &source) })
+            .await;
+        editor
+            .update(cx, |editor, cx| {
+                let multibuffer = editor.buffer().read(cx).snapshot(cx);
+                let blocks: Vec<_> = labels
+                    .into_iter()
+                    .filter_map(|(offset, label)| {
+                        let anchor =
+                            multibuffer.as_singleton_anchor(snapshot.anchor_before(offset))?;
+                        Some(BlockProperties {
+                            placement: BlockPlacement::Above(anchor),
+                            height: Some(1),
+                            style: BlockStyle::Fixed,
+                            render: Arc::new(move |cx: &mut BlockContext| {
+                                h_flex()
+                                    .h(cx.line_height)
+                                    .pl(cx.anchor_x)
+                                    .child(
+                                        Label::new(label.clone())
+                                            .size(LabelSize::XSmall)
+                                            .color(Color::Muted),
+                                    )
+                                    .into_any_element()
+                            }),
+                            priority: 0,
+                        })
+                    })
+                    .collect();
+
+                let Some(lens) = editor.addon_mut::<MethodLens>() else {
+                    return;
+                };
+                let old_block_ids = std::mem::take(&mut lens.block_ids);
+                editor.remove_blocks(old_block_ids.into_iter().collect(), None, cx);
+                let block_ids = editor.insert_blocks(blocks, None, cx);
+                if let Some(lens) = editor.addon_mut::<MethodLens>() {
+                    lens.block_ids = block_ids;
+                }
+            })
+            .ok();
+    });
+}
+
+/// The label for each method of `source`, keyed by its decorator's offset.
+fn lens_labels(path: &Path, source: &str) -> Vec<(usize, SharedString)> {
+    let methods = find_methods(source);
+    if methods.is_empty() {
+        return Vec::new();
+    }
+    let label_all = |label: &'static str| {
+        methods
+            .iter()
+            .map(|method| (method.decorator_offset, label.into()))
+            .collect()
+    };
+    let Some(artifact_path) = find_artifact(path) else {
+        return label_all("not compiled yet");
+    };
+    let artifact = match Artifact::load(&artifact_path) {
+        Ok(artifact) => artifact,
+        Err(err) => {
+            log::warn!("{err:#}");
+            return label_all("artifact unreadable");
+        }
+    };
+
+    let total = artifact.script_size();
+    let method_sizes = artifact.method_sizes().unwrap_or_default();
+    let modified = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified());
+    let stale = match (modified(path), modified(&artifact_path)) {
+        (Ok(source), Ok(compiled)) => compiled < source,
+        _ => false,
+    };
+
+    methods
+        .into_iter()
+        .map(|method| {
+            let size = method_sizes
+                .iter()
+                .find(|(name, _)| *name == method.name)
+                .map(|(_, size)| *size);
+            let mut label = match size {
+                _ if !method.public => "inlined where it's called".to_string(),
+                Some(size) if method_sizes.len() > 1 => {
+                    format!("{} · contract {} bytes", describe(size), total.bytes)
+                }
+                Some(size) => describe(size),
+                None => format!("contract {}", describe(total)),
+            };
+            if stale && method.public {
+                label.push_str(" · compiled before the last save");
+            }
+            (method.decorator_offset, label.into())
+        })
+        .collect()
+}
+
+fn describe(size: ScriptSize) -> String {
+    let bytes = if size.has_placeholders {
+        format!("{} bytes + constructor args", size.bytes)
+    } else {
+        format!("{} bytes", size.bytes)
+    };
+    format!("{bytes} · {} ops", size.ops)
+}
+
+/// The artifact of the contract in `path`, in the nearest ancestor that has one.
+fn find_artifact(path: &Path) -> Option<PathBuf> {
+    let file_name = path.file_name()?.to_string_lossy();
+    let contract = file_name.strip_suffix(".scrypt.ts")?;
+    path.ancestors()
+        .skip(1)
+        .flat_map(find_artifacts)
+        .find(|artifact| artifact_name(artifact) == contract)
+}
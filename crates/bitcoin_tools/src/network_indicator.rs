@@ -0,0 +1,223 @@
+//! The network a Bitcoin project's `.env` points at, and where its deploy key
+//! lives, in the status bar - so nothing goes to mainnet by surprise.
+
+use gpui::{Corner, Entity, PromptLevel, Subscription, Task, WeakEntity};
+use project::Project;
+use ui::{ContextMenu, PopoverMenu, Tooltip, prelude::*};
+use workspace::{StatusItemView, Workspace, item::ItemHandle};
+
+use crate::{
+    WalletPanel,
+    bitcoin_project::{BitcoinProject, Network, project_root},
+    keychain::KeyLocation,
+    wallet_panel::WALLET_ADDRESS_KEY,
+};
+
+/// Root files whose changes can move the project to another network or key.
+pub(crate) const ENV_FILES: [&str; 3] = [".env", ".env.testnet", ".env.mainnet"];
+
+pub struct NetworkIndicator {
+    workspace: WeakEntity<Workspace>,
+    project: Entity<Project>,
+    /// `None` outside Bitcoin projects, which hides the indicator.
+    bitcoin_project: Option<BitcoinProject>,
+    load_task: Task<()>,
+    switch_task: Task<()>,
+    _subscription: Subscription,
+}
+
+impl NetworkIndicator {
+    pub fn new(workspace: &Workspace, cx: &mut Context<Self>) -> Self {
+        let project = workspace.project().clone();
+        let subscription = cx.subscribe(&project, |this, _, event, cx| match event {
+            project::Event::WorktreeAdded(_) | project::Event::WorktreeRemoved(_) => {
+                this.reload(cx)
+            }
+            project::Event::WorktreeUpdatedEntries(_, entries) => {
+                if entries
+                    .iter()
+                    .any(|(path, _, _)| ENV_FILES.contains(&path.as_unix_str()))
+                {
+                    this.reload(cx);
+                }
+            }
+            _ => {}
+        });
+
+        let mut this = Self {
+            workspace: workspace.weak_handle(),
+            project,
+            bitcoin_project: None,
+            load_task: Task::ready(()),
+            switch_task: Task::ready(()),
+            _subscription: subscription,
+        };
+        this.reload(cx);
+        this
+    }
+
+    fn reload(&mut self, cx: &mut Context<Self>) {
+        let root = project_root(&self.project, cx);
+        self.load_task = cx.spawn(async move |this, cx| {
+            let bitcoin_project = match root {
+                Some(root) => cx
+                    .background_spawn(async move { BitcoinProject::load(&root) })
+                    .await
+                    .unwrap_or_else(|err| {
+                        log::warn!("reading the Bitcoin project: {err:#}");
+                        None
+                    }),
+                None => None,
+            };
+            this.update(cx, |this, cx| {
+                this.bitcoin_project = bitcoin_project;
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn switch_network(&mut self, network: Network, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(mut bitcoin_project) = self.bitcoin_project.clone() else {
+            return;
+        };
+        if bitcoin_project.network == network {
+            return;
+        }
+        let confirmation = (network == Network::Mainnet).then(|| {
+            window.prompt(
+                PromptLevel::Warning,
+                "Switch the project to mainnet?",
+                Some("Deploys and contract calls will spend real coins."),
+                &["Switch to Mainnet", "Cancel"],
+                cx,
+            )
+        });
+        self.switch_task = cx.spawn(async move |this, cx| {
+            if let Some(confirmation) = confirmation {
+                if confirmation.await != Ok(0) {
+                    return;
+                }
+            }
+            let result = cx
+                .background_spawn(async move {
+                    bitcoin_project
+                        .use_network(network)
+                        .map(|()| bitcoin_project)
+                })
+                .await;
+            this.update(cx, |this, cx| match result {
+                Ok(bitcoin_project) => {
+                    this.bitcoin_project = Some(bitcoin_project);
+                    cx.notify();
+                }
+                Err(err) => {
+                    let err = format!("{err:#}");
+                    this.workspace
+                        .update(cx, |workspace, cx| workspace.show_error(&err, cx))
+                        .ok();
+                }
+            })
+            .ok();
+        });
+    }
+
+    fn build_menu(
+        &self,
+        current: Network,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<ContextMenu> {
+        let this = cx.weak_entity();
+        let workspace = self.workspace.clone();
+        ContextMenu::build(window, cx, move |menu, _, _| {
+            let menu = [Network::Testnet, Network::Mainnet].into_iter().fold(
+                menu.header("Network"),
+                |menu, network| {
+                    let this = this.clone();
+                    menu.toggleable_entry(
+                        network.name(),
+                        network == current,
+                        IconPosition::Start,
+                        None,
+                        move |window, cx| {
+                            this.update(cx, |this, cx| this.switch_network(network, window, cx))
+                                .ok();
+                        },
+                    )
+                },
+            );
+            menu.separator()
+                .entry("Open Wallet Panel", None, move |window, cx| {
+                    workspace
+                        .update(cx, |workspace, cx| {
+                            workspace.focus_panel::<WalletPanel>(window, cx);
+                        })
+                        .ok();
+                })
+        })
+    }
+}
+
+/// What signs for the project's deploys, as shown in the tooltip.
+fn describe_signer(project: &BitcoinProject) -> String {
+    match KeyLocation::of(project) {
+        KeyLocation::Keychain { address } => format!("Deploy key {address}, in the OS keychain"),
+        KeyLocation::Env => "Deploy key in .env (DEPLOY_PRIVATE_KEY)".to_string(),
+        KeyLocation::Unset => match project.setting(WALLET_ADDRESS_KEY) {
+            Some(address) => format!("No deploy key - watching {address}"),
+            None => "No deploy key - set one up in the Wallet panel".to_string(),
+        },
+    }
+}
+
+impl Render for NetworkIndicator {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let Some(bitcoin_project) = &self.bitcoin_project else {
+            return div();
+        };
+        let network = bitcoin_project.network;
+        let has_key = KeyLocation::of(bitcoin_project) != KeyLocation::Unset;
+        let label = if has_key {
+            network.name().to_string()
+        } else {
+            format!("{} · no key", network.name())
+        };
+        let (icon, color) = match network {
+            Network::Mainnet => (IconName::Warning, Color::Warning),
+            Network::Testnet => (IconName::Public, Color::Muted),
+        };
+        let title = format!("Bitcoin {} (from .env)", network.name());
+        let signer = describe_signer(bitcoin_project);
+        let this = cx.weak_entity();
+
+        div().child(
+            PopoverMenu::new("bitcoin-network")
+                .menu(move |window, cx| {
+                    this.update(cx, |this, cx| this.build_menu(network, window, cx))
+                        .ok()
+                })
+                .anchor(Corner::BottomRight)
+                .trigger_with_tooltip(
+                    Button::new("bitcoin-network-button", label)
+                        .label_size(LabelSize::Small)
+                        .color(color)
+                        .icon(icon)
+                        .icon_position(IconPosition::Start)
+                        .icon_size(IconSize::Small)
+                        .icon_color(color),
+                    move |_window, cx| Tooltip::with_meta(title.clone(), None, signer.clone(), cx),
+                ),
+        )
+    }
+}
+
+impl StatusItemView for NetworkIndicator {
+    fn set_active_pane_item(
+        &mut self,
+        _active_pane_item: Option<&dyn ItemHandle>,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) {
+    }
+}
@@ -0,0 +1,212 @@
+//! Labeled keys for the parties of a multi-party contract test (alice, bob,
+//! an oracle), kept the two ways the deploy key is: in `.env` as
+//! `ALICE_PRIVATE_KEY`, or in the OS keychain with `.env` naming it by
+//! address as `ALICE_KEY_ADDRESS`.
+
+use std::fmt::Write as _;
+
+use anyhow::{Result, bail};
+
+use crate::bitcoin_project::{BitcoinProject, Network};
+
+pub const DEFAULT_LABELS: [&str; 2] = ["alice", "bob"];
+pub const KEYCHAIN_FLAG: &str = "--keychain";
+pub const MAINNET_FLAG: &str = "--mainnet";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Storage {
+    Env,
+    Keychain,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeysRequest {
+    pub labels: Vec<String>,
+    pub storage: Storage,
+    pub network: Network,
+}
+
+/// A key that was generated and stored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartyKey {
+    pub label: String,
+    pub wif: String,
+    pub public_key: String,
+    pub address: String,
+}
+
+/// Parses `/keys [label...] [--keychain] [--mainnet]`. Keys are for testnet
+/// unless `--mainnet` is given, and mainnet keys only go to the keychain.
+pub fn parse_args(args: &[String]) -> Result<KeysRequest> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut storage = Storage::Env;
+    let mut network = Network::Testnet;
+    for arg in args.iter().flat_map(|arg| arg.split([' ', ','])) {
+        match arg.trim() {
+            "" => {}
+            KEYCHAIN_FLAG => storage = Storage::Keychain,
+            MAINNET_FLAG => network = Network::Mainnet,
+            flag if flag.starts_with("--") => {
+                bail!("unknown flag `{flag}` - use {KEYCHAIN_FLAG} or {MAINNET_FLAG}")
+            }
+            label => {
+                if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    || label.starts_with(|c: char| c.is_ascii_digit())
+                {
+                    bail!("`{label}` can't be a label - use letters, digits and underscores");
+                }
+                let label = label.to_ascii_lowercase();
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+        }
+    }
+    if network == Network::Mainnet && storage == Storage::Env {
+        bail!(
+            "mainnet keys hold real coins, so /keys only stores them in the OS keychain - \
+             add {KEYCHAIN_FLAG}"
+        );
+    }
+    if labels.is_empty() {
+        labels = DEFAULT_LABELS.map(String::from).to_vec();
+    }
+    Ok(KeysRequest {
+        labels,
+        storage,
+        network,
+    })
+}
+
+/// The `.env` setting holding `label`'s key, or its address when the key is
+/// in the keychain.
+pub fn setting_name(label: &str, storage: Storage) -> String {
+    let label = label.to_ascii_uppercase();
+    match storage {
+        Storage::Env => format!("{label}_PRIVATE_KEY"),
+        Storage::Keychain => format!("{label}_KEY_ADDRESS"),
+    }
+}
+
+/// The setting already holding a key for `label`, either way it's stored.
+pub fn existing_setting(project: &BitcoinProject, label: &str) -> Option<String> {
+    [Storage::Env, Storage::Keychain]
+        .into_iter()
+        .map(|storage| setting_name(label, storage))
+        .find(|name| project.setting(name).is_some())
+}
+
+/// The markdown shown for `/keys`: the new keys, and the labels skipped
+/// because they have one already. WIFs are only shown for testnet keys kept
+/// in `.env`, which is where they already sit in plain text.
+pub fn report(
+    request: &KeysRequest,
+    project_network: Network,
+    generated: &[PartyKey],
+    kept: &[(String, String)],
+) -> String {
+    let mut text = String::new();
+    let network = request.network.name();
+    if !generated.is_empty() {
+        let place = match request.storage {
+            Storage::Env => ".env",
+            Storage::Keychain => "the OS keychain",
+        };
+        writeln!(
+            text,
+            "## {} {network} key(s) saved to {place}\n",
+            generated.len()
+        )
+        .ok();
+        let show_wif = request.storage == Storage::Env && request.network == Network::Testnet;
+        if show_wif {
+            text.push_str(
+                "| Label | Setting | Address | Public key | WIF |\n|---|---|---|---|---|\n",
+            );
+        } else {
+            text.push_str("| Label | Setting | Address | Public key |\n|---|---|---|---|\n");
+        }
+        for key in generated {
+            write!(
+                text,
+                "| {} | `{}` | `{}` | `{}` |",
+                key.label,
+                setting_name(&key.label, request.storage),
+                key.address,
+                key.public_key
+            )
+            .ok();
+            if show_wif {
+                write!(text, " `{}` |", key.wif).ok();
+            }
+            text.push('\n');
+        }
+    }
+    if !kept.is_empty() {
+        text.push_str("\nAlready set, so left alone:\n\n");
+        for (label, setting) in kept {
+            writeln!(text, "- {label}: `{setting}`").ok();
+        }
+    }
+
+    if let Some(first) = generated.first() {
+        match request.storage {
+            Storage::Env => writeln!(
+                text,
+                "\nIn tests and scripts: `bsv.PrivateKey.fromWIF(process.env.{})`.",
+                setting_name(&first.label, Storage::Env)
+            ),
+            Storage::Keychain => writeln!(
+                text,
+                "\nEach key is in the keychain under `utxix-key://<address>`; `.env` only \
+                 records the address."
+            ),
+        }
+        .ok();
+        if request.network == Network::Testnet {
+            text.push_str("Fund them from the testnet faucet at https://scrypt.io/faucet.\n");
+        }
+        if request.network != project_network {
+            writeln!(
+                text,
+                "\nThe project targets {}, so it won't accept these {network} keys until it's \
+                 switched over.",
+                project_network.name()
+            )
+            .ok();
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let args =
+            |text: &str| -> Vec<String> { text.split_whitespace().map(String::from).collect() };
+        assert_eq!(
+            parse_args(&[]).unwrap(),
+            KeysRequest {
+                labels: vec!["alice".into(), "bob".into()],
+                storage: Storage::Env,
+                network: Network::Testnet,
+            }
+        );
+        let request = parse_args(&args("Alice, oracle alice --keychain")).unwrap();
+        assert_eq!(request.labels, ["alice", "oracle"]);
+        assert_eq!(request.storage, Storage::Keychain);
+        assert_eq!(
+            setting_name("oracle", request.storage),
+            "ORACLE_KEY_ADDRESS"
+        );
+
+        assert!(parse_args(&args("alice --mainnet")).is_err());
+        let request = parse_args(&args("treasury --mainnet --keychain")).unwrap();
+        assert_eq!(request.network, Network::Mainnet);
+        assert!(parse_args(&args("alice-2")).is_err());
+        assert!(parse_args(&args("--force")).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! `/proof <txid>`: checks a transaction's merkle proof against its block
+//! header and prints the scrypt-ts that checks it on-chain.
+
+use std::sync::{Arc, atomic::AtomicBool};
+
+use anyhow::{Context as _, Result, anyhow};
+use assistant_slash_command::{
+    ArgumentCompletion, SlashCommand, SlashCommandOutput, SlashCommandOutputSection,
+    SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use settings::Settings as _;
+use ui::prelude::*;
+use workspace::Workspace;
+
+use crate::{
+    bitcoin_project::{BitcoinProject, workspace_project_root},
+    chain_provider::ChainProviderSettings,
+    spv::{fetch_verified_proof, report},
+};
+
+pub struct ProofSlashCommand;
+
+impl SlashCommand for ProofSlashCommand {
+    fn name(&self) -> String {
+        "proof".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("proof", &["<txid>"], cx)
+    }
+
+    fn description(&self) -> String {
+        "Verify a transaction's merkle proof and generate an sCrypt verifier".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::ShieldCheck
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        _arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        _workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        Task::ready(Ok(Vec::new()))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        let txid = arguments.concat();
+        if txid.trim().is_empty() {
+            return Task::ready(Err(anyhow!("usage: /proof <txid>")));
+        }
+        let Some(root) = workspace_project_root(&workspace, cx) else {
+            return Task::ready(Err(anyhow!("no project is open")));
+        };
+        let http = cx.http_client();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        cx.background_spawn(async move {
+            let project = BitcoinProject::load(&root)?
+                .context("no .env found - create one from .env.testnet")?;
+            let proof = fetch_verified_proof(http, &project, &provider, &txid).await?;
+            let text = report(&proof, project.network.name(), &project.tx_url(&proof.txid));
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::ShieldCheck,
+                    label: format!("Merkle Proof ({})", project.network.name()).into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
@@ -0,0 +1,549 @@
+//! Quick fixes for mistakes that keep coming up in sCrypt projects. Fixes are
+//! worked out from the source text alone, for the lines a code action is
+//! requested on.
+
+use std::{ops::Range, path::Path};
+
+use crate::contract_source::{ends_with_assert, find_methods, mask_source};
+
+/// Math functions with a bigint replacement: a scrypt-ts builtin, or `None`
+/// where bigint division already gives the result.
+const MATH_REPLACEMENTS: [(&str, Option<&str>); 5] = [
+    ("abs", Some("abs")),
+    ("min", Some("min")),
+    ("max", Some("max")),
+    ("floor", None),
+    ("trunc", None),
+];
+
+/// A fix for one mistake, as edits in ascending, non-overlapping order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuickFix {
+    pub title: String,
+    pub edits: Vec<(Range<usize>, String)>,
+}
+
+/// Files that import contracts: scripts, tests and frontend code.
+pub fn is_script_file(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    !name.ends_with(".scrypt.ts")
+        && [".ts", ".tsx", ".js", ".jsx", ".mjs"]
+            .iter()
+            .any(|extension| name.ends_with(extension))
+}
+
+/// Fixes for a contract source that apply to the lines `range` touches.
+pub fn contract_fixes(source: &str, range: Range<usize>) -> Vec<QuickFix> {
+    let masked = mask_source(source);
+    let lines = line_range(source, range.clone());
+    let mut fixes = Vec::new();
+    fixes.extend(bigint_fix(&masked, lines.clone()));
+    fixes.extend(math_fix(source, &masked, lines));
+    fixes.extend(assert_fixes(source, &masked, range));
+    fixes
+}
+
+/// Fixes for a file outside `contracts/` that apply to the lines `range`
+/// touches: contract imports used without loading the compiled artifact.
+pub fn script_fixes(source: &str, range: Range<usize>) -> Vec<QuickFix> {
+    let masked = mask_source(source);
+    let lines = line_range(source, range);
+    let imports = find_imports(&masked);
+    let Some(insert_at) = imports.last().map(|import| import.statement.end) else {
+        return Vec::new();
+    };
+
+    let mut fixes = Vec::new();
+    for import in &imports {
+        let specifier = &source[import.specifier.clone()];
+        let Some(contract) = specifier
+            .strip_suffix(".scrypt.ts")
+            .or_else(|| specifier.strip_suffix(".scrypt"))
+            .map(|path| path.rsplit('/').next().unwrap_or(path))
+        else {
+            continue;
+        };
+        if import.statement.end < lines.start || import.statement.start > lines.end {
+            continue;
+        }
+        let Some(class) = import
+            .default_name
+            .as_deref()
+            .or_else(|| {
+                import
+                    .names
+                    .iter()
+                    .find(|name| *name == contract)
+                    .map(String::as_str)
+            })
+            .or_else(|| import.names.first().map(String::as_str))
+        else {
+            continue;
+        };
+        if masked.contains(&format!("{class}.loadArtifact(")) {
+            continue;
+        }
+
+        let mut edits = Vec::new();
+        if specifier.ends_with(".ts") {
+            let end = import.specifier.end;
+            edits.push((end - ".ts".len()..end, String::new()));
+        }
+        edits.push((insert_at..insert_at, artifact_loader(class, contract)));
+        fixes.push(QuickFix {
+            title: format!("Load `{class}` from its compiled artifact"),
+            edits,
+        });
+    }
+    fixes
+}
+
+/// The whole lines `range` touches.
+fn line_range(source: &str, range: Range<usize>) -> Range<usize> {
+    let start = source[..range.start].rfind('\n').map_or(0, |ix| ix + 1);
+    let end = source[range.end..]
+        .find('\n')
+        .map_or(source.len(), |ix| range.end + ix);
+    start..end
+}
+
+/// `: number` annotations and integer literals turned into bigints. Loop
+/// headers, array indices and type arguments stay numbers, as sCrypt needs
+/// them known at compile time.
+fn bigint_fix(masked: &str, lines: Range<usize>) -> Option<QuickFix> {
+    let mut edits = Vec::new();
+    let mut line_start = lines.start;
+    for line in masked[lines].split('\n') {
+        let trimmed = line.trim_start();
+        if !trimmed.starts_with("for ") && !trimmed.starts_with("for(") {
+            number_edits(line, line_start, &mut edits);
+        }
+        line_start += line.len() + 1;
+    }
+    (!edits.is_empty()).then(|| QuickFix {
+        title: "Use bigint instead of number".to_string(),
+        edits,
+    })
+}
+
+fn number_edits(line: &str, offset: usize, edits: &mut Vec<(Range<usize>, String)>) {
+    let bytes = line.as_bytes();
+    let is_word = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$';
+    let mut brackets = 0usize;
+    let mut type_args = 0usize;
+    let mut ix = 0;
+    while ix < bytes.len() {
+        let byte = bytes[ix];
+        let after_word = ix > 0 && (is_word(bytes[ix - 1]) || bytes[ix - 1] == b'.');
+        match byte {
+            b'[' => brackets += 1,
+            b']' => brackets = brackets.saturating_sub(1),
+            b'<' if after_word => type_args += 1,
+            b'>' if type_args > 0 => type_args -= 1,
+            b'0'..=b'9' if !after_word => {
+                let hex = bytes[ix..].starts_with(b"0x") || bytes[ix..].starts_with(b"0X");
+                let start = ix;
+                ix += if hex { 2 } else { 0 };
+                while ix < bytes.len()
+                    && (bytes[ix] == b'_'
+                        || if hex {
+                            bytes[ix].is_ascii_hexdigit()
+                        } else {
+                            bytes[ix].is_ascii_digit()
+                        })
+                {
+                    ix += 1;
+                }
+                let next = bytes.get(ix).copied();
+                let is_integer = !next.is_some_and(|next| is_word(next) || next == b'.');
+                if is_integer && ix > start && brackets == 0 && type_args == 0 {
+                    edits.push((offset + ix..offset + ix, "n".to_string()));
+                }
+                continue;
+            }
+            b'n' if bytes[ix..].starts_with(b"number") && !after_word => {
+                let end = ix + "number".len();
+                let annotated = line[..ix].trim_end().ends_with(':');
+                if annotated && !bytes.get(end).copied().is_some_and(is_word) {
+                    edits.push((offset + ix..offset + end, "bigint".to_string()));
+                }
+                ix = end;
+                continue;
+            }
+            _ => {}
+        }
+        ix += 1;
+    }
+}
+
+/// `Math.*` calls replaced with scrypt-ts builtins, importing them if needed.
+fn math_fix(source: &str, masked: &str, lines: Range<usize>) -> Option<QuickFix> {
+    let mut edits = Vec::new();
+    let mut builtins = Vec::new();
+    for (ix, _) in masked[lines.clone()].match_indices("Math.") {
+        let start = lines.start + ix;
+        if masked[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        {
+            continue;
+        }
+        let call = &masked[start + "Math.".len()..];
+        let Some((function, builtin)) = MATH_REPLACEMENTS.iter().find(|(function, _)| {
+            call.strip_prefix(function)
+                .is_some_and(|rest| rest.starts_with('('))
+        }) else {
+            continue;
+        };
+        match builtin {
+            // `Math.abs(x)` becomes `abs(x)`
+            Some(builtin) => {
+                edits.push((start..start + "Math.".len(), String::new()));
+                if !builtins.contains(builtin) {
+                    builtins.push(*builtin);
+                }
+            }
+            // `Math.floor(a / b)` becomes `(a / b)`
+            None => edits.push((start..start + "Math.".len() + function.len(), String::new())),
+        }
+    }
+    if edits.is_empty() {
+        return None;
+    }
+    edits.extend(import_edit(source, masked, &builtins));
+    edits.sort_by_key(|(range, _)| range.start);
+    Some(QuickFix {
+        title: "Replace Math.* with bigint arithmetic".to_string(),
+        edits,
+    })
+}
+
+/// Adds whichever of `names` are missing to the `scrypt-ts` import.
+fn import_edit(source: &str, masked: &str, names: &[&str]) -> Option<(Range<usize>, String)> {
+    let imports = find_imports(masked);
+    let Some(import) = imports
+        .iter()
+        .find(|import| &source[import.specifier.clone()] == "scrypt-ts")
+    else {
+        let missing = names.join(", ");
+        return (!names.is_empty()).then(|| {
+            (
+                0..0,
+                format!("import {{ {missing} }} from \"scrypt-ts\";\n"),
+            )
+        });
+    };
+    let missing: Vec<&str> = names
+        .iter()
+        .copied()
+        .filter(|name| !import.names.iter().any(|imported| imported == name))
+        .collect();
+    if missing.is_empty() {
+        return None;
+    }
+    let braces = import.braces.clone()?;
+
+    let inner = &source[braces.start + 1..braces.end - 1];
+    let last_name_end = braces.start + 1 + inner.trim_end().trim_end_matches(',').len();
+    let trailing_comma = inner.trim_end().ends_with(',');
+    if inner.contains('\n') {
+        let line_start = source[..last_name_end].rfind('\n').map_or(0, |ix| ix + 1);
+        let indent: String = source[line_start..]
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+        let mut text: String = missing
+            .iter()
+            .map(|name| format!(",\n{indent}{name}"))
+            .collect();
+        if trailing_comma {
+            text.push(',');
+        }
+        let end = last_name_end + usize::from(trailing_comma);
+        Some((last_name_end..end, text))
+    } else {
+        let text: String = missing.iter().map(|name| format!(", {name}")).collect();
+        Some((last_name_end..last_name_end, text))
+    }
+}
+
+/// A final `assert(true)` for each public method in `range` whose body ends
+/// in anything else, which the sCrypt compiler rejects.
+fn assert_fixes(source: &str, masked: &str, range: Range<usize>) -> Vec<QuickFix> {
+    let semicolons = source.contains(";\n");
+    let mut fixes = Vec::new();
+    for method in find_methods(source) {
+        let Some(body) = method.body.clone().filter(|_| method.public) else {
+            continue;
+        };
+        if method.decorator_offset > range.end || body.end < range.start {
+            continue;
+        }
+        let inner = body.start + 1..body.end - 1;
+        if ends_with_assert(&masked[inner.clone()]) {
+            continue;
+        }
+
+        let decorator_indent = indentation(source, method.decorator_offset);
+        let body_indent = source[inner.clone()]
+            .split('\n')
+            .skip(1)
+            .find(|line| !line.trim().is_empty())
+            .map(|line| line[..line.len() - line.trim_start().len()].to_string())
+            .unwrap_or_else(|| decorator_indent.repeat(2));
+        let statement = format!("assert(true){}", if semicolons { ";" } else { "" });
+        let text = &source[inner.clone()];
+        let edit = if text.trim().is_empty() {
+            (
+                inner,
+                format!("\n{body_indent}{statement}\n{decorator_indent}"),
+            )
+        } else {
+            let at = inner.start + text.trim_end().len();
+            (at..at, format!("\n{body_indent}{statement}"))
+        };
+        fixes.push(QuickFix {
+            title: format!("End `{}` with assert(true)", method.name),
+            edits: vec![edit],
+        });
+    }
+    fixes
+}
+
+fn indentation(source: &str, offset: usize) -> String {
+    let line_start = source[..offset].rfind('\n').map_or(0, |ix| ix + 1);
+    source[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+struct Import {
+    /// From `import` to the end of the line the statement ends on.
+    statement: Range<usize>,
+    /// The module path, without quotes.
+    specifier: Range<usize>,
+    /// The `{ ... }` of named imports.
+    braces: Option<Range<usize>>,
+    default_name: Option<String>,
+    names: Vec<String>,
+}
+
+fn find_imports(masked: &str) -> Vec<Import> {
+    let mut imports = Vec::new();
+    let mut line_start = 0;
+    for line in masked.split_inclusive('\n') {
+        let start = line_start + line.len() - line.trim_start().len();
+        line_start += line.len();
+        let Some(rest) = masked[start..].strip_prefix("import") else {
+            continue;
+        };
+        if !rest.starts_with([' ', '\t', '{', '\n']) {
+            continue;
+        }
+        let clause_start = start + "import".len();
+        let Some(quote) = masked[clause_start..].find(['"', '\'']) else {
+            continue;
+        };
+        let specifier_start = clause_start + quote + 1;
+        let Some(specifier_len) = masked[specifier_start..].find(['"', '\'']) else {
+            continue;
+        };
+        let specifier = specifier_start..specifier_start + specifier_len;
+        let statement_end = masked[specifier.end..]
+            .find('\n')
+            .map_or(masked.len(), |ix| specifier.end + ix);
+
+        let clause = &masked[clause_start..clause_start + quote];
+        let braces = clause.find('{').and_then(|open| {
+            let open = clause_start + open;
+            let close = open + masked[open..].find('}')? + 1;
+            Some(open..close)
+        });
+        let clause = clause.trim_end().trim_end_matches("from").trim();
+        let clause = clause.strip_prefix("type ").unwrap_or(clause);
+        let default_name = clause
+            .split(['{', ','])
+            .next()
+            .map(str::trim)
+            .filter(|name| !name.is_empty() && !name.starts_with('*'))
+            .map(str::to_string);
+        let names = braces
+            .as_ref()
+            .map(|braces| {
+                masked[braces.start + 1..braces.end - 1]
+                    .split(',')
+                    .filter_map(|name| {
+                        let name = name.trim();
+                        let name = name.strip_prefix("type ").unwrap_or(name);
+                        let name = name.rsplit(" as ").next().unwrap_or(name).trim();
+                        (!name.is_empty()).then(|| name.to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        imports.push(Import {
+            statement: start..statement_end,
+            specifier,
+            braces,
+            default_name,
+            names,
+        });
+    }
+    imports
+}
+
+fn artifact_loader(class: &str, contract: &str) -> String {
+    let mut constant = String::new();
+    let mut prev: Option<char> = None;
+    for c in class.chars() {
+        if c.is_uppercase() && prev.is_some_and(|prev| prev.is_lowercase() || prev.is_ascii_digit())
+        {
+            constant.push('_');
+        }
+        constant.push(c.to_ascii_uppercase());
+        prev = Some(c);
+    }
+    let path = format!("{constant}_ARTIFACT_PATH");
+    let mut chars = class.chars();
+    let loaded = match chars.next() {
+        Some(first) => format!(
+            "{}{}ArtifactLoaded",
+            first.to_ascii_lowercase(),
+            chars.as_str()
+        ),
+        None => "artifactLoaded".to_string(),
+    };
+    format!(
+        r#"
+
+const {path} = "/artifacts/contracts/{contract}.scrypt.json";
+let {loaded} = false;
+
+/** Loads the compiled contract; await it before constructing a {class}. */
+async function load{class}Artifact() {{
+  if ({loaded}) return;
+  const res = await fetch({path});
+  if (!res.ok) {{
+    throw new Error(
+      "Contract artifact missing at " + {path} + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }}
+  await {class}.loadArtifact(await res.json());
+  {loaded} = true;
+}}"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(source: &str, fix: &QuickFix) -> String {
+        let mut text = source.to_string();
+        for (range, new_text) in fix.edits.iter().rev() {
+            text.replace_range(range.clone(), new_text);
+        }
+        text
+    }
+
+    fn fix_titled(fixes: Vec<QuickFix>, title: &str) -> QuickFix {
+        fixes
+            .into_iter()
+            .find(|fix| fix.title == title)
+            .unwrap_or_else(|| panic!("no fix titled {title}"))
+    }
+
+    #[test]
+    fn test_contract_fixes() {
+        let source = r#"import { assert, method, prop, SmartContract } from "scrypt-ts";
+
+export class Demo extends SmartContract {
+  @prop()
+  limit: number;
+
+  @method()
+  public check(a: bigint, sigs: FixedArray<Sig, 2>) {
+    const x: number = Math.max(a, 10) + Math.floor(a / 2);
+    for (let i = 0; i < 3; i++) {
+      assert(sigs[0] == sigs[i], "same 1");
+    }
+  }
+}
+"#;
+        let line = source.find("const x").unwrap();
+        let fixes = contract_fixes(source, line..line);
+        assert_eq!(
+            fixes
+                .iter()
+                .map(|fix| fix.title.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "Use bigint instead of number",
+                "Replace Math.* with bigint arithmetic",
+                "End `check` with assert(true)",
+            ]
+        );
+
+        let bigint = apply(
+            source,
+            &fix_titled(fixes.clone(), "Use bigint instead of number"),
+        );
+        assert!(bigint.contains("const x: bigint = Math.max(a, 10n) + Math.floor(a / 2n);"));
+        assert!(bigint.contains("limit: number;"));
+        assert!(bigint.contains("for (let i = 0; i < 3; i++)"));
+
+        let math = apply(
+            source,
+            &fix_titled(fixes.clone(), "Replace Math.* with bigint arithmetic"),
+        );
+        assert!(math.contains("import { assert, method, prop, SmartContract, max } from"));
+        assert!(math.contains("const x: number = max(a, 10) + (a / 2);"));
+
+        let asserted = apply(source, &fix_titled(fixes, "End `check` with assert(true)"));
+        assert!(asserted.contains("    }\n    assert(true);\n  }\n}"));
+
+        let sigs = source.find("sigs: Fixed").unwrap();
+        assert!(
+            contract_fixes(&asserted, sigs..sigs)
+                .iter()
+                .all(|fix| !fix.title.starts_with("End"))
+        );
+    }
+
+    #[test]
+    fn test_multiline_import() {
+        let source =
+            "import {\n  SmartContract,\n  assert,\n} from 'scrypt-ts'\nconst y = Math.abs(x)\n";
+        let line = source.find("const").unwrap();
+        let fix = fix_titled(
+            contract_fixes(source, line..line),
+            "Replace Math.* with bigint arithmetic",
+        );
+        assert_eq!(
+            apply(source, &fix),
+            "import {\n  SmartContract,\n  assert,\n  abs,\n} from 'scrypt-ts'\nconst y = abs(x)\n"
+        );
+    }
+
+    #[test]
+    fn test_script_fixes() {
+        let source = "import { bsv } from \"scrypt-ts\";\nimport TicTacToe from \"../contracts/TicTacToe.scrypt.ts\";\n\nconst game = new TicTacToe(alice, bob);\n";
+        let line = source.find("TicTacToe from").unwrap();
+        let fixes = script_fixes(source, line..line);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            fixes[0].title,
+            "Load `TicTacToe` from its compiled artifact"
+        );
+        let fixed = apply(source, &fixes[0]);
+        assert!(fixed.contains("import TicTacToe from \"../contracts/TicTacToe.scrypt\";\n\nconst TIC_TAC_TOE_ARTIFACT_PATH = \"/artifacts/contracts/TicTacToe.scrypt.json\";\nlet ticTacToeArtifactLoaded = false;"));
+        assert!(fixed.contains("await TicTacToe.loadArtifact(await res.json());"));
+        assert!(script_fixes(&fixed, line..line).is_empty());
+    }
+}
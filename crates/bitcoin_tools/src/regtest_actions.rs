@@ -0,0 +1,138 @@
+//! Actions driving the project's local regtest node. While it runs, `.env`
+//! points `npm run deploy` (`DEPLOY_NETWORK`) and `npm test`
+//! (`TEST_NETWORK`) at it, so a contract can be deployed and called without
+//! testnet coins or a connection.
+
+use std::future::Future;
+
+use anyhow::{Context as _, Result};
+use gpui::{Context, actions};
+use workspace::{Toast, Workspace, notifications::NotificationId};
+
+use crate::{
+    bitcoin_project::{BitcoinProject, project_root},
+    deploy::deploy_key,
+    keychain::KeyLocation,
+    regtest::{Backend, DEFAULT_FUND_SATS, FUND_SATS_KEY, RegtestNode, RpcEndpoint},
+};
+
+/// `.env` key the scaffold's contract tests read to run against the node.
+const TEST_NETWORK: &str = "TEST_NETWORK";
+
+actions!(
+    regtest,
+    [
+        /// Starts the local regtest node and points the deploy scripts and
+        /// contract tests at it.
+        StartNode,
+        /// Stops the local regtest node and points the project back at its
+        /// network.
+        StopNode,
+        /// Mines a block on the local regtest node.
+        MineBlock,
+        /// Sends regtest coins to the project's deploy key.
+        FundDeployKey
+    ]
+);
+
+struct RegtestToast;
+
+pub fn register_workspace(workspace: &mut Workspace) {
+    workspace
+        .register_action(|workspace, _: &StartNode, _window, cx| {
+            spawn_node_task(workspace, cx, start);
+        })
+        .register_action(|workspace, _: &StopNode, _window, cx| {
+            spawn_node_task(workspace, cx, stop);
+        })
+        .register_action(|workspace, _: &MineBlock, _window, cx| {
+            spawn_node_task(workspace, cx, mine);
+        })
+        .register_action(|workspace, _: &FundDeployKey, _window, cx| {
+            spawn_node_task(workspace, cx, fund);
+        });
+}
+
+/// Runs `task` against the node of the project in the first worktree and
+/// shows what it returns in a toast.
+fn spawn_node_task<F, Fut>(workspace: &mut Workspace, cx: &mut Context<Workspace>, task: F)
+where
+    F: FnOnce(BitcoinProject, RegtestNode) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<String>> + Send + 'static,
+{
+    let Some(root) = project_root(workspace.project(), cx) else {
+        show_toast(workspace, "Open a Bitcoin project first".to_string(), cx);
+        return;
+    };
+    let http = cx.http_client();
+    cx.spawn(async move |workspace, cx| {
+        let result = cx
+            .background_spawn(async move {
+                let project =
+                    BitcoinProject::load(&root)?.context("no .env found in the project root")?;
+                let node = RegtestNode::new(http, RpcEndpoint::of(&project)?);
+                task(project, node).await
+            })
+            .await;
+        let message = result.unwrap_or_else(|err| format!("Regtest node: {err:#}"));
+        workspace
+            .update(cx, |workspace, cx| show_toast(workspace, message, cx))
+            .ok();
+    })
+    .detach();
+}
+
+fn show_toast(workspace: &mut Workspace, message: String, cx: &mut Context<Workspace>) {
+    workspace.show_toast(
+        Toast::new(NotificationId::unique::<RegtestToast>(), message),
+        cx,
+    );
+}
+
+async fn start(mut project: BitcoinProject, node: RegtestNode) -> Result<String> {
+    let backend = node.start(&project.root).await?;
+    project.set_setting("DEPLOY_NETWORK", Some("regtest"))?;
+    project.set_setting(TEST_NETWORK, Some("regtest"))?;
+    let height = node.block_count().await?;
+    let started_with = match backend {
+        Backend::Docker => "docker compose",
+        Backend::Bitcoind => "bitcoind",
+    };
+    Ok(format!(
+        "Regtest node running ({started_with}, height {height}) - npm run deploy and npm test now use it"
+    ))
+}
+
+async fn stop(mut project: BitcoinProject, node: RegtestNode) -> Result<String> {
+    node.stop(&project.root).await?;
+    project.set_setting("DEPLOY_NETWORK", Some(""))?;
+    project.set_setting(TEST_NETWORK, None)?;
+    Ok(format!(
+        "Regtest node stopped - npm run deploy is back on {}, npm test on the local mock",
+        project.network.name()
+    ))
+}
+
+async fn mine(_project: BitcoinProject, node: RegtestNode) -> Result<String> {
+    node.generate(1).await?;
+    Ok(format!("Mined block {}", node.block_count().await?))
+}
+
+async fn fund(project: BitcoinProject, node: RegtestNode) -> Result<String> {
+    let address = match KeyLocation::of(&project) {
+        KeyLocation::Keychain { address } => address,
+        KeyLocation::Env => deploy_key(&project)?.address(),
+        KeyLocation::Unset => {
+            anyhow::bail!("the project has no deploy key - generate one in the Wallet panel")
+        }
+    };
+    let satoshis = project
+        .setting(FUND_SATS_KEY)
+        .and_then(|value| value.replace('_', "").parse().ok())
+        .unwrap_or(DEFAULT_FUND_SATS);
+    let txid = node.fund(&address, satoshis).await?;
+    Ok(format!(
+        "Sent {satoshis} sats to {address} on regtest (txid {})",
+        &txid[..txid.len().min(8)]
+    ))
+}
@@ -0,0 +1,585 @@
+use std::{ops::Range, path::PathBuf};
+
+use anyhow::{Context as _, Result};
+use bitcoin_script::{Interpreter, Phase, decode_num};
+use gpui::{
+    Action, App, AsyncWindowContext, Context, Entity, EventEmitter, FocusHandle, Focusable, Pixels,
+    Render, ScrollStrategy, SharedString, Task, UniformListScrollHandle, WeakEntity, Window,
+    actions, px, uniform_list,
+};
+use project::Project;
+use ui::{
+    Button, ButtonStyle, IconButton, IconName, Label, LabelSize, Tab, Tooltip, WithScrollbar,
+    prelude::*,
+};
+use ui_input::InputField;
+use workspace::{
+    Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
+};
+
+use crate::{
+    artifact::{Artifact, artifact_name, find_artifacts, split_args},
+    bitcoin_project::project_root,
+};
+
+const SCRIPT_DEBUGGER_PANEL_KEY: &str = "ScriptDebuggerPanel";
+/// Longest push shown in full in the program listing.
+const MAX_INLINE_PUSH_HEX: usize = 32;
+
+actions!(
+    script_debugger,
+    [
+        /// Toggles focus on the script debugger panel.
+        ToggleFocus
+    ]
+);
+
+/// Steps through a contract's unlocking and locking scripts, showing both stacks.
+pub struct ScriptDebuggerPanel {
+    focus_handle: FocusHandle,
+    project: Entity<Project>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    artifacts: Vec<PathBuf>,
+    artifact: Option<(PathBuf, Artifact)>,
+    method: Option<String>,
+    constructor_args: Entity<InputField>,
+    method_args: Entity<InputField>,
+    preimage: Entity<InputField>,
+    interpreter: Option<Interpreter>,
+    error: Option<SharedString>,
+    scroll_handle: UniformListScrollHandle,
+    scan_task: Task<()>,
+}
+
+impl ScriptDebuggerPanel {
+    pub fn load(
+        workspace: WeakEntity<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<Entity<Self>>> {
+        cx.spawn(async move |cx| {
+            workspace.update_in(cx, |workspace, window, cx| Self::new(workspace, window, cx))
+        })
+    }
+
+    fn new(
+        workspace: &mut Workspace,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) -> Entity<Self> {
+        let project = workspace.project().clone();
+        cx.new(|cx| {
+            let constructor_args = cx.new(|cx| {
+                InputField::new(window, cx, "e.g. 42, 0x02ab…, [1, 2, 3]").label("Constructor args")
+            });
+            let method_args = cx.new(|cx| {
+                InputField::new(window, cx, "e.g. 'hello world', 3n").label("Method args")
+            });
+            let preimage = cx.new(|cx| {
+                InputField::new(window, cx, "hex, needed for OP_CHECKSIG")
+                    .label("Sighash preimage (optional)")
+            });
+
+            let mut this = Self {
+                focus_handle: cx.focus_handle(),
+                project,
+                position: DockPosition::Right,
+                width: None,
+                artifacts: Vec::new(),
+                artifact: None,
+                method: None,
+                constructor_args,
+                method_args,
+                preimage,
+                interpreter: None,
+                error: None,
+                scroll_handle: UniformListScrollHandle::new(),
+                scan_task: Task::ready(()),
+            };
+            this.scan_artifacts(cx);
+            this
+        })
+    }
+
+    fn scan_artifacts(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            self.artifacts.clear();
+            cx.notify();
+            return;
+        };
+        self.scan_task = cx.spawn(async move |this, cx| {
+            let artifacts = cx
+                .background_spawn(async move { find_artifacts(&root) })
+                .await;
+            this.update(cx, |this, cx| {
+                this.artifacts = artifacts;
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn select_artifact(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        match Artifact::load(&path) {
+            Ok(artifact) => {
+                self.method = artifact
+                    .public_methods()
+                    .next()
+                    .and_then(|entry| entry.name.clone());
+                self.artifact = Some((path, artifact));
+                self.error = None;
+            }
+            Err(err) => {
+                self.artifact = None;
+                self.method = None;
+                self.error = Some(format!("{err:#}").into());
+            }
+        }
+        self.interpreter = None;
+        cx.notify();
+    }
+
+    fn select_method(&mut self, method: String, cx: &mut Context<Self>) {
+        self.method = Some(method);
+        self.interpreter = None;
+        cx.notify();
+    }
+
+    fn start(&mut self, cx: &mut Context<Self>) {
+        self.interpreter = None;
+        self.error = match self.build_interpreter(cx) {
+            Ok(interpreter) => {
+                self.interpreter = Some(interpreter);
+                self.scroll_handle.scroll_to_item(0, ScrollStrategy::Top);
+                None
+            }
+            Err(err) => Some(format!("{err:#}").into()),
+        };
+        cx.notify();
+    }
+
+    fn build_interpreter(&self, cx: &App) -> Result<Interpreter> {
+        let (_, artifact) = self.artifact.as_ref().context("choose an artifact first")?;
+        let method = self.method.as_deref().context("choose a public method")?;
+        let locking =
+            artifact.locking_script(&split_args(&self.constructor_args.read(cx).text(cx)))?;
+        let unlocking =
+            artifact.unlocking_script(method, &split_args(&self.method_args.read(cx).text(cx)))?;
+        let interpreter = Interpreter::new(&unlocking, &locking)?;
+        let preimage = self.preimage.read(cx).text(cx);
+        let preimage = preimage.trim();
+        if preimage.is_empty() {
+            Ok(interpreter)
+        } else {
+            let preimage = hex::decode(preimage.trim_start_matches("0x"))
+                .context("sighash preimage must be hex")?;
+            Ok(interpreter.with_preimage(preimage))
+        }
+    }
+
+    fn step(&mut self, cx: &mut Context<Self>) {
+        if let Some(interpreter) = self.interpreter.as_mut() {
+            interpreter.step();
+            let pc = interpreter.pc();
+            self.scroll_handle
+                .scroll_to_item(pc, ScrollStrategy::Center);
+            cx.notify();
+        }
+    }
+
+    fn run(&mut self, cx: &mut Context<Self>) {
+        if let Some(interpreter) = self.interpreter.as_mut() {
+            interpreter.run().ok();
+            let pc = interpreter.pc().saturating_sub(1);
+            self.scroll_handle
+                .scroll_to_item(pc, ScrollStrategy::Center);
+            cx.notify();
+        }
+    }
+
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let running = self
+            .interpreter
+            .as_ref()
+            .is_some_and(|interpreter| interpreter.outcome().is_none());
+
+        h_flex()
+            .justify_between()
+            .px_2()
+            .py_1()
+            .h(Tab::container_height(cx))
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(Label::new("Script Debugger"))
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(
+                        IconButton::new("debugger-restart", IconName::RotateCcw)
+                            .icon_size(IconSize::Small)
+                            .disabled(self.artifact.is_none())
+                            .tooltip(Tooltip::text("Load Scripts"))
+                            .on_click(cx.listener(|this, _, _window, cx| this.start(cx))),
+                    )
+                    .child(
+                        IconButton::new("debugger-step", IconName::DebugStepOver)
+                            .icon_size(IconSize::Small)
+                            .disabled(!running)
+                            .tooltip(Tooltip::text("Step"))
+                            .on_click(cx.listener(|this, _, _window, cx| this.step(cx))),
+                    )
+                    .child(
+                        IconButton::new("debugger-run", IconName::DebugContinue)
+                            .icon_size(IconSize::Small)
+                            .disabled(!running)
+                            .tooltip(Tooltip::text("Run to End"))
+                            .on_click(cx.listener(|this, _, _window, cx| this.run(cx))),
+                    ),
+            )
+    }
+
+    fn render_setup(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let selected_path = self.artifact.as_ref().map(|(path, _)| path.clone());
+        let artifact_buttons = self.artifacts.iter().enumerate().map(|(ix, path)| {
+            let name = artifact_name(path);
+            let selected = selected_path.as_ref() == Some(path);
+            let path = path.clone();
+            Button::new(SharedString::from(format!("artifact-{ix}")), name)
+                .style(if selected {
+                    ButtonStyle::Filled
+                } else {
+                    ButtonStyle::Subtle
+                })
+                .label_size(LabelSize::Small)
+                .on_click(
+                    cx.listener(move |this, _, _window, cx| this.select_artifact(path.clone(), cx)),
+                )
+        });
+
+        let methods: Vec<(String, String)> = self
+            .artifact
+            .as_ref()
+            .map(|(_, artifact)| {
+                artifact
+                    .public_methods()
+                    .filter_map(|entry| {
+                        let name = entry.name.clone()?;
+                        let params = entry
+                            .params
+                            .iter()
+                            .map(|param| format!("{}: {}", param.name, param.ty))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        Some((name, params))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let method_params = methods
+            .iter()
+            .find(|(name, _)| Some(name) == self.method.as_ref())
+            .map(|(_, params)| params.clone());
+        let constructor_params = self.artifact.as_ref().map(|(_, artifact)| {
+            artifact
+                .constructor_params()
+                .iter()
+                .map(|param| format!("{}: {}", param.name, param.ty))
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        let method_buttons = methods.into_iter().map(|(name, _)| {
+            let selected = self.method.as_ref() == Some(&name);
+            Button::new(SharedString::from(format!("method-{name}")), name.clone())
+                .style(if selected {
+                    ButtonStyle::Filled
+                } else {
+                    ButtonStyle::Subtle
+                })
+                .label_size(LabelSize::Small)
+                .on_click(
+                    cx.listener(move |this, _, _window, cx| this.select_method(name.clone(), cx)),
+                )
+        });
+
+        v_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .map(|this| {
+                if self.artifacts.is_empty() {
+                    this.child(
+                        Label::new("No artifacts found - run `npx scrypt-cli compile`.")
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                } else {
+                    this.child(h_flex().flex_wrap().gap_1().children(artifact_buttons))
+                }
+            })
+            .child(h_flex().flex_wrap().gap_1().children(method_buttons))
+            .child(self.constructor_args.clone())
+            .children(
+                constructor_params
+                    .filter(|params| !params.is_empty())
+                    .map(|params| {
+                        Label::new(params)
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted)
+                    }),
+            )
+            .child(self.method_args.clone())
+            .children(
+                method_params
+                    .filter(|params| !params.is_empty())
+                    .map(|params| {
+                        Label::new(params)
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted)
+                    }),
+            )
+            .child(self.preimage.clone())
+            .child(
+                Button::new("debugger-load", "Load Scripts")
+                    .style(ButtonStyle::Filled)
+                    .full_width()
+                    .disabled(self.artifact.is_none() || self.method.is_none())
+                    .on_click(cx.listener(|this, _, _window, cx| this.start(cx))),
+            )
+    }
+
+    fn render_status(&self, interpreter: &Interpreter) -> impl IntoElement {
+        let total = interpreter.program().len();
+        let (text, color) = match interpreter.outcome() {
+            None => (
+                format!("Step {} of {}", interpreter.pc(), total),
+                Color::Muted,
+            ),
+            Some(Ok(())) => ("Script succeeded".to_string(), Color::Success),
+            Some(Err(err)) => {
+                let at = interpreter
+                    .pc()
+                    .checked_sub(1)
+                    .and_then(|ix| interpreter.program().get(ix))
+                    .map(|step| {
+                        format!(
+                            " at {} byte {}",
+                            phase_name(step.phase),
+                            step.instruction.offset
+                        )
+                    })
+                    .unwrap_or_default();
+                (format!("Failed{at}: {err}"), Color::Error)
+            }
+        };
+        div()
+            .px_2()
+            .py_1()
+            .child(Label::new(text).size(LabelSize::Small).color(color))
+    }
+
+    fn render_program(
+        &mut self,
+        range: Range<usize>,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<AnyElement> {
+        let Some(interpreter) = self.interpreter.as_ref() else {
+            return Vec::new();
+        };
+        let pc = interpreter.pc();
+        let failed_at = matches!(interpreter.outcome(), Some(Err(_))).then(|| pc.saturating_sub(1));
+        let selected_bg = cx.theme().colors().element_selected;
+
+        interpreter.program()[range.clone()]
+            .iter()
+            .zip(range)
+            .map(|(step, ix)| {
+                let asm = step.instruction.asm();
+                let asm = if asm.len() > MAX_INLINE_PUSH_HEX {
+                    format!("{}… ({} bytes)", &asm[..MAX_INLINE_PUSH_HEX], asm.len() / 2)
+                } else {
+                    asm
+                };
+                let color = if failed_at == Some(ix) {
+                    Color::Error
+                } else if ix == pc {
+                    Color::Accent
+                } else {
+                    Color::Default
+                };
+
+                h_flex()
+                    .px_2()
+                    .gap_2()
+                    .when(ix == pc && failed_at.is_none(), |this| this.bg(selected_bg))
+                    .child(
+                        Label::new(phase_name(step.phase))
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted),
+                    )
+                    .child(Label::new(asm).size(LabelSize::Small).color(color))
+                    .into_any_element()
+            })
+            .collect()
+    }
+
+    fn render_stack(title: &'static str, items: &[Vec<u8>]) -> impl IntoElement {
+        v_flex()
+            .px_2()
+            .py_1()
+            .gap_0p5()
+            .child(
+                Label::new(format!("{title} ({})", items.len()))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+            .when(items.is_empty(), |this| {
+                this.child(
+                    Label::new("empty")
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted),
+                )
+            })
+            // Top of the stack first
+            .children(items.iter().rev().map(|item| {
+                let hex = if item.is_empty() {
+                    "(empty)".to_string()
+                } else if item.len() * 2 > MAX_INLINE_PUSH_HEX {
+                    format!(
+                        "{}… ({} bytes)",
+                        hex::encode(&item[..MAX_INLINE_PUSH_HEX / 2]),
+                        item.len()
+                    )
+                } else {
+                    hex::encode(item)
+                };
+                let number = (item.len() <= 8).then(|| decode_num(item).to_string());
+                h_flex()
+                    .gap_2()
+                    .child(Label::new(hex).size(LabelSize::Small))
+                    .children(number.map(|number| {
+                        Label::new(number)
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted)
+                    }))
+            }))
+    }
+}
+
+fn phase_name(phase: Phase) -> &'static str {
+    match phase {
+        Phase::Unlocking => "unlocking",
+        Phase::Locking => "locking",
+    }
+}
+
+impl Render for ScriptDebuggerPanel {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .key_context("ScriptDebuggerPanel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.render_header(cx))
+            .child(self.render_setup(cx))
+            .children(self.error.clone().map(|error| {
+                div()
+                    .px_2()
+                    .py_1()
+                    .child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            }))
+            .map(|this| {
+                let Some(interpreter) = self.interpreter.as_ref() else {
+                    return this;
+                };
+                let program_len = interpreter.program().len();
+                this.child(self.render_status(interpreter))
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .child(
+                                uniform_list(
+                                    "script-program",
+                                    program_len,
+                                    cx.processor(|this, range: Range<usize>, window, cx| {
+                                        this.render_program(range, window, cx)
+                                    }),
+                                )
+                                .track_scroll(&self.scroll_handle)
+                                .flex_grow(),
+                            )
+                            .vertical_scrollbar_for(&self.scroll_handle, window, cx),
+                    )
+                    .child(
+                        v_flex()
+                            .border_t_1()
+                            .border_color(cx.theme().colors().border_variant)
+                            .child(Self::render_stack("Main stack", interpreter.stack()))
+                            .child(Self::render_stack("Alt stack", interpreter.alt_stack())),
+                    )
+            })
+    }
+}
+
+impl Focusable for ScriptDebuggerPanel {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for ScriptDebuggerPanel {}
+
+impl Panel for ScriptDebuggerPanel {
+    fn persistent_name() -> &'static str {
+        "ScriptDebuggerPanel"
+    }
+
+    fn panel_key() -> &'static str {
+        SCRIPT_DEBUGGER_PANEL_KEY
+    }
+
+    fn position(&self, _: &Window, _: &App) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, _: DockPosition) -> bool {
+        true
+    }
+
+    fn set_position(&mut self, position: DockPosition, _: &mut Window, cx: &mut Context<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &Window, _: &App) -> Pixels {
+        self.width.unwrap_or(px(360.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, _: &mut Window, cx: &mut Context<Self>) {
+        if active {
+            self.scan_artifacts(cx);
+        }
+    }
+
+    fn icon(&self, _: &Window, _: &App) -> Option<IconName> {
+        (!self.artifacts.is_empty()).then_some(IconName::Binary)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
+        Some("Script Debugger")
+    }
+
+    fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        11
+    }
+}
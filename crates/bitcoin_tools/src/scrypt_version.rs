@@ -0,0 +1,388 @@
+//! Compares a project's scrypt-ts with the release the project templates,
+//! snippets and quick fixes are written for, and rewrites code that uses APIs
+//! renamed since older releases.
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use serde_json::Value;
+
+use crate::contract_source::mask_source;
+
+/// The scrypt-ts release the generated code targets; the wizard's
+/// `package.json` templates depend on `^1.3.0`.
+pub const TARGET_VERSION: Version = Version {
+    major: 1,
+    minor: 3,
+    patch: 0,
+};
+
+/// Directories of a scaffolded project that hold TypeScript using scrypt-ts.
+const SOURCE_DIRS: [&str; 4] = ["src", "contracts", "tests", "scripts"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    /// Parses a version, or the lowest version of a `^`/`~`/`>=` range.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text
+            .trim()
+            .trim_start_matches(['^', '~', '>', '=', 'v'])
+            .trim();
+        let mut parts = text
+            .split(['-', '+', ' '])
+            .next()?
+            .split('.')
+            .map(|part| part.parse::<u64>());
+        Some(Self {
+            major: parts.next()?.ok()?,
+            minor: parts.next().unwrap_or(Ok(0)).ok()?,
+            patch: parts.next().unwrap_or(Ok(0)).ok()?,
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compatibility {
+    Compatible,
+    /// Older than the target; code may use APIs renamed since.
+    Older,
+    /// A newer major release, which generated code may not work with.
+    Newer,
+}
+
+pub fn compatibility(version: Version) -> Compatibility {
+    if version.major > TARGET_VERSION.major {
+        Compatibility::Newer
+    } else if (version.major, version.minor) < (TARGET_VERSION.major, TARGET_VERSION.minor) {
+        Compatibility::Older
+    } else {
+        Compatibility::Compatible
+    }
+}
+
+/// The scrypt-ts version a project uses: the installed one when
+/// `node_modules` is there, otherwise the one `package.json` asks for.
+pub fn project_version(root: &Path) -> Result<Option<Version>> {
+    let installed = root
+        .join("node_modules")
+        .join("scrypt-ts")
+        .join("package.json");
+    if let Ok(text) = fs::read_to_string(&installed) {
+        let package: Value = serde_json::from_str(&text)
+            .with_context(|| format!("parsing {}", installed.display()))?;
+        return Ok(package["version"].as_str().and_then(Version::parse));
+    }
+
+    let manifest = root.join("package.json");
+    let Ok(text) = fs::read_to_string(&manifest) else {
+        return Ok(None);
+    };
+    let package: Value =
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", manifest.display()))?;
+    Ok(["dependencies", "devDependencies"]
+        .iter()
+        .find_map(|section| package[section]["scrypt-ts"].as_str())
+        .and_then(Version::parse))
+}
+
+/// A rewrite for an API that changed between scrypt-ts releases.
+pub struct Codemod {
+    pub title: &'static str,
+    /// Returns the rewritten source, or `None` if it has nothing to change.
+    pub apply: fn(&str) -> Option<String>,
+}
+
+pub const CODEMODS: [Codemod; 3] = [
+    Codemod {
+        title: "`@method` decorators are called: `@method()`",
+        apply: call_method_decorators,
+    },
+    Codemod {
+        title: "`Contract.compile()` is now `Contract.loadArtifact()`",
+        apply: compile_to_load_artifact,
+    },
+    Codemod {
+        title: "`dummyUTXO` is now `getDummyUTXO()`",
+        apply: dummy_utxo,
+    },
+];
+
+/// A file the codemods rewrite, and which of them apply.
+pub struct FileUpgrade {
+    pub path: PathBuf,
+    pub text: String,
+    pub titles: Vec<&'static str>,
+}
+
+/// Runs every codemod over the project's TypeScript sources, without writing
+/// anything.
+pub fn plan_upgrade(root: &Path) -> Vec<FileUpgrade> {
+    let mut upgrades = Vec::new();
+    for path in SOURCE_DIRS
+        .iter()
+        .flat_map(|dir| typescript_files(&root.join(dir)))
+    {
+        let Ok(original) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let mut text = original;
+        let mut titles = Vec::new();
+        for codemod in &CODEMODS {
+            if let Some(rewritten) = (codemod.apply)(&text) {
+                text = rewritten;
+                titles.push(codemod.title);
+            }
+        }
+        if !titles.is_empty() {
+            upgrades.push(FileUpgrade { path, text, titles });
+        }
+    }
+    upgrades
+}
+
+/// Writes the codemods' changes and points `package.json` at the target
+/// release. Returns how many files changed.
+pub fn apply_upgrade(root: &Path) -> Result<usize> {
+    let upgrades = plan_upgrade(root);
+    for upgrade in &upgrades {
+        fs::write(&upgrade.path, &upgrade.text)
+            .with_context(|| format!("writing {}", upgrade.path.display()))?;
+    }
+
+    let manifest = root.join("package.json");
+    let text =
+        fs::read_to_string(&manifest).with_context(|| format!("reading {}", manifest.display()))?;
+    let bumped = set_dependency(&text, "scrypt-ts", &format!("^{TARGET_VERSION}"));
+    if bumped != text {
+        fs::write(&manifest, bumped).with_context(|| format!("writing {}", manifest.display()))?;
+        return Ok(upgrades.len() + 1);
+    }
+    Ok(upgrades.len())
+}
+
+fn typescript_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            if path.is_dir() {
+                if name != "node_modules" && !name.to_string_lossy().starts_with('.') {
+                    dirs.push(path);
+                }
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "ts" || extension == "tsx")
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Replaces the version of `name` in a `package.json`, keeping its layout.
+fn set_dependency(text: &str, name: &str, version: &str) -> String {
+    let key = format!("\"{name}\"");
+    let Some(start) = text.find(&key) else {
+        return text.to_string();
+    };
+    let after_key = start + key.len();
+    let Some(open) = text[after_key..].find('"').map(|ix| after_key + ix + 1) else {
+        return text.to_string();
+    };
+    let Some(close) = text[open..].find('"').map(|ix| open + ix) else {
+        return text.to_string();
+    };
+    format!("{}{version}{}", &text[..open], &text[close..])
+}
+
+/// Applies `edits`, byte ranges of `source` and their replacements, sorted
+/// by position.
+fn rewrite(source: &str, edits: Vec<(usize, usize, String)>) -> Option<String> {
+    if edits.is_empty() {
+        return None;
+    }
+    let mut text = String::with_capacity(source.len());
+    let mut last = 0;
+    for (start, end, replacement) in edits {
+        text.push_str(&source[last..start]);
+        text.push_str(&replacement);
+        last = end;
+    }
+    text.push_str(&source[last..]);
+    Some(text)
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+fn call_method_decorators(source: &str) -> Option<String> {
+    let masked = mask_source(source);
+    let edits = masked
+        .match_indices("@method")
+        .map(|(ix, word)| ix + word.len())
+        .filter(|&end| {
+            let rest = &masked[end..];
+            !rest.starts_with(is_name_char) && !rest.trim_start().starts_with('(')
+        })
+        .map(|end| (end, end, "()".to_string()))
+        .collect();
+    rewrite(source, edits)
+}
+
+fn compile_to_load_artifact(source: &str) -> Option<String> {
+    let masked = mask_source(source);
+    let edits = masked
+        .match_indices(".compile()")
+        .filter(|(ix, _)| {
+            let class = masked[..*ix]
+                .rsplit(|c: char| !is_name_char(c))
+                .next()
+                .unwrap_or_default();
+            class.starts_with(|c: char| c.is_ascii_uppercase())
+        })
+        .map(|(ix, word)| (ix, ix + word.len(), ".loadArtifact()".to_string()))
+        .collect();
+    rewrite(source, edits)
+}
+
+fn dummy_utxo(source: &str) -> Option<String> {
+    let masked = mask_source(source);
+    let edits = masked
+        .match_indices("dummyUTXO")
+        .filter(|(ix, word)| {
+            let before = masked[..*ix].chars().next_back();
+            let after = masked[ix + word.len()..].chars().next();
+            !before.is_some_and(|c| is_name_char(c) || c == '.')
+                && !after.is_some_and(|c| is_name_char(c) || c == '(')
+        })
+        .map(|(ix, word)| {
+            // Imports name the function, uses call it
+            let imported = masked[..ix].rfind("import").is_some_and(|import| {
+                !masked[import..ix].contains(';') && !masked[import..ix].contains(" from ")
+            });
+            let replacement = if imported {
+                "getDummyUTXO"
+            } else {
+                "getDummyUTXO()"
+            };
+            (ix, ix + word.len(), replacement.to_string())
+        })
+        .collect();
+    rewrite(source, edits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version() {
+        assert_eq!(
+            Version::parse("^1.3.0"),
+            Some(Version {
+                major: 1,
+                minor: 3,
+                patch: 0
+            })
+        );
+        assert_eq!(
+            Version::parse("0.9.2-beta.1").map(|version| version.to_string()),
+            Some("0.9.2".to_string())
+        );
+        assert_eq!(Version::parse("latest"), None);
+        assert_eq!(
+            compatibility(Version::parse("1.4.1").unwrap()),
+            Compatibility::Compatible
+        );
+        assert_eq!(
+            compatibility(Version::parse("~1.2.0").unwrap()),
+            Compatibility::Older
+        );
+        assert_eq!(
+            compatibility(Version::parse("2.0.0").unwrap()),
+            Compatibility::Newer
+        );
+    }
+
+    #[test]
+    fn test_codemods() {
+        let source = r#"import { method, prop, SmartContract, dummyUTXO } from "scrypt-ts";
+
+class Demo extends SmartContract {
+    @method
+    public unlock() {
+        // @method stays in comments
+        assert(true)
+    }
+
+    @method() @method ()
+    public other() {}
+}
+
+before(async () => {
+    await Demo.compile();
+    const utxo = dummyUTXO;
+    instance.compile();
+});
+"#;
+        let upgraded = CODEMODS.iter().fold(source.to_string(), |text, codemod| {
+            (codemod.apply)(&text).unwrap_or(text)
+        });
+        assert_eq!(
+            upgraded,
+            r#"import { method, prop, SmartContract, getDummyUTXO } from "scrypt-ts";
+
+class Demo extends SmartContract {
+    @method()
+    public unlock() {
+        // @method stays in comments
+        assert(true)
+    }
+
+    @method() @method ()
+    public other() {}
+}
+
+before(async () => {
+    await Demo.loadArtifact();
+    const utxo = getDummyUTXO();
+    instance.compile();
+});
+"#
+        );
+        assert!(
+            CODEMODS
+                .iter()
+                .all(|codemod| (codemod.apply)(&upgraded).is_none())
+        );
+
+        assert_eq!(
+            set_dependency(
+                "{\n  \"dependencies\": {\n    \"scrypt-ts\": \"^0.9.0\"\n  }\n}",
+                "scrypt-ts",
+                "^1.3.0"
+            ),
+            "{\n  \"dependencies\": {\n    \"scrypt-ts\": \"^1.3.0\"\n  }\n}"
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Checks contract sources for patterns that compile, or nearly do, but are
+//! dangerous to deploy. Findings are shown as diagnostics while editing.
+
+use std::ops::Range;
+
+use bitcoin_script::keys::PrivateKey;
+
+use crate::contract_source::{ends_with_assert, find_methods, mask_source, stateful_props};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    pub range: Range<usize>,
+    pub severity: Severity,
+    /// A short name for the check, shown as the diagnostic's code.
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// Runs every check over a contract source. Findings are sorted by position.
+pub fn lint(source: &str) -> Vec<Finding> {
+    let masked = mask_source(source);
+    let state = stateful_props(&masked);
+    let mut findings = Vec::new();
+
+    for method in find_methods(source) {
+        let Some(body) = method.body.clone() else {
+            continue;
+        };
+        let code = &masked[body.clone()];
+        if method.public {
+            if !ends_with_assert(&code[1..code.len() - 1]) {
+                findings.push(Finding {
+                    range: method.name_offset..method.name_offset + method.name.len(),
+                    severity: Severity::Error,
+                    code: "missing-final-assert",
+                    message: format!(
+                        "`{}` doesn't end with an assert, so this spending path can succeed \
+                         without checking anything",
+                        method.name
+                    ),
+                });
+            }
+            let unchecked = !code.contains("hashOutputs");
+            if let Some((update, prop)) = first_state_update(code, &state).filter(|_| unchecked) {
+                findings.push(Finding {
+                    range: body.start + update.start..body.start + update.end,
+                    severity: Severity::Warning,
+                    code: "unchecked-state-update",
+                    message: format!(
+                        "`this.{prop}` changes, but `{}` never checks this.ctx.hashOutputs, \
+                         so the spending transaction can carry on any state it likes",
+                        method.name
+                    ),
+                });
+            }
+        }
+        let locktime = find_word(code, "ctx.locktime").filter(|_| !code.contains("ctx.sequence"));
+        if let Some(ix) = locktime {
+            findings.push(Finding {
+                range: body.start + ix..body.start + ix + "ctx.locktime".len(),
+                severity: Severity::Warning,
+                code: "locktime-without-sequence",
+                message: "nLockTime is only enforced when the input's sequence is below \
+                          0xffffffff; also assert(this.ctx.sequence < 0xffffffffn)"
+                    .to_string(),
+            });
+        }
+        findings.extend(loop_findings(code, body.start));
+    }
+
+    findings.extend(private_key_findings(source, &masked));
+    findings.sort_by_key(|finding| finding.range.start);
+    findings
+}
+
+/// The first assignment to a stateful property in a masked method body.
+fn first_state_update(code: &str, state: &[String]) -> Option<(Range<usize>, String)> {
+    let mut first: Option<(Range<usize>, String)> = None;
+    for prop in state {
+        let target = format!("this.{prop}");
+        let mut search_from = 0;
+        while let Some(ix) = find_word(&code[search_from..], &target) {
+            let start = search_from + ix;
+            let end = start + target.len();
+            search_from = end;
+            let mut rest = &code[end..];
+            // `this.board[i] = ...` updates the state too
+            while let Some(index) = rest.trim_start().strip_prefix('[') {
+                let Some(close) = index.find(']') else {
+                    break;
+                };
+                rest = &index[close + 1..];
+            }
+            let rest = rest.trim_start();
+            let before = code[..start].trim_end();
+            let assigned = (rest.starts_with('=') && !rest.starts_with("=="))
+                || ["+=", "-=", "*=", "/=", "%=", "++", "--"]
+                    .iter()
+                    .any(|op| rest.starts_with(op))
+                || before.ends_with("++")
+                || before.ends_with("--");
+            if assigned && first.as_ref().is_none_or(|(range, _)| start < range.start) {
+                first = Some((start..end, prop.clone()));
+                break;
+            }
+        }
+    }
+    first
+}
+
+/// Loops the compiler can't unroll: `while`, `do`, `for...of` and `for...in`,
+/// and `for` loops whose bound isn't a compile-time constant.
+fn loop_findings(code: &str, offset: usize) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let finding = |ix: usize, len: usize, message: String| Finding {
+        range: offset + ix..offset + ix + len,
+        severity: Severity::Warning,
+        code: "unbounded-loop",
+        message,
+    };
+    for keyword in ["while", "do"] {
+        let mut search_from = 0;
+        while let Some(ix) = find_word(&code[search_from..], keyword) {
+            let start = search_from + ix;
+            search_from = start + keyword.len();
+            let next = code[search_from..].trim_start();
+            if (keyword == "while" && next.starts_with('('))
+                || (keyword == "do" && next.starts_with('{'))
+            {
+                findings.push(finding(
+                    start,
+                    keyword.len(),
+                    format!(
+                        "`{keyword}` loops have no bound Script can unroll; use a `for` loop \
+                         with a constant bound"
+                    ),
+                ));
+            }
+        }
+    }
+
+    let mut search_from = 0;
+    while let Some(ix) = find_word(&code[search_from..], "for") {
+        let start = search_from + ix;
+        search_from = start + "for".len();
+        let Some(header) = code[search_from..]
+            .trim_start()
+            .strip_prefix('(')
+            .and_then(|header| header.find(')').map(|end| &header[..end]))
+        else {
+            continue;
+        };
+        let clauses: Vec<&str> = header.split(';').collect();
+        let message = match clauses.as_slice() {
+            [_, condition, _] => {
+                let bound = condition
+                    .rsplit(['<', '>', '='])
+                    .next()
+                    .unwrap_or_default()
+                    .trim();
+                (!is_constant(bound)).then(|| {
+                    format!(
+                        "the loop bound `{bound}` must be a compile-time constant, as sCrypt \
+                         unrolls loops; use a literal or a `const`"
+                    )
+                })
+            }
+            _ => Some(
+                "`for...of` and `for...in` loops aren't bounded at compile time; loop over a \
+                 constant index range instead"
+                    .to_string(),
+            ),
+        };
+        if let Some(message) = message {
+            findings.push(finding(start, "for".len(), message));
+        }
+    }
+    findings
+}
+
+fn is_constant(bound: &str) -> bool {
+    let digits = bound.strip_suffix('n').unwrap_or(bound);
+    (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit() || c == '_'))
+        || bound.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+            && bound
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// WIF keys anywhere in the source, and `PrivKey` values written out in code.
+fn private_key_findings(source: &str, masked: &str) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut token_start = None;
+    for (ix, c) in source.char_indices().chain([(source.len(), ' ')]) {
+        if c.is_ascii_alphanumeric() {
+            token_start.get_or_insert(ix);
+            continue;
+        }
+        let Some(start) = token_start.take() else {
+            continue;
+        };
+        if matches!(ix - start, 51 | 52) && PrivateKey::from_wif(&source[start..ix]).is_some() {
+            findings.push(Finding {
+                range: start..ix,
+                severity: Severity::Error,
+                code: "hardcoded-private-key",
+                message: "this is a private key (WIF); anyone who can read the source can spend \
+                          with it, so load it from the environment or the keychain instead"
+                    .to_string(),
+            });
+        }
+    }
+
+    let mut search_from = 0;
+    while let Some(ix) = find_word(&masked[search_from..], "PrivKey") {
+        let start = search_from + ix;
+        search_from = start + "PrivKey".len();
+        let argument = masked[search_from..].trim_start();
+        if argument.strip_prefix('(').is_some_and(|argument| {
+            argument
+                .trim_start()
+                .starts_with(|c: char| c.is_ascii_digit())
+        }) {
+            findings.push(Finding {
+                range: start..search_from,
+                severity: Severity::Error,
+                code: "hardcoded-private-key",
+                message: "a PrivKey written into the contract ends up in its locking script, \
+                          readable by anyone"
+                    .to_string(),
+            });
+        }
+    }
+    findings
+}
+
+/// The first occurrence of `word` in `text` that isn't part of a longer name.
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    let is_name = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    text.match_indices(word)
+        .find(|(ix, _)| {
+            let before = text[..*ix].chars().next_back();
+            let after = text[ix + word.len()..].chars().next();
+            !before.is_some_and(is_name) && !after.is_some_and(is_name)
+        })
+        .map(|(ix, _)| ix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint() {
+        let source = r#"
+export class Demo extends SmartContract {
+    @prop(true)
+    count: bigint
+
+    @prop(true)
+    board: FixedArray<bigint, 9>
+
+    @method()
+    public increment() {
+        this.count++
+        assert(true)
+    }
+
+    @method()
+    public move(n: bigint) {
+        this.board[n] = 1n
+        for (let i = 0; i < this.count; i++) {}
+        while (n > 0) {}
+        assert(this.ctx.locktime >= 100n)
+    }
+
+    @method()
+    public checked() {
+        for (let i = 0; i < MAX_PLAYERS; i++) {}
+        this.count++
+        const outputs = this.buildStateOutput(this.ctx.utxo.value)
+        assert(hash256(outputs) == this.ctx.hashOutputs)
+    }
+
+    @method()
+    public unlock(sig: Sig) {
+        // KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn is ours
+        const key = PrivKey(0x1234n)
+        if (this.checkSig(sig, pubKey(key))) {
+            assert(true)
+        }
+    }
+}
+"#;
+        let findings: Vec<_> = lint(source)
+            .into_iter()
+            .map(|finding| (finding.code, &source[finding.range]))
+            .collect();
+        assert_eq!(
+            findings,
+            [
+                ("unchecked-state-update", "this.count"),
+                ("unchecked-state-update", "this.board"),
+                ("unbounded-loop", "for"),
+                ("unbounded-loop", "while"),
+                ("locktime-without-sequence", "ctx.locktime"),
+                ("missing-final-assert", "unlock"),
+                (
+                    "hardcoded-private-key",
+                    "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn"
+                ),
+                ("hardcoded-private-key", "PrivKey"),
+            ]
+        );
+    }
+}
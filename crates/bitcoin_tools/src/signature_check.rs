@@ -0,0 +1,359 @@
+//! Checks an ECDSA signature against a message and public key the way a
+//! failing `checkSig` would need debugging: which encoding the signature is
+//! in, whether its sighash flag and S value pass BSV policy, and which of the
+//! usual ways of hashing the message it was made over.
+
+use std::fmt::Write as _;
+
+use anyhow::{Result, bail};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use bitcoin_script::{
+    hash::{hash256, sha256},
+    keys::{PrivateKey, decode_address},
+    secp256k1::{PublicKey, Signature, verify},
+    transaction::{SIGHASH_ANYONECANPAY, SIGHASH_FORKID, write_var_int},
+};
+
+const SIGNED_MESSAGE_PREFIX: &[u8] = b"Bitcoin Signed Message:\n";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Encoding {
+    Der,
+    /// DER followed by a sighash type, as pushed for OP_CHECKSIG.
+    DerWithSighash(u8),
+    Compact,
+    /// A compact signature behind a recovery header, as message signing
+    /// tools produce.
+    CompactRecoverable(u8),
+}
+
+/// A way of turning the message into the 32 bytes that were signed. The
+/// first one for each kind of message is the one that should match.
+struct Digest {
+    label: &'static str,
+    digest: [u8; 32],
+    /// Why a signature matching this digest still fails `checkSig`.
+    problem: Option<&'static str>,
+}
+
+/// Checks `signature` (DER or compact, hex or base64) against `message`: a
+/// 32-byte sighash, a sighash preimage, both as hex, or text signed as a
+/// Bitcoin Signed Message. `public_key` is hex, or a WIF to check against
+/// its key. Returns the markdown report; errors are for inputs that can't be
+/// decoded at all.
+pub fn check(signature: &str, public_key: &str, message: &str) -> Result<String> {
+    let (signature, encoding) = parse_signature(signature)?;
+    let public_key = parse_public_key(public_key)?;
+    let (kind, digests, preimage_sighash) = digests(message);
+
+    let matched = digests
+        .iter()
+        .position(|digest| verify(&public_key, &digest.digest, &signature));
+    let mut problems: Vec<String> = Vec::new();
+    if let Some(problem) = matched.and_then(|ix| digests[ix].problem) {
+        problems.push(problem.to_string());
+    }
+    if !signature.is_low_s() {
+        problems.push(format!(
+            "The S value is in the upper half of the curve order. Nodes reject high-S \
+             signatures as non-standard; the equivalent low-S signature is `{}`.",
+            hex::encode(signature.normalized().to_der())
+        ));
+    }
+    if let Encoding::DerWithSighash(sighash_type) = encoding {
+        if sighash_type & SIGHASH_FORKID == 0 {
+            problems.push(format!(
+                "The sighash type {} lacks SIGHASH_FORKID (0x40), which BSV requires - \
+                 sign with `SigHash.ALL` (0x41) or another FORKID type.",
+                sighash_name(sighash_type)
+            ));
+        }
+        if let Some(preimage_sighash) = preimage_sighash.filter(|ty| *ty != sighash_type) {
+            problems.push(format!(
+                "The signature is tagged {} but the preimage was built for {}. OP_CHECKSIG \
+                 rebuilds the preimage from the tag, so both must use the same sighash type, \
+                 like the one in the method's `@method(SigHash...)` decorator.",
+                sighash_name(sighash_type),
+                sighash_name(preimage_sighash)
+            ));
+        }
+    }
+
+    let valid = matched.is_some() && problems.is_empty();
+    let mut text = String::new();
+    match (matched, valid) {
+        (_, true) => text.push_str("## Signature valid\n\n"),
+        (Some(_), false) => text.push_str("## Signature matches, but would fail on chain\n\n"),
+        (None, _) => text.push_str("## Signature invalid\n\n"),
+    }
+    text.push_str("| Check | Result |\n|---|---|\n");
+    let encoding = match encoding {
+        Encoding::Der => "DER, without a sighash type".to_string(),
+        Encoding::DerWithSighash(sighash_type) => {
+            format!("DER + sighash type {}", sighash_name(sighash_type))
+        }
+        Encoding::Compact => "compact (r, s)".to_string(),
+        Encoding::CompactRecoverable(header) => format!("compact, recovery header {header}"),
+    };
+    writeln!(text, "| Encoding | {encoding} |").ok();
+    let low_s = if signature.is_low_s() {
+        "yes"
+    } else {
+        "**no**"
+    };
+    writeln!(text, "| Low S | {low_s} |").ok();
+    writeln!(text, "| Message | {kind} |").ok();
+    let signed = match matched {
+        Some(ix) => format!(
+            "{} `{}`",
+            digests[ix].label,
+            hex::encode(digests[ix].digest)
+        ),
+        None => "none of the digests tried".to_string(),
+    };
+    writeln!(text, "| Signed over | {signed} |").ok();
+
+    if matched.is_none() {
+        text.push_str("\nTried:\n\n");
+        for digest in &digests {
+            writeln!(text, "- {} `{}`", digest.label, hex::encode(digest.digest)).ok();
+        }
+        text.push_str(
+            "\nSo the key or the message differs from what was signed. Check that the key is \
+             the one the contract holds (a compressed and an uncompressed key hash to \
+             different addresses), and for a preimage, that it was built for the same input \
+             index, locking script, satoshis and sighash type.\n",
+        );
+    }
+    if !problems.is_empty() {
+        text.push_str("\nProblems:\n\n");
+        for problem in &problems {
+            writeln!(text, "- {problem}").ok();
+        }
+    }
+    Ok(text)
+}
+
+fn parse_signature(text: &str) -> Result<(Signature, Encoding)> {
+    let text = text.trim();
+    let bytes = match hex::decode(text) {
+        Ok(bytes) => bytes,
+        Err(_) => match STANDARD.decode(text) {
+            Ok(bytes) => bytes,
+            Err(_) => bail!("the signature `{text}` is neither hex nor base64"),
+        },
+    };
+    if let Some(signature) = Signature::parse_der(&bytes) {
+        return Ok((signature, Encoding::Der));
+    }
+    if let Some((signature, sighash_type)) = bytes.split_last().and_then(|(&sighash_type, der)| {
+        Signature::parse_der(der).map(|signature| (signature, sighash_type))
+    }) {
+        return Ok((signature, Encoding::DerWithSighash(sighash_type)));
+    }
+    match bytes.len() {
+        64 => {
+            if let Some(signature) = Signature::from_compact(&bytes) {
+                return Ok((signature, Encoding::Compact));
+            }
+        }
+        65 if (27..=42).contains(&bytes[0]) => {
+            if let Some(signature) = Signature::from_compact(&bytes[1..]) {
+                return Ok((signature, Encoding::CompactRecoverable(bytes[0])));
+            }
+        }
+        _ => {}
+    }
+    if bytes.first() == Some(&0x30) {
+        bail!(
+            "the signature looks like DER but isn't strictly encoded (wrong lengths, or \
+             integers with extra zero padding), which nodes reject"
+        );
+    }
+    bail!(
+        "the signature is {} bytes - expected DER (about 70-72 bytes, plus a sighash byte) \
+         or compact (64 or 65 bytes)",
+        bytes.len()
+    )
+}
+
+fn parse_public_key(text: &str) -> Result<PublicKey> {
+    let text = text.trim();
+    if let Some(key) = hex::decode(text)
+        .ok()
+        .and_then(|bytes| PublicKey::parse(&bytes))
+    {
+        return Ok(key);
+    }
+    if let Some(key) =
+        PrivateKey::from_wif(text).and_then(|key| PublicKey::parse(&key.public_key()))
+    {
+        return Ok(key);
+    }
+    if decode_address(text).is_some() {
+        bail!("`{text}` is an address, which only holds a hash of the key - pass the public key");
+    }
+    bail!("`{text}` is not a public key (33 or 65 bytes of hex) or a WIF")
+}
+
+/// What the message is, the digests to try for it, and the sighash type a
+/// preimage was built for.
+fn digests(message: &str) -> (&'static str, Vec<Digest>, Option<u8>) {
+    let message = message.trim();
+    match hex::decode(message).ok() {
+        Some(sighash) if sighash.len() == 32 => {
+            let mut digest = [0; 32];
+            digest.copy_from_slice(&sighash);
+            let mut reversed = digest;
+            reversed.reverse();
+            let digests = vec![
+                Digest {
+                    label: "the sighash",
+                    digest,
+                    problem: None,
+                },
+                Digest {
+                    label: "the sighash, byte-reversed",
+                    digest: reversed,
+                    problem: Some(
+                        "It was signed over the sighash in reversed byte order, as some \
+                         libraries display it - sign the digest in the order hash256 returns.",
+                    ),
+                },
+            ];
+            ("32-byte sighash", digests, None)
+        }
+        Some(preimage) if preimage.len() > 32 => {
+            let sighash_type = preimage.len().checked_sub(4).map(|ix| preimage[ix]);
+            let digest = hash256(&preimage);
+            let mut reversed = digest;
+            reversed.reverse();
+            let digests = vec![
+                Digest {
+                    label: "hash256 of the preimage",
+                    digest,
+                    problem: None,
+                },
+                Digest {
+                    label: "SHA-256 of the preimage",
+                    digest: sha256(&preimage),
+                    problem: Some(
+                        "It was signed over a single SHA-256 of the preimage; OP_CHECKSIG \
+                         checks a double SHA-256 (hash256).",
+                    ),
+                },
+                Digest {
+                    label: "hash256 of the preimage, byte-reversed",
+                    digest: reversed,
+                    problem: Some(
+                        "It was signed over the sighash in reversed byte order, as some \
+                         libraries display it - sign the digest in the order hash256 returns.",
+                    ),
+                },
+            ];
+            ("sighash preimage", digests, sighash_type)
+        }
+        _ => {
+            let digests = vec![
+                Digest {
+                    label: "Bitcoin Signed Message digest",
+                    digest: signed_message_digest(message.as_bytes()),
+                    problem: None,
+                },
+                Digest {
+                    label: "SHA-256 of the text",
+                    digest: sha256(message.as_bytes()),
+                    problem: None,
+                },
+                Digest {
+                    label: "hash256 of the text",
+                    digest: hash256(message.as_bytes()),
+                    problem: None,
+                },
+            ];
+            ("text", digests, None)
+        }
+    }
+}
+
+fn signed_message_digest(message: &[u8]) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    write_var_int(&mut bytes, SIGNED_MESSAGE_PREFIX.len() as u64);
+    bytes.extend_from_slice(SIGNED_MESSAGE_PREFIX);
+    write_var_int(&mut bytes, message.len() as u64);
+    bytes.extend_from_slice(message);
+    hash256(&bytes)
+}
+
+/// `ALL|FORKID (0x41)` and the like.
+fn sighash_name(sighash_type: u8) -> String {
+    let mut name = match sighash_type & 0x1f {
+        1 => "ALL",
+        2 => "NONE",
+        3 => "SINGLE",
+        _ => "unknown",
+    }
+    .to_string();
+    if sighash_type & SIGHASH_FORKID != 0 {
+        name.push_str("|FORKID");
+    }
+    if sighash_type & SIGHASH_ANYONECANPAY != 0 {
+        name.push_str("|ANYONECANPAY");
+    }
+    format!("{name} (0x{sighash_type:02x})")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let key = PrivateKey::from_secret_bytes(&[7; 32], true).unwrap();
+        let public_key = hex::encode(key.public_key());
+        let preimage = [vec![0xab; 100], vec![0x41, 0, 0, 0]].concat();
+        let digest = hash256(&preimage);
+        let signature = key.sign(&digest);
+        let pushed = hex::encode([signature.to_der(), vec![0x41]].concat());
+
+        let text = check(&pushed, &public_key, &hex::encode(&preimage)).unwrap();
+        assert!(text.starts_with("## Signature valid"), "{text}");
+        let text = check(&pushed, &key.to_wif(), &hex::encode(digest)).unwrap();
+        assert!(text.starts_with("## Signature valid"), "{text}");
+
+        // Tagged ALL without FORKID, and for a different type than the preimage
+        let tagged = hex::encode([signature.to_der(), vec![0x01]].concat());
+        let text = check(&tagged, &public_key, &hex::encode(&preimage)).unwrap();
+        assert!(text.starts_with("## Signature matches, but"), "{text}");
+        assert!(text.contains("lacks SIGHASH_FORKID") && text.contains("preimage was built"));
+
+        let high_s = Signature::from_compact(
+            &[
+                vec![0; 31],
+                vec![1],
+                hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140")
+                    .unwrap(),
+            ]
+            .concat(),
+        )
+        .unwrap();
+        assert!(!high_s.is_low_s());
+        let text = check(&hex::encode(high_s.to_der()), &public_key, "hello").unwrap();
+        assert!(text.starts_with("## Signature invalid"), "{text}");
+        assert!(text.contains("high-S"));
+
+        let mut reversed = digest;
+        reversed.reverse();
+        let text = check(
+            &hex::encode(key.sign(&reversed).to_der()),
+            &public_key,
+            &hex::encode(digest),
+        )
+        .unwrap();
+        assert!(text.contains("reversed byte order"), "{text}");
+
+        assert!(check(&pushed, &key.address(), "hello").is_err());
+        assert!(check("3006020101020101", &public_key, "hello").is_ok());
+        assert!(check("300602010102", &public_key, "hello").is_err());
+    }
+}
@@ -0,0 +1,297 @@
+//! A local signing endpoint for the scaffold's deploy scripts, so a deploy key
+//! kept in the OS keychain never has to be written to disk.
+//!
+//! While running, the server listens on an ephemeral localhost port and writes
+//! its URL, a session token and the key's address and public key to
+//! `.utxix/signer.json`, where `scripts/keychain-signer.ts` picks them up.
+//! Only the user can read that file on unix.
+//! Scripts compute sighash preimages themselves and ask for signatures over them.
+
+use std::{
+    fs,
+    io::{self, Write as _},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context as _, Result, anyhow, ensure};
+use bitcoin_script::{hash::hash256, keys::PrivateKey, transaction::SIGHASH_FORKID};
+use serde::Deserialize;
+use serde_json::json;
+use subtle::ConstantTimeEq as _;
+use tiny_http::{Header, Method, Request, Response, Server};
+use util::ResultExt as _;
+
+/// Session file the deploy scripts read, relative to the project root.
+pub const SIGNER_PATH: &str = ".utxix/signer.json";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Version, the prevouts and sequence hashes, outpoint, an empty script code,
+/// value, sequence, the outputs hash, locktime and sighash type.
+const MIN_PREIMAGE_LEN: usize = 157;
+
+/// Serves signatures until dropped, which also removes the session file.
+pub struct SigningServer {
+    pub url: String,
+    pub address: String,
+    session_path: PathBuf,
+    stopped: Arc<AtomicBool>,
+}
+
+impl SigningServer {
+    pub fn start(root: &Path, key: PrivateKey) -> Result<Self> {
+        let server = Server::http("127.0.0.1:0").map_err(|err| anyhow!(err))?;
+        let url = format!("http://127.0.0.1:{}", server.server_addr().port());
+        let token = hex::encode(rand::random::<[u8; 16]>());
+        let address = key.address();
+
+        let session_path = root.join(SIGNER_PATH);
+        if let Some(dir) = session_path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        let session = json!({
+            "url": url,
+            "token": token,
+            "address": address,
+            "publicKey": hex::encode(key.public_key()),
+        });
+        write_private(
+            &session_path,
+            &(serde_json::to_string_pretty(&session)? + "\n"),
+        )
+        .with_context(|| format!("writing {}", session_path.display()))?;
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        thread::Builder::new()
+            .name("utxix-signer".to_string())
+            .spawn({
+                let stopped = stopped.clone();
+                move || {
+                    while !stopped.load(Ordering::Relaxed) {
+                        match server.recv_timeout(POLL_INTERVAL) {
+                            Ok(Some(request)) => handle(request, &key, &token),
+                            Ok(None) => {}
+                            Err(err) => {
+                                log::error!("signing server stopped: {err}");
+                                break;
+                            }
+                        }
+                    }
+                }
+            })
+            .context("starting the signing server")?;
+
+        Ok(Self {
+            url,
+            address,
+            session_path,
+            stopped,
+        })
+    }
+}
+
+impl Drop for SigningServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        fs::remove_file(&self.session_path).log_err();
+    }
+}
+
+/// Writes a file only its owner can read. A file left from an earlier
+/// session is replaced, as rewriting it would keep its old mode.
+fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
+fn handle(mut request: Request, key: &PrivateKey, token: &str) {
+    // Compared in constant time, so timing doesn't reveal a guessed prefix
+    let authorized = request.headers().iter().any(|header| {
+        header.field.equiv("Authorization")
+            && header
+                .value
+                .as_str()
+                .strip_prefix("Bearer ")
+                .is_some_and(|given| bool::from(given.as_bytes().ct_eq(token.as_bytes())))
+    });
+    let (status, body) = if !authorized {
+        (401, json!({ "error": "missing or wrong session token" }))
+    } else {
+        match (request.method(), request.url()) {
+            (Method::Get, "/key") => (
+                200,
+                json!({
+                    "address": key.address(),
+                    "publicKey": hex::encode(key.public_key()),
+                }),
+            ),
+            (Method::Post, "/sign") => {
+                let mut text = String::new();
+                let signature = request
+                    .as_reader()
+                    .read_to_string(&mut text)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|_| sign(key, &text));
+                match signature {
+                    Ok(signature) => (200, json!({ "signature": signature })),
+                    Err(err) => (400, json!({ "error": format!("{err:#}") })),
+                }
+            }
+            _ => (404, json!({ "error": "not found" })),
+        }
+    };
+
+    Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .map_err(|()| anyhow!("invalid Content-Type header"))
+        .and_then(|content_type| {
+            request
+                .respond(
+                    Response::from_string(body.to_string())
+                        .with_status_code(status)
+                        .with_header(content_type),
+                )
+                .context("answering a signing request")
+        })
+        .log_err();
+}
+
+/// Signs a BIP143 sighash preimage, returning the DER signature in hex
+/// (without the sighash type byte, which the caller appends).
+fn sign(key: &PrivateKey, body: &str) -> Result<String> {
+    #[derive(Deserialize)]
+    struct SignRequest {
+        preimage: String,
+    }
+
+    let request: SignRequest = serde_json::from_str(body).context("parsing the request")?;
+    let preimage = hex::decode(&request.preimage).context("the preimage is not hex")?;
+    // Only sign what looks like a transaction preimage, not arbitrary digests
+    ensure!(
+        preimage.len() >= MIN_PREIMAGE_LEN,
+        "the preimage is too short"
+    );
+    let sighash_type = u32::from_le_bytes(preimage[preimage.len() - 4..].try_into()?);
+    ensure!(
+        sighash_type & SIGHASH_FORKID as u32 != 0,
+        "only SIGHASH_FORKID preimages are signed"
+    );
+    Ok(hex::encode(key.sign(&hash256(&preimage)).to_der()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read as _, Write as _},
+        net::TcpStream,
+    };
+
+    use serde_json::Value;
+
+    use super::*;
+
+    fn preimage(sighash_type: u32) -> Vec<u8> {
+        let mut preimage = vec![0; MIN_PREIMAGE_LEN - 4];
+        preimage.extend(sighash_type.to_le_bytes());
+        preimage
+    }
+
+    /// A request over a fresh connection, returning the status and JSON body.
+    fn send(url: &str, request_line: &str, token: Option<&str>, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(url.trim_start_matches("http://")).unwrap();
+        let authorization = token
+            .map(|token| format!("Authorization: Bearer {token}\r\n"))
+            .unwrap_or_default();
+        write!(
+            stream,
+            "{request_line} HTTP/1.1\r\nHost: localhost\r\n{authorization}\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn test_sign_checks_preimage() {
+        let key = PrivateKey::from_secret_bytes(&[7; 32], true).unwrap();
+        let body = |preimage: &[u8]| json!({ "preimage": hex::encode(preimage) }).to_string();
+
+        let signed = sign(&key, &body(&preimage(0x41))).unwrap();
+        assert_eq!(
+            hex::decode(signed).unwrap(),
+            key.sign(&hash256(&preimage(0x41))).to_der()
+        );
+        let short = &preimage(0x41)[4..];
+        assert!(
+            sign(&key, &body(short))
+                .unwrap_err()
+                .to_string()
+                .contains("too short")
+        );
+        assert!(
+            sign(&key, &body(&preimage(0x01)))
+                .unwrap_err()
+                .to_string()
+                .contains("SIGHASH_FORKID")
+        );
+        assert!(sign(&key, "{\"preimage\": \"not hex\"}").is_err());
+    }
+
+    #[test]
+    fn test_signing_server_requires_token() {
+        let root = tempfile::tempdir().unwrap();
+        let key = PrivateKey::from_secret_bytes(&[7; 32], true).unwrap();
+        let server = SigningServer::start(root.path(), key.clone()).unwrap();
+        let session_path = root.path().join(SIGNER_PATH);
+        let session: Value =
+            serde_json::from_str(&fs::read_to_string(&session_path).unwrap()).unwrap();
+        let token = session["token"].as_str().unwrap();
+        assert_eq!(session["address"], key.address());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            let mode = fs::metadata(&session_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let (status, _) = send(&server.url, "GET /key", None, "");
+        assert_eq!(status, 401);
+        let (status, _) = send(&server.url, "GET /key", Some("wrong"), "");
+        assert_eq!(status, 401);
+        let (status, _) = send(&server.url, "GET /key", Some(&token[..31]), "");
+        assert_eq!(status, 401);
+        let (status, body) = send(&server.url, "GET /key", Some(token), "");
+        assert_eq!(status, 200);
+        assert_eq!(body["publicKey"], hex::encode(key.public_key()));
+
+        let request = json!({ "preimage": hex::encode(preimage(0x41)) }).to_string();
+        let (status, _) = send(&server.url, "POST /sign", None, &request);
+        assert_eq!(status, 401);
+        let (status, body) = send(&server.url, "POST /sign", Some(token), &request);
+        assert_eq!(status, 200);
+        assert!(body["signature"].is_string());
+        let request = json!({ "preimage": hex::encode(preimage(0x01)) }).to_string();
+        let (status, body) = send(&server.url, "POST /sign", Some(token), &request);
+        assert_eq!(status, 400);
+        assert!(body["error"].as_str().unwrap().contains("SIGHASH_FORKID"));
+
+        drop(server);
+        assert!(!session_path.exists());
+    }
+}
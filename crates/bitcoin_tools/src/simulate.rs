@@ -0,0 +1,457 @@
+//! Runs a contract method locally against a made-up spending transaction:
+//! the contract's output is spent by a transaction with one input and one
+//! output paying the same script, and the method's arguments are checked by
+//! the script interpreter, with no network and no test file.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context as _, Result, anyhow, bail};
+use bitcoin_script::{
+    Interpreter, Phase, ScriptError,
+    hash::{hash256, sha256},
+    keys::PrivateKey,
+    transaction::{
+        SIGHASH_ALL, SIGHASH_ANYONECANPAY, SIGHASH_FORKID, SIGHASH_NONE, SIGHASH_SINGLE,
+        Transaction, TxIn, TxOut,
+    },
+};
+
+use crate::artifact::{Artifact, Param, split_args};
+
+/// The txid of the made-up transaction the contract was deployed in.
+const MOCK_TXID: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+const DEFAULT_SATOSHIS: u64 = 1_000;
+/// Arguments the sCrypt compiler adds to methods that read `this.ctx`,
+/// filled in from the spending transaction rather than the chat.
+const IMPLICIT_PREFIX: &str = "__scrypt_ts_";
+/// Stack items shown in a report, from the top.
+const MAX_STACK_ITEMS: usize = 12;
+/// Longest stack item shown in full.
+const MAX_ITEM_HEX: usize = 72;
+
+/// `Contract(constructor args).method(args)`, followed by `key=value` options
+/// for the spending transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    pub contract: String,
+    pub constructor_args: Vec<String>,
+    pub method: String,
+    pub args: Vec<String>,
+    pub context: MockContext,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockContext {
+    /// Value of the contract output being spent.
+    pub satoshis: u64,
+    pub lock_time: u32,
+    /// Defaults to final, or one below it when `lock_time` is set, so the
+    /// lock time applies.
+    pub sequence: Option<u32>,
+    pub sighash_type: u8,
+}
+
+impl Default for MockContext {
+    fn default() -> Self {
+        Self {
+            satoshis: DEFAULT_SATOSHIS,
+            lock_time: 0,
+            sequence: None,
+            sighash_type: SIGHASH_ALL | SIGHASH_FORKID,
+        }
+    }
+}
+
+pub fn parse_call(text: &str) -> Result<Call> {
+    let text = text.trim();
+    let name_end = text
+        .find(['(', '.'])
+        .ok_or_else(|| anyhow!("expected Contract(args).method(args), got `{text}`"))?;
+    let contract = text[..name_end].trim().to_string();
+    let mut rest = &text[name_end..];
+    let mut constructor_args = Vec::new();
+    if rest.starts_with('(') {
+        let (inner, after) = parenthesized(rest)?;
+        constructor_args = split_args(inner);
+        rest = after;
+    }
+    let rest = rest
+        .trim_start()
+        .strip_prefix('.')
+        .ok_or_else(|| anyhow!("expected .method(args) after {contract}"))?;
+    let open = rest
+        .find('(')
+        .ok_or_else(|| anyhow!("expected arguments in parentheses after the method name"))?;
+    let method = rest[..open].trim().to_string();
+    let (inner, rest) = parenthesized(&rest[open..])?;
+    let args = split_args(inner);
+
+    let mut context = MockContext::default();
+    for option in rest.split_whitespace() {
+        let (key, value) = option
+            .split_once('=')
+            .ok_or_else(|| anyhow!("expected key=value, got `{option}`"))?;
+        match key {
+            "sats" | "satoshis" => context.satoshis = parse_number(value)?,
+            "locktime" => context.lock_time = parse_number(value)?,
+            "sequence" => context.sequence = Some(parse_number(value)?),
+            "sighash" => context.sighash_type = parse_sighash(value)?,
+            _ => bail!("unknown option `{key}` - use sats, locktime, sequence or sighash"),
+        }
+    }
+    if contract.is_empty() || method.is_empty() {
+        bail!("expected Contract(args).method(args), got `{text}`");
+    }
+    Ok(Call {
+        contract,
+        constructor_args,
+        method,
+        args,
+        context,
+    })
+}
+
+/// The inside of the parenthesized group `text` starts with, and what follows it.
+fn parenthesized(text: &str) -> Result<(&str, &str)> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    for (ix, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ']') => depth = depth.saturating_sub(1),
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((&text[1..ix], &text[ix + 1..]));
+                }
+            }
+            _ => {}
+        }
+    }
+    bail!("unclosed parenthesis in `{text}`")
+}
+
+fn parse_number<T: TryFrom<u64>>(value: &str) -> Result<T> {
+    let value = value.trim_end_matches('n').replace('_', "");
+    let number = match value.strip_prefix("0x") {
+        Some(digits) => u64::from_str_radix(digits, 16),
+        None => value.parse(),
+    }
+    .with_context(|| format!("`{value}` is not a number"))?;
+    T::try_from(number).map_err(|_| anyhow!("{number} is out of range"))
+}
+
+/// A number, or flags like `all`, `single|anyonecanpay`; FORKID is implied.
+fn parse_sighash(value: &str) -> Result<u8> {
+    if let Ok(number) = parse_number::<u8>(value) {
+        return Ok(number);
+    }
+    let mut sighash_type = SIGHASH_FORKID;
+    for flag in value.to_ascii_lowercase().split(['|', '+']) {
+        sighash_type |= match flag.trim_start_matches("sighash_") {
+            "all" => SIGHASH_ALL,
+            "none" => SIGHASH_NONE,
+            "single" => SIGHASH_SINGLE,
+            "anyonecanpay" => SIGHASH_ANYONECANPAY,
+            "forkid" => SIGHASH_FORKID,
+            _ => bail!("unknown sighash flag `{flag}` - use all, none, single or anyonecanpay"),
+        };
+    }
+    Ok(sighash_type)
+}
+
+/// Runs `call` against `artifact`, returning a markdown report of whether
+/// the method passed, and where it failed if it didn't. Errors are for
+/// arguments that can't be turned into scripts.
+pub fn simulate(artifact: &Artifact, call: &Call) -> Result<String> {
+    let params = artifact.constructor_params();
+    let constructor_args = call
+        .constructor_args
+        .iter()
+        .enumerate()
+        .map(|(ix, arg)| match params.get(ix) {
+            Some(param) => resolve_key(arg, &param.ty),
+            None => arg.clone(),
+        })
+        .collect::<Vec<_>>();
+    let locking = artifact.locking_script(&constructor_args)?;
+
+    let context = &call.context;
+    let mut input = TxIn::new(MOCK_TXID, 0).context("invalid mock txid")?;
+    input.sequence = context.sequence.unwrap_or(if context.lock_time > 0 {
+        u32::MAX - 1
+    } else {
+        u32::MAX
+    });
+    let tx = Transaction {
+        version: 1,
+        inputs: vec![input],
+        outputs: vec![TxOut {
+            satoshis: context.satoshis,
+            script: locking.clone(),
+        }],
+        lock_time: context.lock_time,
+    };
+    let preimage = tx.sighash_preimage(0, &locking, context.satoshis, context.sighash_type);
+
+    let entry = artifact
+        .public_methods()
+        .find(|entry| entry.name.as_deref() == Some(call.method.as_str()))
+        .ok_or_else(|| {
+            let methods: Vec<_> = artifact
+                .public_methods()
+                .filter_map(|entry| entry.name.as_deref())
+                .collect();
+            anyhow!(
+                "{} has no public method `{}` - it has {}",
+                artifact.contract,
+                call.method,
+                methods.join(", ")
+            )
+        })?;
+    let explicit: Vec<&Param> = entry
+        .params
+        .iter()
+        .filter(|param| !param.name.starts_with(IMPLICIT_PREFIX))
+        .collect();
+    if call.args.len() != explicit.len() {
+        bail!(
+            "{} takes {} argument(s) ({}), got {}",
+            call.method,
+            explicit.len(),
+            explicit
+                .iter()
+                .map(|param| format!("{}: {}", param.name, param.ty))
+                .collect::<Vec<_>>()
+                .join(", "),
+            call.args.len()
+        );
+    }
+    let mut given = call.args.iter();
+    let mut args = Vec::new();
+    for param in &entry.params {
+        let arg = match param.name.strip_prefix(IMPLICIT_PREFIX) {
+            Some(name) => implicit_arg(name, &tx, &preimage, context.satoshis, &locking)?,
+            None => {
+                let arg = given.next().context("argument count checked above")?;
+                match param.ty.as_str() {
+                    "Sig" => sign_arg(arg, &preimage, context.sighash_type),
+                    ty => resolve_key(arg, ty),
+                }
+            }
+        };
+        args.push(arg);
+    }
+    let unlocking = artifact.unlocking_script(&call.method, &args)?;
+
+    let mut interpreter = Interpreter::new(&unlocking, &locking)?.with_preimage(preimage);
+    let outcome = interpreter.run();
+    Ok(report(artifact, call, &tx, &interpreter, outcome))
+}
+
+/// The value the compiler expects for an implicit `__scrypt_ts_<name>` argument.
+fn implicit_arg(
+    name: &str,
+    tx: &Transaction,
+    preimage: &[u8],
+    satoshis: u64,
+    locking: &[u8],
+) -> Result<String> {
+    Ok(match name {
+        "txPreimage" => hex::encode(preimage),
+        // No change output
+        "changeAmount" => "0".to_string(),
+        "changeAddress" => hex::encode([0u8; 20]),
+        "prevouts" => hex::encode(
+            tx.inputs
+                .iter()
+                .flat_map(|input| [&input.prev_txid[..], &input.prev_vout.to_le_bytes()].concat())
+                .collect::<Vec<_>>(),
+        ),
+        "spentAmounts" => hex::encode(satoshis.to_le_bytes()),
+        "spentScriptHashes" => hex::encode(sha256(locking)),
+        other => {
+            bail!("don't know how to fill in the compiler's `{IMPLICIT_PREFIX}{other}` argument")
+        }
+    })
+}
+
+/// A WIF given for a key type stands for that key's public key or hash.
+fn resolve_key(arg: &str, ty: &str) -> String {
+    let key = match ty {
+        "PubKey" | "PubKeyHash" | "Ripemd160" | "Addr" => PrivateKey::from_wif(arg),
+        _ => None,
+    };
+    match key {
+        Some(key) if ty == "PubKey" => hex::encode(key.public_key()),
+        Some(key) => hex::encode(key.pubkey_hash()),
+        None => arg.to_string(),
+    }
+}
+
+/// A WIF given for a `Sig` is used to sign the mock transaction.
+fn sign_arg(arg: &str, preimage: &[u8], sighash_type: u8) -> String {
+    match PrivateKey::from_wif(arg) {
+        Some(key) => {
+            let mut signature = key.sign(&hash256(preimage)).normalized().to_der();
+            signature.push(sighash_type);
+            hex::encode(signature)
+        }
+        None => arg.to_string(),
+    }
+}
+
+fn report(
+    artifact: &Artifact,
+    call: &Call,
+    tx: &Transaction,
+    interpreter: &Interpreter,
+    outcome: Result<(), ScriptError>,
+) -> String {
+    let mut text = String::new();
+    let name = format!("{}.{}", artifact.contract, call.method);
+    match &outcome {
+        Ok(()) => writeln!(text, "## {name}: passed\n").ok(),
+        Err(err) => writeln!(text, "## {name}: failed\n\n{err}\n").ok(),
+    };
+
+    if let Err(err) = &outcome {
+        let finished = matches!(err, ScriptError::EmptyStack | ScriptError::EvalFalse);
+        let program = interpreter.program();
+        let failed_at = interpreter.pc().saturating_sub(1);
+        if let Some(step) = program.get(failed_at).filter(|_| !finished) {
+            let script = match step.phase {
+                Phase::Unlocking => "unlocking",
+                Phase::Locking => "locking",
+            };
+            writeln!(
+                text,
+                "Failed at `{}`, byte {} of the {script} script:\n",
+                step.instruction.asm(),
+                step.instruction.offset
+            )
+            .ok();
+            text.push_str("```\n");
+            let start = failed_at.saturating_sub(4);
+            for (ix, step) in program.iter().enumerate().take(failed_at + 3).skip(start) {
+                let marker = if ix == failed_at { "→" } else { " " };
+                writeln!(text, "{marker} {}", short(&step.instruction.asm())).ok();
+            }
+            text.push_str("```\n\n");
+        }
+    }
+
+    push_stack(&mut text, "Stack", interpreter.stack());
+    if !interpreter.alt_stack().is_empty() {
+        push_stack(&mut text, "Alt stack", interpreter.alt_stack());
+    }
+
+    let input = &tx.inputs[0];
+    writeln!(
+        text,
+        "\nMock context: spends {MOCK_TXID}:0 ({} sats), one output paying the same script, \
+         nLockTime {}, nSequence 0x{:08x}, sighash 0x{:02x}.",
+        call.context.satoshis, tx.lock_time, input.sequence, call.context.sighash_type
+    )
+    .ok();
+    if outcome.is_err() && !artifact.state_props.is_empty() {
+        text.push_str(
+            "\nThe contract is stateful: a method that updates its state expects an output with \
+             the new state, which the mock transaction doesn't have.\n",
+        );
+    }
+    text
+}
+
+fn push_stack(text: &mut String, title: &str, items: &[Vec<u8>]) {
+    if items.is_empty() {
+        writeln!(text, "{title}: empty").ok();
+        return;
+    }
+    writeln!(text, "{title} (top first):\n").ok();
+    for item in items.iter().rev().take(MAX_STACK_ITEMS) {
+        let item = if item.is_empty() {
+            "(empty)".to_string()
+        } else {
+            short(&hex::encode(item))
+        };
+        writeln!(text, "- `{item}`").ok();
+    }
+    if items.len() > MAX_STACK_ITEMS {
+        writeln!(text, "- … {} more", items.len() - MAX_STACK_ITEMS).ok();
+    }
+}
+
+fn short(text: &str) -> String {
+    if text.len() > MAX_ITEM_HEX {
+        format!(
+            "{}…{} ({} bytes)",
+            &text[..24],
+            &text[text.len() - 16..],
+            text.len() / 2
+        )
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_call() {
+        let call =
+            parse_call("Auction(5n, [1, 2]).bid(\"a)b\", 7) sats=2000 sighash=single|anyonecanpay")
+                .unwrap();
+        assert_eq!(call.contract, "Auction");
+        assert_eq!(call.constructor_args, ["5n", "[1, 2]"]);
+        assert_eq!(call.method, "bid");
+        assert_eq!(call.args, ["\"a)b\"", "7"]);
+        assert_eq!(call.context.satoshis, 2000);
+        assert_eq!(call.context.sighash_type, 0xc3);
+
+        let call = parse_call("Counter.increment()").unwrap();
+        assert!(call.constructor_args.is_empty() && call.args.is_empty());
+        assert!(parse_call("Counter.increment() fee=1").is_err());
+    }
+
+    #[test]
+    fn test_simulate() {
+        let key = PrivateKey::from_secret_bytes(&[7; 32], true)
+            .unwrap()
+            .to_wif();
+        let artifact: Artifact = serde_json::from_str(
+            r#"{
+                "contract": "Lock",
+                "hex": "<x>9d<owner>ac",
+                "abi": [
+                    {"type": "function", "name": "unlock", "index": 0, "params": [
+                        {"name": "sig", "type": "Sig"},
+                        {"name": "y", "type": "bigint"}
+                    ]},
+                    {"type": "constructor", "params": [
+                        {"name": "x", "type": "bigint"},
+                        {"name": "owner", "type": "PubKey"}
+                    ]}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let call = parse_call(&format!("Lock(5, {key}).unlock({key}, 5)")).unwrap();
+        let report = simulate(&artifact, &call).unwrap();
+        assert!(report.starts_with("## Lock.unlock: passed\n"), "{report}");
+
+        let call = parse_call(&format!("Lock(5, {key}).unlock({key}, 6)")).unwrap();
+        let report = simulate(&artifact, &call).unwrap();
+        assert!(report.starts_with("## Lock.unlock: failed\n\nOP_NUMEQUALVERIFY failed\n"));
+        assert!(report.contains("Failed at `OP_NUMEQUALVERIFY`, byte 1 of the locking script"));
+
+        let call = parse_call(&format!("Lock(5, {key}).unlock(5)")).unwrap();
+        assert!(simulate(&artifact, &call).is_err());
+    }
+}
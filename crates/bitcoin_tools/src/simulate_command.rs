@@ -0,0 +1,169 @@
+//! `/simulate Contract(args).method(args)`: runs a public method of a
+//! compiled contract in the script interpreter and reports whether it passes.
+
+use std::{
+    path::Path,
+    sync::{Arc, atomic::AtomicBool},
+};
+
+use anyhow::{Result, anyhow};
+use assistant_slash_command::{
+    AfterCompletion, ArgumentCompletion, SlashCommand, SlashCommandOutput,
+    SlashCommandOutputSection, SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use ui::prelude::*;
+use workspace::Workspace;
+
+use crate::{
+    artifact::{Artifact, artifact_name, find_artifacts},
+    bitcoin_project::workspace_project_root,
+    simulate::{parse_call, simulate},
+};
+
+pub struct SimulateSlashCommand;
+
+impl SlashCommand for SimulateSlashCommand {
+    fn name(&self) -> String {
+        "simulate".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("simulate", &["Contract(args).method(args)"], cx)
+    }
+
+    fn description(&self) -> String {
+        "Run a contract method locally against a mock transaction".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::PlayOutlined
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        let (Some(root), [query]) = (
+            workspace.and_then(|workspace| workspace_project_root(&workspace, cx)),
+            arguments,
+        ) else {
+            return Task::ready(Ok(Vec::new()));
+        };
+        let query = query.clone();
+        cx.background_spawn(async move { Ok(completions(&root, &query)) })
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        let Some(root) = workspace_project_root(&workspace, cx) else {
+            return Task::ready(Err(anyhow!("no project is open")));
+        };
+        let text = arguments.join(" ");
+        cx.background_spawn(async move {
+            let call = parse_call(&text)?;
+            let artifact = load_artifact(&root, &call.contract)?;
+            let text = simulate(&artifact, &call)?;
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::PlayOutlined,
+                    label: format!("Simulate {}.{}", call.contract, call.method).into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
+
+fn load_artifact(root: &Path, contract: &str) -> Result<Artifact> {
+    let artifacts = find_artifacts(root);
+    let path = artifacts
+        .iter()
+        .find(|path| artifact_name(path).eq_ignore_ascii_case(contract))
+        .ok_or_else(|| {
+            let names: Vec<_> = artifacts.iter().map(|path| artifact_name(path)).collect();
+            if names.is_empty() {
+                anyhow!("no compiled contracts - run `npx scrypt-cli compile` first")
+            } else {
+                anyhow!(
+                    "no artifact for {contract} - compiled contracts are {}",
+                    names.join(", ")
+                )
+            }
+        })?;
+    Artifact::load(path)
+}
+
+/// Contract names until a `.` is typed, then that contract's public methods.
+/// Arguments are split at spaces, so this only helps until the first one.
+fn completions(root: &Path, query: &str) -> Vec<ArgumentCompletion> {
+    let completion = |new_text: String| ArgumentCompletion {
+        label: new_text.clone().into(),
+        new_text,
+        after_completion: AfterCompletion::Compose,
+        replace_previous_arguments: false,
+    };
+    match query.rfind('.') {
+        Some(dot) => {
+            let typed = &query[dot + 1..];
+            if typed.contains('(') {
+                return Vec::new();
+            }
+            let name_end = query.find(['(', '.']).unwrap_or(dot);
+            let Ok(artifact) = load_artifact(root, &query[..name_end]) else {
+                return Vec::new();
+            };
+            artifact
+                .public_methods()
+                .filter_map(|entry| entry.name.as_deref())
+                .filter(|method| method.starts_with(typed))
+                .map(|method| completion(format!("{}{method}(", &query[..=dot])))
+                .collect()
+        }
+        None => find_artifacts(root)
+            .iter()
+            .filter_map(|path| Artifact::load(path).ok().map(|artifact| (path, artifact)))
+            .map(|(path, artifact)| {
+                let name = artifact_name(path);
+                let suffix = if artifact.constructor_params().is_empty() {
+                    "."
+                } else {
+                    "("
+                };
+                (name, suffix)
+            })
+            .filter(|(name, _)| name.to_lowercase().starts_with(&query.to_lowercase()))
+            .map(|(name, suffix)| completion(format!("{name}{suffix}")))
+            .collect(),
+    }
+}
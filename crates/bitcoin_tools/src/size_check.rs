@@ -0,0 +1,122 @@
+//! Records contract sizes whenever the project's artifacts are recompiled,
+//! and warns when a method grows past the thresholds in `.utxix/sizes.json`.
+
+use std::time::Duration;
+
+use chrono::{SecondsFormat, Utc};
+use gpui::{AppContext as _, Context, DismissEvent, Entity, Task, WeakEntity};
+use project::Project;
+use util::ResultExt as _;
+use workspace::{
+    OpenOptions, Workspace,
+    notifications::{NotificationId, simple_message_notification::MessageNotification},
+};
+
+use crate::{
+    bitcoin_project::project_root,
+    size_history::{SIZES_PATH, record_compiles},
+};
+
+/// `scrypt-cli compile` writes one artifact after another.
+const RECORD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+struct SizeCheck {
+    workspace: WeakEntity<Workspace>,
+    project: Entity<Project>,
+    record_task: Task<()>,
+}
+
+struct ScriptSizeNotification;
+
+pub fn register_workspace(workspace: &mut Workspace, cx: &mut Context<Workspace>) {
+    let project = workspace.project().clone();
+    let check = cx.new(|_| SizeCheck {
+        workspace: workspace.weak_handle(),
+        project: project.clone(),
+        record_task: Task::ready(()),
+    });
+    cx.subscribe(&project, {
+        let check = check.clone();
+        move |_, _, event, cx| {
+            let changed = match event {
+                project::Event::WorktreeAdded(_) => true,
+                project::Event::WorktreeUpdatedEntries(_, entries) => {
+                    entries.iter().any(|(path, _, _)| {
+                        let path = path.as_unix_str();
+                        path.starts_with("artifacts/contracts/") && path.ends_with(".scrypt.json")
+                    })
+                }
+                _ => false,
+            };
+            if changed {
+                check.update(cx, |check, cx| check.record(cx));
+            }
+        }
+    })
+    .detach();
+    check.update(cx, |check, cx| check.record(cx));
+}
+
+impl SizeCheck {
+    fn record(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            return;
+        };
+        self.record_task = cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(RECORD_DEBOUNCE).await;
+            let record_root = root.clone();
+            let result = cx
+                .background_spawn(async move {
+                    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+                    record_compiles(&record_root, Some(timestamp))
+                })
+                .await;
+            let Some(warnings) = result.log_err().filter(|warnings| !warnings.is_empty()) else {
+                return;
+            };
+            this.update(cx, |this, cx| {
+                let message = format!(
+                    "{}\n\nThresholds are set in {SIZES_PATH}.",
+                    warnings
+                        .iter()
+                        .map(|warning| format!("- {warning}"))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                );
+                let workspace = this.workspace.clone();
+                this.workspace
+                    .update(cx, |this_workspace, cx| {
+                        this_workspace.show_notification(
+                            NotificationId::unique::<ScriptSizeNotification>(),
+                            cx,
+                            |cx| {
+                                cx.new(|cx| {
+                                    MessageNotification::new(message, cx)
+                                        .with_title("Script size")
+                                        .primary_message("Open sizes.json")
+                                        .primary_on_click(move |window, cx| {
+                                            let path = root.join(SIZES_PATH);
+                                            workspace
+                                                .update(cx, |workspace, cx| {
+                                                    workspace
+                                                        .open_abs_path(
+                                                            path,
+                                                            OpenOptions::default(),
+                                                            window,
+                                                            cx,
+                                                        )
+                                                        .detach_and_log_err(cx);
+                                                })
+                                                .ok();
+                                            cx.emit(DismissEvent);
+                                        })
+                                })
+                            },
+                        );
+                    })
+                    .ok();
+            })
+            .ok();
+        });
+    }
+}
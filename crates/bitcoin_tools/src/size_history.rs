@@ -0,0 +1,314 @@
+//! Compiled script sizes per contract across compiles, kept in
+//! `.utxix/sizes.json` to catch methods that blow up, e.g. from an unrolled
+//! loop or a larger state, before they're deployed.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::artifact::{Artifact, ScriptSize, artifact_hash, artifact_name, find_artifacts};
+
+pub const SIZES_PATH: &str = ".utxix/sizes.json";
+
+/// Compiles kept per contract.
+const MAX_ENTRIES: usize = 50;
+
+/// Growth below these is never a jump, however large in percent.
+const MIN_JUMP_BYTES: usize = 64;
+const MIN_JUMP_OPS: usize = 32;
+
+/// When a method is worth a warning; edited in `.utxix/sizes.json`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Thresholds {
+    pub max_bytes: usize,
+    pub max_ops: usize,
+    /// Growth between two compiles, in percent of the earlier size.
+    pub max_growth_percent: usize,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            max_bytes: 50_000,
+            max_ops: 20_000,
+            max_growth_percent: 25,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MethodSize {
+    pub bytes: usize,
+    pub ops: usize,
+}
+
+impl From<ScriptSize> for MethodSize {
+    fn from(size: ScriptSize) -> Self {
+        Self {
+            bytes: size.bytes,
+            ops: size.ops,
+        }
+    }
+}
+
+/// The sizes of one compile of a contract.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeEntry {
+    pub artifact_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    pub bytes: usize,
+    pub ops: usize,
+    /// Public methods by name; empty when the dispatch couldn't be split.
+    #[serde(default)]
+    pub methods: BTreeMap<String, MethodSize>,
+}
+
+impl SizeEntry {
+    pub fn new(artifact: &Artifact, artifact_hash: String, timestamp: Option<String>) -> Self {
+        let total = artifact.script_size();
+        Self {
+            artifact_hash,
+            timestamp,
+            bytes: total.bytes,
+            ops: total.ops,
+            methods: artifact
+                .method_sizes()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, size)| (name, size.into()))
+                .collect(),
+        }
+    }
+
+    /// What the checks look at: each method, or the whole contract when its
+    /// methods couldn't be told apart.
+    fn parts(&self) -> Vec<(Option<&str>, MethodSize)> {
+        if self.methods.is_empty() {
+            return vec![(
+                None,
+                MethodSize {
+                    bytes: self.bytes,
+                    ops: self.ops,
+                },
+            )];
+        }
+        self.methods
+            .iter()
+            .map(|(name, size)| (Some(name.as_str()), *size))
+            .collect()
+    }
+}
+
+/// The contents of `.utxix/sizes.json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SizeHistory {
+    #[serde(default)]
+    pub thresholds: Thresholds,
+    /// Compiles of each contract, oldest first.
+    #[serde(default)]
+    pub contracts: BTreeMap<String, Vec<SizeEntry>>,
+}
+
+impl SizeHistory {
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(SIZES_PATH);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let text =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing {SIZES_PATH}"))
+    }
+
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let path = root.join(SIZES_PATH);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// Appends a compile of `contract` and returns what regressed since the
+    /// previous one, or `None` if this artifact is already the latest entry.
+    pub fn record(&mut self, contract: &str, entry: SizeEntry) -> Option<Vec<String>> {
+        let thresholds = self.thresholds;
+        let entries = self.contracts.entry(contract.to_string()).or_default();
+        let previous = entries.last();
+        if previous.is_some_and(|previous| previous.artifact_hash == entry.artifact_hash) {
+            return None;
+        }
+
+        let mut warnings = Vec::new();
+        for (method, size) in entry.parts() {
+            let label = match method {
+                Some(method) => format!("{contract}.{method}"),
+                None => contract.to_string(),
+            };
+            let before = previous.and_then(|previous| {
+                previous
+                    .parts()
+                    .into_iter()
+                    .find(|(name, _)| *name == method)
+                    .map(|(_, size)| size)
+            });
+
+            // Past a limit is only news the first time
+            if size.bytes > thresholds.max_bytes
+                && before.is_none_or(|before| before.bytes <= thresholds.max_bytes)
+            {
+                warnings.push(format!(
+                    "{label} compiles to {} bytes, past the {}-byte threshold",
+                    size.bytes, thresholds.max_bytes
+                ));
+            }
+            if size.ops > thresholds.max_ops
+                && before.is_none_or(|before| before.ops <= thresholds.max_ops)
+            {
+                warnings.push(format!(
+                    "{label} has {} ops, past the {}-op threshold",
+                    size.ops, thresholds.max_ops
+                ));
+            }
+
+            let Some(before) = before else {
+                continue;
+            };
+            let jumped = |before: usize, after: usize, min: usize| {
+                before > 0
+                    && after >= before + min
+                    && (after - before) * 100 > before * thresholds.max_growth_percent
+            };
+            if jumped(before.bytes, size.bytes, MIN_JUMP_BYTES) {
+                warnings.push(format!(
+                    "{label} grew from {} to {} bytes (+{}%) since the last compile",
+                    before.bytes,
+                    size.bytes,
+                    (size.bytes - before.bytes) * 100 / before.bytes
+                ));
+            } else if jumped(before.ops, size.ops, MIN_JUMP_OPS) {
+                warnings.push(format!(
+                    "{label} grew from {} to {} ops (+{}%) since the last compile",
+                    before.ops,
+                    size.ops,
+                    (size.ops - before.ops) * 100 / before.ops
+                ));
+            }
+        }
+
+        entries.push(entry);
+        if entries.len() > MAX_ENTRIES {
+            entries.drain(..entries.len() - MAX_ENTRIES);
+        }
+        Some(warnings)
+    }
+}
+
+/// Records every artifact of the project that changed since it was last
+/// seen, saving the history if anything did. Returns the regressions found.
+pub fn record_compiles(root: &Path, timestamp: Option<String>) -> Result<Vec<String>> {
+    let mut history = SizeHistory::load(root)?;
+    let mut changed = false;
+    let mut warnings = Vec::new();
+    for path in find_artifacts(root) {
+        // Read mid-compile; the next change picks it up
+        let (Ok(artifact), Ok(hash)) = (Artifact::load(&path), artifact_hash(&path)) else {
+            continue;
+        };
+        let entry = SizeEntry::new(&artifact, hash, timestamp.clone());
+        if let Some(regressions) = history.record(&artifact_name(&path), entry) {
+            changed = true;
+            warnings.extend(regressions);
+        }
+    }
+    if changed {
+        history.save(root)?;
+    }
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(hash: &str, methods: &[(&str, usize, usize)]) -> SizeEntry {
+        SizeEntry {
+            artifact_hash: hash.to_string(),
+            timestamp: None,
+            bytes: methods.iter().map(|(_, bytes, _)| bytes).sum(),
+            ops: methods.iter().map(|(_, _, ops)| ops).sum(),
+            methods: methods
+                .iter()
+                .map(|(name, bytes, ops)| {
+                    (
+                        name.to_string(),
+                        MethodSize {
+                            bytes: *bytes,
+                            ops: *ops,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut history = SizeHistory {
+            thresholds: Thresholds {
+                max_bytes: 5_000,
+                ..Thresholds::default()
+            },
+            ..SizeHistory::default()
+        };
+        assert_eq!(
+            history.record(
+                "Game",
+                entry("a", &[("move", 1_000, 400), ("claim", 200, 80)])
+            ),
+            Some(Vec::new())
+        );
+        assert_eq!(
+            history.record("Game", entry("a", &[("move", 9_000, 400)])),
+            None
+        );
+        assert_eq!(
+            history.record(
+                "Game",
+                entry("b", &[("move", 1_200, 420), ("claim", 220, 160)])
+            ),
+            Some(vec![
+                "Game.claim grew from 80 to 160 ops (+100%) since the last compile".to_string()
+            ])
+        );
+        assert_eq!(
+            history.record(
+                "Game",
+                entry("c", &[("move", 6_000, 2_000), ("claim", 220, 160)])
+            ),
+            Some(vec![
+                "Game.move compiles to 6000 bytes, past the 5000-byte threshold".to_string(),
+                "Game.move grew from 1200 to 6000 bytes (+400%) since the last compile".to_string(),
+            ])
+        );
+        assert_eq!(
+            history.record(
+                "Game",
+                entry("d", &[("move", 6_100, 2_010), ("claim", 220, 160)])
+            ),
+            Some(Vec::new())
+        );
+        assert_eq!(history.contracts["Game"].len(), 4);
+
+        let text = serde_json::to_string(&history).unwrap();
+        assert_eq!(serde_json::from_str::<SizeHistory>(&text).unwrap(), history);
+        let configured: SizeHistory =
+            serde_json::from_str(r#"{ "thresholds": { "maxGrowthPercent": 10 } }"#).unwrap();
+        assert_eq!(configured.thresholds.max_growth_percent, 10);
+        assert_eq!(configured.thresholds.max_bytes, 50_000);
+    }
+}
@@ -0,0 +1,337 @@
+//! SPV checks against the project's chain provider: a transaction's merkle
+//! proof is verified against the header of the block it claims to be in, and
+//! the same proof can be turned into scrypt-ts for contracts that check
+//! inclusion on-chain.
+
+use std::{fmt::Write as _, sync::Arc};
+
+use anyhow::{Context as _, Result, ensure};
+use bitcoin_script::{
+    merkle::{BlockHeader, MerkleNode, merkle_path},
+    transaction::parse_txid,
+};
+use http_client::HttpClient;
+
+use crate::{
+    bitcoin_project::BitcoinProject,
+    chain_provider::{self, ChainProviderSettings},
+    whatsonchain::{BlockHeaderInfo, TscProof},
+};
+
+/// A merkle proof that checked out against its block header.
+#[derive(Clone, Debug)]
+pub struct VerifiedProof {
+    pub txid: String,
+    pub block_hash: String,
+    pub height: u64,
+    pub confirmations: u64,
+    /// Position of the transaction in the block.
+    pub index: u64,
+    /// `(sibling, sibling_is_left)` from the leaves up, in internal byte order.
+    pub path: Vec<([u8; 32], bool)>,
+    pub merkle_root: [u8; 32],
+}
+
+/// Fetches the merkle proof for `txid` and the header of its block from the
+/// project's provider and checks one against the other.
+pub async fn fetch_verified_proof(
+    http: Arc<dyn HttpClient>,
+    project: &BitcoinProject,
+    provider: &ChainProviderSettings,
+    txid: &str,
+) -> Result<VerifiedProof> {
+    let txid = txid.trim().to_ascii_lowercase();
+    let client = chain_provider::for_project(http, project, provider)?;
+    let proof = client.merkle_proof(&txid).await?.with_context(|| {
+        format!(
+            "no merkle proof for {txid} on {} - is it confirmed yet?",
+            project.network.name()
+        )
+    })?;
+    let header = client
+        .block_header(&proof.target)
+        .await?
+        .with_context(|| format!("block {} not found", proof.target))?;
+    verify_proof(&txid, &proof, &header)
+}
+
+/// Checks that `proof` leads from `txid` to the merkle root `header` commits
+/// to, and that the header hashes to its block and meets its target.
+///
+/// Only this one header is checked: that the block is on the best chain, and
+/// how deep, is the provider's word.
+pub fn verify_proof(txid: &str, proof: &TscProof, info: &BlockHeaderInfo) -> Result<VerifiedProof> {
+    let leaf = parse_txid(txid).with_context(|| format!("{txid} is not a txid"))?;
+    let nodes = proof
+        .nodes
+        .iter()
+        .map(|node| match node.as_str() {
+            "*" => Ok(MerkleNode::Duplicate),
+            hash => parse_txid(hash)
+                .map(MerkleNode::Hash)
+                .with_context(|| format!("malformed node {hash} in the proof")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let (path, merkle_root) = merkle_path(leaf, proof.index, &nodes);
+
+    let header = BlockHeader {
+        version: info.version,
+        prev_block: match &info.previousblockhash {
+            Some(hash) => parse_txid(hash).context("malformed previous block hash")?,
+            None => [0; 32],
+        },
+        merkle_root: parse_txid(&info.merkleroot).context("malformed merkle root")?,
+        time: info.time,
+        bits: u32::from_str_radix(&info.bits, 16).context("malformed bits")?,
+        nonce: info.nonce,
+    };
+    // Don't take the explorer's word for the hash or the root
+    ensure!(
+        display_hex(&header.hash()) == info.hash.to_ascii_lowercase(),
+        "the header fields of block {} don't hash to it",
+        info.hash
+    );
+    ensure!(
+        info.hash.eq_ignore_ascii_case(&proof.target),
+        "the proof is for block {}, but the header is of block {}",
+        proof.target,
+        info.hash
+    );
+    ensure!(
+        header.meets_target(),
+        "block {} doesn't meet its proof-of-work target",
+        info.hash
+    );
+    ensure!(
+        info.confirmations > 0,
+        "the provider reports block {} as no longer on the best chain",
+        info.hash
+    );
+    ensure!(
+        merkle_root == header.merkle_root,
+        "the proof for {txid} leads to merkle root {}, but block {} commits to {}",
+        display_hex(&merkle_root),
+        info.hash,
+        info.merkleroot
+    );
+
+    Ok(VerifiedProof {
+        txid: txid.to_string(),
+        block_hash: info.hash.to_ascii_lowercase(),
+        height: info.height,
+        confirmations: info.confirmations as u64,
+        index: proof.index,
+        path,
+        merkle_root,
+    })
+}
+
+/// The markdown `/proof` shows for a verified proof: where the transaction
+/// is, what was checked, and the contract and arguments to check it on-chain.
+pub fn report(proof: &VerifiedProof, network: &str, tx_url: &str) -> String {
+    format!(
+        "## Merkle Proof Verified\n\n\
+         **txid:** `{txid}`\n\
+         **Network:** {network}\n\
+         **Block:** `{block}` (height {height}, {confirmations} confirmations)\n\
+         **Index:** {index}\n\
+         **Depth:** {depth}\n\n\
+         {tx_url}\n\n\
+         The proof leads to the block's merkle root, and the block's header hashes \
+         to it and meets its proof-of-work target. The headers above it aren't \
+         checked, so the confirmations are the provider's count.\n\n\
+         ### On-chain verifier\n\n\
+         Hashes are in internal byte order (reversed from how explorers display \
+         them), which is what `hash256` produces.\n\n\
+         ```typescript\n{verifier}```\n\n\
+         ### Arguments for this proof\n\n\
+         ```typescript\n{args}```\n",
+        txid = proof.txid,
+        block = proof.block_hash,
+        height = proof.height,
+        confirmations = proof.confirmations,
+        index = proof.index,
+        depth = proof.path.len(),
+        verifier = scrypt_verifier(proof),
+        args = scrypt_proof_args(proof),
+    )
+}
+
+/// scrypt-ts for a contract that checks a merkle path against a stored root,
+/// sized for `proof`. Hashes are in internal byte order, which is what
+/// `hash256` produces.
+pub fn scrypt_verifier(proof: &VerifiedProof) -> String {
+    format!(
+        r#"import {{ assert, FixedArray, hash256, method, prop, Sha256, SmartContract }} from 'scrypt-ts'
+
+/** A sibling on the path to the root; `left` when it is hashed before the running hash. */
+export type MerkleNode = {{
+    hash: Sha256
+    left: boolean
+}}
+
+/** Enough for blocks of up to 2^MERKLE_DEPTH transactions. */
+export const MERKLE_DEPTH = {depth}
+
+export class MerkleProof extends SmartContract {{
+    @prop()
+    readonly merkleRoot: Sha256
+
+    constructor(merkleRoot: Sha256) {{
+        super(...arguments)
+        this.merkleRoot = merkleRoot
+    }}
+
+    @method()
+    static calcMerkleRoot(
+        txid: Sha256,
+        nodes: FixedArray<MerkleNode, typeof MERKLE_DEPTH>
+    ): Sha256 {{
+        let root = txid
+        for (let i = 0; i < MERKLE_DEPTH; i++) {{
+            const node = nodes[i]
+            root = node.left ? hash256(node.hash + root) : hash256(root + node.hash)
+        }}
+        return root
+    }}
+
+    @method()
+    public verify(txid: Sha256, nodes: FixedArray<MerkleNode, typeof MERKLE_DEPTH>) {{
+        assert(MerkleProof.calcMerkleRoot(txid, nodes) == this.merkleRoot, 'tx is not in the block')
+    }}
+}}
+"#,
+        depth = proof.path.len(),
+    )
+}
+
+/// The arguments for [`scrypt_verifier`]'s contract that prove `proof`, for
+/// use in tests and deploy scripts.
+pub fn scrypt_proof_args(proof: &VerifiedProof) -> String {
+    let leaf = parse_txid(&proof.txid).unwrap_or_default();
+    let mut nodes = String::new();
+    for (sibling, sibling_is_left) in &proof.path {
+        writeln!(
+            nodes,
+            "    {{ hash: Sha256('{}'), left: {sibling_is_left} }},",
+            hex::encode(sibling)
+        )
+        .ok();
+    }
+    format!(
+        "// {} at index {} of block {} (height {})\n\
+         const merkleRoot = Sha256('{}')\n\
+         const txid = Sha256('{}')\n\
+         const nodes: FixedArray<MerkleNode, typeof MERKLE_DEPTH> = [\n{nodes}]\n",
+        proof.txid,
+        proof.index,
+        proof.block_hash,
+        proof.height,
+        hex::encode(proof.merkle_root),
+        hex::encode(leaf),
+    )
+}
+
+fn display_hex(hash: &[u8; 32]) -> String {
+    let mut hash = *hash;
+    hash.reverse();
+    hex::encode(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENESIS_TXID: &str = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+    const GENESIS_HASH: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+    fn genesis_proof() -> TscProof {
+        TscProof {
+            index: 0,
+            tx_or_id: GENESIS_TXID.into(),
+            target: GENESIS_HASH.into(),
+            nodes: Vec::new(),
+        }
+    }
+
+    fn genesis_header() -> BlockHeaderInfo {
+        BlockHeaderInfo {
+            hash: GENESIS_HASH.into(),
+            height: 0,
+            confirmations: 900_000,
+            version: 1,
+            merkleroot: GENESIS_TXID.into(),
+            time: 1231006505,
+            nonce: 2083236893,
+            bits: "1d00ffff".into(),
+            previousblockhash: None,
+        }
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let proof = verify_proof(GENESIS_TXID, &genesis_proof(), &genesis_header()).unwrap();
+        assert_eq!(proof.block_hash, GENESIS_HASH);
+        assert_eq!(proof.confirmations, 900_000);
+        assert!(proof.path.is_empty());
+        assert_eq!(display_hex(&proof.merkle_root), GENESIS_TXID);
+
+        let mut header = genesis_header();
+        header.nonce += 1;
+        let err = verify_proof(GENESIS_TXID, &genesis_proof(), &header).unwrap_err();
+        assert!(err.to_string().contains("don't hash to it"), "{err}");
+
+        let mut header = genesis_header();
+        header.confirmations = -1;
+        let err = verify_proof(GENESIS_TXID, &genesis_proof(), &header).unwrap_err();
+        assert!(
+            err.to_string().contains("no longer on the best chain"),
+            "{err}"
+        );
+
+        let mut proof = genesis_proof();
+        proof.nodes.push(GENESIS_TXID.into());
+        let err = verify_proof(GENESIS_TXID, &proof, &genesis_header()).unwrap_err();
+        assert!(err.to_string().contains("leads to merkle root"), "{err}");
+
+        let mut proof = genesis_proof();
+        proof.target = "00".repeat(32);
+        let err = verify_proof(GENESIS_TXID, &proof, &genesis_header()).unwrap_err();
+        assert!(err.to_string().contains("the proof is for block"), "{err}");
+
+        assert!(verify_proof("not a txid", &genesis_proof(), &genesis_header()).is_err());
+    }
+
+    #[test]
+    fn test_scrypt_proof_args() {
+        let proof = VerifiedProof {
+            txid: GENESIS_TXID.into(),
+            block_hash: GENESIS_HASH.into(),
+            height: 0,
+            confirmations: 1,
+            index: 1,
+            path: vec![([0x11; 32], true), ([0x22; 32], false)],
+            merkle_root: [0x33; 32],
+        };
+        let args = scrypt_proof_args(&proof);
+        assert!(args.contains(&format!("const merkleRoot = Sha256('{}')", "33".repeat(32))));
+        // The txid is converted to internal byte order for `hash256`
+        assert!(args.contains(&format!(
+            "const txid = Sha256('{}')",
+            hex::encode(parse_txid(GENESIS_TXID).unwrap())
+        )));
+        assert!(args.contains(&format!(
+            "{{ hash: Sha256('{}'), left: true }}",
+            "11".repeat(32)
+        )));
+        assert!(args.contains(&format!(
+            "{{ hash: Sha256('{}'), left: false }}",
+            "22".repeat(32)
+        )));
+        assert!(scrypt_verifier(&proof).contains("export const MERKLE_DEPTH = 2"));
+
+        let report = report(&proof, "mainnet", "https://whatsonchain.com/tx/4a5e");
+        assert!(report.contains("**Depth:** 2"));
+        assert!(report.contains("the confirmations are the provider's count"));
+    }
+}
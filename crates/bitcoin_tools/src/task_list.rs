@@ -0,0 +1,168 @@
+//! The checklist in a project's `tasks.md`: the `- [ ]` and `- [x]` items,
+//! with the heading each one sits under.
+
+pub const TASKS_PATH: &str = "tasks.md";
+
+/// Tasks mentioning these are about the contract, so finishing one is worth
+/// having the agent check.
+const CONTRACT_WORDS: [&str; 3] = ["contract", "covenant", "scrypt"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaskItem {
+    /// Zero-based line in `tasks.md`.
+    pub line: usize,
+    /// Nesting level, from the item's indentation.
+    pub depth: usize,
+    pub text: String,
+    pub done: bool,
+    /// The closest heading above the item.
+    pub section: Option<String>,
+}
+
+impl TaskItem {
+    pub fn is_contract_task(&self) -> bool {
+        let text = self.text.to_lowercase();
+        CONTRACT_WORDS.iter().any(|word| text.contains(word))
+    }
+}
+
+pub fn parse(text: &str) -> Vec<TaskItem> {
+    let mut section = None;
+    let mut items = Vec::new();
+    for (line, content) in text.lines().enumerate() {
+        if let Some(heading) = content.strip_prefix('#') {
+            section = Some(heading.trim_start_matches('#').trim().to_string());
+            continue;
+        }
+        if let Some((indent, done, text)) = split_item(content) {
+            items.push(TaskItem {
+                line,
+                depth: indent / 2,
+                text: text.trim().to_string(),
+                done,
+                section: section.clone(),
+            });
+        }
+    }
+    items
+}
+
+/// A list item with a checkbox, as its indentation, whether it's checked,
+/// and its text.
+fn split_item(line: &str) -> Option<(usize, bool, &str)> {
+    let content = line.trim_start();
+    let indent = line.len() - content.len();
+    let content = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|bullet| content.strip_prefix(bullet))?;
+    let done = match content.get(..3)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+    Some((indent, done, &content[3..]))
+}
+
+/// `text` with the item on `line` checked or unchecked, everything else kept
+/// as written. `None` when that line isn't an item anymore.
+pub fn set_done(text: &str, line: usize, done: bool) -> Option<String> {
+    let mut lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let current = *lines.get(line)?;
+    split_item(current)?;
+    let checkbox = current.find('[')?;
+    let mark = if done { "x" } else { " " };
+    let updated = format!(
+        "{}{mark}{}",
+        &current[..checkbox + 1],
+        &current[checkbox + 2..]
+    );
+    lines[line] = &updated;
+    Some(lines.concat())
+}
+
+/// What to ask the agent once `finished` is checked off: to check the work
+/// it describes, then pick up the next open task.
+pub fn follow_up_prompt(items: &[TaskItem], finished: &TaskItem) -> String {
+    let mut prompt = format!(
+        "I've checked off \"{}\" in tasks.md. Review the contracts in contracts/ for it: \
+         compile them with `npx scrypt-cli compile`, run `npm test`, and fix anything that's \
+         missing or broken.",
+        finished.text
+    );
+    let next = items
+        .iter()
+        .find(|item| !item.done && item.line != finished.line);
+    if let Some(next) = next {
+        prompt.push_str(&format!(
+            "\n\nThen start on the next open task: \"{}\".",
+            next.text
+        ));
+    }
+    prompt
+}
+
+/// What to ask the agent to work on `task`.
+pub fn task_prompt(task: &TaskItem) -> String {
+    let section = task
+        .section
+        .as_deref()
+        .map(|section| format!(" (under \"{section}\")"))
+        .unwrap_or_default();
+    format!(
+        "Work on this task from tasks.md{section}: \"{}\". When it's done, check it off in tasks.md.",
+        task.text
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_set_done() {
+        let text = "# Contract\n- [ ] Implement sCrypt covenant contract\n  - [x] Add tests\n\n\
+                    Some notes - [ ] not an item\n## UI\n* [X] Wire wallet connect flow\n- plain item\r\n";
+        let items = parse(text);
+        assert_eq!(
+            items,
+            vec![
+                TaskItem {
+                    line: 1,
+                    depth: 0,
+                    text: "Implement sCrypt covenant contract".to_string(),
+                    done: false,
+                    section: Some("Contract".to_string()),
+                },
+                TaskItem {
+                    line: 2,
+                    depth: 1,
+                    text: "Add tests".to_string(),
+                    done: true,
+                    section: Some("Contract".to_string()),
+                },
+                TaskItem {
+                    line: 6,
+                    depth: 0,
+                    text: "Wire wallet connect flow".to_string(),
+                    done: true,
+                    section: Some("UI".to_string()),
+                },
+            ]
+        );
+        assert!(items[0].is_contract_task());
+        assert!(!items[2].is_contract_task());
+
+        let checked = set_done(text, 1, true).unwrap();
+        assert_eq!(checked, text.replace("- [ ] Implement", "- [x] Implement"));
+        assert_eq!(
+            set_done(&checked, 6, false).unwrap(),
+            checked.replace("* [X] Wire", "* [ ] Wire")
+        );
+        assert_eq!(set_done(text, 4, true), None);
+        assert_eq!(set_done(text, 42, true), None);
+
+        let prompt = follow_up_prompt(&items, &items[0]);
+        assert!(prompt.contains("\"Implement sCrypt covenant contract\""));
+        assert!(!prompt.contains("next open task"));
+    }
+}
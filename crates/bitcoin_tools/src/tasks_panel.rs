@@ -0,0 +1,375 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use agent_ui::AgentPanel;
+use anyhow::{Context as _, Result};
+use gpui::{
+    Action, App, AsyncWindowContext, Context, Entity, EventEmitter, FocusHandle, Focusable, Pixels,
+    Render, SharedString, Task, WeakEntity, Window, actions, px,
+};
+use project::Project;
+use ui::{
+    Checkbox, IconButton, IconName, Label, LabelSize, ListItem, ListItemSpacing, Tab, ToggleState,
+    Tooltip, prelude::*,
+};
+use workspace::{
+    OpenOptions, Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
+};
+
+use crate::{
+    bitcoin_project::project_root,
+    task_list::{self, TASKS_PATH, TaskItem},
+};
+
+const TASKS_PANEL_KEY: &str = "TasksPanel";
+
+actions!(
+    tasks_panel,
+    [
+        /// Toggles focus on the tasks panel.
+        ToggleFocus
+    ]
+);
+
+/// The checklist in the project's `tasks.md`. Checking an item writes it
+/// back, and checking off contract work asks the agent to review it and
+/// move on to the next task.
+pub struct TasksPanel {
+    focus_handle: FocusHandle,
+    workspace: WeakEntity<Workspace>,
+    project: Entity<Project>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    /// `tasks.md`, when the project has one.
+    path: Option<PathBuf>,
+    items: Vec<TaskItem>,
+    error: Option<SharedString>,
+    load_task: Task<()>,
+    write_task: Task<()>,
+}
+
+impl TasksPanel {
+    pub fn load(
+        workspace: WeakEntity<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<Entity<Self>>> {
+        cx.spawn(async move |cx| {
+            workspace.update_in(cx, |workspace, _window, cx| Self::new(workspace, cx))
+        })
+    }
+
+    fn new(workspace: &mut Workspace, cx: &mut Context<Workspace>) -> Entity<Self> {
+        let project = workspace.project().clone();
+        let workspace = workspace.weak_handle();
+
+        cx.new(|cx| {
+            // The agent and the user edit tasks.md too
+            cx.subscribe(&project, |this: &mut Self, _, event, cx| match event {
+                project::Event::WorktreeAdded(_) => this.refresh(cx),
+                project::Event::WorktreeUpdatedEntries(_, entries) => {
+                    if entries
+                        .iter()
+                        .any(|(path, _, _)| path.as_unix_str() == TASKS_PATH)
+                    {
+                        this.refresh(cx);
+                    }
+                }
+                _ => {}
+            })
+            .detach();
+
+            let mut this = Self {
+                focus_handle: cx.focus_handle(),
+                workspace,
+                project,
+                position: DockPosition::Right,
+                width: None,
+                path: None,
+                items: Vec::new(),
+                error: None,
+                load_task: Task::ready(()),
+                write_task: Task::ready(()),
+            };
+            this.refresh(cx);
+            this
+        })
+    }
+
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        let Some(path) = project_root(&self.project, cx).map(|root| root.join(TASKS_PATH)) else {
+            self.path = None;
+            self.items.clear();
+            cx.notify();
+            return;
+        };
+
+        self.load_task = cx.spawn(async move |this, cx| {
+            let read_path = path.clone();
+            let text = cx
+                .background_spawn(async move {
+                    read_path
+                        .is_file()
+                        .then(|| fs::read_to_string(&read_path))
+                        .transpose()
+                })
+                .await;
+            this.update(cx, |this, cx| {
+                match text {
+                    Ok(Some(text)) => {
+                        this.path = Some(path);
+                        this.items = task_list::parse(&text);
+                        this.error = None;
+                    }
+                    Ok(None) => {
+                        this.path = None;
+                        this.items.clear();
+                        this.error = None;
+                    }
+                    Err(err) => {
+                        log::error!("reading {TASKS_PATH} failed: {err:?}");
+                        this.error = Some(format!("{err:#}").into());
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn set_done(&mut self, ix: usize, done: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let (Some(path), Some(item)) = (self.path.clone(), self.items.get(ix).cloned()) else {
+            return;
+        };
+        if done && item.is_contract_task() {
+            let prompt = task_list::follow_up_prompt(&self.items, &item);
+            self.ask_agent(&prompt, window, cx);
+        }
+        self.items[ix].done = done;
+        cx.notify();
+
+        self.write_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_spawn(async move { write_done(&path, item.line, done) })
+                .await;
+            if let Err(err) = result {
+                this.update(cx, |this, cx| {
+                    this.error = Some(format!("{err:#}").into());
+                    this.refresh(cx);
+                })
+                .ok();
+            }
+        });
+    }
+
+    /// Opens the agent panel with `prompt` typed in, for the user to send.
+    fn ask_agent(&self, prompt: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.workspace
+            .update(cx, |workspace, cx| {
+                if let Some(panel) = workspace.focus_panel::<AgentPanel>(window, cx) {
+                    panel.update(cx, |panel, cx| panel.set_prompt_text(prompt, window, cx));
+                }
+            })
+            .ok();
+    }
+
+    fn open_file(&self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+        self.workspace
+            .update(cx, |workspace, cx| {
+                workspace
+                    .open_abs_path(path, OpenOptions::default(), window, cx)
+                    .detach_and_log_err(cx);
+            })
+            .ok();
+    }
+
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let done = self.items.iter().filter(|item| item.done).count();
+
+        h_flex()
+            .justify_between()
+            .px_2()
+            .py_1()
+            .h(Tab::container_height(cx))
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(h_flex().gap_2().child(Label::new("Tasks")).when(
+                !self.items.is_empty(),
+                |this| {
+                    this.child(
+                        Label::new(format!("{done}/{} done", self.items.len()))
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                },
+            ))
+            .child(
+                IconButton::new("open-tasks-file", IconName::FileCode)
+                    .icon_size(IconSize::Small)
+                    .disabled(self.path.is_none())
+                    .tooltip(Tooltip::text("Open tasks.md"))
+                    .on_click(cx.listener(|this, _, window, cx| this.open_file(window, cx))),
+            )
+    }
+
+    fn render_message(message: impl Into<SharedString>) -> impl IntoElement {
+        div().p_4().child(
+            Label::new(message)
+                .color(Color::Muted)
+                .size(LabelSize::Small),
+        )
+    }
+
+    fn render_item(&self, ix: usize, item: &TaskItem, cx: &mut Context<Self>) -> impl IntoElement {
+        let prompt = task_list::task_prompt(item);
+        let ask = (!item.done).then(|| {
+            IconButton::new(
+                SharedString::from(format!("ask-agent-{ix}")),
+                IconName::Sparkle,
+            )
+            .icon_size(IconSize::Small)
+            .tooltip(Tooltip::text("Ask Agent to Do This"))
+            .on_click(cx.listener(move |this, _, window, cx| this.ask_agent(&prompt, window, cx)))
+        });
+
+        ListItem::new(SharedString::from(format!("task-{ix}")))
+            .spacing(ListItemSpacing::Sparse)
+            .inset(true)
+            .indent_level(item.depth)
+            .child(
+                Checkbox::new(
+                    SharedString::from(format!("task-done-{ix}")),
+                    ToggleState::from(item.done),
+                )
+                .label(item.text.clone())
+                .label_size(LabelSize::Small)
+                .label_color(if item.done {
+                    Color::Muted
+                } else {
+                    Color::Default
+                })
+                .on_click(cx.listener(
+                    move |this, state: &ToggleState, window, cx| {
+                        this.set_done(ix, state.selected(), window, cx)
+                    },
+                )),
+            )
+            .end_slot(h_flex().children(ask))
+    }
+}
+
+fn write_done(path: &Path, line: usize, done: bool) -> Result<()> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let updated = task_list::set_done(&text, line, done)
+        .with_context(|| format!("{TASKS_PATH} changed - line {} isn't a task", line + 1))?;
+    fs::write(path, updated).with_context(|| format!("writing {}", path.display()))
+}
+
+impl Render for TasksPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let body = if self.path.is_none() {
+            Self::render_message(
+                "No tasks.md in the project root. Projects created with the wizard's docs option have one.",
+            )
+            .into_any_element()
+        } else {
+            let rows: Vec<_> = self
+                .items
+                .iter()
+                .enumerate()
+                .map(|(ix, item)| self.render_item(ix, item, cx).into_any_element())
+                .collect();
+            v_flex()
+                .id("tasks")
+                .flex_1()
+                .overflow_y_scroll()
+                .when(rows.is_empty(), |this| {
+                    this.child(Self::render_message(
+                        "tasks.md has no checklist items - add some as `- [ ] task`.",
+                    ))
+                })
+                .children(rows)
+                .into_any_element()
+        };
+
+        v_flex()
+            .key_context("TasksPanel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.render_header(cx))
+            .children(self.error.clone().map(|error| {
+                div()
+                    .px_2()
+                    .py_1()
+                    .child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            }))
+            .child(body)
+    }
+}
+
+impl Focusable for TasksPanel {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for TasksPanel {}
+
+impl Panel for TasksPanel {
+    fn persistent_name() -> &'static str {
+        "TasksPanel"
+    }
+
+    fn panel_key() -> &'static str {
+        TASKS_PANEL_KEY
+    }
+
+    fn position(&self, _: &Window, _: &App) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, position: DockPosition) -> bool {
+        matches!(position, DockPosition::Left | DockPosition::Right)
+    }
+
+    fn set_position(&mut self, position: DockPosition, _: &mut Window, cx: &mut Context<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &Window, _: &App) -> Pixels {
+        self.width.unwrap_or(px(320.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, _: &mut Window, cx: &mut Context<Self>) {
+        if active {
+            self.refresh(cx);
+        }
+    }
+
+    fn icon(&self, _: &Window, _: &App) -> Option<IconName> {
+        self.path.is_some().then_some(IconName::ListTodo)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
+        Some("Tasks")
+    }
+
+    fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        16
+    }
+}
@@ -0,0 +1,593 @@
+use std::{collections::HashMap, path::Path, sync::Arc, time::Duration};
+
+use anyhow::Result;
+use gpui::{
+    Action, App, AsyncWindowContext, Context, Entity, EventEmitter, FocusHandle, Focusable, Pixels,
+    Render, SharedString, Task, WeakEntity, Window, actions, px,
+};
+use http_client::HttpClient;
+use project::Project;
+use settings::Settings as _;
+use ui::{
+    IconButton, IconName, Label, LabelSize, ListItem, ListItemSpacing, ListSubHeader, Tab, Tooltip,
+    prelude::*,
+};
+use workspace::{
+    Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
+};
+
+use crate::{
+    bitcoin_project::{BitcoinProject, Deployment, WatchKind, project_root},
+    chain_provider::{self, ChainProvider, ChainProviderSettings},
+    explorer_client,
+};
+
+const UTXO_PANEL_KEY: &str = "UtxoPanel";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+/// Spends followed per contract and refresh; longer chains catch up over
+/// the following refreshes.
+const MAX_CHAIN_STEPS: usize = 50;
+
+actions!(
+    utxo_panel,
+    [
+        /// Toggles focus on the UTXO panel.
+        ToggleFocus
+    ]
+);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Outpoint {
+    txid: String,
+    vout: u32,
+}
+
+struct UtxoRow {
+    outpoint: Outpoint,
+    satoshis: u64,
+    confirmations: u64,
+    /// Current head of a stateful covenant chain.
+    is_head: bool,
+}
+
+struct UtxoGroup {
+    title: SharedString,
+    subtitle: SharedString,
+    rows: Vec<UtxoRow>,
+    note: Option<SharedString>,
+}
+
+struct Snapshot {
+    network: &'static str,
+    explorer_url: String,
+    block_height: u64,
+    groups: Vec<UtxoGroup>,
+    /// The chain provider couldn't be reached, so this came from the cache.
+    offline: bool,
+}
+
+/// Lists the live UTXOs of the project's deployed contracts and addresses.
+pub struct UtxoPanel {
+    focus_handle: FocusHandle,
+    project: Entity<Project>,
+    http_client: Arc<dyn HttpClient>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    active: bool,
+    is_bitcoin_project: bool,
+    loading: bool,
+    snapshot: Option<Snapshot>,
+    error: Option<SharedString>,
+    /// Last known head per deployment outpoint, so refreshes only follow new spends.
+    heads: HashMap<Outpoint, Outpoint>,
+    refresh_task: Task<()>,
+    _poll_task: Task<()>,
+}
+
+impl UtxoPanel {
+    pub fn load(
+        workspace: WeakEntity<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<Entity<Self>>> {
+        cx.spawn(async move |cx| {
+            workspace.update_in(cx, |workspace, _window, cx| Self::new(workspace, cx))
+        })
+    }
+
+    fn new(workspace: &mut Workspace, cx: &mut Context<Workspace>) -> Entity<Self> {
+        let project = workspace.project().clone();
+        let http_client = cx.http_client();
+
+        cx.new(|cx| {
+            let poll_task = cx.spawn(async move |this, cx| {
+                loop {
+                    cx.background_executor().timer(REFRESH_INTERVAL).await;
+                    let updated = this.update(cx, |this, cx| {
+                        if this.active {
+                            this.refresh(cx);
+                        }
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+            });
+
+            let mut this = Self {
+                focus_handle: cx.focus_handle(),
+                project,
+                http_client,
+                position: DockPosition::Right,
+                width: None,
+                active: false,
+                is_bitcoin_project: false,
+                loading: false,
+                snapshot: None,
+                error: None,
+                heads: HashMap::default(),
+                refresh_task: Task::ready(()),
+                _poll_task: poll_task,
+            };
+            this.refresh(cx);
+            this
+        })
+    }
+
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            self.is_bitcoin_project = false;
+            self.snapshot = None;
+            cx.notify();
+            return;
+        };
+
+        let client = self.http_client.clone();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        let heads = self.heads.clone();
+        self.loading = true;
+        cx.notify();
+
+        self.refresh_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_spawn(
+                    async move { fetch_snapshot(&root, client, &provider, heads).await },
+                )
+                .await;
+            this.update(cx, |this, cx| {
+                this.loading = false;
+                match result {
+                    Ok(None) => {
+                        this.is_bitcoin_project = false;
+                        this.snapshot = None;
+                        this.error = None;
+                    }
+                    Ok(Some((snapshot, heads))) => {
+                        this.is_bitcoin_project = true;
+                        this.snapshot = Some(snapshot);
+                        this.heads = heads;
+                        this.error = None;
+                    }
+                    Err(err) => {
+                        log::error!("UTXO refresh failed: {err:?}");
+                        this.error = Some(format!("{err:#}").into());
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let network = self
+            .snapshot
+            .as_ref()
+            .map(|snapshot| format!("{} · block {}", snapshot.network, snapshot.block_height));
+        let offline = self
+            .snapshot
+            .as_ref()
+            .is_some_and(|snapshot| snapshot.offline);
+
+        h_flex()
+            .justify_between()
+            .px_2()
+            .py_1()
+            .h(Tab::container_height(cx))
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new("UTXOs"))
+                    .children(network.map(|network| {
+                        Label::new(network)
+                            .size(LabelSize::Small)
+                            .color(Color::Muted)
+                    }))
+                    .when(offline, |this| {
+                        this.child(
+                            div()
+                                .id("utxos-offline")
+                                .child(
+                                    Label::new("offline")
+                                        .size(LabelSize::Small)
+                                        .color(Color::Warning),
+                                )
+                                .tooltip(Tooltip::text(
+                                    "The chain provider can't be reached - showing cached UTXOs",
+                                )),
+                        )
+                    }),
+            )
+            .child(
+                IconButton::new("refresh-utxos", IconName::RotateCw)
+                    .icon_size(IconSize::Small)
+                    .disabled(self.loading)
+                    .tooltip(Tooltip::text("Refresh"))
+                    .on_click(cx.listener(|this, _, _window, cx| this.refresh(cx))),
+            )
+    }
+
+    fn render_message(message: impl Into<SharedString>) -> impl IntoElement {
+        div().p_4().child(
+            Label::new(message)
+                .color(Color::Muted)
+                .size(LabelSize::Small),
+        )
+    }
+
+    fn render_group(
+        &self,
+        group_ix: usize,
+        group: &UtxoGroup,
+        explorer_url: &str,
+    ) -> impl IntoElement {
+        v_flex()
+            .child(ListSubHeader::new(group.title.clone()).inset(true))
+            .child(
+                div().px_3().child(
+                    Label::new(group.subtitle.clone())
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted)
+                        .truncate(),
+                ),
+            )
+            .children(group.note.clone().map(|note| {
+                div()
+                    .px_3()
+                    .child(Label::new(note).size(LabelSize::Small).color(Color::Muted))
+            }))
+            .children(group.rows.iter().enumerate().map(|(row_ix, row)| {
+                let url = format!("{}/tx/{}", explorer_url, row.outpoint.txid);
+                let confirmations = match row.confirmations {
+                    0 => "unconfirmed".to_string(),
+                    1 => "1 conf".to_string(),
+                    n => format!("{n} conf"),
+                };
+
+                ListItem::new(SharedString::from(format!("utxo-{group_ix}-{row_ix}")))
+                    .spacing(ListItemSpacing::Sparse)
+                    .inset(true)
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Label::new(short_outpoint(&row.outpoint)).size(LabelSize::Small))
+                            .when(row.is_head, |this| {
+                                this.child(
+                                    Label::new("HEAD")
+                                        .size(LabelSize::XSmall)
+                                        .color(Color::Accent),
+                                )
+                            }),
+                    )
+                    .end_slot(
+                        Label::new(format!("{} sats · {}", row.satoshis, confirmations))
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                    .tooltip(Tooltip::text("Open in explorer"))
+                    .on_click(move |_, _window, cx| cx.open_url(&url))
+            }))
+    }
+}
+
+pub(crate) fn short_txid(txid: &str) -> String {
+    if txid.len() > 16 {
+        format!("{}…{}", &txid[..8], &txid[txid.len() - 8..])
+    } else {
+        txid.to_string()
+    }
+}
+
+fn short_outpoint(outpoint: &Outpoint) -> String {
+    format!("{}:{}", short_txid(&outpoint.txid), outpoint.vout)
+}
+
+async fn fetch_snapshot(
+    root: &Path,
+    http: Arc<dyn HttpClient>,
+    provider: &ChainProviderSettings,
+    mut heads: HashMap<Outpoint, Outpoint>,
+) -> Result<Option<(Snapshot, HashMap<Outpoint, Outpoint>)>> {
+    let Some(project) = BitcoinProject::load(root)? else {
+        return Ok(None);
+    };
+    let client = chain_provider::for_project(http, &project, provider)?;
+    let block_height = client.block_height().await?;
+
+    let mut groups = Vec::new();
+    let mut contract_heads = Vec::new();
+    for deployment in &project.deployments {
+        let origin = Outpoint {
+            txid: deployment.txid.clone(),
+            vout: deployment.vout,
+        };
+        let start = heads
+            .get(&origin)
+            .cloned()
+            .unwrap_or_else(|| origin.clone());
+        match contract_group(client.as_ref(), &project, deployment, start).await {
+            Ok((group, head)) => {
+                contract_heads.push(head.clone());
+                heads.insert(origin, head);
+                groups.push(group);
+            }
+            Err(err) => groups.push(UtxoGroup {
+                title: deployment.contract.clone().into(),
+                subtitle: short_outpoint(&origin).into(),
+                rows: Vec::new(),
+                note: Some(format!("{err:#}").into()),
+            }),
+        }
+    }
+
+    for target in project.watch_targets() {
+        let unspent = match target.kind {
+            WatchKind::Address => client.address_unspent(&target.value).await,
+            WatchKind::ScriptHash => client.script_unspent(&target.value).await,
+        };
+        let (rows, note) = match unspent {
+            Ok(unspent) if unspent.is_empty() => (Vec::new(), Some("No UTXOs".into())),
+            Ok(unspent) => {
+                let rows = unspent
+                    .into_iter()
+                    .map(|utxo| {
+                        let outpoint = Outpoint {
+                            txid: utxo.tx_hash,
+                            vout: utxo.tx_pos,
+                        };
+                        UtxoRow {
+                            is_head: contract_heads.contains(&outpoint),
+                            outpoint,
+                            satoshis: utxo.value,
+                            confirmations: confirmations(block_height, utxo.height),
+                        }
+                    })
+                    .collect();
+                (rows, None)
+            }
+            Err(err) => (Vec::new(), Some(format!("{err:#}").into())),
+        };
+        groups.push(UtxoGroup {
+            title: target.label.into(),
+            subtitle: target.value.into(),
+            rows,
+            note,
+        });
+    }
+
+    Ok(Some((
+        Snapshot {
+            network: project.network.name(),
+            explorer_url: project.explorer_url.clone(),
+            block_height,
+            groups,
+            offline: explorer_client::is_offline(client.api_url()),
+        },
+        heads,
+    )))
+}
+
+/// Follows the contract from `start` to its unspent head. Stateful contracts
+/// continue at output 0 of each spending transaction until it is spent into a
+/// plain P2PKH output; stateless ones end at their first spend.
+async fn contract_group(
+    client: &dyn ChainProvider,
+    project: &BitcoinProject,
+    deployment: &Deployment,
+    start: Outpoint,
+) -> Result<(UtxoGroup, Outpoint)> {
+    let stateful = project.is_stateful(&deployment.contract);
+    let mut head = start;
+    let mut spent_in = None;
+    let mut caught_up = false;
+
+    for _ in 0..MAX_CHAIN_STEPS {
+        let Some(spend) = client.spent_by(&head.txid, head.vout).await? else {
+            caught_up = true;
+            break;
+        };
+        let next = Outpoint {
+            txid: spend.txid,
+            vout: 0,
+        };
+        let continues = stateful
+            && client
+                .output(&next.txid, next.vout)
+                .await?
+                .is_some_and(|output| !output.is_p2pkh());
+        if !continues {
+            spent_in = Some(next.txid);
+            caught_up = true;
+            break;
+        }
+        head = next;
+    }
+
+    let mut rows = Vec::new();
+    let note = if let Some(txid) = spent_in {
+        Some(format!("Spent in {}", short_txid(&txid)).into())
+    } else if !caught_up {
+        Some("Following the covenant chain…".into())
+    } else {
+        match client.output(&head.txid, head.vout).await? {
+            Some(output) => {
+                rows.push(UtxoRow {
+                    outpoint: head.clone(),
+                    satoshis: output.satoshis,
+                    confirmations: output.confirmations,
+                    is_head: stateful,
+                });
+                None
+            }
+            None => Some("Transaction not found on this network".into()),
+        }
+    };
+
+    let deployed = deployment
+        .timestamp
+        .as_deref()
+        .map(|timestamp| {
+            format!(
+                " · deployed {}",
+                timestamp.split('T').next().unwrap_or(timestamp)
+            )
+        })
+        .unwrap_or_default();
+    let group = UtxoGroup {
+        title: deployment.contract.clone().into(),
+        subtitle: format!(
+            "{}{}{}",
+            short_outpoint(&Outpoint {
+                txid: deployment.txid.clone(),
+                vout: deployment.vout,
+            }),
+            if stateful { " · stateful" } else { "" },
+            deployed
+        )
+        .into(),
+        rows,
+        note,
+    };
+    Ok((group, head))
+}
+
+pub(crate) fn confirmations(block_height: u64, height: u64) -> u64 {
+    if height == 0 || height > block_height {
+        0
+    } else {
+        block_height - height + 1
+    }
+}
+
+impl Render for UtxoPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let body = if !self.is_bitcoin_project {
+            if self.loading {
+                Self::render_message("Loading UTXOs…").into_any_element()
+            } else {
+                Self::render_message(
+                    "No Bitcoin project found. The panel reads .env and .utxix/deployments.json from the project root.",
+                )
+                .into_any_element()
+            }
+        } else if let Some(snapshot) = &self.snapshot {
+            v_flex()
+                .id("utxo-groups")
+                .flex_1()
+                .overflow_y_scroll()
+                .when(snapshot.groups.is_empty(), |this| {
+                    this.child(Self::render_message(
+                        "Nothing to track yet - run `npm run deploy` or list addresses in UTXIX_WATCH.",
+                    ))
+                })
+                .children(
+                    snapshot
+                        .groups
+                        .iter()
+                        .enumerate()
+                        .map(|(ix, group)| self.render_group(ix, group, &snapshot.explorer_url)),
+                )
+                .into_any_element()
+        } else {
+            Self::render_message("Loading UTXOs…").into_any_element()
+        };
+
+        v_flex()
+            .key_context("UtxoPanel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.render_header(cx))
+            .children(self.error.clone().map(|error| {
+                div()
+                    .px_2()
+                    .py_1()
+                    .child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            }))
+            .child(body)
+    }
+}
+
+impl Focusable for UtxoPanel {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for UtxoPanel {}
+
+impl Panel for UtxoPanel {
+    fn persistent_name() -> &'static str {
+        "UtxoPanel"
+    }
+
+    fn panel_key() -> &'static str {
+        UTXO_PANEL_KEY
+    }
+
+    fn position(&self, _: &Window, _: &App) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, position: DockPosition) -> bool {
+        matches!(position, DockPosition::Left | DockPosition::Right)
+    }
+
+    fn set_position(&mut self, position: DockPosition, _: &mut Window, cx: &mut Context<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &Window, _: &App) -> Pixels {
+        self.width.unwrap_or(px(320.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.active = active;
+        if active {
+            self.refresh(cx);
+        }
+    }
+
+    fn icon(&self, _: &Window, _: &App) -> Option<IconName> {
+        self.is_bitcoin_project.then_some(IconName::Blocks)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
+        Some("UTXO Panel")
+    }
+
+    fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        10
+    }
+}
@@ -0,0 +1,102 @@
+//! Checks that a deployed contract runs the local source: the artifact is
+//! rebuilt into a locking script with the constructor arguments recorded at
+//! deploy time, and compared with the script found on chain.
+
+use std::path::Path;
+
+use anyhow::{Context as _, Result, bail};
+
+use crate::{
+    artifact::{Artifact, split_state},
+    bitcoin_project::Deployment,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    /// The code is the same and only the serialized state differs, as it
+    /// does once a stateful contract has been called.
+    StateDiffers,
+    /// The code differs, first at this byte.
+    Mismatch {
+        offset: usize,
+    },
+}
+
+/// The locking script the local artifact of `deployment`'s contract builds
+/// with the recorded constructor arguments.
+pub fn local_script(root: &Path, deployment: &Deployment) -> Result<Vec<u8>> {
+    let path = root
+        .join("artifacts")
+        .join("contracts")
+        .join(format!("{}.scrypt.json", deployment.contract));
+    if !path.exists() {
+        bail!("no artifact for {} - compile it first", deployment.contract);
+    }
+    let artifact = Artifact::load(&path)?;
+    if deployment.args.is_empty() && !artifact.constructor_params().is_empty() {
+        bail!(
+            "{} takes constructor arguments, but none were recorded with this deployment",
+            deployment.contract
+        );
+    }
+    artifact
+        .locking_script(&deployment.args)
+        .with_context(|| format!("building the locking script of {}", deployment.contract))
+}
+
+/// Compares a locally built locking script with the one found on chain.
+pub fn compare_scripts(local: &[u8], on_chain: &[u8]) -> Verdict {
+    if local == on_chain {
+        return Verdict::Match;
+    }
+    fn code(script: &[u8]) -> &[u8] {
+        split_state(script).map_or(script, |(code, _)| code)
+    }
+    let (local, on_chain) = (code(local), code(on_chain));
+    if local == on_chain {
+        return Verdict::StateDiffers;
+    }
+    let offset = local
+        .iter()
+        .zip(on_chain)
+        .position(|(a, b)| a != b)
+        .unwrap_or(local.len().min(on_chain.len()));
+    Verdict::Mismatch { offset }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_scripts() {
+        let code = [0x76, 0xa9, 0x88, 0xac];
+        let stateful = |state: &[u8]| {
+            let mut script = code.to_vec();
+            script.push(0x6a);
+            script.extend_from_slice(state);
+            script.extend_from_slice(&(state.len() as u32).to_le_bytes());
+            script.push(0);
+            script
+        };
+
+        assert_eq!(compare_scripts(&code, &code), Verdict::Match);
+        assert_eq!(
+            compare_scripts(&stateful(&[1, 2]), &stateful(&[3, 4, 5])),
+            Verdict::StateDiffers
+        );
+        assert_eq!(
+            compare_scripts(&code, &stateful(&[1])),
+            Verdict::StateDiffers
+        );
+        assert_eq!(
+            compare_scripts(&code, &[0x76, 0xa9, 0x87, 0xac]),
+            Verdict::Mismatch { offset: 2 }
+        );
+        assert_eq!(
+            compare_scripts(&code, &code[..3]),
+            Verdict::Mismatch { offset: 3 }
+        );
+    }
+}
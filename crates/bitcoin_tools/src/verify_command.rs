@@ -0,0 +1,101 @@
+//! `/verify <signature> <public key> <message>`: checks an ECDSA signature
+//! and explains why a `checkSig` on it would fail.
+
+use std::sync::{Arc, atomic::AtomicBool};
+
+use anyhow::{Result, anyhow};
+use assistant_slash_command::{
+    ArgumentCompletion, SlashCommand, SlashCommandOutput, SlashCommandOutputSection,
+    SlashCommandResult, create_label_for_command,
+};
+use gpui::{App, Task, WeakEntity};
+use language::{BufferSnapshot, CodeLabel, LspAdapterDelegate};
+use ui::prelude::*;
+use workspace::Workspace;
+
+use crate::signature_check::check;
+
+pub struct VerifySlashCommand;
+
+impl SlashCommand for VerifySlashCommand {
+    fn name(&self) -> String {
+        "verify".into()
+    }
+
+    fn label(&self, cx: &App) -> CodeLabel {
+        create_label_for_command("verify", &["<signature> <public key> <message>"], cx)
+    }
+
+    fn description(&self) -> String {
+        "Check a signature against a public key and message or sighash".into()
+    }
+
+    fn icon(&self) -> IconName {
+        IconName::ShieldCheck
+    }
+
+    fn menu_text(&self) -> String {
+        self.description()
+    }
+
+    fn requires_argument(&self) -> bool {
+        true
+    }
+
+    fn accepts_arguments(&self) -> bool {
+        true
+    }
+
+    fn complete_argument(
+        self: Arc<Self>,
+        _arguments: &[String],
+        _cancel: Arc<AtomicBool>,
+        _workspace: Option<WeakEntity<Workspace>>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Task<Result<Vec<ArgumentCompletion>>> {
+        Task::ready(Ok(Vec::new()))
+    }
+
+    fn run(
+        self: Arc<Self>,
+        arguments: &[String],
+        _context_slash_command_output_sections: &[SlashCommandOutputSection<language::Anchor>],
+        _context_buffer: BufferSnapshot,
+        _workspace: WeakEntity<Workspace>,
+        _delegate: Option<Arc<dyn LspAdapterDelegate>>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Task<SlashCommandResult> {
+        // Arguments are split at spaces, so a text message is joined back
+        // with single spaces
+        let [signature, public_key, message @ ..] = arguments else {
+            return Task::ready(Err(anyhow!(
+                "usage: /verify <signature> <public key> <message, sighash or preimage>"
+            )));
+        };
+        if message.is_empty() {
+            return Task::ready(Err(anyhow!(
+                "missing the message, sighash or preimage that was signed"
+            )));
+        }
+        let signature = signature.clone();
+        let public_key = public_key.clone();
+        let message = message.join(" ");
+        cx.background_spawn(async move {
+            let text = check(&signature, &public_key, &message)?;
+            let range = 0..text.len();
+            Ok(SlashCommandOutput {
+                text,
+                sections: vec![SlashCommandOutputSection {
+                    range,
+                    icon: IconName::ShieldCheck,
+                    label: "Verify signature".into(),
+                    metadata: None,
+                }],
+                run_commands_in_text: false,
+            }
+            .into_event_stream())
+        })
+    }
+}
@@ -0,0 +1,176 @@
+//! Warns when a project's scrypt-ts isn't the release the generated code
+//! targets, and offers to upgrade older projects.
+
+use std::path::PathBuf;
+
+use gpui::{AppContext as _, Context, DismissEvent, Entity, Task, WeakEntity};
+use project::Project;
+use util::ResultExt as _;
+use workspace::{
+    Toast, Workspace,
+    notifications::{NotificationId, simple_message_notification::MessageNotification},
+};
+
+use crate::{
+    bitcoin_project::project_root,
+    scrypt_version::{
+        Compatibility, TARGET_VERSION, Version, apply_upgrade, compatibility, plan_upgrade,
+        project_version,
+    },
+};
+
+/// Files whose changes can change the project's scrypt-ts.
+const MANIFESTS: [&str; 2] = ["package.json", "node_modules/scrypt-ts/package.json"];
+
+struct VersionCheck {
+    workspace: WeakEntity<Workspace>,
+    project: Entity<Project>,
+    /// The last version warned about, so each is only mentioned once.
+    warned: Option<Version>,
+    check_task: Task<()>,
+}
+
+struct ScryptVersionNotification;
+struct ScryptUpgradeToast;
+
+pub fn register_workspace(workspace: &mut Workspace, cx: &mut Context<Workspace>) {
+    let project = workspace.project().clone();
+    let check = cx.new(|_| VersionCheck {
+        workspace: workspace.weak_handle(),
+        project: project.clone(),
+        warned: None,
+        check_task: Task::ready(()),
+    });
+    cx.subscribe(&project, {
+        let check = check.clone();
+        move |_, _, event, cx| {
+            let changed = match event {
+                project::Event::WorktreeAdded(_) => true,
+                project::Event::WorktreeUpdatedEntries(_, entries) => entries
+                    .iter()
+                    .any(|(path, _, _)| MANIFESTS.contains(&path.as_unix_str())),
+                _ => false,
+            };
+            if changed {
+                check.update(cx, |check, cx| check.check(cx));
+            }
+        }
+    })
+    .detach();
+    check.update(cx, |check, cx| check.check(cx));
+}
+
+impl VersionCheck {
+    fn check(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            return;
+        };
+        self.check_task = cx.spawn(async move |this, cx| {
+            let check_root = root.clone();
+            let result = cx
+                .background_spawn(async move {
+                    let version = project_version(&check_root)?;
+                    anyhow::Ok(version.map(|version| {
+                        let upgrades = match compatibility(version) {
+                            Compatibility::Older => plan_upgrade(&check_root),
+                            _ => Vec::new(),
+                        };
+                        (version, upgrades)
+                    }))
+                })
+                .await;
+            let Some(Some((version, upgrades))) = result.log_err() else {
+                return;
+            };
+            this.update(cx, |this, cx| {
+                let compatibility = compatibility(version);
+                if compatibility == Compatibility::Compatible || this.warned == Some(version) {
+                    return;
+                }
+                this.warned = Some(version);
+
+                let mut titles: Vec<&str> = upgrades
+                    .iter()
+                    .flat_map(|upgrade| upgrade.titles.iter().copied())
+                    .collect();
+                titles.sort();
+                titles.dedup();
+                let message = match compatibility {
+                    Compatibility::Newer => format!(
+                        "This project uses scrypt-ts {version}, but the editor's templates, snippets \
+                         and quick fixes are written for {TARGET_VERSION}; code they generate \
+                         may use APIs that have changed."
+                    ),
+                    _ if titles.is_empty() => format!(
+                        "This project uses scrypt-ts {version}, older than the {TARGET_VERSION} \
+                         the editor's templates, snippets and quick fixes are written for."
+                    ),
+                    _ => format!(
+                        "This project uses scrypt-ts {version}, older than the {TARGET_VERSION} \
+                         the editor's templates, snippets and quick fixes are written for. {} \
+                         file(s) use APIs that changed:\n{}",
+                        upgrades.len(),
+                        titles
+                            .iter()
+                            .map(|title| format!("- {title}"))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    ),
+                };
+                let upgradable = compatibility == Compatibility::Older;
+                let workspace = this.workspace.clone();
+                this.workspace
+                    .update(cx, |this_workspace, cx| {
+                        this_workspace.show_notification(
+                            NotificationId::unique::<ScryptVersionNotification>(),
+                            cx,
+                            |cx| {
+                                cx.new(|cx| {
+                                    let notification = MessageNotification::new(message, cx)
+                                        .with_title("scrypt-ts version");
+                                    if !upgradable {
+                                        return notification;
+                                    }
+                                    notification
+                                        .primary_message(format!(
+                                            "Upgrade to scrypt-ts {TARGET_VERSION}"
+                                        ))
+                                        .primary_on_click(move |_window, cx| {
+                                            upgrade(root.clone(), workspace.clone(), cx);
+                                            cx.emit(DismissEvent);
+                                        })
+                                })
+                            },
+                        );
+                    })
+                    .ok();
+            })
+            .ok();
+        });
+    }
+}
+
+/// Rewrites the project for the target release, then says what's left to do.
+fn upgrade(root: PathBuf, workspace: WeakEntity<Workspace>, cx: &mut gpui::App) {
+    cx.spawn(async move |cx| {
+        let result = cx
+            .background_spawn(async move { apply_upgrade(&root) })
+            .await;
+        workspace
+            .update(cx, |workspace, cx| {
+                let message = match result {
+                    Ok(changed) => format!(
+                        "Updated {changed} file(s) for scrypt-ts {TARGET_VERSION} - run \
+                         `npm install` to install it."
+                    ),
+                    Err(err) => format!("Upgrading scrypt-ts failed: {err:#}"),
+                };
+                workspace.show_toast(
+                    Toast::new(NotificationId::unique::<ScryptUpgradeToast>(), message),
+                    cx,
+                );
+            })
+            .ok();
+    })
+    .detach();
+}
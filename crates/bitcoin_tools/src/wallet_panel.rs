@@ -0,0 +1,727 @@
+use std::{cmp::Reverse, path::Path, sync::Arc, time::Duration};
+
+use anyhow::{Context as _, Result, bail};
+use bitcoin_script::keys::decode_address;
+use credentials_provider::CredentialsProvider;
+use gpui::{
+    Action, App, AsyncWindowContext, ClipboardItem, Context, Entity, EventEmitter, FocusHandle,
+    Focusable, Pixels, Render, SharedString, Task, WeakEntity, Window, actions, px,
+};
+use http_client::HttpClient;
+use project::Project;
+use settings::Settings as _;
+use ui::{
+    Button, IconButton, IconName, Label, LabelSize, ListItem, ListItemSpacing, ListSubHeader, Tab,
+    Tooltip, prelude::*,
+};
+use ui_input::InputField;
+use workspace::{
+    Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
+};
+
+use crate::{
+    bitcoin_project::{BitcoinProject, Network, project_root},
+    chain_provider::{self, ChainProviderSettings},
+    deploy::{deploy_key, parse_key},
+    keychain::{DEPLOY_KEY_ADDRESS, KeyLocation, adopt_key, generate_key, load_deploy_key},
+    signer::SigningServer,
+    utxo_panel::{confirmations, short_txid},
+    whatsonchain::Balance,
+};
+
+const WALLET_PANEL_KEY: &str = "WalletPanel";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+/// Watch-only address shown instead of the deploy key's address.
+pub(crate) const WALLET_ADDRESS_KEY: &str = "UTXIX_WALLET";
+const TESTNET_FAUCET_URL: &str = "https://scrypt.io/faucet";
+const MAX_RECENT_TRANSACTIONS: usize = 10;
+
+actions!(
+    wallet_panel,
+    [
+        /// Toggles focus on the wallet panel.
+        ToggleFocus
+    ]
+);
+
+struct RecentTransaction {
+    txid: String,
+    confirmations: u64,
+}
+
+struct Snapshot {
+    network: Network,
+    explorer_url: String,
+    key_location: KeyLocation,
+    /// `None` until the project has a deploy key or a watch-only address.
+    account: Option<Account>,
+}
+
+struct Account {
+    address: String,
+    /// Where the address comes from: the deploy key or a watch-only setting.
+    source: &'static str,
+    balance: Balance,
+    recent: Vec<RecentTransaction>,
+}
+
+enum KeyAction {
+    Generate,
+    Import(String),
+    MoveFromEnv,
+}
+
+/// Balance and recent transactions of the project's deploy key (or a
+/// watch-only address), so checking on a deploy doesn't need a browser wallet.
+/// Also moves the deploy key into the OS keychain and serves signatures from
+/// it to the deploy scripts.
+pub struct WalletPanel {
+    focus_handle: FocusHandle,
+    project: Entity<Project>,
+    http_client: Arc<dyn HttpClient>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    active: bool,
+    is_bitcoin_project: bool,
+    loading: bool,
+    snapshot: Option<Snapshot>,
+    error: Option<SharedString>,
+    wif_input: Entity<InputField>,
+    signer: Option<SigningServer>,
+    refresh_task: Task<()>,
+    key_task: Task<()>,
+    _poll_task: Task<()>,
+}
+
+impl WalletPanel {
+    pub fn load(
+        workspace: WeakEntity<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<Entity<Self>>> {
+        cx.spawn(async move |cx| {
+            workspace.update_in(cx, |workspace, window, cx| Self::new(workspace, window, cx))
+        })
+    }
+
+    fn new(
+        workspace: &mut Workspace,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) -> Entity<Self> {
+        let project = workspace.project().clone();
+        let http_client = cx.http_client();
+
+        cx.new(|cx| {
+            let poll_task = cx.spawn(async move |this, cx| {
+                loop {
+                    cx.background_executor().timer(REFRESH_INTERVAL).await;
+                    let updated = this.update(cx, |this, cx| {
+                        if this.active {
+                            this.refresh(cx);
+                        }
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+            });
+
+            let mut this = Self {
+                focus_handle: cx.focus_handle(),
+                project,
+                http_client,
+                position: DockPosition::Right,
+                width: None,
+                active: false,
+                is_bitcoin_project: false,
+                loading: false,
+                snapshot: None,
+                error: None,
+                wif_input: cx.new(|cx| InputField::new(window, cx, "Paste a WIF key")),
+                signer: None,
+                refresh_task: Task::ready(()),
+                key_task: Task::ready(()),
+                _poll_task: poll_task,
+            };
+            this.refresh(cx);
+            this
+        })
+    }
+
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            self.is_bitcoin_project = false;
+            self.snapshot = None;
+            self.signer = None;
+            cx.notify();
+            return;
+        };
+
+        let client = self.http_client.clone();
+        let provider = ChainProviderSettings::get_global(cx).clone();
+        self.loading = true;
+        cx.notify();
+
+        self.refresh_task = cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_spawn(async move { fetch_snapshot(&root, client, &provider).await })
+                .await;
+            this.update(cx, |this, cx| {
+                this.loading = false;
+                match result {
+                    Ok(None) => {
+                        this.is_bitcoin_project = false;
+                        this.snapshot = None;
+                        this.signer = None;
+                        this.error = None;
+                    }
+                    Ok(Some(snapshot)) => {
+                        // The signer serves one project's keychain key; stop it when that changes
+                        let signing_for = match &snapshot.key_location {
+                            KeyLocation::Keychain { address } => Some(address),
+                            KeyLocation::Env | KeyLocation::Unset => None,
+                        };
+                        if this
+                            .signer
+                            .as_ref()
+                            .is_some_and(|signer| Some(&signer.address) != signing_for)
+                        {
+                            this.signer = None;
+                        }
+                        this.is_bitcoin_project = true;
+                        this.snapshot = Some(snapshot);
+                        this.error = None;
+                    }
+                    Err(err) => {
+                        this.is_bitcoin_project = true;
+                        this.error = Some(format!("{err:#}").into());
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    /// Puts a key into the OS keychain and points the project's `.env` at it.
+    fn store_key(&mut self, action: KeyAction, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            return;
+        };
+        let credentials = <dyn CredentialsProvider>::global(cx);
+        self.key_task = cx.spawn_in(window, async move |this, cx| {
+            let result = async {
+                let mut project = BitcoinProject::load(&root)?
+                    .context("no .env found - create one from .env.testnet")?;
+                let key = match action {
+                    KeyAction::Generate => generate_key(project.network),
+                    KeyAction::Import(wif) => parse_key(&wif, project.network, "the pasted key")?,
+                    KeyAction::MoveFromEnv => deploy_key(&project)?,
+                };
+                adopt_key(&mut project, &key, credentials.as_ref(), cx).await
+            }
+            .await;
+            this.update_in(cx, |this, window, cx| {
+                match result {
+                    Ok(()) => {
+                        this.wif_input
+                            .update(cx, |input, cx| input.set_text("", window, cx));
+                        this.error = None;
+                        this.refresh(cx);
+                    }
+                    Err(err) => this.error = Some(format!("{err:#}").into()),
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    /// Copies the keychain key's WIF, for an offline backup.
+    fn copy_wif(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = project_root(&self.project, cx) else {
+            return;
+        };
+        let credentials = <dyn CredentialsProvider>::global(cx);
+        self.key_task = cx.spawn(async move |this, cx| {
+            let result = async {
+                let project = BitcoinProject::load(&root)?.context("no .env found")?;
+                load_deploy_key(&project, credentials.as_ref(), cx).await
+            }
+            .await;
+            this.update(cx, |this, cx| {
+                match result {
+                    Ok(key) => cx.write_to_clipboard(ClipboardItem::new_string(key.to_wif())),
+                    Err(err) => this.error = Some(format!("{err:#}").into()),
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    /// Starts or stops serving signatures from the keychain key to the deploy scripts.
+    fn toggle_signer(&mut self, cx: &mut Context<Self>) {
+        if self.signer.take().is_some() {
+            cx.notify();
+            return;
+        }
+        let Some(root) = project_root(&self.project, cx) else {
+            return;
+        };
+        let credentials = <dyn CredentialsProvider>::global(cx);
+        self.key_task = cx.spawn(async move |this, cx| {
+            let result = async {
+                let project = BitcoinProject::load(&root)?.context("no .env found")?;
+                let key = load_deploy_key(&project, credentials.as_ref(), cx).await?;
+                SigningServer::start(&root, key)
+            }
+            .await;
+            this.update(cx, |this, cx| {
+                match result {
+                    Ok(signer) => this.signer = Some(signer),
+                    Err(err) => this.error = Some(format!("{err:#}").into()),
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn render_header(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let network = self
+            .snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.network.name());
+
+        h_flex()
+            .justify_between()
+            .px_2()
+            .py_1()
+            .h(Tab::container_height(cx))
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new("Wallet"))
+                    .children(network.map(|network| {
+                        Label::new(network)
+                            .size(LabelSize::Small)
+                            .color(Color::Muted)
+                    })),
+            )
+            .child(
+                IconButton::new("refresh-wallet", IconName::RotateCw)
+                    .icon_size(IconSize::Small)
+                    .disabled(self.loading)
+                    .tooltip(Tooltip::text("Refresh"))
+                    .on_click(cx.listener(|this, _, _window, cx| this.refresh(cx))),
+            )
+    }
+
+    fn render_message(message: impl Into<SharedString>) -> impl IntoElement {
+        div().p_4().child(
+            Label::new(message)
+                .color(Color::Muted)
+                .size(LabelSize::Small),
+        )
+    }
+
+    fn render_address(snapshot: &Snapshot, account: &Account) -> impl IntoElement {
+        let address = account.address.clone();
+        let copied_address = address.clone();
+        let address_url = format!("{}/address/{}", snapshot.explorer_url, address);
+        let fund_address = address.clone();
+
+        v_flex()
+            .child(ListSubHeader::new("Address").inset(true))
+            .child(
+                v_flex()
+                    .px_3()
+                    .gap_1()
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(Label::new(address).size(LabelSize::Small).truncate())
+                            .child(
+                                IconButton::new("wallet-copy-address", IconName::Copy)
+                                    .icon_size(IconSize::Small)
+                                    .tooltip(Tooltip::text("Copy Address"))
+                                    .on_click(move |_, _window, cx| {
+                                        cx.write_to_clipboard(ClipboardItem::new_string(
+                                            copied_address.clone(),
+                                        ))
+                                    }),
+                            )
+                            .child(
+                                IconButton::new("wallet-open-address", IconName::ArrowUpRight)
+                                    .icon_size(IconSize::Small)
+                                    .tooltip(Tooltip::text("Open in Explorer"))
+                                    .on_click(move |_, _window, cx| cx.open_url(&address_url)),
+                            ),
+                    )
+                    .child(
+                        Label::new(account.source)
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted),
+                    )
+                    .when(snapshot.network == Network::Testnet, |this| {
+                        this.child(
+                            Button::new("wallet-fund", "Fund This Address")
+                                .label_size(LabelSize::Small)
+                                .tooltip(Tooltip::text(
+                                    "Copies the address and opens the testnet faucet",
+                                ))
+                                .on_click(move |_, _window, cx| {
+                                    cx.write_to_clipboard(ClipboardItem::new_string(
+                                        fund_address.clone(),
+                                    ));
+                                    cx.open_url(TESTNET_FAUCET_URL);
+                                }),
+                        )
+                    }),
+            )
+    }
+
+    fn render_keys(&self, snapshot: &Snapshot, cx: &mut Context<Self>) -> impl IntoElement {
+        let body = match &snapshot.key_location {
+            KeyLocation::Keychain { .. } => v_flex()
+                .gap_1()
+                .child(
+                    Label::new("Stored in the OS keychain")
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                )
+                .child(
+                    h_flex()
+                        .gap_1()
+                        .child(
+                            Button::new(
+                                "wallet-toggle-signer",
+                                if self.signer.is_some() {
+                                    "Stop Script Signer"
+                                } else {
+                                    "Let Deploy Scripts Sign"
+                                },
+                            )
+                            .label_size(LabelSize::Small)
+                            .tooltip(Tooltip::text(
+                                "Serves signatures to npm run deploy through .utxix/signer.json",
+                            ))
+                            .on_click(cx.listener(|this, _, _window, cx| this.toggle_signer(cx))),
+                        )
+                        .child(
+                            Button::new("wallet-copy-wif", "Copy WIF")
+                                .label_size(LabelSize::Small)
+                                .tooltip(Tooltip::text(
+                                    "Copies the private key for an offline backup",
+                                ))
+                                .on_click(cx.listener(|this, _, _window, cx| this.copy_wif(cx))),
+                        ),
+                )
+                .children(self.signer.as_ref().map(|signer| {
+                    Label::new(format!("Signing at {}", signer.url))
+                        .size(LabelSize::XSmall)
+                        .color(Color::Success)
+                }))
+                .into_any_element(),
+            KeyLocation::Env => v_flex()
+                .gap_1()
+                .child(
+                    Label::new("DEPLOY_PRIVATE_KEY is stored in plaintext in .env")
+                        .size(LabelSize::Small)
+                        .color(Color::Warning),
+                )
+                .child(
+                    Button::new("wallet-move-key", "Move to Keychain")
+                        .label_size(LabelSize::Small)
+                        .tooltip(Tooltip::text(
+                            "Saves the key in the OS keychain and removes it from .env",
+                        ))
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.store_key(KeyAction::MoveFromEnv, window, cx)
+                        })),
+                )
+                .into_any_element(),
+            KeyLocation::Unset => v_flex()
+                .gap_1()
+                .child(
+                    Label::new(format!(
+                        "No deploy key yet. Generate a {} key or import one; it is kept in the OS keychain.",
+                        snapshot.network.name()
+                    ))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+                )
+                .child(
+                    Button::new("wallet-generate-key", "Generate Key")
+                        .label_size(LabelSize::Small)
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.store_key(KeyAction::Generate, window, cx)
+                        })),
+                )
+                .child(
+                    h_flex()
+                        .gap_1()
+                        .child(div().flex_1().child(self.wif_input.clone()))
+                        .child(
+                            Button::new("wallet-import-key", "Import")
+                                .label_size(LabelSize::Small)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    let wif = this.wif_input.read(cx).text(cx).trim().to_string();
+                                    this.store_key(KeyAction::Import(wif), window, cx)
+                                })),
+                        ),
+                )
+                .into_any_element(),
+        };
+
+        v_flex()
+            .child(ListSubHeader::new("Deploy Key").inset(true))
+            .child(div().px_3().child(body))
+    }
+
+    fn render_balance(balance: Balance) -> impl IntoElement {
+        v_flex()
+            .child(ListSubHeader::new("Balance").inset(true))
+            .child(
+                v_flex()
+                    .px_3()
+                    .child(Label::new(format_satoshis(balance.confirmed)))
+                    .when(balance.unconfirmed != 0, |this| {
+                        this.child(
+                            Label::new(format!(
+                                "{} unconfirmed",
+                                format_satoshis(balance.unconfirmed)
+                            ))
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                        )
+                    }),
+            )
+    }
+
+    fn render_recent(snapshot: &Snapshot, account: &Account) -> impl IntoElement {
+        v_flex()
+            .child(ListSubHeader::new("Recent Transactions").inset(true))
+            .when(account.recent.is_empty(), |this| {
+                this.child(
+                    div().px_3().child(
+                        Label::new("No transactions yet")
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    ),
+                )
+            })
+            .children(account.recent.iter().enumerate().map(|(ix, tx)| {
+                let url = format!("{}/tx/{}", snapshot.explorer_url, tx.txid);
+                let status = match tx.confirmations {
+                    0 => "unconfirmed".to_string(),
+                    1 => "1 conf".to_string(),
+                    n => format!("{n} conf"),
+                };
+                ListItem::new(SharedString::from(format!("wallet-tx-{ix}")))
+                    .spacing(ListItemSpacing::Sparse)
+                    .inset(true)
+                    .child(Label::new(short_txid(&tx.txid)).size(LabelSize::Small))
+                    .end_slot(Label::new(status).size(LabelSize::Small).color(
+                        if tx.confirmations == 0 {
+                            Color::Warning
+                        } else {
+                            Color::Muted
+                        },
+                    ))
+                    .tooltip(Tooltip::text("Open in explorer"))
+                    .on_click(move |_, _window, cx| cx.open_url(&url))
+            }))
+    }
+}
+
+fn format_satoshis(satoshis: i64) -> String {
+    format!(
+        "{} sats ({:.8} BSV)",
+        satoshis,
+        satoshis as f64 / 100_000_000.0
+    )
+}
+
+async fn fetch_snapshot(
+    root: &Path,
+    http: Arc<dyn HttpClient>,
+    provider: &ChainProviderSettings,
+) -> Result<Option<Snapshot>> {
+    let Some(project) = BitcoinProject::load(root)? else {
+        return Ok(None);
+    };
+
+    let key_location = KeyLocation::of(&project);
+    let watched = project.setting(WALLET_ADDRESS_KEY);
+    let (address, source) = match (watched, &key_location) {
+        (Some(address), _) => {
+            check_address(address, project.network, WALLET_ADDRESS_KEY)?;
+            (address.to_string(), "Watch-only (UTXIX_WALLET)")
+        }
+        (None, KeyLocation::Keychain { address }) => {
+            check_address(address, project.network, DEPLOY_KEY_ADDRESS)?;
+            (address.clone(), "Deploy key (OS keychain)")
+        }
+        (None, KeyLocation::Env) => (
+            deploy_key(&project)?.address(),
+            "Deploy key (DEPLOY_PRIVATE_KEY in .env)",
+        ),
+        (None, KeyLocation::Unset) => {
+            return Ok(Some(Snapshot {
+                network: project.network,
+                explorer_url: project.explorer_url.clone(),
+                key_location,
+                account: None,
+            }));
+        }
+    };
+
+    let client = chain_provider::for_project(http, &project, provider)?;
+    let block_height = client.block_height().await?;
+    let balance = client.address_balance(&address).await?;
+    let mut history = client.address_history(&address).await?;
+    // Unconfirmed (height 0) first, then newest blocks
+    history.sort_by_key(|entry| (entry.height != 0, Reverse(entry.height)));
+    let recent = history
+        .into_iter()
+        .take(MAX_RECENT_TRANSACTIONS)
+        .map(|entry| RecentTransaction {
+            confirmations: confirmations(block_height, entry.height),
+            txid: entry.tx_hash,
+        })
+        .collect();
+
+    Ok(Some(Snapshot {
+        network: project.network,
+        explorer_url: project.explorer_url.clone(),
+        key_location,
+        account: Some(Account {
+            address,
+            source,
+            balance,
+            recent,
+        }),
+    }))
+}
+
+fn check_address(address: &str, network: Network, source: &str) -> Result<()> {
+    let (_, testnet) =
+        decode_address(address).with_context(|| format!("{source} is not a valid address"))?;
+    if testnet != (network == Network::Testnet) {
+        bail!("{source} is not a {} address", network.name());
+    }
+    Ok(())
+}
+
+impl Render for WalletPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let body = if !self.is_bitcoin_project {
+            if self.loading {
+                Self::render_message("Loading wallet…").into_any_element()
+            } else {
+                Self::render_message(
+                    "No Bitcoin project found. The panel reads the deploy key or UTXIX_WALLET from .env.",
+                )
+                .into_any_element()
+            }
+        } else if let Some(snapshot) = &self.snapshot {
+            v_flex()
+                .id("wallet")
+                .flex_1()
+                .gap_2()
+                .overflow_y_scroll()
+                .when_some(snapshot.account.as_ref(), |this, account| {
+                    this.child(Self::render_address(snapshot, account))
+                        .child(Self::render_balance(account.balance))
+                        .child(Self::render_recent(snapshot, account))
+                })
+                .child(self.render_keys(snapshot, cx))
+                .into_any_element()
+        } else if self.loading {
+            Self::render_message("Loading wallet…").into_any_element()
+        } else {
+            div().into_any_element()
+        };
+
+        v_flex()
+            .key_context("WalletPanel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.render_header(cx))
+            .children(self.error.clone().map(|error| {
+                div()
+                    .px_2()
+                    .py_1()
+                    .child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            }))
+            .child(body)
+    }
+}
+
+impl Focusable for WalletPanel {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for WalletPanel {}
+
+impl Panel for WalletPanel {
+    fn persistent_name() -> &'static str {
+        "WalletPanel"
+    }
+
+    fn panel_key() -> &'static str {
+        WALLET_PANEL_KEY
+    }
+
+    fn position(&self, _: &Window, _: &App) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, _: DockPosition) -> bool {
+        true
+    }
+
+    fn set_position(&mut self, position: DockPosition, _: &mut Window, cx: &mut Context<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &Window, _: &App) -> Pixels {
+        self.width.unwrap_or(px(300.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.active = active;
+        if active {
+            self.refresh(cx);
+        }
+    }
+
+    fn icon(&self, _: &Window, _: &App) -> Option<IconName> {
+        self.is_bitcoin_project.then_some(IconName::LockOutlined)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
+        Some("Wallet")
+    }
+
+    fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        13
+    }
+}
@@ -0,0 +1,317 @@
+use std::sync::Arc;
+
+use anyhow::{Context as _, Result};
+use http_client::HttpClient;
+use serde::{Deserialize, de::DeserializeOwned};
+
+use crate::explorer_client::{ExplorerClient, Freshness};
+
+/// An unspent output as listed by `/address/{address}/unspent`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Unspent {
+    pub tx_hash: String,
+    pub tx_pos: u32,
+    pub value: u64,
+    /// 0 while unconfirmed.
+    #[serde(default)]
+    pub height: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Spend {
+    pub txid: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct TxOut {
+    pub satoshis: u64,
+    pub confirmations: u64,
+    /// Height of the block containing the transaction; 0 while unconfirmed.
+    pub height: u64,
+    pub script_hex: String,
+}
+
+impl TxOut {
+    pub fn is_p2pkh(&self) -> bool {
+        self.script_hex.len() == 50 && self.script_hex.starts_with("76a914")
+    }
+}
+
+/// An input of a transaction: the outpoint it spends and its unlocking script.
+#[derive(Clone, Debug)]
+pub struct TxInput {
+    /// Empty for a coinbase input.
+    pub txid: String,
+    pub vout: u32,
+    pub script_sig_hex: String,
+}
+
+/// Where a transaction stands, from `/tx/hash/{txid}`.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct TxStatus {
+    /// 0 while unconfirmed.
+    #[serde(default)]
+    pub confirmations: u64,
+    #[serde(default)]
+    pub blockheight: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub struct Balance {
+    pub confirmed: i64,
+    pub unconfirmed: i64,
+}
+
+/// A transaction touching an address or script, as listed by
+/// `/address/{address}/history` and `/script/{hash}/history`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct HistoryEntry {
+    pub tx_hash: String,
+    /// 0 while unconfirmed.
+    #[serde(default)]
+    pub height: u64,
+}
+
+/// A merkle proof in the TSC format, as returned by `/tx/{txid}/proof/tsc`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TscProof {
+    pub index: u64,
+    /// The txid, or the whole transaction in hex.
+    #[serde(rename = "txOrId")]
+    pub tx_or_id: String,
+    /// Hash of the block the proof leads to.
+    pub target: String,
+    /// Sibling hashes from the leaves up; `*` duplicates the running hash.
+    pub nodes: Vec<String>,
+}
+
+/// A block header as returned by `/block/{hash}/header`, hashes in display order.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BlockHeaderInfo {
+    pub hash: String,
+    pub height: u64,
+    /// Negative for blocks that are no longer on the best chain.
+    #[serde(default)]
+    pub confirmations: i64,
+    pub version: u32,
+    pub merkleroot: String,
+    pub time: u32,
+    pub nonce: u32,
+    /// Compact target, in hex.
+    pub bits: String,
+    /// Absent for the genesis block.
+    #[serde(default)]
+    pub previousblockhash: Option<String>,
+}
+
+/// Minimal WhatsOnChain API client, cached and rate limited through
+/// [`ExplorerClient`].
+pub struct WhatsOnChain {
+    client: ExplorerClient,
+    api_url: String,
+}
+
+impl WhatsOnChain {
+    pub fn new(http: Arc<dyn HttpClient>, api_url: String) -> Self {
+        Self {
+            client: ExplorerClient::new(http),
+            api_url,
+        }
+    }
+
+    pub async fn block_height(&self) -> Result<u64> {
+        #[derive(Deserialize)]
+        struct ChainInfo {
+            blocks: u64,
+        }
+
+        let info: ChainInfo = self
+            .get("/chain/info", Freshness::Live)
+            .await?
+            .context("chain info not found")?;
+        Ok(info.blocks)
+    }
+
+    pub async fn address_unspent(&self, address: &str) -> Result<Vec<Unspent>> {
+        Ok(self
+            .get(&format!("/address/{address}/unspent"), Freshness::Live)
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub async fn address_balance(&self, address: &str) -> Result<Balance> {
+        Ok(self
+            .get(&format!("/address/{address}/balance"), Freshness::Live)
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub async fn address_history(&self, address: &str) -> Result<Vec<HistoryEntry>> {
+        Ok(self
+            .get(&format!("/address/{address}/history"), Freshness::Live)
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub async fn script_unspent(&self, script_hash: &str) -> Result<Vec<Unspent>> {
+        Ok(self
+            .get(&format!("/script/{script_hash}/unspent"), Freshness::Live)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Transactions paying to or spending from a script, by its script hash
+    /// (SHA-256 of the script, in reversed byte order).
+    pub async fn script_history(&self, script_hash: &str) -> Result<Vec<HistoryEntry>> {
+        Ok(self
+            .get(&format!("/script/{script_hash}/history"), Freshness::Live)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// The transaction spending `txid:vout`, or `None` while it is unspent.
+    pub async fn spent_by(&self, txid: &str, vout: u32) -> Result<Option<Spend>> {
+        self.get(&format!("/tx/{txid}/{vout}/spent"), Freshness::Live)
+            .await
+    }
+
+    pub async fn tx_status(&self, txid: &str) -> Result<Option<TxStatus>> {
+        self.get(&format!("/tx/hash/{txid}"), Freshness::Live).await
+    }
+
+    pub async fn output(&self, txid: &str, vout: u32) -> Result<Option<TxOut>> {
+        Ok(self
+            .outputs(txid)
+            .await?
+            .and_then(|outputs| outputs.into_iter().nth(vout as usize)))
+    }
+
+    /// Every output of a transaction, in order.
+    pub async fn outputs(&self, txid: &str) -> Result<Option<Vec<TxOut>>> {
+        #[derive(Deserialize)]
+        struct Tx {
+            #[serde(default)]
+            confirmations: u64,
+            #[serde(default)]
+            blockheight: u64,
+            vout: Vec<Output>,
+        }
+
+        #[derive(Deserialize)]
+        struct Output {
+            n: u32,
+            /// In BSV, not satoshis.
+            value: f64,
+            #[serde(rename = "scriptPubKey")]
+            script_pub_key: ScriptPubKey,
+        }
+
+        #[derive(Deserialize)]
+        struct ScriptPubKey {
+            hex: String,
+        }
+
+        let Some(mut tx) = self
+            .get::<Tx>(&format!("/tx/hash/{txid}"), Freshness::Live)
+            .await?
+        else {
+            return Ok(None);
+        };
+        tx.vout.sort_by_key(|output| output.n);
+        Ok(Some(
+            tx.vout
+                .into_iter()
+                .map(|output| TxOut {
+                    satoshis: (output.value * 100_000_000.0).round() as u64,
+                    confirmations: tx.confirmations,
+                    height: tx.blockheight,
+                    script_hex: output.script_pub_key.hex,
+                })
+                .collect(),
+        ))
+    }
+
+    /// Every input of a transaction, in order. Comes from the same response
+    /// as [`Self::outputs`], so asking for both costs one request.
+    pub async fn inputs(&self, txid: &str) -> Result<Option<Vec<TxInput>>> {
+        #[derive(Deserialize)]
+        struct Tx {
+            vin: Vec<Input>,
+        }
+
+        #[derive(Deserialize)]
+        struct Input {
+            #[serde(default)]
+            txid: String,
+            #[serde(default)]
+            vout: u32,
+            #[serde(default, rename = "scriptSig")]
+            script_sig: Option<ScriptSig>,
+        }
+
+        #[derive(Deserialize)]
+        struct ScriptSig {
+            hex: String,
+        }
+
+        let Some(tx) = self
+            .get::<Tx>(&format!("/tx/hash/{txid}"), Freshness::Live)
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(
+            tx.vin
+                .into_iter()
+                .map(|input| TxInput {
+                    txid: input.txid,
+                    vout: input.vout,
+                    script_sig_hex: input
+                        .script_sig
+                        .map(|script_sig| script_sig.hex)
+                        .unwrap_or_default(),
+                })
+                .collect(),
+        ))
+    }
+
+    pub async fn merkle_proof(&self, txid: &str) -> Result<Option<TscProof>> {
+        // Returned as a one-element array, or `null` while unconfirmed
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Proofs {
+            One(TscProof),
+            Many(Vec<TscProof>),
+        }
+
+        let proofs = self
+            .get::<Option<Proofs>>(&format!("/tx/{txid}/proof/tsc"), Freshness::Live)
+            .await?
+            .flatten();
+        Ok(match proofs {
+            Some(Proofs::One(proof)) => Some(proof),
+            Some(Proofs::Many(proofs)) => proofs.into_iter().next(),
+            None => None,
+        })
+    }
+
+    pub async fn block_header(&self, hash: &str) -> Result<Option<BlockHeaderInfo>> {
+        self.get(&format!("/block/{hash}/header"), Freshness::Settled)
+            .await
+    }
+
+    /// GETs `path` and parses the JSON body; a 404 is `Ok(None)`.
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        freshness: Freshness,
+    ) -> Result<Option<T>> {
+        let url = format!("{}{}", self.api_url, path);
+        let Some(body) = self.client.get(&url, freshness).await? else {
+            return Ok(None);
+        };
+        serde_json::from_slice(&body)
+            .map(Some)
+            .with_context(|| format!("parsing response from {url}"))
+    }
+}
@@ -415,6 +415,30 @@ pub fn set_diagnostic_renderer(renderer: impl DiagnosticRenderer + 'static, cx:
     cx.set_global(GlobalDiagnosticRenderer(Arc::new(renderer)));
 }
 
+/// Links words that are neither URLs nor paths, such as transaction ids, in
+/// buffers and in terminal output.
+pub trait LinkResolver {
+    /// The URL `word` links to. `dir` is the directory of the file or the
+    /// terminal the word appears in.
+    fn resolve_link(&self, word: &str, dir: Option<&Path>, cx: &App) -> Option<String>;
+
+    /// A plain-text description of what `word` refers to, shown on hover, or
+    /// `None` when it doesn't link anywhere.
+    fn describe_link(&self, word: &str, dir: Option<&Path>, cx: &mut App) -> Task<Option<String>>;
+}
+
+struct GlobalLinkResolver(Arc<dyn LinkResolver>);
+
+impl gpui::Global for GlobalLinkResolver {}
+
+pub fn link_resolver(cx: &App) -> Option<Arc<dyn LinkResolver>> {
+    cx.try_global::<GlobalLinkResolver>().map(|g| g.0.clone())
+}
+
+pub fn set_link_resolver(resolver: impl LinkResolver + 'static, cx: &mut App) {
+    cx.set_global(GlobalLinkResolver(Arc::new(resolver)));
+}
+
 pub struct SearchWithinRange;
 
 trait InvalidationRegion {
@@ -10,7 +10,10 @@ use linkify::{LinkFinder, LinkKind};
 use lsp::LanguageServerId;
 use project::{InlayId, LocationLink, Project, ResolvedPath};
 use settings::Settings;
-use std::ops::Range;
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+};
 use theme::ActiveTheme as _;
 use util::{ResultExt, TryFutureExt as _, maybe};
 
@@ -360,6 +363,22 @@ pub fn show_link_definition(
                             (range, vec![HoverLink::Url(url)])
                         })
                         .ok()
+                    } else if let Some((word_range, word, dir)) = cx
+                        .update(|_, cx| find_resolvable_word(&buffer, text_anchor, cx))
+                        .ok()
+                        .flatten()
+                        && let Some(url) = cx
+                            .update(|_, cx| {
+                                crate::link_resolver(cx)?.resolve_link(&word, dir.as_deref(), cx)
+                            })
+                            .ok()
+                            .flatten()
+                    {
+                        let range = maybe!({
+                            let range = snapshot.anchor_range_in_excerpt(excerpt_id, word_range)?;
+                            Some(RangeInEditor::Text(range))
+                        });
+                        Some((range, vec![HoverLink::Url(url)]))
                     } else if let Some((filename_range, filename)) =
                         find_file(&buffer, project.clone(), text_anchor, cx).await
                     {
@@ -526,6 +545,46 @@ pub(crate) fn find_url(
     None
 }
 
+/// The alphanumeric word under `position`, for the global [`crate::LinkResolver`],
+/// along with the directory of the buffer's file.
+pub(crate) fn find_resolvable_word(
+    buffer: &Entity<language::Buffer>,
+    position: text::Anchor,
+    cx: &App,
+) -> Option<(Range<text::Anchor>, String, Option<PathBuf>)> {
+    const LIMIT: usize = 128;
+
+    let buffer = buffer.read(cx);
+    let snapshot = buffer.snapshot();
+    let offset = position.to_offset(&snapshot);
+    let start = offset
+        - snapshot
+            .reversed_chars_at(offset)
+            .take(LIMIT)
+            .take_while(char::is_ascii_alphanumeric)
+            .count();
+    let end = offset
+        + snapshot
+            .chars_at(offset)
+            .take(LIMIT)
+            .take_while(char::is_ascii_alphanumeric)
+            .count();
+    if start == end {
+        return None;
+    }
+
+    let word = snapshot.text_for_range(start..end).collect::<String>();
+    let dir = buffer
+        .file()
+        .and_then(|file| file.as_local())
+        .and_then(|file| file.abs_path(cx).parent().map(Path::to_path_buf));
+    Some((
+        snapshot.anchor_before(start)..snapshot.anchor_after(end),
+        word,
+        dir,
+    ))
+}
+
 pub(crate) fn find_url_from_range(
     buffer: &Entity<language::Buffer>,
     range: Range<text::Anchor>,
@@ -1625,6 +1684,121 @@ mod tests {
         });
     }
 
+    /// Links words starting with `tx` to a fake explorer, like the Bitcoin
+    /// tools do for transaction ids.
+    struct TestLinkResolver;
+
+    impl crate::LinkResolver for TestLinkResolver {
+        fn resolve_link(&self, word: &str, dir: Option<&Path>, _: &App) -> Option<String> {
+            let txid = word.strip_prefix("tx")?;
+            let dir = dir?.file_name()?.to_str()?;
+            Some(format!("https://explorer.test/{dir}/tx/{txid}"))
+        }
+
+        fn describe_link(&self, word: &str, _: Option<&Path>, _: &mut App) -> Task<Option<String>> {
+            Task::ready(
+                word.strip_prefix("tx")
+                    .map(|txid| format!("Transaction {txid}")),
+            )
+        }
+    }
+
+    #[gpui::test]
+    async fn test_resolvable_word(cx: &mut gpui::TestAppContext) {
+        init_test(cx, |_| {});
+        let mut cx = EditorLspTestContext::new_rust(
+            lsp::ServerCapabilities {
+                ..Default::default()
+            },
+            cx,
+        )
+        .await;
+
+        let test_cases = [
+            ("ˇtx00ff", Some("tx00ff")),
+            ("tx00ffˇ", Some("tx00ff")),
+            ("\"tx0ˇ0ff\"", Some("tx00ff")),
+            ("deadbeef:1ˇ2", Some("12")),
+            ("a.bˇc", Some("bc")),
+            ("a ˇ b", None),
+            ("ˇ", None),
+        ];
+
+        for (input, expected) in test_cases {
+            cx.set_state(input);
+
+            let result = cx.editor(|editor, _, cx| {
+                let position = editor.selections.newest_anchor().head().text_anchor;
+                let buffer = editor.buffer().read(cx).as_singleton().unwrap();
+                find_resolvable_word(&buffer, position, cx)
+            });
+
+            match expected {
+                Some(expected) => {
+                    let (_, word, dir) = result.unwrap_or_else(|| panic!("no word in {input}"));
+                    assert_eq!(word, expected, "Incorrect word for input: {input}");
+                    assert_eq!(
+                        dir.as_deref().and_then(Path::file_name),
+                        Some(std::ffi::OsStr::new("dir")),
+                        "Incorrect directory for input: {input}"
+                    );
+                }
+                None => assert!(result.is_none(), "Expected no word in {input}"),
+            }
+        }
+    }
+
+    #[gpui::test]
+    async fn test_hover_resolved_links(cx: &mut gpui::TestAppContext) {
+        init_test(cx, |_| {});
+        let mut cx = EditorLspTestContext::new_rust(
+            lsp::ServerCapabilities {
+                ..Default::default()
+            },
+            cx,
+        )
+        .await;
+        cx.update(|_, cx| crate::set_link_resolver(TestLinkResolver, cx));
+
+        cx.set_state(indoc! {"
+            let txid = \"tx00ff\";
+            let other = \"00ff\";ˇ
+        "});
+
+        // Words the resolver doesn't link aren't highlighted
+        let screen_coord = cx.pixel_position(indoc! {"
+            let txid = \"tx00ff\";
+            let other = \"00ˇff\";
+        "});
+        cx.simulate_mouse_move(screen_coord, None, Modifiers::secondary_key());
+        cx.update_editor(|editor, window, cx| {
+            assert!(
+                editor
+                    .snapshot(window, cx)
+                    .text_highlight_ranges::<HoveredLinkState>()
+                    .unwrap_or_default()
+                    .1
+                    .is_empty()
+            );
+        });
+
+        let screen_coord = cx.pixel_position(indoc! {"
+            let txid = \"tx0ˇ0ff\";
+            let other = \"00ff\";
+        "});
+        cx.simulate_mouse_move(screen_coord, None, Modifiers::secondary_key());
+        cx.assert_editor_text_highlights::<HoveredLinkState>(indoc! {"
+            let txid = \"«tx00ffˇ»\";
+            let other = \"00ff\";
+        "});
+
+        cx.simulate_click(screen_coord, Modifiers::secondary_key());
+        assert_eq!(
+            cx.opened_url(),
+            Some("https://explorer.test/dir/tx/00ff".into())
+        );
+    }
+
     #[gpui::test]
     async fn test_hover_directories(cx: &mut gpui::TestAppContext) {
         init_test(cx, |_| {});
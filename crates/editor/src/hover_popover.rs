@@ -2,7 +2,7 @@ use crate::{
     ActiveDiagnostic, Anchor, AnchorRangeExt, DisplayPoint, DisplayRow, Editor, EditorSettings,
     EditorSnapshot, GlobalDiagnosticRenderer, Hover,
     display_map::{InlayOffset, ToDisplayPoint, is_invisible},
-    hover_links::{InlayHighlight, RangeInEditor},
+    hover_links::{InlayHighlight, RangeInEditor, find_resolvable_word},
     movement::TextLayoutDetails,
     scroll::ScrollAmount,
 };
@@ -306,6 +306,11 @@ fn show_hover(
             };
 
             let hover_request = cx.update(|_, cx| provider.hover(&buffer, buffer_position, cx))?;
+            let link_description = cx.update(|_, cx| {
+                let (range, word, dir) = find_resolvable_word(&buffer, buffer_position, cx)?;
+                let resolver = crate::link_resolver(cx)?;
+                Some((range, resolver.describe_link(&word, dir.as_deref(), cx)))
+            })?;
 
             if let Some(delay) = delay {
                 delay.await;
@@ -441,7 +446,9 @@ fn show_hover(
             let snapshot = this.update_in(cx, |this, window, cx| this.snapshot(window, cx))?;
             let mut hover_highlights = Vec::with_capacity(hovers_response.len());
             let mut info_popovers = Vec::with_capacity(
-                hovers_response.len() + if invisible_char.is_some() { 1 } else { 0 },
+                hovers_response.len()
+                    + if invisible_char.is_some() { 1 } else { 0 }
+                    + if link_description.is_some() { 1 } else { 0 },
             );
 
             if let Some((invisible, range)) = invisible_char {
@@ -470,6 +477,39 @@ fn show_hover(
                 })
             }
 
+            if let Some((range, description)) = link_description
+                && let Some(text) = description.await
+            {
+                let range = snapshot
+                    .buffer_snapshot()
+                    .anchor_range_in_excerpt(excerpt_id, range)
+                    .unwrap_or_else(|| anchor..anchor);
+                let blocks = vec![HoverBlock {
+                    text,
+                    kind: HoverBlockKind::PlainText,
+                }];
+                let parsed_content =
+                    parse_blocks(&blocks, language_registry.as_ref(), None, cx).await;
+                let scroll_handle = ScrollHandle::new();
+                hover_highlights.push(range.clone());
+                let subscription = this
+                    .update(cx, |_, cx| {
+                        parsed_content.as_ref().map(|parsed_content| {
+                            cx.observe(parsed_content, |_, _, cx| cx.notify())
+                        })
+                    })
+                    .ok()
+                    .flatten();
+                info_popovers.push(InfoPopover {
+                    symbol_range: RangeInEditor::Text(range),
+                    parsed_content,
+                    scroll_handle,
+                    keyboard_grace: Rc::new(RefCell::new(ignore_timeout)),
+                    anchor: Some(anchor),
+                    _subscription: subscription,
+                });
+            }
+
             for hover_result in hovers_response {
                 // Create symbol range of anchors for highlighting and filtering of future requests.
                 let range = hover_result
@@ -81,6 +81,9 @@ pub struct SettingsContent {
     /// Default: VSCode
     pub base_keymap: Option<BaseKeymapContent>,
 
+    /// Where the Bitcoin tools read chain data and broadcast transactions.
+    pub chain_provider: Option<ChainProviderSettingsContent>,
+
     /// Configuration for the collab panel visual settings.
     pub collaboration_panel: Option<PanelSettingsContent>,
 
@@ -884,6 +887,55 @@ pub enum ImageFileSizeUnit {
     Decimal,
 }
 
+/// Where the Bitcoin tools read chain data and broadcast transactions. A
+/// project's `.env` can pick another with `CHAIN_PROVIDER` and
+/// `CHAIN_PROVIDER_URL`.
+#[with_fallible_options]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, MergeFrom, Default, PartialEq)]
+pub struct ChainProviderSettingsContent {
+    /// The provider to use.
+    ///
+    /// Default: "whatsonchain"
+    pub provider: Option<ChainProviderName>,
+    /// Base URL of a WhatsOnChain-compatible indexer for testnet, used by the
+    /// "custom" provider.
+    ///
+    /// Default: null
+    pub testnet_url: Option<String>,
+    /// Base URL of a WhatsOnChain-compatible indexer for mainnet, used by the
+    /// "custom" provider.
+    ///
+    /// Default: null
+    pub mainnet_url: Option<String>,
+}
+
+#[with_fallible_options]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    MergeFrom,
+    Default,
+    PartialEq,
+    strum::VariantArray,
+    strum::VariantNames,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainProviderName {
+    /// WhatsOnChain for everything.
+    #[default]
+    WhatsOnChain,
+    /// GorillaPool's ARC for broadcasts and transaction status.
+    GorillaPool,
+    /// TAAL's ARC for broadcasts and transaction status.
+    Taal,
+    /// A WhatsOnChain-compatible indexer at a URL of your own.
+    Custom,
+}
+
 #[with_fallible_options]
 #[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, MergeFrom, PartialEq)]
 pub struct RemoteSettingsContent {
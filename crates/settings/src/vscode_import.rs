@@ -177,6 +177,7 @@ impl VsCodeSettings {
             auto_update: None,
             base_keymap: Some(BaseKeymapContent::VSCode),
             calls: None,
+            chain_provider: None,
             collaboration_panel: None,
             debugger: None,
             diagnostics: None,
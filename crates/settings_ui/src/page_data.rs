@@ -7091,6 +7091,22 @@ fn language_settings_data() -> Vec<SettingsPageItem> {
                 metadata: None,
                 files: USER,
             }),
+            SettingsPageItem::SettingItem(SettingItem {
+                title: "Chain Provider",
+                description: "Where the Bitcoin tools read chain data and broadcast transactions.",
+                field: Box::new(SettingField {
+                    json_path: Some("chain_provider.provider"),
+                    pick: |settings_content| {
+                        settings_content.chain_provider.as_ref().and_then(|chain_provider| chain_provider.provider.as_ref())
+                    },
+                    write: |settings_content, value| {
+                        settings_content.chain_provider.get_or_insert_default().provider = value;
+
+                    },
+                }),
+                metadata: None,
+                files: USER,
+            }),
             SettingsPageItem::SettingItem(SettingItem {
                 title: "Auto Replace Emoji Shortcode",
                 description: "Whether to automatically replace emoji shortcodes with emoji characters.",
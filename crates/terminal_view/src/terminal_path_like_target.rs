@@ -1,10 +1,10 @@
 use super::{HoverTarget, HoveredWord, TerminalView};
 use anyhow::{Context as _, Result};
-use editor::Editor;
+use editor::{Editor, LinkResolver};
 use gpui::{App, AppContext, Context, Task, WeakEntity, Window};
 use itertools::Itertools;
 use project::{Entry, Metadata};
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 use terminal::PathLikeTarget;
 use util::{
     ResultExt, debug_panic,
@@ -73,6 +73,9 @@ pub(super) fn hover_path_like_target(
     path_like_target: &PathLikeTarget,
     cx: &mut Context<TerminalView>,
 ) -> Task<()> {
+    if let Some((resolver, url)) = resolve_link(path_like_target, cx) {
+        return hover_resolved_link(resolver, url, hovered_word, path_like_target, cx);
+    }
     #[cfg(not(test))]
     {
         possible_hover_target(workspace, hovered_word, path_like_target, cx)
@@ -89,6 +92,61 @@ pub(super) fn hover_path_like_target(
     }
 }
 
+/// The URL the global [`LinkResolver`] links the word to, such as an
+/// explorer link for a transaction id, which takes precedence over paths.
+fn resolve_link(
+    path_like_target: &PathLikeTarget,
+    cx: &App,
+) -> Option<(Arc<dyn LinkResolver>, String)> {
+    let resolver = editor::link_resolver(cx)?;
+    let url = resolver.resolve_link(
+        &path_like_target.maybe_path,
+        path_like_target.terminal_dir.as_deref(),
+        cx,
+    )?;
+    Some((resolver, url))
+}
+
+fn hover_resolved_link(
+    resolver: Arc<dyn LinkResolver>,
+    url: String,
+    hovered_word: HoveredWord,
+    path_like_target: &PathLikeTarget,
+    cx: &mut Context<TerminalView>,
+) -> Task<()> {
+    let description = resolver.describe_link(
+        &path_like_target.maybe_path,
+        path_like_target.terminal_dir.as_deref(),
+        cx,
+    );
+    cx.spawn(async move |terminal_view, cx| {
+        terminal_view
+            .update(cx, |terminal_view, cx| {
+                terminal_view.hover = Some(HoverTarget {
+                    tooltip: url.clone(),
+                    hovered_word: hovered_word.clone(),
+                });
+                cx.notify();
+            })
+            .ok();
+        let Some(description) = description.await else {
+            return;
+        };
+        terminal_view
+            .update(cx, |terminal_view, cx| {
+                if let Some(hover) = terminal_view
+                    .hover
+                    .as_mut()
+                    .filter(|hover| hover.hovered_word == hovered_word)
+                {
+                    hover.tooltip = format!("{url}\n{description}");
+                    cx.notify();
+                }
+            })
+            .ok();
+    })
+}
+
 fn possible_hover_target(
     workspace: &WeakEntity<Workspace>,
     hovered_word: HoveredWord,
@@ -404,6 +462,10 @@ pub(super) fn open_path_like_target(
     window: &mut Window,
     cx: &mut Context<TerminalView>,
 ) {
+    if let Some((_, url)) = resolve_link(path_like_target, cx) {
+        cx.open_url(&url);
+        return;
+    }
     #[cfg(not(test))]
     {
         possibly_open_target(workspace, terminal_view, path_like_target, window, cx)
@@ -905,6 +967,117 @@ mod tests {
         )
     }
 
+    /// Links words starting with `tx` to a fake explorer, like the Bitcoin
+    /// tools do for transaction ids.
+    struct TestLinkResolver;
+
+    impl LinkResolver for TestLinkResolver {
+        fn resolve_link(&self, word: &str, dir: Option<&Path>, _: &App) -> Option<String> {
+            let txid = word.strip_prefix("tx")?;
+            let dir = dir?.file_name()?.to_str()?;
+            Some(format!("https://explorer.test/{dir}/tx/{txid}"))
+        }
+
+        fn describe_link(&self, word: &str, _: Option<&Path>, _: &mut App) -> Task<Option<String>> {
+            Task::ready(
+                word.strip_prefix("tx")
+                    .map(|txid| format!("Transaction {txid}")),
+            )
+        }
+    }
+
+    #[gpui::test]
+    async fn resolved_link_preferred(app_cx: &mut TestAppContext) {
+        let fs = app_cx.update(AppState::test).fs.as_fake().clone();
+        app_cx.update(|cx| {
+            theme::init(theme::LoadThemes::JustBase, cx);
+            editor::init(cx);
+            editor::set_link_resolver(TestLinkResolver, cx);
+        });
+        fs.insert_tree(
+            path!("/test"),
+            json!({
+                "tx00ff": "",
+                "lib.rs": "",
+            }),
+        )
+        .await;
+        let project = Project::test(fs.clone(), [Path::new(path!("/test"))], app_cx).await;
+        let (workspace, cx) =
+            app_cx.add_window_view(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        let cwd = std::env::current_dir().expect("Failed to get working directory");
+        let terminal = project
+            .update(cx, |project, cx| {
+                project.create_terminal_shell(Some(cwd), cx)
+            })
+            .await
+            .expect("Failed to create a terminal");
+        let (terminal_view, cx) = app_cx.add_window_view(|window, cx| {
+            TerminalView::new(
+                terminal,
+                workspace.downgrade(),
+                None,
+                project.downgrade(),
+                window,
+                cx,
+            )
+        });
+
+        let mut hover = async |maybe_path: &str| {
+            let hovered_word = HoveredWord {
+                word: maybe_path.to_string(),
+                word_match: AlacPoint::default()..=AlacPoint::default(),
+                id: 0,
+            };
+            let path_like_target = PathLikeTarget {
+                maybe_path: maybe_path.to_string(),
+                terminal_dir: Some(PathBuf::from(path!("/test"))),
+            };
+            terminal_view
+                .update(cx, |_, cx| {
+                    hover_path_like_target(
+                        &workspace.downgrade(),
+                        hovered_word,
+                        &path_like_target,
+                        cx,
+                    )
+                })
+                .await;
+            terminal_view.update_in(cx, |terminal_view, window, cx| {
+                open_path_like_target(
+                    &workspace.downgrade(),
+                    terminal_view,
+                    &path_like_target,
+                    window,
+                    cx,
+                )
+            });
+            cx.run_until_parked();
+            let tooltip = terminal_view.read_with(cx, |terminal_view, _| {
+                terminal_view
+                    .hover
+                    .as_ref()
+                    .map(|hover| hover.tooltip.clone())
+            });
+            (tooltip, cx.opened_url())
+        };
+
+        // The word is also a file in the worktree, but the resolver wins
+        let (tooltip, opened_url) = hover("tx00ff").await;
+        assert_eq!(
+            tooltip.as_deref(),
+            Some("https://explorer.test/test/tx/00ff\nTransaction 00ff")
+        );
+        assert_eq!(
+            opened_url.as_deref(),
+            Some("https://explorer.test/test/tx/00ff")
+        );
+
+        // Words the resolver doesn't link are still paths
+        let (tooltip, _) = hover("lib.rs").await;
+        assert_eq!(tooltip.as_deref(), Some(path!("/test/lib.rs")));
+    }
+
     mod issues {
         use super::*;
 
@@ -0,0 +1,222 @@
+//! The frameworks, contract templates and databases a project can be
+//! scaffolded with, and what each template puts in the project.
+
+use crate::templates;
+
+/// Frontend framework of the generated app.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Framework {
+    /// React on Vite.
+    #[default]
+    React,
+    /// Next.js with the App Router.
+    Nextjs,
+    /// Vue 3 on Vite.
+    Vue,
+    /// Angular with standalone components.
+    Angular,
+    /// Svelte on Vite.
+    Svelte,
+}
+
+impl Framework {
+    /// Every framework, in the order the wizard offers them.
+    pub fn all() -> [Framework; 5] {
+        [
+            Framework::React,
+            Framework::Nextjs,
+            Framework::Vue,
+            Framework::Angular,
+            Framework::Svelte,
+        ]
+    }
+
+    /// The name shown to users, like "Next.js".
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Framework::React => "React",
+            Framework::Nextjs => "Next.js",
+            Framework::Vue => "Vue",
+            Framework::Angular => "Angular",
+            Framework::Svelte => "Svelte",
+        }
+    }
+
+    /// Where the frontend's services go, relative to the project.
+    pub fn services_dir(&self) -> &'static str {
+        templates::services_dir(*self)
+    }
+
+    /// The template's main UI component, relative to the project.
+    pub fn component_path(&self) -> &'static str {
+        templates::game_component_path(*self)
+    }
+}
+
+/// Database behind the optional state API backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Database {
+    /// A file in the backend's folder; nothing to set up.
+    Sqlite,
+    /// A server at the backend's `DATABASE_URL`.
+    Postgres,
+}
+
+impl Database {
+    /// Every database, in the order the wizard offers them.
+    pub fn all() -> [Database; 2] {
+        [Database::Sqlite, Database::Postgres]
+    }
+
+    /// The name shown to users.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Database::Sqlite => "SQLite",
+            Database::Postgres => "Postgres",
+        }
+    }
+}
+
+/// Runner for the contract tests. Mocha is what sCrypt's own projects use;
+/// the others let the tests share a runner with the frontend.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TestFramework {
+    #[default]
+    Mocha,
+    Vitest,
+    Jest,
+}
+
+impl TestFramework {
+    /// Every runner, the default first.
+    pub fn all() -> [TestFramework; 3] {
+        [
+            TestFramework::Mocha,
+            TestFramework::Vitest,
+            TestFramework::Jest,
+        ]
+    }
+
+    /// The name shown to users.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TestFramework::Mocha => "Mocha",
+            TestFramework::Vitest => "Vitest",
+            TestFramework::Jest => "Jest",
+        }
+    }
+
+    /// What `npm test` runs, after compiling the contracts.
+    pub fn command(&self) -> &'static str {
+        match self {
+            TestFramework::Mocha => "mocha",
+            TestFramework::Vitest => "vitest run",
+            TestFramework::Jest => "jest",
+        }
+    }
+}
+
+/// The contract, tests and UI a project starts from. Each one's
+/// [`Template::descriptor`] lists what it adds to the project.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Template {
+    #[default]
+    HelloWorld,
+    Counter,
+    TicTacToe,
+    Auction,
+    Treasury,
+    RockPaperScissors,
+    Lottery,
+    Prediction,
+    NftMarketplace,
+    Token,
+    StreamingPayments,
+    Marketplace,
+    Playground,
+    /// A placeholder contract for a description, for the assistant to fill in.
+    Custom,
+}
+
+impl Template {
+    /// Every template, in the order the wizard offers them.
+    pub fn all() -> [Template; 14] {
+        [
+            Template::HelloWorld,
+            Template::Counter,
+            Template::TicTacToe,
+            Template::Auction,
+            Template::Treasury,
+            Template::RockPaperScissors,
+            Template::Lottery,
+            Template::Prediction,
+            Template::NftMarketplace,
+            Template::Token,
+            Template::StreamingPayments,
+            Template::Marketplace,
+            Template::Playground,
+            Template::Custom,
+        ]
+    }
+
+    /// The name shown to users, like "Tic-Tac-Toe".
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Template::HelloWorld => "Hello World",
+            Template::Counter => "Counter",
+            Template::TicTacToe => "Tic-Tac-Toe",
+            Template::Auction => "Auction",
+            Template::Treasury => "Treasury",
+            Template::RockPaperScissors => "Rock-Paper-Scissors",
+            Template::Lottery => "Lottery",
+            Template::Prediction => "Prediction",
+            Template::NftMarketplace => "NFT Marketplace",
+            Template::Token => "Token",
+            Template::StreamingPayments => "Streaming Payments",
+            Template::Marketplace => "Marketplace",
+            Template::Playground => "Playground",
+            Template::Custom => "Custom",
+        }
+    }
+
+    /// The files the template adds, without generating them.
+    pub fn descriptor(&self) -> TemplateDescriptor {
+        TemplateDescriptor {
+            template: *self,
+            name: self.display_name(),
+            contract_file: templates::contract_filename(*self),
+            test_file: templates::test_filename(*self),
+            // The file names are the same for every framework
+            services: templates::template_services(*self, Framework::default())
+                .into_iter()
+                .map(|(file, _)| file)
+                .collect(),
+            backend_files: templates::backend_files(*self)
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect(),
+        }
+    }
+}
+
+/// What a template adds to a project beyond the shared scaffold, for
+/// frontends that list templates or open their files afterwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateDescriptor {
+    pub template: Template,
+    /// [`Template::display_name`].
+    pub name: &'static str,
+    /// File name in `contracts/`.
+    pub contract_file: &'static str,
+    /// File name in `tests/`.
+    pub test_file: String,
+    /// File names in the framework's [`Framework::services_dir`].
+    pub services: Vec<&'static str>,
+    /// Companion servers, such as the Prediction oracle, relative to the
+    /// project.
+    pub backend_files: Vec<&'static str>,
+}
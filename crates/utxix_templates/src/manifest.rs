@@ -0,0 +1,667 @@
+//! Every file a project is scaffolded with, for a set of options, before
+//! anything is written.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Branding, Database, Framework, Template, TestFramework, Variables, templates};
+
+/// The choices a project is scaffolded from, as the wizard's steps make them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScaffoldOptions {
+    pub framework: Framework,
+    pub template: Template,
+    /// What to build; fills in the [`Template::Custom`] contract and steers
+    /// the [`agent_prompt`].
+    pub custom_description: Option<String>,
+    /// Adds `PRD.md` with the agent prompt and a starter `tasks.md`.
+    pub generate_docs: bool,
+    /// Adds the UTXO indexer backend and its client.
+    pub include_indexer: bool,
+    /// Adds the client for paying `name@domain` paymail handles.
+    pub include_paymail: bool,
+    /// Adds the HandCash Connect backend and its client.
+    pub include_handcash: bool,
+    /// Adds the state API backend, on this database, and its client.
+    pub state_database: Option<Database>,
+    /// Adds a local regtest node and deploy scripts that can target it.
+    pub include_regtest: bool,
+    /// Adds a GitHub Actions workflow running the contract tests.
+    pub include_ci: bool,
+    /// Adds the generator for deterministic test keys and UTXOs.
+    pub include_fixtures: bool,
+    /// Adds fast-check property tests for the contract.
+    pub include_property_tests: bool,
+    /// Runs the contract tests, and the property tests, with `npm test`.
+    pub test_framework: TestFramework,
+    pub branding: Branding,
+}
+
+/// Where a manifest file's contents come from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FileSource {
+    /// Generated contents.
+    Text(String),
+    /// A file copied as it is, such as the branding logo.
+    Copy(PathBuf),
+}
+
+/// One file of a [`Manifest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestFile {
+    /// Relative to the project folder.
+    pub path: PathBuf,
+    pub source: FileSource,
+}
+
+/// A scaffolded project: its folders and files, relative to the project
+/// folder, in the order they're written.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    /// Folders created even when no file ends up in them.
+    pub directories: Vec<PathBuf>,
+    pub files: Vec<ManifestFile>,
+}
+
+impl Manifest {
+    /// Lists what `options` scaffolds; nothing is written until
+    /// [`Manifest::write`].
+    pub fn new(options: &ScaffoldOptions) -> Self {
+        let framework = options.framework;
+        let template = options.template;
+        let mut manifest = Manifest {
+            directories: ["contracts", "src/components", "src/lib", "src/services"]
+                .into_iter()
+                .map(PathBuf::from)
+                .collect(),
+            files: Vec::new(),
+        };
+
+        manifest.push("README.md", templates::readme(options));
+        manifest.push("AI_RULES.md", templates::ai_rules(framework));
+        manifest.push(
+            Path::new("contracts").join(templates::contract_filename(template)),
+            templates::contract_source(template, options.custom_description.as_deref()),
+        );
+        manifest.push(
+            Path::new("tests").join(templates::test_filename(template)),
+            templates::test_source(template, options.test_framework),
+        );
+
+        // Per-network settings and the `npm run use:<network>` switch
+        manifest.extend("", templates::env_files(template));
+
+        // Runner config and helpers for the contract tests (`npm test`)
+        manifest.extend("", templates::test_support_files(options.test_framework));
+
+        // fast-check property tests fuzzing the contract's methods
+        if options.include_property_tests {
+            manifest.extend(
+                "",
+                templates::property_test_files(template, options.test_framework),
+            );
+        }
+
+        // Generator for the deterministic keys/UTXOs those tests pick up
+        if options.include_fixtures {
+            manifest.extend("", templates::fixture_files());
+        }
+
+        // GitHub Actions workflow running those tests on push
+        if options.include_ci {
+            manifest.extend("", templates::ci_files(options.test_framework));
+        }
+
+        if options.generate_docs {
+            manifest.push(
+                "PRD.md",
+                format!(
+                    "# Project Requirements\n\nPrompt:\n\n{}\n",
+                    agent_prompt(options)
+                ),
+            );
+            manifest.push(
+                "tasks.md",
+                "- [ ] Implement sCrypt covenant contract\n- [ ] Wire wallet connect flow\n- [ ] Build game UI and state management\n- [ ] Add transaction signing and broadcasting\n- [ ] Style with Tailwind (customize as needed)\n- [ ] Test on testnet\n- [ ] Update README with deploy instructions\n".to_string(),
+            );
+        }
+
+        // Frontend files (includes package.json, vite config, components, etc.),
+        // with the app's name, color and logo in place of the placeholders and
+        // the chosen test runner's script and dependencies
+        let frontend_files = templates::with_test_framework(
+            templates::frontend_files(framework, template),
+            options.test_framework,
+        );
+        manifest.extend(
+            "",
+            templates::brand_frontend_files(frontend_files, &options.branding),
+        );
+        if let Some(logo) = &options.branding.logo {
+            manifest.files.push(ManifestFile {
+                path: Path::new("public").join(templates::logo_file_name(logo)),
+                source: FileSource::Copy(logo.clone()),
+            });
+        }
+
+        let services_dir = framework.services_dir();
+        manifest.extend(
+            services_dir,
+            templates::template_services(template, framework),
+        );
+
+        // Deploy script for `npm run deploy`
+        manifest.extend(
+            "",
+            templates::deploy_files(template, options.include_regtest),
+        );
+
+        // Backend files (template companion servers, e.g. the Prediction oracle)
+        manifest.extend("", templates::backend_files(template));
+
+        // Optional UTXO indexer and the frontend client that reads from it
+        if options.include_indexer {
+            manifest.extend("", templates::indexer_files());
+            manifest.extend(services_dir, templates::indexer_services());
+        }
+
+        // Optional client for paying name@domain paymail handles
+        if options.include_paymail {
+            manifest.extend(services_dir, templates::paymail_services());
+        }
+
+        // Optional HandCash Connect backend (OAuth, payments) and its client
+        if options.include_handcash {
+            manifest.extend("", templates::handcash_files());
+            manifest.extend(services_dir, templates::handcash_services());
+        }
+
+        // Optional state API (REST + database) and its client
+        if let Some(database) = options.state_database {
+            manifest.extend("", templates::state_api_files(database));
+            manifest.extend(services_dir, templates::state_api_services());
+        }
+
+        // Optional local regtest node (docker compose) and its funding script
+        if options.include_regtest {
+            manifest.extend("", templates::regtest_files());
+        }
+
+        manifest
+    }
+
+    /// The file at `path`, relative to the project folder.
+    pub fn file(&self, path: impl AsRef<Path>) -> Option<&ManifestFile> {
+        let path = path.as_ref();
+        self.files.iter().find(|file| file.path == path)
+    }
+
+    /// Fills in `variables` in every text file, for tools adding their own
+    /// placeholders to the templates.
+    pub fn apply(&mut self, variables: &Variables) {
+        for file in &mut self.files {
+            if let FileSource::Text(text) = &mut file.source {
+                *text = variables.apply(text);
+            }
+        }
+    }
+
+    /// Writes the project into `project`, creating it if needed. Files that
+    /// already exist are overwritten.
+    pub fn write(&self, project: &Path) -> io::Result<()> {
+        fs::create_dir_all(project)
+            .map_err(|err| with_path(err, "create project folder", project))?;
+        for directory in &self.directories {
+            let path = project.join(directory);
+            fs::create_dir_all(&path).map_err(|err| with_path(err, "create folder", &path))?;
+        }
+        for file in &self.files {
+            let path = project.join(&file.path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|err| with_path(err, "create folder", parent))?;
+            }
+            match &file.source {
+                FileSource::Text(text) => {
+                    fs::write(&path, text).map_err(|err| with_path(err, "write file", &path))?
+                }
+                FileSource::Copy(source) => {
+                    fs::copy(source, &path).map_err(|err| with_path(err, "copy", source))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, path: impl Into<PathBuf>, text: String) {
+        self.files.push(ManifestFile {
+            path: path.into(),
+            source: FileSource::Text(text),
+        });
+    }
+
+    fn extend<P: AsRef<Path>>(&mut self, dir: &str, files: Vec<(P, String)>) {
+        for (path, text) in files {
+            self.push(Path::new(dir).join(path), text);
+        }
+    }
+}
+
+fn with_path(err: io::Error, action: &str, path: &Path) -> io::Error {
+    io::Error::new(err.kind(), format!("{action} {}: {err}", path.display()))
+}
+
+/// The prompt the assistant starts from in the new project: what to build
+/// and the rules generated projects depend on.
+pub fn agent_prompt(options: &ScaffoldOptions) -> String {
+    let framework = options.framework;
+    let template = options.template;
+    let contract_filename = templates::contract_filename(template);
+    let component_path = framework.component_path();
+    let component_step = if template == Template::Custom {
+        format!(
+            "Replace the placeholder UI in {} with one that interacts with the COMPILED contract artifact",
+            component_path
+        )
+    } else {
+        format!(
+            "{} already has a working {} UI wired to the COMPILED contract artifact - extend it rather than starting over",
+            component_path,
+            template.display_name()
+        )
+    };
+
+    let prompt_text = if let Some(desc) = &options.custom_description {
+        format!(
+            "The project scaffold is already created with {} frontend and Yours Wallet integration. \
+            DO NOT run any CLI commands like 'npm create' or 'npx create-vue'. \
+            Instead, EDIT the existing files to implement: {}\n\n\
+            CRITICAL RULES:\n\
+            - NEVER import .scrypt.ts files directly into frontend components\n\
+            - Contracts must be compiled: `npx scrypt-cli compile`\n\
+            - Load compiled artifacts dynamically, not via direct import\n\
+            - When adding npm packages, ALSO update package.json dependencies\n\
+            - Use toRaw() when passing Vue reactive contract instances to SDK methods\n\
+            - Use bindTxBuilder() for custom transaction building with ANYONECANPAY_SINGLE\n\n\
+            Focus on:\n\
+            1. Complete the smart contract logic in contracts/{}\n\
+            2. {}\n\
+            3. Use YoursDirectSigner for settlement transactions\n\n\
+            See AI_RULES.md for full guidelines on tx building, signer patterns, and common pitfalls.",
+            framework.display_name(),
+            desc,
+            contract_filename,
+            component_step
+        )
+    } else {
+        format!(
+            "The project scaffold is already created with {} frontend and Yours Wallet integration. \
+            DO NOT run any CLI commands. \
+            EDIT the existing files to complete the {} implementation.\n\n\
+            CRITICAL RULES:\n\
+            - NEVER import .scrypt.ts files directly into frontend components\n\
+            - Contracts must be compiled: `npx scrypt-cli compile`\n\
+            - Load compiled artifacts dynamically, not via direct import\n\
+            - When adding npm packages, ALSO update package.json dependencies\n\
+            - Use toRaw() when passing Vue reactive contract instances to SDK methods\n\
+            - Use bindTxBuilder() for custom transaction building with ANYONECANPAY_SINGLE\n\n\
+            Focus on:\n\
+            1. Complete the smart contract logic in contracts/{}\n\
+            2. {}\n\n\
+            See AI_RULES.md for full guidelines on tx building, signer patterns, and common pitfalls.",
+            framework.display_name(),
+            template.display_name(),
+            contract_filename,
+            component_step
+        )
+    };
+    let prompt_text = if options.include_handcash {
+        format!(
+            "{}\n\n\
+            HandCash Connect is included too: the handcash/ backend holds the OAuth token, and the \
+            frontend talks to it through handcashService.ts. Add a \"Log in with HandCash\" option \
+            next to the Yours Wallet button (call completeHandCashLogin() on startup) and use \
+            payWithHandCash() for plain payments - contract calls still need Yours Wallet.",
+            prompt_text
+        )
+    } else {
+        prompt_text
+    };
+    let prompt_text = if options.test_framework == TestFramework::Mocha {
+        prompt_text
+    } else {
+        format!(
+            "{}\n\n\
+            The contract tests in tests/ run on {} (`npm test`), not sCrypt's usual mocha: keep \
+            their chai assertions, but use beforeAll() rather than mocha's before() in new tests.",
+            prompt_text,
+            options.test_framework.display_name()
+        )
+    };
+    if let Some(database) = options.state_database {
+        format!(
+            "{}\n\n\
+            A state API is included too: api/server.mjs persists profiles, lobbies and cached \
+            contract state in {} through Sequelize, and the frontend reaches it through \
+            stateApi.ts. Keep off-chain state there (add models to api/server.mjs as needed) - \
+            never treat it as the source of truth for anything a contract enforces.",
+            prompt_text,
+            database.display_name()
+        )
+    } else {
+        prompt_text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, path::Component};
+
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        let options = ScaffoldOptions {
+            framework: Framework::Angular,
+            template: Template::Prediction,
+            state_database: Some(Database::Postgres),
+            generate_docs: true,
+            branding: Branding {
+                logo: Some(PathBuf::from("/tmp/Brand.PNG")),
+                ..Branding::default()
+            },
+            ..ScaffoldOptions::default()
+        };
+        let mut manifest = Manifest::new(&options);
+        let text = |manifest: &Manifest, path: &str| match &manifest.file(path).unwrap().source {
+            FileSource::Text(text) => text.clone(),
+            FileSource::Copy(_) => panic!("{path} is copied"),
+        };
+
+        assert!(
+            manifest
+                .file("contracts/PredictionMarket.scrypt.ts")
+                .is_some()
+        );
+        assert!(manifest.file("oracle/server.mjs").is_some());
+        assert!(manifest.file("src/app/services/stateApi.ts").is_some());
+        assert!(manifest.file("api/docker-compose.yml").is_some());
+        assert!(manifest.file("indexer/server.mjs").is_none());
+        assert_eq!(
+            manifest.file("public/logo.png").unwrap().source,
+            FileSource::Copy(PathBuf::from("/tmp/Brand.PNG"))
+        );
+        assert!(text(&manifest, "PRD.md").contains("A state API is included too"));
+        assert!(text(&manifest, "api/server.mjs").contains("postgres://"));
+        assert!(!text(&manifest, "README.md").contains("__DATABASE_"));
+
+        let descriptor = Template::Prediction.descriptor();
+        assert_eq!(descriptor.contract_file, "PredictionMarket.scrypt.ts");
+        assert!(descriptor.backend_files.contains(&"oracle/server.mjs"));
+
+        manifest.files.push(ManifestFile {
+            path: PathBuf::from("NOTES.md"),
+            source: FileSource::Text("Built by __AUTHOR__ for __APP__".into()),
+        });
+        manifest.apply(&Variables::new().with("AUTHOR", "alice"));
+        assert_eq!(text(&manifest, "NOTES.md"), "Built by alice for __APP__");
+    }
+
+    #[test]
+    fn test_template_files() {
+        for template in Template::all() {
+            let descriptor = template.descriptor();
+            let contract = descriptor.contract_file.trim_end_matches(".scrypt.ts");
+            for framework in Framework::all() {
+                let manifest = Manifest::new(&ScaffoldOptions {
+                    framework,
+                    template,
+                    ..ScaffoldOptions::default()
+                });
+                let case = format!("{template:?} on {framework:?}");
+                let text = |path: &str| match manifest.file(path).map(|file| &file.source) {
+                    Some(FileSource::Text(text)) => text.clone(),
+                    _ => panic!("{case}: no {path}"),
+                };
+
+                assert!(
+                    text(&format!("contracts/{}", descriptor.contract_file))
+                        .contains(&format!("export default {contract};")),
+                    "{case}: contract"
+                );
+                let import = format!("from \"../contracts/{contract}.scrypt\"");
+                assert!(
+                    text(&format!("tests/{}", descriptor.test_file)).contains(&import),
+                    "{case}: test"
+                );
+                assert!(
+                    text("scripts/deploy.ts").contains(&import),
+                    "{case}: deploy script"
+                );
+                for network in ["testnet", "mainnet"] {
+                    assert!(
+                        text(&format!(".env.{network}"))
+                            .contains(&format!("VITE_BSV_NETWORK={network}")),
+                        "{case}: .env.{network}"
+                    );
+                }
+                for service in &descriptor.services {
+                    text(&format!("{}/{service}", framework.services_dir()));
+                }
+                for path in &descriptor.backend_files {
+                    text(path);
+                }
+                if template != Template::Custom {
+                    let custom = Manifest::new(&ScaffoldOptions {
+                        framework,
+                        template: Template::Custom,
+                        ..ScaffoldOptions::default()
+                    });
+                    let component = framework.component_path();
+                    assert_ne!(
+                        manifest.file(component),
+                        custom.file(component),
+                        "{case}: generic component"
+                    );
+                }
+                assert!(
+                    text("AI_RULES.md").contains(framework.display_name()),
+                    "{case}: AI rules"
+                );
+                assert!(
+                    text("README.md").contains(&format!("`{contract}`")),
+                    "{case}: README"
+                );
+            }
+
+            let manifest = Manifest::new(&ScaffoldOptions {
+                template,
+                include_indexer: true,
+                include_regtest: true,
+                include_ci: true,
+                include_fixtures: true,
+                include_property_tests: true,
+                ..ScaffoldOptions::default()
+            });
+            for path in [
+                "indexer/server.mjs".to_string(),
+                "docker-compose.yml".to_string(),
+                "scripts/fund-regtest.ts".to_string(),
+                ".github/workflows/contracts.yml".to_string(),
+                "scripts/fixtures.ts".to_string(),
+                format!("tests/{contract}.property.test.ts"),
+            ] {
+                assert!(manifest.file(&path).is_some(), "{template:?}: no {path}");
+            }
+        }
+
+        let treasury = Manifest::new(&ScaffoldOptions {
+            framework: Framework::Angular,
+            template: Template::Treasury,
+            ..ScaffoldOptions::default()
+        });
+        let text = |path: &str| match &treasury.file(path).unwrap().source {
+            FileSource::Text(text) => text.clone(),
+            FileSource::Copy(_) => panic!("{path} is copied"),
+        };
+        assert!(!text("contracts/Treasury.scrypt.ts").contains("required = 3n"));
+        assert!(
+            text("src/app/services/treasuryService.ts")
+                .contains("from \"../../../contracts/Treasury.scrypt\"")
+        );
+    }
+
+    #[test]
+    fn test_manifest_test_framework() {
+        let options = ScaffoldOptions {
+            framework: Framework::Vue,
+            template: Template::Counter,
+            include_ci: true,
+            include_property_tests: true,
+            test_framework: TestFramework::Vitest,
+            ..ScaffoldOptions::default()
+        };
+        let manifest = Manifest::new(&options);
+        let text = |path: &str| match &manifest.file(path).unwrap().source {
+            FileSource::Text(text) => text.clone(),
+            FileSource::Copy(_) => panic!("{path} is copied"),
+        };
+
+        assert!(manifest.file(".mocharc.json").is_none());
+        assert!(manifest.file("vitest.config.mts").is_some());
+        assert!(text("tsconfig.test.json").contains("\"types\": [\"vitest/globals\", \"node\"]"));
+        let package_json = text("package.json");
+        assert!(package_json.contains("\"test\": \"vitest run\","));
+        assert!(!package_json.contains("mocha"));
+        assert!(package_json.contains(
+            "\"vite\": \"^5.0.0\",\n    \"vitest\": \"^1.6.0\",\n    \"vue-tsc\": \"^1.8.0\"\n  }\n}"
+        ));
+        assert!(text("tests/Counter.test.ts").contains("  beforeAll(async () => {"));
+        assert!(!text("tests/Counter.property.test.ts").contains(" before("));
+        assert!(text(".github/workflows/contracts.yml").contains("run: npx vitest run"));
+
+        let options = ScaffoldOptions {
+            test_framework: TestFramework::Jest,
+            ..options
+        };
+        let manifest = Manifest::new(&options);
+        assert!(manifest.file("jest.config.cjs").is_some());
+        assert!(manifest.file("tests/register.cjs").is_none());
+    }
+
+    /// Relative module specifiers in `source`, from `import`s, `export ... from`s
+    /// and `require` calls.
+    fn relative_imports(source: &str) -> Vec<&str> {
+        source
+            .match_indices(['"', '\''])
+            .filter_map(|(start, quote)| {
+                let specifier = &source[start + 1..];
+                let specifier = &specifier[..specifier.find(quote)?];
+                let before = source[..start].trim_end();
+                let imported = ["from", "import", "import(", "require("]
+                    .iter()
+                    .any(|keyword| before.ends_with(keyword));
+                (imported && (specifier.starts_with("./") || specifier.starts_with("../")))
+                    .then_some(specifier)
+            })
+            .collect()
+    }
+
+    /// `specifier` imported from `file`, relative to the project, or `None` if
+    /// it leaves the project.
+    fn resolve_import(file: &Path, specifier: &str) -> Option<PathBuf> {
+        let mut path = file.parent()?.to_path_buf();
+        for component in specifier.split('/') {
+            match component {
+                "." => {}
+                ".." => {
+                    if !path.pop() {
+                        return None;
+                    }
+                }
+                component => path.push(component),
+            }
+        }
+        Some(path)
+    }
+
+    #[test]
+    fn test_manifest_imports_resolve() {
+        const EXTENSIONS: [&str; 9] = [
+            "", ".ts", ".tsx", ".js", ".mjs", ".cjs", ".vue", ".svelte", ".json",
+        ];
+        const SOURCE_EXTENSIONS: [&str; 7] = ["ts", "tsx", "js", "mjs", "cjs", "vue", "svelte"];
+
+        for template in Template::all() {
+            for framework in Framework::all() {
+                for everything in [false, true] {
+                    let options = ScaffoldOptions {
+                        framework,
+                        template,
+                        custom_description: Some("An escrow for freelance work".into()),
+                        generate_docs: everything,
+                        include_indexer: everything,
+                        include_paymail: everything,
+                        include_handcash: everything,
+                        state_database: everything.then_some(Database::Sqlite),
+                        include_regtest: everything,
+                        include_ci: everything,
+                        include_fixtures: everything,
+                        include_property_tests: everything,
+                        ..ScaffoldOptions::default()
+                    };
+                    let manifest = Manifest::new(&options);
+                    let case = format!("{template:?} on {framework:?} (everything: {everything})");
+
+                    let mut paths = HashSet::new();
+                    for file in &manifest.files {
+                        assert!(
+                            file.path.is_relative()
+                                && file
+                                    .path
+                                    .components()
+                                    .all(|component| matches!(component, Component::Normal(_))),
+                            "{case}: {} isn't inside the project",
+                            file.path.display()
+                        );
+                        assert!(
+                            paths.insert(&file.path),
+                            "{case}: {} is written twice",
+                            file.path.display()
+                        );
+                    }
+
+                    for file in &manifest.files {
+                        let FileSource::Text(source) = &file.source else {
+                            continue;
+                        };
+                        let is_source = file.path.extension().is_some_and(|extension| {
+                            SOURCE_EXTENSIONS.iter().any(|e| extension == *e)
+                        });
+                        if !is_source {
+                            continue;
+                        }
+                        for specifier in relative_imports(source) {
+                            let resolved = resolve_import(&file.path, specifier);
+                            let found = resolved.as_ref().is_some_and(|resolved| {
+                                EXTENSIONS.iter().any(|extension| {
+                                    let mut candidate = resolved.clone().into_os_string();
+                                    candidate.push(extension);
+                                    paths.contains(&PathBuf::from(candidate))
+                                })
+                            });
+                            assert!(
+                                found,
+                                "{case}: {} imports {specifier}, which isn't in the project",
+                                file.path.display()
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
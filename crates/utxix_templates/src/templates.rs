@@ -0,0 +1,59 @@
+mod ai_rules;
+mod angular;
+mod backends;
+mod branding;
+mod contract_tests;
+mod contracts;
+mod deploy;
+mod frontends;
+mod handcash;
+mod indexer;
+mod nextjs;
+mod paymail;
+mod property_tests;
+mod react;
+mod readme;
+mod regtest;
+mod shared;
+mod state_api;
+mod svelte;
+mod vue;
+mod wallet;
+
+pub use self::{
+    ai_rules::ai_rules,
+    backends::backend_files,
+    branding::{
+        Branding, DEFAULT_PRIMARY_COLOR, brand_frontend_files, logo_file_name, parse_color,
+    },
+    contract_tests::{
+        ci_files, fixture_files, property_test_files, test_filename, test_source,
+        test_support_files, with_test_framework,
+    },
+    contracts::{contract_filename, contract_source},
+    deploy::{deploy_files, env_files},
+    frontends::{game_component_path, services_dir, template_services},
+    handcash::{handcash_files, handcash_services},
+    indexer::{indexer_files, indexer_services},
+    paymail::paymail_services,
+    readme::readme,
+    regtest::regtest_files,
+    state_api::{state_api_files, state_api_services},
+};
+
+use crate::{Framework, Template};
+use angular::angular_files;
+use nextjs::nextjs_files;
+use react::react_files;
+use svelte::svelte_files;
+use vue::vue_files;
+
+pub fn frontend_files(framework: Framework, template: Template) -> Vec<(&'static str, String)> {
+    match framework {
+        Framework::React => react_files(template),
+        Framework::Vue => vue_files(template),
+        Framework::Nextjs => nextjs_files(template),
+        Framework::Angular => angular_files(template),
+        Framework::Svelte => svelte_files(template),
+    }
+}
@@ -0,0 +1,333 @@
+//! Rules for the assistant written into the project, per framework.
+
+use crate::Framework;
+
+use super::frontends::{game_component_path, services_dir};
+
+/// `AI_RULES.md` - the shared sCrypt/wallet rules plus the framework's own
+/// integration pitfalls and file layout.
+pub fn ai_rules(framework: Framework) -> String {
+    let framework_rules = match framework {
+        Framework::React => AI_RULES_REACT.to_string(),
+        Framework::Nextjs => format!("{AI_RULES_NEXTJS}\n{AI_RULES_REACT}"),
+        Framework::Vue => AI_RULES_VUE.to_string(),
+        Framework::Angular => AI_RULES_ANGULAR.to_string(),
+        Framework::Svelte => AI_RULES_SVELTE.to_string(),
+    };
+    let services = services_dir(framework);
+    let component = game_component_path(framework);
+    format!(
+        "# AI Rules for Bitcoin App Development ({})\n\n{AI_RULES_CORE}\n{framework_rules}\n{AI_RULES_PITFALLS}\n\
+         ### File Organization\n\
+         - `{component}` - Main UI component (already wired to the contract)\n\
+         - `{services}/` - Contract services (deploy, restore, method calls)\n\
+         - `{services}/yoursWalletDirect.ts` - Custom signer implementation\n\
+         - `contracts/*.scrypt.ts` - Smart contract source\n\
+         - `artifacts/` - Compiled contract artifacts\n\
+         - `tests/` - Contract tests (`npm test`)\n\
+         - `scripts/` - Command-line deploy (`npm run deploy`)\n",
+        framework.display_name()
+    )
+}
+
+const AI_RULES_CORE: &str = r#"## sCrypt Smart Contract Rules
+
+### NEVER Import .scrypt.ts Files Directly
+```typescript
+// ❌ WRONG - This will cause runtime errors
+import Contract from "../contracts/Contract.scrypt.ts";
+import { RockPaperScissors } from "@/contracts/RockPaperScissors.scrypt.ts";
+
+// ✅ CORRECT - Import compiled artifacts or use dynamic loading
+import Contract from "../../contracts/Contract.scrypt";
+// Then load artifact dynamically:
+const artifact = await fetch("/artifacts/contracts/Contract.scrypt.json");
+await Contract.loadArtifact(await artifact.json());
+```
+
+### Contract Compilation Workflow
+1. Write contract code in `contracts/*.scrypt.ts`
+2. Compile with `npx scrypt-cli compile`
+3. This generates `artifacts/*.json`
+4. Load the artifact dynamically before using the contract
+
+## Architecture & Flow
+
+### Commit-Reveal Pattern
+Use commit-reveal for games/auctions where players shouldn't see each other's moves:
+1. Both players commit their move hash: `hash256(nonce + move)`
+2. Contract is deployed with both commitments (ON-CHAIN)
+3. Players share their move+nonce off-chain
+4. Either player calls settle() with all moves+nonces
+5. Contract verifies commitments ON-CHAIN, determines winner, pays out
+
+### Funding Model
+Contract deployer currently funds the full pot. The loser never funds on-chain.
+If you need both players to fund, design a two-party funding flow.
+
+## Transaction Building (CRITICAL)
+
+### Use Custom Transaction Builders
+The SDK's default tx building can conflict with custom sighash/outputs.
+Use `bindTxBuilder()` to control inputs/outputs/change:
+
+```typescript
+// Bind BEFORE calling contract method
+instance.bindTxBuilder('settle', buildSettleTx);
+
+async function buildSettleTx(
+  current: Contract,
+  options: MethodCallOptions<Contract>,
+  ...methodArgs: any[]
+): Promise<ContractTransaction> {
+  const unsignedTx = new bsv.Transaction();
+  
+  // Contract input at index 0
+  unsignedTx.addInput(current.buildContractInput());
+  
+  // Build output 0 - matches contract's expected output
+  const script = Utils.buildPublicKeyHashScript(winnerPkh);
+  unsignedTx.addOutput(new bsv.Transaction.Output({
+    script: bsv.Script.fromHex(script),
+    satoshis: current.balance,
+  }));
+  
+  // Manual fee input (wallet UTXO)
+  const feeUtxos = await getWalletUtxos();
+  unsignedTx.from({
+    txId: feeUtxo.txid,
+    outputIndex: feeUtxo.vout,
+    script: feeUtxo.script,
+    satoshis: feeUtxo.satoshis,
+  });
+  
+  // Fee rate + change
+  unsignedTx.feePerKb(100);
+  unsignedTx.change(options.changeAddress);
+  
+  return { tx: unsignedTx, atInputIndex: 0, nexts: [] };
+}
+```
+
+### ANYONECANPAY_SINGLE Sighash
+When using `SigHash.ANYONECANPAY_SINGLE`:
+- ANYONECANPAY: allows anyone to add inputs (for fees)
+- SINGLE: only verifies output at same index as contract input (index 0)
+- Keep contract UTXO at input 0, verified payout at output 0
+- Fee/change can be at other indices
+
+### Fee Strategy
+- Default relay: ~0.5 sat/byte (~500 sat/KB)
+- Safe choice: `feePerKb(100)` (100 sat/KB)
+- ALWAYS add a change output - without it, providers may merge values
+- Manual fee input gives you control; auto-fund may use minimal fees
+
+## Signer Integration
+
+### Use YoursDirectSigner for Settlement
+The standard PandaSigner can have proxy bugs. Use YoursDirectSigner:
+
+```typescript
+import { getYoursDirectSigner } from "./yoursWalletDirect";
+
+const signer = await getYoursDirectSigner();
+await instance.connect(signer);
+```
+
+### Why YoursDirectSigner?
+- Implements `Signer.getSignatures()` to call wallet's direct API
+- Bypasses proxy object issues
+- Still uses SDK helpers for script building
+
+### Avoid Double Wallet Prompts
+Don't call explicit `wallet.broadcast()` after contract methods.
+Let the provider broadcast after signing - keeps single prompt UX.
+"#;
+
+const AI_RULES_PITFALLS: &str = r#"## Common Pitfalls
+
+### Provider Auto-Funding Issues
+- Can override your fee rate
+- May modify outputs if change isn't specified
+- Can merge fee value into output 0, breaking payout amounts
+
+### Output Mismatch Errors
+When "outputs mismatch" appears:
+1. Check if SDK merged fee value into output 0
+2. Verify payout output hex matches what contract expects
+3. Use `Utils.buildPublicKeyHashOutput()` for exact match
+
+### Only Winner Can Settle
+In winner-takes-all patterns, only the winner should call settle.
+Loser calling settle will fail signature verification.
+
+## Debugging
+
+### Log Transaction Details
+```typescript
+tx.outputs.forEach((out, i) => {
+  console.log(`Output ${i}: ${out.satoshis} sats`);
+});
+```
+
+### Compare Expected vs Actual
+```typescript
+const expected = Utils.buildPublicKeyHashOutput(pkh, value);
+const actual = tx.outputs[0].toBufferWriter().toBuffer().toString('hex');
+console.log("Expected:", expected);
+console.log("Actual:", actual);
+```
+
+## What Works (Proven Patterns)
+
+✅ Custom tx builder + ANYONECANPAY_SINGLE + explicit change
+✅ YoursDirectSigner bypassing proxy errors  
+✅ Manual fee input + feePerKb enforced rate
+✅ Single signing prompt (no extra broadcast)
+
+## What Doesn't Work
+
+❌ Letting provider auto-fund when controlling fee/payout
+❌ Extra wallet.broadcast() (causes second prompt)
+❌ Framework proxies/reactivity wrapped around contract instances
+❌ Assuming SDK default tx building works with custom sighash
+
+## Package Management
+
+### When Adding Imports, Update package.json
+```typescript
+// If you add this import:
+import confetti from "canvas-confetti";
+
+// You MUST also add to package.json:
+// "canvas-confetti": "^1.9.0"
+```
+
+## Project Structure
+
+### Do Not Run Project Creation Commands
+```bash
+# ❌ NEVER run these - the project already exists
+npx create-vue
+npm create vite
+npx create-next-app
+ng new
+
+# ✅ Only edit existing files
+```
+"#;
+
+const AI_RULES_REACT: &str = r#"## React Integration
+
+### Keep Contract Instances in State, Replace - Don't Mutate
+Every stateful method call spends the contract UTXO and produces a NEW
+instance. Store the latest one with `setGame(next)`; never mutate the old
+instance or hold it in a ref that outlives the call.
+
+```typescript
+// ❌ WRONG - stale instance, next call spends an already-spent UTXO
+await makeMove(game, position);
+
+// ✅ CORRECT - continue from the new transaction
+const result = await makeMove(game, position);
+setGame(await restoreGameFromRaw(result.rawTx));
+```
+
+### Hooks
+- Read the wallet with `useWallet()` from `WalletProvider` - don't poll
+  `window.yours` from components
+- Never create signers or call contract methods during render; do it in
+  event handlers or `useEffect`
+- Async handlers capture the state of the render they were created in -
+  use the value an action returns rather than re-reading state after `await`
+- Close sockets/intervals in the `useEffect` cleanup
+- StrictMode runs effects twice in development - effects must be idempotent
+  (never deploy or broadcast from an effect)
+"#;
+
+const AI_RULES_NEXTJS: &str = r#"## Next.js Integration
+
+### Wallet Code Is Client-Only
+Yours Wallet lives on `window`, and contract instances can't be serialized
+across the server/client boundary.
+
+- Any file using the wallet, signers, scrypt-ts contract instances or
+  `src/services/*` must run on the client: keep `"use client"` at the top of
+  `src/app/page.tsx` and `WalletProvider.tsx`, and add it to new entry points
+- Never import `src/services/*` or `contracts/*` from Server Components,
+  `layout.tsx`, route handlers or server actions
+- Touch `window` only inside effects or event handlers - it doesn't exist
+  during server rendering
+- For client-only third-party widgets, use
+  `dynamic(() => import("..."), { ssr: false })`
+- Don't pass contract instances or bigint values as props from a Server
+  Component - pass txids and restore on the client
+"#;
+
+const AI_RULES_VUE: &str = r#"## Vue Integration
+
+### CRITICAL: Use toRaw() for Vue Reactive Objects
+Vue's reactive proxy interferes with scrypt-ts SDK property access.
+Always unwrap before passing to contract methods:
+
+```typescript
+import { toRaw } from "vue";
+
+// ❌ WRONG - proxy breaks contract
+await settleContract(contractInstance.value, params);
+
+// ✅ CORRECT - unwrap the proxy
+const rawInstance = toRaw(contractInstance.value);
+await settleContract(rawInstance, params);
+```
+
+### Hold Contracts in shallowRef
+`ref()` deep-proxies the instance - `this.player1` then returns undefined
+inside the SDK. Use `shallowRef` and replace `.value` with the new instance
+after every call:
+
+```typescript
+const game = shallowRef<any>(null);
+game.value = await restoreGame(txid);
+```
+
+### Wallet State
+Read the wallet with `inject("wallet")` (provided by `App.vue`); close
+sockets/intervals in `onUnmounted`.
+"#;
+
+const AI_RULES_ANGULAR: &str = r#"## Angular Integration
+
+### Standalone Components + Signals
+The scaffold uses standalone components and signals. Hold the contract
+instance in a `signal<any>()` and `set()` the new instance after every call:
+
+```typescript
+game = signal<any>(null);
+
+this.game.set(await restoreGame(txid));
+```
+
+- Never bind contract instances with `[(ngModel)]` or pass them through
+  `@Input()` setters that copy/freeze them - pass txids instead
+- Get the wallet from `WalletService` via `inject(WalletService)`
+- Contract services are plain modules in `src/app/services` - import the
+  functions, don't wrap scrypt-ts in an `@Injectable` that clones instances
+- Implement `OnDestroy` to close sockets/intervals
+- New components must be `standalone: true` and list what they use in
+  `imports`
+"#;
+
+const AI_RULES_SVELTE: &str = r#"## Svelte Integration
+
+### Reassign, Don't Mutate
+Svelte only re-renders on assignment. After every contract call, assign
+the new instance (`game = next`) - mutating fields of the old one neither
+re-renders nor spends the right UTXO.
+
+- Read the wallet through the `$wallet` store (`src/stores/wallet`)
+- Use `$:` reactive statements for values derived from the instance
+- Don't put contract instances in writable stores that other components
+  mutate - share txids and restore
+- Close sockets/intervals in `onDestroy`
+"#;
@@ -0,0 +1,359 @@
+//! The Angular frontend.
+
+use crate::Template;
+
+use super::{
+    contracts::contract_filename,
+    frontends::angular_game_component,
+    shared::{POSTCSS_CONFIG, TAILWIND_CONFIG, TAILWIND_CSS},
+    wallet::{CONFIG_SERVICE, PANDA_SIGNER_SERVICE, YOURS_DIRECT_SIGNER},
+};
+
+pub fn angular_files(template: Template) -> Vec<(&'static str, String)> {
+    let contract_name = contract_filename(template);
+    vec![
+        ("src/main.ts", ANGULAR_MAIN.to_string()),
+        ("src/index.html", ANGULAR_INDEX_HTML.to_string()),
+        ("src/styles.css", TAILWIND_CSS.to_string()),
+        ("src/app/app.component.ts", format!(r#"import {{ Component }} from "@angular/core";
+import {{ CommonModule }} from "@angular/common";
+import {{ WalletService }} from "./services/wallet.service";
+import {{ WalletButtonComponent }} from "./components/wallet-button.component";
+import {{ GameComponent }} from "./components/game.component";
+
+@Component({{
+  selector: "app-root",
+  standalone: true,
+  imports: [CommonModule, WalletButtonComponent, GameComponent],
+  template: `
+    <div class="min-h-screen bg-slate-900 text-slate-100">
+      <header class="border-b border-slate-700 p-4">
+        <div class="max-w-4xl mx-auto flex justify-between items-center">
+          <h1 class="text-2xl font-bold">Bitcoin App</h1>
+          <app-wallet-button></app-wallet-button>
+        </div>
+      </header>
+      <main class="max-w-4xl mx-auto p-4">
+        <app-game [contractName]="'{contract_name}'"></app-game>
+      </main>
+    </div>
+  `,
+}})
+export class AppComponent {{}}
+"#)),
+        ("src/app/services/wallet.service.ts", ANGULAR_WALLET_SERVICE.to_string()),
+        ("src/app/services/pandaSignerService.ts", PANDA_SIGNER_SERVICE.to_string()),
+        ("src/app/services/yoursWalletDirect.ts", YOURS_DIRECT_SIGNER.to_string()),
+        ("src/app/services/config.ts", CONFIG_SERVICE.to_string()),
+        ("src/app/components/wallet-button.component.ts", ANGULAR_WALLET_BUTTON.to_string()),
+        ("src/app/components/game.component.ts", angular_game_component(template).to_string()),
+        ("angular.json", ANGULAR_JSON.to_string()),
+        ("tsconfig.json", ANGULAR_TSCONFIG.to_string()),
+        ("tsconfig.app.json", ANGULAR_TSCONFIG_APP.to_string()),
+        ("tailwind.config.js", TAILWIND_CONFIG.to_string()),
+        ("postcss.config.js", POSTCSS_CONFIG.to_string()),
+        ("package.json", angular_package_json()),
+    ]
+}
+
+const ANGULAR_MAIN: &str = r#"import { bootstrapApplication } from "@angular/platform-browser";
+import { AppComponent } from "./app/app.component";
+
+bootstrapApplication(AppComponent).catch((err) => console.error(err));
+"#;
+
+const ANGULAR_INDEX_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <title>Bitcoin App</title>
+    <base href="/" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+  </head>
+  <body class="bg-slate-900">
+    <app-root></app-root>
+  </body>
+</html>
+"#;
+
+const ANGULAR_WALLET_SERVICE: &str = r#"import { Injectable, signal } from "@angular/core";
+
+export type WalletType = "yours" | null;
+
+export interface WalletState {
+  connected: boolean;
+  address: string | null;
+  balance: number;
+  loading: boolean;
+  error: string | null;
+  walletType: WalletType;
+}
+
+const STORAGE_KEY = "bsv_wallet";
+
+@Injectable({ providedIn: "root" })
+export class WalletService {
+  wallet = signal<WalletState>({
+    connected: false,
+    address: null,
+    balance: 0,
+    loading: false,
+    error: null,
+    walletType: null,
+  });
+
+  constructor() {
+    // Check for stored auth on init
+    const stored = this.getStoredAuth();
+    if (stored) {
+      this.wallet.set({
+        connected: true,
+        address: stored.address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType: stored.walletType,
+      });
+    }
+  }
+
+  async connect() {
+    this.wallet.update(w => ({ ...w, loading: true, error: null }));
+    try {
+      const { getPandaSigner } = await import("../services/pandaSignerService");
+      const signer = await getPandaSigner(true);
+      const addr = await signer.getDefaultAddress();
+      const address = addr.toString();
+      const walletType: WalletType = "yours";
+      localStorage.setItem(STORAGE_KEY, JSON.stringify({ address, walletType }));
+      this.wallet.set({
+        connected: true,
+        address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType,
+      });
+    } catch (err: any) {
+      this.wallet.update(w => ({ 
+        ...w, 
+        loading: false, 
+        error: err?.message || "Failed to connect Yours Wallet" 
+      }));
+    }
+  }
+
+  disconnect() {
+    localStorage.removeItem(STORAGE_KEY);
+    this.wallet.set({
+      connected: false,
+      address: null,
+      balance: 0,
+      loading: false,
+      error: null,
+      walletType: null,
+    });
+  }
+
+  private getStoredAuth(): { address: string; walletType: WalletType } | null {
+    const raw = localStorage.getItem(STORAGE_KEY);
+    if (!raw) return null;
+    try {
+      const parsed = JSON.parse(raw);
+      if (parsed.walletType !== "yours") return null;
+      return parsed;
+    } catch {
+      return null;
+    }
+  }
+}
+"#;
+
+const ANGULAR_WALLET_BUTTON: &str = r#"import { Component, inject } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { WalletService } from "../services/wallet.service";
+
+@Component({
+  selector: "app-wallet-button",
+  standalone: true,
+  imports: [CommonModule],
+  template: `
+    <button *ngIf="walletService.wallet().loading" class="btn" disabled>
+      Connecting...
+    </button>
+    <div *ngIf="walletService.wallet().connected" class="flex items-center gap-2">
+      <span class="text-sm text-slate-400">{{ walletService.wallet().address }}</span>
+      <button (click)="walletService.disconnect()" class="btn btn-secondary">
+        Disconnect
+      </button>
+    </div>
+    <button
+      *ngIf="!walletService.wallet().connected && !walletService.wallet().loading"
+      (click)="walletService.connect()"
+      class="btn btn-primary"
+    >
+      Connect Yours Wallet
+    </button>
+  `,
+})
+export class WalletButtonComponent {
+  walletService = inject(WalletService);
+}
+"#;
+
+pub(super) const ANGULAR_GAME_COMPONENT: &str = r#"import { Component, Input, inject } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Game</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+      </div>
+      <div *ngIf="walletService.wallet().connected" class="bg-slate-800 rounded-lg p-6">
+        <p class="text-slate-400 mb-4">Contract: {{ contractName }}</p>
+        <!-- TODO: Implement game UI here -->
+        <p class="text-center text-slate-500">Game interface will be implemented here</p>
+      </div>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+}
+"#;
+
+const ANGULAR_JSON: &str = r#"{
+  "$schema": "./node_modules/@angular/cli/lib/config/schema.json",
+  "version": 1,
+  "newProjectRoot": "projects",
+  "projects": {
+    "bitcoin-app": {
+      "projectType": "application",
+      "root": "",
+      "sourceRoot": "src",
+      "prefix": "app",
+      "architect": {
+        "build": {
+          "builder": "@angular-devkit/build-angular:application",
+          "options": {
+            "outputPath": "dist/bitcoin-app",
+            "index": "src/index.html",
+            "browser": "src/main.ts",
+            "tsConfig": "tsconfig.app.json",
+            "styles": ["src/styles.css"],
+            "scripts": []
+          }
+        },
+        "serve": {
+          "builder": "@angular-devkit/build-angular:dev-server",
+          "options": {
+            "buildTarget": "bitcoin-app:build"
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+const ANGULAR_TSCONFIG: &str = r#"{
+  "compileOnSave": false,
+  "compilerOptions": {
+    "outDir": "./dist/out-tsc",
+    "strict": true,
+    "noImplicitOverride": true,
+    "noPropertyAccessFromIndexSignature": true,
+    "noImplicitReturns": true,
+    "noFallthroughCasesInSwitch": true,
+    "skipLibCheck": true,
+    "esModuleInterop": true,
+    "sourceMap": true,
+    "declaration": false,
+    "experimentalDecorators": true,
+    "moduleResolution": "bundler",
+    "importHelpers": true,
+    "target": "ES2022",
+    "module": "ES2022",
+    "lib": ["ES2022", "dom"]
+  }
+}
+"#;
+
+const ANGULAR_TSCONFIG_APP: &str = r#"{
+  "extends": "./tsconfig.json",
+  "compilerOptions": {
+    "outDir": "./out-tsc/app",
+    "types": []
+  },
+  "files": ["src/main.ts"],
+  "include": ["src/**/*.d.ts"]
+}
+"#;
+
+fn angular_package_json() -> String {
+    r#"{
+  "name": "bitcoin-app",
+  "version": "0.1.0",
+  "scripts": {
+    "ng": "ng",
+    "start": "ng serve",
+    "dev": "ng serve",
+    "build": "ng build",
+    "pretest": "npx scrypt-cli compile",
+    "test": "mocha",
+    "deploy": "npx scrypt-cli compile && ts-node --project tsconfig.test.json scripts/deploy.ts",
+    "use:testnet": "node scripts/use-network.mjs testnet",
+    "use:mainnet": "node scripts/use-network.mjs mainnet"
+  },
+  "dependencies": {
+    "@angular/common": "^17.0.0",
+    "@angular/compiler": "^17.0.0",
+    "@angular/core": "^17.0.0",
+    "@angular/forms": "^17.0.0",
+    "@angular/platform-browser": "^17.0.0",
+    "@angular/platform-browser-dynamic": "^17.0.0",
+    "rxjs": "~7.8.0",
+    "tslib": "^2.3.0",
+    "zone.js": "~0.14.0",
+    "scrypt-ts": "^1.3.0",
+    "scrypt-ts-lib": "^0.1.28",
+    "scrypt-ord": "^1.0.0"
+  },
+  "devDependencies": {
+    "@angular-devkit/build-angular": "^17.0.0",
+    "@angular/cli": "^17.0.0",
+    "@angular/compiler-cli": "^17.0.0",
+    "@types/chai": "^4.3.0",
+    "@types/chai-as-promised": "^7.1.0",
+    "@types/mocha": "^10.0.0",
+    "@types/node": "^20.0.0",
+    "autoprefixer": "^10.4.0",
+    "chai": "^4.3.0",
+    "chai-as-promised": "^7.1.0",
+    "dotenv": "^16.3.0",
+    "fast-check": "^3.15.0",
+    "mocha": "^10.2.0",
+    "postcss": "^8.4.0",
+    "rabinsig": "^1.0.0",
+    "tailwindcss": "^3.4.0",
+    "ts-node": "^10.9.0",
+    "typescript": "~5.2.0"
+  }
+}
+"#.to_string()
+}
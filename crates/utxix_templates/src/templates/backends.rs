@@ -0,0 +1,1144 @@
+//! Companion servers some templates ship: the Prediction oracle, the
+//! Streaming Payments relay, the Tic-Tac-Toe and Rock-Paper-Scissors
+//! multiplayer server and the Playground's compile server.
+
+use crate::Template;
+
+pub fn backend_files(template: Template) -> Vec<(&'static str, String)> {
+    // No backend needed for the wallet - Yours Wallet is a browser extension.
+    // Some templates ship a small companion server.
+    match template {
+        Template::Prediction => vec![
+            ("oracle/package.json", ORACLE_PACKAGE_JSON.to_string()),
+            ("oracle/server.mjs", ORACLE_SERVER.to_string()),
+            // The data file holds the oracle's private key
+            ("oracle/.gitignore", "node_modules/\noracle-data.json\n".to_string()),
+        ],
+        Template::StreamingPayments => vec![
+            ("relay/package.json", RELAY_PACKAGE_JSON.to_string()),
+            ("relay/server.mjs", RELAY_SERVER.to_string()),
+        ],
+        Template::TicTacToe | Template::RockPaperScissors => vec![
+            ("multiplayer/package.json", MULTIPLAYER_PACKAGE_JSON.to_string()),
+            ("multiplayer/server.mjs", MULTIPLAYER_SERVER.to_string()),
+        ],
+        Template::Playground => vec![
+            ("playground/package.json", PLAYGROUND_PACKAGE_JSON.to_string()),
+            ("playground/server.mjs", PLAYGROUND_SERVER.to_string()),
+            ("playground/.gitignore", "node_modules/\nsnippets/\n".to_string()),
+        ],
+        _ => vec![],
+    }
+}
+
+// ORACLE SERVER (Prediction template)
+// ============================================================================
+
+const ORACLE_PACKAGE_JSON: &str = r#"{
+  "name": "bitcoin-app-oracle",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "start": "node server.mjs"
+  },
+  "dependencies": {
+    "rabinsig": "^1.0.0"
+  }
+}
+"#;
+
+const ORACLE_SERVER: &str = r#"/**
+ * Oracle server stub - registers yes/no questions and Rabin-signs outcomes
+ *
+ * The PredictionMarket contract accepts a 33-byte message:
+ *   eventId (32 bytes) + outcome (01 = yes, 00 = no)
+ * signed with this oracle's Rabin key.
+ *
+ * Run:  cd oracle && npm install && ORACLE_ADMIN_TOKEN=secret npm start
+ *
+ * Endpoints:
+ *   GET  /pubkey              → { pubKey }               (decimal string)
+ *   POST /events              { question } → event
+ *   GET  /events/:id          → event (with attestation once resolved)
+ *   POST /events/:id/resolve  { outcome: "yes" | "no" }  (Bearer ORACLE_ADMIN_TOKEN)
+ *
+ * This is a stub: outcomes are set by hand. A real oracle resolves events
+ * from a data feed and must never sign two outcomes for the same event.
+ */
+
+import { createServer } from "node:http";
+import { randomBytes } from "node:crypto";
+import { existsSync, readFileSync, writeFileSync } from "node:fs";
+import { Rabin } from "rabinsig";
+
+const PORT = Number(process.env.PORT || 3001);
+const ADMIN_TOKEN = process.env.ORACLE_ADMIN_TOKEN || "";
+const DATA_FILE = new URL("./oracle-data.json", import.meta.url);
+
+const rabin = new Rabin();
+
+// ============================================================================
+// STATE (key + events persisted to oracle-data.json - keep it private!)
+// ============================================================================
+
+function loadData() {
+  if (existsSync(DATA_FILE)) {
+    const data = JSON.parse(readFileSync(DATA_FILE, "utf8"));
+    return {
+      privKey: { p: BigInt(data.privKey.p), q: BigInt(data.privKey.q) },
+      events: data.events,
+    };
+  }
+  console.log("Generating a new oracle key in oracle-data.json");
+  return { privKey: rabin.generatePrivKey(), events: {} };
+}
+
+function saveData() {
+  const { p, q } = data.privKey;
+  writeFileSync(
+    DATA_FILE,
+    JSON.stringify({ privKey: { p: p.toString(), q: q.toString() }, events: data.events }, null, 2),
+  );
+}
+
+const data = loadData();
+saveData();
+const pubKey = rabin.privKeyToPubKey(data.privKey);
+
+// ============================================================================
+// ATTESTATION
+// ============================================================================
+
+function attest(eventId, outcome) {
+  const message = eventId + (outcome === "yes" ? "01" : "00");
+  const { signature, paddingByteCount } = rabin.sign(message, data.privKey);
+  return {
+    message,
+    s: signature.toString(),
+    padding: "00".repeat(paddingByteCount),
+  };
+}
+
+// ============================================================================
+// HTTP
+// ============================================================================
+
+function send(res, status, body) {
+  res.writeHead(status, {
+    "Content-Type": "application/json",
+    "Access-Control-Allow-Origin": "*",
+    "Access-Control-Allow-Headers": "Content-Type, Authorization",
+    "Access-Control-Allow-Methods": "GET, POST, OPTIONS",
+  });
+  res.end(body === undefined ? "" : JSON.stringify(body));
+}
+
+async function readJson(req) {
+  let raw = "";
+  for await (const chunk of req) raw += chunk;
+  return raw ? JSON.parse(raw) : {};
+}
+
+const server = createServer(async (req, res) => {
+  try {
+    if (req.method === "OPTIONS") return send(res, 204);
+
+    const url = new URL(req.url, "http://localhost");
+    const parts = url.pathname.split("/").filter(Boolean);
+
+    if (req.method === "GET" && url.pathname === "/pubkey") {
+      return send(res, 200, { pubKey: pubKey.toString() });
+    }
+
+    if (req.method === "POST" && url.pathname === "/events") {
+      const { question } = await readJson(req);
+      if (!question || typeof question !== "string") {
+        return send(res, 400, { error: "question is required" });
+      }
+      const eventId = randomBytes(32).toString("hex");
+      data.events[eventId] = { eventId, question, outcome: null, attestation: null };
+      saveData();
+      return send(res, 201, data.events[eventId]);
+    }
+
+    if (parts[0] === "events" && parts[1]) {
+      const event = data.events[parts[1]];
+      if (!event) return send(res, 404, { error: "unknown event" });
+
+      if (req.method === "GET" && parts.length === 2) {
+        return send(res, 200, event);
+      }
+
+      if (req.method === "POST" && parts[2] === "resolve") {
+        if (!ADMIN_TOKEN || req.headers.authorization !== "Bearer " + ADMIN_TOKEN) {
+          return send(res, 401, { error: "unauthorized" });
+        }
+        // Never sign a second outcome for the same event
+        if (event.outcome) return send(res, 409, { error: "already resolved" });
+
+        const { outcome } = await readJson(req);
+        if (outcome !== "yes" && outcome !== "no") {
+          return send(res, 400, { error: "outcome must be yes or no" });
+        }
+        event.outcome = outcome;
+        event.attestation = attest(event.eventId, outcome);
+        saveData();
+        return send(res, 200, event);
+      }
+    }
+
+    send(res, 404, { error: "not found" });
+  } catch (err) {
+    send(res, 500, { error: String(err?.message || err) });
+  }
+});
+
+server.listen(PORT, () => {
+  console.log("Oracle listening on http://localhost:" + PORT);
+  if (!ADMIN_TOKEN) {
+    console.log("ORACLE_ADMIN_TOKEN is not set - events cannot be resolved");
+  }
+});
+"#;
+
+// ============================================================================
+// RELAY SERVER (Streaming Payments template)
+// ============================================================================
+
+const RELAY_PACKAGE_JSON: &str = r#"{
+  "name": "bitcoin-app-relay",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "start": "node server.mjs"
+  }
+}
+"#;
+
+const RELAY_SERVER: &str = r#"/**
+ * Payment channel relay - forwards signed off-chain updates
+ *
+ * The sender posts each signed close transaction (amount + signature);
+ * the receiver polls for the latest one. The relay is untrusted: the
+ * receiver verifies every signature before accepting an update, so the
+ * worst a malicious relay can do is withhold updates.
+ *
+ * Run:  cd relay && npm start
+ *
+ * Endpoints:
+ *   POST /channels/:txid/updates         { amount, senderSig }
+ *   GET  /channels/:txid/updates/latest  → { amount, senderSig, receivedAt }
+ *
+ * Updates are kept in memory; restart the relay and the receiver keeps
+ * whatever it last fetched.
+ */
+
+import { createServer } from "node:http";
+
+const PORT = Number(process.env.PORT || 3002);
+
+// channel txid -> latest update
+const latest = new Map();
+
+function send(res, status, body) {
+  res.writeHead(status, {
+    "Content-Type": "application/json",
+    "Access-Control-Allow-Origin": "*",
+    "Access-Control-Allow-Headers": "Content-Type",
+    "Access-Control-Allow-Methods": "GET, POST, OPTIONS",
+  });
+  res.end(body === undefined ? "" : JSON.stringify(body));
+}
+
+async function readJson(req) {
+  let raw = "";
+  for await (const chunk of req) raw += chunk;
+  return raw ? JSON.parse(raw) : {};
+}
+
+const server = createServer(async (req, res) => {
+  try {
+    if (req.method === "OPTIONS") return send(res, 204);
+
+    const [, channels, txid, updates, which] = new URL(req.url, "http://localhost").pathname.split("/");
+    if (channels !== "channels" || !/^[0-9a-f]{64}$/.test(txid || "") || updates !== "updates") {
+      return send(res, 404, { error: "not found" });
+    }
+
+    if (req.method === "POST" && !which) {
+      const { amount, senderSig } = await readJson(req);
+      if (!Number.isSafeInteger(amount) || amount <= 0 || typeof senderSig !== "string") {
+        return send(res, 400, { error: "amount and senderSig are required" });
+      }
+      // A channel only ever pays more; ignore stale or replayed updates
+      const current = latest.get(txid);
+      if (current && amount <= current.amount) {
+        return send(res, 409, { error: "amount must increase", latest: current.amount });
+      }
+      latest.set(txid, { amount, senderSig, receivedAt: Date.now() });
+      return send(res, 201, latest.get(txid));
+    }
+
+    if (req.method === "GET" && which === "latest") {
+      const update = latest.get(txid);
+      return update ? send(res, 200, update) : send(res, 404, { error: "no updates yet" });
+    }
+
+    send(res, 404, { error: "not found" });
+  } catch (err) {
+    send(res, 500, { error: String(err?.message || err) });
+  }
+});
+
+server.listen(PORT, () => {
+  console.log("Channel relay listening on http://localhost:" + PORT);
+});
+"#;
+
+// ============================================================================
+// MULTIPLAYER SERVER (Tic-Tac-Toe, Rock-Paper-Scissors)
+// ============================================================================
+
+const MULTIPLAYER_PACKAGE_JSON: &str = r#"{
+  "name": "bitcoin-app-multiplayer",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "engines": {
+    "node": ">=18"
+  },
+  "scripts": {
+    "start": "node server.mjs"
+  },
+  "dependencies": {
+    "ws": "^8.16.0"
+  }
+}
+"#;
+
+const MULTIPLAYER_SERVER: &str = r#"/**
+ * Multiplayer server - lobbies, matchmaking and turn relay over WebSocket
+ *
+ * Pairs two players - through a lobby code one of them shares, or by queueing
+ * for the same game at the same stake - then relays each turn - the txid plus
+ * the signed (or partially signed) raw transaction - to the other player as
+ * soon as it is made, instead of both browsers polling an explorer.
+ *
+ * The server never holds keys or funds, and the contract checks every
+ * transaction once it is broadcast. Relayed transactions are checked before
+ * they reach the opponent too: the raw tx must hash to the txid and carry a
+ * valid signature by the sender's key, so nobody can inject turns for a
+ * player they aren't. The worst the server can do is withhold turns, which
+ * the contracts' timeouts already cover.
+ *
+ * Run:  cd multiplayer && npm install && npm start
+ * The other machine sets VITE_MULTIPLAYER_URL=ws://<this machine's IP>:3004.
+ *
+ * Client → server:
+ *   { type: "create-lobby", game, pubKey, stake }   open a lobby, get its code
+ *   { type: "list-lobbies", game }                  open lobbies for a game
+ *   { type: "join-lobby", code, pubKey }            join one by code
+ *   { type: "queue", game, pubKey, stake }          wait for any opponent
+ *   { type: "start", matchId, gameTxid }            creator deployed the game
+ *   { type: "join", room, pubKey? }                 follow a game (room = deploy txid)
+ *   { type: "turn", room, txid, rawTx?, note? }
+ *
+ * Server → client:
+ *   { type: "lobby", code, game, stake }
+ *   { type: "lobbies", game, lobbies: [{ code, stake, createdAt }] }
+ *   { type: "matched", matchId, role: "creator" | "joiner", opponent }
+ *   { type: "start", matchId, gameTxid }
+ *   { type: "turn", room, txid, rawTx?, note? }   latest turn is replayed on join
+ *   { type: "opponent-left", room? }
+ *   { type: "error", error }
+ *
+ * Env: PORT (3004), BSV_NETWORK (testnet | mainnet) - where the inputs of
+ *      relayed transactions are looked up
+ *
+ * State is kept in memory; clients can always resume a game from its txid.
+ */
+
+import { createHash, createPublicKey, randomBytes, randomUUID, verify } from "node:crypto";
+import { WebSocketServer } from "ws";
+
+const PORT = Number(process.env.PORT || 3004);
+const NETWORK = process.env.BSV_NETWORK || "testnet";
+const WOC_URL = "https://api.whatsonchain.com/v1/bsv/" + (NETWORK === "mainnet" ? "main" : "test");
+const GAMES = new Set(["tictactoe", "rps"]);
+const PUBKEY = /^0[23][0-9a-f]{64}$/;
+const TXID = /^[0-9a-f]{64}$/;
+
+// "<game>:<stake>" -> socket waiting for an opponent
+const queue = new Map();
+// lobby code -> { socket, game, stake, createdAt }
+const lobbies = new Map();
+// matchId -> { creator, joiner }
+const matches = new Map();
+// deploy txid -> { sockets: Set, latest: turn | null }
+const rooms = new Map();
+
+function send(socket, message) {
+  if (socket.readyState === socket.OPEN) {
+    socket.send(JSON.stringify(message));
+  }
+}
+
+function roomFor(id) {
+  if (!rooms.has(id)) rooms.set(id, { sockets: new Set(), latest: null });
+  return rooms.get(id);
+}
+
+function validPlayer(socket, { game, pubKey, stake }) {
+  if (!GAMES.has(game) || !PUBKEY.test(pubKey || "") || !Number.isSafeInteger(stake) || stake <= 0) {
+    send(socket, { type: "error", error: "game, pubKey and a positive stake are required" });
+    return false;
+  }
+  socket.pubKey = pubKey;
+  return true;
+}
+
+// The creator deploys (and funds) the game
+function pair(creator, joiner) {
+  const matchId = randomUUID();
+  matches.set(matchId, { creator, joiner });
+  creator.matchId = joiner.matchId = matchId;
+  send(creator, { type: "matched", matchId, role: "creator", opponent: joiner.pubKey });
+  send(joiner, { type: "matched", matchId, role: "joiner", opponent: creator.pubKey });
+}
+
+// ============================================================================
+// LOBBIES AND MATCHMAKING
+// ============================================================================
+
+function handleCreateLobby(socket, message) {
+  if (!validPlayer(socket, message)) return;
+  // Unambiguous characters, easy to read out
+  const alphabet = "ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+  let code;
+  do {
+    code = Array.from(randomBytes(6), (byte) => alphabet[byte % alphabet.length]).join("");
+  } while (lobbies.has(code));
+  lobbies.set(code, { socket, game: message.game, stake: message.stake, createdAt: Date.now() });
+  (socket.lobbies ??= new Set()).add(code);
+  send(socket, { type: "lobby", code, game: message.game, stake: message.stake });
+}
+
+function handleListLobbies(socket, { game }) {
+  const open = [...lobbies.entries()]
+    .filter(([, lobby]) => lobby.game === game && lobby.socket.readyState === lobby.socket.OPEN)
+    .map(([code, lobby]) => ({ code, stake: lobby.stake, createdAt: lobby.createdAt }));
+  send(socket, { type: "lobbies", game, lobbies: open });
+}
+
+function handleJoinLobby(socket, { code, pubKey }) {
+  const lobby = lobbies.get(String(code || "").toUpperCase());
+  if (!lobby || lobby.socket.readyState !== lobby.socket.OPEN) {
+    return send(socket, { type: "error", error: "no open lobby " + code });
+  }
+  if (!PUBKEY.test(pubKey || "") || pubKey === lobby.socket.pubKey) {
+    return send(socket, { type: "error", error: "join the lobby with your own pubKey" });
+  }
+  socket.pubKey = pubKey;
+  lobbies.delete(code.toUpperCase());
+  pair(lobby.socket, socket);
+}
+
+function handleQueue(socket, message) {
+  if (!validPlayer(socket, message)) return;
+  const key = message.game + ":" + message.stake;
+  const waiting = queue.get(key);
+  if (!waiting || waiting === socket || waiting.readyState !== waiting.OPEN || waiting.pubKey === socket.pubKey) {
+    queue.set(key, socket);
+    socket.queueKey = key;
+    return;
+  }
+  // First in the queue creates the game
+  queue.delete(key);
+  pair(waiting, socket);
+}
+
+function handleStart(socket, { matchId, gameTxid }) {
+  const match = matches.get(matchId);
+  if (!match || match.creator !== socket || !TXID.test(gameTxid || "")) {
+    return send(socket, { type: "error", error: "unknown match" });
+  }
+  matches.delete(matchId);
+  send(match.joiner, { type: "start", matchId, gameTxid });
+}
+
+// ============================================================================
+// TURNS
+// ============================================================================
+
+function handleJoin(socket, { room, pubKey }) {
+  if (!TXID.test(room || "")) {
+    return send(socket, { type: "error", error: "room must be the game's deploy txid" });
+  }
+  if (PUBKEY.test(pubKey || "")) socket.pubKey = pubKey;
+  const state = roomFor(room);
+  state.sockets.add(socket);
+  (socket.rooms ??= new Set()).add(room);
+  if (state.latest) send(socket, state.latest);
+}
+
+async function handleTurn(socket, { room, txid, rawTx, note }) {
+  const state = rooms.get(room);
+  if (!state?.sockets.has(socket) || !TXID.test(txid || "")) {
+    return send(socket, { type: "error", error: "join the room before sending turns" });
+  }
+  if (rawTx !== undefined) {
+    const problem = await checkTurn(socket.pubKey, txid, rawTx).catch((err) => String(err?.message || err));
+    if (problem) {
+      return send(socket, { type: "error", error: "turn " + txid + " rejected: " + problem });
+    }
+  }
+  state.latest = { type: "turn", room, txid, rawTx, note };
+  for (const peer of state.sockets) {
+    if (peer !== socket) send(peer, state.latest);
+  }
+}
+
+// ============================================================================
+// TRANSACTION CHECKS
+// ============================================================================
+
+const SIGHASH_NONE = 0x02;
+const SIGHASH_SINGLE = 0x03;
+const SIGHASH_FORKID = 0x40;
+const SIGHASH_ANYONECANPAY = 0x80;
+// SubjectPublicKeyInfo DER header for a compressed secp256k1 public key
+const SPKI_PREFIX = Buffer.from("3036301006072a8648ce3d020106052b8104000a032200", "hex");
+
+const sha256 = (data) => createHash("sha256").update(data).digest();
+const hash256 = (data) => sha256(sha256(data));
+
+/**
+ * Why a relayed transaction shouldn't reach the opponent, or null when it's
+ * fine: it must be the transaction `txid` names, and at least one of its
+ * signatures must be the sender's. Other signatures - the opponent's, or the
+ * fixed key sCrypt signs sighash preimages with - are left to the contract.
+ */
+async function checkTurn(pubKey, txid, rawTx) {
+  if (typeof rawTx !== "string" || !/^([0-9a-f]{2})+$/i.test(rawTx)) {
+    return "rawTx must be hex";
+  }
+  if (!pubKey) {
+    return "send your pubKey when joining the room to relay transactions";
+  }
+  const raw = Buffer.from(rawTx, "hex");
+  if (Buffer.from(hash256(raw)).reverse().toString("hex") !== txid) {
+    return "rawTx doesn't hash to the txid";
+  }
+  const tx = parseTx(raw);
+  const key = createPublicKey({
+    key: Buffer.concat([SPKI_PREFIX, Buffer.from(pubKey, "hex")]),
+    format: "der",
+    type: "spki",
+  });
+  let unchecked = null;
+  for (let index = 0; index < tx.inputs.length; index++) {
+    const signatures = pushes(tx.inputs[index].script).filter(isSignature);
+    if (signatures.length === 0) continue;
+    let spent;
+    try {
+      spent = await sourceOutput(tx.inputs[index].outpoint);
+    } catch (err) {
+      unchecked = err.message;
+      continue;
+    }
+    for (const signature of signatures) {
+      const preimage = sighashPreimage(tx, index, spent, signature[signature.length - 1]);
+      // verify() hashes once more, giving the double SHA-256 Bitcoin signs
+      if (verify("sha256", sha256(preimage), key, signature.subarray(0, -1))) {
+        // The next turn spends this one, likely before explorers index it
+        rememberTx(txid, tx);
+        return null;
+      }
+    }
+  }
+  return unchecked || "no input is signed by the sender's key";
+}
+
+function parseTx(raw) {
+  let pos = 0;
+  const bytes = (n) => {
+    if (pos + n > raw.length) throw new Error("truncated transaction");
+    pos += n;
+    return raw.subarray(pos - n, pos);
+  };
+  const varint = () => {
+    const first = bytes(1)[0];
+    if (first < 0xfd) return first;
+    if (first === 0xfd) return bytes(2).readUInt16LE(0);
+    if (first === 0xfe) return bytes(4).readUInt32LE(0);
+    return Number(bytes(8).readBigUInt64LE(0));
+  };
+  const version = bytes(4);
+  const inputs = Array.from({ length: varint() }, () => ({
+    outpoint: bytes(36),
+    script: bytes(varint()),
+    sequence: bytes(4),
+  }));
+  const outputs = Array.from({ length: varint() }, () => {
+    const start = pos;
+    const value = bytes(8);
+    const script = bytes(varint());
+    return { value, script, raw: raw.subarray(start, pos) };
+  });
+  const lockTime = bytes(4);
+  if (pos !== raw.length) throw new Error("trailing bytes after the transaction");
+  return { version, inputs, outputs, lockTime };
+}
+
+/** The data pushed by a script, in order */
+function pushes(script) {
+  const data = [];
+  for (let i = 0; i < script.length; ) {
+    const op = script[i++];
+    let length;
+    if (op >= 0x01 && op <= 0x4b) {
+      length = op;
+    } else if (op === 0x4c) {
+      length = script[i];
+      i += 1;
+    } else if (op === 0x4d) {
+      length = script.readUInt16LE(i);
+      i += 2;
+    } else if (op === 0x4e) {
+      length = script.readUInt32LE(i);
+      i += 4;
+    } else {
+      continue;
+    }
+    data.push(script.subarray(i, i + length));
+    i += length;
+  }
+  return data;
+}
+
+/** A DER signature followed by a FORKID sighash byte */
+function isSignature(push) {
+  return (
+    push.length >= 9 &&
+    push.length <= 73 &&
+    push[0] === 0x30 &&
+    push[1] === push.length - 3 &&
+    (push[push.length - 1] & SIGHASH_FORKID) !== 0
+  );
+}
+
+// Relayed transactions and the sources of checked inputs, by txid
+const sourceTxs = new Map();
+
+function rememberTx(txid, tx) {
+  if (sourceTxs.size >= 1000) sourceTxs.clear();
+  sourceTxs.set(txid, tx);
+}
+
+async function sourceOutput(outpoint) {
+  const txid = Buffer.from(outpoint.subarray(0, 32)).reverse().toString("hex");
+  const vout = outpoint.readUInt32LE(32);
+  if (!sourceTxs.has(txid)) {
+    const res = await fetch(WOC_URL + "/tx/" + txid + "/hex");
+    if (!res.ok) throw new Error("can't load input " + txid + " (HTTP " + res.status + ")");
+    rememberTx(txid, parseTx(Buffer.from((await res.text()).trim(), "hex")));
+  }
+  const output = sourceTxs.get(txid).outputs[vout];
+  if (!output) throw new Error(txid + " has no output " + vout);
+  return output;
+}
+
+/** BIP143-style preimage with FORKID, as BSV signs it */
+function sighashPreimage(tx, index, spent, type) {
+  const base = type & 0x1f;
+  const anyoneCanPay = (type & SIGHASH_ANYONECANPAY) !== 0;
+  const zero = Buffer.alloc(32);
+  const hashPrevouts = anyoneCanPay ? zero : hash256(Buffer.concat(tx.inputs.map((input) => input.outpoint)));
+  const hashSequence =
+    anyoneCanPay || base === SIGHASH_SINGLE || base === SIGHASH_NONE
+      ? zero
+      : hash256(Buffer.concat(tx.inputs.map((input) => input.sequence)));
+  let hashOutputs = zero;
+  if (base !== SIGHASH_SINGLE && base !== SIGHASH_NONE) {
+    hashOutputs = hash256(Buffer.concat(tx.outputs.map((output) => output.raw)));
+  } else if (base === SIGHASH_SINGLE && index < tx.outputs.length) {
+    hashOutputs = hash256(tx.outputs[index].raw);
+  }
+  const scriptLength = Buffer.alloc(9);
+  const scriptLengthSize = writeVarint(scriptLength, spent.script.length);
+  const sighashType = Buffer.alloc(4);
+  sighashType.writeUInt32LE(type);
+  return Buffer.concat([
+    tx.version,
+    hashPrevouts,
+    hashSequence,
+    tx.inputs[index].outpoint,
+    scriptLength.subarray(0, scriptLengthSize),
+    spent.script,
+    spent.value,
+    tx.inputs[index].sequence,
+    hashOutputs,
+    tx.lockTime,
+    sighashType,
+  ]);
+}
+
+function writeVarint(buffer, n) {
+  if (n < 0xfd) {
+    buffer[0] = n;
+    return 1;
+  }
+  if (n <= 0xffff) {
+    buffer[0] = 0xfd;
+    buffer.writeUInt16LE(n, 1);
+    return 3;
+  }
+  buffer[0] = 0xfe;
+  buffer.writeUInt32LE(n, 1);
+  return 5;
+}
+
+// ============================================================================
+// CONNECTIONS
+// ============================================================================
+
+function handleClose(socket) {
+  if (socket.queueKey && queue.get(socket.queueKey) === socket) {
+    queue.delete(socket.queueKey);
+  }
+  for (const code of socket.lobbies ?? []) {
+    if (lobbies.get(code)?.socket === socket) lobbies.delete(code);
+  }
+  const match = socket.matchId && matches.get(socket.matchId);
+  if (match) {
+    matches.delete(socket.matchId);
+    send(match.creator === socket ? match.joiner : match.creator, { type: "opponent-left" });
+  }
+  for (const room of socket.rooms ?? []) {
+    const state = rooms.get(room);
+    state.sockets.delete(socket);
+    for (const peer of state.sockets) send(peer, { type: "opponent-left", room });
+    if (state.sockets.size === 0) rooms.delete(room);
+  }
+}
+
+const handlers = {
+  "create-lobby": handleCreateLobby,
+  "list-lobbies": handleListLobbies,
+  "join-lobby": handleJoinLobby,
+  queue: handleQueue,
+  start: handleStart,
+  join: handleJoin,
+  turn: handleTurn,
+};
+
+const wss = new WebSocketServer({ port: PORT });
+
+wss.on("connection", (socket) => {
+  socket.on("message", async (data) => {
+    try {
+      const message = JSON.parse(data.toString());
+      const handler = handlers[message.type];
+      if (!handler) return send(socket, { type: "error", error: "unknown message type" });
+      await handler(socket, message);
+    } catch (err) {
+      send(socket, { type: "error", error: String(err?.message || err) });
+    }
+  });
+  socket.on("close", () => handleClose(socket));
+});
+
+wss.on("listening", () => {
+  console.log("Multiplayer server listening on ws://localhost:" + PORT);
+});
+"#;
+
+pub(super) const MULTIPLAYER_CLIENT: &str = r#"/**
+ * Multiplayer client - lobbies, matchmaking and live turns via multiplayer/server.mjs
+ *
+ * Flow:
+ * 1. Either both players call findMatch() with the same game and stake, or
+ *    one calls createLobby() and shares the code, which the other passes to
+ *    joinLobby() (listLobbies() shows the open ones)
+ * 2. The creator deploys the game and calls startMatch(); the joiner's
+ *    onStart handler receives the deploy txid
+ * 3. Both join(deployTxid) and sendTurn() after every transaction; the
+ *    opponent's onTurn handler receives it, raw tx included. The server only
+ *    relays raw transactions signed by the sender's key - a rejected turn
+ *    reaches the onError handler
+ *
+ * Start the server with: cd multiplayer && npm install && npm start
+ * To play from another machine, point VITE_MULTIPLAYER_URL at it, e.g.
+ * ws://192.168.1.20:3004
+ */
+
+import { config } from "./config";
+
+const MULTIPLAYER_URL = config.multiplayerUrl;
+
+export type GameKind = "tictactoe" | "rps";
+
+export interface Match {
+  matchId: string;
+  role: "creator" | "joiner";
+  opponent: string;
+}
+
+export interface Lobby {
+  code: string;
+  stake: number;
+  createdAt: number;
+}
+
+export interface Turn {
+  txid: string;
+  // Signed - or partially signed, for the opponent to complete - transaction
+  rawTx?: string;
+  // Set when the turn ended the game, e.g. "X wins"
+  note?: string;
+}
+
+export class MultiplayerSession {
+  private pendingMatch: { resolve: (match: Match) => void; reject: (err: Error) => void } | null = null;
+  private pendingLobby: { resolve: (code: string) => void; reject: (err: Error) => void } | null = null;
+  private pendingLobbies: ((lobbies: Lobby[]) => void) | null = null;
+  // Sent with join() so the server can check the signatures on our turns
+  private pubKey: string | null = null;
+  // A lobby match that arrived before waitForOpponent()
+  private unclaimedMatch: Match | null = null;
+  private startHandler: ((gameTxid: string) => void) | null = null;
+  private turnHandler: ((turn: Turn) => void) | null = null;
+  private leftHandler: (() => void) | null = null;
+  private errorHandler: ((error: string) => void) | null = null;
+
+  private constructor(private socket: WebSocket) {
+    socket.addEventListener("message", (event) => this.handle(JSON.parse(event.data)));
+    socket.addEventListener("close", () => {
+      this.fail(new Error("Multiplayer server disconnected"));
+    });
+  }
+
+  static connect(url: string = MULTIPLAYER_URL): Promise<MultiplayerSession> {
+    return new Promise((resolve, reject) => {
+      const socket = new WebSocket(url);
+      socket.addEventListener("open", () => resolve(new MultiplayerSession(socket)), { once: true });
+      socket.addEventListener(
+        "error",
+        () => reject(new Error("Can't reach the multiplayer server at " + url + " (is multiplayer/server.mjs running?)")),
+        { once: true },
+      );
+    });
+  }
+
+  private send(message: object) {
+    this.socket.send(JSON.stringify(message));
+  }
+
+  private fail(err: Error) {
+    this.pendingMatch?.reject(err);
+    this.pendingMatch = null;
+    this.pendingLobby?.reject(err);
+    this.pendingLobby = null;
+  }
+
+  private handle(message: any) {
+    switch (message.type) {
+      case "lobby":
+        this.pendingLobby?.resolve(message.code);
+        this.pendingLobby = null;
+        break;
+      case "lobbies":
+        this.pendingLobbies?.(message.lobbies);
+        this.pendingLobbies = null;
+        break;
+      case "matched":
+        if (this.pendingMatch) {
+          this.pendingMatch.resolve(message);
+        } else {
+          this.unclaimedMatch = message;
+        }
+        this.pendingMatch = null;
+        break;
+      case "start":
+        this.startHandler?.(message.gameTxid);
+        break;
+      case "turn":
+        this.turnHandler?.({ txid: message.txid, rawTx: message.rawTx, note: message.note });
+        break;
+      case "opponent-left":
+        this.leftHandler?.();
+        break;
+      case "error":
+        this.fail(new Error(message.error));
+        this.errorHandler?.(message.error);
+        console.warn("Multiplayer server:", message.error);
+        break;
+    }
+  }
+
+  /**
+   * Resolves once another player queues for the same game and stake
+   */
+  findMatch(game: GameKind, pubKey: string, stakeSats: number): Promise<Match> {
+    this.pubKey = pubKey;
+    return new Promise((resolve, reject) => {
+      this.pendingMatch = { resolve, reject };
+      this.send({ type: "queue", game, pubKey, stake: stakeSats });
+    });
+  }
+
+  /**
+   * Opens a lobby and resolves with its code; waitForOpponent() then
+   * resolves once someone joins it, with this player as the creator
+   */
+  createLobby(game: GameKind, pubKey: string, stakeSats: number): Promise<string> {
+    this.pubKey = pubKey;
+    return new Promise((resolve, reject) => {
+      this.pendingLobby = { resolve, reject };
+      this.send({ type: "create-lobby", game, pubKey, stake: stakeSats });
+    });
+  }
+
+  waitForOpponent(): Promise<Match> {
+    const match = this.unclaimedMatch;
+    this.unclaimedMatch = null;
+    if (match) return Promise.resolve(match);
+    return new Promise((resolve, reject) => {
+      this.pendingMatch = { resolve, reject };
+    });
+  }
+
+  /**
+   * Joins the lobby with `code`, as the joiner
+   */
+  joinLobby(code: string, pubKey: string): Promise<Match> {
+    this.pubKey = pubKey;
+    return new Promise((resolve, reject) => {
+      this.pendingMatch = { resolve, reject };
+      this.send({ type: "join-lobby", code: code.trim().toUpperCase(), pubKey });
+    });
+  }
+
+  listLobbies(game: GameKind): Promise<Lobby[]> {
+    return new Promise((resolve) => {
+      this.pendingLobbies = resolve;
+      this.send({ type: "list-lobbies", game });
+    });
+  }
+
+  /**
+   * Creator only: hand the deployed game to the matched opponent
+   */
+  startMatch(matchId: string, gameTxid: string) {
+    this.send({ type: "start", matchId, gameTxid });
+  }
+
+  join(gameTxid: string, pubKey: string | null = this.pubKey) {
+    this.pubKey = pubKey;
+    this.send({ type: "join", room: gameTxid, pubKey: pubKey ?? undefined });
+  }
+
+  sendTurn(gameTxid: string, turn: Turn) {
+    this.send({ type: "turn", room: gameTxid, ...turn });
+  }
+
+  // Each handler replaces the previous one
+
+  onStart(handler: (gameTxid: string) => void) {
+    this.startHandler = handler;
+  }
+
+  onTurn(handler: (turn: Turn) => void) {
+    this.turnHandler = handler;
+  }
+
+  onOpponentLeft(handler: () => void) {
+    this.leftHandler = handler;
+  }
+
+  // E.g. a turn the server wouldn't relay
+  onError(handler: (error: string) => void) {
+    this.errorHandler = handler;
+  }
+
+  close() {
+    this.socket.close();
+  }
+}
+"#;
+
+// ============================================================================
+// PLAYGROUND COMPILE SERVER (Playground template)
+// ============================================================================
+
+const PLAYGROUND_PACKAGE_JSON: &str = r#"{
+  "name": "bitcoin-app-playground",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "start": "node server.mjs"
+  }
+}
+"#;
+
+const PLAYGROUND_SERVER: &str = r#"/**
+ * Playground compile server - runs the sCrypt transpiler and compiler on
+ * snippets sent by the playground page
+ *
+ * The compiler is a native binary, so the browser can't run it. Each snippet
+ * is written to playground/snippets/, compiled with the project's own
+ * scrypt-cli (one at a time - they share artifacts/), and its artifact is
+ * sent back; both are deleted afterwards.
+ *
+ * Compiling runs the TypeScript compiler over whatever is submitted, so the
+ * server only listens on localhost unless HOST says otherwise - set
+ * HOST=0.0.0.0 to let a workshop's laptops share one.
+ *
+ * Run:  npm install (in the project root), then cd playground && npm start
+ *
+ * Endpoints:
+ *   POST /compile  { source }  → { artifact, compileMs }
+ *                              | 422 { error, log }  compiler output
+ *
+ * Env: PORT (3006), HOST (localhost)
+ */
+
+import { spawn } from "node:child_process";
+import { randomUUID } from "node:crypto";
+import { existsSync } from "node:fs";
+import { mkdir, readdir, readFile, rm, stat, writeFile } from "node:fs/promises";
+import { createServer } from "node:http";
+import { dirname, join, relative, resolve } from "node:path";
+import { fileURLToPath } from "node:url";
+
+const PORT = Number(process.env.PORT || 3006);
+const HOST = process.env.HOST || "localhost";
+const ROOT = resolve(dirname(fileURLToPath(import.meta.url)), "..");
+const SNIPPETS = join(ROOT, "playground", "snippets");
+const ARTIFACTS = join(ROOT, "artifacts");
+const MAX_SOURCE_BYTES = 50_000;
+const COMPILE_TIMEOUT_MS = 120_000;
+
+function send(res, status, body) {
+  res.writeHead(status, {
+    "Content-Type": "application/json",
+    "Access-Control-Allow-Origin": "*",
+    "Access-Control-Allow-Headers": "Content-Type",
+    "Access-Control-Allow-Methods": "POST, OPTIONS",
+  });
+  res.end(body === undefined ? "" : JSON.stringify(body));
+}
+
+async function readJson(req) {
+  let raw = "";
+  for await (const chunk of req) {
+    raw += chunk;
+    if (raw.length > MAX_SOURCE_BYTES * 2) throw new Error("request too large");
+  }
+  return raw ? JSON.parse(raw) : {};
+}
+
+// Runs `npx scrypt-cli compile` on one file, resolving with its output
+function scryptCompile(file) {
+  return new Promise((resolvePromise, reject) => {
+    const child = spawn("npx", ["scrypt-cli", "compile", "--include", file], {
+      cwd: ROOT,
+      shell: process.platform === "win32",
+    });
+    let log = "";
+    child.stdout.on("data", (data) => (log += data));
+    child.stderr.on("data", (data) => (log += data));
+    const timer = setTimeout(() => {
+      child.kill();
+      reject(new Error("compile timed out after " + COMPILE_TIMEOUT_MS / 1000 + "s"));
+    }, COMPILE_TIMEOUT_MS);
+    child.on("error", (err) => {
+      clearTimeout(timer);
+      reject(err);
+    });
+    child.on("close", (code) => {
+      clearTimeout(timer);
+      resolvePromise({ ok: code === 0, log });
+    });
+  });
+}
+
+// The artifact written for snippet `id`, wherever scrypt-cli mirrored it to
+async function findArtifact(dir, id, name) {
+  if (!existsSync(dir)) return null;
+  for (const entry of await readdir(dir, { withFileTypes: true })) {
+    const path = join(dir, entry.name);
+    if (entry.isDirectory()) {
+      const found = await findArtifact(path, id, name);
+      if (found) return found;
+    } else if (entry.name === name + ".scrypt.json" && path.includes(id)) {
+      return path;
+    }
+  }
+  return null;
+}
+
+async function compile(source) {
+  const name = /export\s+(?:default\s+)?class\s+([A-Za-z_]\w*)\s+extends\s+SmartContract\b/.exec(source)?.[1];
+  if (!name) {
+    return { status: 422, body: { error: "the snippet must export a class extending SmartContract" } };
+  }
+
+  const id = randomUUID();
+  const dir = join(SNIPPETS, id);
+  const file = join(dir, name + ".scrypt.ts");
+  await mkdir(dir, { recursive: true });
+  await writeFile(file, source);
+  const started = Date.now();
+  let artifactDir = null;
+  try {
+    const { ok, log } = await scryptCompile(relative(ROOT, file));
+    const artifact = await findArtifact(ARTIFACTS, id, name);
+    if (!ok || !artifact || !(await stat(artifact)).isFile()) {
+      return { status: 422, body: { error: "compile failed", log: log.trim() } };
+    }
+    artifactDir = dirname(artifact);
+    return {
+      status: 200,
+      body: { artifact: JSON.parse(await readFile(artifact, "utf8")), compileMs: Date.now() - started },
+    };
+  } finally {
+    await rm(dir, { recursive: true, force: true });
+    if (artifactDir) await rm(artifactDir, { recursive: true, force: true });
+  }
+}
+
+// Compiles share artifacts/, so they run one after another
+let queue = Promise.resolve();
+
+const server = createServer(async (req, res) => {
+  try {
+    if (req.method === "OPTIONS") return send(res, 204);
+
+    const path = new URL(req.url, "http://localhost").pathname;
+    if (req.method === "POST" && path === "/compile") {
+      const { source } = await readJson(req);
+      if (typeof source !== "string" || source.length === 0 || source.length > MAX_SOURCE_BYTES) {
+        return send(res, 400, { error: "source is required (up to " + MAX_SOURCE_BYTES + " characters)" });
+      }
+      const result = queue.then(() => compile(source));
+      queue = result.catch(() => {});
+      const { status, body } = await result;
+      return send(res, status, body);
+    }
+
+    send(res, 404, { error: "not found" });
+  } catch (err) {
+    send(res, 500, { error: String(err?.message || err) });
+  }
+});
+
+server.listen(PORT, HOST, () => {
+  console.log("Playground compile server listening on http://" + HOST + ":" + PORT);
+});
+"#;
+
+// ============================================================================
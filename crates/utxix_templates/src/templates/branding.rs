@@ -0,0 +1,245 @@
+//! App identity from the wizard's branding step, applied to a frontend.
+
+use std::path::{Path, PathBuf};
+
+/// App identity from the wizard's branding step, replacing the "Bitcoin App"
+/// placeholders and stock button color in the frontend.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Branding {
+    pub display_name: String,
+    /// `#rrggbb`, from [`parse_color`].
+    pub primary_color: String,
+    /// Image copied into `public/` as the favicon, manifest icon and header logo.
+    pub logo: Option<PathBuf>,
+}
+
+/// The primary color the frontends ship with (Tailwind's emerald-600).
+pub const DEFAULT_PRIMARY_COLOR: &str = "#059669";
+
+const PLACEHOLDER_NAME: &str = "Bitcoin App";
+
+const BACKGROUND_COLOR: &str = "#0f172a";
+
+impl Default for Branding {
+    /// The frontends as they ship: the placeholder name and stock color.
+    fn default() -> Self {
+        Branding {
+            display_name: PLACEHOLDER_NAME.to_string(),
+            primary_color: DEFAULT_PRIMARY_COLOR.to_string(),
+            logo: None,
+        }
+    }
+}
+
+/// Normalizes `#rgb` or `#rrggbb` (the `#` is optional) to `#rrggbb`.
+pub fn parse_color(text: &str) -> Option<String> {
+    let hex = text.trim().trim_start_matches('#').to_ascii_lowercase();
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        3 => Some(hex.chars().fold(String::from("#"), |mut color, c| {
+            color.push(c);
+            color.push(c);
+            color
+        })),
+        6 => Some(format!("#{hex}")),
+        _ => None,
+    }
+}
+
+/// The logo's file name in `public/`, keeping its extension.
+pub fn logo_file_name(logo: &Path) -> String {
+    match logo.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) => format!("logo.{}", ext.to_ascii_lowercase()),
+        None => "logo".to_string(),
+    }
+}
+
+/// Applies `branding` to the generated frontend files and adds a web app
+/// manifest. Titles, headers and the Tailwind theme are all patched in the
+/// generated text so every framework's templates keep one source.
+pub fn brand_frontend_files(
+    files: Vec<(&'static str, String)>,
+    branding: &Branding,
+) -> Vec<(&'static str, String)> {
+    let logo = branding
+        .logo
+        .as_deref()
+        .map(|logo| format!("/{}", logo_file_name(logo)));
+    let markup_name = escape_markup(&branding.display_name);
+    let mut files: Vec<(&'static str, String)> = files
+        .into_iter()
+        .map(|(path, contents)| {
+            let contents = match path {
+                "index.html" | "src/index.html" => {
+                    brand_index_html(contents, branding, &markup_name, logo.as_deref())
+                }
+                "src/app/layout.tsx" => {
+                    let mut metadata = format!(
+                        "title: {},\n  manifest: \"/manifest.webmanifest\",",
+                        json_string(&branding.display_name)
+                    );
+                    if let Some(logo) = &logo {
+                        metadata.push_str(&format!("\n  icons: {},", json_string(logo)));
+                    }
+                    contents.replace(&format!("title: \"{PLACEHOLDER_NAME}\","), &metadata)
+                }
+                "tailwind.config.js" | "tailwind.config.ts" => contents.replace(
+                    "extend: {},",
+                    &format!(
+                        "extend: {{\n      colors: {{\n        primary: \"{}\",\n      }},\n    }},",
+                        branding.primary_color
+                    ),
+                ),
+                "angular.json" => contents.replace(
+                    "\"scripts\": []",
+                    "\"scripts\": [],\n            \"assets\": [{ \"glob\": \"**/*\", \"input\": \"public\" }]",
+                ),
+                _ => contents,
+            };
+            let contents = contents.replace(
+                "bg-emerald-600 hover:bg-emerald-700",
+                "bg-primary hover:brightness-90",
+            );
+            (path, brand_header(contents, &markup_name, logo.as_deref()))
+        })
+        .collect();
+    files.push(("public/manifest.webmanifest", manifest(branding, logo.as_deref())));
+    files
+}
+
+fn brand_index_html(
+    contents: String,
+    branding: &Branding,
+    markup_name: &str,
+    logo: Option<&str>,
+) -> String {
+    let mut head = format!(
+        "    <meta name=\"theme-color\" content=\"{}\" />\n    <link rel=\"manifest\" href=\"/manifest.webmanifest\" />\n",
+        branding.primary_color
+    );
+    let mut contents = contents.replace(
+        &format!("<title>{PLACEHOLDER_NAME}</title>"),
+        &format!("<title>{markup_name}</title>"),
+    );
+    if let Some(logo) = logo {
+        head.push_str(&format!("    <link rel=\"icon\" href=\"{logo}\" />\n"));
+        contents = contents
+            .lines()
+            .filter(|line| !line.contains("rel=\"icon\""))
+            .map(|line| format!("{line}\n"))
+            .collect();
+    }
+    contents.replacen("  </head>", &format!("{head}  </head>"), 1)
+}
+
+/// Puts the name, and the logo next to it, in the app header.
+fn brand_header(contents: String, markup_name: &str, logo: Option<&str>) -> String {
+    let placeholder = format!(">{PLACEHOLDER_NAME}</h1>");
+    if !contents.contains(&placeholder) {
+        return contents;
+    }
+    let mut branded = String::new();
+    for line in contents.split_inclusive('\n') {
+        if !line.contains(&placeholder) {
+            branded.push_str(line);
+            continue;
+        }
+        let heading = line.replace(&placeholder, &format!(">{markup_name}</h1>"));
+        match logo {
+            Some(logo) => {
+                let indent = &line[..line.len() - line.trim_start().len()];
+                // JSX spells the attribute className
+                let class = if line.contains("className=") {
+                    "className"
+                } else {
+                    "class"
+                };
+                branded.push_str(&format!(
+                    "{indent}<div {class}=\"flex items-center gap-3\">\n\
+                     {indent}  <img src=\"{logo}\" alt=\"\" {class}=\"h-8 w-8\" />\n\
+                     {indent}  {}\
+                     {indent}</div>\n",
+                    heading.trim_start()
+                ));
+            }
+            None => branded.push_str(&heading),
+        }
+    }
+    branded
+}
+
+fn manifest(branding: &Branding, logo: Option<&str>) -> String {
+    let icons = match (logo, branding.logo.as_deref()) {
+        (Some(logo), Some(path)) => {
+            let mime = match path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ext.to_ascii_lowercase())
+                .as_deref()
+            {
+                Some("svg") => "image/svg+xml",
+                Some("jpg" | "jpeg") => "image/jpeg",
+                Some("webp") => "image/webp",
+                Some("ico") => "image/x-icon",
+                _ => "image/png",
+            };
+            format!(
+                "[\n    {{ \"src\": {}, \"sizes\": \"any\", \"type\": \"{mime}\" }}\n  ]",
+                json_string(logo)
+            )
+        }
+        _ => "[]".to_string(),
+    };
+    format!(
+        r#"{{
+  "name": {name},
+  "short_name": {name},
+  "start_url": "/",
+  "display": "standalone",
+  "background_color": "{BACKGROUND_COLOR}",
+  "theme_color": "{color}",
+  "icons": {icons}
+}}
+"#,
+        name = json_string(&branding.display_name),
+        color = branding.primary_color,
+    )
+}
+
+/// Escapes text for HTML, and for the JSX, Vue, Svelte and Angular templates
+/// where braces, `$` and backticks would otherwise start an expression.
+fn escape_markup(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '{' => escaped.push_str("&#123;"),
+            '}' => escaped.push_str("&#125;"),
+            '$' => escaped.push_str("&#36;"),
+            '`' => escaped.push_str("&#96;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A JSON string literal, which is also a valid JavaScript one.
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,1878 @@
+//! Each template's contract test (chai on mocha, vitest or jest, run locally
+//! against MockProvider), the runner config they share and the optional
+//! fixtures and CI workflow.
+
+use crate::{Template, TestFramework};
+
+use super::{
+    contracts::contract_filename,
+    property_tests::{
+        AUCTION_PROPERTY_TEST, COUNTER_PROPERTY_TEST, CUSTOM_CONTRACT_PROPERTY_TEST,
+        ESCROW_PROPERTY_TEST, HELLOWORLD_PROPERTY_TEST, LOTTERY_PROPERTY_TEST,
+        ORDINAL_LISTING_PROPERTY_TEST, PAYMENT_CHANNEL_PROPERTY_TEST, PLAYGROUND_PROPERTY_TEST,
+        PREDICTION_PROPERTY_TEST, PROPERTY_ARBITRARIES, ROCK_PAPER_SCISSORS_PROPERTY_TEST,
+        TICTACTOE_PROPERTY_TEST, TOKEN_MINT_PROPERTY_TEST, TREASURY_PROPERTY_TEST,
+    },
+};
+
+pub fn test_filename(template: Template) -> String {
+    format!("{}.test.ts", contract_filename(template).trim_end_matches(".scrypt.ts"))
+}
+
+pub fn test_source(template: Template, test_framework: TestFramework) -> String {
+    let source = match template {
+        Template::HelloWorld => HELLOWORLD_TEST.to_string(),
+        Template::Counter => COUNTER_TEST.to_string(),
+        Template::TicTacToe => TICTACTOE_TEST.to_string(),
+        Template::Auction => AUCTION_TEST.to_string(),
+        Template::Treasury => TREASURY_TEST.to_string(),
+        Template::RockPaperScissors => ROCK_PAPER_SCISSORS_TEST.to_string(),
+        Template::Lottery => LOTTERY_TEST.to_string(),
+        Template::Prediction => PREDICTION_TEST.to_string(),
+        Template::NftMarketplace => ORDINAL_LISTING_TEST.to_string(),
+        Template::Token => TOKEN_MINT_TEST.to_string(),
+        Template::StreamingPayments => PAYMENT_CHANNEL_TEST.to_string(),
+        Template::Marketplace => ESCROW_TEST.to_string(),
+        Template::Playground => PLAYGROUND_TEST.to_string(),
+        Template::Custom => CUSTOM_CONTRACT_TEST.to_string(),
+    };
+    for_test_framework(source, test_framework)
+}
+
+/// The tests are written for mocha and assert with chai, which every runner
+/// can keep using; only mocha's `before` hook is named differently elsewhere.
+fn for_test_framework(source: String, test_framework: TestFramework) -> String {
+    match test_framework {
+        TestFramework::Mocha => source,
+        TestFramework::Vitest | TestFramework::Jest => source.replace(" before(", " beforeAll("),
+    }
+}
+
+/// Optional fast-check property tests for the template's contract, plus the
+/// Script-bounded arbitraries they share. The test runner picks them up with
+/// the example-based test.
+pub fn property_test_files(
+    template: Template,
+    test_framework: TestFramework,
+) -> Vec<(String, String)> {
+    let contract = contract_filename(template).trim_end_matches(".scrypt.ts");
+    let source = match template {
+        Template::HelloWorld => HELLOWORLD_PROPERTY_TEST,
+        Template::Counter => COUNTER_PROPERTY_TEST,
+        Template::TicTacToe => TICTACTOE_PROPERTY_TEST,
+        Template::Auction => AUCTION_PROPERTY_TEST,
+        Template::Treasury => TREASURY_PROPERTY_TEST,
+        Template::RockPaperScissors => ROCK_PAPER_SCISSORS_PROPERTY_TEST,
+        Template::Lottery => LOTTERY_PROPERTY_TEST,
+        Template::Prediction => PREDICTION_PROPERTY_TEST,
+        Template::NftMarketplace => ORDINAL_LISTING_PROPERTY_TEST,
+        Template::Token => TOKEN_MINT_PROPERTY_TEST,
+        Template::StreamingPayments => PAYMENT_CHANNEL_PROPERTY_TEST,
+        Template::Marketplace => ESCROW_PROPERTY_TEST,
+        Template::Playground => PLAYGROUND_PROPERTY_TEST,
+        Template::Custom => CUSTOM_CONTRACT_PROPERTY_TEST,
+    };
+    vec![
+        (
+            format!("tests/{contract}.property.test.ts"),
+            for_test_framework(source.to_string(), test_framework),
+        ),
+        ("tests/utils/arbitraries.ts".to_string(), PROPERTY_ARBITRARIES.to_string()),
+    ]
+}
+
+/// Test runner config and helpers shared by every template's contract test.
+pub fn test_support_files(test_framework: TestFramework) -> Vec<(&'static str, String)> {
+    let tsconfig_test = |types: &str| TSCONFIG_TEST.replace("\"mocha\"", types);
+    let mut files = match test_framework {
+        TestFramework::Mocha => vec![
+            (".mocharc.json", MOCHARC_JSON.to_string()),
+            ("tsconfig.test.json", TSCONFIG_TEST.to_string()),
+        ],
+        TestFramework::Vitest => vec![
+            ("vitest.config.mts", VITEST_CONFIG.to_string()),
+            ("tsconfig.test.json", tsconfig_test("\"vitest/globals\"")),
+        ],
+        TestFramework::Jest => vec![
+            ("jest.config.cjs", JEST_CONFIG.to_string()),
+            ("tsconfig.test.json", tsconfig_test("\"jest\"")),
+        ],
+    };
+    // ts-jest compiles everything the tests load itself
+    if test_framework != TestFramework::Jest {
+        files.push(("tests/register.cjs", TEST_REGISTER.to_string()));
+    }
+    files.push(("tests/utils/txHelper.ts", TEST_TX_HELPER.to_string()));
+    files
+}
+
+/// Swaps mocha for `test_framework` in the generated `package.json`: the
+/// `test` script and the runner's dev dependencies, kept sorted.
+pub fn with_test_framework(
+    files: Vec<(&'static str, String)>,
+    test_framework: TestFramework,
+) -> Vec<(&'static str, String)> {
+    let dev_dependencies: &[&str] = match test_framework {
+        TestFramework::Mocha => return files,
+        TestFramework::Vitest => &["\"vitest\": \"^1.6.0\""],
+        TestFramework::Jest => &[
+            "\"@types/jest\": \"^29.5.0\"",
+            "\"jest\": \"^29.7.0\"",
+            "\"ts-jest\": \"^29.1.0\"",
+        ],
+    };
+    files
+        .into_iter()
+        .map(|(path, contents)| {
+            if path != "package.json" {
+                return (path, contents);
+            }
+            let contents = contents.replace(
+                "\"test\": \"mocha\",",
+                &format!("\"test\": \"{}\",", test_framework.command()),
+            );
+            let Some((head, rest)) = contents.split_once("\"devDependencies\": {\n") else {
+                return (path, contents);
+            };
+            let Some((entries, tail)) = rest.split_once("\n  }") else {
+                return (path, contents);
+            };
+            let mut entries: Vec<&str> = entries
+                .lines()
+                .map(|line| line.trim().trim_end_matches(','))
+                .filter(|entry| {
+                    !entry.starts_with("\"mocha\":") && !entry.starts_with("\"@types/mocha\":")
+                })
+                .chain(dev_dependencies.iter().copied())
+                .collect();
+            entries.sort_unstable();
+            let contents = format!(
+                "{head}\"devDependencies\": {{\n    {}\n  }}{tail}",
+                entries.join(",\n    ")
+            );
+            (path, contents)
+        })
+        .collect()
+}
+
+/// Optional `scripts/fixtures.ts`, writing the deterministic keys and UTXOs
+/// the test helpers above pick up from `tests/fixtures.json`.
+pub fn fixture_files() -> Vec<(&'static str, String)> {
+    vec![("scripts/fixtures.ts", FIXTURES_SCRIPT.to_string())]
+}
+
+const FIXTURES_SCRIPT: &str = r#"/**
+ * Deterministic test fixtures
+ *
+ *   npx ts-node --project tsconfig.test.json scripts/fixtures.ts
+ *
+ * Derives keypairs, addresses and dummy wallet UTXOs from FIXTURE_SEED and
+ * writes them to tests/fixtures.json. The same seed always gives the same
+ * file - commit it, and tests/utils/txHelper.ts hands out these keys and
+ * UTXOs on every run and machine instead of random ones. Local simulations
+ * can read the file too.
+ *
+ * Testnet keys derived from a public seed: never send real coins to them.
+ *
+ * Env: FIXTURE_SEED ("utxix-fixtures"), FIXTURE_KEYS (10),
+ *      FIXTURE_UTXOS_PER_KEY (3), FIXTURE_UTXO_SATS (1000000)
+ */
+
+import { mkdirSync, writeFileSync } from "fs";
+import * as path from "path";
+import { bsv } from "scrypt-ts";
+
+const SEED = process.env.FIXTURE_SEED || "utxix-fixtures";
+const KEY_COUNT = Number(process.env.FIXTURE_KEYS || 10);
+const UTXOS_PER_KEY = Number(process.env.FIXTURE_UTXOS_PER_KEY || 3);
+const UTXO_SATS = Number(process.env.FIXTURE_UTXO_SATS || 1_000_000);
+const OUT_PATH = path.join(__dirname, "..", "tests", "fixtures.json");
+
+function sha256Hex(label: string): string {
+  return bsv.crypto.Hash.sha256(Buffer.from(SEED + ":" + label, "utf8")).toString("hex");
+}
+
+// Retries the (astronomically unlikely) hashes outside the secp256k1 range
+function deriveKey(index: number): bsv.PrivateKey {
+  for (let attempt = 0; ; attempt++) {
+    try {
+      return bsv.PrivateKey.fromHex(sha256Hex("key:" + index + ":" + attempt), bsv.Networks.testnet);
+    } catch {
+      // next attempt
+    }
+  }
+}
+
+const keys = Array.from({ length: KEY_COUNT }, (_, index) => {
+  const privateKey = deriveKey(index);
+  const publicKey = privateKey.toPublicKey();
+  return {
+    index,
+    wif: privateKey.toWIF(),
+    publicKey: publicKey.toHex(),
+    address: privateKey.toAddress().toString(),
+    pkh: bsv.crypto.Hash.sha256ripemd160(publicKey.toBuffer()).toString("hex"),
+  };
+});
+
+// Made-up funding outputs - valid P2PKH scripts, but the txids don't exist
+// on any chain, so they only work with the mock providers
+const utxos = keys.flatMap((key) =>
+  Array.from({ length: UTXOS_PER_KEY }, (_, n) => ({
+    address: key.address,
+    txId: sha256Hex("utxo:" + key.index + ":" + n),
+    outputIndex: n,
+    script: bsv.Script.buildPublicKeyHashOut(bsv.Address.fromString(key.address)).toHex(),
+    satoshis: UTXO_SATS,
+  })),
+);
+
+mkdirSync(path.dirname(OUT_PATH), { recursive: true });
+writeFileSync(OUT_PATH, JSON.stringify({ seed: SEED, network: "testnet", keys, utxos }, null, 2) + "\n");
+
+console.log("Wrote " + keys.length + " keys and " + utxos.length + " UTXOs to tests/fixtures.json (seed \"" + SEED + "\")");
+"#;
+
+/// Optional GitHub Actions workflow compiling the contracts and running the
+/// tests above on every push.
+pub fn ci_files(test_framework: TestFramework) -> Vec<(&'static str, String)> {
+    let config = match test_framework {
+        TestFramework::Mocha => ".mocharc.json",
+        TestFramework::Vitest => "vitest.config.mts",
+        TestFramework::Jest => "jest.config.cjs",
+    };
+    let workflow = CI_WORKFLOW
+        .replace(
+            "      - \"tsconfig.test.json\"\n",
+            &format!("      - \"tsconfig.test.json\"\n      - \"{config}\"\n"),
+        )
+        .replace("npx mocha", &format!("npx {}", test_framework.command()));
+    vec![(".github/workflows/contracts.yml", workflow)]
+}
+
+const CI_WORKFLOW: &str = r#"# Compiles the sCrypt contracts and runs their tests (local MockProvider -
+# no keys or funds needed)
+name: Contracts
+
+on:
+  push:
+    paths:
+      - "contracts/**"
+      - "tests/**"
+      - "package.json"
+      - "tsconfig.test.json"
+      - ".github/workflows/contracts.yml"
+  pull_request:
+    paths:
+      - "contracts/**"
+      - "tests/**"
+      - "package.json"
+      - "tsconfig.test.json"
+      - ".github/workflows/contracts.yml"
+
+jobs:
+  contracts:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+
+      - uses: actions/setup-node@v4
+        with:
+          node-version: 20
+
+      - name: Install dependencies
+        run: npm install
+
+      - name: Compile contracts
+        run: npx scrypt-cli compile
+
+      - name: Run contract tests
+        run: npx mocha
+"#;
+
+const MOCHARC_JSON: &str = r#"{
+  "require": "./tests/register.cjs",
+  "extension": ["ts"],
+  "spec": "tests/**/*.test.ts",
+  "timeout": 600000
+}
+"#;
+
+// Only the contract tests - the frontend's vite config and plugins stay out
+// of it. Decorators are set here as the app's tsconfig may not enable them.
+const VITEST_CONFIG: &str = r#"import { defineConfig } from "vitest/config";
+
+export default defineConfig({
+  test: {
+    include: ["tests/**/*.test.ts"],
+    environment: "node",
+    globals: true,
+    // ts-node, for the regtest provider txHelper.ts loads with require()
+    setupFiles: ["./tests/register.cjs"],
+    testTimeout: 600000,
+    hookTimeout: 600000,
+  },
+  esbuild: {
+    tsconfigRaw: {
+      compilerOptions: {
+        experimentalDecorators: true,
+        useDefineForClassFields: false,
+      },
+    },
+  },
+});
+"#;
+
+const JEST_CONFIG: &str = r#"// Compiles contracts and tests as CommonJS through ts-jest, whatever module
+// setup the app itself uses (see tsconfig.test.json)
+module.exports = {
+  testEnvironment: "node",
+  roots: ["<rootDir>/tests"],
+  testMatch: ["**/*.test.ts"],
+  transform: {
+    "^.+\\.ts$": [
+      "ts-jest",
+      { tsconfig: "<rootDir>/tsconfig.test.json", isolatedModules: true },
+    ],
+  },
+  testTimeout: 600000,
+};
+"#;
+
+const TSCONFIG_TEST: &str = r#"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "module": "commonjs",
+    "moduleResolution": "node",
+    "esModuleInterop": true,
+    "experimentalDecorators": true,
+    "skipLibCheck": true,
+    "types": ["mocha", "node"]
+  },
+  "ts-node": {
+    "transpileOnly": true,
+    "moduleTypes": {
+      "contracts/**/*": "cjs",
+      "scripts/**/*": "cjs",
+      "tests/**/*": "cjs"
+    }
+  },
+  "include": ["contracts", "scripts", "tests"]
+}
+"#;
+
+const TEST_REGISTER: &str = r#"// Runs contracts and tests through ts-node as CommonJS, whatever module
+// setup the app itself uses (see tsconfig.test.json)
+const path = require("path");
+
+require("ts-node").register({
+  project: path.join(__dirname, "..", "tsconfig.test.json"),
+});
+"#;
+
+const TEST_TX_HELPER: &str = r#"/**
+ * Local test helpers
+ *
+ * Tests run against MockProvider: nothing is broadcast and wallet UTXOs are
+ * made up, so every public method can be called without funds. The contract
+ * input's script is still really executed - see expectValid().
+ *
+ * With tests/fixtures.json (see scripts/fixtures.ts, if generated) keys and
+ * wallet UTXOs come from there instead, so every run uses the same ones.
+ *
+ * With TEST_NETWORK=regtest (set in .env by the editor's "regtest: start
+ * node" action) the tests broadcast to the local regtest node instead, and
+ * each key is funded from the node's wallet the first time its coins are
+ * listed. Timeout paths then need the chain to reach their lockTime - mine()
+ * in scripts/provider.ts, or the "regtest: mine block" action.
+ */
+
+import "dotenv/config";
+import { expect } from "chai";
+import { existsSync, readFileSync } from "fs";
+import * as path from "path";
+import {
+  bsv,
+  findSig,
+  hash160,
+  MockProvider,
+  PubKey,
+  Provider,
+  PubKeyHash,
+  SignatureResponse,
+  Signer,
+  SmartContract,
+  TestWallet,
+  UTXO,
+  UtxoQueryOptions,
+  Utils,
+} from "scrypt-ts";
+
+export interface TestKey {
+  privateKey: bsv.PrivateKey;
+  publicKey: bsv.PublicKey;
+  pubKey: PubKey;
+  pkh: PubKeyHash;
+}
+
+interface Fixtures {
+  keys: { wif: string }[];
+  utxos: (UTXO & { address: string })[];
+}
+
+const FIXTURES_PATH = path.join(__dirname, "..", "fixtures.json");
+const fixtures: Fixtures | null = existsSync(FIXTURES_PATH)
+  ? JSON.parse(readFileSync(FIXTURES_PATH, "utf8"))
+  : null;
+let nextFixtureKey = 0;
+
+function keyFrom(privateKey: bsv.PrivateKey): TestKey {
+  const publicKey = privateKey.toPublicKey();
+  const pubKey = PubKey(publicKey.toHex());
+  return { privateKey, publicKey, pubKey, pkh: hash160(pubKey) };
+}
+
+export function randomKey(): TestKey {
+  return keyFrom(bsv.PrivateKey.fromRandom(bsv.Networks.testnet));
+}
+
+/**
+ * Next key from tests/fixtures.json, or a random key if it wasn't generated
+ */
+export function testKey(): TestKey {
+  if (!fixtures) {
+    return randomKey();
+  }
+  const fixture = fixtures.keys[nextFixtureKey++];
+  if (!fixture) {
+    throw new Error(
+      "tests/fixtures.json only has " + fixtures.keys.length + " keys - regenerate it with a higher FIXTURE_KEYS",
+    );
+  }
+  return keyFrom(bsv.PrivateKey.fromWIF(fixture.wif));
+}
+
+// MockProvider serving the fixture UTXOs of fixture addresses
+class FixtureProvider extends MockProvider {
+  async listUnspent(address: bsv.Address | string, options?: UtxoQueryOptions): Promise<UTXO[]> {
+    const utxos = (fixtures?.utxos ?? [])
+      .filter((utxo) => utxo.address === address.toString())
+      .map(({ txId, outputIndex, script, satoshis }) => ({ txId, outputIndex, script, satoshis }));
+    return utxos.length > 0 ? utxos : super.listUnspent(address, options);
+  }
+}
+
+const TEST_NETWORK = (process.env.TEST_NETWORK || "").toLowerCase();
+const REGTEST_FUND_SATS = Number(process.env.REGTEST_FUND_SATS || 10_000_000);
+
+// The regtest node's provider, funding addresses that have no coins yet
+function regtestProvider(): Provider {
+  // Only generated with the regtest node, so loaded on demand
+  const { RegtestProvider, fund } = require("../../scripts/provider");
+  if (!RegtestProvider || !fund) {
+    throw new Error("TEST_NETWORK=regtest needs the regtest node's scripts/provider.ts - regenerate with the regtest node included");
+  }
+  class FundingProvider extends RegtestProvider {
+    // The node is reached on the first call; a failure reports its URL
+    isConnected(): boolean {
+      return true;
+    }
+
+    async listUnspent(address: bsv.Address | string, options?: UtxoQueryOptions): Promise<UTXO[]> {
+      const utxos: UTXO[] = await super.listUnspent(address, options);
+      if (utxos.length > 0) {
+        return utxos;
+      }
+      await fund(address.toString(), REGTEST_FUND_SATS);
+      return super.listUnspent(address, options);
+    }
+  }
+  return new FundingProvider();
+}
+
+/**
+ * One wallet holding every party's key, so a single connected instance can
+ * sign as any of them (choose with pubKeyOrAddrToSign + sigFrom)
+ */
+export function getDefaultSigner(...keys: TestKey[]): TestWallet {
+  const provider =
+    TEST_NETWORK === "regtest" ? regtestProvider() : fixtures ? new FixtureProvider() : new MockProvider();
+  return new TestWallet(keys.map((key) => key.privateKey), provider);
+}
+
+// Signature argument for a method call, signed by `key`
+export function sigFrom(key: TestKey) {
+  return (sigResps: SignatureResponse[]) => findSig(sigResps, key.publicKey);
+}
+
+export function payTo(pkh: PubKeyHash, satoshis: bigint | number): bsv.Transaction.Output {
+  return new bsv.Transaction.Output({
+    script: bsv.Script.fromHex(Utils.buildPublicKeyHashScript(pkh)),
+    satoshis: Number(satoshis),
+  });
+}
+
+export function stateOutput(next: SmartContract, satoshis: bigint | number): bsv.Transaction.Output {
+  return new bsv.Transaction.Output({ script: next.lockingScript, satoshis: Number(satoshis) });
+}
+
+// Parses an output serialized in-contract (e.g. by Utils.buildOutput)
+export function outputFromBytes(output: string): bsv.Transaction.Output {
+  return bsv.Transaction.Output.fromBufferReader(new bsv.encoding.BufferReader(Buffer.from(output, "hex")));
+}
+
+/**
+ * Contract input (input 0) followed by `outputs`. Pass lockTime for
+ * timeout paths - input 0 then gets a non-final sequence.
+ */
+export function callTx(current: SmartContract, outputs: bsv.Transaction.Output[], lockTime?: number): bsv.Transaction {
+  const tx = new bsv.Transaction().addInput(current.buildContractInput());
+  for (const output of outputs) {
+    tx.addOutput(output);
+  }
+  if (lockTime !== undefined) {
+    tx.setLockTime(lockTime);
+    tx.setInputSequence(0, 0);
+  }
+  return tx;
+}
+
+// Wallet inputs (input 1+) for calls that put more value into the contract
+export async function addFunding(tx: bsv.Transaction, signer: Signer): Promise<bsv.Transaction> {
+  return tx.from(await signer.listUnspent(await signer.getDefaultAddress()));
+}
+
+// Runs the unlocking script of the contract input against its locking script
+export function expectValid(result: { tx: bsv.Transaction; atInputIndex: number }) {
+  const verified = result.tx.verifyScript(result.atInputIndex);
+  expect(verified.success, verified.error).to.eq(true);
+}
+"#;
+
+const HELLOWORLD_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { sha256, toByteString } from "scrypt-ts";
+import HelloWorld from "../contracts/HelloWorld.scrypt";
+import { expectValid, getDefaultSigner, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const MESSAGE = toByteString("hello world", true);
+
+describe("HelloWorld", () => {
+  const owner = testKey();
+  let instance: HelloWorld;
+
+  before(async () => {
+    await HelloWorld.loadArtifact("artifacts/contracts/HelloWorld.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    instance = new HelloWorld(sha256(MESSAGE));
+    await instance.connect(getDefaultSigner(owner));
+    await instance.deploy(1);
+  });
+
+  it("unlock() accepts the message", async () => {
+    expectValid(await instance.methods.unlock(MESSAGE));
+  });
+
+  it("unlock() rejects any other message", async () => {
+    await expect(instance.methods.unlock(toByteString("hello", true))).to.be.rejectedWith(/Hash mismatch/);
+  });
+});
+"#;
+
+const COUNTER_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { MethodCallOptions } from "scrypt-ts";
+import Counter from "../contracts/Counter.scrypt";
+import { expectValid, getDefaultSigner, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+describe("Counter", () => {
+  const owner = testKey();
+  let counter: Counter;
+
+  before(async () => {
+    await Counter.loadArtifact("artifacts/contracts/Counter.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    counter = new Counter(0n);
+    await counter.connect(getDefaultSigner(owner));
+    await counter.deploy(1);
+  });
+
+  // The default tx builder puts `next` at output 0 and adds change
+  function increment(current: Counter, by = 1n) {
+    const next = current.next();
+    next.count = current.count + by;
+    return current.methods.increment({
+      next: { instance: next, atOutputIndex: 0, balance: current.balance },
+    } as MethodCallOptions<Counter>);
+  }
+
+  it("increment() moves the counter forward", async () => {
+    for (let i = 1n; i <= 3n; i++) {
+      const result = await increment(counter);
+      expectValid(result);
+      counter = result.next!.instance;
+      expect(counter.count).to.eq(i);
+    }
+  });
+
+  it("increment() rejects skipping ahead", async () => {
+    await expect(increment(counter, 2n)).to.be.rejectedWith(/hashOutputs mismatch/);
+  });
+});
+"#;
+
+const TICTACTOE_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, MethodCallOptions, Utils } from "scrypt-ts";
+import TicTacToe from "../contracts/TicTacToe.scrypt";
+import {
+  TestKey,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+  stateOutput,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+describe("TicTacToe", () => {
+  const playerX = testKey();
+  const playerO = testKey();
+  let game: TicTacToe;
+
+  before(async () => {
+    await TicTacToe.loadArtifact("artifacts/contracts/TicTacToe.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    game = new TicTacToe(playerX.pubKey, playerO.pubKey, 1000n);
+    await game.connect(getDefaultSigner(playerX, playerO));
+    await game.deploy(2000);
+  });
+
+  // Output 0 mirrors the contract: winner payout, draw split or next state
+  function move(current: TicTacToe, position: number, player: TestKey) {
+    current.bindTxBuilder("move", async (self: TicTacToe) => {
+      const next = self.next();
+      next.board[position] = self.isXTurn ? 1n : 2n;
+
+      const winner = next.checkWinner();
+      if (winner !== 0n) {
+        const pkh = hash160(winner === 1n ? self.playerX : self.playerO);
+        return { tx: callTx(self, [payTo(pkh, self.balance)]), atInputIndex: 0, nexts: [] };
+      }
+      if (next.isBoardFull()) {
+        const half = Math.floor(self.balance / 2);
+        const outputs = [payTo(hash160(self.playerX), half), payTo(hash160(self.playerO), self.balance - half)];
+        return { tx: callTx(self, outputs), atInputIndex: 0, nexts: [] };
+      }
+
+      next.isXTurn = !self.isXTurn;
+      return {
+        tx: callTx(self, [stateOutput(next, self.balance)]),
+        atInputIndex: 0,
+        nexts: [{ instance: next, atOutputIndex: 0, balance: self.balance }],
+      };
+    });
+
+    return current.methods.move(BigInt(position), sigFrom(player), {
+      pubKeyOrAddrToSign: player.publicKey,
+    } as MethodCallOptions<TicTacToe>);
+  }
+
+  it("move() marks the square and passes the turn", async () => {
+    const result = await move(game, 4, playerX);
+    expectValid(result);
+
+    const next = result.next!.instance;
+    expect(next.board[4]).to.eq(1n);
+    expect(next.isXTurn).to.eq(false);
+  });
+
+  it("move() pays the whole pot to a winning line", async () => {
+    const moves: Array<[number, TestKey]> = [[0, playerX], [3, playerO], [1, playerX], [4, playerO]];
+    let current = game;
+    for (const [position, player] of moves) {
+      current = (await move(current, position, player)).next!.instance;
+    }
+
+    const result = await move(current, 2, playerX);
+    expectValid(result);
+    expect(result.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(playerX.pkh));
+    expect(result.tx.outputs[0].satoshis).to.eq(2000);
+  });
+
+  it("move() rejects playing out of turn", async () => {
+    await expect(move(game, 0, playerO)).to.be.rejectedWith(/Invalid signature/);
+  });
+
+  it("move() rejects an occupied square", async () => {
+    const next = (await move(game, 0, playerX)).next!.instance;
+    await expect(move(next, 0, playerO)).to.be.rejectedWith(/Position occupied/);
+  });
+});
+"#;
+
+const AUCTION_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, MethodCallOptions, Utils } from "scrypt-ts";
+import Auction from "../contracts/Auction.scrypt";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+  stateOutput,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const DEADLINE = 800_000;
+
+describe("Auction", () => {
+  const auctioneer = testKey();
+  const alice = testKey();
+  const bob = testKey();
+  let auction: Auction;
+
+  before(async () => {
+    await Auction.loadArtifact("artifacts/contracts/Auction.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    auction = new Auction(auctioneer.pubKey, BigInt(DEADLINE));
+    await auction.connect(getDefaultSigner(auctioneer, alice, bob));
+    await auction.deploy(1);
+  });
+
+  // Output 0: the new highest bid, output 1: refund for the outbid bidder
+  function bid(current: Auction, bidder: TestKey, amount: bigint) {
+    current.bindTxBuilder("bid", async (self: Auction) => {
+      const next = self.next();
+      next.highestBidder = bidder.pubKey;
+      next.highestBid = amount;
+
+      const outputs = [stateOutput(next, amount)];
+      if (self.highestBid > 0n) {
+        outputs.push(payTo(hash160(self.highestBidder), self.highestBid));
+      }
+      const tx = await addFunding(callTx(self, outputs), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: Number(amount) }] };
+    });
+    return current.methods.bid(bidder.pubKey, amount);
+  }
+
+  function close(current: Auction, lockTime: number) {
+    current.bindTxBuilder("close", async (self: Auction) => ({
+      tx: callTx(self, [payTo(hash160(self.auctioneer), self.highestBid)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return current.methods.close(sigFrom(auctioneer), {
+      pubKeyOrAddrToSign: auctioneer.publicKey,
+    } as MethodCallOptions<Auction>);
+  }
+
+  it("bid() records the first bid", async () => {
+    const result = await bid(auction, alice, 1000n);
+    expectValid(result);
+
+    const next = result.next!.instance;
+    expect(next.highestBidder).to.eq(alice.pubKey);
+    expect(next.highestBid).to.eq(1000n);
+  });
+
+  it("bid() refunds the outbid bidder", async () => {
+    const first = (await bid(auction, alice, 1000n)).next!.instance;
+    const result = await bid(first, bob, 2000n);
+    expectValid(result);
+    expect(result.tx.outputs[1].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(alice.pkh));
+    expect(result.tx.outputs[1].satoshis).to.eq(1000);
+  });
+
+  it("bid() rejects a lower bid", async () => {
+    const first = (await bid(auction, alice, 1000n)).next!.instance;
+    await expect(bid(first, bob, 500n)).to.be.rejectedWith(/Bid too low/);
+  });
+
+  it("close() pays the auctioneer after the deadline", async () => {
+    const first = (await bid(auction, alice, 1000n)).next!.instance;
+    expectValid(await close(first, DEADLINE));
+  });
+
+  it("close() rejects before the deadline", async () => {
+    const first = (await bid(auction, alice, 1000n)).next!.instance;
+    await expect(close(first, DEADLINE - 1)).to.be.rejectedWith(/Auction not ended/);
+  });
+
+  it("refund() returns the deposit when nobody bid", async () => {
+    auction.bindTxBuilder("refund", async (self: Auction) => ({
+      tx: callTx(self, [payTo(hash160(self.auctioneer), self.balance)]),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    const result = await auction.methods.refund(sigFrom(auctioneer), {
+      pubKeyOrAddrToSign: auctioneer.publicKey,
+    } as MethodCallOptions<Auction>);
+    expectValid(result);
+  });
+});
+"#;
+
+const TREASURY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import {
+  findSig,
+  FixedArray,
+  MethodCallOptions,
+  PubKey,
+  Sig,
+  SignatureResponse,
+  toByteString,
+} from "scrypt-ts";
+import Treasury, { SIGNER_COUNT } from "../contracts/Treasury.scrypt";
+import {
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  stateOutput,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const FAST_TRACK_LIMIT = 1_000n;
+const HIGH_VALUE_LIMIT = 100_000n;
+const CHANGE = 500n;
+
+describe("Treasury", () => {
+  const signers = [testKey(), testKey(), testKey()];
+  const recipient = testKey();
+  const proposer = testKey();
+  let treasury: Treasury;
+
+  before(async () => {
+    await Treasury.loadArtifact("artifacts/contracts/Treasury.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    treasury = new Treasury(
+      signers.map((signer) => signer.pubKey) as FixedArray<PubKey, typeof SIGNER_COUNT>,
+      2n,
+      FAST_TRACK_LIMIT,
+      HIGH_VALUE_LIMIT,
+    );
+    await treasury.connect(getDefaultSigner(...signers, proposer));
+    await treasury.deploy(200_000);
+  });
+
+  // Output 0: payment, output 1: remaining treasury, output 2: proposer change
+  function spend(current: Treasury, amount: bigint, approvers: number[]) {
+    current.bindTxBuilder("spend", async (self: Treasury) => {
+      const remaining = BigInt(self.balance) - amount;
+      const next = self.next();
+      next.executed = self.executed + 1n;
+
+      const outputs = [payTo(recipient.pkh, amount)];
+      if (remaining > 0n) {
+        outputs.push(stateOutput(next, remaining));
+      }
+      outputs.push(payTo(proposer.pkh, CHANGE));
+
+      const tx = await addFunding(callTx(self, outputs), self.signer);
+      const nexts = remaining > 0n ? [{ instance: next, atOutputIndex: 1, balance: Number(remaining) }] : [];
+      return { tx, atInputIndex: 0, nexts };
+    });
+
+    const approved = signers.map((_, i) => approvers.includes(i));
+    return current.methods.spend(
+      recipient.pkh,
+      amount,
+      proposer.pkh,
+      CHANGE,
+      (sigResps: SignatureResponse[]) =>
+        signers.map((signer, i) => (approved[i] ? findSig(sigResps, signer.publicKey) : Sig(toByteString("")))),
+      approved as FixedArray<boolean, typeof SIGNER_COUNT>,
+      { pubKeyOrAddrToSign: approvers.map((i) => signers[i].publicKey) } as MethodCallOptions<Treasury>,
+    );
+  }
+
+  it("spend() pays a fast-track amount with one approval", async () => {
+    const result = await spend(treasury, FAST_TRACK_LIMIT, [0]);
+    expectValid(result);
+
+    const next = result.next!.instance;
+    expect(next.executed).to.eq(1n);
+    expect(result.next!.balance).to.eq(199_000);
+  });
+
+  it("spend() needs the threshold above the fast-track limit", async () => {
+    await expect(spend(treasury, 5_000n, [1])).to.be.rejectedWith(/Not enough approvals/);
+    expectValid(await spend(treasury, 5_000n, [0, 2]));
+  });
+
+  it("spend() needs every co-signer above the high-value limit", async () => {
+    await expect(spend(treasury, 150_000n, [0, 1])).to.be.rejectedWith(/Not enough approvals/);
+    expectValid(await spend(treasury, 150_000n, [0, 1, 2]));
+  });
+
+  it("spend() can empty the treasury", async () => {
+    const result = await spend(treasury, 200_000n, [0, 1, 2]);
+    expectValid(result);
+    expect(result.nexts).to.be.empty;
+  });
+
+  it("spend() rejects more than the treasury holds", async () => {
+    await expect(spend(treasury, 200_001n, [0, 1, 2])).to.be.rejectedWith(/Insufficient treasury funds/);
+  });
+});
+"#;
+
+const ROCK_PAPER_SCISSORS_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, hash256, int2ByteString, MethodCallOptions, toByteString, Utils } from "scrypt-ts";
+import RockPaperScissors from "../contracts/RockPaperScissors.scrypt";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+  stateOutput,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const ROCK = 1n;
+const PAPER = 2n;
+const SCISSORS = 3n;
+
+const STAKE = 1000n;
+const JOIN_DEADLINE = 800_000;
+const REVEAL_DEADLINE = 800_144;
+const NONCE = toByteString("5eed".repeat(16));
+
+describe("RockPaperScissors", () => {
+  const player1 = testKey();
+  const player2 = testKey();
+
+  before(async () => {
+    await RockPaperScissors.loadArtifact("artifacts/contracts/RockPaperScissors.scrypt.json");
+  });
+
+  async function deploy(player1Move: bigint) {
+    const game = new RockPaperScissors(
+      player1.pubKey,
+      player2.pubKey,
+      hash256(NONCE + int2ByteString(player1Move)),
+      STAKE,
+      BigInt(JOIN_DEADLINE),
+      BigInt(REVEAL_DEADLINE),
+    );
+    await game.connect(getDefaultSigner(player1, player2));
+    await game.deploy(Number(STAKE));
+    return game;
+  }
+
+  async function play(current: RockPaperScissors, move: bigint) {
+    current.bindTxBuilder("play", async (self: RockPaperScissors) => {
+      const next = self.next();
+      next.player2Move = move;
+      const pot = Number(self.stake * 2n);
+      const tx = await addFunding(callTx(self, [stateOutput(next, pot)]), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: pot }] };
+    });
+    const result = await current.methods.play(move, sigFrom(player2), {
+      pubKeyOrAddrToSign: player2.publicKey,
+    } as MethodCallOptions<RockPaperScissors>);
+    expectValid(result);
+    return result.next!.instance;
+  }
+
+  function reveal(current: RockPaperScissors, move: bigint) {
+    current.bindTxBuilder("reveal", async (self: RockPaperScissors) => {
+      const pot = BigInt(self.balance);
+      const result = (move - self.player2Move + 3n) % 3n;
+      const outputs = result === 0n
+        ? [payTo(hash160(self.player1), pot / 2n), payTo(hash160(self.player2), pot - pot / 2n)]
+        : [payTo(hash160(result === 1n ? self.player1 : self.player2), pot)];
+      return { tx: callTx(self, outputs), atInputIndex: 0, nexts: [] };
+    });
+    return current.methods.reveal(move, NONCE, sigFrom(player1), {
+      pubKeyOrAddrToSign: player1.publicKey,
+    } as MethodCallOptions<RockPaperScissors>);
+  }
+
+  // claimTimeout() and cancel() both hand the whole pot to one player
+  function timeout(current: RockPaperScissors, method: "claimTimeout" | "cancel", player: TestKey, lockTime: number) {
+    current.bindTxBuilder(method, async (self: RockPaperScissors) => ({
+      tx: callTx(self, [payTo(player.pkh, self.balance)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return current.methods[method](sigFrom(player), {
+      pubKeyOrAddrToSign: player.publicKey,
+    } as MethodCallOptions<RockPaperScissors>);
+  }
+
+  it("play() records player 2's move and matches the stake", async () => {
+    const next = await play(await deploy(ROCK), PAPER);
+    expect(next.player2Move).to.eq(PAPER);
+    expect(next.balance).to.eq(Number(STAKE * 2n));
+  });
+
+  it("reveal() pays the winner", async () => {
+    const game = await play(await deploy(ROCK), SCISSORS);
+    const result = await reveal(game, ROCK);
+    expectValid(result);
+    expect(result.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(player1.pkh));
+    expect(result.tx.outputs[0].satoshis).to.eq(Number(STAKE * 2n));
+  });
+
+  it("reveal() splits the pot on a draw", async () => {
+    const game = await play(await deploy(PAPER), PAPER);
+    const result = await reveal(game, PAPER);
+    expectValid(result);
+    expect(result.tx.outputs[0].satoshis).to.eq(Number(STAKE));
+    expect(result.tx.outputs[1].satoshis).to.eq(Number(STAKE));
+  });
+
+  it("reveal() rejects a move that doesn't match the commitment", async () => {
+    const game = await play(await deploy(ROCK), PAPER);
+    await expect(reveal(game, SCISSORS)).to.be.rejectedWith(/Commitment mismatch/);
+  });
+
+  it("claimTimeout() pays player 2 once the reveal deadline passed", async () => {
+    const game = await play(await deploy(ROCK), PAPER);
+    await expect(timeout(game, "claimTimeout", player2, REVEAL_DEADLINE - 1)).to.be.rejectedWith(
+      /Reveal deadline not reached/,
+    );
+    expectValid(await timeout(game, "claimTimeout", player2, REVEAL_DEADLINE));
+  });
+
+  it("cancel() refunds player 1 when nobody joins", async () => {
+    const game = await deploy(ROCK);
+    await expect(timeout(game, "cancel", player1, JOIN_DEADLINE - 1)).to.be.rejectedWith(/Join deadline not reached/);
+    expectValid(await timeout(game, "cancel", player1, JOIN_DEADLINE));
+  });
+});
+"#;
+
+const LOTTERY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import {
+  byteString2Int,
+  hash160,
+  hash256,
+  MethodCallOptions,
+  PubKey,
+  sha256,
+  slice,
+  toByteString,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+import Lottery from "../contracts/Lottery.scrypt";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+  stateOutput,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const TICKET_PRICE = 1000n;
+const REVEAL_DEADLINE = 800_000;
+
+const secretOf = (i: number): ByteString => sha256(toByteString("secret " + i, true));
+
+// Same pick as Lottery.draw()
+function winnerOf(lottery: Lottery): PubKey {
+  let seed = toByteString("");
+  for (const secret of lottery.secrets) {
+    seed += secret;
+  }
+  const random = byteString2Int(slice(hash256(seed), 0n, 4n) + toByteString("00"));
+  const revealed = lottery.entrants.filter((_, i) => lottery.secrets[i] !== toByteString(""));
+  return revealed[Number(random % lottery.revealCount)];
+}
+
+describe("Lottery", () => {
+  const players = [testKey(), testKey(), testKey()];
+  let lottery: Lottery;
+
+  before(async () => {
+    await Lottery.loadArtifact("artifacts/contracts/Lottery.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    lottery = new Lottery(TICKET_PRICE, BigInt(REVEAL_DEADLINE));
+    await lottery.connect(getDefaultSigner(...players));
+    await lottery.deploy(1);
+  });
+
+  function enter(current: Lottery, entrant: TestKey, secret: ByteString) {
+    const commitment = hash256(secret);
+    current.bindTxBuilder("enter", async (self: Lottery) => {
+      const next = self.next();
+      next.entrants[Number(self.entryCount)] = entrant.pubKey;
+      next.commitments[Number(self.entryCount)] = commitment;
+      next.entryCount = self.entryCount + 1n;
+
+      const satoshis = self.balance + Number(self.ticketPrice);
+      const tx = await addFunding(callTx(self, [stateOutput(next, satoshis)]), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: satoshis }] };
+    });
+    return current.methods.enter(entrant.pubKey, commitment, sigFrom(entrant), {
+      pubKeyOrAddrToSign: entrant.publicKey,
+    } as MethodCallOptions<Lottery>);
+  }
+
+  function reveal(current: Lottery, index: number, secret: ByteString) {
+    current.bindTxBuilder("reveal", async (self: Lottery) => {
+      const next = self.next();
+      next.secrets[index] = secret;
+      next.revealCount = self.revealCount + 1n;
+      return {
+        tx: callTx(self, [stateOutput(next, self.balance)]),
+        atInputIndex: 0,
+        nexts: [{ instance: next, atOutputIndex: 0, balance: self.balance }],
+      };
+    });
+    return current.methods.reveal(BigInt(index), secret);
+  }
+
+  function draw(current: Lottery, lockTime?: number) {
+    current.bindTxBuilder("draw", async (self: Lottery) => ({
+      tx: callTx(self, [payTo(hash160(winnerOf(self)), self.balance)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return current.methods.draw({} as MethodCallOptions<Lottery>);
+  }
+
+  async function enterAll(count: number) {
+    let current = lottery;
+    for (let i = 0; i < count; i++) {
+      const result = await enter(current, players[i], secretOf(i));
+      expectValid(result);
+      current = result.next!.instance;
+    }
+    return current;
+  }
+
+  it("enter() sells a ticket", async () => {
+    const next = await enterAll(1);
+    expect(next.entryCount).to.eq(1n);
+    expect(next.entrants[0]).to.eq(players[0].pubKey);
+    expect(next.balance).to.eq(1 + Number(TICKET_PRICE));
+  });
+
+  it("reveal() opens a commitment", async () => {
+    const result = await reveal(await enterAll(2), 1, secretOf(1));
+    expectValid(result);
+    expect(result.next!.instance.revealCount).to.eq(1n);
+  });
+
+  it("reveal() rejects the wrong secret", async () => {
+    await expect(reveal(await enterAll(2), 0, secretOf(1))).to.be.rejectedWith(/Commitment mismatch/);
+  });
+
+  it("enter() closes once reveals start", async () => {
+    const revealed = (await reveal(await enterAll(2), 0, secretOf(0))).next!.instance;
+    await expect(enter(revealed, players[2], secretOf(2))).to.be.rejectedWith(/Entries closed/);
+  });
+
+  it("draw() pays the winner once everyone revealed", async () => {
+    let current = await enterAll(3);
+    for (let i = 0; i < 3; i++) {
+      current = (await reveal(current, i, secretOf(i))).next!.instance;
+    }
+
+    const result = await draw(current);
+    expectValid(result);
+    const winnerPkh = hash160(winnerOf(current));
+    expect(result.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(winnerPkh));
+    expect(result.tx.outputs[0].satoshis).to.eq(current.balance);
+  });
+
+  it("draw() waits for the deadline when someone didn't reveal", async () => {
+    const current = (await reveal(await enterAll(2), 0, secretOf(0))).next!.instance;
+    await expect(draw(current)).to.be.rejectedWith(/Reveal deadline not reached/);
+    expectValid(await draw(current, REVEAL_DEADLINE));
+  });
+});
+"#;
+
+const PREDICTION_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, MethodCallOptions, sha256, toByteString, ByteString, Utils } from "scrypt-ts";
+import { RabinSig } from "scrypt-ts-lib";
+import { Rabin } from "rabinsig";
+import PredictionMarket from "../contracts/PredictionMarket.scrypt";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+  stateOutput,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const STAKE = 1000n;
+const ACCEPT_DEADLINE = 800_000;
+const SETTLE_DEADLINE = 801_000;
+const EVENT_ID = sha256(toByteString("Will it rain tomorrow?", true));
+
+// Stands in for oracle/server.mjs
+const rabin = new Rabin();
+const oracleKey = rabin.generatePrivKey();
+
+function attest(eventId: ByteString, outcome: "yes" | "no", privKey = oracleKey) {
+  const msg = eventId + (outcome === "yes" ? "01" : "00");
+  const { signature, paddingByteCount } = rabin.sign(msg, privKey);
+  const sig: RabinSig = { s: signature, padding: toByteString("00".repeat(paddingByteCount)) };
+  return { msg: toByteString(msg), sig };
+}
+
+describe("PredictionMarket", () => {
+  const yesPlayer = testKey();
+  const noPlayer = testKey();
+  let market: PredictionMarket;
+
+  before(async () => {
+    await PredictionMarket.loadArtifact("artifacts/contracts/PredictionMarket.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    market = new PredictionMarket(
+      rabin.privKeyToPubKey(oracleKey),
+      EVENT_ID,
+      yesPlayer.pubKey,
+      noPlayer.pubKey,
+      STAKE,
+      BigInt(ACCEPT_DEADLINE),
+      BigInt(SETTLE_DEADLINE),
+    );
+    await market.connect(getDefaultSigner(yesPlayer, noPlayer));
+    await market.deploy(Number(STAKE));
+  });
+
+  async function accept(current: PredictionMarket) {
+    current.bindTxBuilder("accept", async (self: PredictionMarket) => {
+      const next = self.next();
+      next.accepted = true;
+      const pot = Number(self.stake * 2n);
+      const tx = await addFunding(callTx(self, [stateOutput(next, pot)]), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: pot }] };
+    });
+    const result = await current.methods.accept(sigFrom(noPlayer), {
+      pubKeyOrAddrToSign: noPlayer.publicKey,
+    } as MethodCallOptions<PredictionMarket>);
+    expectValid(result);
+    return result.next!.instance;
+  }
+
+  function settle(current: PredictionMarket, attestation: ReturnType<typeof attest>, winner: TestKey) {
+    current.bindTxBuilder("settle", async (self: PredictionMarket) => ({
+      tx: callTx(self, [payTo(winner.pkh, self.balance)]),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return current.methods.settle(attestation.msg, attestation.sig);
+  }
+
+  it("accept() matches the stake", async () => {
+    const next = await accept(market);
+    expect(next.accepted).to.eq(true);
+    expect(next.balance).to.eq(Number(STAKE * 2n));
+  });
+
+  it("settle() pays the YES player on a yes attestation", async () => {
+    const result = await settle(await accept(market), attest(EVENT_ID, "yes"), yesPlayer);
+    expectValid(result);
+    expect(result.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(yesPlayer.pkh));
+  });
+
+  it("settle() pays the NO player on a no attestation", async () => {
+    expectValid(await settle(await accept(market), attest(EVENT_ID, "no"), noPlayer));
+  });
+
+  it("settle() rejects an attestation for another event", async () => {
+    const other = attest(sha256(toByteString("Another question", true)), "yes");
+    await expect(settle(await accept(market), other, yesPlayer)).to.be.rejectedWith(
+      /Attestation is for another event/,
+    );
+  });
+
+  it("settle() rejects a signature from another oracle", async () => {
+    const forged = attest(EVENT_ID, "yes", rabin.generatePrivKey());
+    await expect(settle(await accept(market), forged, yesPlayer)).to.be.rejectedWith(/Invalid oracle signature/);
+  });
+
+  it("refund() returns both stakes after the settle deadline", async () => {
+    const accepted = await accept(market);
+    accepted.bindTxBuilder("refund", async (self: PredictionMarket) => ({
+      tx: callTx(
+        self,
+        [payTo(hash160(self.yesPlayer), self.stake), payTo(hash160(self.noPlayer), BigInt(self.balance) - self.stake)],
+        SETTLE_DEADLINE,
+      ),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    expectValid(await accepted.methods.refund());
+  });
+
+  it("cancel() returns the YES stake when nobody accepts", async () => {
+    market.bindTxBuilder("cancel", async (self: PredictionMarket) => ({
+      tx: callTx(self, [payTo(hash160(self.yesPlayer), self.balance)], ACCEPT_DEADLINE),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    const result = await market.methods.cancel(sigFrom(yesPlayer), {
+      pubKeyOrAddrToSign: yesPlayer.publicKey,
+    } as MethodCallOptions<PredictionMarket>);
+    expectValid(result);
+  });
+});
+"#;
+
+const ORDINAL_LISTING_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, MethodCallOptions, Utils } from "scrypt-ts";
+import OrdinalListing from "../contracts/OrdinalListing.scrypt";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const PRICE = 5000n;
+
+describe("OrdinalListing", () => {
+  const seller = testKey();
+  const buyer = testKey();
+  let listing: OrdinalListing;
+
+  before(async () => {
+    await OrdinalListing.loadArtifact("artifacts/contracts/OrdinalListing.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    listing = new OrdinalListing(seller.pubKey, PRICE);
+    await listing.connect(getDefaultSigner(seller, buyer));
+    // The inscribed sat
+    await listing.deploy(1);
+  });
+
+  // Output 0: the ordinal to the buyer, output 1: payment to the seller
+  function purchase(paid = PRICE) {
+    listing.bindTxBuilder("purchase", async (self: OrdinalListing) => {
+      const outputs = [payTo(buyer.pkh, 1), payTo(hash160(self.seller), paid)];
+      return { tx: await addFunding(callTx(self, outputs), self.signer), atInputIndex: 0, nexts: [] };
+    });
+    return listing.methods.purchase(buyer.pkh);
+  }
+
+  function cancel(signer: TestKey) {
+    listing.bindTxBuilder("cancel", async (self: OrdinalListing) => ({
+      tx: callTx(self, [payTo(hash160(self.seller), self.balance)]),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return listing.methods.cancel(sigFrom(signer), {
+      pubKeyOrAddrToSign: signer.publicKey,
+    } as MethodCallOptions<OrdinalListing>);
+  }
+
+  it("purchase() sends the ordinal to the buyer and pays the seller", async () => {
+    const result = await purchase();
+    expectValid(result);
+    expect(result.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(buyer.pkh));
+    expect(result.tx.outputs[0].satoshis).to.eq(1);
+    expect(result.tx.outputs[1].satoshis).to.eq(Number(PRICE));
+  });
+
+  it("purchase() rejects underpaying the seller", async () => {
+    await expect(purchase(PRICE - 1n)).to.be.rejectedWith(/outputs mismatch/);
+  });
+
+  it("cancel() lets the seller take the ordinal back", async () => {
+    expectValid(await cancel(seller));
+  });
+
+  it("cancel() rejects anyone but the seller", async () => {
+    await expect(cancel(buyer)).to.be.rejectedWith(/Invalid seller signature/);
+  });
+});
+"#;
+
+const TOKEN_MINT_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { Addr, toByteString } from "scrypt-ts";
+import { BSV20V2 } from "scrypt-ord";
+import TokenMint from "../contracts/TokenMint.scrypt";
+import {
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  outputFromBytes,
+  testKey,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const MAX_SUPPLY = 1000n;
+const LIMIT = 100n;
+
+describe("TokenMint", () => {
+  const minter = testKey();
+  const dest = Addr(minter.pkh);
+  let token: TokenMint;
+
+  before(async () => {
+    await TokenMint.loadArtifact("artifacts/contracts/TokenMint.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    token = new TokenMint(toByteString(""), toByteString("TEST", true), MAX_SUPPLY, 0n, LIMIT);
+    await token.connect(getDefaultSigner(minter));
+    await token.deployToken();
+  });
+
+  // Output 0: the covenant with the remaining supply, then the minted tokens
+  function mint(current: TokenMint, amount: bigint) {
+    current.bindTxBuilder("mint", async (self: TokenMint) => {
+      const supply = self.supply - amount;
+      const outputs = [];
+      const nexts = [];
+      if (supply > 0n) {
+        const next = self.next();
+        next.supply = supply;
+        outputs.push(outputFromBytes(next.buildStateOutputFT(supply)));
+        nexts.push({ instance: next, atOutputIndex: 0, balance: 1 });
+      }
+      outputs.push(outputFromBytes(BSV20V2.buildTransferOutput(dest, self.id, amount)));
+
+      const tx = await addFunding(callTx(self, outputs), self.signer);
+      return { tx, atInputIndex: 0, nexts };
+    });
+    return current.methods.mint(dest, amount);
+  }
+
+  it("mint() releases tokens and keeps the rest in the covenant", async () => {
+    const result = await mint(token, LIMIT);
+    expectValid(result);
+    expect(result.next!.instance.supply).to.eq(MAX_SUPPLY - LIMIT);
+  });
+
+  it("mint() rejects more than the per-call limit", async () => {
+    await expect(mint(token, LIMIT + 1n)).to.be.rejectedWith(/Mint amount exceeds limit/);
+  });
+
+  it("mint() closes the covenant with the last tokens", async () => {
+    let current = token;
+    for (let minted = 0n; minted < MAX_SUPPLY - LIMIT; minted += LIMIT) {
+      current = (await mint(current, LIMIT)).next!.instance;
+    }
+
+    const result = await mint(current, LIMIT);
+    expectValid(result);
+    expect(result.nexts).to.be.empty;
+  });
+});
+"#;
+
+const PAYMENT_CHANNEL_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { MethodCallOptions, Utils } from "scrypt-ts";
+import PaymentChannel from "../contracts/PaymentChannel.scrypt";
+import {
+  TestKey,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const DEPOSIT = 10_000;
+const FEE = 500n;
+const EXPIRY = 800_000;
+
+describe("PaymentChannel", () => {
+  const sessionKey = testKey();
+  const sender = testKey();
+  const receiver = testKey();
+  let channel: PaymentChannel;
+
+  before(async () => {
+    await PaymentChannel.loadArtifact("artifacts/contracts/PaymentChannel.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    channel = new PaymentChannel(sessionKey.pubKey, sender.pkh, receiver.pubKey, FEE, BigInt(EXPIRY));
+    await channel.connect(getDefaultSigner(sessionKey, receiver));
+    await channel.deploy(DEPOSIT);
+  });
+
+  // Same layout as buildCloseTx() in channelService.ts: no inputs but the
+  // deposit, fee taken from it
+  function close(amount: bigint, receiverSigner: TestKey = receiver) {
+    channel.bindTxBuilder("close", async (self: PaymentChannel) => {
+      const available = BigInt(self.balance) - self.fee;
+      const outputs = [payTo(receiver.pkh, amount)];
+      if (amount < available) {
+        outputs.push(payTo(self.senderPkh, available - amount));
+      }
+      return { tx: callTx(self, outputs), atInputIndex: 0, nexts: [] };
+    });
+    return channel.methods.close(amount, sigFrom(sessionKey), sigFrom(receiverSigner), {
+      pubKeyOrAddrToSign: [sessionKey.publicKey, receiverSigner.publicKey],
+    } as MethodCallOptions<PaymentChannel>);
+  }
+
+  function refund(lockTime: number) {
+    channel.bindTxBuilder("refund", async (self: PaymentChannel) => ({
+      tx: callTx(self, [payTo(self.senderPkh, BigInt(self.balance) - self.fee)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return channel.methods.refund();
+  }
+
+  it("close() pays the receiver and returns the rest to the sender", async () => {
+    const result = await close(3000n);
+    expectValid(result);
+    expect(result.tx.outputs[0].satoshis).to.eq(3000);
+    expect(result.tx.outputs[1].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(sender.pkh));
+    expect(result.tx.outputs[1].satoshis).to.eq(DEPOSIT - Number(FEE) - 3000);
+  });
+
+  it("close() can pay out the whole deposit", async () => {
+    const result = await close(BigInt(DEPOSIT) - FEE);
+    expectValid(result);
+    expect(result.tx.outputs).to.have.length(1);
+  });
+
+  it("close() rejects more than the deposit", async () => {
+    await expect(close(BigInt(DEPOSIT))).to.be.rejectedWith(/Invalid amount/);
+  });
+
+  it("close() needs the receiver's signature", async () => {
+    await expect(close(3000n, sessionKey)).to.be.rejectedWith(/Invalid receiver signature/);
+  });
+
+  it("refund() returns the deposit once the channel expired", async () => {
+    await expect(refund(EXPIRY - 1)).to.be.rejectedWith(/Channel not expired/);
+    expectValid(await refund(EXPIRY));
+  });
+});
+"#;
+
+const ESCROW_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, MethodCallOptions, toByteString, PubKey } from "scrypt-ts";
+import Escrow from "../contracts/Escrow.scrypt";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+  stateOutput,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const PRICE = 5000n;
+const REVIEW_BLOCKS = 432n;
+const CHECKOUT_HEIGHT = 800_000;
+const DEADLINE = CHECKOUT_HEIGHT + Number(REVIEW_BLOCKS);
+
+describe("Escrow", () => {
+  const seller = testKey();
+  const buyer = testKey();
+  const arbiter = testKey();
+  let listing: Escrow;
+
+  before(async () => {
+    await Escrow.loadArtifact("artifacts/contracts/Escrow.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    listing = new Escrow(seller.pubKey, arbiter.pubKey, toByteString("Vintage camera", true), PRICE, REVIEW_BLOCKS);
+    await listing.connect(getDefaultSigner(seller, buyer, arbiter));
+    await listing.deploy(1);
+  });
+
+  const sign = (key: TestKey) => ({ pubKeyOrAddrToSign: key.publicKey }) as MethodCallOptions<Escrow>;
+
+  async function checkout(current = listing) {
+    current.bindTxBuilder("checkout", async (self: Escrow) => {
+      const next = self.next();
+      next.buyer = buyer.pubKey;
+      next.status = 1n;
+      next.deadline = BigInt(CHECKOUT_HEIGHT) + self.reviewBlocks;
+      const satoshis = self.balance + Number(self.price);
+      const tx = await addFunding(callTx(self, [stateOutput(next, satoshis)], CHECKOUT_HEIGHT), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: satoshis }] };
+    });
+    const result = await current.methods.checkout(buyer.pubKey, sigFrom(buyer), sign(buyer));
+    expectValid(result);
+    return result.next!.instance;
+  }
+
+  async function dispute(current: Escrow, by: TestKey) {
+    current.bindTxBuilder("dispute", async (self: Escrow) => {
+      const next = self.next();
+      next.status = 2n;
+      return {
+        tx: callTx(self, [stateOutput(next, self.balance)]),
+        atInputIndex: 0,
+        nexts: [{ instance: next, atOutputIndex: 0, balance: self.balance }],
+      };
+    });
+    const result = await current.methods.dispute(sigFrom(by), by === buyer, sign(by));
+    expectValid(result);
+    return result.next!.instance;
+  }
+
+  // delist(), release() and refund() pay the whole escrow to one party
+  function payout(current: Escrow, method: "delist" | "release" | "refund", signer: TestKey, recipient: PubKey) {
+    current.bindTxBuilder(method, async (self: Escrow) => ({
+      tx: callTx(self, [payTo(hash160(recipient), self.balance)]),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return current.methods[method](sigFrom(signer), sign(signer));
+  }
+
+  function resolve(current: Escrow, signer: TestKey, sellerAmount: bigint) {
+    current.bindTxBuilder("resolve", async (self: Escrow) => {
+      const total = BigInt(self.balance);
+      const outputs = [];
+      if (sellerAmount > 0n) {
+        outputs.push(payTo(hash160(self.seller), sellerAmount));
+      }
+      if (sellerAmount < total) {
+        outputs.push(payTo(hash160(self.buyer), total - sellerAmount));
+      }
+      return { tx: callTx(self, outputs), atInputIndex: 0, nexts: [] };
+    });
+    return current.methods.resolve(sigFrom(signer), sellerAmount, sign(signer));
+  }
+
+  function autoRelease(current: Escrow, lockTime: number) {
+    current.bindTxBuilder("autoRelease", async (self: Escrow) => ({
+      tx: callTx(self, [payTo(hash160(self.seller), self.balance)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return current.methods.autoRelease();
+  }
+
+  it("checkout() takes the payment and starts the review period", async () => {
+    const paid = await checkout();
+    expect(paid.buyer).to.eq(buyer.pubKey);
+    expect(paid.status).to.eq(1n);
+    expect(paid.deadline).to.eq(BigInt(DEADLINE));
+    expect(paid.balance).to.eq(1 + Number(PRICE));
+  });
+
+  it("delist() returns the listing to the seller", async () => {
+    expectValid(await payout(listing, "delist", seller, seller.pubKey));
+  });
+
+  it("delist() is closed once the buyer paid", async () => {
+    await expect(payout(await checkout(), "delist", seller, seller.pubKey)).to.be.rejectedWith(/Already sold/);
+  });
+
+  it("release() pays the seller when the buyer confirms", async () => {
+    expectValid(await payout(await checkout(), "release", buyer, seller.pubKey));
+  });
+
+  it("refund() returns the payment when the seller agrees", async () => {
+    expectValid(await payout(await checkout(), "refund", seller, buyer.pubKey));
+  });
+
+  it("resolve() lets the arbiter split a disputed escrow", async () => {
+    const disputed = await dispute(await checkout(), buyer);
+    expect(disputed.status).to.eq(2n);
+    expectValid(await resolve(disputed, arbiter, 3000n));
+  });
+
+  it("resolve() rejects anyone but the arbiter", async () => {
+    const disputed = await dispute(await checkout(), seller);
+    await expect(resolve(disputed, seller, BigInt(disputed.balance))).to.be.rejectedWith(
+      /Invalid arbiter signature/,
+    );
+  });
+
+  it("autoRelease() pays the seller after an undisputed review period", async () => {
+    const paid = await checkout();
+    await expect(autoRelease(paid, DEADLINE - 1)).to.be.rejectedWith(/Review period not over/);
+    expectValid(await autoRelease(paid, DEADLINE));
+  });
+
+  it("autoRelease() is blocked by a dispute", async () => {
+    const disputed = await dispute(await checkout(), buyer);
+    await expect(autoRelease(disputed, DEADLINE)).to.be.rejectedWith(/Not in review/);
+  });
+});
+"#;
+
+const PLAYGROUND_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import Playground from "../contracts/Playground.scrypt";
+import { expectValid, getDefaultSigner, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+describe("Playground", () => {
+  const owner = testKey();
+  let instance: Playground;
+
+  before(async () => {
+    await Playground.loadArtifact("artifacts/contracts/Playground.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    instance = new Playground(49n);
+    await instance.connect(getDefaultSigner(owner));
+    await instance.deploy(1);
+  });
+
+  it("unlock() accepts the square root", async () => {
+    expectValid(await instance.methods.unlock(7n));
+  });
+
+  it("unlock() accepts the negative root too", async () => {
+    expectValid(await instance.methods.unlock(-7n));
+  });
+
+  it("unlock() rejects anything else", async () => {
+    await expect(instance.methods.unlock(6n)).to.be.rejectedWith(/Not a square root/);
+  });
+});
+"#;
+
+const CUSTOM_CONTRACT_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, MethodCallOptions, Utils } from "scrypt-ts";
+import Contract from "../contracts/Contract.scrypt";
+import {
+  TestKey,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  testKey,
+  sigFrom,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+// Starting point - update alongside contracts/Contract.scrypt.ts as the
+// business logic takes shape
+
+const TIMEOUT = 800_000;
+
+describe("Contract", () => {
+  const player1 = testKey();
+  const player2 = testKey();
+  let instance: Contract;
+
+  before(async () => {
+    await Contract.loadArtifact("artifacts/contracts/Contract.scrypt.json");
+  });
+
+  beforeEach(async () => {
+    instance = new Contract(player1.pubKey, player2.pubKey, BigInt(TIMEOUT));
+    await instance.connect(getDefaultSigner(player1, player2));
+    await instance.deploy(1000);
+  });
+
+  function settle(callerIsPlayer1: boolean, signer: TestKey) {
+    instance.bindTxBuilder("settle", async (self: Contract) => {
+      const caller = callerIsPlayer1 ? self.player1 : self.player2;
+      return { tx: callTx(self, [payTo(hash160(caller), self.balance)]), atInputIndex: 0, nexts: [] };
+    });
+    return instance.methods.settle(callerIsPlayer1, sigFrom(signer), {
+      pubKeyOrAddrToSign: signer.publicKey,
+    } as MethodCallOptions<Contract>);
+  }
+
+  function claimAfterTimeout(lockTime: number) {
+    instance.bindTxBuilder("claimAfterTimeout", async (self: Contract) => ({
+      tx: callTx(self, [payTo(hash160(self.player1), self.balance)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return instance.methods.claimAfterTimeout(sigFrom(player1), {
+      pubKeyOrAddrToSign: player1.publicKey,
+    } as MethodCallOptions<Contract>);
+  }
+
+  it("settle() pays the calling player", async () => {
+    const result = await settle(false, player2);
+    expectValid(result);
+    expect(result.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(player2.pkh));
+  });
+
+  it("settle() rejects a signature from the other player", async () => {
+    await expect(settle(true, player2)).to.be.rejectedWith(/sig required/);
+  });
+
+  it("claimAfterTimeout() works once the timeout is reached", async () => {
+    await expect(claimAfterTimeout(TIMEOUT - 1)).to.be.rejectedWith(/timeout not reached/);
+    expectValid(await claimAfterTimeout(TIMEOUT));
+  });
+});
+"#;
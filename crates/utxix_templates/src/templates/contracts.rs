@@ -0,0 +1,1482 @@
+//! The sCrypt contract each template starts from, written to `contracts/`.
+
+use crate::Template;
+
+pub fn contract_filename(template: Template) -> &'static str {
+    match template {
+        Template::HelloWorld => "HelloWorld.scrypt.ts",
+        Template::Counter => "Counter.scrypt.ts",
+        Template::TicTacToe => "TicTacToe.scrypt.ts",
+        Template::Auction => "Auction.scrypt.ts",
+        Template::Treasury => "Treasury.scrypt.ts",
+        Template::RockPaperScissors => "RockPaperScissors.scrypt.ts",
+        Template::Lottery => "Lottery.scrypt.ts",
+        Template::Prediction => "PredictionMarket.scrypt.ts",
+        Template::NftMarketplace => "OrdinalListing.scrypt.ts",
+        Template::Token => "TokenMint.scrypt.ts",
+        Template::StreamingPayments => "PaymentChannel.scrypt.ts",
+        Template::Marketplace => "Escrow.scrypt.ts",
+        Template::Playground => "Playground.scrypt.ts",
+        Template::Custom => "Contract.scrypt.ts",
+    }
+}
+
+pub fn contract_source(template: Template, description: Option<&str>) -> String {
+    match template {
+        Template::HelloWorld => HELLOWORLD_CONTRACT.to_string(),
+        Template::Counter => COUNTER_CONTRACT.to_string(),
+        Template::TicTacToe => TICTACTOE_CONTRACT.to_string(),
+        Template::Auction => AUCTION_CONTRACT.to_string(),
+        Template::Treasury => TREASURY_CONTRACT.to_string(),
+        Template::RockPaperScissors => ROCK_PAPER_SCISSORS_CONTRACT.to_string(),
+        Template::Lottery => LOTTERY_CONTRACT.to_string(),
+        Template::Prediction => PREDICTION_CONTRACT.to_string(),
+        Template::NftMarketplace => ORDINAL_LISTING_CONTRACT.to_string(),
+        Template::Token => TOKEN_MINT_CONTRACT.to_string(),
+        Template::StreamingPayments => PAYMENT_CHANNEL_CONTRACT.to_string(),
+        Template::Marketplace => ESCROW_CONTRACT.to_string(),
+        Template::Playground => PLAYGROUND_CONTRACT.to_string(),
+        Template::Custom => generate_custom_contract(description.unwrap_or("Custom Bitcoin app")),
+    }
+}
+
+pub const HELLOWORLD_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  ByteString,
+  sha256,
+  assert,
+} from "scrypt-ts";
+
+export class HelloWorld extends SmartContract {
+  @prop()
+  hash: ByteString;
+
+  constructor(hash: ByteString) {
+    super(...arguments);
+    this.hash = hash;
+  }
+
+  @method()
+  public unlock(message: ByteString) {
+    assert(sha256(message) === this.hash, "Hash mismatch");
+  }
+}
+
+export default HelloWorld;
+"#;
+
+pub const COUNTER_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  SigHash,
+} from "scrypt-ts";
+
+export class Counter extends SmartContract {
+  @prop(true)
+  count: bigint;
+
+  constructor(count: bigint) {
+    super(...arguments);
+    this.count = count;
+  }
+
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public increment() {
+    this.count++;
+
+    const outputs = this.buildStateOutput(this.ctx.utxo.value);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "hashOutputs mismatch");
+  }
+}
+
+export default Counter;
+"#;
+
+pub const TICTACTOE_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  FixedArray,
+  assert,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  hash256,
+  hash160,
+  SigHash,
+  Utils,
+  ByteString,
+  toByteString,
+} from "scrypt-ts";
+
+/**
+ * Tic-Tac-Toe Smart Contract
+ * 
+ * Board: 0 = empty, 1 = X, 2 = O
+ * 
+ * Uses ANYONECANPAY_SINGLE sighash:
+ * - ANYONECANPAY: allows anyone to add inputs (for fees)
+ * - SINGLE: only verifies output at index 0
+ * 
+ * Tx structure:
+ * - Input 0: Contract UTXO
+ * - Input 1+: Fee UTXOs (added by custom tx builder)
+ * - Output 0: Next state or winner payout (verified by contract)
+ * - Output 1+: Change (not verified)
+ */
+export class TicTacToe extends SmartContract {
+  @prop(true)
+  board: FixedArray<bigint, 9>;
+
+  @prop(true)
+  isXTurn: boolean;
+
+  @prop()
+  playerX: PubKey;
+
+  @prop()
+  playerO: PubKey;
+
+  @prop()
+  stake: bigint;
+
+  constructor(playerX: PubKey, playerO: PubKey, stake: bigint) {
+    super(...arguments);
+    this.board = [0n, 0n, 0n, 0n, 0n, 0n, 0n, 0n, 0n];
+    this.isXTurn = true;
+    this.playerX = playerX;
+    this.playerO = playerO;
+    this.stake = stake;
+  }
+
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public move(position: bigint, sig: Sig) {
+    const currentPlayer = this.isXTurn ? this.playerX : this.playerO;
+    assert(this.checkSig(sig, currentPlayer), "Invalid signature");
+
+    assert(position >= 0n && position < 9n, "Invalid position");
+    assert(this.board[Number(position)] === 0n, "Position occupied");
+
+    this.board[Number(position)] = this.isXTurn ? 1n : 2n;
+    
+    const winner = this.checkWinner();
+    let outputs: ByteString = toByteString("");
+    
+    if (winner !== 0n) {
+      // Game over - pay winner
+      const winnerPubKey = winner === 1n ? this.playerX : this.playerO;
+      const winnerPkh: PubKeyHash = hash160(winnerPubKey);
+      outputs = Utils.buildPublicKeyHashOutput(winnerPkh, this.ctx.utxo.value);
+    } else if (this.isBoardFull()) {
+      // Draw - split pot (each gets half)
+      const halfValue = this.ctx.utxo.value / 2n;
+      const xPkh: PubKeyHash = hash160(this.playerX);
+      outputs = Utils.buildPublicKeyHashOutput(xPkh, halfValue);
+      // Note: Output 1 (playerO) built by frontend, not verified with SINGLE
+    } else {
+      // Game continues - update state
+      this.isXTurn = !this.isXTurn;
+      outputs = this.buildStateOutput(this.ctx.utxo.value);
+    }
+    
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  @method()
+  isBoardFull(): boolean {
+    let full = true;
+    for (let i = 0; i < 9; i++) {
+      if (this.board[i] === 0n) {
+        full = false;
+      }
+    }
+    return full;
+  }
+
+  @method()
+  checkWinner(): bigint {
+    // Winning combinations
+    const lines: FixedArray<FixedArray<bigint, 3>, 8> = [
+      [0n, 1n, 2n], [3n, 4n, 5n], [6n, 7n, 8n], // rows
+      [0n, 3n, 6n], [1n, 4n, 7n], [2n, 5n, 8n], // cols
+      [0n, 4n, 8n], [2n, 4n, 6n]                 // diagonals
+    ];
+    
+    for (let i = 0; i < 8; i++) {
+      const a = Number(lines[i][0]);
+      const b = Number(lines[i][1]);
+      const c = Number(lines[i][2]);
+      if (this.board[a] !== 0n && 
+          this.board[a] === this.board[b] && 
+          this.board[a] === this.board[c]) {
+        return this.board[a]; // 1 for X, 2 for O
+      }
+    }
+    return 0n; // No winner
+  }
+}
+
+export default TicTacToe;
+"#;
+
+pub const AUCTION_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  assert,
+  hash256,
+  hash160,
+  SigHash,
+  Utils,
+  ByteString,
+} from "scrypt-ts";
+
+/**
+ * Auction Smart Contract
+ * 
+ * Uses ANYONECANPAY_SINGLE sighash:
+ * - ANYONECANPAY: allows bidders to add their funding inputs
+ * - SINGLE: only verifies output at index 0 (contract state or payout)
+ * 
+ * Flow:
+ * 1. Auctioneer deploys with initial state
+ * 2. Bidders call bid() with higher amounts
+ * 3. Previous bidder gets refunded (output 1, not verified)
+ * 4. After deadline, anyone can call close() to pay auctioneer
+ */
+export class Auction extends SmartContract {
+  @prop()
+  auctioneer: PubKey;
+
+  @prop(true)
+  highestBidder: PubKey;
+
+  @prop(true)
+  highestBid: bigint;
+
+  @prop()
+  auctionDeadline: bigint;
+
+  constructor(auctioneer: PubKey, deadline: bigint) {
+    super(...arguments);
+    this.auctioneer = auctioneer;
+    this.highestBidder = auctioneer;
+    this.highestBid = 0n;
+    this.auctionDeadline = deadline;
+  }
+
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public bid(bidder: PubKey, bidAmount: bigint) {
+    // Ensure auction is still active (before deadline)
+    assert(this.ctx.locktime < this.auctionDeadline, "Auction ended");
+
+    // Ensure bid is higher than current highest
+    assert(bidAmount > this.highestBid, "Bid too low");
+
+    // Store previous bidder for refund (built by frontend at output 1)
+    const prevBidder = this.highestBidder;
+    const prevBid = this.highestBid;
+
+    // Update state
+    this.highestBidder = bidder;
+    this.highestBid = bidAmount;
+
+    // Output 0: Updated contract state with new bid amount
+    // Note: Frontend must also build output 1 with refund to prevBidder
+    let outputs: ByteString = this.buildStateOutput(bidAmount);
+    
+    // If there was a previous bid, verify refund output exists
+    // (With SINGLE, we only verify output 0, but we can check total outputs)
+    if (prevBid > 0n) {
+      // Frontend must add: Utils.buildPublicKeyHashOutput(hash160(prevBidder), prevBid)
+      // as output 1 in the custom tx builder
+    }
+    
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public close(sig: Sig) {
+    // Only auctioneer can close
+    assert(this.checkSig(sig, this.auctioneer), "Not auctioneer");
+
+    // Ensure deadline has passed
+    assert(this.ctx.locktime >= this.auctionDeadline, "Auction not ended");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+
+    // Pay the winning bid to auctioneer
+    const auctioneerPkh: PubKeyHash = hash160(this.auctioneer);
+    const outputs = Utils.buildPublicKeyHashOutput(auctioneerPkh, this.highestBid);
+    
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public refund(sig: Sig) {
+    // If no bids, auctioneer can reclaim deposit
+    assert(this.checkSig(sig, this.auctioneer), "Not auctioneer");
+    assert(this.highestBid === 0n, "Has bids");
+    
+    const auctioneerPkh: PubKeyHash = hash160(this.auctioneer);
+    const outputs = Utils.buildPublicKeyHashOutput(auctioneerPkh, this.ctx.utxo.value);
+    
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+}
+
+export default Auction;
+"#;
+
+pub const TREASURY_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  FixedArray,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+
+// Number of co-signers (N)
+export const SIGNER_COUNT = 3;
+
+/**
+ * Treasury Smart Contract
+ *
+ * M-of-N multisig vault with spending-limit tiers:
+ * - amount <= fastTrackLimit  → any 1 co-signer
+ * - amount <= highValueLimit  → threshold (M) co-signers
+ * - amount >  highValueLimit  → all N co-signers
+ *
+ * Uses SigHash.ALL: every co-signer signs the exact same proposal
+ * transaction, so the proposal (including fee input and change) must be
+ * fixed before the first approval is collected.
+ *
+ * Tx structure:
+ * - Input 0: Treasury UTXO
+ * - Input 1: Fee UTXO (proposer's wallet)
+ * - Output 0: Payment to recipient
+ * - Output 1: Remaining funds back into the treasury (next state)
+ * - Output 2: Proposer's change
+ */
+export class Treasury extends SmartContract {
+  @prop()
+  signers: FixedArray<PubKey, typeof SIGNER_COUNT>;
+
+  @prop()
+  threshold: bigint;
+
+  @prop()
+  fastTrackLimit: bigint;
+
+  @prop()
+  highValueLimit: bigint;
+
+  // Number of executed proposals
+  @prop(true)
+  executed: bigint;
+
+  constructor(
+    signers: FixedArray<PubKey, typeof SIGNER_COUNT>,
+    threshold: bigint,
+    fastTrackLimit: bigint,
+    highValueLimit: bigint,
+  ) {
+    super(...arguments);
+    this.signers = signers;
+    this.threshold = threshold;
+    this.fastTrackLimit = fastTrackLimit;
+    this.highValueLimit = highValueLimit;
+    this.executed = 0n;
+  }
+
+  @method()
+  public spend(
+    recipient: PubKeyHash,
+    amount: bigint,
+    changePkh: PubKeyHash,
+    changeAmount: bigint,
+    sigs: FixedArray<Sig, typeof SIGNER_COUNT>,
+    approved: FixedArray<boolean, typeof SIGNER_COUNT>,
+  ) {
+    assert(amount > 0n, "Amount must be positive");
+    assert(amount <= this.ctx.utxo.value, "Insufficient treasury funds");
+
+    // Count approvals - only flagged co-signers are checked
+    let approvals = 0n;
+    for (let i = 0; i < SIGNER_COUNT; i++) {
+      if (approved[i]) {
+        assert(this.checkSig(sigs[i], this.signers[i]), "Invalid co-signer signature");
+        approvals++;
+      }
+    }
+    assert(approvals >= this.requiredApprovals(amount), "Not enough approvals");
+
+    this.executed++;
+
+    // Output 0: payment, output 1: remaining treasury, output 2: change
+    let outputs: ByteString = Utils.buildPublicKeyHashOutput(recipient, amount);
+    const remaining = this.ctx.utxo.value - amount;
+    if (remaining > 0n) {
+      outputs += this.buildStateOutput(remaining);
+    }
+    outputs += Utils.buildPublicKeyHashOutput(changePkh, changeAmount);
+
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  @method()
+  requiredApprovals(amount: bigint): bigint {
+    let required = this.threshold;
+    if (amount <= this.fastTrackLimit) {
+      required = 1n;
+    } else if (amount > this.highValueLimit) {
+      // All co-signers. SIGNER_COUNT is a compile-time constant, which sCrypt
+      // accepts as a loop bound but not as a bigint value.
+      required = 0n;
+      for (let i = 0; i < SIGNER_COUNT; i++) {
+        required++;
+      }
+    }
+    return required;
+  }
+}
+
+export default Treasury;
+"#;
+
+pub const ROCK_PAPER_SCISSORS_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  hash160,
+  int2ByteString,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  SigHash,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+
+/**
+ * Rock-Paper-Scissors Smart Contract (commit-reveal)
+ *
+ * Moves: 1 = rock, 2 = paper, 3 = scissors
+ *
+ * Flow:
+ * 1. Player 1 deploys with commitment = hash256(nonce + int2ByteString(move))
+ *    and their stake. The move stays hidden on-chain.
+ * 2. Player 2 calls play() with their move in the clear and matches the stake.
+ *    (Player 1's move is already locked in, so seeing P2's move doesn't help)
+ * 3. Player 1 calls reveal() with move + nonce; the contract checks the
+ *    commitment, decides the winner and pays out.
+ *
+ * Anti-griefing timeouts:
+ * - Nobody joins before joinDeadline → player 1 cancel()s and takes the stake back
+ * - Player 1 refuses to reveal (because they lost) before revealDeadline →
+ *   player 2 claimTimeout()s and takes the whole pot
+ *
+ * Deadlines are block heights enforced through nLockTime, so timeout paths
+ * also require a non-final input sequence.
+ */
+export class RockPaperScissors extends SmartContract {
+  @prop()
+  player1: PubKey;
+
+  @prop()
+  player2: PubKey;
+
+  @prop()
+  player1Commitment: ByteString;
+
+  @prop()
+  stake: bigint;
+
+  @prop()
+  joinDeadline: bigint;
+
+  @prop()
+  revealDeadline: bigint;
+
+  // 0 until player 2 has played
+  @prop(true)
+  player2Move: bigint;
+
+  constructor(
+    player1: PubKey,
+    player2: PubKey,
+    player1Commitment: ByteString,
+    stake: bigint,
+    joinDeadline: bigint,
+    revealDeadline: bigint,
+  ) {
+    super(...arguments);
+    this.player1 = player1;
+    this.player2 = player2;
+    this.player1Commitment = player1Commitment;
+    this.stake = stake;
+    this.joinDeadline = joinDeadline;
+    this.revealDeadline = revealDeadline;
+    this.player2Move = 0n;
+  }
+
+  /**
+   * Player 2 joins: plays their move and matches the stake.
+   * ANYONECANPAY_SINGLE lets player 2 add funding inputs and change.
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public play(move: bigint, sig: Sig) {
+    assert(this.player2Move === 0n, "Already played");
+    assert(move >= 1n && move <= 3n, "Invalid move");
+    assert(this.checkSig(sig, this.player2), "Invalid player 2 signature");
+
+    this.player2Move = move;
+
+    // Output 0: same contract holding both stakes
+    const outputs = this.buildStateOutput(this.stake * 2n);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Player 1 opens the commitment and the pot is settled.
+   * Uses SigHash.ALL so both payouts of a draw are verified, plus change.
+   */
+  @method()
+  public reveal(move: bigint, nonce: ByteString, sig: Sig) {
+    assert(this.player2Move !== 0n, "Player 2 has not played");
+    assert(this.checkSig(sig, this.player1), "Invalid player 1 signature");
+    assert(
+      hash256(nonce + int2ByteString(move)) === this.player1Commitment,
+      "Commitment mismatch",
+    );
+    assert(move >= 1n && move <= 3n, "Invalid move");
+
+    const pot = this.ctx.utxo.value;
+    const player1Pkh: PubKeyHash = hash160(this.player1);
+    const player2Pkh: PubKeyHash = hash160(this.player2);
+
+    // (m1 - m2 + 3) % 3: 0 = draw, 1 = player 1 wins, 2 = player 2 wins
+    const result = (move - this.player2Move + 3n) % 3n;
+
+    let outputs: ByteString = Utils.buildPublicKeyHashOutput(player1Pkh, pot);
+    if (result === 0n) {
+      outputs =
+        Utils.buildPublicKeyHashOutput(player1Pkh, pot / 2n) +
+        Utils.buildPublicKeyHashOutput(player2Pkh, pot - pot / 2n);
+    } else if (result === 2n) {
+      outputs = Utils.buildPublicKeyHashOutput(player2Pkh, pot);
+    }
+    outputs += this.buildChangeOutput();
+
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Player 2 takes the pot if player 1 never reveals
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public claimTimeout(sig: Sig) {
+    assert(this.player2Move !== 0n, "Player 2 has not played");
+    assert(this.ctx.locktime >= this.revealDeadline, "Reveal deadline not reached");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+    assert(this.checkSig(sig, this.player2), "Invalid player 2 signature");
+
+    const outputs = Utils.buildPublicKeyHashOutput(hash160(this.player2), this.ctx.utxo.value);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Player 1 takes their stake back if nobody joins
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public cancel(sig: Sig) {
+    assert(this.player2Move === 0n, "Game already started");
+    assert(this.ctx.locktime >= this.joinDeadline, "Join deadline not reached");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+    assert(this.checkSig(sig, this.player1), "Invalid player 1 signature");
+
+    const outputs = Utils.buildPublicKeyHashOutput(hash160(this.player1), this.ctx.utxo.value);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+}
+
+export default RockPaperScissors;
+"#;
+
+pub const LOTTERY_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  hash160,
+  byteString2Int,
+  fill,
+  len,
+  slice,
+  toByteString,
+  FixedArray,
+  PubKey,
+  Sig,
+  SigHash,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+
+export const MAX_ENTRANTS = 5;
+
+/**
+ * Lottery Smart Contract (commit-reveal randomness)
+ *
+ * Flow:
+ * 1. Anyone deploys the lottery with a ticket price and reveal deadline
+ * 2. Entrants call enter() with hash256(secret) and pay the ticket price
+ * 3. Once reveals start, entries close. Each entrant reveals their 32-byte
+ *    secret with reveal()
+ * 4. draw() hashes all revealed secrets together; the result picks the
+ *    winner, who receives the whole pot
+ *
+ * No single party controls the outcome: it depends on every revealed secret,
+ * and secrets are committed before any of them is known.
+ *
+ * Entrants who never reveal are excluded once revealDeadline has passed, so
+ * withholding a secret can only remove yourself from the draw. For
+ * randomness that doesn't depend on entrants at all, the seed can be swapped
+ * for a block hash after the deadline (e.g. verified via a block header
+ * passed to draw()).
+ */
+export class Lottery extends SmartContract {
+  @prop()
+  ticketPrice: bigint;
+
+  @prop()
+  revealDeadline: bigint;
+
+  @prop(true)
+  entrants: FixedArray<PubKey, typeof MAX_ENTRANTS>;
+
+  @prop(true)
+  commitments: FixedArray<ByteString, typeof MAX_ENTRANTS>;
+
+  // Empty until revealed
+  @prop(true)
+  secrets: FixedArray<ByteString, typeof MAX_ENTRANTS>;
+
+  @prop(true)
+  entryCount: bigint;
+
+  @prop(true)
+  revealCount: bigint;
+
+  constructor(ticketPrice: bigint, revealDeadline: bigint) {
+    super(...arguments);
+    this.ticketPrice = ticketPrice;
+    this.revealDeadline = revealDeadline;
+    this.entrants = fill(PubKey(toByteString("")), MAX_ENTRANTS);
+    this.commitments = fill(toByteString(""), MAX_ENTRANTS);
+    this.secrets = fill(toByteString(""), MAX_ENTRANTS);
+    this.entryCount = 0n;
+    this.revealCount = 0n;
+  }
+
+  /**
+   * Buy a ticket. ANYONECANPAY_SINGLE lets the entrant add funding inputs.
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public enter(entrant: PubKey, commitment: ByteString, sig: Sig) {
+    assert(this.revealCount === 0n, "Entries closed");
+    assert(this.entryCount < 5n, "Lottery full"); // MAX_ENTRANTS
+    assert(len(commitment) === 32n, "Invalid commitment");
+    assert(this.checkSig(sig, entrant), "Invalid entrant signature");
+
+    this.entrants[Number(this.entryCount)] = entrant;
+    this.commitments[Number(this.entryCount)] = commitment;
+    this.entryCount++;
+
+    const outputs = this.buildStateOutput(this.ctx.utxo.value + this.ticketPrice);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Open a commitment. Anyone may submit it - the secret is what counts.
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public reveal(index: bigint, secret: ByteString) {
+    assert(this.entryCount >= 2n, "Need at least two entrants");
+    assert(index >= 0n && index < this.entryCount, "Invalid index");
+    assert(this.secrets[Number(index)] === toByteString(""), "Already revealed");
+    assert(len(secret) === 32n, "Secret must be 32 bytes");
+    assert(hash256(secret) === this.commitments[Number(index)], "Commitment mismatch");
+
+    this.secrets[Number(index)] = secret;
+    this.revealCount++;
+
+    const outputs = this.buildStateOutput(this.ctx.utxo.value);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Pay the pot to the winner. Callable by anyone once every entrant has
+   * revealed, or after revealDeadline with whoever did reveal.
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public draw() {
+    assert(this.revealCount > 0n, "Nothing revealed");
+    if (this.revealCount < this.entryCount) {
+      assert(this.ctx.locktime >= this.revealDeadline, "Reveal deadline not reached");
+      assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+    }
+
+    let seed = toByteString("");
+    for (let i = 0; i < MAX_ENTRANTS; i++) {
+      seed += this.secrets[i];
+    }
+    // 4 random bytes + 0x00 sign byte → non-negative little-endian integer
+    const random = byteString2Int(slice(hash256(seed), 0n, 4n) + toByteString("00"));
+    const pick = random % this.revealCount;
+
+    // Winner is the pick-th entrant that revealed
+    let winner = this.entrants[0];
+    let k = 0n;
+    for (let i = 0; i < MAX_ENTRANTS; i++) {
+      if (this.secrets[i] !== toByteString("")) {
+        if (k === pick) {
+          winner = this.entrants[i];
+        }
+        k++;
+      }
+    }
+
+    const outputs = Utils.buildPublicKeyHashOutput(hash160(winner), this.ctx.utxo.value);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+}
+
+export default Lottery;
+"#;
+
+pub const PREDICTION_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  hash160,
+  len,
+  slice,
+  toByteString,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  SigHash,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+import { RabinPubKey, RabinSig, RabinVerifier } from "scrypt-ts-lib";
+
+/**
+ * Prediction Market Smart Contract (oracle-settled bet)
+ *
+ * Two players bet on opposite sides of a yes/no question. The outcome is
+ * attested by an oracle that Rabin-signs the message:
+ *
+ *   eventId (32 bytes) + outcome (1 byte: 01 = yes, 00 = no)
+ *
+ * Rabin signatures are cheap to verify in script, and binding the eventId
+ * into the message stops an attestation for one event settling another.
+ *
+ * Flow:
+ * 1. The YES player registers the question with the oracle, then deploys
+ *    with the oracle's public key, the eventId and their stake
+ * 2. The NO player calls accept() and matches the stake
+ * 3. Once the oracle publishes its attestation, anyone can call settle();
+ *    the whole pot goes to the winning side
+ *
+ * Timeouts (block heights, enforced through nLockTime):
+ * - Nobody accepts before acceptDeadline → YES player cancel()s
+ * - The oracle never attests before settleDeadline → refund() returns
+ *   both stakes
+ */
+export class PredictionMarket extends SmartContract {
+  @prop()
+  oraclePubKey: RabinPubKey;
+
+  @prop()
+  eventId: ByteString;
+
+  @prop()
+  yesPlayer: PubKey;
+
+  @prop()
+  noPlayer: PubKey;
+
+  @prop()
+  stake: bigint;
+
+  @prop()
+  acceptDeadline: bigint;
+
+  @prop()
+  settleDeadline: bigint;
+
+  @prop(true)
+  accepted: boolean;
+
+  constructor(
+    oraclePubKey: RabinPubKey,
+    eventId: ByteString,
+    yesPlayer: PubKey,
+    noPlayer: PubKey,
+    stake: bigint,
+    acceptDeadline: bigint,
+    settleDeadline: bigint,
+  ) {
+    super(...arguments);
+    this.oraclePubKey = oraclePubKey;
+    this.eventId = eventId;
+    this.yesPlayer = yesPlayer;
+    this.noPlayer = noPlayer;
+    this.stake = stake;
+    this.acceptDeadline = acceptDeadline;
+    this.settleDeadline = settleDeadline;
+    this.accepted = false;
+  }
+
+  /**
+   * The NO player takes the other side and matches the stake.
+   * ANYONECANPAY_SINGLE lets them add funding inputs and change.
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public accept(sig: Sig) {
+    assert(!this.accepted, "Already accepted");
+    assert(this.checkSig(sig, this.noPlayer), "Invalid NO player signature");
+
+    this.accepted = true;
+
+    // Output 0: same contract holding both stakes
+    const outputs = this.buildStateOutput(this.stake * 2n);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Pays the pot to the side the oracle attested.
+   * No player signature needed - the payout is fixed by the attestation.
+   */
+  @method()
+  public settle(msg: ByteString, oracleSig: RabinSig) {
+    assert(this.accepted, "Bet not accepted");
+    assert(len(msg) === 33n, "Invalid oracle message");
+    assert(slice(msg, 0n, 32n) === this.eventId, "Attestation is for another event");
+    assert(RabinVerifier.verifySig(msg, oracleSig, this.oraclePubKey), "Invalid oracle signature");
+
+    const outcome = slice(msg, 32n, 33n);
+    let winner: PubKeyHash = hash160(this.noPlayer);
+    if (outcome === toByteString("01")) {
+      winner = hash160(this.yesPlayer);
+    } else {
+      assert(outcome === toByteString("00"), "Invalid outcome");
+    }
+
+    let outputs: ByteString = Utils.buildPublicKeyHashOutput(winner, this.ctx.utxo.value);
+    outputs += this.buildChangeOutput();
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Both stakes go back if the oracle stays silent past settleDeadline
+   */
+  @method()
+  public refund() {
+    assert(this.accepted, "Bet not accepted");
+    assert(this.ctx.locktime >= this.settleDeadline, "Settle deadline not reached");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+
+    let outputs: ByteString =
+      Utils.buildPublicKeyHashOutput(hash160(this.yesPlayer), this.stake) +
+      Utils.buildPublicKeyHashOutput(hash160(this.noPlayer), this.ctx.utxo.value - this.stake);
+    outputs += this.buildChangeOutput();
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * The YES player takes their stake back if nobody accepts
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public cancel(sig: Sig) {
+    assert(!this.accepted, "Bet already accepted");
+    assert(this.ctx.locktime >= this.acceptDeadline, "Accept deadline not reached");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+    assert(this.checkSig(sig, this.yesPlayer), "Invalid YES player signature");
+
+    const outputs = Utils.buildPublicKeyHashOutput(hash160(this.yesPlayer), this.ctx.utxo.value);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+}
+
+export default PredictionMarket;
+"#;
+
+pub const ORDINAL_LISTING_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  hash160,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  SigHash,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+
+/**
+ * Ordinal Listing Smart Contract (ordinal-lock covenant)
+ *
+ * Holds a single 1-sat ordinal (inscription) for sale at a fixed price.
+ *
+ * Flow:
+ * 1. The seller moves the inscription's sat into this contract (output 0,
+ *    1 sat) - see listInscription() in nftService.ts
+ * 2. Anyone calls purchase(): the covenant forces output 0 to send the
+ *    ordinal to the buyer and output 1 to pay the seller
+ * 3. The seller can cancel() at any time before a sale
+ *
+ * Ordinal theory tracks sats first-in-first-out, so the listing input must
+ * be input 0 and the buyer's ordinal output must be output 0.
+ *
+ * purchase() uses ANYONECANPAY_ALL: the preimage commits to every output
+ * but only to this input. A purchase can therefore be built as a partial
+ * transaction (listing input + fixed outputs) that the buyer completes by
+ * appending their own funding inputs.
+ */
+export class OrdinalListing extends SmartContract {
+  @prop()
+  seller: PubKey;
+
+  @prop()
+  price: bigint;
+
+  constructor(seller: PubKey, price: bigint) {
+    super(...arguments);
+    this.seller = seller;
+    this.price = price;
+  }
+
+  @method(SigHash.ANYONECANPAY_ALL)
+  public purchase(buyer: PubKeyHash) {
+    let outputs: ByteString =
+      Utils.buildPublicKeyHashOutput(buyer, 1n) +
+      Utils.buildPublicKeyHashOutput(hash160(this.seller), this.price);
+    outputs += this.buildChangeOutput();
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Seller delists; the ordinal goes wherever the seller's signature says
+   */
+  @method()
+  public cancel(sig: Sig) {
+    assert(this.checkSig(sig, this.seller), "Invalid seller signature");
+  }
+}
+
+export default OrdinalListing;
+"#;
+
+pub const TOKEN_MINT_CONTRACT: &str = r#"import {
+  method,
+  prop,
+  assert,
+  hash256,
+  toByteString,
+  ByteString,
+  Addr,
+} from "scrypt-ts";
+import { BSV20V2 } from "scrypt-ord";
+
+/**
+ * Token Mint Smart Contract (BSV-21 fungible token)
+ *
+ * deployToken() inscribes a BSV-21 "deploy+mint" of the whole supply into
+ * this covenant, so the token id is "<deploy txid>_0" and no tokens exist
+ * outside the contract yet.
+ *
+ * mint() releases up to `lim` tokens per call to any address. The rest of
+ * the supply stays in the covenant (output 0), so minting is permissionless
+ * but rate-limited, and nobody - not even the deployer - can take the
+ * remaining supply in one go.
+ *
+ * Minted tokens land in standard BSV-21 transfer inscriptions on 1-sat P2PKH
+ * outputs, which wallets and indexers understand; moving them afterwards
+ * is a normal token transfer (see tokenService.ts).
+ */
+export class TokenMint extends BSV20V2 {
+  // Tokens still held by the covenant
+  @prop(true)
+  supply: bigint;
+
+  // Maximum tokens per mint() call
+  @prop()
+  lim: bigint;
+
+  constructor(id: ByteString, sym: ByteString, max: bigint, dec: bigint, lim: bigint) {
+    super(id, sym, max, dec);
+    this.init(...arguments);
+    this.supply = max;
+    this.lim = lim;
+  }
+
+  @method()
+  public mint(dest: Addr, amount: bigint) {
+    assert(amount > 0n, "Mint amount must be positive");
+    assert(amount <= this.lim, "Mint amount exceeds limit");
+
+    this.supply -= amount;
+    assert(this.supply >= 0n, "Supply exhausted");
+
+    let outputs = toByteString("");
+    // Output 0: the covenant keeps the rest of the supply
+    if (this.supply > 0n) {
+      outputs += this.buildStateOutputFT(this.supply);
+    }
+    outputs += BSV20V2.buildTransferOutput(dest, this.id, amount);
+    outputs += this.buildChangeOutput();
+
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+}
+
+export default TokenMint;
+"#;
+
+pub const PAYMENT_CHANNEL_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  hash160,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+
+/**
+ * Payment Channel Smart Contract (unidirectional, off-chain updates)
+ *
+ * The sender locks a deposit once, then streams payments by signing ever
+ * larger "close" transactions off-chain. Only the last one is broadcast.
+ *
+ * Flow:
+ * 1. Sender deploys with the receiver's key, a fixed close fee and an expiry
+ * 2. Sender signs close(amount) transactions with a session key and sends
+ *    them through the relay (relay/server.mjs) - no chain activity, no fees
+ * 3. Receiver keeps the highest-amount update and, whenever they like,
+ *    co-signs and broadcasts it with close()
+ * 4. If the receiver never closes, refund() returns the deposit to the
+ *    sender after expiry - so the receiver must close before then
+ *
+ * The close fee is fixed at deployment (paid from the deposit) and there
+ * are no funding inputs or change, so both parties can rebuild the exact
+ * same transaction from just the amount.
+ *
+ * Updates are signed by senderKey, a throwaway key held by the sender's
+ * browser, so streaming needs no wallet popups. Funds always return to
+ * senderPkh, the sender's wallet address.
+ */
+export class PaymentChannel extends SmartContract {
+  @prop()
+  senderKey: PubKey;
+
+  @prop()
+  senderPkh: PubKeyHash;
+
+  @prop()
+  receiver: PubKey;
+
+  @prop()
+  fee: bigint;
+
+  // Block height after which the sender can reclaim the deposit
+  @prop()
+  expiry: bigint;
+
+  constructor(senderKey: PubKey, senderPkh: PubKeyHash, receiver: PubKey, fee: bigint, expiry: bigint) {
+    super(...arguments);
+    this.senderKey = senderKey;
+    this.senderPkh = senderPkh;
+    this.receiver = receiver;
+    this.fee = fee;
+    this.expiry = expiry;
+  }
+
+  /**
+   * Pays `amount` to the receiver and the rest back to the sender
+   */
+  @method()
+  public close(amount: bigint, senderSig: Sig, receiverSig: Sig) {
+    assert(this.checkSig(senderSig, this.senderKey), "Invalid sender signature");
+    assert(this.checkSig(receiverSig, this.receiver), "Invalid receiver signature");
+
+    const available = this.ctx.utxo.value - this.fee;
+    assert(amount > 0n && amount <= available, "Invalid amount");
+
+    let outputs: ByteString = Utils.buildPublicKeyHashOutput(hash160(this.receiver), amount);
+    if (amount < available) {
+      outputs += Utils.buildPublicKeyHashOutput(this.senderPkh, available - amount);
+    }
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Anyone can return the deposit to the sender once the channel expires
+   */
+  @method()
+  public refund() {
+    assert(this.ctx.locktime >= this.expiry, "Channel not expired");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+
+    const outputs = Utils.buildPublicKeyHashOutput(this.senderPkh, this.ctx.utxo.value - this.fee);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+}
+
+export default PaymentChannel;
+"#;
+
+pub const ESCROW_CONTRACT: &str = r#"import {
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  hash160,
+  PubKey,
+  Sig,
+  SigHash,
+  ByteString,
+  Utils,
+} from "scrypt-ts";
+
+/**
+ * Escrow Smart Contract (one per marketplace listing)
+ *
+ * Roles: seller, buyer (set at checkout) and an arbiter chosen by the seller.
+ *
+ * Status: 0 = listed, 1 = paid, 2 = disputed
+ *
+ * Spending paths:
+ * - checkout()     listed → paid      buyer pays the price into escrow
+ * - delist()       listed             seller withdraws the listing
+ * - release()      paid/disputed      buyer confirms delivery, seller is paid
+ * - refund()       paid/disputed      seller gives the buyer their money back
+ * - dispute()      paid → disputed    buyer or seller asks the arbiter to decide
+ * - resolve()      disputed           arbiter splits the escrow between both
+ * - autoRelease()  paid              seller is paid once the review period ends
+ *                                    without a dispute
+ *
+ * The review period starts at checkout: deadline = checkout nLockTime +
+ * reviewBlocks. checkout requires nLockTime to be enforced, so the buyer
+ * can't push the deadline into the future (the tx wouldn't be mined) and
+ * gains nothing by pulling it into the past.
+ */
+export class Escrow extends SmartContract {
+  @prop()
+  seller: PubKey;
+
+  @prop()
+  arbiter: PubKey;
+
+  // UTF-8 listing title
+  @prop()
+  title: ByteString;
+
+  @prop()
+  price: bigint;
+
+  @prop()
+  reviewBlocks: bigint;
+
+  // Equal to seller until checkout
+  @prop(true)
+  buyer: PubKey;
+
+  @prop(true)
+  status: bigint;
+
+  @prop(true)
+  deadline: bigint;
+
+  constructor(seller: PubKey, arbiter: PubKey, title: ByteString, price: bigint, reviewBlocks: bigint) {
+    super(...arguments);
+    this.seller = seller;
+    this.arbiter = arbiter;
+    this.title = title;
+    this.price = price;
+    this.reviewBlocks = reviewBlocks;
+    this.buyer = seller;
+    this.status = 0n;
+    this.deadline = 0n;
+  }
+
+  /**
+   * Buyer pays the price into escrow.
+   * ANYONECANPAY_SINGLE lets the buyer add funding inputs and change.
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public checkout(buyer: PubKey, sig: Sig) {
+    assert(this.status === 0n, "Not for sale");
+    assert(this.checkSig(sig, buyer), "Invalid buyer signature");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+
+    this.buyer = buyer;
+    this.status = 1n;
+    this.deadline = this.ctx.locktime + this.reviewBlocks;
+
+    const outputs = this.buildStateOutput(this.ctx.utxo.value + this.price);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  @method()
+  public delist(sig: Sig) {
+    assert(this.status === 0n, "Already sold");
+    assert(this.checkSig(sig, this.seller), "Invalid seller signature");
+    this.payTo(this.seller);
+  }
+
+  @method()
+  public release(sig: Sig) {
+    assert(this.status > 0n, "Not paid");
+    assert(this.checkSig(sig, this.buyer), "Invalid buyer signature");
+    this.payTo(this.seller);
+  }
+
+  @method()
+  public refund(sig: Sig) {
+    assert(this.status > 0n, "Not paid");
+    assert(this.checkSig(sig, this.seller), "Invalid seller signature");
+    this.payTo(this.buyer);
+  }
+
+  /**
+   * Either party freezes the escrow until the arbiter decides
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public dispute(sig: Sig, byBuyer: boolean) {
+    assert(this.status === 1n, "Not in review");
+    if (byBuyer) {
+      assert(this.checkSig(sig, this.buyer), "Invalid buyer signature");
+    } else {
+      assert(this.checkSig(sig, this.seller), "Invalid seller signature");
+    }
+
+    this.status = 2n;
+
+    const outputs = this.buildStateOutput(this.ctx.utxo.value);
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Arbiter pays sellerAmount to the seller and the rest to the buyer
+   */
+  @method()
+  public resolve(sig: Sig, sellerAmount: bigint) {
+    assert(this.status === 2n, "Not disputed");
+    assert(this.checkSig(sig, this.arbiter), "Invalid arbiter signature");
+
+    const total = this.ctx.utxo.value;
+    assert(sellerAmount >= 0n && sellerAmount <= total, "Invalid split");
+
+    let outputs: ByteString = Utils.buildPublicKeyHashOutput(hash160(this.seller), sellerAmount);
+    if (sellerAmount === 0n) {
+      outputs = Utils.buildPublicKeyHashOutput(hash160(this.buyer), total);
+    } else if (sellerAmount < total) {
+      outputs += Utils.buildPublicKeyHashOutput(hash160(this.buyer), total - sellerAmount);
+    }
+    outputs += this.buildChangeOutput();
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+
+  /**
+   * Anyone can pay the seller once the review period passed undisputed
+   */
+  @method()
+  public autoRelease() {
+    assert(this.status === 1n, "Not in review");
+    assert(this.ctx.locktime >= this.deadline, "Review period not over");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+    this.payTo(this.seller);
+  }
+
+  @method()
+  payTo(recipient: PubKey): void {
+    let outputs: ByteString = Utils.buildPublicKeyHashOutput(hash160(recipient), this.ctx.utxo.value);
+    outputs += this.buildChangeOutput();
+    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+  }
+}
+
+export default Escrow;
+"#;
+
+pub const PLAYGROUND_CONTRACT: &str = r#"import { SmartContract, method, prop, assert } from "scrypt-ts";
+
+/**
+ * The playground's starter snippet: whoever knows a square root of `square`
+ * can unlock the coins. Change it in the playground, compile, and deploy
+ * the result as a throwaway contract.
+ */
+export class Playground extends SmartContract {
+  @prop()
+  square: bigint;
+
+  constructor(square: bigint) {
+    super(...arguments);
+    this.square = square;
+  }
+
+  @method()
+  public unlock(root: bigint) {
+    assert(root * root == this.square, "Not a square root");
+  }
+}
+
+export default Playground;
+"#;
+
+pub fn generate_custom_contract(description: &str) -> String {
+    format!(
+        r#"import {{
+  SmartContract,
+  method,
+  prop,
+  assert,
+  hash256,
+  hash160,
+  SigHash,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  ByteString,
+  toByteString,
+  Utils,
+}} from "scrypt-ts";
+
+/**
+ * Custom Bitcoin Smart Contract
+ * 
+ * Requirements from user:
+ * {description}
+ * 
+ * KEY PATTERNS TO USE:
+ * 
+ * 1. SigHash.ANYONECANPAY_SINGLE for settlement methods:
+ *    - ANYONECANPAY: allows adding fee inputs
+ *    - SINGLE: only verifies output 0
+ * 
+ * 2. Output verification:
+ *    const outputs = Utils.buildPublicKeyHashOutput(pkh, value);
+ *    assert(hash256(outputs) === this.ctx.hashOutputs, "outputs mismatch");
+ * 
+ * 3. Commit-reveal pattern for hidden moves:
+ *    - Store commitment: hash256(nonce + move)
+ *    - Verify on reveal: hash256(revealedNonce + revealedMove) === commitment
+ * 
+ * 4. Timeout handling:
+ *    assert(this.ctx.locktime >= this.timeoutHeight, "timeout not reached");
+ *    assert(this.ctx.sequence < 0xffffffff, "nLocktime must be enabled");
+ */
+export class Contract extends SmartContract {{
+  @prop()
+  player1: PubKey;
+
+  @prop()
+  player2: PubKey;
+
+  @prop()
+  timeoutBlockHeight: bigint;
+
+  constructor(
+    player1: PubKey,
+    player2: PubKey,
+    timeoutBlockHeight: bigint,
+  ) {{
+    super(...arguments);
+    this.player1 = player1;
+    this.player2 = player2;
+    this.timeoutBlockHeight = timeoutBlockHeight;
+  }}
+
+  /**
+   * Main interaction method
+   * 
+   * Uses ANYONECANPAY_SINGLE:
+   * - Input 0: Contract UTXO
+   * - Input 1+: Fee UTXOs (added by custom tx builder)
+   * - Output 0: Payout (verified by contract)
+   * - Output 1+: Change (not verified)
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public settle(
+    callerIsPlayer1: boolean,
+    callerSig: Sig
+  ) {{
+    const callerPubKey: PubKey = callerIsPlayer1 ? this.player1 : this.player2;
+    assert(this.checkSig(callerSig, callerPubKey), "sig required");
+    
+    // TODO: Implement your game/business logic here
+    // Determine winner and build payout output
+    
+    // Example: Winner takes all
+    const winnerPkh: PubKeyHash = hash160(callerPubKey);
+    const outputs = Utils.buildPublicKeyHashOutput(winnerPkh, this.ctx.utxo.value);
+    
+    // Verify output 0 matches (SINGLE sighash)
+    assert(this.ctx.hashOutputs === hash256(outputs), "outputs mismatch");
+  }}
+
+  /**
+   * Timeout claim - if opponent doesn't respond
+   */
+  @method(SigHash.ANYONECANPAY_SINGLE)
+  public claimAfterTimeout(sig: Sig) {{
+    // Verify timeout reached
+    assert(this.ctx.locktime >= this.timeoutBlockHeight, "timeout not reached");
+    assert(this.ctx.sequence < 0xffffffffn, "nLocktime must be enabled");
+    
+    // Either player can claim after timeout
+    assert(
+      this.checkSig(sig, this.player1) || this.checkSig(sig, this.player2),
+      "player sig required"
+    );
+    
+    // Claimant gets full value
+    const claimantPkh: PubKeyHash = hash160(this.player1);
+    const outputs = Utils.buildPublicKeyHashOutput(claimantPkh, this.ctx.utxo.value);
+    assert(this.ctx.hashOutputs === hash256(outputs), "outputs mismatch");
+  }}
+}}
+
+export default Contract;
+"#
+    )
+}
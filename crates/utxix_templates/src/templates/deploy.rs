@@ -0,0 +1,1008 @@
+//! `scripts/deploy.ts` for each template with the helpers the scripts share,
+//! and the per-network `.env` files they're configured through.
+
+use crate::Template;
+
+use super::regtest::REGTEST_PROVIDER;
+
+/// `scripts/deploy.ts` for the template plus the helpers it shares with
+/// every other template's deploy script.
+///
+/// With `regtest`, `scripts/provider.ts` can also target the local node from
+/// `regtest_files`.
+pub fn deploy_files(template: Template, regtest: bool) -> Vec<(&'static str, String)> {
+    let provider = if regtest { REGTEST_PROVIDER } else { DEPLOY_PROVIDER };
+    vec![
+        ("scripts/utils.ts", DEPLOY_UTILS.to_string()),
+        ("scripts/keychain-signer.ts", KEYCHAIN_SIGNER.to_string()),
+        ("scripts/provider.ts", provider.to_string()),
+        ("scripts/deploy.ts", deploy_script(template).to_string()),
+    ]
+}
+
+fn deploy_script(template: Template) -> &'static str {
+    match template {
+        Template::HelloWorld => HELLOWORLD_DEPLOY,
+        Template::Counter => COUNTER_DEPLOY,
+        Template::TicTacToe => TICTACTOE_DEPLOY,
+        Template::Auction => AUCTION_DEPLOY,
+        Template::Treasury => TREASURY_DEPLOY,
+        Template::RockPaperScissors => ROCK_PAPER_SCISSORS_DEPLOY,
+        Template::Lottery => LOTTERY_DEPLOY,
+        Template::Prediction => PREDICTION_DEPLOY,
+        Template::NftMarketplace => ORDINAL_LISTING_DEPLOY,
+        Template::Token => TOKEN_MINT_DEPLOY,
+        Template::StreamingPayments => PAYMENT_CHANNEL_DEPLOY,
+        Template::Marketplace => ESCROW_DEPLOY,
+        Template::Playground => PLAYGROUND_DEPLOY,
+        Template::Custom => CUSTOM_CONTRACT_DEPLOY,
+    }
+}
+
+/// `.env.testnet`, `.env.mainnet` and the `npm run use:<network>` script
+/// copying one of them into `.env`.
+pub fn env_files(template: Template) -> Vec<(&'static str, String)> {
+    vec![
+        (".env.testnet", network_env("testnet", template)),
+        (".env.mainnet", network_env("mainnet", template)),
+        ("scripts/use-network.mjs", USE_NETWORK_SCRIPT.to_string()),
+    ]
+}
+
+/// One network's settings: chain endpoints and fee rate, the shared app
+/// settings and the template's deploy script settings.
+fn network_env(network: &str, template: Template) -> String {
+    let (explorer, woc, fee_per_kb, coins) = match network {
+        "mainnet" => (
+            "https://whatsonchain.com",
+            "https://api.whatsonchain.com/v1/bsv/main",
+            100,
+            "real BSV",
+        ),
+        _ => (
+            "https://test.whatsonchain.com",
+            "https://api.whatsonchain.com/v1/bsv/test",
+            50,
+            "faucet coins",
+        ),
+    };
+    let deploy_env = deploy_env(template);
+    format!(
+        "# {network} settings - `npm run use:{network}` copies them into .env\n\
+         # (read through src/services/config.ts)\n\
+         VITE_BSV_NETWORK={network}\n\n\
+         # Block explorer (transaction links) and WhatsOnChain API\n\
+         VITE_EXPLORER_URL={explorer}\n\
+         VITE_WOC_API_URL={woc}\n\n\
+         # Transaction fee rate in satoshis per kB\n\
+         VITE_FEE_PER_KB={fee_per_kb}\n\n\
+         {ENV_SHARED}\n\
+         # Deploy script (npm run deploy), paid with {coins} by the deploy key.\n\
+         # Generate or import it in the utxix Wallet panel, which keeps it in the OS\n\
+         # keychain and sets DEPLOY_KEY_ADDRESS in .env. A plaintext DEPLOY_PRIVATE_KEY\n\
+         # also works - put it in .env only (switching networks keeps it), never commit it!\n\
+         # Overrides VITE_BSV_NETWORK for the scripts (regtest: see docker-compose.yml, if generated)\n\
+         DEPLOY_NETWORK=\n\
+         {deploy_env}"
+    )
+}
+
+const USE_NETWORK_SCRIPT: &str = r#"/**
+ * npm run use:testnet | npm run use:mainnet
+ *
+ * Writes .env from .env.testnet or .env.mainnet. Variables in the current
+ * .env that the network file doesn't set (the deploy key, personal
+ * overrides) are kept.
+ */
+
+import { existsSync, readFileSync, writeFileSync } from "node:fs";
+
+const network = process.argv[2];
+if (network !== "testnet" && network !== "mainnet") {
+  console.error("Usage: node scripts/use-network.mjs testnet|mainnet");
+  process.exit(1);
+}
+
+const source = ".env." + network;
+const settings = readFileSync(source, "utf8");
+
+const keyOf = (line) => /^\s*([A-Za-z_][A-Za-z0-9_]*)\s*=/.exec(line)?.[1];
+const defined = new Set(settings.split(/\r?\n/).map(keyOf).filter(Boolean));
+
+const kept = existsSync(".env")
+  ? readFileSync(".env", "utf8")
+      .split(/\r?\n/)
+      .filter((line) => {
+        const key = keyOf(line);
+        return key && !defined.has(key);
+      })
+  : [];
+
+let env = settings.endsWith("\n") ? settings : settings + "\n";
+if (kept.length > 0) {
+  env += "\n# Kept from the previous .env\n" + kept.join("\n") + "\n";
+}
+writeFileSync(".env", env);
+
+console.log(".env now targets " + network + " (from " + source + ")");
+const keptKey = kept.map(keyOf).find((key) => key === "DEPLOY_KEY_ADDRESS" || key === "DEPLOY_PRIVATE_KEY");
+if (keptKey) {
+  console.log(keptKey + " was kept - make sure it holds " + network + " coins");
+}
+"#;
+
+/// `.env` settings read by the template's `scripts/deploy.ts`.
+pub(super) fn deploy_env(template: Template) -> &'static str {
+    match template {
+        Template::HelloWorld => "HELLO_MESSAGE=hello world\nDEPLOY_SATS=1\n",
+        Template::Counter => "COUNTER_START=0\n",
+        Template::TicTacToe => "PLAYER_O_PUBKEY=\nSTAKE_SATS=1000\n",
+        Template::Auction => "AUCTION_DURATION_BLOCKS=144\n",
+        Template::Treasury => {
+            "# Exactly 3 comma-separated co-signer public keys\nTREASURY_SIGNERS=\nTREASURY_THRESHOLD=2\nTREASURY_FAST_TRACK_LIMIT_SATS=10000\nTREASURY_HIGH_VALUE_LIMIT_SATS=1000000\nDEPLOY_SATS=100000\n"
+        }
+        Template::RockPaperScissors => {
+            "OPPONENT_PUBKEY=\n# 1 = rock, 2 = paper, 3 = scissors\nRPS_MOVE=1\nSTAKE_SATS=1000\nJOIN_BLOCKS=144\nREVEAL_BLOCKS=144\n"
+        }
+        Template::Lottery => "TICKET_PRICE_SATS=1000\nREVEAL_BLOCKS=144\n",
+        Template::Prediction => {
+            "PREDICTION_QUESTION=\nOPPONENT_PUBKEY=\nSTAKE_SATS=1000\nACCEPT_BLOCKS=144\nSETTLE_BLOCKS=1008\n"
+        }
+        Template::NftMarketplace => {
+            "# <txid>_<vout> of an inscription owned by the deploy key\nLISTING_INSCRIPTION_OUTPOINT=\nLISTING_PRICE_SATS=10000\n"
+        }
+        Template::Token => "TOKEN_SYMBOL=TEST\nTOKEN_MAX_SUPPLY=21000000\nTOKEN_DECIMALS=0\nTOKEN_MINT_LIMIT=1000\n",
+        Template::StreamingPayments => {
+            "CHANNEL_RECEIVER_PUBKEY=\nCHANNEL_DEPOSIT_SATS=10000\nCHANNEL_FEE_SATS=500\nCHANNEL_EXPIRY_BLOCKS=1008\n"
+        }
+        Template::Marketplace => "LISTING_TITLE=\nLISTING_PRICE_SATS=10000\nARBITER_PUBKEY=\nREVIEW_BLOCKS=432\n",
+        Template::Playground => "PLAYGROUND_SQUARE=49\nDEPLOY_SATS=1\n",
+        Template::Custom => "PLAYER2_PUBKEY=\nTIMEOUT_BLOCKS=144\nDEPLOY_SATS=1000\n",
+    }
+}
+
+const DEPLOY_UTILS: &str = r#"/**
+ * Helpers shared by scripts/deploy.ts
+ *
+ * Settings come from .env (npm run use:testnet | use:mainnet):
+ *   VITE_BSV_NETWORK     testnet | mainnet
+ *   DEPLOY_NETWORK       overrides VITE_BSV_NETWORK for the scripts only
+ *   DEPLOY_KEY_ADDRESS   address of the deploy key kept in the OS keychain by
+ *                        the utxix Wallet panel, which signs for the scripts
+ *   DEPLOY_PRIVATE_KEY   WIF key that pays for the deployment, if it isn't in
+ *                        the keychain
+ *
+ * Chain access (provider, block height, explorer links) lives in
+ * scripts/provider.ts.
+ */
+
+import "dotenv/config";
+import { createHash } from "crypto";
+import { existsSync, mkdirSync, readFileSync, writeFileSync } from "fs";
+import { bsv, Provider, Signer, TestWallet } from "scrypt-ts";
+import { KeychainSigner, signerSession } from "./keychain-signer";
+import { NETWORK, network, createProvider, explorerLink, getBlockHeight } from "./provider";
+
+const HISTORY_DIR = ".utxix";
+const HISTORY_PATH = HISTORY_DIR + "/deployments.json";
+
+export { NETWORK, network, getBlockHeight };
+
+export function envString(name: string, fallback?: string): string {
+  const value = process.env[name]?.trim() || fallback;
+  if (value === undefined || value === "") {
+    throw new Error(name + " is not set - add it to .env (see .env.testnet)");
+  }
+  return value;
+}
+
+export function envBigInt(name: string, fallback?: bigint): bigint {
+  return BigInt(envString(name, fallback?.toString()));
+}
+
+export function envNumber(name: string, fallback?: number): number {
+  const value = Number(envString(name, fallback?.toString()));
+  if (!Number.isFinite(value)) {
+    throw new Error(name + " must be a number");
+  }
+  return value;
+}
+
+/** Whether the deploy key is in the OS keychain rather than .env */
+function inKeychain(): boolean {
+  return Boolean(process.env.DEPLOY_KEY_ADDRESS?.trim());
+}
+
+function envKey(): bsv.PrivateKey {
+  return bsv.PrivateKey.fromWIF(envString("DEPLOY_PRIVATE_KEY"));
+}
+
+export function deployPublicKey(): bsv.PublicKey {
+  return inKeychain() ? bsv.PublicKey.fromString(signerSession().publicKey) : envKey().toPublicKey();
+}
+
+export function deployAddress(): bsv.Address {
+  return inKeychain() ? bsv.Address.fromString(envString("DEPLOY_KEY_ADDRESS")) : envKey().toAddress(network);
+}
+
+/**
+ * Signs with the deploy key - through the editor when it's in the OS
+ * keychain, else with DEPLOY_PRIVATE_KEY. Pass a provider to override the
+ * default one (e.g. OrdiProvider for ordinals).
+ */
+export async function getSigner(provider: Provider = createProvider()): Promise<Signer> {
+  await provider.connect();
+  return inKeychain() ? new KeychainSigner(provider) : new TestWallet(envKey(), provider);
+}
+
+/**
+ * Signs input `inputIndex`, a P2PKH output of the deploy key, of a
+ * transaction built by hand rather than through a contract method
+ */
+export async function signP2pkhInput(tx: bsv.Transaction, inputIndex: number) {
+  const input = tx.inputs[inputIndex];
+  const output = input.output!;
+  const sighashType = bsv.crypto.Signature.SIGHASH_ALL | bsv.crypto.Signature.SIGHASH_FORKID;
+  const sig = inKeychain()
+    ? await new KeychainSigner().sign(tx, inputIndex, output.script, output.satoshis, sighashType)
+    : bsv.Transaction.Sighash.sign(tx, envKey(), sighashType, inputIndex, output.script, new bsv.crypto.BN(output.satoshis));
+  input.setScript(bsv.Script.buildPublicKeyHashIn(deployPublicKey(), sig, sighashType));
+}
+
+export function logDeployment(contract: string, txid: string, details: Record<string, unknown> = {}) {
+  console.log(contract + " deployed to " + NETWORK);
+  console.log("  txid: " + txid);
+  for (const [key, value] of Object.entries(details)) {
+    console.log("  " + key + ": " + String(value));
+  }
+  const link = explorerLink(txid);
+  if (link) {
+    console.log("  " + link);
+  }
+  recordHistory({ kind: "deploy", contract, txid });
+}
+
+/**
+ * Logs a broadcast that called a public method of a deployed contract, and
+ * records it next to the deployments
+ */
+export function logCall(contract: string, method: string, txid: string) {
+  console.log(contract + "." + method + "() called on " + NETWORK);
+  console.log("  txid: " + txid);
+  const link = explorerLink(txid);
+  if (link) {
+    console.log("  " + link);
+  }
+  recordHistory({ kind: "call", contract, method, txid });
+}
+
+/**
+ * Appends a deployment or call to .utxix/deployments.json, shown in the
+ * editor's Deployments panel. A deployed contract sits at output 0; the
+ * editor's UTXO panel follows it (and the deployer address) from there.
+ */
+function recordHistory(entry: { kind: "deploy" | "call"; contract: string; method?: string; txid: string }) {
+  const history = existsSync(HISTORY_PATH) ? JSON.parse(readFileSync(HISTORY_PATH, "utf8")) : [];
+  const artifact = "artifacts/contracts/" + entry.contract + ".scrypt.json";
+  history.push({
+    ...entry,
+    vout: 0,
+    network: NETWORK,
+    deployer: deployAddress().toString(),
+    timestamp: new Date().toISOString(),
+    artifactHash: existsSync(artifact) ? createHash("sha256").update(readFileSync(artifact)).digest("hex") : undefined,
+  });
+  mkdirSync(HISTORY_DIR, { recursive: true });
+  writeFileSync(HISTORY_PATH, JSON.stringify(history, null, 2) + "\n");
+}
+
+/**
+ * Runs a deploy script's main(), exiting non-zero on failure
+ */
+export function run(main: () => Promise<void>) {
+  main().catch((err) => {
+    console.error(err instanceof Error ? err.message : err);
+    process.exit(1);
+  });
+}
+"#;
+
+const KEYCHAIN_SIGNER: &str = r#"/**
+ * Signs with the deploy key kept in the OS keychain, through the utxix editor
+ *
+ * Start the signer from the editor's Wallet panel ("Let Deploy Scripts
+ * Sign"). While it runs, .utxix/signer.json holds its local URL and a session
+ * token; the key itself never leaves the editor. Scripts compute each sighash
+ * preimage here and the editor signs it.
+ */
+
+import { existsSync, readFileSync } from "fs";
+import {
+  AddressesOption,
+  AddressOption,
+  bsv,
+  Provider,
+  SignatureRequest,
+  SignatureResponse,
+  Signer,
+  SignTransactionOptions,
+} from "scrypt-ts";
+
+const SESSION_PATH = ".utxix/signer.json";
+const DEFAULT_SIGHASH_TYPE = bsv.crypto.Signature.SIGHASH_ALL | bsv.crypto.Signature.SIGHASH_FORKID;
+
+export interface SignerSession {
+  url: string;
+  token: string;
+  address: string;
+  publicKey: string;
+}
+
+export function signerSession(): SignerSession {
+  if (!existsSync(SESSION_PATH)) {
+    throw new Error(
+      "The deploy key is in the OS keychain (DEPLOY_KEY_ADDRESS) - click " +
+        '"Let Deploy Scripts Sign" in the utxix Wallet panel first',
+    );
+  }
+  const session: SignerSession = JSON.parse(readFileSync(SESSION_PATH, "utf8"));
+  const expected = process.env.DEPLOY_KEY_ADDRESS?.trim();
+  if (expected && expected !== session.address) {
+    throw new Error("The editor is signing for " + session.address + ", not DEPLOY_KEY_ADDRESS " + expected);
+  }
+  return session;
+}
+
+export class KeychainSigner extends Signer {
+  private readonly session = signerSession();
+
+  constructor(provider?: Provider) {
+    super(provider);
+  }
+
+  override async isAuthenticated(): Promise<boolean> {
+    return true;
+  }
+
+  override async requestAuth(): Promise<{ isAuthenticated: boolean; error: string }> {
+    return { isAuthenticated: true, error: "" };
+  }
+
+  override async connect(provider?: Provider): Promise<this> {
+    const target = provider ?? this.provider;
+    if (!target) {
+      throw new Error("No provider to connect to");
+    }
+    if (!target.isConnected()) {
+      await target.connect();
+    }
+    this.provider = target;
+    return this;
+  }
+
+  override async getDefaultAddress(): Promise<bsv.Address> {
+    return bsv.Address.fromString(this.session.address);
+  }
+
+  override async getDefaultPubKey(): Promise<bsv.PublicKey> {
+    return bsv.PublicKey.fromString(this.session.publicKey);
+  }
+
+  override async getPubKey(address?: AddressOption): Promise<bsv.PublicKey> {
+    if (address && !this.owns(address)) {
+      throw new Error("The keychain signer has no key for " + address.toString());
+    }
+    return this.getDefaultPubKey();
+  }
+
+  override async getNetwork(): Promise<bsv.Networks.Network> {
+    return (await this.getDefaultAddress()).network;
+  }
+
+  override async signMessage(): Promise<string> {
+    throw new Error("The keychain signer only signs transactions");
+  }
+
+  override async signRawTransaction(rawTxHex: string, options: SignTransactionOptions): Promise<string> {
+    const tx = new bsv.Transaction(rawTxHex);
+    for (const sigReq of options?.sigRequests ?? []) {
+      tx.inputs[sigReq.inputIndex].output = new bsv.Transaction.Output({
+        script: this.scriptOf(sigReq),
+        satoshis: sigReq.satoshis,
+      });
+    }
+    return (await this.signTransaction(tx)).toString();
+  }
+
+  /** Signs every input that spends a P2PKH output of the deploy key */
+  override async signTransaction(tx: bsv.Transaction): Promise<bsv.Transaction> {
+    const owned = bsv.Script.buildPublicKeyHashOut(this.session.address).toHex();
+    const publicKey = await this.getDefaultPubKey();
+    for (let i = 0; i < tx.inputs.length; i++) {
+      const output = tx.inputs[i].output;
+      if (output && output.script.toHex() === owned) {
+        const sig = await this.sign(tx, i, output.script, output.satoshis, DEFAULT_SIGHASH_TYPE);
+        tx.inputs[i].setScript(bsv.Script.buildPublicKeyHashIn(publicKey, sig, DEFAULT_SIGHASH_TYPE));
+      }
+    }
+    return tx;
+  }
+
+  override async getSignatures(rawTxHex: string, sigRequests: SignatureRequest[]): Promise<SignatureResponse[]> {
+    const tx = new bsv.Transaction(rawTxHex);
+    const publicKey = (await this.getDefaultPubKey()).toString();
+    const responses: SignatureResponse[] = [];
+    for (const sigReq of sigRequests) {
+      if (!this.owns(sigReq.address)) {
+        continue;
+      }
+      const script = this.scriptOf(sigReq);
+      tx.inputs[sigReq.inputIndex].output = new bsv.Transaction.Output({ script, satoshis: sigReq.satoshis });
+      const sighashType = sigReq.sigHashType ?? DEFAULT_SIGHASH_TYPE;
+      const subScript = sigReq.csIdx !== undefined ? script.subScript(sigReq.csIdx) : script;
+      const sig = await this.sign(tx, sigReq.inputIndex, subScript, sigReq.satoshis, sighashType);
+      responses.push({
+        sig: sig.toTxFormat().toString("hex"),
+        publicKey,
+        inputIndex: sigReq.inputIndex,
+        sigHashType: sighashType,
+        csIdx: sigReq.csIdx,
+      });
+    }
+    return responses;
+  }
+
+  /** Has the editor sign input `inputIndex` of `tx` */
+  async sign(
+    tx: bsv.Transaction,
+    inputIndex: number,
+    subScript: bsv.Script,
+    satoshis: number,
+    sighashType: number,
+  ): Promise<bsv.crypto.Signature> {
+    const preimage = bsv.Transaction.Sighash.sighashPreimage(
+      tx,
+      sighashType,
+      inputIndex,
+      subScript,
+      new bsv.crypto.BN(satoshis),
+    );
+    const response = await fetch(this.session.url + "/sign", {
+      method: "POST",
+      headers: { Authorization: "Bearer " + this.session.token, "Content-Type": "application/json" },
+      body: JSON.stringify({ preimage: preimage.toString("hex") }),
+    }).catch(() => {
+      throw new Error("The utxix signer isn't running - start it from the Wallet panel");
+    });
+    const body = await response.json();
+    if (!response.ok) {
+      throw new Error("The utxix signer refused: " + body.error);
+    }
+    const sig = bsv.crypto.Signature.fromDER(Buffer.from(body.signature, "hex"));
+    sig.nhashtype = sighashType;
+    return sig;
+  }
+
+  private owns(address: AddressesOption): boolean {
+    return [address].flat().some((item) => item.toString() === this.session.address);
+  }
+
+  private scriptOf(sigReq: SignatureRequest): bsv.Script {
+    return sigReq.scriptHex
+      ? bsv.Script.fromHex(sigReq.scriptHex)
+      : bsv.Script.buildPublicKeyHashOut(sigReq.address.toString());
+  }
+}
+"#;
+
+const DEPLOY_PROVIDER: &str = r#"/**
+ * Chain access for the deploy scripts - WhatsOnChain through DefaultProvider
+ *
+ * DEPLOY_NETWORK (falling back to VITE_BSV_NETWORK) picks testnet or mainnet.
+ */
+
+import { bsv, DefaultProvider, Provider } from "scrypt-ts";
+
+const setting = (process.env.DEPLOY_NETWORK || process.env.VITE_BSV_NETWORK || "testnet").toLowerCase();
+
+export const NETWORK = setting === "mainnet" ? "mainnet" : "testnet";
+export const network = NETWORK === "mainnet" ? bsv.Networks.mainnet : bsv.Networks.testnet;
+
+const WOC_API = "https://api.whatsonchain.com/v1/bsv/" + (NETWORK === "mainnet" ? "main" : "test");
+const EXPLORER = NETWORK === "mainnet" ? "https://whatsonchain.com" : "https://test.whatsonchain.com";
+
+export function createProvider(): Provider {
+  return new DefaultProvider({ network });
+}
+
+export async function getBlockHeight(): Promise<number> {
+  const res = await fetch(WOC_API + "/chain/info");
+  if (!res.ok) {
+    throw new Error("Failed to fetch block height: HTTP " + res.status);
+  }
+  return (await res.json()).blocks;
+}
+
+export function explorerLink(txid: string): string | null {
+  return EXPLORER + "/tx/" + txid;
+}
+"#;
+
+const HELLOWORLD_DEPLOY: &str = r#"/**
+ * Deploys HelloWorld
+ *
+ * .env: HELLO_MESSAGE (the message that unlocks it), DEPLOY_SATS
+ */
+
+import { sha256, toByteString } from "scrypt-ts";
+import HelloWorld from "../contracts/HelloWorld.scrypt";
+import { envNumber, envString, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await HelloWorld.loadArtifact("artifacts/contracts/HelloWorld.scrypt.json");
+
+  const message = envString("HELLO_MESSAGE", "hello world");
+  const instance = new HelloWorld(sha256(toByteString(message, true)));
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(envNumber("DEPLOY_SATS", 1));
+  logDeployment("HelloWorld", tx.id, { message });
+});
+"#;
+
+const COUNTER_DEPLOY: &str = r#"/**
+ * Deploys Counter
+ *
+ * .env: COUNTER_START
+ */
+
+import Counter from "../contracts/Counter.scrypt";
+import { envBigInt, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await Counter.loadArtifact("artifacts/contracts/Counter.scrypt.json");
+
+  const count = envBigInt("COUNTER_START", 0n);
+  const instance = new Counter(count);
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(1);
+  logDeployment("Counter", tx.id, { count });
+});
+"#;
+
+const TICTACTOE_DEPLOY: &str = r#"/**
+ * Deploys TicTacToe with the deploy key as player X
+ *
+ * .env: PLAYER_O_PUBKEY, STAKE_SATS (per player - the pot holds both)
+ */
+
+import { PubKey } from "scrypt-ts";
+import TicTacToe from "../contracts/TicTacToe.scrypt";
+import { deployPublicKey, envBigInt, envString, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await TicTacToe.loadArtifact("artifacts/contracts/TicTacToe.scrypt.json");
+
+  const stake = envBigInt("STAKE_SATS", 1000n);
+  const instance = new TicTacToe(
+    PubKey(deployPublicKey().toHex()),
+    PubKey(envString("PLAYER_O_PUBKEY")),
+    stake,
+  );
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(Number(stake * 2n));
+  logDeployment("TicTacToe", tx.id, { pot: stake * 2n });
+});
+"#;
+
+const AUCTION_DEPLOY: &str = r#"/**
+ * Deploys Auction with the deploy key as auctioneer
+ *
+ * .env: AUCTION_DURATION_BLOCKS (~144 per day)
+ */
+
+import { PubKey } from "scrypt-ts";
+import Auction from "../contracts/Auction.scrypt";
+import { deployPublicKey, envNumber, getBlockHeight, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await Auction.loadArtifact("artifacts/contracts/Auction.scrypt.json");
+
+  const deadline = (await getBlockHeight()) + envNumber("AUCTION_DURATION_BLOCKS", 144);
+  const instance = new Auction(PubKey(deployPublicKey().toHex()), BigInt(deadline));
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(1);
+  logDeployment("Auction", tx.id, { deadline });
+});
+"#;
+
+const TREASURY_DEPLOY: &str = r#"/**
+ * Deploys and funds Treasury
+ *
+ * .env: TREASURY_SIGNERS (3 comma-separated public keys), TREASURY_THRESHOLD,
+ *       TREASURY_FAST_TRACK_LIMIT_SATS, TREASURY_HIGH_VALUE_LIMIT_SATS,
+ *       DEPLOY_SATS (initial treasury balance)
+ */
+
+import { FixedArray, PubKey } from "scrypt-ts";
+import Treasury, { SIGNER_COUNT } from "../contracts/Treasury.scrypt";
+import { envBigInt, envNumber, envString, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await Treasury.loadArtifact("artifacts/contracts/Treasury.scrypt.json");
+
+  const signers = envString("TREASURY_SIGNERS").split(",").map((key) => key.trim());
+  if (signers.length !== SIGNER_COUNT) {
+    throw new Error("TREASURY_SIGNERS needs exactly " + SIGNER_COUNT + " public keys");
+  }
+
+  const instance = new Treasury(
+    signers.map((key) => PubKey(key)) as FixedArray<PubKey, typeof SIGNER_COUNT>,
+    envBigInt("TREASURY_THRESHOLD", 2n),
+    envBigInt("TREASURY_FAST_TRACK_LIMIT_SATS", 10_000n),
+    envBigInt("TREASURY_HIGH_VALUE_LIMIT_SATS", 1_000_000n),
+  );
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(envNumber("DEPLOY_SATS", 100_000));
+  logDeployment("Treasury", tx.id, { balance: instance.balance });
+});
+"#;
+
+const ROCK_PAPER_SCISSORS_DEPLOY: &str = r#"/**
+ * Deploys RockPaperScissors with the deploy key as player 1
+ *
+ * .env: OPPONENT_PUBKEY, RPS_MOVE (1 rock, 2 paper, 3 scissors), STAKE_SATS,
+ *       JOIN_BLOCKS, REVEAL_BLOCKS (both counted from now)
+ *
+ * The move is committed with a random nonce. Both are printed once - keep
+ * them, reveal() is impossible without them.
+ */
+
+import { randomBytes } from "crypto";
+import { hash256, int2ByteString, PubKey, toByteString } from "scrypt-ts";
+import RockPaperScissors from "../contracts/RockPaperScissors.scrypt";
+import { deployPublicKey, envBigInt, envNumber, envString, getBlockHeight, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await RockPaperScissors.loadArtifact("artifacts/contracts/RockPaperScissors.scrypt.json");
+
+  const move = envBigInt("RPS_MOVE");
+  if (move < 1n || move > 3n) {
+    throw new Error("RPS_MOVE must be 1 (rock), 2 (paper) or 3 (scissors)");
+  }
+  const nonce = toByteString(randomBytes(32).toString("hex"));
+  const stake = envBigInt("STAKE_SATS", 1000n);
+
+  const height = await getBlockHeight();
+  const joinDeadline = height + envNumber("JOIN_BLOCKS", 144);
+  const revealDeadline = joinDeadline + envNumber("REVEAL_BLOCKS", 144);
+
+  const instance = new RockPaperScissors(
+    PubKey(deployPublicKey().toHex()),
+    PubKey(envString("OPPONENT_PUBKEY")),
+    hash256(nonce + int2ByteString(move)),
+    stake,
+    BigInt(joinDeadline),
+    BigInt(revealDeadline),
+  );
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(Number(stake));
+  logDeployment("RockPaperScissors", tx.id, { joinDeadline, revealDeadline, move, nonce });
+  console.log("Save the move and nonce above - they are needed to reveal.");
+});
+"#;
+
+const LOTTERY_DEPLOY: &str = r#"/**
+ * Deploys Lottery
+ *
+ * .env: TICKET_PRICE_SATS, REVEAL_BLOCKS (reveal deadline, counted from now)
+ */
+
+import Lottery from "../contracts/Lottery.scrypt";
+import { envBigInt, envNumber, getBlockHeight, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await Lottery.loadArtifact("artifacts/contracts/Lottery.scrypt.json");
+
+  const revealDeadline = (await getBlockHeight()) + envNumber("REVEAL_BLOCKS", 144);
+  const instance = new Lottery(envBigInt("TICKET_PRICE_SATS", 1000n), BigInt(revealDeadline));
+  await instance.connect(await getSigner());
+
+  // The pot starts at 1 sat and grows with each ticket
+  const tx = await instance.deploy(1);
+  logDeployment("Lottery", tx.id, { revealDeadline });
+});
+"#;
+
+const PREDICTION_DEPLOY: &str = r#"/**
+ * Registers a question with the oracle and deploys PredictionMarket with the
+ * deploy key as the YES player
+ *
+ * .env: VITE_ORACLE_URL, PREDICTION_QUESTION, OPPONENT_PUBKEY, STAKE_SATS,
+ *       ACCEPT_BLOCKS, SETTLE_BLOCKS (both counted from now)
+ */
+
+import { PubKey, toByteString } from "scrypt-ts";
+import PredictionMarket from "../contracts/PredictionMarket.scrypt";
+import { deployPublicKey, envBigInt, envNumber, envString, getBlockHeight, getSigner, logDeployment, run } from "./utils";
+
+const ORACLE_URL = envString("VITE_ORACLE_URL", "http://localhost:3001");
+
+async function oracleRequest<T>(path: string, init?: RequestInit): Promise<T> {
+  const res = await fetch(ORACLE_URL + path, {
+    ...init,
+    headers: { "Content-Type": "application/json" },
+  });
+  if (!res.ok) {
+    throw new Error("Oracle request " + path + " failed: HTTP " + res.status + " (is oracle/server.mjs running?)");
+  }
+  return res.json() as Promise<T>;
+}
+
+run(async () => {
+  await PredictionMarket.loadArtifact("artifacts/contracts/PredictionMarket.scrypt.json");
+
+  const event = await oracleRequest<{ eventId: string }>("/events", {
+    method: "POST",
+    body: JSON.stringify({ question: envString("PREDICTION_QUESTION") }),
+  });
+  const { pubKey } = await oracleRequest<{ pubKey: string }>("/pubkey");
+
+  const height = await getBlockHeight();
+  const acceptDeadline = height + envNumber("ACCEPT_BLOCKS", 144);
+  const settleDeadline = height + envNumber("SETTLE_BLOCKS", 1008);
+  if (settleDeadline <= acceptDeadline) {
+    throw new Error("SETTLE_BLOCKS must be larger than ACCEPT_BLOCKS");
+  }
+  const stake = envBigInt("STAKE_SATS", 1000n);
+
+  const instance = new PredictionMarket(
+    BigInt(pubKey),
+    toByteString(event.eventId),
+    PubKey(deployPublicKey().toHex()),
+    PubKey(envString("OPPONENT_PUBKEY")),
+    stake,
+    BigInt(acceptDeadline),
+    BigInt(settleDeadline),
+  );
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(Number(stake));
+  logDeployment("PredictionMarket", tx.id, { eventId: event.eventId, acceptDeadline, settleDeadline });
+});
+"#;
+
+const ORDINAL_LISTING_DEPLOY: &str = r#"/**
+ * Lists an inscription: moves its sat into a new OrdinalListing covenant
+ *
+ * .env: LISTING_INSCRIPTION_OUTPOINT (<txid>_<vout>, owned by the deploy
+ *       key), LISTING_PRICE_SATS
+ */
+
+import { bsv, PubKey } from "scrypt-ts";
+import OrdinalListing from "../contracts/OrdinalListing.scrypt";
+import { deployPublicKey, envBigInt, envString, getSigner, logDeployment, run, signP2pkhInput } from "./utils";
+
+run(async () => {
+  await OrdinalListing.loadArtifact("artifacts/contracts/OrdinalListing.scrypt.json");
+
+  const signer = await getSigner();
+  const address = await signer.getDefaultAddress();
+  const listing = new OrdinalListing(PubKey(deployPublicKey().toHex()), envBigInt("LISTING_PRICE_SATS", 10_000n));
+
+  const outpoint = envString("LISTING_INSCRIPTION_OUTPOINT");
+  const [prevTxid, vout] = outpoint.split("_");
+  const prevTx = await signer.provider!.getTransaction(prevTxid);
+  const ordOutput = prevTx.outputs[Number(vout)];
+
+  // Input 0 carries the ordinal sat into output 0
+  const tx = new bsv.Transaction()
+    .from({ txId: prevTxid, outputIndex: Number(vout), script: ordOutput.script.toHex(), satoshis: ordOutput.satoshis })
+    .addOutput(new bsv.Transaction.Output({ script: listing.lockingScript, satoshis: 1 }))
+    .from(await signer.listUnspent(address))
+    .change(address);
+
+  // Every input is the deploy key's P2PKH - the inscription just carries an
+  // envelope, which bsv's tx.sign() doesn't recognise
+  for (let i = 0; i < tx.inputs.length; i++) {
+    await signP2pkhInput(tx, i);
+  }
+
+  const txid = await signer.provider!.sendTransaction(tx);
+  logDeployment("OrdinalListing", txid, { inscription: outpoint, price: listing.price });
+});
+"#;
+
+const TOKEN_MINT_DEPLOY: &str = r#"/**
+ * Deploys a BSV-21 token with its whole supply held by TokenMint
+ *
+ * .env: TOKEN_SYMBOL, TOKEN_MAX_SUPPLY, TOKEN_DECIMALS, TOKEN_MINT_LIMIT
+ *       (max tokens per mint() call)
+ */
+
+import { toByteString } from "scrypt-ts";
+import { OrdiProvider } from "scrypt-ord";
+import TokenMint from "../contracts/TokenMint.scrypt";
+import { envBigInt, envString, getSigner, logDeployment, network, run } from "./utils";
+
+run(async () => {
+  await TokenMint.loadArtifact("artifacts/contracts/TokenMint.scrypt.json");
+
+  const sym = envString("TOKEN_SYMBOL");
+  const instance = new TokenMint(
+    toByteString(""),
+    toByteString(sym, true),
+    envBigInt("TOKEN_MAX_SUPPLY"),
+    envBigInt("TOKEN_DECIMALS", 0n),
+    envBigInt("TOKEN_MINT_LIMIT"),
+  );
+  await instance.connect(await getSigner(new OrdiProvider(network)));
+
+  const tokenId = await instance.deployToken();
+  logDeployment("TokenMint", tokenId.split("_")[0], { tokenId, sym });
+});
+"#;
+
+const PAYMENT_CHANNEL_DEPLOY: &str = r#"/**
+ * Opens a PaymentChannel from the deploy key's address
+ *
+ * .env: CHANNEL_RECEIVER_PUBKEY, CHANNEL_DEPOSIT_SATS, CHANNEL_FEE_SATS,
+ *       CHANNEL_EXPIRY_BLOCKS (counted from now)
+ *
+ * Updates are signed with a fresh session key, printed once - keep it to
+ * keep streaming, and to import the channel into the app.
+ */
+
+import { bsv, PubKey, PubKeyHash } from "scrypt-ts";
+import PaymentChannel from "../contracts/PaymentChannel.scrypt";
+import { deployAddress, envNumber, envString, getBlockHeight, getSigner, logDeployment, network, run } from "./utils";
+
+run(async () => {
+  await PaymentChannel.loadArtifact("artifacts/contracts/PaymentChannel.scrypt.json");
+
+  const deposit = envNumber("CHANNEL_DEPOSIT_SATS", 10_000);
+  const fee = envNumber("CHANNEL_FEE_SATS", 500);
+  if (deposit <= fee) {
+    throw new Error("CHANNEL_DEPOSIT_SATS must be larger than CHANNEL_FEE_SATS");
+  }
+  const expiry = (await getBlockHeight()) + envNumber("CHANNEL_EXPIRY_BLOCKS", 1008);
+  const sessionKey = bsv.PrivateKey.fromRandom(network);
+
+  const instance = new PaymentChannel(
+    PubKey(sessionKey.toPublicKey().toHex()),
+    PubKeyHash(deployAddress().hashBuffer.toString("hex")),
+    PubKey(envString("CHANNEL_RECEIVER_PUBKEY")),
+    BigInt(fee),
+    BigInt(expiry),
+  );
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(deposit);
+  logDeployment("PaymentChannel", tx.id, { expiry, sessionKey: sessionKey.toWIF() });
+  console.log("Save the session key above - further updates can't be signed without it.");
+});
+"#;
+
+const ESCROW_DEPLOY: &str = r#"/**
+ * Creates a marketplace listing (Escrow) with the deploy key as seller
+ *
+ * .env: LISTING_TITLE, LISTING_PRICE_SATS, ARBITER_PUBKEY, REVIEW_BLOCKS
+ *       (blocks the buyer has to confirm or dispute, ~144 per day)
+ */
+
+import { PubKey, toByteString } from "scrypt-ts";
+import Escrow from "../contracts/Escrow.scrypt";
+import { deployPublicKey, envBigInt, envString, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await Escrow.loadArtifact("artifacts/contracts/Escrow.scrypt.json");
+
+  const title = envString("LISTING_TITLE");
+  const instance = new Escrow(
+    PubKey(deployPublicKey().toHex()),
+    PubKey(envString("ARBITER_PUBKEY")),
+    toByteString(title, true),
+    envBigInt("LISTING_PRICE_SATS", 10_000n),
+    envBigInt("REVIEW_BLOCKS", 432n),
+  );
+  await instance.connect(await getSigner());
+
+  // 1 sat keeps the listing on-chain until checkout
+  const tx = await instance.deploy(1);
+  logDeployment("Escrow", tx.id, { title, price: instance.price });
+});
+"#;
+
+const PLAYGROUND_DEPLOY: &str = r#"/**
+ * Deploys the Playground starter contract
+ *
+ * .env: PLAYGROUND_SQUARE (unlocked by either of its square roots), DEPLOY_SATS
+ */
+
+import Playground from "../contracts/Playground.scrypt";
+import { envBigInt, envNumber, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await Playground.loadArtifact("artifacts/contracts/Playground.scrypt.json");
+
+  const square = envBigInt("PLAYGROUND_SQUARE", 49n);
+  const instance = new Playground(square);
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(envNumber("DEPLOY_SATS", 1));
+  logDeployment("Playground", tx.id, { square });
+});
+"#;
+
+const CUSTOM_CONTRACT_DEPLOY: &str = r#"/**
+ * Deploys Contract with the deploy key as player 1
+ *
+ * .env: PLAYER2_PUBKEY, TIMEOUT_BLOCKS (counted from now), DEPLOY_SATS
+ *
+ * Update the constructor arguments alongside contracts/Contract.scrypt.ts.
+ */
+
+import { PubKey } from "scrypt-ts";
+import Contract from "../contracts/Contract.scrypt";
+import { deployPublicKey, envNumber, envString, getBlockHeight, getSigner, logDeployment, run } from "./utils";
+
+run(async () => {
+  await Contract.loadArtifact("artifacts/contracts/Contract.scrypt.json");
+
+  const timeout = (await getBlockHeight()) + envNumber("TIMEOUT_BLOCKS", 144);
+  const instance = new Contract(
+    PubKey(deployPublicKey().toHex()),
+    PubKey(envString("PLAYER2_PUBKEY")),
+    BigInt(timeout),
+  );
+  await instance.connect(await getSigner());
+
+  const tx = await instance.deploy(envNumber("DEPLOY_SATS", 1000));
+  logDeployment("Contract", tx.id, { timeout });
+});
+"#;
+
+/// Settings shared by `.env.testnet` and `.env.mainnet`.
+const ENV_SHARED: &str = r#"# Contract artifact path (generated by scrypt-cli compile)
+VITE_ARTIFACT_PATH=/artifacts/contracts/Contract.scrypt.json
+
+# Oracle server (Prediction template only - see oracle/server.mjs)
+VITE_ORACLE_URL=http://localhost:3001
+
+# Payment channel relay (Streaming Payments template only - see relay/server.mjs)
+VITE_RELAY_URL=http://localhost:3002
+
+# Contract UTXO indexer (optional - see indexer/server.mjs)
+VITE_INDEXER_URL=http://localhost:3003
+
+# Multiplayer server (Tic-Tac-Toe and Rock-Paper-Scissors templates only - see multiplayer/server.mjs)
+VITE_MULTIPLAYER_URL=ws://localhost:3004
+
+# HandCash Connect backend (optional - see handcash/server.mjs). Put its
+# HANDCASH_APP_ID and HANDCASH_APP_SECRET in .env only (switching networks keeps
+# them) - the backend reads them, the app never sees them
+VITE_HANDCASH_URL=http://localhost:3005
+
+# Playground compile server (Playground template only - see playground/server.mjs)
+VITE_PLAYGROUND_URL=http://localhost:3006
+
+# State API for profiles, lobbies and cached contract state (optional - see
+# api/server.mjs). Its DATABASE_URL goes in .env only, like the HandCash keys
+VITE_API_URL=http://localhost:3007
+
+# Extra addresses or script hashes for the utxix UTXO panel (comma-separated)
+UTXIX_WATCH=
+# Watch-only address for the utxix wallet panel (defaults to the deploy key's address)
+UTXIX_WALLET=
+# TAAL ARC API key for the utxix broadcaster (optional - WhatsOnChain and GorillaPool need none)
+ARC_API_KEY=
+# Where the utxix panels read chain data and broadcast: whatsonchain, gorillapool, taal or custom (empty: the editor's chain_provider setting)
+CHAIN_PROVIDER=
+# Base URL of a WhatsOnChain-compatible indexer (CHAIN_PROVIDER=custom only)
+CHAIN_PROVIDER_URL=
+"#;
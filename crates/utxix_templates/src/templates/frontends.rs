@@ -0,0 +1,206 @@
+//! Each template's services and main UI component, per framework.
+
+mod auction;
+mod counter;
+mod hello_world;
+mod lottery;
+mod marketplace;
+mod nft_marketplace;
+mod playground;
+mod prediction;
+mod rock_paper_scissors;
+mod streaming_payments;
+mod tic_tac_toe;
+mod token;
+mod treasury;
+
+use crate::{Framework, Template, Variables};
+
+use super::{
+    angular::ANGULAR_GAME_COMPONENT, backends::MULTIPLAYER_CLIENT, react::REACT_GAME_COMPONENT,
+    svelte::SVELTE_GAME_COMPONENT, vue::VUE_GAME_COMPONENT, wallet::CONTRACT_SERVICE,
+};
+use auction::{
+    AUCTION_ANGULAR_COMPONENT, AUCTION_REACT_COMPONENT, AUCTION_SERVICE, AUCTION_SVELTE_COMPONENT,
+    AUCTION_VUE_COMPONENT,
+};
+use counter::{
+    COUNTER_ANGULAR_COMPONENT, COUNTER_REACT_COMPONENT, COUNTER_SERVICE, COUNTER_SVELTE_COMPONENT,
+    COUNTER_VUE_COMPONENT,
+};
+use hello_world::{
+    HELLOWORLD_ANGULAR_COMPONENT, HELLOWORLD_REACT_COMPONENT, HELLOWORLD_SERVICE,
+    HELLOWORLD_SVELTE_COMPONENT, HELLOWORLD_VUE_COMPONENT,
+};
+use lottery::{
+    LOTTERY_ANGULAR_COMPONENT, LOTTERY_REACT_COMPONENT, LOTTERY_SERVICE, LOTTERY_SVELTE_COMPONENT,
+    LOTTERY_VUE_COMPONENT,
+};
+use marketplace::{
+    MARKETPLACE_ANGULAR_COMPONENT, MARKETPLACE_REACT_COMPONENT, MARKETPLACE_SERVICE,
+    MARKETPLACE_SVELTE_COMPONENT, MARKETPLACE_VUE_COMPONENT,
+};
+use nft_marketplace::{
+    NFT_ANGULAR_COMPONENT, NFT_REACT_COMPONENT, NFT_SERVICE, NFT_SVELTE_COMPONENT,
+    NFT_VUE_COMPONENT,
+};
+use playground::{
+    PLAYGROUND_ANGULAR_COMPONENT, PLAYGROUND_REACT_COMPONENT, PLAYGROUND_SERVICE,
+    PLAYGROUND_SVELTE_COMPONENT, PLAYGROUND_VUE_COMPONENT,
+};
+use prediction::{
+    PREDICTION_ANGULAR_COMPONENT, PREDICTION_REACT_COMPONENT, PREDICTION_SERVICE,
+    PREDICTION_SVELTE_COMPONENT, PREDICTION_VUE_COMPONENT,
+};
+use rock_paper_scissors::{
+    RPS_ANGULAR_COMPONENT, RPS_REACT_COMPONENT, RPS_SERVICE, RPS_SVELTE_COMPONENT,
+    RPS_VUE_COMPONENT,
+};
+use streaming_payments::{
+    CHANNEL_ANGULAR_COMPONENT, CHANNEL_REACT_COMPONENT, CHANNEL_SERVICE, CHANNEL_SVELTE_COMPONENT,
+    CHANNEL_VUE_COMPONENT,
+};
+use tic_tac_toe::{
+    TICTACTOE_ANGULAR_COMPONENT, TICTACTOE_REACT_COMPONENT, TICTACTOE_SERVICE,
+    TICTACTOE_SVELTE_COMPONENT, TICTACTOE_VUE_COMPONENT,
+};
+use token::{
+    TOKEN_ANGULAR_COMPONENT, TOKEN_REACT_COMPONENT, TOKEN_SERVICE, TOKEN_SVELTE_COMPONENT,
+    TOKEN_VUE_COMPONENT,
+};
+use treasury::{
+    TREASURY_ANGULAR_COMPONENT, TREASURY_REACT_COMPONENT, TREASURY_SERVICE,
+    TREASURY_SVELTE_COMPONENT, TREASURY_VUE_COMPONENT,
+};
+
+/// The template's services, relative to `framework`'s [`services_dir`].
+pub fn template_services(template: Template, framework: Framework) -> Vec<(&'static str, String)> {
+    let services = match template {
+        Template::HelloWorld => vec![("helloWorldService.ts", HELLOWORLD_SERVICE.to_string())],
+        Template::Counter => vec![("counterService.ts", COUNTER_SERVICE.to_string())],
+        Template::TicTacToe => vec![
+            ("ticTacToeService.ts", TICTACTOE_SERVICE.to_string()),
+            ("multiplayerClient.ts", MULTIPLAYER_CLIENT.to_string()),
+        ],
+        Template::Auction => vec![("auctionService.ts", AUCTION_SERVICE.to_string())],
+        Template::Treasury => vec![("treasuryService.ts", TREASURY_SERVICE.to_string())],
+        Template::RockPaperScissors => vec![
+            ("rpsService.ts", RPS_SERVICE.to_string()),
+            ("multiplayerClient.ts", MULTIPLAYER_CLIENT.to_string()),
+        ],
+        Template::Lottery => vec![("lotteryService.ts", LOTTERY_SERVICE.to_string())],
+        Template::Prediction => vec![("predictionService.ts", PREDICTION_SERVICE.to_string())],
+        Template::NftMarketplace => vec![("nftService.ts", NFT_SERVICE.to_string())],
+        Template::Token => vec![("tokenService.ts", TOKEN_SERVICE.to_string())],
+        Template::StreamingPayments => vec![("channelService.ts", CHANNEL_SERVICE.to_string())],
+        Template::Marketplace => vec![("marketplaceService.ts", MARKETPLACE_SERVICE.to_string())],
+        Template::Playground => vec![("playgroundService.ts", PLAYGROUND_SERVICE.to_string())],
+        Template::Custom => vec![("contractService.ts", CONTRACT_SERVICE.to_string())],
+    };
+    let variables = Variables::new().with("CONTRACTS_DIR", contracts_dir(framework));
+    services
+        .into_iter()
+        .map(|(file, source)| (file, variables.apply(&source)))
+        .collect()
+}
+
+/// `contracts/` relative to `framework`'s [`services_dir`], for the services'
+/// contract imports.
+fn contracts_dir(framework: Framework) -> String {
+    let depth = services_dir(framework).split('/').count();
+    format!("{}contracts", "../".repeat(depth))
+}
+
+/// Directory template services are written to, relative to the project root.
+pub fn services_dir(framework: Framework) -> &'static str {
+    match framework {
+        Framework::Angular => "src/app/services",
+        _ => "src/services",
+    }
+}
+
+/// Path of the template's main UI component, relative to the project root.
+pub fn game_component_path(framework: Framework) -> &'static str {
+    match framework {
+        Framework::React | Framework::Nextjs => "src/components/Game.tsx",
+        Framework::Vue => "src/components/Game.vue",
+        Framework::Angular => "src/app/components/game.component.ts",
+        Framework::Svelte => "src/components/Game.svelte",
+    }
+}
+
+pub(super) fn react_game_component(template: Template) -> &'static str {
+    match template {
+        Template::HelloWorld => HELLOWORLD_REACT_COMPONENT,
+        Template::Counter => COUNTER_REACT_COMPONENT,
+        Template::TicTacToe => TICTACTOE_REACT_COMPONENT,
+        Template::Auction => AUCTION_REACT_COMPONENT,
+        Template::Treasury => TREASURY_REACT_COMPONENT,
+        Template::RockPaperScissors => RPS_REACT_COMPONENT,
+        Template::Lottery => LOTTERY_REACT_COMPONENT,
+        Template::Prediction => PREDICTION_REACT_COMPONENT,
+        Template::NftMarketplace => NFT_REACT_COMPONENT,
+        Template::Token => TOKEN_REACT_COMPONENT,
+        Template::StreamingPayments => CHANNEL_REACT_COMPONENT,
+        Template::Marketplace => MARKETPLACE_REACT_COMPONENT,
+        Template::Playground => PLAYGROUND_REACT_COMPONENT,
+        Template::Custom => REACT_GAME_COMPONENT,
+    }
+}
+
+pub(super) fn vue_game_component(template: Template) -> &'static str {
+    match template {
+        Template::HelloWorld => HELLOWORLD_VUE_COMPONENT,
+        Template::Counter => COUNTER_VUE_COMPONENT,
+        Template::TicTacToe => TICTACTOE_VUE_COMPONENT,
+        Template::Auction => AUCTION_VUE_COMPONENT,
+        Template::Treasury => TREASURY_VUE_COMPONENT,
+        Template::RockPaperScissors => RPS_VUE_COMPONENT,
+        Template::Lottery => LOTTERY_VUE_COMPONENT,
+        Template::Prediction => PREDICTION_VUE_COMPONENT,
+        Template::NftMarketplace => NFT_VUE_COMPONENT,
+        Template::Token => TOKEN_VUE_COMPONENT,
+        Template::StreamingPayments => CHANNEL_VUE_COMPONENT,
+        Template::Marketplace => MARKETPLACE_VUE_COMPONENT,
+        Template::Playground => PLAYGROUND_VUE_COMPONENT,
+        Template::Custom => VUE_GAME_COMPONENT,
+    }
+}
+
+pub(super) fn angular_game_component(template: Template) -> &'static str {
+    match template {
+        Template::HelloWorld => HELLOWORLD_ANGULAR_COMPONENT,
+        Template::Counter => COUNTER_ANGULAR_COMPONENT,
+        Template::TicTacToe => TICTACTOE_ANGULAR_COMPONENT,
+        Template::Auction => AUCTION_ANGULAR_COMPONENT,
+        Template::Treasury => TREASURY_ANGULAR_COMPONENT,
+        Template::RockPaperScissors => RPS_ANGULAR_COMPONENT,
+        Template::Lottery => LOTTERY_ANGULAR_COMPONENT,
+        Template::Prediction => PREDICTION_ANGULAR_COMPONENT,
+        Template::NftMarketplace => NFT_ANGULAR_COMPONENT,
+        Template::Token => TOKEN_ANGULAR_COMPONENT,
+        Template::StreamingPayments => CHANNEL_ANGULAR_COMPONENT,
+        Template::Marketplace => MARKETPLACE_ANGULAR_COMPONENT,
+        Template::Playground => PLAYGROUND_ANGULAR_COMPONENT,
+        Template::Custom => ANGULAR_GAME_COMPONENT,
+    }
+}
+
+pub(super) fn svelte_game_component(template: Template) -> &'static str {
+    match template {
+        Template::HelloWorld => HELLOWORLD_SVELTE_COMPONENT,
+        Template::Counter => COUNTER_SVELTE_COMPONENT,
+        Template::TicTacToe => TICTACTOE_SVELTE_COMPONENT,
+        Template::Auction => AUCTION_SVELTE_COMPONENT,
+        Template::Treasury => TREASURY_SVELTE_COMPONENT,
+        Template::RockPaperScissors => RPS_SVELTE_COMPONENT,
+        Template::Lottery => LOTTERY_SVELTE_COMPONENT,
+        Template::Prediction => PREDICTION_SVELTE_COMPONENT,
+        Template::NftMarketplace => NFT_SVELTE_COMPONENT,
+        Template::Token => TOKEN_SVELTE_COMPONENT,
+        Template::StreamingPayments => CHANNEL_SVELTE_COMPONENT,
+        Template::Marketplace => MARKETPLACE_SVELTE_COMPONENT,
+        Template::Playground => PLAYGROUND_SVELTE_COMPONENT,
+        Template::Custom => SVELTE_GAME_COMPONENT,
+    }
+}
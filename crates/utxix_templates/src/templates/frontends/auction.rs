@@ -0,0 +1,772 @@
+//! Auction (English auction with automatic refunds): the template's service and its main
+//! component for each framework.
+
+pub(super) const AUCTION_SERVICE: &str = r#"/**
+ * Auction Service - create, bid, close and reclaim
+ *
+ * Each bid spends the auction UTXO, locks the new bid in the next state and
+ * refunds the outbid bidder in the same transaction. Always work from the
+ * latest txid.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import { bsv, PubKey, findSig, ContractTransaction, MethodCallOptions } from "scrypt-ts";
+import Auction from "__CONTRACTS_DIR__/Auction.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/Auction.scrypt.json";
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await Auction.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// STATE HELPERS
+// ============================================================================
+
+export async function getBlockHeight(): Promise<number> {
+  const res = await fetch(config.wocApiUrl + "/chain/info");
+  if (!res.ok) {
+    throw new Error("Failed to fetch block height: HTTP " + res.status);
+  }
+  return (await res.json()).blocks;
+}
+
+export async function getMyPubKey(): Promise<string> {
+  const signer = await getYoursDirectSigner();
+  return (await signer.getDefaultPubKey()).toHex();
+}
+
+export interface AuctionSummary {
+  auctioneer: string;
+  highestBidder: string;
+  highestBid: number;
+  deadline: number;
+  hasBids: boolean;
+}
+
+export function summarize(instance: Auction): AuctionSummary {
+  return {
+    auctioneer: instance.auctioneer,
+    highestBidder: instance.highestBidder,
+    highestBid: Number(instance.highestBid),
+    deadline: Number(instance.auctionDeadline),
+    hasBids: instance.highestBid > 0n,
+  };
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export async function createAuction(deadline: number) {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const myPubKey = await signer.getDefaultPubKey();
+  const instance = new Auction(PubKey(myPubKey.toHex()), BigInt(deadline));
+  await instance.connect(signer);
+
+  // 1 sat carries the auction until the first bid
+  const tx = await instance.deploy(1);
+  return { txid: tx.id, instance };
+}
+
+export async function restoreAuction(txid: string, outputIndex: number = 0): Promise<Auction> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = Auction.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDER
+// ============================================================================
+
+function payTo(pubKey: string, satoshis: number) {
+  return { script: bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(pubKey).toAddress()), satoshis };
+}
+
+async function buildTx(
+  current: Auction,
+  options: MethodCallOptions<Auction>,
+  outputs: Array<{ script: bsv.Script; satoshis: number }>,
+  extraFunding: number,
+  next?: Auction,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  for (const output of outputs) {
+    tx.addOutput(new bsv.Transaction.Output(output));
+  }
+
+  if (options.lockTime !== undefined) {
+    tx.setLockTime(options.lockTime);
+    tx.setInputSequence(0, options.sequence ?? 0);
+  }
+
+  let total = 0;
+  for (const utxo of await getWalletUtxos()) {
+    if (total >= extraFunding + MIN_FEE_SATS) break;
+    tx.from({ txId: utxo.txid, outputIndex: utxo.vout, script: utxo.script, satoshis: utxo.satoshis });
+    total += utxo.satoshis;
+  }
+  if (total < extraFunding + MIN_FEE_SATS) {
+    throw new Error("Wallet balance too low: need " + (extraFunding + MIN_FEE_SATS) + " sats");
+  }
+
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  const nexts = next ? [{ instance: next, atOutputIndex: 0, balance: outputs[0].satoshis }] : [];
+  return { tx, atInputIndex: 0, nexts };
+}
+
+// ============================================================================
+// ACTIONS
+// ============================================================================
+
+export async function placeBid(instance: Auction, amountSats: number): Promise<{ txid: string }> {
+  if (BigInt(amountSats) <= instance.highestBid) {
+    throw new Error("Bid must be higher than " + instance.highestBid + " sats");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const bidder = PubKey((await signer.getDefaultPubKey()).toHex());
+
+  // Output 0: the new highest bid, output 1: refund for the outbid bidder
+  instance.bindTxBuilder("bid", (current: Auction, options: MethodCallOptions<Auction>) => {
+    const next = current.next();
+    next.highestBidder = bidder;
+    next.highestBid = BigInt(amountSats);
+
+    const outputs = [{ script: next.lockingScript, satoshis: amountSats }];
+    if (current.highestBid > 0n) {
+      outputs.push(payTo(current.highestBidder, Number(current.highestBid)));
+    }
+    return buildTx(current, options, outputs, amountSats, next);
+  });
+
+  const { tx } = await instance.methods.bid(bidder, BigInt(amountSats), {
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<Auction>);
+  return { txid: tx.id };
+}
+
+/**
+ * Auctioneer collects the winning bid once the deadline has passed
+ */
+export async function closeAuction(instance: Auction): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  instance.bindTxBuilder("close", (current: Auction, options: MethodCallOptions<Auction>) =>
+    buildTx(current, options, [payTo(current.auctioneer, Number(current.highestBid))], 0),
+  );
+
+  const { tx } = await instance.methods.close((sigResps: any) => findSig(sigResps, pubKey), {
+    pubKeyOrAddrToSign: pubKey,
+    changeAddress: await signer.getDefaultAddress(),
+    lockTime: Number(instance.auctionDeadline),
+    sequence: 0,
+  } as MethodCallOptions<Auction>);
+  return { txid: tx.id };
+}
+
+/**
+ * Auctioneer takes the deposit back when nobody bid
+ */
+export async function reclaimDeposit(instance: Auction): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  instance.bindTxBuilder("refund", (current: Auction, options: MethodCallOptions<Auction>) =>
+    buildTx(current, options, [payTo(current.auctioneer, current.balance)], 0),
+  );
+
+  const { tx } = await instance.methods.refund((sigResps: any) => findSig(sigResps, pubKey), {
+    pubKeyOrAddrToSign: pubKey,
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<Auction>);
+  return { txid: tx.id };
+}
+
+export default {
+  createAuction,
+  restoreAuction,
+  placeBid,
+  closeAuction,
+  reclaimDeposit,
+};
+"#;
+
+pub(super) const AUCTION_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  createAuction,
+  restoreAuction,
+  placeBid,
+  closeAuction,
+  reclaimDeposit,
+  summarize,
+  getBlockHeight,
+  getMyPubKey,
+} from "../services/auctionService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [auction, setAuction] = useState<any>(null);
+  const [me, setMe] = useState("");
+  const [txid, setTxid] = useState("");
+  const [duration, setDuration] = useState("144");
+  const [bid, setBid] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  // The action may return a message to keep on screen
+  const run = async (label: string, action: () => Promise<string | void>) => {
+    setStatus(label + "...");
+    try {
+      setStatus((await action()) || null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const create = () =>
+    run("Creating auction", async () => {
+      // ~144 blocks per day
+      const height = await getBlockHeight();
+      const result = await createAuction(height + Number(duration));
+      setMe(await getMyPubKey());
+      setTxid(result.txid);
+      setAuction(result.instance);
+    });
+
+  const load = () =>
+    run("Loading auction", async () => {
+      setMe(await getMyPubKey());
+      setAuction(await restoreAuction(txid));
+    });
+
+  // A bid spends the auction UTXO; reload from the new txid
+  const placeMyBid = () =>
+    run("Bidding", async () => {
+      const result = await placeBid(auction, Number(bid));
+      setTxid(result.txid);
+      setAuction(await restoreAuction(result.txid));
+    });
+
+  const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const result = await action();
+      setAuction(null);
+      return "Settled in " + result.txid;
+    });
+
+  const summary = auction ? summarize(auction) : null;
+  const isAuctioneer = summary !== null && summary.auctioneer === me;
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Auction</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to play</p>
+        </div>
+      ) : !summary ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">New auction</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Duration (blocks)"
+              value={duration}
+              onChange={(e) => setDuration(e.target.value)}
+            />
+            <button onClick={create} className="btn btn-primary">Deploy</button>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open an existing auction</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Latest auction txid"
+              value={txid}
+              onChange={(e) => setTxid(e.target.value)}
+            />
+            <button onClick={load} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400 break-all">Latest txid: {txid}</p>
+          <p>
+            Highest bid: {summary.hasBids ? summary.highestBid + " sats" : "none yet"}
+            {summary.hasBids && summary.highestBidder === me ? " (yours)" : ""} · Ends at block {summary.deadline}
+          </p>
+          <div className="flex gap-2">
+            <input
+              className="flex-1 rounded bg-slate-900 p-2"
+              placeholder="Your bid (sats)"
+              value={bid}
+              onChange={(e) => setBid(e.target.value)}
+            />
+            <button onClick={placeMyBid} className="btn btn-primary">Bid</button>
+          </div>
+          {isAuctioneer && (
+            <div className="flex gap-2">
+              {summary.hasBids ? (
+                <button onClick={() => settle("Closing", () => closeAuction(auction))} className="btn btn-secondary">
+                  Close &amp; collect
+                </button>
+              ) : (
+                <button onClick={() => settle("Reclaiming", () => reclaimDeposit(auction))} className="btn btn-secondary">
+                  Cancel auction
+                </button>
+              )}
+            </div>
+          )}
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const AUCTION_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { computed, inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  createAuction,
+  restoreAuction,
+  placeBid,
+  closeAuction,
+  reclaimDeposit,
+  summarize,
+  getBlockHeight,
+  getMyPubKey,
+} from "../services/auctionService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const auction = shallowRef<any>(null);
+const me = ref("");
+const txid = ref("");
+const duration = ref("144");
+const bid = ref("");
+const status = ref<string | null>(null);
+
+const summary = computed(() => (auction.value ? summarize(toRaw(auction.value)) : null));
+const isAuctioneer = computed(() => summary.value !== null && summary.value.auctioneer === me.value);
+
+// The action may return a message to keep on screen
+async function run(label: string, action: () => Promise<string | void>) {
+  status.value = label + "...";
+  try {
+    status.value = (await action()) || null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+const create = () =>
+  run("Creating auction", async () => {
+    // ~144 blocks per day
+    const height = await getBlockHeight();
+    const result = await createAuction(height + Number(duration.value));
+    me.value = await getMyPubKey();
+    txid.value = result.txid;
+    auction.value = result.instance;
+  });
+
+const load = () =>
+  run("Loading auction", async () => {
+    me.value = await getMyPubKey();
+    auction.value = await restoreAuction(txid.value);
+  });
+
+// A bid spends the auction UTXO; reload from the new txid
+const placeMyBid = () =>
+  run("Bidding", async () => {
+    const result = await placeBid(toRaw(auction.value), Number(bid.value));
+    txid.value = result.txid;
+    auction.value = await restoreAuction(result.txid);
+  });
+
+const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+  run(label, async () => {
+    const result = await action();
+    auction.value = null;
+    return "Settled in " + result.txid;
+  });
+
+const close = () => settle("Closing", () => closeAuction(toRaw(auction.value)));
+const cancel = () => settle("Reclaiming", () => reclaimDeposit(toRaw(auction.value)));
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Auction</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+    <div v-else-if="!summary" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New auction</h3>
+        <input v-model="duration" class="w-full rounded bg-slate-900 p-2" placeholder="Duration (blocks)" />
+        <button @click="create" class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing auction</h3>
+        <input v-model="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest auction txid" />
+        <button @click="load" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+      <p>
+        Highest bid: {{ summary.hasBids ? summary.highestBid + " sats" : "none yet" }}{{
+          summary.hasBids && summary.highestBidder === me ? " (yours)" : ""
+        }}
+        · Ends at block {{ summary.deadline }}
+      </p>
+      <div class="flex gap-2">
+        <input v-model="bid" class="flex-1 rounded bg-slate-900 p-2" placeholder="Your bid (sats)" />
+        <button @click="placeMyBid" class="btn btn-primary">Bid</button>
+      </div>
+      <div v-if="isAuctioneer" class="flex gap-2">
+        <button v-if="summary.hasBids" @click="close" class="btn btn-secondary">Close &amp; collect</button>
+        <button v-else @click="cancel" class="btn btn-secondary">Cancel auction</button>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const AUCTION_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  createAuction,
+  restoreAuction,
+  placeBid,
+  closeAuction,
+  reclaimDeposit,
+  summarize,
+  getBlockHeight,
+  getMyPubKey,
+} from "../services/auctionService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Auction</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !auction()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">New auction</h3>
+          <input [(ngModel)]="duration" class="w-full rounded bg-slate-900 p-2" placeholder="Duration (blocks)" />
+          <button (click)="create()" class="btn btn-primary">Deploy</button>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open an existing auction</h3>
+          <input [(ngModel)]="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest auction txid" />
+          <button (click)="load()" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && auction()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+        <p>
+          Highest bid: {{ summary().hasBids ? summary().highestBid + " sats" : "none yet" }}{{
+            summary().hasBids && summary().highestBidder === me ? " (yours)" : ""
+          }}
+          · Ends at block {{ summary().deadline }}
+        </p>
+        <div class="flex gap-2">
+          <input [(ngModel)]="bid" class="flex-1 rounded bg-slate-900 p-2" placeholder="Your bid (sats)" />
+          <button (click)="placeMyBid()" class="btn btn-primary">Bid</button>
+        </div>
+        <div *ngIf="isAuctioneer()" class="flex gap-2">
+          <button *ngIf="summary().hasBids" (click)="close()" class="btn btn-secondary">Close &amp; collect</button>
+          <button *ngIf="!summary().hasBids" (click)="cancel()" class="btn btn-secondary">Cancel auction</button>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  auction = signal<any>(null);
+  status = signal<string | null>(null);
+
+  me = "";
+  txid = "";
+  duration = "144";
+  bid = "";
+
+  summary() {
+    return summarize(this.auction());
+  }
+
+  isAuctioneer() {
+    return this.summary().auctioneer === this.me;
+  }
+
+  // The action may return a message to keep on screen
+  private async run(label: string, action: () => Promise<string | void>) {
+    this.status.set(label + "...");
+    try {
+      this.status.set((await action()) || null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  private settle(label: string, action: () => Promise<{ txid: string }>) {
+    return this.run(label, async () => {
+      const result = await action();
+      this.auction.set(null);
+      return "Settled in " + result.txid;
+    });
+  }
+
+  create() {
+    return this.run("Creating auction", async () => {
+      // ~144 blocks per day
+      const height = await getBlockHeight();
+      const result = await createAuction(height + Number(this.duration));
+      this.me = await getMyPubKey();
+      this.txid = result.txid;
+      this.auction.set(result.instance);
+    });
+  }
+
+  load() {
+    return this.run("Loading auction", async () => {
+      this.me = await getMyPubKey();
+      this.auction.set(await restoreAuction(this.txid));
+    });
+  }
+
+  // A bid spends the auction UTXO; reload from the new txid
+  placeMyBid() {
+    return this.run("Bidding", async () => {
+      const result = await placeBid(this.auction(), Number(this.bid));
+      this.txid = result.txid;
+      this.auction.set(await restoreAuction(result.txid));
+    });
+  }
+
+  close() {
+    return this.settle("Closing", () => closeAuction(this.auction()));
+  }
+
+  cancel() {
+    return this.settle("Reclaiming", () => reclaimDeposit(this.auction()));
+  }
+}
+"#;
+
+pub(super) const AUCTION_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    createAuction,
+    restoreAuction,
+    placeBid,
+    closeAuction,
+    reclaimDeposit,
+    summarize,
+    getBlockHeight,
+    getMyPubKey,
+  } from "../services/auctionService";
+
+  export let contractName: string;
+
+  let auction: any = null;
+  let me = "";
+  let txid = "";
+  let duration = "144";
+  let bid = "";
+  let status: string | null = null;
+
+  $: summary = auction ? summarize(auction) : null;
+  $: isAuctioneer = summary !== null && summary.auctioneer === me;
+
+  // The action may return a message to keep on screen
+  async function run(label: string, action: () => Promise<string | void>) {
+    status = label + "...";
+    try {
+      status = (await action()) || null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  const create = () =>
+    run("Creating auction", async () => {
+      // ~144 blocks per day
+      const height = await getBlockHeight();
+      const result = await createAuction(height + Number(duration));
+      me = await getMyPubKey();
+      txid = result.txid;
+      auction = result.instance;
+    });
+
+  const load = () =>
+    run("Loading auction", async () => {
+      me = await getMyPubKey();
+      auction = await restoreAuction(txid);
+    });
+
+  // A bid spends the auction UTXO; reload from the new txid
+  const placeMyBid = () =>
+    run("Bidding", async () => {
+      const result = await placeBid(auction, Number(bid));
+      txid = result.txid;
+      auction = await restoreAuction(result.txid);
+    });
+
+  const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const result = await action();
+      auction = null;
+      return "Settled in " + result.txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Auction</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+  {:else if !summary}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New auction</h3>
+        <input bind:value={duration} class="w-full rounded bg-slate-900 p-2" placeholder="Duration (blocks)" />
+        <button on:click={create} class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing auction</h3>
+        <input bind:value={txid} class="w-full rounded bg-slate-900 p-2" placeholder="Latest auction txid" />
+        <button on:click={load} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Latest txid: {txid}</p>
+      <p>
+        Highest bid: {summary.hasBids ? summary.highestBid + " sats" : "none yet"}{summary.hasBids &&
+        summary.highestBidder === me
+          ? " (yours)"
+          : ""} · Ends at block {summary.deadline}
+      </p>
+      <div class="flex gap-2">
+        <input bind:value={bid} class="flex-1 rounded bg-slate-900 p-2" placeholder="Your bid (sats)" />
+        <button on:click={placeMyBid} class="btn btn-primary">Bid</button>
+      </div>
+      {#if isAuctioneer}
+        <div class="flex gap-2">
+          {#if summary.hasBids}
+            <button on:click={() => settle("Closing", () => closeAuction(auction))} class="btn btn-secondary">
+              Close &amp; collect
+            </button>
+          {:else}
+            <button on:click={() => settle("Reclaiming", () => reclaimDeposit(auction))} class="btn btn-secondary">
+              Cancel auction
+            </button>
+          {/if}
+        </div>
+      {/if}
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
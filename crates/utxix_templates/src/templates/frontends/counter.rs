@@ -0,0 +1,494 @@
+//! Counter (stateful increment): the template's service and its main
+//! component for each framework.
+
+pub(super) const COUNTER_SERVICE: &str = r#"/**
+ * Counter Service - deploy, load and increment the on-chain counter
+ *
+ * The counter is stateful: every increment spends the current UTXO and
+ * creates a new one, so always work from the latest txid.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import { bsv, ContractTransaction, MethodCallOptions } from "scrypt-ts";
+import Counter from "__CONTRACTS_DIR__/Counter.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/Counter.scrypt.json";
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await Counter.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export async function createCounter(start: number = 0) {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const instance = new Counter(BigInt(start));
+  await instance.connect(signer);
+
+  // 1 sat is enough to carry the state forever - fees come from the wallet
+  const tx = await instance.deploy(1);
+  return { txid: tx.id, instance };
+}
+
+export async function restoreCounter(txid: string, outputIndex: number = 0): Promise<Counter> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = Counter.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDER
+// ============================================================================
+
+/**
+ * Output 0 is the next state (the only output increment() verifies, thanks
+ * to ANYONECANPAY_SINGLE); wallet UTXOs pay the fee into output 1 as change.
+ */
+async function buildIncrementTx(
+  current: Counter,
+  options: MethodCallOptions<Counter>,
+  next: Counter,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  tx.addOutput(new bsv.Transaction.Output({ script: next.lockingScript, satoshis: current.balance }));
+
+  let total = 0;
+  for (const utxo of await getWalletUtxos()) {
+    if (total >= MIN_FEE_SATS) break;
+    tx.from({ txId: utxo.txid, outputIndex: utxo.vout, script: utxo.script, satoshis: utxo.satoshis });
+    total += utxo.satoshis;
+  }
+  if (total < MIN_FEE_SATS) {
+    throw new Error("Wallet balance too low: need " + MIN_FEE_SATS + " sats for the fee");
+  }
+
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: current.balance }] };
+}
+
+// ============================================================================
+// ACTIONS
+// ============================================================================
+
+export async function incrementCounter(instance: Counter): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  instance.bindTxBuilder("increment", (current: Counter, options: MethodCallOptions<Counter>) => {
+    const next = current.next();
+    next.count = current.count + 1n;
+    return buildIncrementTx(current, options, next);
+  });
+
+  const { tx } = await instance.methods.increment({
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<Counter>);
+  return { txid: tx.id };
+}
+
+export default {
+  createCounter,
+  restoreCounter,
+  incrementCounter,
+};
+"#;
+
+pub(super) const COUNTER_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import { createCounter, restoreCounter, incrementCounter } from "../services/counterService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [counter, setCounter] = useState<any>(null);
+  const [txid, setTxid] = useState("");
+  const [start, setStart] = useState("0");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const create = () =>
+    run("Deploying counter", async () => {
+      const result = await createCounter(Number(start));
+      setTxid(result.txid);
+      setCounter(result.instance);
+    });
+
+  const load = () =>
+    run("Loading counter", async () => {
+      setCounter(await restoreCounter(txid));
+    });
+
+  // Every increment spends the counter UTXO; reload from the new txid
+  const increment = () =>
+    run("Incrementing", async () => {
+      const result = await incrementCounter(counter);
+      setTxid(result.txid);
+      setCounter(await restoreCounter(result.txid));
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Counter</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to play</p>
+        </div>
+      ) : !counter ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">New counter</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Start value"
+              value={start}
+              onChange={(e) => setStart(e.target.value)}
+            />
+            <button onClick={create} className="btn btn-primary">Deploy</button>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open an existing counter</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Latest counter txid"
+              value={txid}
+              onChange={(e) => setTxid(e.target.value)}
+            />
+            <button onClick={load} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4 text-center">
+          <p className="text-slate-400 break-all">Latest txid: {txid}</p>
+          <p className="text-6xl font-bold">{counter.count.toString()}</p>
+          <div className="flex justify-center gap-2">
+            <button onClick={increment} className="btn btn-primary">Increment</button>
+            <button onClick={load} className="btn btn-secondary">Refresh</button>
+          </div>
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const COUNTER_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import { createCounter, restoreCounter, incrementCounter } from "../services/counterService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const counter = shallowRef<any>(null);
+const txid = ref("");
+const start = ref("0");
+const status = ref<string | null>(null);
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+const create = () =>
+  run("Deploying counter", async () => {
+    const result = await createCounter(Number(start.value));
+    txid.value = result.txid;
+    counter.value = result.instance;
+  });
+
+const load = () =>
+  run("Loading counter", async () => {
+    counter.value = await restoreCounter(txid.value);
+  });
+
+// Every increment spends the counter UTXO; reload from the new txid
+const increment = () =>
+  run("Incrementing", async () => {
+    const result = await incrementCounter(toRaw(counter.value));
+    txid.value = result.txid;
+    counter.value = await restoreCounter(result.txid);
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Counter</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+    <div v-else-if="!counter" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New counter</h3>
+        <input v-model="start" class="w-full rounded bg-slate-900 p-2" placeholder="Start value" />
+        <button @click="create" class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing counter</h3>
+        <input v-model="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest counter txid" />
+        <button @click="load" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-4 text-center">
+      <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+      <p class="text-6xl font-bold">{{ counter.count.toString() }}</p>
+      <div class="flex justify-center gap-2">
+        <button @click="increment" class="btn btn-primary">Increment</button>
+        <button @click="load" class="btn btn-secondary">Refresh</button>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const COUNTER_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import { createCounter, restoreCounter, incrementCounter } from "../services/counterService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Counter</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !counter()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">New counter</h3>
+          <input [(ngModel)]="start" class="w-full rounded bg-slate-900 p-2" placeholder="Start value" />
+          <button (click)="create()" class="btn btn-primary">Deploy</button>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open an existing counter</h3>
+          <input [(ngModel)]="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest counter txid" />
+          <button (click)="load()" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && counter()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4 text-center"
+      >
+        <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+        <p class="text-6xl font-bold">{{ counter().count.toString() }}</p>
+        <div class="flex justify-center gap-2">
+          <button (click)="increment()" class="btn btn-primary">Increment</button>
+          <button (click)="load()" class="btn btn-secondary">Refresh</button>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  counter = signal<any>(null);
+  status = signal<string | null>(null);
+
+  txid = "";
+  start = "0";
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  create() {
+    return this.run("Deploying counter", async () => {
+      const result = await createCounter(Number(this.start));
+      this.txid = result.txid;
+      this.counter.set(result.instance);
+    });
+  }
+
+  load() {
+    return this.run("Loading counter", async () => {
+      this.counter.set(await restoreCounter(this.txid));
+    });
+  }
+
+  // Every increment spends the counter UTXO; reload from the new txid
+  increment() {
+    return this.run("Incrementing", async () => {
+      const result = await incrementCounter(this.counter());
+      this.txid = result.txid;
+      this.counter.set(await restoreCounter(result.txid));
+    });
+  }
+}
+"#;
+
+pub(super) const COUNTER_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import { createCounter, restoreCounter, incrementCounter } from "../services/counterService";
+
+  export let contractName: string;
+
+  let counter: any = null;
+  let txid = "";
+  let start = "0";
+  let status: string | null = null;
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  const create = () =>
+    run("Deploying counter", async () => {
+      const result = await createCounter(Number(start));
+      txid = result.txid;
+      counter = result.instance;
+    });
+
+  const load = () =>
+    run("Loading counter", async () => {
+      counter = await restoreCounter(txid);
+    });
+
+  // Every increment spends the counter UTXO; reload from the new txid
+  const increment = () =>
+    run("Incrementing", async () => {
+      const result = await incrementCounter(counter);
+      txid = result.txid;
+      counter = await restoreCounter(result.txid);
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Counter</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+  {:else if !counter}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New counter</h3>
+        <input bind:value={start} class="w-full rounded bg-slate-900 p-2" placeholder="Start value" />
+        <button on:click={create} class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing counter</h3>
+        <input bind:value={txid} class="w-full rounded bg-slate-900 p-2" placeholder="Latest counter txid" />
+        <button on:click={load} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4 text-center">
+      <p class="text-slate-400 break-all">Latest txid: {txid}</p>
+      <p class="text-6xl font-bold">{counter.count.toString()}</p>
+      <div class="flex justify-center gap-2">
+        <button on:click={increment} class="btn btn-primary">Increment</button>
+        <button on:click={load} class="btn btn-secondary">Refresh</button>
+      </div>
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,544 @@
+//! Hello World (sha256 hash puzzle): the template's service and its main
+//! component for each framework.
+
+pub(super) const HELLOWORLD_SERVICE: &str = r#"/**
+ * HelloWorld Service - lock sats behind a message, unlock them by revealing it
+ *
+ * The contract only stores sha256(message): anyone who knows the message can
+ * claim the sats, so use it to learn the deploy/call flow, not to guard funds.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import { bsv, sha256, toByteString, ContractTransaction, MethodCallOptions } from "scrypt-ts";
+import HelloWorld from "__CONTRACTS_DIR__/HelloWorld.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/HelloWorld.scrypt.json";
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await HelloWorld.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// STATE HELPERS
+// ============================================================================
+
+export interface HelloWorldSummary {
+  lockedSats: number;
+  hash: string;
+}
+
+export function summarize(instance: HelloWorld): HelloWorldSummary {
+  return { lockedSats: instance.balance, hash: instance.hash };
+}
+
+/**
+ * Off-chain mirror of HelloWorld.unlock() - check before broadcasting
+ */
+export function checkMessage(instance: HelloWorld, message: string): boolean {
+  return sha256(toByteString(message, true)) === instance.hash;
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export async function createHelloWorld(message: string, lockedSats: number) {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const instance = new HelloWorld(sha256(toByteString(message, true)));
+  await instance.connect(signer);
+
+  const tx = await instance.deploy(lockedSats);
+  return { txid: tx.id, instance };
+}
+
+export async function restoreHelloWorld(txid: string, outputIndex: number = 0): Promise<HelloWorld> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = HelloWorld.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDER
+// ============================================================================
+
+/**
+ * The locked sats go to the caller's change output. Wallet UTXOs are only
+ * added when the locked amount doesn't cover the fee.
+ */
+async function buildUnlockTx(
+  current: HelloWorld,
+  options: MethodCallOptions<HelloWorld>,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+
+  let total = current.balance;
+  if (total < MIN_FEE_SATS) {
+    for (const utxo of await getWalletUtxos()) {
+      if (total >= MIN_FEE_SATS) break;
+      tx.from({ txId: utxo.txid, outputIndex: utxo.vout, script: utxo.script, satoshis: utxo.satoshis });
+      total += utxo.satoshis;
+    }
+  }
+  if (total < MIN_FEE_SATS) {
+    throw new Error("Wallet balance too low: need " + MIN_FEE_SATS + " sats for the fee");
+  }
+
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  return { tx, atInputIndex: 0, nexts: [] };
+}
+
+// ============================================================================
+// ACTIONS
+// ============================================================================
+
+export async function unlockHelloWorld(instance: HelloWorld, message: string): Promise<{ txid: string }> {
+  if (!checkMessage(instance, message)) {
+    throw new Error("Wrong message: its sha256 doesn't match the locked hash");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  instance.bindTxBuilder("unlock", buildUnlockTx);
+
+  const { tx } = await instance.methods.unlock(toByteString(message, true), {
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<HelloWorld>);
+  return { txid: tx.id };
+}
+
+export default {
+  createHelloWorld,
+  restoreHelloWorld,
+  unlockHelloWorld,
+};
+"#;
+
+pub(super) const HELLOWORLD_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import { createHelloWorld, restoreHelloWorld, unlockHelloWorld, summarize } from "../services/helloWorldService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [puzzle, setPuzzle] = useState<any>(null);
+  const [txid, setTxid] = useState("");
+  const [message, setMessage] = useState("hello world");
+  const [lockedSats, setLockedSats] = useState("1000");
+  const [guess, setGuess] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  // The action may return a message to keep on screen
+  const run = async (label: string, action: () => Promise<string | void>) => {
+    setStatus(label + "...");
+    try {
+      setStatus((await action()) || null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const create = () =>
+    run("Locking sats", async () => {
+      const result = await createHelloWorld(message, Number(lockedSats));
+      setTxid(result.txid);
+      setPuzzle(result.instance);
+    });
+
+  const load = () =>
+    run("Loading contract", async () => {
+      setPuzzle(await restoreHelloWorld(txid));
+    });
+
+  const unlock = () =>
+    run("Unlocking", async () => {
+      const result = await unlockHelloWorld(puzzle, guess);
+      setPuzzle(null);
+      return "Unlocked! Sats sent to your wallet in " + result.txid;
+    });
+
+  const summary = puzzle ? summarize(puzzle) : null;
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Hello World</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to play</p>
+        </div>
+      ) : !summary ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Lock sats behind a message</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Secret message"
+              value={message}
+              onChange={(e) => setMessage(e.target.value)}
+            />
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Amount (sats)"
+              value={lockedSats}
+              onChange={(e) => setLockedSats(e.target.value)}
+            />
+            <button onClick={create} className="btn btn-primary">Deploy</button>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open an existing contract</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Deploy txid"
+              value={txid}
+              onChange={(e) => setTxid(e.target.value)}
+            />
+            <button onClick={load} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400 break-all">Txid: {txid}</p>
+          <p>Locked: {summary.lockedSats} sats</p>
+          <p className="text-slate-400 break-all">sha256: {summary.hash}</p>
+          <div className="space-y-2">
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Message"
+              value={guess}
+              onChange={(e) => setGuess(e.target.value)}
+            />
+            <button onClick={unlock} className="btn btn-primary">Unlock</button>
+          </div>
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const HELLOWORLD_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { computed, inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import { createHelloWorld, restoreHelloWorld, unlockHelloWorld, summarize } from "../services/helloWorldService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const puzzle = shallowRef<any>(null);
+const txid = ref("");
+const message = ref("hello world");
+const lockedSats = ref("1000");
+const guess = ref("");
+const status = ref<string | null>(null);
+
+const summary = computed(() => (puzzle.value ? summarize(toRaw(puzzle.value)) : null));
+
+// The action may return a message to keep on screen
+async function run(label: string, action: () => Promise<string | void>) {
+  status.value = label + "...";
+  try {
+    status.value = (await action()) || null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+const create = () =>
+  run("Locking sats", async () => {
+    const result = await createHelloWorld(message.value, Number(lockedSats.value));
+    txid.value = result.txid;
+    puzzle.value = result.instance;
+  });
+
+const load = () =>
+  run("Loading contract", async () => {
+    puzzle.value = await restoreHelloWorld(txid.value);
+  });
+
+const unlock = () =>
+  run("Unlocking", async () => {
+    const result = await unlockHelloWorld(toRaw(puzzle.value), guess.value);
+    puzzle.value = null;
+    return "Unlocked! Sats sent to your wallet in " + result.txid;
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Hello World</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+    <div v-else-if="!summary" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Lock sats behind a message</h3>
+        <input v-model="message" class="w-full rounded bg-slate-900 p-2" placeholder="Secret message" />
+        <input v-model="lockedSats" class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+        <button @click="create" class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing contract</h3>
+        <input v-model="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Deploy txid" />
+        <button @click="load" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Txid: {{ txid }}</p>
+      <p>Locked: {{ summary.lockedSats }} sats</p>
+      <p class="text-slate-400 break-all">sha256: {{ summary.hash }}</p>
+      <div class="space-y-2">
+        <input v-model="guess" class="w-full rounded bg-slate-900 p-2" placeholder="Message" />
+        <button @click="unlock" class="btn btn-primary">Unlock</button>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const HELLOWORLD_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import { createHelloWorld, restoreHelloWorld, unlockHelloWorld, summarize } from "../services/helloWorldService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Hello World</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !puzzle()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Lock sats behind a message</h3>
+          <input [(ngModel)]="message" class="w-full rounded bg-slate-900 p-2" placeholder="Secret message" />
+          <input [(ngModel)]="lockedSats" class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+          <button (click)="create()" class="btn btn-primary">Deploy</button>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open an existing contract</h3>
+          <input [(ngModel)]="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Deploy txid" />
+          <button (click)="load()" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && puzzle()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400 break-all">Txid: {{ txid }}</p>
+        <p>Locked: {{ summary().lockedSats }} sats</p>
+        <p class="text-slate-400 break-all">sha256: {{ summary().hash }}</p>
+        <div class="space-y-2">
+          <input [(ngModel)]="guess" class="w-full rounded bg-slate-900 p-2" placeholder="Message" />
+          <button (click)="unlock()" class="btn btn-primary">Unlock</button>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  puzzle = signal<any>(null);
+  status = signal<string | null>(null);
+
+  txid = "";
+  message = "hello world";
+  lockedSats = "1000";
+  guess = "";
+
+  summary() {
+    return summarize(this.puzzle());
+  }
+
+  // The action may return a message to keep on screen
+  private async run(label: string, action: () => Promise<string | void>) {
+    this.status.set(label + "...");
+    try {
+      this.status.set((await action()) || null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  create() {
+    return this.run("Locking sats", async () => {
+      const result = await createHelloWorld(this.message, Number(this.lockedSats));
+      this.txid = result.txid;
+      this.puzzle.set(result.instance);
+    });
+  }
+
+  load() {
+    return this.run("Loading contract", async () => {
+      this.puzzle.set(await restoreHelloWorld(this.txid));
+    });
+  }
+
+  unlock() {
+    return this.run("Unlocking", async () => {
+      const result = await unlockHelloWorld(this.puzzle(), this.guess);
+      this.puzzle.set(null);
+      return "Unlocked! Sats sent to your wallet in " + result.txid;
+    });
+  }
+}
+"#;
+
+pub(super) const HELLOWORLD_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import { createHelloWorld, restoreHelloWorld, unlockHelloWorld, summarize } from "../services/helloWorldService";
+
+  export let contractName: string;
+
+  let puzzle: any = null;
+  let txid = "";
+  let message = "hello world";
+  let lockedSats = "1000";
+  let guess = "";
+  let status: string | null = null;
+
+  $: summary = puzzle ? summarize(puzzle) : null;
+
+  // The action may return a message to keep on screen
+  async function run(label: string, action: () => Promise<string | void>) {
+    status = label + "...";
+    try {
+      status = (await action()) || null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  const create = () =>
+    run("Locking sats", async () => {
+      const result = await createHelloWorld(message, Number(lockedSats));
+      txid = result.txid;
+      puzzle = result.instance;
+    });
+
+  const load = () =>
+    run("Loading contract", async () => {
+      puzzle = await restoreHelloWorld(txid);
+    });
+
+  const unlock = () =>
+    run("Unlocking", async () => {
+      const result = await unlockHelloWorld(puzzle, guess);
+      puzzle = null;
+      return "Unlocked! Sats sent to your wallet in " + result.txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Hello World</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+  {:else if !summary}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Lock sats behind a message</h3>
+        <input bind:value={message} class="w-full rounded bg-slate-900 p-2" placeholder="Secret message" />
+        <input bind:value={lockedSats} class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+        <button on:click={create} class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing contract</h3>
+        <input bind:value={txid} class="w-full rounded bg-slate-900 p-2" placeholder="Deploy txid" />
+        <button on:click={load} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Txid: {txid}</p>
+      <p>Locked: {summary.lockedSats} sats</p>
+      <p class="text-slate-400 break-all">sha256: {summary.hash}</p>
+      <div class="space-y-2">
+        <input bind:value={guess} class="w-full rounded bg-slate-900 p-2" placeholder="Message" />
+        <button on:click={unlock} class="btn btn-primary">Unlock</button>
+      </div>
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
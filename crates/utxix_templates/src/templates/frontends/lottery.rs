@@ -0,0 +1,813 @@
+//! Lottery (commit-reveal randomness): the template's service and its main
+//! component for each framework.
+
+pub(super) const LOTTERY_SERVICE: &str = r#"/**
+ * Lottery Service - enter, reveal and draw
+ *
+ * Each ticket's 32-byte secret is kept in localStorage (keyed by its
+ * commitment) until it is revealed. Losing it means the ticket drops out of
+ * the draw once the reveal deadline passes.
+ *
+ * The lottery is stateful: every enter/reveal spends the current UTXO and
+ * creates a new one, so always work from the latest txid.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  bsv,
+  hash256,
+  byteString2Int,
+  slice,
+  toByteString,
+  PubKey,
+  findSig,
+  ByteString,
+  ContractTransaction,
+  MethodCallOptions,
+} from "scrypt-ts";
+import Lottery from "__CONTRACTS_DIR__/Lottery.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/Lottery.scrypt.json";
+const SECRETS_KEY = "lottery_secrets";
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await Lottery.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// SECRETS
+// ============================================================================
+
+function randomSecret(): string {
+  const bytes = new Uint8Array(32);
+  crypto.getRandomValues(bytes);
+  return Array.from(bytes, (b) => b.toString(16).padStart(2, "0")).join("");
+}
+
+function loadSecrets(): Record<string, string> {
+  try {
+    return JSON.parse(localStorage.getItem(SECRETS_KEY) || "{}");
+  } catch {
+    return {};
+  }
+}
+
+function saveSecret(commitment: string, secret: string) {
+  const secrets = loadSecrets();
+  secrets[commitment] = secret;
+  localStorage.setItem(SECRETS_KEY, JSON.stringify(secrets));
+}
+
+// ============================================================================
+// STATE HELPERS
+// ============================================================================
+
+export async function getBlockHeight(): Promise<number> {
+  const res = await fetch(config.wocApiUrl + "/chain/info");
+  if (!res.ok) {
+    throw new Error("Failed to fetch block height: HTTP " + res.status);
+  }
+  return (await res.json()).blocks;
+}
+
+export interface LotterySummary {
+  pot: number;
+  ticketPrice: number;
+  entryCount: number;
+  revealCount: number;
+  revealDeadline: number;
+  entriesOpen: boolean;
+}
+
+export function summarize(instance: Lottery): LotterySummary {
+  return {
+    pot: instance.balance,
+    ticketPrice: Number(instance.ticketPrice),
+    entryCount: Number(instance.entryCount),
+    revealCount: Number(instance.revealCount),
+    revealDeadline: Number(instance.revealDeadline),
+    entriesOpen: instance.revealCount === 0n,
+  };
+}
+
+/**
+ * Index of the connected wallet's ticket whose secret we hold and which
+ * hasn't been revealed yet
+ */
+export function myUnrevealedTicket(instance: Lottery): number | null {
+  const secrets = loadSecrets();
+  for (let i = 0; i < Number(instance.entryCount); i++) {
+    if (instance.secrets[i] === toByteString("") && secrets[instance.commitments[i]]) {
+      return i;
+    }
+  }
+  return null;
+}
+
+/**
+ * Off-chain mirror of Lottery.draw() - who would win right now
+ */
+export function previewWinner(instance: Lottery): string | null {
+  if (instance.revealCount === 0n) return null;
+  const seed = instance.secrets.join("");
+  const random = byteString2Int(slice(hash256(seed), 0n, 4n) + toByteString("00"));
+  const pick = random % instance.revealCount;
+  let k = 0n;
+  for (let i = 0; i < instance.secrets.length; i++) {
+    if (instance.secrets[i] !== toByteString("")) {
+      if (k === pick) return instance.entrants[i].toString();
+      k++;
+    }
+  }
+  return null;
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export async function createLottery(ticketPriceSats: number, revealDeadline: number) {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const instance = new Lottery(BigInt(ticketPriceSats), BigInt(revealDeadline));
+  await instance.connect(signer);
+
+  // The pot starts at 1 sat and grows with each ticket
+  const tx = await instance.deploy(1);
+  return { txid: tx.id, instance };
+}
+
+export async function restoreLottery(txid: string, outputIndex: number = 0): Promise<Lottery> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = Lottery.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDER
+// ============================================================================
+
+async function buildTx(
+  current: Lottery,
+  options: MethodCallOptions<Lottery>,
+  output: { script: bsv.Script; satoshis: number },
+  extraFunding: number,
+  next?: Lottery,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  tx.addOutput(new bsv.Transaction.Output(output));
+
+  if (options.lockTime !== undefined) {
+    tx.setLockTime(options.lockTime);
+    tx.setInputSequence(0, options.sequence ?? 0);
+  }
+
+  const utxos = await getWalletUtxos();
+  let total = 0;
+  for (const utxo of utxos) {
+    if (total >= extraFunding + MIN_FEE_SATS) break;
+    tx.from({ txId: utxo.txid, outputIndex: utxo.vout, script: utxo.script, satoshis: utxo.satoshis });
+    total += utxo.satoshis;
+  }
+  if (total < extraFunding + MIN_FEE_SATS) {
+    throw new Error("Wallet balance too low: need " + (extraFunding + MIN_FEE_SATS) + " sats");
+  }
+
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  const nexts = next ? [{ instance: next, atOutputIndex: 0, balance: output.satoshis }] : [];
+  return { tx, atInputIndex: 0, nexts };
+}
+
+// ============================================================================
+// ACTIONS
+// ============================================================================
+
+export async function enterLottery(instance: Lottery): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  const secret = randomSecret();
+  const commitment: ByteString = hash256(toByteString(secret));
+  // Persist before broadcasting - the secret can't be recovered later
+  saveSecret(commitment, secret);
+
+  instance.bindTxBuilder("enter", (current: Lottery, options: MethodCallOptions<Lottery>) => {
+    const next = current.next();
+    next.entrants[Number(current.entryCount)] = PubKey(pubKey.toHex());
+    next.commitments[Number(current.entryCount)] = commitment;
+    next.entryCount = current.entryCount + 1n;
+    const satoshis = current.balance + Number(current.ticketPrice);
+    return buildTx(current, options, { script: next.lockingScript, satoshis }, Number(current.ticketPrice), next);
+  });
+
+  const { tx } = await instance.methods.enter(
+    PubKey(pubKey.toHex()),
+    commitment,
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+    } as MethodCallOptions<Lottery>,
+  );
+  return { txid: tx.id };
+}
+
+export async function revealTicket(instance: Lottery): Promise<{ txid: string }> {
+  const index = myUnrevealedTicket(instance);
+  if (index === null) {
+    throw new Error("No unrevealed ticket of yours found in this browser");
+  }
+  const secret = loadSecrets()[instance.commitments[index]];
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  instance.bindTxBuilder("reveal", (current: Lottery, options: MethodCallOptions<Lottery>) => {
+    const next = current.next();
+    next.secrets[index] = toByteString(secret);
+    next.revealCount = current.revealCount + 1n;
+    return buildTx(current, options, { script: next.lockingScript, satoshis: current.balance }, 0, next);
+  });
+
+  const { tx } = await instance.methods.reveal(BigInt(index), toByteString(secret), {
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<Lottery>);
+  return { txid: tx.id };
+}
+
+export async function drawWinner(instance: Lottery): Promise<{ txid: string; winner: string }> {
+  const winner = previewWinner(instance);
+  if (!winner) {
+    throw new Error("Nothing revealed yet");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  instance.bindTxBuilder("draw", (current: Lottery, options: MethodCallOptions<Lottery>) => {
+    const script = bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(winner).toAddress());
+    return buildTx(current, options, { script, satoshis: current.balance }, 0);
+  });
+
+  // Everyone revealed → draw immediately; otherwise wait for the deadline
+  const timeout = instance.revealCount < instance.entryCount
+    ? { lockTime: Number(instance.revealDeadline), sequence: 0 }
+    : {};
+
+  const { tx } = await instance.methods.draw({
+    changeAddress: await signer.getDefaultAddress(),
+    ...timeout,
+  } as MethodCallOptions<Lottery>);
+  return { txid: tx.id, winner };
+}
+
+export default {
+  createLottery,
+  restoreLottery,
+  enterLottery,
+  revealTicket,
+  drawWinner,
+};
+"#;
+
+pub(super) const LOTTERY_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  createLottery,
+  restoreLottery,
+  enterLottery,
+  revealTicket,
+  drawWinner,
+  summarize,
+  myUnrevealedTicket,
+  getBlockHeight,
+} from "../services/lotteryService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [lottery, setLottery] = useState<any>(null);
+  const [txid, setTxid] = useState("");
+  const [ticketPrice, setTicketPrice] = useState("1000");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  // Every action spends the lottery UTXO; reload from the new txid
+  const advance = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const result = await action();
+      setTxid(result.txid);
+      setLottery(await restoreLottery(result.txid));
+    });
+
+  const create = () =>
+    run("Creating lottery", async () => {
+      // Reveals must happen within ~24 blocks
+      const height = await getBlockHeight();
+      const result = await createLottery(Number(ticketPrice), height + 24);
+      setTxid(result.txid);
+      setLottery(result.instance);
+    });
+
+  const load = () =>
+    run("Loading lottery", async () => {
+      setLottery(await restoreLottery(txid));
+    });
+
+  const draw = () =>
+    run("Drawing", async () => {
+      const result = await drawWinner(lottery);
+      setLottery(null);
+      setStatus("Winner " + result.winner + " paid in " + result.txid);
+    });
+
+  const summary = lottery ? summarize(lottery) : null;
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Lottery</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to play</p>
+        </div>
+      ) : !summary ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">New lottery</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Ticket price (sats)"
+              value={ticketPrice}
+              onChange={(e) => setTicketPrice(e.target.value)}
+            />
+            <button onClick={create} className="btn btn-primary">Deploy</button>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open an existing lottery</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Latest lottery txid"
+              value={txid}
+              onChange={(e) => setTxid(e.target.value)}
+            />
+            <button onClick={load} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400 break-all">Latest txid: {txid}</p>
+          <p>
+            Pot: {summary.pot} sats · Tickets: {summary.entryCount} · Revealed: {summary.revealCount} ·
+            Reveal deadline: block {summary.revealDeadline}
+          </p>
+          <div className="flex gap-2">
+            {summary.entriesOpen && (
+              <button onClick={() => advance("Buying ticket", () => enterLottery(lottery))} className="btn btn-primary">
+                Buy ticket ({summary.ticketPrice} sats)
+              </button>
+            )}
+            {myUnrevealedTicket(lottery) !== null && summary.entryCount >= 2 && (
+              <button onClick={() => advance("Revealing", () => revealTicket(lottery))} className="btn btn-secondary">
+                Reveal my ticket
+              </button>
+            )}
+            {summary.revealCount > 0 && (
+              <button onClick={draw} className="btn btn-primary">Draw winner</button>
+            )}
+          </div>
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const LOTTERY_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { computed, inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  createLottery,
+  restoreLottery,
+  enterLottery,
+  revealTicket,
+  drawWinner,
+  summarize,
+  myUnrevealedTicket,
+  getBlockHeight,
+} from "../services/lotteryService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const lottery = shallowRef<any>(null);
+const txid = ref("");
+const ticketPrice = ref("1000");
+const status = ref<string | null>(null);
+
+const summary = computed(() => (lottery.value ? summarize(toRaw(lottery.value)) : null));
+const canReveal = computed(
+  () => lottery.value && myUnrevealedTicket(toRaw(lottery.value)) !== null && summary.value!.entryCount >= 2,
+);
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+// Every action spends the lottery UTXO; reload from the new txid
+const advance = (label: string, action: () => Promise<{ txid: string }>) =>
+  run(label, async () => {
+    const result = await action();
+    txid.value = result.txid;
+    lottery.value = await restoreLottery(result.txid);
+  });
+
+const create = () =>
+  run("Creating lottery", async () => {
+    // Reveals must happen within ~24 blocks
+    const height = await getBlockHeight();
+    const result = await createLottery(Number(ticketPrice.value), height + 24);
+    txid.value = result.txid;
+    lottery.value = result.instance;
+  });
+
+const load = () =>
+  run("Loading lottery", async () => {
+    lottery.value = await restoreLottery(txid.value);
+  });
+
+const enter = () => advance("Buying ticket", () => enterLottery(toRaw(lottery.value)));
+const reveal = () => advance("Revealing", () => revealTicket(toRaw(lottery.value)));
+
+const draw = () =>
+  run("Drawing", async () => {
+    const result = await drawWinner(toRaw(lottery.value));
+    lottery.value = null;
+    status.value = "Winner " + result.winner + " paid in " + result.txid;
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Lottery</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+    <div v-else-if="!summary" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New lottery</h3>
+        <input v-model="ticketPrice" class="w-full rounded bg-slate-900 p-2" placeholder="Ticket price (sats)" />
+        <button @click="create" class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing lottery</h3>
+        <input v-model="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest lottery txid" />
+        <button @click="load" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+      <p>
+        Pot: {{ summary.pot }} sats · Tickets: {{ summary.entryCount }} · Revealed: {{ summary.revealCount }} ·
+        Reveal deadline: block {{ summary.revealDeadline }}
+      </p>
+      <div class="flex gap-2">
+        <button v-if="summary.entriesOpen" @click="enter" class="btn btn-primary">
+          Buy ticket ({{ summary.ticketPrice }} sats)
+        </button>
+        <button v-if="canReveal" @click="reveal" class="btn btn-secondary">Reveal my ticket</button>
+        <button v-if="summary.revealCount > 0" @click="draw" class="btn btn-primary">Draw winner</button>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const LOTTERY_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  createLottery,
+  restoreLottery,
+  enterLottery,
+  revealTicket,
+  drawWinner,
+  summarize,
+  myUnrevealedTicket,
+  getBlockHeight,
+} from "../services/lotteryService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Lottery</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !lottery()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">New lottery</h3>
+          <input [(ngModel)]="ticketPrice" class="w-full rounded bg-slate-900 p-2" placeholder="Ticket price (sats)" />
+          <button (click)="create()" class="btn btn-primary">Deploy</button>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open an existing lottery</h3>
+          <input [(ngModel)]="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest lottery txid" />
+          <button (click)="load()" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && lottery()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+        <p>
+          Pot: {{ summary().pot }} sats · Tickets: {{ summary().entryCount }} ·
+          Revealed: {{ summary().revealCount }} · Reveal deadline: block {{ summary().revealDeadline }}
+        </p>
+        <div class="flex gap-2">
+          <button *ngIf="summary().entriesOpen" (click)="enter()" class="btn btn-primary">
+            Buy ticket ({{ summary().ticketPrice }} sats)
+          </button>
+          <button *ngIf="canReveal()" (click)="reveal()" class="btn btn-secondary">Reveal my ticket</button>
+          <button *ngIf="summary().revealCount > 0" (click)="draw()" class="btn btn-primary">Draw winner</button>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  lottery = signal<any>(null);
+  status = signal<string | null>(null);
+
+  txid = "";
+  ticketPrice = "1000";
+
+  summary() {
+    return summarize(this.lottery());
+  }
+
+  canReveal() {
+    return myUnrevealedTicket(this.lottery()) !== null && this.summary().entryCount >= 2;
+  }
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  // Every action spends the lottery UTXO; reload from the new txid
+  private advance(label: string, action: () => Promise<{ txid: string }>) {
+    return this.run(label, async () => {
+      const result = await action();
+      this.txid = result.txid;
+      this.lottery.set(await restoreLottery(result.txid));
+    });
+  }
+
+  create() {
+    return this.run("Creating lottery", async () => {
+      // Reveals must happen within ~24 blocks
+      const height = await getBlockHeight();
+      const result = await createLottery(Number(this.ticketPrice), height + 24);
+      this.txid = result.txid;
+      this.lottery.set(result.instance);
+    });
+  }
+
+  load() {
+    return this.run("Loading lottery", async () => {
+      this.lottery.set(await restoreLottery(this.txid));
+    });
+  }
+
+  enter() {
+    return this.advance("Buying ticket", () => enterLottery(this.lottery()));
+  }
+
+  reveal() {
+    return this.advance("Revealing", () => revealTicket(this.lottery()));
+  }
+
+  draw() {
+    return this.run("Drawing", async () => {
+      const result = await drawWinner(this.lottery());
+      this.lottery.set(null);
+      this.status.set("Winner " + result.winner + " paid in " + result.txid);
+    });
+  }
+}
+"#;
+
+pub(super) const LOTTERY_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    createLottery,
+    restoreLottery,
+    enterLottery,
+    revealTicket,
+    drawWinner,
+    summarize,
+    myUnrevealedTicket,
+    getBlockHeight,
+  } from "../services/lotteryService";
+
+  export let contractName: string;
+
+  let lottery: any = null;
+  let txid = "";
+  let ticketPrice = "1000";
+  let status: string | null = null;
+
+  $: summary = lottery ? summarize(lottery) : null;
+  $: canReveal = lottery !== null && myUnrevealedTicket(lottery) !== null && summary!.entryCount >= 2;
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  // Every action spends the lottery UTXO; reload from the new txid
+  const advance = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const result = await action();
+      txid = result.txid;
+      lottery = await restoreLottery(result.txid);
+    });
+
+  const create = () =>
+    run("Creating lottery", async () => {
+      // Reveals must happen within ~24 blocks
+      const height = await getBlockHeight();
+      const result = await createLottery(Number(ticketPrice), height + 24);
+      txid = result.txid;
+      lottery = result.instance;
+    });
+
+  const load = () =>
+    run("Loading lottery", async () => {
+      lottery = await restoreLottery(txid);
+    });
+
+  const draw = () =>
+    run("Drawing", async () => {
+      const result = await drawWinner(lottery);
+      lottery = null;
+      status = "Winner " + result.winner + " paid in " + result.txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Lottery</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+  {:else if !summary}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New lottery</h3>
+        <input bind:value={ticketPrice} class="w-full rounded bg-slate-900 p-2" placeholder="Ticket price (sats)" />
+        <button on:click={create} class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing lottery</h3>
+        <input bind:value={txid} class="w-full rounded bg-slate-900 p-2" placeholder="Latest lottery txid" />
+        <button on:click={load} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Latest txid: {txid}</p>
+      <p>
+        Pot: {summary.pot} sats · Tickets: {summary.entryCount} · Revealed: {summary.revealCount} ·
+        Reveal deadline: block {summary.revealDeadline}
+      </p>
+      <div class="flex gap-2">
+        {#if summary.entriesOpen}
+          <button on:click={() => advance("Buying ticket", () => enterLottery(lottery))} class="btn btn-primary">
+            Buy ticket ({summary.ticketPrice} sats)
+          </button>
+        {/if}
+        {#if canReveal}
+          <button on:click={() => advance("Revealing", () => revealTicket(lottery))} class="btn btn-secondary">
+            Reveal my ticket
+          </button>
+        {/if}
+        {#if summary.revealCount > 0}
+          <button on:click={draw} class="btn btn-primary">Draw winner</button>
+        {/if}
+      </div>
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,959 @@
+//! Marketplace (per-listing escrow with arbiter): the template's service and its main
+//! component for each framework.
+
+pub(super) const MARKETPLACE_SERVICE: &str = r#"/**
+ * Marketplace Service - listings, checkout and escrow resolution
+ *
+ * Every listing is its own Escrow contract. The contract moves to a new
+ * UTXO on checkout and dispute, so listings are remembered by their
+ * original txid and followed forward to the latest state on load.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  bsv,
+  toByteString,
+  PubKey,
+  findSig,
+  ContractTransaction,
+  MethodCallOptions,
+} from "scrypt-ts";
+import Escrow from "__CONTRACTS_DIR__/Escrow.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/Escrow.scrypt.json";
+const LISTINGS_KEY = "marketplace_listings";
+const WOC_API = config.wocApiUrl;
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await Escrow.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+export type ListingStatus = "listed" | "paid" | "disputed";
+export type Role = "seller" | "buyer" | "arbiter" | "visitor";
+export type Action = "checkout" | "delist" | "release" | "refund" | "dispute" | "autoRelease" | "resolve";
+
+export const ACTION_LABELS: Record<Action, string> = {
+  checkout: "Buy",
+  delist: "Delist",
+  release: "Confirm delivery",
+  refund: "Refund buyer",
+  dispute: "Open dispute",
+  autoRelease: "Claim after review period",
+  resolve: "Resolve dispute",
+};
+
+export interface ListingView {
+  // Original listing txid - stable across state changes
+  id: string;
+  // Current contract UTXO
+  txid: string;
+  title: string;
+  price: number;
+  balance: number;
+  status: ListingStatus;
+  role: Role;
+  deadline: number;
+  actions: Action[];
+  instance: Escrow;
+}
+
+const STATUSES: ListingStatus[] = ["listed", "paid", "disputed"];
+
+function decodeTitle(hex: string): string {
+  const bytes = new Uint8Array((hex.match(/../g) || []).map((b) => parseInt(b, 16)));
+  return new TextDecoder().decode(bytes);
+}
+
+function actionsFor(status: ListingStatus, role: Role): Action[] {
+  switch (role) {
+    case "visitor":
+      return status === "listed" ? ["checkout"] : [];
+    case "seller":
+      if (status === "listed") return ["delist"];
+      return status === "paid" ? ["refund", "dispute", "autoRelease"] : ["refund"];
+    case "buyer":
+      return status === "paid" ? ["release", "dispute"] : status === "disputed" ? ["release"] : [];
+    case "arbiter":
+      return status === "disputed" ? ["resolve"] : [];
+  }
+}
+
+// ============================================================================
+// LISTING REGISTRY
+// ============================================================================
+
+function loadIds(): string[] {
+  try {
+    return JSON.parse(localStorage.getItem(LISTINGS_KEY) || "[]");
+  } catch {
+    return [];
+  }
+}
+
+function saveIds(ids: string[]) {
+  localStorage.setItem(LISTINGS_KEY, JSON.stringify([...new Set(ids)]));
+}
+
+export function addListing(id: string) {
+  saveIds([...loadIds(), id]);
+}
+
+export function forgetListing(id: string) {
+  saveIds(loadIds().filter((t) => t !== id));
+}
+
+async function spentBy(txid: string): Promise<string | null> {
+  const res = await fetch(WOC_API + "/tx/" + txid + "/0/spent");
+  if (res.status === 404) return null;
+  if (!res.ok) {
+    throw new Error("Spent lookup failed: HTTP " + res.status);
+  }
+  return (await res.json()).txid;
+}
+
+/**
+ * Follows a listing from its original txid to the current contract UTXO.
+ * Returns null once the escrow has paid out.
+ */
+async function followLatest(id: string): Promise<{ txid: string; instance: Escrow } | null> {
+  const signer = await getYoursDirectSigner();
+  let txid = id;
+  let instance = Escrow.fromTx(await signer.provider!.getTransaction(txid), 0);
+
+  for (let next = await spentBy(txid); next; next = await spentBy(txid)) {
+    try {
+      const candidate = Escrow.fromTx(await signer.provider!.getTransaction(next), 0);
+      // Output 0 of a payout isn't this listing's contract
+      if (candidate.seller !== instance.seller || candidate.title !== instance.title) return null;
+      txid = next;
+      instance = candidate;
+    } catch {
+      return null;
+    }
+  }
+
+  await instance.connect(signer);
+  return { txid, instance };
+}
+
+export async function loadListings(): Promise<ListingView[]> {
+  await loadArtifact();
+  const signer = await getYoursDirectSigner();
+  const me = (await signer.getDefaultPubKey()).toHex();
+
+  const views: ListingView[] = [];
+  for (const id of loadIds()) {
+    const latest = await followLatest(id);
+    if (!latest) {
+      forgetListing(id);
+      continue;
+    }
+    const { txid, instance } = latest;
+    const status = STATUSES[Number(instance.status)];
+    const role: Role =
+      instance.seller === me ? "seller"
+      : status !== "listed" && instance.buyer === me ? "buyer"
+      : instance.arbiter === me ? "arbiter"
+      : "visitor";
+    views.push({
+      id,
+      txid,
+      title: decodeTitle(instance.title),
+      price: Number(instance.price),
+      balance: instance.balance,
+      status,
+      role,
+      deadline: Number(instance.deadline),
+      actions: actionsFor(status, role),
+      instance,
+    });
+  }
+  return views;
+}
+
+// ============================================================================
+// CHAIN HELPERS
+// ============================================================================
+
+export async function getBlockHeight(): Promise<number> {
+  const res = await fetch(WOC_API + "/chain/info");
+  if (!res.ok) {
+    throw new Error("Failed to fetch block height: HTTP " + res.status);
+  }
+  return (await res.json()).blocks;
+}
+
+// ============================================================================
+// CREATE LISTING
+// ============================================================================
+
+export interface NewListingParams {
+  title: string;
+  priceSats: number;
+  arbiterPubKeyHex: string;
+  // Blocks the buyer has to confirm or dispute after paying (~144/day)
+  reviewBlocks: number;
+}
+
+export async function createListing(params: NewListingParams): Promise<{ id: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const seller = await signer.getDefaultPubKey();
+
+  const instance = new Escrow(
+    PubKey(seller.toHex()),
+    PubKey(params.arbiterPubKeyHex),
+    toByteString(params.title, true),
+    BigInt(params.priceSats),
+    BigInt(params.reviewBlocks),
+  );
+  await instance.connect(signer);
+
+  // 1 sat keeps the listing on-chain until checkout
+  const tx = await instance.deploy(1);
+  addListing(tx.id);
+  return { id: tx.id };
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDERS
+// ============================================================================
+
+async function addFunding(tx: bsv.Transaction, needed: number) {
+  const utxos = await getWalletUtxos();
+  let total = 0;
+  for (const utxo of utxos) {
+    if (total >= needed) break;
+    tx.from({
+      txId: utxo.txid,
+      outputIndex: utxo.vout,
+      script: utxo.script,
+      satoshis: utxo.satoshis,
+    });
+    total += utxo.satoshis;
+  }
+  if (total < needed) {
+    throw new Error("Wallet balance too low: need " + needed + " sats");
+  }
+}
+
+async function finish(
+  tx: bsv.Transaction,
+  options: MethodCallOptions<Escrow>,
+  extraFunding: number,
+): Promise<bsv.Transaction> {
+  if (options.lockTime !== undefined) {
+    tx.setLockTime(options.lockTime);
+    tx.setInputSequence(0, options.sequence ?? 0);
+  }
+  await addFunding(tx, extraFunding + MIN_FEE_SATS);
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+  return tx;
+}
+
+function stateTxBuilder(mutate: (next: Escrow, current: Escrow) => void, extra: (current: Escrow) => number) {
+  return async (current: Escrow, options: MethodCallOptions<Escrow>): Promise<ContractTransaction> => {
+    const next = current.next();
+    mutate(next, current);
+    const satoshis = current.balance + extra(current);
+
+    const tx = new bsv.Transaction();
+    tx.addInput(current.buildContractInput());
+    tx.addOutput(new bsv.Transaction.Output({ script: next.lockingScript, satoshis }));
+    await finish(tx, options, extra(current));
+
+    return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: satoshis }] };
+  };
+}
+
+function payoutTxBuilder(payouts: (current: Escrow) => Array<{ pubKeyHex: string; satoshis: number }>) {
+  return async (current: Escrow, options: MethodCallOptions<Escrow>): Promise<ContractTransaction> => {
+    const tx = new bsv.Transaction();
+    tx.addInput(current.buildContractInput());
+    for (const payout of payouts(current)) {
+      if (payout.satoshis <= 0) continue;
+      tx.addOutput(new bsv.Transaction.Output({
+        script: bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(payout.pubKeyHex).toAddress()),
+        satoshis: payout.satoshis,
+      }));
+    }
+    await finish(tx, options, 0);
+    return { tx, atInputIndex: 0, nexts: [] };
+  };
+}
+
+const toSeller = (current: Escrow) => [{ pubKeyHex: current.seller.toString(), satoshis: current.balance }];
+const toBuyer = (current: Escrow) => [{ pubKeyHex: current.buyer.toString(), satoshis: current.balance }];
+
+// ============================================================================
+// ACTIONS
+// ============================================================================
+
+/**
+ * Runs a listing action. `sellerAmount` is only used by "resolve".
+ */
+export async function performAction(
+  listing: ListingView,
+  action: Action,
+  sellerAmount?: number,
+): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const instance = listing.instance;
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+  const sign = (sigResps: any) => findSig(sigResps, pubKey);
+  const options = {
+    pubKeyOrAddrToSign: pubKey,
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<Escrow>;
+
+  let result;
+  switch (action) {
+    case "checkout": {
+      // The review deadline counts from this nLockTime
+      const height = await getBlockHeight();
+      instance.bindTxBuilder("checkout", stateTxBuilder(
+        (next, current) => {
+          next.buyer = PubKey(pubKey.toHex());
+          next.status = 1n;
+          next.deadline = BigInt(height) + current.reviewBlocks;
+        },
+        (current) => Number(current.price),
+      ));
+      result = await instance.methods.checkout(PubKey(pubKey.toHex()), sign, {
+        ...options,
+        lockTime: height,
+        sequence: 0,
+      });
+      break;
+    }
+    case "dispute":
+      instance.bindTxBuilder("dispute", stateTxBuilder((next) => (next.status = 2n), () => 0));
+      result = await instance.methods.dispute(sign, listing.role === "buyer", options);
+      break;
+    case "delist":
+      instance.bindTxBuilder("delist", payoutTxBuilder(toSeller));
+      result = await instance.methods.delist(sign, options);
+      break;
+    case "release":
+      instance.bindTxBuilder("release", payoutTxBuilder(toSeller));
+      result = await instance.methods.release(sign, options);
+      break;
+    case "refund":
+      instance.bindTxBuilder("refund", payoutTxBuilder(toBuyer));
+      result = await instance.methods.refund(sign, options);
+      break;
+    case "autoRelease":
+      instance.bindTxBuilder("autoRelease", payoutTxBuilder(toSeller));
+      result = await instance.methods.autoRelease({
+        changeAddress: options.changeAddress,
+        lockTime: listing.deadline,
+        sequence: 0,
+      } as MethodCallOptions<Escrow>);
+      break;
+    case "resolve": {
+      const amount = sellerAmount ?? 0;
+      if (amount < 0 || amount > listing.balance) {
+        throw new Error("Seller amount must be between 0 and " + listing.balance);
+      }
+      instance.bindTxBuilder("resolve", payoutTxBuilder((current) => [
+        { pubKeyHex: current.seller.toString(), satoshis: amount },
+        { pubKeyHex: current.buyer.toString(), satoshis: current.balance - amount },
+      ]));
+      result = await instance.methods.resolve(sign, BigInt(amount), options);
+      break;
+    }
+  }
+  return { txid: result.tx.id };
+}
+
+export default {
+  createListing,
+  loadListings,
+  addListing,
+  performAction,
+};
+"#;
+
+pub(super) const MARKETPLACE_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  Action,
+  ListingView,
+  ACTION_LABELS,
+  createListing,
+  loadListings,
+  addListing,
+  performAction,
+} from "../services/marketplaceService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [page, setPage] = useState<"browse" | "sell">("browse");
+  const [listings, setListings] = useState<ListingView[]>([]);
+  const [splits, setSplits] = useState<Record<string, string>>({});
+  const [listingId, setListingId] = useState("");
+  const [title, setTitle] = useState("");
+  const [price, setPrice] = useState("10000");
+  const [arbiter, setArbiter] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const refresh = () =>
+    run("Loading listings", async () => {
+      setListings(await loadListings());
+    });
+
+  const add = () =>
+    run("Adding listing", async () => {
+      addListing(listingId);
+      setListingId("");
+      setListings(await loadListings());
+    });
+
+  const sell = () =>
+    run("Creating listing", async () => {
+      // ~3 days for the buyer to confirm or dispute
+      await createListing({ title, priceSats: Number(price), arbiterPubKeyHex: arbiter, reviewBlocks: 432 });
+      setTitle("");
+      setListings(await loadListings());
+      setPage("browse");
+    });
+
+  const act = (listing: ListingView, action: Action) =>
+    run(ACTION_LABELS[action], async () => {
+      const { txid } = await performAction(listing, action, Number(splits[listing.id] || 0));
+      setListings(await loadListings());
+      setStatus(ACTION_LABELS[action] + " done: " + txid);
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Marketplace</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to trade</p>
+        </div>
+      ) : (
+        <div className="space-y-6">
+          <div className="flex gap-2 items-center">
+            <button onClick={() => setPage("browse")} className={page === "browse" ? "btn btn-primary" : "btn btn-secondary"}>
+              Browse
+            </button>
+            <button onClick={() => setPage("sell")} className={page === "sell" ? "btn btn-primary" : "btn btn-secondary"}>
+              Sell
+            </button>
+            <p className="text-slate-400 flex-1 text-right">Contract: {contractName}</p>
+          </div>
+
+          {page === "sell" ? (
+            <div className="bg-slate-800 rounded-lg p-6 space-y-2">
+              <h3 className="font-semibold">New listing</h3>
+              <input className="w-full rounded bg-slate-900 p-2" placeholder="Title" value={title} onChange={(e) => setTitle(e.target.value)} />
+              <input className="w-full rounded bg-slate-900 p-2" placeholder="Price (sats)" value={price} onChange={(e) => setPrice(e.target.value)} />
+              <input
+                className="w-full rounded bg-slate-900 p-2"
+                placeholder="Arbiter public key"
+                value={arbiter}
+                onChange={(e) => setArbiter(e.target.value)}
+              />
+              <button onClick={sell} className="btn btn-primary">List item</button>
+            </div>
+          ) : (
+            <div className="space-y-4">
+              <div className="flex gap-2">
+                <input
+                  className="flex-1 rounded bg-slate-900 p-2"
+                  placeholder="Add a listing by txid"
+                  value={listingId}
+                  onChange={(e) => setListingId(e.target.value)}
+                />
+                <button onClick={add} className="btn btn-secondary">Add</button>
+                <button onClick={refresh} className="btn btn-secondary">Refresh</button>
+              </div>
+              {listings.map((listing) => (
+                <div key={listing.id} className="bg-slate-800 rounded-lg p-4 space-y-2">
+                  <div className="flex justify-between">
+                    <h3 className="font-semibold">{listing.title}</h3>
+                    <span>{listing.price} sats</span>
+                  </div>
+                  <p className="text-sm text-slate-400">
+                    {listing.status} · you are {listing.role}
+                    {listing.status === "paid" && " · review ends at block " + listing.deadline}
+                  </p>
+                  <div className="flex gap-2 items-center">
+                    {listing.actions.includes("resolve") && (
+                      <input
+                        className="w-40 rounded bg-slate-900 p-2"
+                        placeholder="Sats to seller"
+                        value={splits[listing.id] || ""}
+                        onChange={(e) => setSplits({ ...splits, [listing.id]: e.target.value })}
+                      />
+                    )}
+                    {listing.actions.map((action) => (
+                      <button key={action} onClick={() => act(listing, action)} className="btn btn-secondary">
+                        {ACTION_LABELS[action]}
+                      </button>
+                    ))}
+                  </div>
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const MARKETPLACE_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  type Action,
+  type ListingView,
+  ACTION_LABELS,
+  createListing,
+  loadListings,
+  addListing,
+  performAction,
+} from "../services/marketplaceService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+const page = ref<"browse" | "sell">("browse");
+// shallowRef keeps the contract instances out of Vue's deep reactivity
+const listings = shallowRef<ListingView[]>([]);
+const splits = ref<Record<string, string>>({});
+const listingId = ref("");
+const title = ref("");
+const price = ref("10000");
+const arbiter = ref("");
+const status = ref<string | null>(null);
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+const refresh = () =>
+  run("Loading listings", async () => {
+    listings.value = await loadListings();
+  });
+
+const add = () =>
+  run("Adding listing", async () => {
+    addListing(listingId.value);
+    listingId.value = "";
+    listings.value = await loadListings();
+  });
+
+const sell = () =>
+  run("Creating listing", async () => {
+    // ~3 days for the buyer to confirm or dispute
+    await createListing({
+      title: title.value,
+      priceSats: Number(price.value),
+      arbiterPubKeyHex: arbiter.value,
+      reviewBlocks: 432,
+    });
+    title.value = "";
+    listings.value = await loadListings();
+    page.value = "browse";
+  });
+
+const act = (listing: ListingView, action: Action) =>
+  run(ACTION_LABELS[action], async () => {
+    const { txid } = await performAction(toRaw(listing), action, Number(splits.value[listing.id] || 0));
+    listings.value = await loadListings();
+    status.value = ACTION_LABELS[action] + " done: " + txid;
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Marketplace</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to trade</p>
+    </div>
+    <div v-else class="space-y-6">
+      <div class="flex gap-2 items-center">
+        <button @click="page = 'browse'" :class="page === 'browse' ? 'btn btn-primary' : 'btn btn-secondary'">Browse</button>
+        <button @click="page = 'sell'" :class="page === 'sell' ? 'btn btn-primary' : 'btn btn-secondary'">Sell</button>
+        <p class="text-slate-400 flex-1 text-right">Contract: {{ contractName }}</p>
+      </div>
+
+      <div v-if="page === 'sell'" class="bg-slate-800 rounded-lg p-6 space-y-2">
+        <h3 class="font-semibold">New listing</h3>
+        <input v-model="title" class="w-full rounded bg-slate-900 p-2" placeholder="Title" />
+        <input v-model="price" class="w-full rounded bg-slate-900 p-2" placeholder="Price (sats)" />
+        <input v-model="arbiter" class="w-full rounded bg-slate-900 p-2" placeholder="Arbiter public key" />
+        <button @click="sell" class="btn btn-primary">List item</button>
+      </div>
+      <div v-else class="space-y-4">
+        <div class="flex gap-2">
+          <input v-model="listingId" class="flex-1 rounded bg-slate-900 p-2" placeholder="Add a listing by txid" />
+          <button @click="add" class="btn btn-secondary">Add</button>
+          <button @click="refresh" class="btn btn-secondary">Refresh</button>
+        </div>
+        <div v-for="listing in listings" :key="listing.id" class="bg-slate-800 rounded-lg p-4 space-y-2">
+          <div class="flex justify-between">
+            <h3 class="font-semibold">{{ listing.title }}</h3>
+            <span>{{ listing.price }} sats</span>
+          </div>
+          <p class="text-sm text-slate-400">
+            {{ listing.status }} · you are {{ listing.role }}
+            <template v-if="listing.status === 'paid'"> · review ends at block {{ listing.deadline }}</template>
+          </p>
+          <div class="flex gap-2 items-center">
+            <input
+              v-if="listing.actions.includes('resolve')"
+              v-model="splits[listing.id]"
+              class="w-40 rounded bg-slate-900 p-2"
+              placeholder="Sats to seller"
+            />
+            <button v-for="action in listing.actions" :key="action" @click="act(listing, action)" class="btn btn-secondary">
+              {{ ACTION_LABELS[action] }}
+            </button>
+          </div>
+        </div>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const MARKETPLACE_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  Action,
+  ListingView,
+  ACTION_LABELS,
+  createListing,
+  loadListings,
+  addListing,
+  performAction,
+} from "../services/marketplaceService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Marketplace</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to trade</p>
+      </div>
+
+      <div *ngIf="walletService.wallet().connected" class="space-y-6">
+        <div class="flex gap-2 items-center">
+          <button (click)="page = 'browse'" [class]="page === 'browse' ? 'btn btn-primary' : 'btn btn-secondary'">Browse</button>
+          <button (click)="page = 'sell'" [class]="page === 'sell' ? 'btn btn-primary' : 'btn btn-secondary'">Sell</button>
+          <p class="text-slate-400 flex-1 text-right">Contract: {{ contractName }}</p>
+        </div>
+
+        <div *ngIf="page === 'sell'" class="bg-slate-800 rounded-lg p-6 space-y-2">
+          <h3 class="font-semibold">New listing</h3>
+          <input [(ngModel)]="title" class="w-full rounded bg-slate-900 p-2" placeholder="Title" />
+          <input [(ngModel)]="price" class="w-full rounded bg-slate-900 p-2" placeholder="Price (sats)" />
+          <input [(ngModel)]="arbiter" class="w-full rounded bg-slate-900 p-2" placeholder="Arbiter public key" />
+          <button (click)="sell()" class="btn btn-primary">List item</button>
+        </div>
+
+        <div *ngIf="page === 'browse'" class="space-y-4">
+          <div class="flex gap-2">
+            <input [(ngModel)]="listingId" class="flex-1 rounded bg-slate-900 p-2" placeholder="Add a listing by txid" />
+            <button (click)="add()" class="btn btn-secondary">Add</button>
+            <button (click)="refresh()" class="btn btn-secondary">Refresh</button>
+          </div>
+          <div *ngFor="let listing of listings()" class="bg-slate-800 rounded-lg p-4 space-y-2">
+            <div class="flex justify-between">
+              <h3 class="font-semibold">{{ listing.title }}</h3>
+              <span>{{ listing.price }} sats</span>
+            </div>
+            <p class="text-sm text-slate-400">
+              {{ listing.status }} · you are {{ listing.role }}
+              <span *ngIf="listing.status === 'paid'"> · review ends at block {{ listing.deadline }}</span>
+            </p>
+            <div class="flex gap-2 items-center">
+              <input
+                *ngIf="listing.actions.includes('resolve')"
+                [(ngModel)]="splits[listing.id]"
+                class="w-40 rounded bg-slate-900 p-2"
+                placeholder="Sats to seller"
+              />
+              <button *ngFor="let action of listing.actions" (click)="act(listing, action)" class="btn btn-secondary">
+                {{ labels[action] }}
+              </button>
+            </div>
+          </div>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  readonly labels = ACTION_LABELS;
+
+  listings = signal<ListingView[]>([]);
+  status = signal<string | null>(null);
+
+  page: "browse" | "sell" = "browse";
+  splits: Record<string, string> = {};
+  listingId = "";
+  title = "";
+  price = "10000";
+  arbiter = "";
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  refresh() {
+    return this.run("Loading listings", async () => {
+      this.listings.set(await loadListings());
+    });
+  }
+
+  add() {
+    return this.run("Adding listing", async () => {
+      addListing(this.listingId);
+      this.listingId = "";
+      this.listings.set(await loadListings());
+    });
+  }
+
+  sell() {
+    return this.run("Creating listing", async () => {
+      // ~3 days for the buyer to confirm or dispute
+      await createListing({
+        title: this.title,
+        priceSats: Number(this.price),
+        arbiterPubKeyHex: this.arbiter,
+        reviewBlocks: 432,
+      });
+      this.title = "";
+      this.listings.set(await loadListings());
+      this.page = "browse";
+    });
+  }
+
+  act(listing: ListingView, action: Action) {
+    return this.run(ACTION_LABELS[action], async () => {
+      const { txid } = await performAction(listing, action, Number(this.splits[listing.id] || 0));
+      this.listings.set(await loadListings());
+      this.status.set(ACTION_LABELS[action] + " done: " + txid);
+    });
+  }
+}
+"#;
+
+pub(super) const MARKETPLACE_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    type Action,
+    type ListingView,
+    ACTION_LABELS,
+    createListing,
+    loadListings,
+    addListing,
+    performAction,
+  } from "../services/marketplaceService";
+
+  export let contractName: string;
+
+  let page: "browse" | "sell" = "browse";
+  let listings: ListingView[] = [];
+  let splits: Record<string, string> = {};
+  let listingId = "";
+  let title = "";
+  let price = "10000";
+  let arbiter = "";
+  let status: string | null = null;
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  const refresh = () =>
+    run("Loading listings", async () => {
+      listings = await loadListings();
+    });
+
+  const add = () =>
+    run("Adding listing", async () => {
+      addListing(listingId);
+      listingId = "";
+      listings = await loadListings();
+    });
+
+  const sell = () =>
+    run("Creating listing", async () => {
+      // ~3 days for the buyer to confirm or dispute
+      await createListing({ title, priceSats: Number(price), arbiterPubKeyHex: arbiter, reviewBlocks: 432 });
+      title = "";
+      listings = await loadListings();
+      page = "browse";
+    });
+
+  const act = (listing: ListingView, action: Action) =>
+    run(ACTION_LABELS[action], async () => {
+      const result = await performAction(listing, action, Number(splits[listing.id] || 0));
+      listings = await loadListings();
+      status = ACTION_LABELS[action] + " done: " + result.txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Marketplace</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to trade</p>
+    </div>
+  {:else}
+    <div class="space-y-6">
+      <div class="flex gap-2 items-center">
+        <button on:click={() => (page = "browse")} class={page === "browse" ? "btn btn-primary" : "btn btn-secondary"}>Browse</button>
+        <button on:click={() => (page = "sell")} class={page === "sell" ? "btn btn-primary" : "btn btn-secondary"}>Sell</button>
+        <p class="text-slate-400 flex-1 text-right">Contract: {contractName}</p>
+      </div>
+
+      {#if page === "sell"}
+        <div class="bg-slate-800 rounded-lg p-6 space-y-2">
+          <h3 class="font-semibold">New listing</h3>
+          <input bind:value={title} class="w-full rounded bg-slate-900 p-2" placeholder="Title" />
+          <input bind:value={price} class="w-full rounded bg-slate-900 p-2" placeholder="Price (sats)" />
+          <input bind:value={arbiter} class="w-full rounded bg-slate-900 p-2" placeholder="Arbiter public key" />
+          <button on:click={sell} class="btn btn-primary">List item</button>
+        </div>
+      {:else}
+        <div class="space-y-4">
+          <div class="flex gap-2">
+            <input bind:value={listingId} class="flex-1 rounded bg-slate-900 p-2" placeholder="Add a listing by txid" />
+            <button on:click={add} class="btn btn-secondary">Add</button>
+            <button on:click={refresh} class="btn btn-secondary">Refresh</button>
+          </div>
+          {#each listings as listing (listing.id)}
+            <div class="bg-slate-800 rounded-lg p-4 space-y-2">
+              <div class="flex justify-between">
+                <h3 class="font-semibold">{listing.title}</h3>
+                <span>{listing.price} sats</span>
+              </div>
+              <p class="text-sm text-slate-400">
+                {listing.status} · you are {listing.role}
+                {#if listing.status === "paid"} · review ends at block {listing.deadline}{/if}
+              </p>
+              <div class="flex gap-2 items-center">
+                {#if listing.actions.includes("resolve")}
+                  <input bind:value={splits[listing.id]} class="w-40 rounded bg-slate-900 p-2" placeholder="Sats to seller" />
+                {/if}
+                {#each listing.actions as action (action)}
+                  <button on:click={() => act(listing, action)} class="btn btn-secondary">{ACTION_LABELS[action]}</button>
+                {/each}
+              </div>
+            </div>
+          {/each}
+        </div>
+      {/if}
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,895 @@
+//! NFT Marketplace (ordinal-lock listings): the template's service and its main
+//! component for each framework.
+
+pub(super) const NFT_SERVICE: &str = r#"/**
+ * NFT Service - inscription gallery, listing and purchase
+ *
+ * Inscriptions are indexed by the GorillaPool 1Sat Ordinals API. Yours
+ * Wallet keeps ordinals on a separate ordinals address, so listing signs
+ * the inscription input with that address and fees with the payment one.
+ *
+ * The contract UTXOs themselves aren't indexed by address, so listing
+ * txids are remembered in localStorage. Share a listing txid to let others
+ * add it to their gallery.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  bsv,
+  PubKey,
+  PubKeyHash,
+  findSig,
+  ContractTransaction,
+  MethodCallOptions,
+} from "scrypt-ts";
+import OrdinalListing from "__CONTRACTS_DIR__/OrdinalListing.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/OrdinalListing.scrypt.json";
+const LISTINGS_KEY = "nft_listings";
+const NETWORK = config.network;
+const ORD_API = NETWORK === "testnet"
+  ? "https://testnet.ordinals.gorillapool.io"
+  : "https://ordinals.gorillapool.io";
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await OrdinalListing.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+export interface Inscription {
+  // Current location, "txid_vout"
+  outpoint: string;
+  // Where it was inscribed - stable id used for content
+  origin: string;
+  contentType: string;
+  contentUrl: string;
+}
+
+export interface Listing extends Inscription {
+  txid: string;
+  price: number;
+  mine: boolean;
+  instance: OrdinalListing;
+}
+
+// ============================================================================
+// INDEXER (GorillaPool 1Sat Ordinals API)
+// ============================================================================
+
+async function ordApi<T>(path: string): Promise<T> {
+  const res = await fetch(ORD_API + path);
+  if (!res.ok) {
+    throw new Error("Ordinals API " + path + " failed: HTTP " + res.status);
+  }
+  return res.json();
+}
+
+function toInscription(txo: any): Inscription {
+  const origin = txo.origin?.outpoint || txo.outpoint;
+  return {
+    outpoint: txo.outpoint,
+    origin,
+    contentType: txo.origin?.data?.insc?.file?.type || "unknown",
+    contentUrl: ORD_API + "/content/" + origin,
+  };
+}
+
+async function getOrdAddress(): Promise<string> {
+  const yours = (window as any).yours;
+  if (!yours) {
+    throw new Error("Yours Wallet not available");
+  }
+  return (await yours.getAddresses()).ordAddress;
+}
+
+/**
+ * Inscriptions held by the connected wallet's ordinals address
+ */
+export async function fetchMyInscriptions(): Promise<Inscription[]> {
+  const address = await getOrdAddress();
+  const txos = await ordApi<any[]>("/api/txos/address/" + address + "/unspent?limit=100");
+  // 1-sat outputs with an origin are inscriptions; anything else is dust
+  return txos.filter((txo) => txo.satoshis === 1 && txo.origin).map(toInscription);
+}
+
+// ============================================================================
+// LISTING REGISTRY
+// ============================================================================
+
+function loadListingTxids(): string[] {
+  try {
+    return JSON.parse(localStorage.getItem(LISTINGS_KEY) || "[]");
+  } catch {
+    return [];
+  }
+}
+
+function saveListingTxids(txids: string[]) {
+  localStorage.setItem(LISTINGS_KEY, JSON.stringify([...new Set(txids)]));
+}
+
+export function addListing(txid: string) {
+  saveListingTxids([...loadListingTxids(), txid]);
+}
+
+function removeListing(txid: string) {
+  saveListingTxids(loadListingTxids().filter((t) => t !== txid));
+}
+
+/**
+ * Restores every remembered listing that is still unsold
+ */
+export async function loadListings(): Promise<Listing[]> {
+  await loadArtifact();
+  const signer = await getYoursDirectSigner();
+  const myPubKey = (await signer.getDefaultPubKey()).toHex();
+
+  const listings: Listing[] = [];
+  for (const txid of loadListingTxids()) {
+    const txo = await ordApi<any>("/api/txos/" + txid + "_0");
+    if (txo.spend) {
+      // Sold or cancelled
+      removeListing(txid);
+      continue;
+    }
+    const tx = await signer.provider!.getTransaction(txid);
+    const instance = OrdinalListing.fromTx(tx, 0);
+    await instance.connect(signer);
+    listings.push({
+      ...toInscription(txo),
+      txid,
+      price: Number(instance.price),
+      mine: instance.seller.toString() === myPubKey,
+      instance,
+    });
+  }
+  return listings;
+}
+
+// ============================================================================
+// FUNDING
+// ============================================================================
+
+async function addFunding(tx: bsv.Transaction, needed: number) {
+  const utxos = await getWalletUtxos();
+  let total = 0;
+  for (const utxo of utxos) {
+    if (total >= needed) break;
+    tx.from({
+      txId: utxo.txid,
+      outputIndex: utxo.vout,
+      script: utxo.script,
+      satoshis: utxo.satoshis,
+    });
+    total += utxo.satoshis;
+  }
+  if (total < needed) {
+    throw new Error("Wallet balance too low: need " + needed + " sats");
+  }
+}
+
+// ============================================================================
+// LIST
+// ============================================================================
+
+/**
+ * Moves an inscription into a new OrdinalListing covenant
+ */
+export async function listInscription(inscription: Inscription, priceSats: number): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const sellerPubKey = await signer.getDefaultPubKey();
+  const paymentAddress = await signer.getDefaultAddress();
+  const ordAddress = await getOrdAddress();
+
+  const listing = new OrdinalListing(PubKey(sellerPubKey.toHex()), BigInt(priceSats));
+
+  const [prevTxid, vout] = inscription.outpoint.split("_");
+  const prevTx = await signer.provider!.getTransaction(prevTxid);
+  const ordOutput = prevTx.outputs[Number(vout)];
+
+  // Input 0 carries the ordinal sat into output 0
+  const tx = new bsv.Transaction();
+  tx.from({ txId: prevTxid, outputIndex: Number(vout), script: ordOutput.script.toHex(), satoshis: 1 });
+  tx.addOutput(new bsv.Transaction.Output({ script: listing.lockingScript, satoshis: 1 }));
+  await addFunding(tx, MIN_FEE_SATS);
+  tx.feePerKb(FEE_PER_KB);
+  tx.change(paymentAddress);
+
+  const sigResponses = await signer.getSignatures(
+    tx.toString(),
+    tx.inputs.map((input: any, inputIndex: number) => ({
+      prevTxId: input.prevTxId.toString("hex"),
+      outputIndex: input.outputIndex,
+      inputIndex,
+      satoshis: input.output.satoshis,
+      address: inputIndex === 0 ? ordAddress : paymentAddress.toString(),
+      scriptHex: input.output.script.toHex(),
+    })),
+  );
+  for (const resp of sigResponses) {
+    tx.inputs[resp.inputIndex].setScript(bsv.Script.fromASM(resp.sig + " " + resp.publicKey));
+  }
+
+  const txid = await signer.provider!.sendTransaction(tx);
+  addListing(txid);
+  return { txid };
+}
+
+// ============================================================================
+// PURCHASE / CANCEL
+// ============================================================================
+
+async function buildPurchaseTx(
+  current: OrdinalListing,
+  options: MethodCallOptions<OrdinalListing>,
+  buyerOrdAddress: string,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  // Output 0: the ordinal to the buyer; output 1: the price to the seller
+  tx.addOutput(new bsv.Transaction.Output({
+    script: bsv.Script.buildPublicKeyHashOut(bsv.Address.fromString(buyerOrdAddress)),
+    satoshis: 1,
+  }));
+  tx.addOutput(new bsv.Transaction.Output({
+    script: bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(current.seller.toString()).toAddress()),
+    satoshis: Number(current.price),
+  }));
+
+  // ANYONECANPAY: the buyer's funding inputs are appended freely
+  await addFunding(tx, Number(current.price) + MIN_FEE_SATS);
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  return { tx, atInputIndex: 0, nexts: [] };
+}
+
+export async function buyListing(listing: Listing): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await listing.instance.connect(signer);
+  const buyerOrdAddress = await getOrdAddress();
+  const buyerPkh = bsv.Address.fromString(buyerOrdAddress).hashBuffer.toString("hex");
+
+  listing.instance.bindTxBuilder("purchase", (current: OrdinalListing, options: MethodCallOptions<OrdinalListing>) =>
+    buildPurchaseTx(current, options, buyerOrdAddress),
+  );
+
+  const { tx } = await listing.instance.methods.purchase(PubKeyHash(buyerPkh), {
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<OrdinalListing>);
+  removeListing(listing.txid);
+  return { txid: tx.id };
+}
+
+/**
+ * Seller: delist and send the ordinal back to the ordinals address
+ */
+export async function cancelListing(listing: Listing): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await listing.instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+  const ordAddress = await getOrdAddress();
+
+  listing.instance.bindTxBuilder("cancel", async (current: OrdinalListing, options: MethodCallOptions<OrdinalListing>) => {
+    const tx = new bsv.Transaction();
+    tx.addInput(current.buildContractInput());
+    tx.addOutput(new bsv.Transaction.Output({
+      script: bsv.Script.buildPublicKeyHashOut(bsv.Address.fromString(ordAddress)),
+      satoshis: 1,
+    }));
+    await addFunding(tx, MIN_FEE_SATS);
+    tx.feePerKb(FEE_PER_KB);
+    if (options.changeAddress) {
+      tx.change(options.changeAddress);
+    }
+    return { tx, atInputIndex: 0, nexts: [] } as ContractTransaction;
+  });
+
+  const { tx } = await listing.instance.methods.cancel(
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+    } as MethodCallOptions<OrdinalListing>,
+  );
+  removeListing(listing.txid);
+  return { txid: tx.id };
+}
+
+export default {
+  fetchMyInscriptions,
+  loadListings,
+  addListing,
+  listInscription,
+  buyListing,
+  cancelListing,
+};
+"#;
+
+pub(super) const NFT_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  Inscription,
+  Listing,
+  fetchMyInscriptions,
+  loadListings,
+  addListing,
+  listInscription,
+  buyListing,
+  cancelListing,
+} from "../services/nftService";
+
+interface GameProps {
+  contractName: string;
+}
+
+function Preview({ item }: { item: Inscription }) {
+  return item.contentType.startsWith("image/") ? (
+    <img src={item.contentUrl} alt={item.origin} className="w-full aspect-square object-cover rounded" />
+  ) : (
+    <div className="w-full aspect-square flex items-center justify-center rounded bg-slate-900 text-slate-400 text-sm">
+      {item.contentType}
+    </div>
+  );
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [inscriptions, setInscriptions] = useState<Inscription[]>([]);
+  const [listings, setListings] = useState<Listing[]>([]);
+  const [prices, setPrices] = useState<Record<string, string>>({});
+  const [listingTxid, setListingTxid] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const refresh = () =>
+    run("Indexing inscriptions", async () => {
+      setInscriptions(await fetchMyInscriptions());
+      setListings(await loadListings());
+    });
+
+  const add = () =>
+    run("Adding listing", async () => {
+      addListing(listingTxid);
+      setListingTxid("");
+      setListings(await loadListings());
+    });
+
+  // Any trade changes both the wallet's inscriptions and the listings
+  const trade = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const { txid } = await action();
+      setInscriptions(await fetchMyInscriptions());
+      setListings(await loadListings());
+      setStatus(label + " done: " + txid);
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">NFT Marketplace</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to browse</p>
+        </div>
+      ) : (
+        <div className="space-y-6">
+          <div className="flex gap-2 items-center">
+            <p className="text-slate-400 flex-1">Contract: {contractName}</p>
+            <button onClick={refresh} className="btn btn-secondary">Refresh gallery</button>
+          </div>
+
+          <section className="space-y-2">
+            <h3 className="font-semibold">Listings</h3>
+            <div className="flex gap-2">
+              <input
+                className="flex-1 rounded bg-slate-900 p-2"
+                placeholder="Add a listing by txid"
+                value={listingTxid}
+                onChange={(e) => setListingTxid(e.target.value)}
+              />
+              <button onClick={add} className="btn btn-secondary">Add</button>
+            </div>
+            <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
+              {listings.map((listing) => (
+                <div key={listing.txid} className="bg-slate-800 rounded-lg p-3 space-y-2">
+                  <Preview item={listing} />
+                  <p className="text-sm">{listing.price} sats</p>
+                  {listing.mine ? (
+                    <button onClick={() => trade("Cancel", () => cancelListing(listing))} className="btn btn-secondary w-full">
+                      Cancel
+                    </button>
+                  ) : (
+                    <button onClick={() => trade("Purchase", () => buyListing(listing))} className="btn btn-primary w-full">
+                      Buy
+                    </button>
+                  )}
+                </div>
+              ))}
+            </div>
+          </section>
+
+          <section className="space-y-2">
+            <h3 className="font-semibold">My inscriptions</h3>
+            <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
+              {inscriptions.map((item) => (
+                <div key={item.outpoint} className="bg-slate-800 rounded-lg p-3 space-y-2">
+                  <Preview item={item} />
+                  <input
+                    className="w-full rounded bg-slate-900 p-2 text-sm"
+                    placeholder="Price (sats)"
+                    value={prices[item.outpoint] || ""}
+                    onChange={(e) => setPrices({ ...prices, [item.outpoint]: e.target.value })}
+                  />
+                  <button
+                    onClick={() => trade("Listing", () => listInscription(item, Number(prices[item.outpoint])))}
+                    disabled={!prices[item.outpoint]}
+                    className="btn btn-primary w-full"
+                  >
+                    List
+                  </button>
+                </div>
+              ))}
+            </div>
+          </section>
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const NFT_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  type Inscription,
+  type Listing,
+  fetchMyInscriptions,
+  loadListings,
+  addListing,
+  listInscription,
+  buyListing,
+  cancelListing,
+} from "../services/nftService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+const inscriptions = ref<Inscription[]>([]);
+// shallowRef keeps the contract instances out of Vue's deep reactivity
+const listings = shallowRef<Listing[]>([]);
+const prices = ref<Record<string, string>>({});
+const listingTxid = ref("");
+const status = ref<string | null>(null);
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+async function reload() {
+  inscriptions.value = await fetchMyInscriptions();
+  listings.value = await loadListings();
+}
+
+const refresh = () => run("Indexing inscriptions", reload);
+
+const add = () =>
+  run("Adding listing", async () => {
+    addListing(listingTxid.value);
+    listingTxid.value = "";
+    listings.value = await loadListings();
+  });
+
+// Any trade changes both the wallet's inscriptions and the listings
+const trade = (label: string, action: () => Promise<{ txid: string }>) =>
+  run(label, async () => {
+    const { txid } = await action();
+    await reload();
+    status.value = label + " done: " + txid;
+  });
+
+const list = (item: Inscription) =>
+  trade("Listing", () => listInscription(toRaw(item), Number(prices.value[item.outpoint])));
+const buy = (listing: Listing) => trade("Purchase", () => buyListing(toRaw(listing)));
+const cancel = (listing: Listing) => trade("Cancel", () => cancelListing(toRaw(listing)));
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">NFT Marketplace</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to browse</p>
+    </div>
+    <div v-else class="space-y-6">
+      <div class="flex gap-2 items-center">
+        <p class="text-slate-400 flex-1">Contract: {{ contractName }}</p>
+        <button @click="refresh" class="btn btn-secondary">Refresh gallery</button>
+      </div>
+
+      <section class="space-y-2">
+        <h3 class="font-semibold">Listings</h3>
+        <div class="flex gap-2">
+          <input v-model="listingTxid" class="flex-1 rounded bg-slate-900 p-2" placeholder="Add a listing by txid" />
+          <button @click="add" class="btn btn-secondary">Add</button>
+        </div>
+        <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+          <div v-for="listing in listings" :key="listing.txid" class="bg-slate-800 rounded-lg p-3 space-y-2">
+            <img
+              v-if="listing.contentType.startsWith('image/')"
+              :src="listing.contentUrl"
+              :alt="listing.origin"
+              class="w-full aspect-square object-cover rounded"
+            />
+            <div v-else class="w-full aspect-square flex items-center justify-center rounded bg-slate-900 text-slate-400 text-sm">
+              {{ listing.contentType }}
+            </div>
+            <p class="text-sm">{{ listing.price }} sats</p>
+            <button v-if="listing.mine" @click="cancel(listing)" class="btn btn-secondary w-full">Cancel</button>
+            <button v-else @click="buy(listing)" class="btn btn-primary w-full">Buy</button>
+          </div>
+        </div>
+      </section>
+
+      <section class="space-y-2">
+        <h3 class="font-semibold">My inscriptions</h3>
+        <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+          <div v-for="item in inscriptions" :key="item.outpoint" class="bg-slate-800 rounded-lg p-3 space-y-2">
+            <img
+              v-if="item.contentType.startsWith('image/')"
+              :src="item.contentUrl"
+              :alt="item.origin"
+              class="w-full aspect-square object-cover rounded"
+            />
+            <div v-else class="w-full aspect-square flex items-center justify-center rounded bg-slate-900 text-slate-400 text-sm">
+              {{ item.contentType }}
+            </div>
+            <input v-model="prices[item.outpoint]" class="w-full rounded bg-slate-900 p-2 text-sm" placeholder="Price (sats)" />
+            <button @click="list(item)" :disabled="!prices[item.outpoint]" class="btn btn-primary w-full">List</button>
+          </div>
+        </div>
+      </section>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const NFT_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  Inscription,
+  Listing,
+  fetchMyInscriptions,
+  loadListings,
+  addListing,
+  listInscription,
+  buyListing,
+  cancelListing,
+} from "../services/nftService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <ng-template #preview let-item>
+      <img
+        *ngIf="item.contentType.startsWith('image/')"
+        [src]="item.contentUrl"
+        [alt]="item.origin"
+        class="w-full aspect-square object-cover rounded"
+      />
+      <div
+        *ngIf="!item.contentType.startsWith('image/')"
+        class="w-full aspect-square flex items-center justify-center rounded bg-slate-900 text-slate-400 text-sm"
+      >
+        {{ item.contentType }}
+      </div>
+    </ng-template>
+
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">NFT Marketplace</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to browse</p>
+      </div>
+
+      <div *ngIf="walletService.wallet().connected" class="space-y-6">
+        <div class="flex gap-2 items-center">
+          <p class="text-slate-400 flex-1">Contract: {{ contractName }}</p>
+          <button (click)="refresh()" class="btn btn-secondary">Refresh gallery</button>
+        </div>
+
+        <section class="space-y-2">
+          <h3 class="font-semibold">Listings</h3>
+          <div class="flex gap-2">
+            <input [(ngModel)]="listingTxid" class="flex-1 rounded bg-slate-900 p-2" placeholder="Add a listing by txid" />
+            <button (click)="add()" class="btn btn-secondary">Add</button>
+          </div>
+          <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+            <div *ngFor="let listing of listings()" class="bg-slate-800 rounded-lg p-3 space-y-2">
+              <ng-container *ngTemplateOutlet="preview; context: { $implicit: listing }"></ng-container>
+              <p class="text-sm">{{ listing.price }} sats</p>
+              <button *ngIf="listing.mine" (click)="cancel(listing)" class="btn btn-secondary w-full">Cancel</button>
+              <button *ngIf="!listing.mine" (click)="buy(listing)" class="btn btn-primary w-full">Buy</button>
+            </div>
+          </div>
+        </section>
+
+        <section class="space-y-2">
+          <h3 class="font-semibold">My inscriptions</h3>
+          <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+            <div *ngFor="let item of inscriptions()" class="bg-slate-800 rounded-lg p-3 space-y-2">
+              <ng-container *ngTemplateOutlet="preview; context: { $implicit: item }"></ng-container>
+              <input [(ngModel)]="prices[item.outpoint]" class="w-full rounded bg-slate-900 p-2 text-sm" placeholder="Price (sats)" />
+              <button (click)="list(item)" [disabled]="!prices[item.outpoint]" class="btn btn-primary w-full">List</button>
+            </div>
+          </div>
+        </section>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  inscriptions = signal<Inscription[]>([]);
+  listings = signal<Listing[]>([]);
+  status = signal<string | null>(null);
+
+  prices: Record<string, string> = {};
+  listingTxid = "";
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  private async reload() {
+    this.inscriptions.set(await fetchMyInscriptions());
+    this.listings.set(await loadListings());
+  }
+
+  // Any trade changes both the wallet's inscriptions and the listings
+  private trade(label: string, action: () => Promise<{ txid: string }>) {
+    return this.run(label, async () => {
+      const { txid } = await action();
+      await this.reload();
+      this.status.set(label + " done: " + txid);
+    });
+  }
+
+  refresh() {
+    return this.run("Indexing inscriptions", () => this.reload());
+  }
+
+  add() {
+    return this.run("Adding listing", async () => {
+      addListing(this.listingTxid);
+      this.listingTxid = "";
+      this.listings.set(await loadListings());
+    });
+  }
+
+  list(item: Inscription) {
+    return this.trade("Listing", () => listInscription(item, Number(this.prices[item.outpoint])));
+  }
+
+  buy(listing: Listing) {
+    return this.trade("Purchase", () => buyListing(listing));
+  }
+
+  cancel(listing: Listing) {
+    return this.trade("Cancel", () => cancelListing(listing));
+  }
+}
+"#;
+
+pub(super) const NFT_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    type Inscription,
+    type Listing,
+    fetchMyInscriptions,
+    loadListings,
+    addListing,
+    listInscription,
+    buyListing,
+    cancelListing,
+  } from "../services/nftService";
+
+  export let contractName: string;
+
+  let inscriptions: Inscription[] = [];
+  let listings: Listing[] = [];
+  let prices: Record<string, string> = {};
+  let listingTxid = "";
+  let status: string | null = null;
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  async function reload() {
+    inscriptions = await fetchMyInscriptions();
+    listings = await loadListings();
+  }
+
+  const refresh = () => run("Indexing inscriptions", reload);
+
+  const add = () =>
+    run("Adding listing", async () => {
+      addListing(listingTxid);
+      listingTxid = "";
+      listings = await loadListings();
+    });
+
+  // Any trade changes both the wallet's inscriptions and the listings
+  const trade = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const { txid } = await action();
+      await reload();
+      status = label + " done: " + txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">NFT Marketplace</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to browse</p>
+    </div>
+  {:else}
+    <div class="space-y-6">
+      <div class="flex gap-2 items-center">
+        <p class="text-slate-400 flex-1">Contract: {contractName}</p>
+        <button on:click={refresh} class="btn btn-secondary">Refresh gallery</button>
+      </div>
+
+      <section class="space-y-2">
+        <h3 class="font-semibold">Listings</h3>
+        <div class="flex gap-2">
+          <input bind:value={listingTxid} class="flex-1 rounded bg-slate-900 p-2" placeholder="Add a listing by txid" />
+          <button on:click={add} class="btn btn-secondary">Add</button>
+        </div>
+        <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+          {#each listings as listing (listing.txid)}
+            <div class="bg-slate-800 rounded-lg p-3 space-y-2">
+              {#if listing.contentType.startsWith("image/")}
+                <img src={listing.contentUrl} alt={listing.origin} class="w-full aspect-square object-cover rounded" />
+              {:else}
+                <div class="w-full aspect-square flex items-center justify-center rounded bg-slate-900 text-slate-400 text-sm">
+                  {listing.contentType}
+                </div>
+              {/if}
+              <p class="text-sm">{listing.price} sats</p>
+              {#if listing.mine}
+                <button on:click={() => trade("Cancel", () => cancelListing(listing))} class="btn btn-secondary w-full">
+                  Cancel
+                </button>
+              {:else}
+                <button on:click={() => trade("Purchase", () => buyListing(listing))} class="btn btn-primary w-full">
+                  Buy
+                </button>
+              {/if}
+            </div>
+          {/each}
+        </div>
+      </section>
+
+      <section class="space-y-2">
+        <h3 class="font-semibold">My inscriptions</h3>
+        <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+          {#each inscriptions as item (item.outpoint)}
+            <div class="bg-slate-800 rounded-lg p-3 space-y-2">
+              {#if item.contentType.startsWith("image/")}
+                <img src={item.contentUrl} alt={item.origin} class="w-full aspect-square object-cover rounded" />
+              {:else}
+                <div class="w-full aspect-square flex items-center justify-center rounded bg-slate-900 text-slate-400 text-sm">
+                  {item.contentType}
+                </div>
+              {/if}
+              <input bind:value={prices[item.outpoint]} class="w-full rounded bg-slate-900 p-2 text-sm" placeholder="Price (sats)" />
+              <button
+                on:click={() => trade("Listing", () => listInscription(item, Number(prices[item.outpoint])))}
+                disabled={!prices[item.outpoint]}
+                class="btn btn-primary w-full"
+              >
+                List
+              </button>
+            </div>
+          {/each}
+        </div>
+      </section>
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,670 @@
+//! Playground (compile snippets, deploy throwaway contracts): the template's service and its main
+//! component for each framework.
+
+pub(super) const PLAYGROUND_SERVICE: &str = r#"/**
+ * Playground Service - compile sCrypt snippets and deploy them to testnet
+ *
+ * Snippets are compiled by playground/server.mjs: the sCrypt compiler is a
+ * native binary, so the server runs the transpiler and compiler and sends
+ * the artifact back. Deploying needs no generated class - an empty
+ * SmartContract subclass loads the artifact and builds the locking script
+ * from it.
+ *
+ * Start the server with: cd playground && npm start
+ */
+
+import { bsv, SmartContract } from "scrypt-ts";
+import { getYoursDirectSigner } from "./yoursWalletDirect";
+import { config, txUrl } from "./config";
+
+const PLAYGROUND_URL = config.playgroundUrl;
+
+// Constructor arguments in the artifact's script template, e.g. <square>
+const PLACEHOLDER = /<[^>]+>/g;
+
+export interface Example {
+  name: string;
+  source: string;
+}
+
+export const EXAMPLES: Example[] = [
+  {
+    name: "Square root",
+    source: `import { SmartContract, method, prop, assert } from "scrypt-ts";
+
+export class Playground extends SmartContract {
+  @prop()
+  square: bigint;
+
+  constructor(square: bigint) {
+    super(...arguments);
+    this.square = square;
+  }
+
+  @method()
+  public unlock(root: bigint) {
+    assert(root * root == this.square, "Not a square root");
+  }
+}
+`,
+  },
+  {
+    name: "Hash lock",
+    source: `import { SmartContract, method, prop, assert, ByteString, Sha256, sha256 } from "scrypt-ts";
+
+export class HashLock extends SmartContract {
+  @prop()
+  hash: Sha256;
+
+  constructor(hash: Sha256) {
+    super(...arguments);
+    this.hash = hash;
+  }
+
+  @method()
+  public unlock(preimage: ByteString) {
+    assert(sha256(preimage) == this.hash, "Wrong preimage");
+  }
+}
+`,
+  },
+  {
+    name: "Pay to public key hash",
+    source: `import { SmartContract, method, prop, assert, PubKey, PubKeyHash, Sig, hash160 } from "scrypt-ts";
+
+export class P2PKH extends SmartContract {
+  @prop()
+  pubKeyHash: PubKeyHash;
+
+  constructor(pubKeyHash: PubKeyHash) {
+    super(...arguments);
+    this.pubKeyHash = pubKeyHash;
+  }
+
+  @method()
+  public unlock(sig: Sig, pubKey: PubKey) {
+    assert(hash160(pubKey) == this.pubKeyHash, "Wrong public key");
+    assert(this.checkSig(sig, pubKey), "Bad signature");
+  }
+}
+`,
+  },
+];
+
+export interface AbiParam {
+  name: string;
+  type: string;
+}
+
+export interface CompiledSnippet {
+  contract: string;
+  artifact: any;
+  asm: string;
+  // Locking script size before the constructor arguments are filled in
+  scriptBytes: number;
+  params: AbiParam[];
+  // @prop(true) contracts keep state the playground can't build
+  stateful: boolean;
+  compileMs: number;
+}
+
+export async function compileSnippet(source: string): Promise<CompiledSnippet> {
+  let res: Response;
+  try {
+    res = await fetch(PLAYGROUND_URL + "/compile", {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ source }),
+    });
+  } catch {
+    throw new Error(
+      "Can't reach the playground server at " + PLAYGROUND_URL + " (is playground/server.mjs running?)",
+    );
+  }
+  const body = await res.json();
+  if (!res.ok) {
+    throw new Error(body.log ? body.error + ":\n" + body.log : body.error);
+  }
+
+  const artifact = body.artifact;
+  const constructor = artifact.abi.find((entry: any) => entry.type === "constructor");
+  return {
+    contract: artifact.contract,
+    artifact,
+    asm: templateAsm(artifact.hex),
+    scriptBytes: artifact.hex.replace(PLACEHOLDER, "").length / 2,
+    params: constructor?.params ?? [],
+    stateful: (artifact.stateProps ?? []).length > 0,
+    compileMs: body.compileMs,
+  };
+}
+
+/**
+ * ASM of a script template, constructor arguments left as <name>
+ */
+export function templateAsm(hex: string): string {
+  const parts: string[] = [];
+  let last = 0;
+  for (const match of hex.matchAll(PLACEHOLDER)) {
+    parts.push(hexToAsm(hex.slice(last, match.index)), match[0]);
+    last = match.index! + match[0].length;
+  }
+  parts.push(hexToAsm(hex.slice(last)));
+  return parts.filter(Boolean).join(" ");
+}
+
+function hexToAsm(hex: string): string {
+  return hex ? bsv.Script.fromHex(hex).toASM() : "";
+}
+
+/**
+ * A constructor argument typed in the form, as scrypt-ts expects it
+ */
+function parseArg(param: AbiParam, value: string): bigint | boolean | string {
+  const text = value.trim();
+  if (param.type === "int") {
+    if (!/^-?\d+$/.test(text)) throw new Error(param.name + " must be an integer");
+    return BigInt(text);
+  }
+  if (param.type === "bool") {
+    if (text !== "true" && text !== "false") throw new Error(param.name + " must be true or false");
+    return text === "true";
+  }
+  if (param.type.includes("[") || param.type.includes("<")) {
+    throw new Error(param.name + ": " + param.type + " arguments can't be set in the playground");
+  }
+  // ByteString and its subtypes (PubKey, Sha256, ...) are hex
+  if (!/^([0-9a-fA-F]{2})*$/.test(text)) throw new Error(param.name + " must be hex (" + param.type + ")");
+  return text.toLowerCase();
+}
+
+/**
+ * Deploys a compiled snippet as a throwaway testnet contract
+ */
+export async function deploySnippet(
+  compiled: CompiledSnippet,
+  args: string[],
+  lockedSats: number,
+): Promise<{ txid: string; url: string; scriptBytes: number }> {
+  if (config.network !== "testnet") {
+    throw new Error("The playground only deploys to testnet - run npm run use:testnet");
+  }
+  if (compiled.stateful) {
+    throw new Error(
+      compiled.contract + " has @prop(true) state - compile it into contracts/ and deploy it with npm run deploy",
+    );
+  }
+
+  const Snippet = class extends SmartContract {};
+  Snippet.loadArtifact(compiled.artifact);
+  const instance = new Snippet(...compiled.params.map((param, i) => parseArg(param, args[i] ?? "")));
+  await instance.connect(await getYoursDirectSigner());
+
+  const tx = await instance.deploy(lockedSats);
+  return { txid: tx.id, url: txUrl(tx.id), scriptBytes: instance.lockingScript.toBuffer().length };
+}
+
+export default {
+  compileSnippet,
+  deploySnippet,
+};
+"#;
+
+pub(super) const PLAYGROUND_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import { EXAMPLES, compileSnippet, deploySnippet, type CompiledSnippet } from "../services/playgroundService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [source, setSource] = useState(EXAMPLES[0].source);
+  const [compiled, setCompiled] = useState<CompiledSnippet | null>(null);
+  const [args, setArgs] = useState<string[]>([]);
+  const [lockedSats, setLockedSats] = useState("1");
+  const [deployed, setDeployed] = useState<{ txid: string; url: string } | null>(null);
+  const [status, setStatus] = useState<string | null>(null);
+
+  // The action may return a message to keep on screen
+  const run = async (label: string, action: () => Promise<string | void>) => {
+    setStatus(label + "...");
+    try {
+      setStatus((await action()) || null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const edit = (next: string) => {
+    setSource(next);
+    setCompiled(null);
+    setDeployed(null);
+  };
+
+  const compile = () =>
+    run("Compiling", async () => {
+      const result = await compileSnippet(source);
+      setCompiled(result);
+      setArgs(result.params.map(() => ""));
+      return "Compiled " + result.contract + " in " + result.compileMs + " ms";
+    });
+
+  const deploy = () =>
+    run("Deploying", async () => {
+      const result = await deploySnippet(compiled!, args, Number(lockedSats));
+      setDeployed(result);
+      return "Deployed a " + result.scriptBytes + "-byte locking script";
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Playground</h2>
+        <WalletButton />
+      </div>
+
+      <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+        <p className="text-slate-400">Starter contract: {contractName}</p>
+        <div className="flex flex-wrap gap-2">
+          {EXAMPLES.map((example) => (
+            <button key={example.name} onClick={() => edit(example.source)} className="btn btn-secondary">
+              {example.name}
+            </button>
+          ))}
+        </div>
+        <textarea
+          className="w-full h-80 rounded bg-slate-900 p-2 font-mono text-sm"
+          spellCheck={false}
+          value={source}
+          onChange={(e) => edit(e.target.value)}
+        />
+        <button onClick={compile} className="btn btn-primary">Compile</button>
+      </div>
+
+      {compiled && (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <h3 className="font-semibold">
+            {compiled.contract} - {compiled.scriptBytes} bytes
+            {compiled.params.length > 0 && " plus constructor arguments"}
+          </h3>
+          <pre className="max-h-64 overflow-auto rounded bg-slate-900 p-2 text-xs whitespace-pre-wrap break-all">
+            {compiled.asm}
+          </pre>
+
+          {!wallet.connected ? (
+            <p className="text-slate-400">Connect your wallet to deploy it to testnet</p>
+          ) : compiled.stateful ? (
+            <p className="text-slate-400">Stateful contracts compile here but deploy with npm run deploy</p>
+          ) : (
+            <div className="space-y-2">
+              <h3 className="font-semibold">Deploy to testnet</h3>
+              {compiled.params.map((param, i) => (
+                <input
+                  key={param.name}
+                  className="w-full rounded bg-slate-900 p-2"
+                  placeholder={param.name + ": " + param.type}
+                  value={args[i] ?? ""}
+                  onChange={(e) => setArgs(args.map((arg, j) => (j === i ? e.target.value : arg)))}
+                />
+              ))}
+              <input
+                className="w-full rounded bg-slate-900 p-2"
+                placeholder="Amount (sats)"
+                value={lockedSats}
+                onChange={(e) => setLockedSats(e.target.value)}
+              />
+              <button onClick={deploy} className="btn btn-primary">Deploy</button>
+            </div>
+          )}
+
+          {deployed && (
+            <a href={deployed.url} target="_blank" rel="noreferrer" className="block text-slate-400 break-all underline">
+              Txid: {deployed.txid}
+            </a>
+          )}
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400 whitespace-pre-wrap">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const PLAYGROUND_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { inject, ref, shallowRef } from "vue";
+import WalletButton from "./WalletButton.vue";
+import { EXAMPLES, compileSnippet, deploySnippet, type CompiledSnippet } from "../services/playgroundService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+const source = ref(EXAMPLES[0].source);
+// shallowRef keeps the artifact out of Vue's deep reactivity
+const compiled = shallowRef<CompiledSnippet | null>(null);
+const args = ref<string[]>([]);
+const lockedSats = ref("1");
+const deployed = ref<{ txid: string; url: string } | null>(null);
+const status = ref<string | null>(null);
+
+// The action may return a message to keep on screen
+async function run(label: string, action: () => Promise<string | void>) {
+  status.value = label + "...";
+  try {
+    status.value = (await action()) || null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+function edit(next: string) {
+  source.value = next;
+  compiled.value = null;
+  deployed.value = null;
+}
+
+const compile = () =>
+  run("Compiling", async () => {
+    const result = await compileSnippet(source.value);
+    compiled.value = result;
+    args.value = result.params.map(() => "");
+    return "Compiled " + result.contract + " in " + result.compileMs + " ms";
+  });
+
+const deploy = () =>
+  run("Deploying", async () => {
+    const result = await deploySnippet(compiled.value!, args.value, Number(lockedSats.value));
+    deployed.value = result;
+    return "Deployed a " + result.scriptBytes + "-byte locking script";
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Playground</h2>
+      <WalletButton />
+    </div>
+
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Starter contract: {{ contractName }}</p>
+      <div class="flex flex-wrap gap-2">
+        <button v-for="example in EXAMPLES" :key="example.name" @click="edit(example.source)" class="btn btn-secondary">
+          {{ example.name }}
+        </button>
+      </div>
+      <textarea
+        :value="source"
+        @input="edit(($event.target as HTMLTextAreaElement).value)"
+        class="w-full h-80 rounded bg-slate-900 p-2 font-mono text-sm"
+        spellcheck="false"
+      ></textarea>
+      <button @click="compile" class="btn btn-primary">Compile</button>
+    </div>
+
+    <div v-if="compiled" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <h3 class="font-semibold">
+        {{ compiled.contract }} - {{ compiled.scriptBytes }} bytes
+        <template v-if="compiled.params.length > 0">plus constructor arguments</template>
+      </h3>
+      <pre class="max-h-64 overflow-auto rounded bg-slate-900 p-2 text-xs whitespace-pre-wrap break-all">{{ compiled.asm }}</pre>
+
+      <p v-if="!wallet.connected" class="text-slate-400">Connect your wallet to deploy it to testnet</p>
+      <p v-else-if="compiled.stateful" class="text-slate-400">
+        Stateful contracts compile here but deploy with npm run deploy
+      </p>
+      <div v-else class="space-y-2">
+        <h3 class="font-semibold">Deploy to testnet</h3>
+        <input
+          v-for="(param, i) in compiled.params"
+          :key="param.name"
+          v-model="args[i]"
+          class="w-full rounded bg-slate-900 p-2"
+          :placeholder="param.name + ': ' + param.type"
+        />
+        <input v-model="lockedSats" class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+        <button @click="deploy" class="btn btn-primary">Deploy</button>
+      </div>
+
+      <a v-if="deployed" :href="deployed.url" target="_blank" rel="noreferrer" class="block text-slate-400 break-all underline">
+        Txid: {{ deployed.txid }}
+      </a>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400 whitespace-pre-wrap">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const PLAYGROUND_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import { EXAMPLES, compileSnippet, deploySnippet, type CompiledSnippet } from "../services/playgroundService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Playground</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+        <p class="text-slate-400">Starter contract: {{ contractName }}</p>
+        <div class="flex flex-wrap gap-2">
+          <button *ngFor="let example of examples" (click)="edit(example.source)" class="btn btn-secondary">
+            {{ example.name }}
+          </button>
+        </div>
+        <textarea
+          [ngModel]="source"
+          (ngModelChange)="edit($event)"
+          class="w-full h-80 rounded bg-slate-900 p-2 font-mono text-sm"
+          spellcheck="false"
+        ></textarea>
+        <button (click)="compile()" class="btn btn-primary">Compile</button>
+      </div>
+
+      <div *ngIf="compiled() as snippet" class="bg-slate-800 rounded-lg p-6 space-y-4">
+        <h3 class="font-semibold">
+          {{ snippet.contract }} - {{ snippet.scriptBytes }} bytes
+          <ng-container *ngIf="snippet.params.length > 0">plus constructor arguments</ng-container>
+        </h3>
+        <pre class="max-h-64 overflow-auto rounded bg-slate-900 p-2 text-xs whitespace-pre-wrap break-all">{{ snippet.asm }}</pre>
+
+        <p *ngIf="!walletService.wallet().connected" class="text-slate-400">
+          Connect your wallet to deploy it to testnet
+        </p>
+        <p *ngIf="walletService.wallet().connected && snippet.stateful" class="text-slate-400">
+          Stateful contracts compile here but deploy with npm run deploy
+        </p>
+        <div *ngIf="walletService.wallet().connected && !snippet.stateful" class="space-y-2">
+          <h3 class="font-semibold">Deploy to testnet</h3>
+          <input
+            *ngFor="let param of snippet.params; let i = index"
+            [(ngModel)]="args[i]"
+            class="w-full rounded bg-slate-900 p-2"
+            [placeholder]="param.name + ': ' + param.type"
+          />
+          <input [(ngModel)]="lockedSats" class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+          <button (click)="deploy()" class="btn btn-primary">Deploy</button>
+        </div>
+
+        <a
+          *ngIf="deployed() as result"
+          [href]="result.url"
+          target="_blank"
+          rel="noreferrer"
+          class="block text-slate-400 break-all underline"
+        >
+          Txid: {{ result.txid }}
+        </a>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400 whitespace-pre-wrap">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  examples = EXAMPLES;
+  compiled = signal<CompiledSnippet | null>(null);
+  deployed = signal<{ txid: string; url: string } | null>(null);
+  status = signal<string | null>(null);
+
+  source = EXAMPLES[0].source;
+  args: string[] = [];
+  lockedSats = "1";
+
+  // The action may return a message to keep on screen
+  private async run(label: string, action: () => Promise<string | void>) {
+    this.status.set(label + "...");
+    try {
+      this.status.set((await action()) || null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  edit(next: string) {
+    this.source = next;
+    this.compiled.set(null);
+    this.deployed.set(null);
+  }
+
+  compile() {
+    return this.run("Compiling", async () => {
+      const result = await compileSnippet(this.source);
+      this.compiled.set(result);
+      this.args = result.params.map(() => "");
+      return "Compiled " + result.contract + " in " + result.compileMs + " ms";
+    });
+  }
+
+  deploy() {
+    return this.run("Deploying", async () => {
+      const result = await deploySnippet(this.compiled()!, this.args, Number(this.lockedSats));
+      this.deployed.set(result);
+      return "Deployed a " + result.scriptBytes + "-byte locking script";
+    });
+  }
+}
+"#;
+
+pub(super) const PLAYGROUND_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import { EXAMPLES, compileSnippet, deploySnippet, type CompiledSnippet } from "../services/playgroundService";
+
+  export let contractName: string;
+
+  let source = EXAMPLES[0].source;
+  let compiled: CompiledSnippet | null = null;
+  let args: string[] = [];
+  let lockedSats = "1";
+  let deployed: { txid: string; url: string } | null = null;
+  let status: string | null = null;
+
+  // The action may return a message to keep on screen
+  async function run(label: string, action: () => Promise<string | void>) {
+    status = label + "...";
+    try {
+      status = (await action()) || null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  function edit(next: string) {
+    source = next;
+    compiled = null;
+    deployed = null;
+  }
+
+  const compile = () =>
+    run("Compiling", async () => {
+      const result = await compileSnippet(source);
+      compiled = result;
+      args = result.params.map(() => "");
+      return "Compiled " + result.contract + " in " + result.compileMs + " ms";
+    });
+
+  const deploy = () =>
+    run("Deploying", async () => {
+      const result = await deploySnippet(compiled!, args, Number(lockedSats));
+      deployed = result;
+      return "Deployed a " + result.scriptBytes + "-byte locking script";
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Playground</h2>
+    <WalletButton />
+  </div>
+
+  <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+    <p class="text-slate-400">Starter contract: {contractName}</p>
+    <div class="flex flex-wrap gap-2">
+      {#each EXAMPLES as example (example.name)}
+        <button on:click={() => edit(example.source)} class="btn btn-secondary">{example.name}</button>
+      {/each}
+    </div>
+    <textarea
+      value={source}
+      on:input={(e) => edit(e.currentTarget.value)}
+      class="w-full h-80 rounded bg-slate-900 p-2 font-mono text-sm"
+      spellcheck="false"
+    ></textarea>
+    <button on:click={compile} class="btn btn-primary">Compile</button>
+  </div>
+
+  {#if compiled}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <h3 class="font-semibold">
+        {compiled.contract} - {compiled.scriptBytes} bytes
+        {#if compiled.params.length > 0}plus constructor arguments{/if}
+      </h3>
+      <pre class="max-h-64 overflow-auto rounded bg-slate-900 p-2 text-xs whitespace-pre-wrap break-all">{compiled.asm}</pre>
+
+      {#if !$wallet.connected}
+        <p class="text-slate-400">Connect your wallet to deploy it to testnet</p>
+      {:else if compiled.stateful}
+        <p class="text-slate-400">Stateful contracts compile here but deploy with npm run deploy</p>
+      {:else}
+        <div class="space-y-2">
+          <h3 class="font-semibold">Deploy to testnet</h3>
+          {#each compiled.params as param, i (param.name)}
+            <input bind:value={args[i]} class="w-full rounded bg-slate-900 p-2" placeholder={param.name + ": " + param.type} />
+          {/each}
+          <input bind:value={lockedSats} class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+          <button on:click={deploy} class="btn btn-primary">Deploy</button>
+        </div>
+      {/if}
+
+      {#if deployed}
+        <a href={deployed.url} target="_blank" rel="noreferrer" class="block text-slate-400 break-all underline">
+          Txid: {deployed.txid}
+        </a>
+      {/if}
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400 whitespace-pre-wrap">{status}</p>
+  {/if}
+</div>
+"#;
+
+// ============================================================================
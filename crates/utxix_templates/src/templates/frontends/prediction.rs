@@ -0,0 +1,1040 @@
+//! Prediction (oracle-settled bet): the template's service and its main
+//! component for each framework.
+
+pub(super) const PREDICTION_SERVICE: &str = r#"/**
+ * Prediction Service - oracle-settled yes/no bets
+ *
+ * Talks to two things:
+ * - the oracle server (oracle/server.mjs, VITE_ORACLE_URL) which registers
+ *   questions and Rabin-signs their outcome
+ * - the PredictionMarket contract holding both stakes
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  bsv,
+  toByteString,
+  PubKey,
+  findSig,
+  ContractTransaction,
+  MethodCallOptions,
+} from "scrypt-ts";
+import type { RabinSig } from "scrypt-ts-lib";
+import PredictionMarket from "__CONTRACTS_DIR__/PredictionMarket.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/PredictionMarket.scrypt.json";
+const ORACLE_URL = config.oracleUrl;
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await PredictionMarket.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// ORACLE
+// ============================================================================
+
+export interface Attestation {
+  // eventId + outcome byte, hex
+  message: string;
+  // Rabin signature as a decimal string
+  s: string;
+  // Padding bytes, hex
+  padding: string;
+}
+
+export interface OracleEvent {
+  eventId: string;
+  question: string;
+  outcome: "yes" | "no" | null;
+  attestation: Attestation | null;
+}
+
+async function oracleRequest<T>(path: string, init?: RequestInit): Promise<T> {
+  const res = await fetch(ORACLE_URL + path, {
+    ...init,
+    headers: { "Content-Type": "application/json", ...(init?.headers || {}) },
+  });
+  if (!res.ok) {
+    throw new Error("Oracle request " + path + " failed: HTTP " + res.status + " " + (await res.text()));
+  }
+  return res.json();
+}
+
+export async function fetchOraclePubKey(): Promise<bigint> {
+  const { pubKey } = await oracleRequest<{ pubKey: string }>("/pubkey");
+  return BigInt(pubKey);
+}
+
+export function fetchEvent(eventId: string): Promise<OracleEvent> {
+  return oracleRequest<OracleEvent>("/events/" + eventId);
+}
+
+/**
+ * Publish the outcome. Only works with the oracle's admin token - in
+ * production this would be driven by a data feed, not a button.
+ */
+export function resolveEvent(eventId: string, outcome: "yes" | "no", adminToken: string): Promise<OracleEvent> {
+  return oracleRequest<OracleEvent>("/events/" + eventId + "/resolve", {
+    method: "POST",
+    headers: { Authorization: "Bearer " + adminToken },
+    body: JSON.stringify({ outcome }),
+  });
+}
+
+// ============================================================================
+// CHAIN HELPERS
+// ============================================================================
+
+export async function getBlockHeight(): Promise<number> {
+  const res = await fetch(config.wocApiUrl + "/chain/info");
+  if (!res.ok) {
+    throw new Error("Failed to fetch block height: HTTP " + res.status);
+  }
+  return (await res.json()).blocks;
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export interface NewMarketParams {
+  question: string;
+  opponentPubKeyHex: string;
+  stakeSats: number;
+  acceptDeadline: number;
+  settleDeadline: number;
+}
+
+/**
+ * Registers the question with the oracle and deploys the bet, taking the
+ * YES side
+ */
+export async function createMarket(params: NewMarketParams) {
+  if (params.settleDeadline <= params.acceptDeadline) {
+    throw new Error("Settle deadline must be after the accept deadline");
+  }
+  await loadArtifact();
+
+  const event = await oracleRequest<OracleEvent>("/events", {
+    method: "POST",
+    body: JSON.stringify({ question: params.question }),
+  });
+  const oraclePubKey = await fetchOraclePubKey();
+
+  const signer = await getYoursDirectSigner();
+  const myPubKey = await signer.getDefaultPubKey();
+
+  const instance = new PredictionMarket(
+    oraclePubKey,
+    toByteString(event.eventId),
+    PubKey(myPubKey.toHex()),
+    PubKey(params.opponentPubKeyHex),
+    BigInt(params.stakeSats),
+    BigInt(params.acceptDeadline),
+    BigInt(params.settleDeadline),
+  );
+  await instance.connect(signer);
+
+  const tx = await instance.deploy(params.stakeSats);
+  return { txid: tx.id, instance, event };
+}
+
+export async function restoreMarket(txid: string, outputIndex: number = 0) {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = PredictionMarket.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+
+  const event = await fetchEvent(instance.eventId);
+  return { instance, event };
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDERS
+// ============================================================================
+
+async function addFunding(tx: bsv.Transaction, needed: number) {
+  const utxos = await getWalletUtxos();
+  let total = 0;
+  for (const utxo of utxos) {
+    if (total >= needed) break;
+    tx.from({
+      txId: utxo.txid,
+      outputIndex: utxo.vout,
+      script: utxo.script,
+      satoshis: utxo.satoshis,
+    });
+    total += utxo.satoshis;
+  }
+  if (total < needed) {
+    throw new Error("Wallet balance too low: need " + needed + " sats");
+  }
+}
+
+function payoutOutput(pubKeyHex: string, satoshis: number) {
+  return new bsv.Transaction.Output({
+    script: bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(pubKeyHex).toAddress()),
+    satoshis,
+  });
+}
+
+async function buildAcceptTx(
+  current: PredictionMarket,
+  options: MethodCallOptions<PredictionMarket>,
+): Promise<ContractTransaction> {
+  const next = current.next();
+  next.accepted = true;
+  const pot = Number(current.stake) * 2;
+
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  tx.addOutput(new bsv.Transaction.Output({ script: next.lockingScript, satoshis: pot }));
+
+  // The NO player funds their stake plus fee
+  await addFunding(tx, Number(current.stake) + MIN_FEE_SATS);
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: pot }] };
+}
+
+async function buildPayoutTx(
+  current: PredictionMarket,
+  options: MethodCallOptions<PredictionMarket>,
+  payouts: Array<{ pubKeyHex: string; satoshis: number }>,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  for (const payout of payouts) {
+    tx.addOutput(payoutOutput(payout.pubKeyHex, payout.satoshis));
+  }
+
+  // Timeout paths: nLockTime must reach the deadline, input 0 must be non-final
+  if (options.lockTime !== undefined) {
+    tx.setLockTime(options.lockTime);
+    tx.setInputSequence(0, options.sequence ?? 0);
+  }
+
+  await addFunding(tx, MIN_FEE_SATS);
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  return { tx, atInputIndex: 0, nexts: [] };
+}
+
+// ============================================================================
+// ACTIONS
+// ============================================================================
+
+/**
+ * NO player: take the other side of the bet
+ */
+export async function acceptBet(instance: PredictionMarket): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  instance.bindTxBuilder("accept", buildAcceptTx);
+
+  const { tx } = await instance.methods.accept(
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+    } as MethodCallOptions<PredictionMarket>,
+  );
+  return { txid: tx.id };
+}
+
+/**
+ * Anyone: pay the pot out according to the oracle's attestation
+ */
+export async function settleMarket(instance: PredictionMarket): Promise<{ txid: string; outcome: "yes" | "no" }> {
+  const event = await fetchEvent(instance.eventId);
+  if (!event.outcome || !event.attestation) {
+    throw new Error("The oracle has not attested this event yet");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  const winner = event.outcome === "yes" ? instance.yesPlayer : instance.noPlayer;
+  instance.bindTxBuilder("settle", (current: PredictionMarket, options: MethodCallOptions<PredictionMarket>) =>
+    buildPayoutTx(current, options, [{ pubKeyHex: winner.toString(), satoshis: current.balance }]),
+  );
+
+  const oracleSig: RabinSig = {
+    s: BigInt(event.attestation.s),
+    padding: toByteString(event.attestation.padding),
+  };
+  const { tx } = await instance.methods.settle(toByteString(event.attestation.message), oracleSig, {
+    changeAddress: await signer.getDefaultAddress(),
+  } as MethodCallOptions<PredictionMarket>);
+  return { txid: tx.id, outcome: event.outcome };
+}
+
+/**
+ * Anyone: return both stakes once the settle deadline passes unattested
+ */
+export async function refundMarket(instance: PredictionMarket): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  instance.bindTxBuilder("refund", (current: PredictionMarket, options: MethodCallOptions<PredictionMarket>) =>
+    buildPayoutTx(current, options, [
+      { pubKeyHex: current.yesPlayer.toString(), satoshis: Number(current.stake) },
+      { pubKeyHex: current.noPlayer.toString(), satoshis: current.balance - Number(current.stake) },
+    ]),
+  );
+
+  const { tx } = await instance.methods.refund({
+    changeAddress: await signer.getDefaultAddress(),
+    lockTime: Number(instance.settleDeadline),
+    sequence: 0,
+  } as MethodCallOptions<PredictionMarket>);
+  return { txid: tx.id };
+}
+
+/**
+ * YES player: take the stake back if nobody accepted in time
+ */
+export async function cancelMarket(instance: PredictionMarket): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  instance.bindTxBuilder("cancel", (current: PredictionMarket, options: MethodCallOptions<PredictionMarket>) =>
+    buildPayoutTx(current, options, [{ pubKeyHex: current.yesPlayer.toString(), satoshis: current.balance }]),
+  );
+
+  const { tx } = await instance.methods.cancel(
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+      lockTime: Number(instance.acceptDeadline),
+      sequence: 0,
+    } as MethodCallOptions<PredictionMarket>,
+  );
+  return { txid: tx.id };
+}
+
+export default {
+  createMarket,
+  restoreMarket,
+  acceptBet,
+  settleMarket,
+  refundMarket,
+  cancelMarket,
+  resolveEvent,
+};
+"#;
+
+pub(super) const PREDICTION_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  OracleEvent,
+  createMarket,
+  restoreMarket,
+  acceptBet,
+  settleMarket,
+  refundMarket,
+  cancelMarket,
+  resolveEvent,
+  getBlockHeight,
+} from "../services/predictionService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [market, setMarket] = useState<any>(null);
+  const [event, setEvent] = useState<OracleEvent | null>(null);
+  const [marketTxid, setMarketTxid] = useState("");
+  const [question, setQuestion] = useState("");
+  const [opponent, setOpponent] = useState("");
+  const [stake, setStake] = useState("1000");
+  const [adminToken, setAdminToken] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const create = () =>
+    run("Creating market", async () => {
+      // ~6 blocks to accept; the oracle gets ~1 day after that
+      const height = await getBlockHeight();
+      const result = await createMarket({
+        question,
+        opponentPubKeyHex: opponent,
+        stakeSats: Number(stake),
+        acceptDeadline: height + 6,
+        settleDeadline: height + 150,
+      });
+      setMarketTxid(result.txid);
+      setMarket(result.instance);
+      setEvent(result.event);
+    });
+
+  const load = (txid: string) =>
+    run("Loading market", async () => {
+      const result = await restoreMarket(txid);
+      setMarketTxid(txid);
+      setMarket(result.instance);
+      setEvent(result.event);
+    });
+
+  const accept = () =>
+    run("Accepting", async () => {
+      const { txid } = await acceptBet(market);
+      const result = await restoreMarket(txid);
+      setMarketTxid(txid);
+      setMarket(result.instance);
+      setEvent(result.event);
+    });
+
+  const resolve = (outcome: "yes" | "no") =>
+    run("Publishing outcome", async () => {
+      setEvent(await resolveEvent(event!.eventId, outcome, adminToken));
+    });
+
+  const payout = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const { txid } = await action();
+      setMarket(null);
+      setEvent(null);
+      setStatus("Paid out: " + txid);
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Prediction Market</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to bet</p>
+        </div>
+      ) : !market ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">New bet (you take YES)</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Question, e.g. Will it rain in Lisbon on 1 May?"
+              value={question}
+              onChange={(e) => setQuestion(e.target.value)}
+            />
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Opponent public key (takes NO)"
+              value={opponent}
+              onChange={(e) => setOpponent(e.target.value)}
+            />
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Stake (sats)"
+              value={stake}
+              onChange={(e) => setStake(e.target.value)}
+            />
+            <button onClick={create} className="btn btn-primary">Register &amp; deploy</button>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open an existing bet</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Latest market txid"
+              value={marketTxid}
+              onChange={(e) => setMarketTxid(e.target.value)}
+            />
+            <button onClick={() => load(marketTxid)} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="font-semibold">{event?.question}</p>
+          <p className="text-slate-400 break-all">Latest txid: {marketTxid}</p>
+          <p>
+            Pot: {market.balance} sats · {market.accepted ? "Accepted" : "Waiting for NO player"} · Oracle:{" "}
+            {event?.outcome ? "resolved " + event.outcome.toUpperCase() : "pending"}
+          </p>
+
+          {!market.accepted ? (
+            <div className="flex gap-2">
+              <button onClick={accept} className="btn btn-primary">Accept (take NO)</button>
+              <button onClick={() => payout("Cancelling", () => cancelMarket(market))} className="btn btn-secondary">
+                Cancel after block {String(market.acceptDeadline)}
+              </button>
+            </div>
+          ) : (
+            <div className="flex gap-2">
+              <button
+                onClick={() => payout("Settling", () => settleMarket(market))}
+                disabled={!event?.attestation}
+                className="btn btn-primary"
+              >
+                Settle
+              </button>
+              <button onClick={() => payout("Refunding", () => refundMarket(market))} className="btn btn-secondary">
+                Refund after block {String(market.settleDeadline)}
+              </button>
+            </div>
+          )}
+
+          {!event?.outcome && (
+            <div className="space-y-2 border-t border-slate-700 pt-4">
+              <h3 className="font-semibold">Oracle admin</h3>
+              <input
+                className="w-full rounded bg-slate-900 p-2"
+                placeholder="ORACLE_ADMIN_TOKEN"
+                value={adminToken}
+                onChange={(e) => setAdminToken(e.target.value)}
+              />
+              <div className="flex gap-2">
+                <button onClick={() => resolve("yes")} className="btn btn-secondary">Resolve YES</button>
+                <button onClick={() => resolve("no")} className="btn btn-secondary">Resolve NO</button>
+              </div>
+            </div>
+          )}
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const PREDICTION_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  type OracleEvent,
+  createMarket,
+  restoreMarket,
+  acceptBet,
+  settleMarket,
+  refundMarket,
+  cancelMarket,
+  resolveEvent,
+  getBlockHeight,
+} from "../services/predictionService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const market = shallowRef<any>(null);
+const event = ref<OracleEvent | null>(null);
+const marketTxid = ref("");
+const question = ref("");
+const opponent = ref("");
+const stake = ref("1000");
+const adminToken = ref("");
+const status = ref<string | null>(null);
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+const create = () =>
+  run("Creating market", async () => {
+    // ~6 blocks to accept; the oracle gets ~1 day after that
+    const height = await getBlockHeight();
+    const result = await createMarket({
+      question: question.value,
+      opponentPubKeyHex: opponent.value,
+      stakeSats: Number(stake.value),
+      acceptDeadline: height + 6,
+      settleDeadline: height + 150,
+    });
+    marketTxid.value = result.txid;
+    market.value = result.instance;
+    event.value = result.event;
+  });
+
+const load = (txid: string) =>
+  run("Loading market", async () => {
+    const result = await restoreMarket(txid);
+    marketTxid.value = txid;
+    market.value = result.instance;
+    event.value = result.event;
+  });
+
+const accept = () =>
+  run("Accepting", async () => {
+    const { txid } = await acceptBet(toRaw(market.value));
+    const result = await restoreMarket(txid);
+    marketTxid.value = txid;
+    market.value = result.instance;
+    event.value = result.event;
+  });
+
+const resolve = (outcome: "yes" | "no") =>
+  run("Publishing outcome", async () => {
+    event.value = await resolveEvent(event.value!.eventId, outcome, adminToken.value);
+  });
+
+const payout = (label: string, action: (instance: any) => Promise<{ txid: string }>) =>
+  run(label, async () => {
+    const { txid } = await action(toRaw(market.value));
+    market.value = null;
+    event.value = null;
+    status.value = "Paid out: " + txid;
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Prediction Market</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to bet</p>
+    </div>
+    <div v-else-if="!market" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New bet (you take YES)</h3>
+        <input
+          v-model="question"
+          class="w-full rounded bg-slate-900 p-2"
+          placeholder="Question, e.g. Will it rain in Lisbon on 1 May?"
+        />
+        <input v-model="opponent" class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key (takes NO)" />
+        <input v-model="stake" class="w-full rounded bg-slate-900 p-2" placeholder="Stake (sats)" />
+        <button @click="create" class="btn btn-primary">Register &amp; deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing bet</h3>
+        <input v-model="marketTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest market txid" />
+        <button @click="load(marketTxid)" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="font-semibold">{{ event?.question }}</p>
+      <p class="text-slate-400 break-all">Latest txid: {{ marketTxid }}</p>
+      <p>
+        Pot: {{ market.balance }} sats · {{ market.accepted ? "Accepted" : "Waiting for NO player" }} · Oracle:
+        {{ event?.outcome ? "resolved " + event.outcome.toUpperCase() : "pending" }}
+      </p>
+
+      <div v-if="!market.accepted" class="flex gap-2">
+        <button @click="accept" class="btn btn-primary">Accept (take NO)</button>
+        <button @click="payout('Cancelling', cancelMarket)" class="btn btn-secondary">
+          Cancel after block {{ String(market.acceptDeadline) }}
+        </button>
+      </div>
+      <div v-else class="flex gap-2">
+        <button @click="payout('Settling', settleMarket)" :disabled="!event?.attestation" class="btn btn-primary">
+          Settle
+        </button>
+        <button @click="payout('Refunding', refundMarket)" class="btn btn-secondary">
+          Refund after block {{ String(market.settleDeadline) }}
+        </button>
+      </div>
+
+      <div v-if="!event?.outcome" class="space-y-2 border-t border-slate-700 pt-4">
+        <h3 class="font-semibold">Oracle admin</h3>
+        <input v-model="adminToken" class="w-full rounded bg-slate-900 p-2" placeholder="ORACLE_ADMIN_TOKEN" />
+        <div class="flex gap-2">
+          <button @click="resolve('yes')" class="btn btn-secondary">Resolve YES</button>
+          <button @click="resolve('no')" class="btn btn-secondary">Resolve NO</button>
+        </div>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const PREDICTION_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  OracleEvent,
+  createMarket,
+  restoreMarket,
+  acceptBet,
+  settleMarket,
+  refundMarket,
+  cancelMarket,
+  resolveEvent,
+  getBlockHeight,
+} from "../services/predictionService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Prediction Market</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to bet</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !market()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">New bet (you take YES)</h3>
+          <input
+            [(ngModel)]="question"
+            class="w-full rounded bg-slate-900 p-2"
+            placeholder="Question, e.g. Will it rain in Lisbon on 1 May?"
+          />
+          <input [(ngModel)]="opponent" class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key (takes NO)" />
+          <input [(ngModel)]="stake" class="w-full rounded bg-slate-900 p-2" placeholder="Stake (sats)" />
+          <button (click)="create()" class="btn btn-primary">Register &amp; deploy</button>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open an existing bet</h3>
+          <input [(ngModel)]="marketTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest market txid" />
+          <button (click)="load(marketTxid)" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && market()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="font-semibold">{{ event()?.question }}</p>
+        <p class="text-slate-400 break-all">Latest txid: {{ marketTxid }}</p>
+        <p>
+          Pot: {{ market().balance }} sats · {{ market().accepted ? "Accepted" : "Waiting for NO player" }} ·
+          Oracle: {{ event()?.outcome ? "resolved " + event()!.outcome!.toUpperCase() : "pending" }}
+        </p>
+
+        <div *ngIf="!market().accepted" class="flex gap-2">
+          <button (click)="accept()" class="btn btn-primary">Accept (take NO)</button>
+          <button (click)="payout('Cancelling', cancelMarket)" class="btn btn-secondary">
+            Cancel after block {{ market().acceptDeadline.toString() }}
+          </button>
+        </div>
+        <div *ngIf="market().accepted" class="flex gap-2">
+          <button (click)="payout('Settling', settleMarket)" [disabled]="!event()?.attestation" class="btn btn-primary">
+            Settle
+          </button>
+          <button (click)="payout('Refunding', refundMarket)" class="btn btn-secondary">
+            Refund after block {{ market().settleDeadline.toString() }}
+          </button>
+        </div>
+
+        <div *ngIf="!event()?.outcome" class="space-y-2 border-t border-slate-700 pt-4">
+          <h3 class="font-semibold">Oracle admin</h3>
+          <input [(ngModel)]="adminToken" class="w-full rounded bg-slate-900 p-2" placeholder="ORACLE_ADMIN_TOKEN" />
+          <div class="flex gap-2">
+            <button (click)="resolve('yes')" class="btn btn-secondary">Resolve YES</button>
+            <button (click)="resolve('no')" class="btn btn-secondary">Resolve NO</button>
+          </div>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  market = signal<any>(null);
+  event = signal<OracleEvent | null>(null);
+  status = signal<string | null>(null);
+
+  marketTxid = "";
+  question = "";
+  opponent = "";
+  stake = "1000";
+  adminToken = "";
+
+  readonly settleMarket = settleMarket;
+  readonly refundMarket = refundMarket;
+  readonly cancelMarket = cancelMarket;
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  create() {
+    return this.run("Creating market", async () => {
+      // ~6 blocks to accept; the oracle gets ~1 day after that
+      const height = await getBlockHeight();
+      const result = await createMarket({
+        question: this.question,
+        opponentPubKeyHex: this.opponent,
+        stakeSats: Number(this.stake),
+        acceptDeadline: height + 6,
+        settleDeadline: height + 150,
+      });
+      this.marketTxid = result.txid;
+      this.market.set(result.instance);
+      this.event.set(result.event);
+    });
+  }
+
+  load(txid: string) {
+    return this.run("Loading market", async () => {
+      const result = await restoreMarket(txid);
+      this.marketTxid = txid;
+      this.market.set(result.instance);
+      this.event.set(result.event);
+    });
+  }
+
+  accept() {
+    return this.run("Accepting", async () => {
+      const { txid } = await acceptBet(this.market());
+      const result = await restoreMarket(txid);
+      this.marketTxid = txid;
+      this.market.set(result.instance);
+      this.event.set(result.event);
+    });
+  }
+
+  resolve(outcome: "yes" | "no") {
+    return this.run("Publishing outcome", async () => {
+      this.event.set(await resolveEvent(this.event()!.eventId, outcome, this.adminToken));
+    });
+  }
+
+  payout(label: string, action: (instance: any) => Promise<{ txid: string }>) {
+    return this.run(label, async () => {
+      const { txid } = await action(this.market());
+      this.market.set(null);
+      this.event.set(null);
+      this.status.set("Paid out: " + txid);
+    });
+  }
+}
+"#;
+
+pub(super) const PREDICTION_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    type OracleEvent,
+    createMarket,
+    restoreMarket,
+    acceptBet,
+    settleMarket,
+    refundMarket,
+    cancelMarket,
+    resolveEvent,
+    getBlockHeight,
+  } from "../services/predictionService";
+
+  export let contractName: string;
+
+  let market: any = null;
+  let event: OracleEvent | null = null;
+  let marketTxid = "";
+  let question = "";
+  let opponent = "";
+  let stake = "1000";
+  let adminToken = "";
+  let status: string | null = null;
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  const create = () =>
+    run("Creating market", async () => {
+      // ~6 blocks to accept; the oracle gets ~1 day after that
+      const height = await getBlockHeight();
+      const result = await createMarket({
+        question,
+        opponentPubKeyHex: opponent,
+        stakeSats: Number(stake),
+        acceptDeadline: height + 6,
+        settleDeadline: height + 150,
+      });
+      marketTxid = result.txid;
+      market = result.instance;
+      event = result.event;
+    });
+
+  const load = (txid: string) =>
+    run("Loading market", async () => {
+      const result = await restoreMarket(txid);
+      marketTxid = txid;
+      market = result.instance;
+      event = result.event;
+    });
+
+  const accept = () =>
+    run("Accepting", async () => {
+      const { txid } = await acceptBet(market);
+      const result = await restoreMarket(txid);
+      marketTxid = txid;
+      market = result.instance;
+      event = result.event;
+    });
+
+  const resolve = (outcome: "yes" | "no") =>
+    run("Publishing outcome", async () => {
+      event = await resolveEvent(event!.eventId, outcome, adminToken);
+    });
+
+  const payout = (label: string, action: (instance: any) => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const { txid } = await action(market);
+      market = null;
+      event = null;
+      status = "Paid out: " + txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Prediction Market</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to bet</p>
+    </div>
+  {:else if !market}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New bet (you take YES)</h3>
+        <input
+          bind:value={question}
+          class="w-full rounded bg-slate-900 p-2"
+          placeholder="Question, e.g. Will it rain in Lisbon on 1 May?"
+        />
+        <input bind:value={opponent} class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key (takes NO)" />
+        <input bind:value={stake} class="w-full rounded bg-slate-900 p-2" placeholder="Stake (sats)" />
+        <button on:click={create} class="btn btn-primary">Register &amp; deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing bet</h3>
+        <input bind:value={marketTxid} class="w-full rounded bg-slate-900 p-2" placeholder="Latest market txid" />
+        <button on:click={() => load(marketTxid)} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="font-semibold">{event?.question}</p>
+      <p class="text-slate-400 break-all">Latest txid: {marketTxid}</p>
+      <p>
+        Pot: {market.balance} sats · {market.accepted ? "Accepted" : "Waiting for NO player"} · Oracle:
+        {event?.outcome ? "resolved " + event.outcome.toUpperCase() : "pending"}
+      </p>
+
+      {#if !market.accepted}
+        <div class="flex gap-2">
+          <button on:click={accept} class="btn btn-primary">Accept (take NO)</button>
+          <button on:click={() => payout("Cancelling", cancelMarket)} class="btn btn-secondary">
+            Cancel after block {String(market.acceptDeadline)}
+          </button>
+        </div>
+      {:else}
+        <div class="flex gap-2">
+          <button on:click={() => payout("Settling", settleMarket)} disabled={!event?.attestation} class="btn btn-primary">
+            Settle
+          </button>
+          <button on:click={() => payout("Refunding", refundMarket)} class="btn btn-secondary">
+            Refund after block {String(market.settleDeadline)}
+          </button>
+        </div>
+      {/if}
+
+      {#if !event?.outcome}
+        <div class="space-y-2 border-t border-slate-700 pt-4">
+          <h3 class="font-semibold">Oracle admin</h3>
+          <input bind:value={adminToken} class="w-full rounded bg-slate-900 p-2" placeholder="ORACLE_ADMIN_TOKEN" />
+          <div class="flex gap-2">
+            <button on:click={() => resolve("yes")} class="btn btn-secondary">Resolve YES</button>
+            <button on:click={() => resolve("no")} class="btn btn-secondary">Resolve NO</button>
+          </div>
+        </div>
+      {/if}
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,1234 @@
+//! Rock-Paper-Scissors (commit-reveal with timeouts): the template's service and its main
+//! component for each framework.
+
+pub(super) const RPS_SERVICE: &str = r#"/**
+ * Rock-Paper-Scissors Service - commit-reveal game flow
+ *
+ * The secret (move + nonce) behind player 1's commitment is kept in
+ * localStorage until reveal. Losing it means player 1 can no longer
+ * reveal, and player 2 will win by timeout - back it up!
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  bsv,
+  hash256,
+  int2ByteString,
+  toByteString,
+  PubKey,
+  findSig,
+  ByteString,
+  ContractTransaction,
+  MethodCallOptions,
+} from "scrypt-ts";
+import RockPaperScissors from "__CONTRACTS_DIR__/RockPaperScissors.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/RockPaperScissors.scrypt.json";
+const SECRETS_KEY = "rps_secrets";
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+export const MOVES = ["rock", "paper", "scissors"] as const;
+export type Move = (typeof MOVES)[number];
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await RockPaperScissors.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+export function moveToNumber(move: Move): bigint {
+  return BigInt(MOVES.indexOf(move) + 1);
+}
+
+export function moveFromNumber(value: bigint): Move | null {
+  return value >= 1n && value <= 3n ? MOVES[Number(value) - 1] : null;
+}
+
+// ============================================================================
+// COMMITMENTS
+// ============================================================================
+
+interface Secret {
+  move: Move;
+  nonce: string;
+}
+
+function randomNonce(): string {
+  const bytes = new Uint8Array(32);
+  crypto.getRandomValues(bytes);
+  return Array.from(bytes, (b) => b.toString(16).padStart(2, "0")).join("");
+}
+
+/**
+ * Must match the contract: hash256(nonce + int2ByteString(move))
+ */
+export function commitmentFor(move: Move, nonce: string): ByteString {
+  return hash256(toByteString(nonce) + int2ByteString(moveToNumber(move)));
+}
+
+function loadSecrets(): Record<string, Secret> {
+  try {
+    return JSON.parse(localStorage.getItem(SECRETS_KEY) || "{}");
+  } catch {
+    return {};
+  }
+}
+
+function saveSecret(gameTxid: string, secret: Secret) {
+  const secrets = loadSecrets();
+  secrets[gameTxid] = secret;
+  localStorage.setItem(SECRETS_KEY, JSON.stringify(secrets));
+}
+
+export function getSecret(gameTxid: string): Secret | null {
+  return loadSecrets()[gameTxid] ?? null;
+}
+
+// ============================================================================
+// CHAIN HELPERS
+// ============================================================================
+
+export async function getBlockHeight(): Promise<number> {
+  const res = await fetch(config.wocApiUrl + "/chain/info");
+  if (!res.ok) {
+    throw new Error("Failed to fetch block height: HTTP " + res.status);
+  }
+  return (await res.json()).blocks;
+}
+
+export async function getMyPubKey(): Promise<string> {
+  const signer = await getYoursDirectSigner();
+  return (await signer.getDefaultPubKey()).toHex();
+}
+
+export type GamePhase = "waiting-for-opponent" | "waiting-for-reveal";
+
+export function gamePhase(instance: RockPaperScissors): GamePhase {
+  return instance.player2Move === 0n ? "waiting-for-opponent" : "waiting-for-reveal";
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export interface NewGameParams {
+  opponentPubKeyHex: string;
+  move: Move;
+  stakeSats: number;
+  joinDeadline: number;
+  revealDeadline: number;
+}
+
+export async function createGame(params: NewGameParams) {
+  if (params.revealDeadline <= params.joinDeadline) {
+    throw new Error("Reveal deadline must be after the join deadline");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const myPubKey = await signer.getDefaultPubKey();
+  const nonce = randomNonce();
+
+  const instance = new RockPaperScissors(
+    PubKey(myPubKey.toHex()),
+    PubKey(params.opponentPubKeyHex),
+    commitmentFor(params.move, nonce),
+    BigInt(params.stakeSats),
+    BigInt(params.joinDeadline),
+    BigInt(params.revealDeadline),
+  );
+  await instance.connect(signer);
+
+  const tx = await instance.deploy(params.stakeSats);
+  // Persist the secret immediately - it is the only way to reveal later
+  saveSecret(tx.id, { move: params.move, nonce });
+
+  return { txid: tx.id, instance };
+}
+
+export async function restoreGame(txid: string, outputIndex: number = 0): Promise<RockPaperScissors> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = RockPaperScissors.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+/**
+ * Same as restoreGame, from a raw tx relayed by the opponent - no need to
+ * wait for the explorer to pick it up
+ */
+export async function restoreGameFromRaw(rawTx: string, outputIndex: number = 0): Promise<RockPaperScissors> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const instance = RockPaperScissors.fromTx(new bsv.Transaction(rawTx), outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDERS
+// ============================================================================
+
+/**
+ * Add wallet UTXOs until `needed` sats are covered
+ */
+async function addFunding(tx: bsv.Transaction, needed: number) {
+  const utxos = await getWalletUtxos();
+  let total = 0;
+  for (const utxo of utxos) {
+    if (total >= needed) break;
+    tx.from({
+      txId: utxo.txid,
+      outputIndex: utxo.vout,
+      script: utxo.script,
+      satoshis: utxo.satoshis,
+    });
+    total += utxo.satoshis;
+  }
+  if (total < needed) {
+    throw new Error("Wallet balance too low: need " + needed + " sats");
+  }
+}
+
+function payoutOutput(pubKeyHex: string, satoshis: number) {
+  return new bsv.Transaction.Output({
+    script: bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(pubKeyHex).toAddress()),
+    satoshis,
+  });
+}
+
+async function buildPlayTx(
+  current: RockPaperScissors,
+  options: MethodCallOptions<RockPaperScissors>,
+  move: bigint,
+): Promise<ContractTransaction> {
+  const next = current.next();
+  next.player2Move = move;
+
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  tx.addOutput(new bsv.Transaction.Output({
+    script: next.lockingScript,
+    satoshis: Number(current.stake) * 2,
+  }));
+
+  // Player 2 funds their stake plus fee
+  await addFunding(tx, Number(current.stake) + MIN_FEE_SATS);
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  return {
+    tx,
+    atInputIndex: 0,
+    nexts: [{ instance: next, atOutputIndex: 0, balance: Number(current.stake) * 2 }],
+  };
+}
+
+async function buildSettleTx(
+  current: RockPaperScissors,
+  options: MethodCallOptions<RockPaperScissors>,
+  payouts: Array<{ pubKeyHex: string; satoshis: number }>,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  for (const payout of payouts) {
+    tx.addOutput(payoutOutput(payout.pubKeyHex, payout.satoshis));
+  }
+
+  // Timeout paths: nLockTime must reach the deadline, input 0 must be non-final
+  if (options.lockTime !== undefined) {
+    tx.setLockTime(options.lockTime);
+    tx.setInputSequence(0, options.sequence ?? 0);
+  }
+
+  await addFunding(tx, MIN_FEE_SATS);
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  return { tx, atInputIndex: 0, nexts: [] };
+}
+
+// ============================================================================
+// GAME ACTIONS
+// ============================================================================
+
+export async function playMove(instance: RockPaperScissors, move: Move): Promise<{ txid: string; rawTx: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+  const moveNumber = moveToNumber(move);
+
+  instance.bindTxBuilder("play", (current: RockPaperScissors, options: MethodCallOptions<RockPaperScissors>) =>
+    buildPlayTx(current, options, moveNumber),
+  );
+
+  const { tx } = await instance.methods.play(
+    moveNumber,
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+    } as MethodCallOptions<RockPaperScissors>,
+  );
+  return { txid: tx.id, rawTx: tx.toString() };
+}
+
+export async function revealMove(
+  instance: RockPaperScissors,
+  gameTxid: string,
+): Promise<{ txid: string; outcome: "draw" | "player1" | "player2" }> {
+  const secret = getSecret(gameTxid);
+  if (!secret) {
+    throw new Error("No stored move for game " + gameTxid + " - was it created in this browser?");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  const pot = instance.balance;
+  const result = (moveToNumber(secret.move) - instance.player2Move + 3n) % 3n;
+  const outcome = result === 0n ? "draw" : result === 1n ? "player1" : "player2";
+  const payouts =
+    outcome === "draw"
+      ? [
+          { pubKeyHex: instance.player1.toString(), satoshis: Math.floor(pot / 2) },
+          { pubKeyHex: instance.player2.toString(), satoshis: pot - Math.floor(pot / 2) },
+        ]
+      : [{ pubKeyHex: (outcome === "player1" ? instance.player1 : instance.player2).toString(), satoshis: pot }];
+
+  instance.bindTxBuilder("reveal", (current: RockPaperScissors, options: MethodCallOptions<RockPaperScissors>) =>
+    buildSettleTx(current, options, payouts),
+  );
+
+  const { tx } = await instance.methods.reveal(
+    moveToNumber(secret.move),
+    toByteString(secret.nonce),
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+    } as MethodCallOptions<RockPaperScissors>,
+  );
+  return { txid: tx.id, outcome };
+}
+
+async function callTimeoutPath(
+  instance: RockPaperScissors,
+  method: "claimTimeout" | "cancel",
+  deadline: bigint,
+  winnerPubKeyHex: string,
+): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  instance.bindTxBuilder(method, (current: RockPaperScissors, options: MethodCallOptions<RockPaperScissors>) =>
+    buildSettleTx(current, options, [{ pubKeyHex: winnerPubKeyHex, satoshis: current.balance }]),
+  );
+
+  const { tx } = await instance.methods[method](
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+      lockTime: Number(deadline),
+      sequence: 0,
+    } as MethodCallOptions<RockPaperScissors>,
+  );
+  return { txid: tx.id };
+}
+
+/**
+ * Player 2: take the pot once the reveal deadline has passed
+ */
+export function claimTimeout(instance: RockPaperScissors) {
+  return callTimeoutPath(instance, "claimTimeout", instance.revealDeadline, instance.player2.toString());
+}
+
+/**
+ * Player 1: take the stake back if nobody joined before the join deadline
+ */
+export function cancelGame(instance: RockPaperScissors) {
+  return callTimeoutPath(instance, "cancel", instance.joinDeadline, instance.player1.toString());
+}
+
+export default {
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  playMove,
+  revealMove,
+  claimTimeout,
+  cancelGame,
+};
+"#;
+
+pub(super) const RPS_REACT_COMPONENT: &str = r#"import { useEffect, useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  MOVES,
+  Move,
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  playMove,
+  revealMove,
+  claimTimeout,
+  cancelGame,
+  gamePhase,
+  getBlockHeight,
+  getMyPubKey,
+  getSecret,
+  moveFromNumber,
+} from "../services/rpsService";
+import { MultiplayerSession } from "../services/multiplayerClient";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [game, setGame] = useState<any>(null);
+  const [gameTxid, setGameTxid] = useState("");
+  const [opponent, setOpponent] = useState("");
+  const [stake, setStake] = useState("1000");
+  const [move, setMove] = useState<Move>("rock");
+  const [session, setSession] = useState<MultiplayerSession | null>(null);
+  const [matchId, setMatchId] = useState<string | null>(null);
+  const [status, setStatus] = useState<string | null>(null);
+
+  useEffect(() => () => session?.close(), [session]);
+
+  // The action may return a message to keep on screen
+  const run = async (label: string, action: () => Promise<string | void>) => {
+    setStatus(label + "...");
+    try {
+      setStatus((await action()) || null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  // Opponent's transactions arrive through the multiplayer server
+  const follow = (s: MultiplayerSession, txid: string) => {
+    s.join(txid);
+    s.onTurn((turn) =>
+      run("Opponent moved", async () => {
+        if (turn.note) {
+          setGame(null);
+          return turn.note;
+        }
+        setGame(turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid));
+      }),
+    );
+    s.onOpponentLeft(() => setStatus("Opponent disconnected - the game continues on-chain"));
+  };
+
+  const findOpponent = () =>
+    run("Looking for an opponent", async () => {
+      const s = session ?? (await MultiplayerSession.connect());
+      setSession(s);
+      const match = await s.findMatch("rps", await getMyPubKey(), Number(stake));
+      if (match.role === "creator") {
+        setOpponent(match.opponent);
+        setMatchId(match.matchId);
+        return "Matched! Commit your move to start the game";
+      }
+      s.onStart((txid) =>
+        run("Loading game", async () => {
+          setGameTxid(txid);
+          setGame(await restoreGame(txid));
+          follow(s, txid);
+        }),
+      );
+      return "Matched! Waiting for your opponent to commit their move";
+    });
+
+  const create = () =>
+    run("Creating game", async () => {
+      // Opponent gets ~6 blocks to join, then ~6 more before the reveal deadline
+      const height = await getBlockHeight();
+      const { txid, instance } = await createGame({
+        opponentPubKeyHex: opponent,
+        move,
+        stakeSats: Number(stake),
+        joinDeadline: height + 6,
+        revealDeadline: height + 12,
+      });
+      setGameTxid(txid);
+      setGame(instance);
+      if (session && matchId) {
+        session.startMatch(matchId, txid);
+        setMatchId(null);
+        follow(session, txid);
+      }
+    });
+
+  const load = () =>
+    run("Loading game", async () => {
+      setGame(await restoreGame(gameTxid));
+    });
+
+  const play = () =>
+    run("Playing", async () => {
+      const result = await playMove(game, move);
+      setGame(await restoreGameFromRaw(result.rawTx));
+      session?.sendTurn(gameTxid, result);
+    });
+
+  const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const { txid } = await action();
+      setGame(null);
+      session?.sendTurn(gameTxid, { txid, note: "Game settled in " + txid });
+      return "Settled: " + txid;
+    });
+
+  const isCreator = game && getSecret(gameTxid) !== null;
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Rock · Paper · Scissors</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to play</p>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-6">
+          <p className="text-slate-400">Contract: {contractName}</p>
+
+          <div className="flex gap-2">
+            {MOVES.map((m) => (
+              <button
+                key={m}
+                onClick={() => setMove(m)}
+                className={m === move ? "btn btn-primary" : "btn btn-secondary"}
+              >
+                {m}
+              </button>
+            ))}
+          </div>
+
+          {!game ? (
+            <div className="space-y-4">
+              <div className="space-y-2">
+                <h3 className="font-semibold">New game</h3>
+                <input
+                  className="w-full rounded bg-slate-900 p-2"
+                  placeholder="Opponent public key"
+                  value={opponent}
+                  onChange={(e) => setOpponent(e.target.value)}
+                />
+                <input
+                  className="w-full rounded bg-slate-900 p-2"
+                  placeholder="Stake (sats)"
+                  value={stake}
+                  onChange={(e) => setStake(e.target.value)}
+                />
+                <div className="flex gap-2">
+                  <button onClick={create} className="btn btn-primary">Commit move &amp; deploy</button>
+                  <button onClick={findOpponent} className="btn btn-secondary">Find opponent</button>
+                </div>
+              </div>
+              <div className="space-y-2">
+                <h3 className="font-semibold">Join or resume a game</h3>
+                <input
+                  className="w-full rounded bg-slate-900 p-2"
+                  placeholder="Game txid"
+                  value={gameTxid}
+                  onChange={(e) => setGameTxid(e.target.value)}
+                />
+                <button onClick={load} className="btn btn-secondary">Load</button>
+              </div>
+            </div>
+          ) : gamePhase(game) === "waiting-for-opponent" ? (
+            <div className="space-y-2">
+              <p>Waiting for opponent (join deadline: block {String(game.joinDeadline)})</p>
+              {isCreator ? (
+                <button onClick={() => settle("Cancelling", () => cancelGame(game))} className="btn btn-secondary">
+                  Cancel after deadline
+                </button>
+              ) : (
+                <button onClick={play} className="btn btn-primary">Play {move}</button>
+              )}
+            </div>
+          ) : (
+            <div className="space-y-2">
+              <p>
+                Opponent played {moveFromNumber(game.player2Move)} — reveal deadline: block{" "}
+                {String(game.revealDeadline)}
+              </p>
+              {isCreator ? (
+                <button onClick={() => settle("Revealing", () => revealMove(game, gameTxid))} className="btn btn-primary">
+                  Reveal my move
+                </button>
+              ) : (
+                <button onClick={() => settle("Claiming", () => claimTimeout(game))} className="btn btn-secondary">
+                  Claim pot after deadline
+                </button>
+              )}
+            </div>
+          )}
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const RPS_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { computed, inject, onUnmounted, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  MOVES,
+  Move,
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  playMove,
+  revealMove,
+  claimTimeout,
+  cancelGame,
+  gamePhase,
+  getBlockHeight,
+  getMyPubKey,
+  getSecret,
+  moveFromNumber,
+} from "../services/rpsService";
+import { MultiplayerSession } from "../services/multiplayerClient";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const game = shallowRef<any>(null);
+const gameTxid = ref("");
+const opponent = ref("");
+const stake = ref("1000");
+const move = ref<Move>("rock");
+const session = shallowRef<MultiplayerSession | null>(null);
+const matchId = ref<string | null>(null);
+const status = ref<string | null>(null);
+
+const isCreator = computed(() => game.value && getSecret(gameTxid.value) !== null);
+
+onUnmounted(() => session.value?.close());
+
+// The action may return a message to keep on screen
+async function run(label: string, action: () => Promise<string | void>) {
+  status.value = label + "...";
+  try {
+    status.value = (await action()) || null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+// Opponent's transactions arrive through the multiplayer server
+function follow(s: MultiplayerSession, txid: string) {
+  s.join(txid);
+  s.onTurn((turn) =>
+    run("Opponent moved", async () => {
+      if (turn.note) {
+        game.value = null;
+        return turn.note;
+      }
+      game.value = turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid);
+    }),
+  );
+  s.onOpponentLeft(() => (status.value = "Opponent disconnected - the game continues on-chain"));
+}
+
+const findOpponent = () =>
+  run("Looking for an opponent", async () => {
+    const s = session.value ?? (await MultiplayerSession.connect());
+    session.value = s;
+    const match = await s.findMatch("rps", await getMyPubKey(), Number(stake.value));
+    if (match.role === "creator") {
+      opponent.value = match.opponent;
+      matchId.value = match.matchId;
+      return "Matched! Commit your move to start the game";
+    }
+    s.onStart((txid) =>
+      run("Loading game", async () => {
+        gameTxid.value = txid;
+        game.value = await restoreGame(txid);
+        follow(s, txid);
+      }),
+    );
+    return "Matched! Waiting for your opponent to commit their move";
+  });
+
+const create = () =>
+  run("Creating game", async () => {
+    // Opponent gets ~6 blocks to join, then ~6 more before the reveal deadline
+    const height = await getBlockHeight();
+    const { txid, instance } = await createGame({
+      opponentPubKeyHex: opponent.value,
+      move: move.value,
+      stakeSats: Number(stake.value),
+      joinDeadline: height + 6,
+      revealDeadline: height + 12,
+    });
+    gameTxid.value = txid;
+    game.value = instance;
+    if (session.value && matchId.value) {
+      session.value.startMatch(matchId.value, txid);
+      matchId.value = null;
+      follow(session.value, txid);
+    }
+  });
+
+const load = () =>
+  run("Loading game", async () => {
+    game.value = await restoreGame(gameTxid.value);
+  });
+
+const play = () =>
+  run("Playing", async () => {
+    const result = await playMove(toRaw(game.value), move.value);
+    game.value = await restoreGameFromRaw(result.rawTx);
+    session.value?.sendTurn(gameTxid.value, result);
+  });
+
+const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+  run(label, async () => {
+    const { txid } = await action();
+    game.value = null;
+    session.value?.sendTurn(gameTxid.value, { txid, note: "Game settled in " + txid });
+    return "Settled: " + txid;
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Rock · Paper · Scissors</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-6">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+
+      <div class="flex gap-2">
+        <button
+          v-for="m in MOVES"
+          :key="m"
+          @click="move = m"
+          :class="m === move ? 'btn btn-primary' : 'btn btn-secondary'"
+        >
+          {{ m }}
+        </button>
+      </div>
+
+      <div v-if="!game" class="space-y-4">
+        <div class="space-y-2">
+          <h3 class="font-semibold">New game</h3>
+          <input v-model="opponent" class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key" />
+          <input v-model="stake" class="w-full rounded bg-slate-900 p-2" placeholder="Stake (sats)" />
+          <div class="flex gap-2">
+            <button @click="create" class="btn btn-primary">Commit move &amp; deploy</button>
+            <button @click="findOpponent" class="btn btn-secondary">Find opponent</button>
+          </div>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Join or resume a game</h3>
+          <input v-model="gameTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Game txid" />
+          <button @click="load" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+      <div v-else-if="gamePhase(game) === 'waiting-for-opponent'" class="space-y-2">
+        <p>Waiting for opponent (join deadline: block {{ String(game.joinDeadline) }})</p>
+        <button v-if="isCreator" @click="settle('Cancelling', () => cancelGame(toRaw(game)))" class="btn btn-secondary">
+          Cancel after deadline
+        </button>
+        <button v-else @click="play" class="btn btn-primary">Play {{ move }}</button>
+      </div>
+      <div v-else class="space-y-2">
+        <p>
+          Opponent played {{ moveFromNumber(game.player2Move) }} — reveal deadline: block
+          {{ String(game.revealDeadline) }}
+        </p>
+        <button
+          v-if="isCreator"
+          @click="settle('Revealing', () => revealMove(toRaw(game), gameTxid))"
+          class="btn btn-primary"
+        >
+          Reveal my move
+        </button>
+        <button v-else @click="settle('Claiming', () => claimTimeout(toRaw(game)))" class="btn btn-secondary">
+          Claim pot after deadline
+        </button>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const RPS_ANGULAR_COMPONENT: &str = r#"import { Component, Input, OnDestroy, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  MOVES,
+  Move,
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  playMove,
+  revealMove,
+  claimTimeout,
+  cancelGame,
+  gamePhase,
+  getBlockHeight,
+  getMyPubKey,
+  getSecret,
+  moveFromNumber,
+} from "../services/rpsService";
+import { MultiplayerSession } from "../services/multiplayerClient";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Rock · Paper · Scissors</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+      </div>
+
+      <div *ngIf="walletService.wallet().connected" class="bg-slate-800 rounded-lg p-6 space-y-6">
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+
+        <div class="flex gap-2">
+          <button
+            *ngFor="let m of moves"
+            (click)="move = m"
+            [class]="m === move ? 'btn btn-primary' : 'btn btn-secondary'"
+          >
+            {{ m }}
+          </button>
+        </div>
+
+        <div *ngIf="!game()" class="space-y-4">
+          <div class="space-y-2">
+            <h3 class="font-semibold">New game</h3>
+            <input [(ngModel)]="opponent" class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key" />
+            <input [(ngModel)]="stake" class="w-full rounded bg-slate-900 p-2" placeholder="Stake (sats)" />
+            <div class="flex gap-2">
+              <button (click)="create()" class="btn btn-primary">Commit move &amp; deploy</button>
+              <button (click)="findOpponent()" class="btn btn-secondary">Find opponent</button>
+            </div>
+          </div>
+          <div class="space-y-2">
+            <h3 class="font-semibold">Join or resume a game</h3>
+            <input [(ngModel)]="gameTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Game txid" />
+            <button (click)="load()" class="btn btn-secondary">Load</button>
+          </div>
+        </div>
+
+        <div *ngIf="game() && phase() === 'waiting-for-opponent'" class="space-y-2">
+          <p>Waiting for opponent (join deadline: block {{ game().joinDeadline.toString() }})</p>
+          <button *ngIf="isCreator()" (click)="cancel()" class="btn btn-secondary">Cancel after deadline</button>
+          <button *ngIf="!isCreator()" (click)="play()" class="btn btn-primary">Play {{ move }}</button>
+        </div>
+
+        <div *ngIf="game() && phase() === 'waiting-for-reveal'" class="space-y-2">
+          <p>
+            Opponent played {{ opponentMove() }} — reveal deadline: block
+            {{ game().revealDeadline.toString() }}
+          </p>
+          <button *ngIf="isCreator()" (click)="reveal()" class="btn btn-primary">Reveal my move</button>
+          <button *ngIf="!isCreator()" (click)="claim()" class="btn btn-secondary">Claim pot after deadline</button>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent implements OnDestroy {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  moves = MOVES;
+  game = signal<any>(null);
+  status = signal<string | null>(null);
+
+  gameTxid = "";
+  opponent = "";
+  stake = "1000";
+  move: Move = "rock";
+
+  private session: MultiplayerSession | null = null;
+  private matchId: string | null = null;
+
+  ngOnDestroy() {
+    this.session?.close();
+  }
+
+  phase() {
+    return gamePhase(this.game());
+  }
+
+  opponentMove() {
+    return moveFromNumber(this.game().player2Move);
+  }
+
+  isCreator() {
+    return getSecret(this.gameTxid) !== null;
+  }
+
+  // The action may return a message to keep on screen
+  private async run(label: string, action: () => Promise<string | void>) {
+    this.status.set(label + "...");
+    try {
+      this.status.set((await action()) || null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  private settle(label: string, action: () => Promise<{ txid: string }>) {
+    return this.run(label, async () => {
+      const { txid } = await action();
+      this.game.set(null);
+      this.session?.sendTurn(this.gameTxid, { txid, note: "Game settled in " + txid });
+      return "Settled: " + txid;
+    });
+  }
+
+  // Opponent's transactions arrive through the multiplayer server
+  private follow(session: MultiplayerSession, txid: string) {
+    session.join(txid);
+    session.onTurn((turn) =>
+      this.run("Opponent moved", async () => {
+        if (turn.note) {
+          this.game.set(null);
+          return turn.note;
+        }
+        this.game.set(turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid));
+      }),
+    );
+    session.onOpponentLeft(() => this.status.set("Opponent disconnected - the game continues on-chain"));
+  }
+
+  findOpponent() {
+    return this.run("Looking for an opponent", async () => {
+      const session = this.session ?? (await MultiplayerSession.connect());
+      this.session = session;
+      const match = await session.findMatch("rps", await getMyPubKey(), Number(this.stake));
+      if (match.role === "creator") {
+        this.opponent = match.opponent;
+        this.matchId = match.matchId;
+        return "Matched! Commit your move to start the game";
+      }
+      session.onStart((txid) =>
+        this.run("Loading game", async () => {
+          this.gameTxid = txid;
+          this.game.set(await restoreGame(txid));
+          this.follow(session, txid);
+        }),
+      );
+      return "Matched! Waiting for your opponent to commit their move";
+    });
+  }
+
+  create() {
+    return this.run("Creating game", async () => {
+      // Opponent gets ~6 blocks to join, then ~6 more before the reveal deadline
+      const height = await getBlockHeight();
+      const { txid, instance } = await createGame({
+        opponentPubKeyHex: this.opponent,
+        move: this.move,
+        stakeSats: Number(this.stake),
+        joinDeadline: height + 6,
+        revealDeadline: height + 12,
+      });
+      this.gameTxid = txid;
+      this.game.set(instance);
+      if (this.session && this.matchId) {
+        this.session.startMatch(this.matchId, txid);
+        this.matchId = null;
+        this.follow(this.session, txid);
+      }
+    });
+  }
+
+  load() {
+    return this.run("Loading game", async () => {
+      this.game.set(await restoreGame(this.gameTxid));
+    });
+  }
+
+  play() {
+    return this.run("Playing", async () => {
+      const result = await playMove(this.game(), this.move);
+      this.game.set(await restoreGameFromRaw(result.rawTx));
+      this.session?.sendTurn(this.gameTxid, result);
+    });
+  }
+
+  reveal() {
+    return this.settle("Revealing", () => revealMove(this.game(), this.gameTxid));
+  }
+
+  claim() {
+    return this.settle("Claiming", () => claimTimeout(this.game()));
+  }
+
+  cancel() {
+    return this.settle("Cancelling", () => cancelGame(this.game()));
+  }
+}
+"#;
+
+pub(super) const RPS_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { onDestroy } from "svelte";
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    MOVES,
+    type Move,
+    createGame,
+    restoreGame,
+    restoreGameFromRaw,
+    playMove,
+    revealMove,
+    claimTimeout,
+    cancelGame,
+    gamePhase,
+    getBlockHeight,
+    getMyPubKey,
+    getSecret,
+    moveFromNumber,
+  } from "../services/rpsService";
+  import { MultiplayerSession } from "../services/multiplayerClient";
+
+  export let contractName: string;
+
+  let game: any = null;
+  let gameTxid = "";
+  let opponent = "";
+  let stake = "1000";
+  let move: Move = "rock";
+  let session: MultiplayerSession | null = null;
+  let matchId: string | null = null;
+  let status: string | null = null;
+
+  $: isCreator = game !== null && getSecret(gameTxid) !== null;
+
+  onDestroy(() => session?.close());
+
+  // The action may return a message to keep on screen
+  async function run(label: string, action: () => Promise<string | void>) {
+    status = label + "...";
+    try {
+      status = (await action()) || null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  // Opponent's transactions arrive through the multiplayer server
+  function follow(s: MultiplayerSession, txid: string) {
+    s.join(txid);
+    s.onTurn((turn) =>
+      run("Opponent moved", async () => {
+        if (turn.note) {
+          game = null;
+          return turn.note;
+        }
+        game = turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid);
+      }),
+    );
+    s.onOpponentLeft(() => (status = "Opponent disconnected - the game continues on-chain"));
+  }
+
+  const findOpponent = () =>
+    run("Looking for an opponent", async () => {
+      const s = session ?? (await MultiplayerSession.connect());
+      session = s;
+      const match = await s.findMatch("rps", await getMyPubKey(), Number(stake));
+      if (match.role === "creator") {
+        opponent = match.opponent;
+        matchId = match.matchId;
+        return "Matched! Commit your move to start the game";
+      }
+      s.onStart((txid) =>
+        run("Loading game", async () => {
+          gameTxid = txid;
+          game = await restoreGame(txid);
+          follow(s, txid);
+        }),
+      );
+      return "Matched! Waiting for your opponent to commit their move";
+    });
+
+  const create = () =>
+    run("Creating game", async () => {
+      // Opponent gets ~6 blocks to join, then ~6 more before the reveal deadline
+      const height = await getBlockHeight();
+      const result = await createGame({
+        opponentPubKeyHex: opponent,
+        move,
+        stakeSats: Number(stake),
+        joinDeadline: height + 6,
+        revealDeadline: height + 12,
+      });
+      gameTxid = result.txid;
+      game = result.instance;
+      if (session && matchId) {
+        session.startMatch(matchId, result.txid);
+        matchId = null;
+        follow(session, result.txid);
+      }
+    });
+
+  const load = () =>
+    run("Loading game", async () => {
+      game = await restoreGame(gameTxid);
+    });
+
+  const play = () =>
+    run("Playing", async () => {
+      const result = await playMove(game, move);
+      game = await restoreGameFromRaw(result.rawTx);
+      session?.sendTurn(gameTxid, result);
+    });
+
+  const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const { txid } = await action();
+      game = null;
+      session?.sendTurn(gameTxid, { txid, note: "Game settled in " + txid });
+      return "Settled: " + txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Rock · Paper · Scissors</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-6">
+      <p class="text-slate-400">Contract: {contractName}</p>
+
+      <div class="flex gap-2">
+        {#each MOVES as m}
+          <button on:click={() => (move = m)} class={m === move ? "btn btn-primary" : "btn btn-secondary"}>
+            {m}
+          </button>
+        {/each}
+      </div>
+
+      {#if !game}
+        <div class="space-y-4">
+          <div class="space-y-2">
+            <h3 class="font-semibold">New game</h3>
+            <input bind:value={opponent} class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key" />
+            <input bind:value={stake} class="w-full rounded bg-slate-900 p-2" placeholder="Stake (sats)" />
+            <div class="flex gap-2">
+              <button on:click={create} class="btn btn-primary">Commit move &amp; deploy</button>
+              <button on:click={findOpponent} class="btn btn-secondary">Find opponent</button>
+            </div>
+          </div>
+          <div class="space-y-2">
+            <h3 class="font-semibold">Join or resume a game</h3>
+            <input bind:value={gameTxid} class="w-full rounded bg-slate-900 p-2" placeholder="Game txid" />
+            <button on:click={load} class="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      {:else if gamePhase(game) === "waiting-for-opponent"}
+        <div class="space-y-2">
+          <p>Waiting for opponent (join deadline: block {String(game.joinDeadline)})</p>
+          {#if isCreator}
+            <button on:click={() => settle("Cancelling", () => cancelGame(game))} class="btn btn-secondary">
+              Cancel after deadline
+            </button>
+          {:else}
+            <button on:click={play} class="btn btn-primary">Play {move}</button>
+          {/if}
+        </div>
+      {:else}
+        <div class="space-y-2">
+          <p>
+            Opponent played {moveFromNumber(game.player2Move)} — reveal deadline: block
+            {String(game.revealDeadline)}
+          </p>
+          {#if isCreator}
+            <button on:click={() => settle("Revealing", () => revealMove(game, gameTxid))} class="btn btn-primary">
+              Reveal my move
+            </button>
+          {:else}
+            <button on:click={() => settle("Claiming", () => claimTimeout(game))} class="btn btn-secondary">
+              Claim pot after deadline
+            </button>
+          {/if}
+        </div>
+      {/if}
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,1009 @@
+//! Streaming Payments (unidirectional payment channel): the template's service and its main
+//! component for each framework.
+
+pub(super) const CHANNEL_SERVICE: &str = r#"/**
+ * Channel Service - payment channel with off-chain updates
+ *
+ * On-chain: open (deploy), close (receiver), refund (after expiry).
+ * Off-chain: the sender signs close transactions for increasing amounts
+ * with a browser session key and posts them to the relay
+ * (relay/server.mjs, VITE_RELAY_URL). The receiver polls the relay and
+ * verifies every update before trusting it.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  bsv,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  findSig,
+  MethodCallOptions,
+} from "scrypt-ts";
+import PaymentChannel from "__CONTRACTS_DIR__/PaymentChannel.scrypt";
+import { getYoursDirectSigner } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/PaymentChannel.scrypt.json";
+const SESSION_KEYS_KEY = "channel_session_keys";
+const RELAY_URL = config.relayUrl;
+const NETWORK = config.network;
+const SIGHASH_ALL_FORKID = bsv.crypto.Signature.SIGHASH_ALL | bsv.crypto.Signature.SIGHASH_FORKID;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await PaymentChannel.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// SESSION KEYS (sender only)
+// ============================================================================
+
+function loadSessionKeys(): Record<string, string> {
+  try {
+    return JSON.parse(localStorage.getItem(SESSION_KEYS_KEY) || "{}");
+  } catch {
+    return {};
+  }
+}
+
+function saveSessionKey(channelTxid: string, wif: string) {
+  localStorage.setItem(SESSION_KEYS_KEY, JSON.stringify({ ...loadSessionKeys(), [channelTxid]: wif }));
+}
+
+function sessionKeyFor(channelTxid: string): bsv.PrivateKey | null {
+  const wif = loadSessionKeys()[channelTxid];
+  return wif ? bsv.PrivateKey.fromWIF(wif) : null;
+}
+
+/**
+ * True when this browser opened the channel (and can sign updates)
+ */
+export function isSender(channelTxid: string): boolean {
+  return sessionKeyFor(channelTxid) !== null;
+}
+
+// ============================================================================
+// CHAIN HELPERS
+// ============================================================================
+
+export async function getBlockHeight(): Promise<number> {
+  const res = await fetch(config.wocApiUrl + "/chain/info");
+  if (!res.ok) {
+    throw new Error("Failed to fetch block height: HTTP " + res.status);
+  }
+  return (await res.json()).blocks;
+}
+
+/**
+ * Most the receiver can be paid: the deposit minus the fixed close fee
+ */
+export function capacity(instance: PaymentChannel): number {
+  return instance.balance - Number(instance.fee);
+}
+
+// ============================================================================
+// OPEN / RESTORE
+// ============================================================================
+
+export interface OpenChannelParams {
+  receiverPubKeyHex: string;
+  depositSats: number;
+  feeSats: number;
+  expiry: number;
+}
+
+export async function openChannel(params: OpenChannelParams) {
+  if (params.depositSats <= params.feeSats) {
+    throw new Error("Deposit must be larger than the close fee");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const walletAddress = await signer.getDefaultAddress();
+  const sessionKey = bsv.PrivateKey.fromRandom(NETWORK === "testnet" ? "testnet" : "mainnet");
+
+  const instance = new PaymentChannel(
+    PubKey(sessionKey.toPublicKey().toHex()),
+    PubKeyHash(walletAddress.hashBuffer.toString("hex")),
+    PubKey(params.receiverPubKeyHex),
+    BigInt(params.feeSats),
+    BigInt(params.expiry),
+  );
+  await instance.connect(signer);
+
+  const tx = await instance.deploy(params.depositSats);
+  // Without the session key no further updates can be signed
+  saveSessionKey(tx.id, sessionKey.toWIF());
+  return { txid: tx.id, instance };
+}
+
+export async function restoreChannel(txid: string, outputIndex: number = 0): Promise<PaymentChannel> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = PaymentChannel.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// CLOSE TRANSACTION (shared by both sides)
+// ============================================================================
+
+function p2pkhScript(pkh: string): bsv.Script {
+  return bsv.Script.fromHex("76a914" + pkh + "88ac");
+}
+
+/**
+ * Splits "DER signature + sighash byte" hex back into a Signature
+ */
+function parseTxSig(sigHex: string): bsv.crypto.Signature {
+  const sig = bsv.crypto.Signature.fromString(sigHex.slice(0, -2));
+  sig.nhashtype = parseInt(sigHex.slice(-2), 16);
+  return sig;
+}
+
+/**
+ * Must produce byte-identical transactions for sender and receiver -
+ * no funding inputs, no change, fee fixed by the contract
+ */
+function buildCloseTx(instance: PaymentChannel, amount: number): bsv.Transaction {
+  const available = capacity(instance);
+  const tx = new bsv.Transaction();
+  tx.addInput(instance.buildContractInput());
+  tx.addOutput(new bsv.Transaction.Output({
+    script: bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(instance.receiver.toString()).toAddress()),
+    satoshis: amount,
+  }));
+  if (amount < available) {
+    tx.addOutput(new bsv.Transaction.Output({
+      script: p2pkhScript(instance.senderPkh),
+      satoshis: available - amount,
+    }));
+  }
+  return tx;
+}
+
+// ============================================================================
+// OFF-CHAIN UPDATES
+// ============================================================================
+
+export interface ChannelUpdate {
+  amount: number;
+  // DER signature + sighash byte, hex
+  senderSig: string;
+}
+
+/**
+ * Sender: sign a close transaction paying `amount` and publish it
+ */
+export async function sendUpdate(instance: PaymentChannel, channelTxid: string, amount: number): Promise<ChannelUpdate> {
+  const sessionKey = sessionKeyFor(channelTxid);
+  if (!sessionKey) {
+    throw new Error("No session key for channel " + channelTxid + " - was it opened in this browser?");
+  }
+  if (amount <= 0 || amount > capacity(instance)) {
+    throw new Error("Amount must be between 1 and " + capacity(instance) + " sats");
+  }
+
+  const tx = buildCloseTx(instance, amount);
+  const sig = bsv.Transaction.Sighash.sign(
+    tx,
+    sessionKey,
+    SIGHASH_ALL_FORKID,
+    0,
+    instance.lockingScript,
+    new bsv.crypto.BN(instance.balance),
+  );
+  const update = { amount, senderSig: sig.toTxFormat().toString("hex") };
+
+  const res = await fetch(RELAY_URL + "/channels/" + channelTxid + "/updates", {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify(update),
+  });
+  if (!res.ok) {
+    throw new Error("Relay rejected update: HTTP " + res.status + " " + (await res.text()));
+  }
+  return update;
+}
+
+/**
+ * Receiver: check an update really is signed by the channel's sender
+ */
+export function verifyUpdate(instance: PaymentChannel, update: ChannelUpdate): boolean {
+  if (update.amount <= 0 || update.amount > capacity(instance)) return false;
+  try {
+    const tx = buildCloseTx(instance, update.amount);
+    return bsv.Transaction.Sighash.verify(
+      tx,
+      parseTxSig(update.senderSig),
+      bsv.PublicKey.fromHex(instance.senderKey.toString()),
+      0,
+      instance.lockingScript,
+      new bsv.crypto.BN(instance.balance),
+    );
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Receiver: latest update from the relay, or null if it is missing or invalid
+ */
+export async function fetchLatestUpdate(instance: PaymentChannel, channelTxid: string): Promise<ChannelUpdate | null> {
+  const res = await fetch(RELAY_URL + "/channels/" + channelTxid + "/updates/latest");
+  if (res.status === 404) return null;
+  if (!res.ok) {
+    throw new Error("Relay request failed: HTTP " + res.status);
+  }
+  const update: ChannelUpdate = await res.json();
+  // Never trust the relay - it only forwards what the sender signed
+  return verifyUpdate(instance, update) ? update : null;
+}
+
+// ============================================================================
+// ON-CHAIN SETTLEMENT
+// ============================================================================
+
+/**
+ * Receiver: co-sign the latest update and broadcast it
+ */
+export async function closeChannel(instance: PaymentChannel, update: ChannelUpdate): Promise<{ txid: string }> {
+  if (!verifyUpdate(instance, update)) {
+    throw new Error("Update signature is invalid");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  instance.bindTxBuilder("close", async (current: PaymentChannel) => ({
+    tx: buildCloseTx(current, update.amount),
+    atInputIndex: 0,
+    nexts: [],
+  }));
+
+  const { tx } = await instance.methods.close(
+    BigInt(update.amount),
+    Sig(update.senderSig),
+    (sigResps: any) => findSig(sigResps, pubKey),
+    { pubKeyOrAddrToSign: pubKey } as MethodCallOptions<PaymentChannel>,
+  );
+  return { txid: tx.id };
+}
+
+/**
+ * Anyone: return the deposit to the sender after expiry
+ */
+export async function refundChannel(instance: PaymentChannel): Promise<{ txid: string }> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  instance.bindTxBuilder("refund", async (current: PaymentChannel, options: MethodCallOptions<PaymentChannel>) => {
+    const tx = new bsv.Transaction();
+    tx.addInput(current.buildContractInput());
+    tx.addOutput(new bsv.Transaction.Output({
+      script: p2pkhScript(current.senderPkh),
+      satoshis: capacity(current),
+    }));
+    // nLockTime must reach the expiry, input 0 must be non-final
+    tx.setLockTime(options.lockTime!);
+    tx.setInputSequence(0, options.sequence ?? 0);
+    return { tx, atInputIndex: 0, nexts: [] };
+  });
+
+  const { tx } = await instance.methods.refund({
+    lockTime: Number(instance.expiry),
+    sequence: 0,
+  } as MethodCallOptions<PaymentChannel>);
+  return { txid: tx.id };
+}
+
+export default {
+  openChannel,
+  restoreChannel,
+  sendUpdate,
+  fetchLatestUpdate,
+  closeChannel,
+  refundChannel,
+};
+"#;
+
+pub(super) const CHANNEL_REACT_COMPONENT: &str = r#"import { useEffect, useRef, useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  ChannelUpdate,
+  openChannel,
+  restoreChannel,
+  sendUpdate,
+  fetchLatestUpdate,
+  closeChannel,
+  refundChannel,
+  capacity,
+  isSender,
+  getBlockHeight,
+} from "../services/channelService";
+
+interface GameProps {
+  contractName: string;
+}
+
+const TICK_MS = 2000;
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [channel, setChannel] = useState<any>(null);
+  const [channelTxid, setChannelTxid] = useState("");
+  const [receiver, setReceiver] = useState("");
+  const [deposit, setDeposit] = useState("10000");
+  const [rate, setRate] = useState("10");
+  const [streaming, setStreaming] = useState(false);
+  const [latest, setLatest] = useState<ChannelUpdate | null>(null);
+  const [status, setStatus] = useState<string | null>(null);
+  const paid = useRef(0);
+
+  const sender = channel !== null && isSender(channelTxid);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  // Sender: sign a slightly larger payment every tick
+  useEffect(() => {
+    if (!streaming || !channel) return;
+    const timer = setInterval(async () => {
+      const amount = Math.min(paid.current + Number(rate), capacity(channel));
+      try {
+        setLatest(await sendUpdate(channel, channelTxid, amount));
+        paid.current = amount;
+        if (amount >= capacity(channel)) setStreaming(false);
+      } catch (err: any) {
+        setStreaming(false);
+        setStatus(err?.message || String(err));
+      }
+    }, TICK_MS);
+    return () => clearInterval(timer);
+  }, [streaming, channel, channelTxid, rate]);
+
+  // Receiver: watch the relay for new verified updates
+  useEffect(() => {
+    if (!channel || sender) return;
+    const timer = setInterval(async () => {
+      const update = await fetchLatestUpdate(channel, channelTxid).catch(() => null);
+      if (update) setLatest(update);
+    }, TICK_MS);
+    return () => clearInterval(timer);
+  }, [channel, channelTxid, sender]);
+
+  const open = () =>
+    run("Opening channel", async () => {
+      // Receiver has ~1 day to close before the sender can reclaim
+      const height = await getBlockHeight();
+      const result = await openChannel({
+        receiverPubKeyHex: receiver,
+        depositSats: Number(deposit),
+        feeSats: 500,
+        expiry: height + 144,
+      });
+      paid.current = 0;
+      setChannelTxid(result.txid);
+      setChannel(result.instance);
+    });
+
+  const load = () =>
+    run("Loading channel", async () => {
+      setChannel(await restoreChannel(channelTxid));
+    });
+
+  const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const { txid } = await action();
+      setChannel(null);
+      setLatest(null);
+      setStatus("Settled: " + txid);
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Streaming Payments</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to open a channel</p>
+        </div>
+      ) : !channel ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open a channel (you pay)</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Receiver public key"
+              value={receiver}
+              onChange={(e) => setReceiver(e.target.value)}
+            />
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Deposit (sats)"
+              value={deposit}
+              onChange={(e) => setDeposit(e.target.value)}
+            />
+            <button onClick={open} className="btn btn-primary">Open</button>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open an existing channel</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Channel txid"
+              value={channelTxid}
+              onChange={(e) => setChannelTxid(e.target.value)}
+            />
+            <button onClick={load} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400 break-all">Channel: {channelTxid}</p>
+          <p>
+            {sender ? "Sent" : "Received"} off-chain: {latest?.amount ?? 0} / {capacity(channel)} sats · expires at block{" "}
+            {String(channel.expiry)}
+          </p>
+          {sender ? (
+            <div className="flex gap-2 items-center">
+              <input
+                className="w-32 rounded bg-slate-900 p-2"
+                placeholder="Sats per tick"
+                value={rate}
+                onChange={(e) => setRate(e.target.value)}
+              />
+              <button onClick={() => setStreaming(!streaming)} className="btn btn-primary">
+                {streaming ? "Pause" : "Stream"}
+              </button>
+              <button onClick={() => settle("Refunding", () => refundChannel(channel))} className="btn btn-secondary">
+                Refund after expiry
+              </button>
+            </div>
+          ) : (
+            <button
+              onClick={() => settle("Closing", () => closeChannel(channel, latest!))}
+              disabled={!latest}
+              className="btn btn-primary"
+            >
+              Close &amp; collect
+            </button>
+          )}
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const CHANNEL_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { computed, inject, onUnmounted, ref, shallowRef, toRaw, watch } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  type ChannelUpdate,
+  openChannel,
+  restoreChannel,
+  sendUpdate,
+  fetchLatestUpdate,
+  closeChannel,
+  refundChannel,
+  capacity,
+  isSender,
+  getBlockHeight,
+} from "../services/channelService";
+
+defineProps<{ contractName: string }>();
+
+const TICK_MS = 2000;
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const channel = shallowRef<any>(null);
+const channelTxid = ref("");
+const receiver = ref("");
+const deposit = ref("10000");
+const rate = ref("10");
+const streaming = ref(false);
+const latest = ref<ChannelUpdate | null>(null);
+const status = ref<string | null>(null);
+let paid = 0;
+
+const sender = computed(() => channel.value !== null && isSender(channelTxid.value));
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+// Sender: sign a slightly larger payment every tick.
+// Receiver: watch the relay for new verified updates.
+let timer: ReturnType<typeof setInterval> | undefined;
+watch([channel, streaming, sender], () => {
+  clearInterval(timer);
+  const instance = toRaw(channel.value);
+  if (!instance) return;
+  if (sender.value && streaming.value) {
+    timer = setInterval(async () => {
+      const amount = Math.min(paid + Number(rate.value), capacity(instance));
+      try {
+        latest.value = await sendUpdate(instance, channelTxid.value, amount);
+        paid = amount;
+        if (amount >= capacity(instance)) streaming.value = false;
+      } catch (err: any) {
+        streaming.value = false;
+        status.value = err?.message || String(err);
+      }
+    }, TICK_MS);
+  } else if (!sender.value) {
+    timer = setInterval(async () => {
+      const update = await fetchLatestUpdate(instance, channelTxid.value).catch(() => null);
+      if (update) latest.value = update;
+    }, TICK_MS);
+  }
+});
+onUnmounted(() => clearInterval(timer));
+
+const open = () =>
+  run("Opening channel", async () => {
+    // Receiver has ~1 day to close before the sender can reclaim
+    const height = await getBlockHeight();
+    const result = await openChannel({
+      receiverPubKeyHex: receiver.value,
+      depositSats: Number(deposit.value),
+      feeSats: 500,
+      expiry: height + 144,
+    });
+    paid = 0;
+    channelTxid.value = result.txid;
+    channel.value = result.instance;
+  });
+
+const load = () =>
+  run("Loading channel", async () => {
+    channel.value = await restoreChannel(channelTxid.value);
+  });
+
+const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+  run(label, async () => {
+    const { txid } = await action();
+    channel.value = null;
+    latest.value = null;
+    status.value = "Settled: " + txid;
+  });
+
+const close = () => settle("Closing", () => closeChannel(toRaw(channel.value), latest.value!));
+const refund = () => settle("Refunding", () => refundChannel(toRaw(channel.value)));
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Streaming Payments</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to open a channel</p>
+    </div>
+    <div v-else-if="!channel" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open a channel (you pay)</h3>
+        <input v-model="receiver" class="w-full rounded bg-slate-900 p-2" placeholder="Receiver public key" />
+        <input v-model="deposit" class="w-full rounded bg-slate-900 p-2" placeholder="Deposit (sats)" />
+        <button @click="open" class="btn btn-primary">Open</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing channel</h3>
+        <input v-model="channelTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Channel txid" />
+        <button @click="load" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Channel: {{ channelTxid }}</p>
+      <p>
+        {{ sender ? "Sent" : "Received" }} off-chain: {{ latest?.amount ?? 0 }} / {{ capacity(channel) }} sats ·
+        expires at block {{ String(channel.expiry) }}
+      </p>
+      <div v-if="sender" class="flex gap-2 items-center">
+        <input v-model="rate" class="w-32 rounded bg-slate-900 p-2" placeholder="Sats per tick" />
+        <button @click="streaming = !streaming" class="btn btn-primary">{{ streaming ? "Pause" : "Stream" }}</button>
+        <button @click="refund" class="btn btn-secondary">Refund after expiry</button>
+      </div>
+      <button v-else @click="close" :disabled="!latest" class="btn btn-primary">Close &amp; collect</button>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const CHANNEL_ANGULAR_COMPONENT: &str = r#"import { Component, Input, OnDestroy, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  ChannelUpdate,
+  openChannel,
+  restoreChannel,
+  sendUpdate,
+  fetchLatestUpdate,
+  closeChannel,
+  refundChannel,
+  capacity,
+  isSender,
+  getBlockHeight,
+} from "../services/channelService";
+
+const TICK_MS = 2000;
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Streaming Payments</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to open a channel</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !channel()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open a channel (you pay)</h3>
+          <input [(ngModel)]="receiver" class="w-full rounded bg-slate-900 p-2" placeholder="Receiver public key" />
+          <input [(ngModel)]="deposit" class="w-full rounded bg-slate-900 p-2" placeholder="Deposit (sats)" />
+          <button (click)="open()" class="btn btn-primary">Open</button>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open an existing channel</h3>
+          <input [(ngModel)]="channelTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Channel txid" />
+          <button (click)="load()" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && channel()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400 break-all">Channel: {{ channelTxid }}</p>
+        <p>
+          {{ sender() ? "Sent" : "Received" }} off-chain: {{ latest()?.amount ?? 0 }} / {{ capacity() }} sats ·
+          expires at block {{ channel().expiry.toString() }}
+        </p>
+        <div *ngIf="sender()" class="flex gap-2 items-center">
+          <input [(ngModel)]="rate" class="w-32 rounded bg-slate-900 p-2" placeholder="Sats per tick" />
+          <button (click)="toggleStreaming()" class="btn btn-primary">{{ streaming ? "Pause" : "Stream" }}</button>
+          <button (click)="refund()" class="btn btn-secondary">Refund after expiry</button>
+        </div>
+        <button *ngIf="!sender()" (click)="close()" [disabled]="!latest()" class="btn btn-primary">
+          Close &amp; collect
+        </button>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent implements OnDestroy {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  channel = signal<any>(null);
+  latest = signal<ChannelUpdate | null>(null);
+  status = signal<string | null>(null);
+
+  channelTxid = "";
+  receiver = "";
+  deposit = "10000";
+  rate = "10";
+  streaming = false;
+
+  private paid = 0;
+  private timer: ReturnType<typeof setInterval> | undefined;
+
+  ngOnDestroy() {
+    clearInterval(this.timer);
+  }
+
+  sender() {
+    return this.channel() !== null && isSender(this.channelTxid);
+  }
+
+  capacity() {
+    return capacity(this.channel());
+  }
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  // Sender: sign a slightly larger payment every tick.
+  // Receiver: watch the relay for new verified updates.
+  private restartTimer() {
+    clearInterval(this.timer);
+    const instance = this.channel();
+    if (!instance) return;
+    if (this.sender() && this.streaming) {
+      this.timer = setInterval(async () => {
+        const amount = Math.min(this.paid + Number(this.rate), capacity(instance));
+        try {
+          this.latest.set(await sendUpdate(instance, this.channelTxid, amount));
+          this.paid = amount;
+          if (amount >= capacity(instance)) this.toggleStreaming();
+        } catch (err: any) {
+          this.toggleStreaming();
+          this.status.set(err?.message || String(err));
+        }
+      }, TICK_MS);
+    } else if (!this.sender()) {
+      this.timer = setInterval(async () => {
+        const update = await fetchLatestUpdate(instance, this.channelTxid).catch(() => null);
+        if (update) this.latest.set(update);
+      }, TICK_MS);
+    }
+  }
+
+  toggleStreaming() {
+    this.streaming = !this.streaming;
+    this.restartTimer();
+  }
+
+  open() {
+    return this.run("Opening channel", async () => {
+      // Receiver has ~1 day to close before the sender can reclaim
+      const height = await getBlockHeight();
+      const result = await openChannel({
+        receiverPubKeyHex: this.receiver,
+        depositSats: Number(this.deposit),
+        feeSats: 500,
+        expiry: height + 144,
+      });
+      this.paid = 0;
+      this.channelTxid = result.txid;
+      this.channel.set(result.instance);
+      this.restartTimer();
+    });
+  }
+
+  load() {
+    return this.run("Loading channel", async () => {
+      this.channel.set(await restoreChannel(this.channelTxid));
+      this.restartTimer();
+    });
+  }
+
+  private settle(label: string, action: () => Promise<{ txid: string }>) {
+    return this.run(label, async () => {
+      const { txid } = await action();
+      clearInterval(this.timer);
+      this.channel.set(null);
+      this.latest.set(null);
+      this.status.set("Settled: " + txid);
+    });
+  }
+
+  close() {
+    return this.settle("Closing", () => closeChannel(this.channel(), this.latest()!));
+  }
+
+  refund() {
+    return this.settle("Refunding", () => refundChannel(this.channel()));
+  }
+}
+"#;
+
+pub(super) const CHANNEL_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { onDestroy } from "svelte";
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    type ChannelUpdate,
+    openChannel,
+    restoreChannel,
+    sendUpdate,
+    fetchLatestUpdate,
+    closeChannel,
+    refundChannel,
+    capacity,
+    isSender,
+    getBlockHeight,
+  } from "../services/channelService";
+
+  export let contractName: string;
+
+  const TICK_MS = 2000;
+
+  let channel: any = null;
+  let channelTxid = "";
+  let receiver = "";
+  let deposit = "10000";
+  let rate = "10";
+  let streaming = false;
+  let latest: ChannelUpdate | null = null;
+  let status: string | null = null;
+  let paid = 0;
+
+  $: sender = channel !== null && isSender(channelTxid);
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  // Sender: sign a slightly larger payment every tick.
+  // Receiver: watch the relay for new verified updates.
+  let timer: ReturnType<typeof setInterval> | undefined;
+  function restartTimer(instance: any, isSending: boolean, isStreaming: boolean) {
+    clearInterval(timer);
+    if (!instance) return;
+    if (isSending && isStreaming) {
+      timer = setInterval(async () => {
+        const amount = Math.min(paid + Number(rate), capacity(instance));
+        try {
+          latest = await sendUpdate(instance, channelTxid, amount);
+          paid = amount;
+          if (amount >= capacity(instance)) streaming = false;
+        } catch (err: any) {
+          streaming = false;
+          status = err?.message || String(err);
+        }
+      }, TICK_MS);
+    } else if (!isSending) {
+      timer = setInterval(async () => {
+        const update = await fetchLatestUpdate(instance, channelTxid).catch(() => null);
+        if (update) latest = update;
+      }, TICK_MS);
+    }
+  }
+  $: restartTimer(channel, sender, streaming);
+  onDestroy(() => clearInterval(timer));
+
+  const open = () =>
+    run("Opening channel", async () => {
+      // Receiver has ~1 day to close before the sender can reclaim
+      const height = await getBlockHeight();
+      const result = await openChannel({
+        receiverPubKeyHex: receiver,
+        depositSats: Number(deposit),
+        feeSats: 500,
+        expiry: height + 144,
+      });
+      paid = 0;
+      channelTxid = result.txid;
+      channel = result.instance;
+    });
+
+  const load = () =>
+    run("Loading channel", async () => {
+      channel = await restoreChannel(channelTxid);
+    });
+
+  const settle = (label: string, action: () => Promise<{ txid: string }>) =>
+    run(label, async () => {
+      const result = await action();
+      channel = null;
+      latest = null;
+      status = "Settled: " + result.txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Streaming Payments</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to open a channel</p>
+    </div>
+  {:else if !channel}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open a channel (you pay)</h3>
+        <input bind:value={receiver} class="w-full rounded bg-slate-900 p-2" placeholder="Receiver public key" />
+        <input bind:value={deposit} class="w-full rounded bg-slate-900 p-2" placeholder="Deposit (sats)" />
+        <button on:click={open} class="btn btn-primary">Open</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing channel</h3>
+        <input bind:value={channelTxid} class="w-full rounded bg-slate-900 p-2" placeholder="Channel txid" />
+        <button on:click={load} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Channel: {channelTxid}</p>
+      <p>
+        {sender ? "Sent" : "Received"} off-chain: {latest?.amount ?? 0} / {capacity(channel)} sats · expires at block
+        {String(channel.expiry)}
+      </p>
+      {#if sender}
+        <div class="flex gap-2 items-center">
+          <input bind:value={rate} class="w-32 rounded bg-slate-900 p-2" placeholder="Sats per tick" />
+          <button on:click={() => (streaming = !streaming)} class="btn btn-primary">{streaming ? "Pause" : "Stream"}</button>
+          <button on:click={() => settle("Refunding", () => refundChannel(channel))} class="btn btn-secondary">
+            Refund after expiry
+          </button>
+        </div>
+      {:else}
+        <button
+          on:click={() => settle("Closing", () => closeChannel(channel, latest!))}
+          disabled={!latest}
+          class="btn btn-primary"
+        >
+          Close &amp; collect
+        </button>
+      {/if}
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,949 @@
+//! Tic-Tac-Toe (two-player stateful game): the template's service and its main
+//! component for each framework.
+
+pub(super) const TICTACTOE_SERVICE: &str = r#"/**
+ * Tic-Tac-Toe Service - create a game, load it and play moves
+ *
+ * The creator plays X and funds the pot. Every move spends the game UTXO and
+ * creates a new one, so share the latest txid with your opponent after each
+ * move (or reload it from theirs) - multiplayerClient.ts does this for you.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import { bsv, PubKey, findSig, ContractTransaction, MethodCallOptions } from "scrypt-ts";
+import TicTacToe from "__CONTRACTS_DIR__/TicTacToe.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/TicTacToe.scrypt.json";
+const FEE_PER_KB = config.feePerKb;
+const MIN_FEE_SATS = 1000;
+
+export const MARKS = ["", "X", "O"] as const;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await TicTacToe.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// STATE HELPERS
+// ============================================================================
+
+export async function getMyPubKey(): Promise<string> {
+  const signer = await getYoursDirectSigner();
+  return (await signer.getDefaultPubKey()).toHex();
+}
+
+export interface GameSummary {
+  board: number[];
+  isXTurn: boolean;
+  pot: number;
+  playerX: string;
+  playerO: string;
+}
+
+export function summarize(instance: TicTacToe): GameSummary {
+  return {
+    board: instance.board.map(Number),
+    isXTurn: instance.isXTurn,
+    pot: instance.balance,
+    playerX: instance.playerX,
+    playerO: instance.playerO,
+  };
+}
+
+export function isMyTurn(instance: TicTacToe, myPubKey: string): boolean {
+  return (instance.isXTurn ? instance.playerX : instance.playerO) === myPubKey;
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export async function createGame(opponentPubKeyHex: string, stakeSats: number) {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const myPubKey = await signer.getDefaultPubKey();
+  const instance = new TicTacToe(PubKey(myPubKey.toHex()), PubKey(opponentPubKeyHex), BigInt(stakeSats));
+  await instance.connect(signer);
+
+  // The pot covers both stakes
+  const tx = await instance.deploy(stakeSats * 2);
+  return { txid: tx.id, instance };
+}
+
+export async function restoreGame(txid: string, outputIndex: number = 0): Promise<TicTacToe> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = TicTacToe.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+/**
+ * Same as restoreGame, from a raw tx relayed by the opponent - no need to
+ * wait for the explorer to pick it up
+ */
+export async function restoreGameFromRaw(rawTx: string, outputIndex: number = 0): Promise<TicTacToe> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const instance = TicTacToe.fromTx(new bsv.Transaction(rawTx), outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDER
+// ============================================================================
+
+function payTo(pubKey: string, satoshis: number) {
+  return { script: bsv.Script.buildPublicKeyHashOut(bsv.PublicKey.fromHex(pubKey).toAddress()), satoshis };
+}
+
+async function buildTx(
+  current: TicTacToe,
+  options: MethodCallOptions<TicTacToe>,
+  outputs: Array<{ script: bsv.Script; satoshis: number }>,
+  next?: TicTacToe,
+): Promise<ContractTransaction> {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  for (const output of outputs) {
+    tx.addOutput(new bsv.Transaction.Output(output));
+  }
+
+  let total = 0;
+  for (const utxo of await getWalletUtxos()) {
+    if (total >= MIN_FEE_SATS) break;
+    tx.from({ txId: utxo.txid, outputIndex: utxo.vout, script: utxo.script, satoshis: utxo.satoshis });
+    total += utxo.satoshis;
+  }
+  if (total < MIN_FEE_SATS) {
+    throw new Error("Wallet balance too low: need " + MIN_FEE_SATS + " sats for the fee");
+  }
+
+  tx.feePerKb(FEE_PER_KB);
+  if (options.changeAddress) {
+    tx.change(options.changeAddress);
+  }
+
+  const nexts = next ? [{ instance: next, atOutputIndex: 0, balance: current.balance }] : [];
+  return { tx, atInputIndex: 0, nexts };
+}
+
+// ============================================================================
+// ACTIONS
+// ============================================================================
+
+export type MoveOutcome = "continue" | "X wins" | "O wins" | "draw";
+
+export async function makeMove(
+  instance: TicTacToe,
+  position: number,
+): Promise<{ txid: string; rawTx: string; outcome: MoveOutcome }> {
+  if (instance.board[position] !== 0n) {
+    throw new Error("Square already taken");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  const pubKey = await signer.getDefaultPubKey();
+
+  let outcome: MoveOutcome = "continue";
+
+  // Output 0 mirrors TicTacToe.move(): winner payout, draw split or next state
+  instance.bindTxBuilder("move", (current: TicTacToe, options: MethodCallOptions<TicTacToe>) => {
+    const next = current.next();
+    next.board[position] = current.isXTurn ? 1n : 2n;
+
+    const winner = next.checkWinner();
+    if (winner !== 0n) {
+      outcome = winner === 1n ? "X wins" : "O wins";
+      const winnerPubKey = winner === 1n ? current.playerX : current.playerO;
+      return buildTx(current, options, [payTo(winnerPubKey, current.balance)]);
+    }
+    if (next.isBoardFull()) {
+      // Only output 0 (X's half) is verified - O's half is built here
+      outcome = "draw";
+      const half = Math.floor(current.balance / 2);
+      return buildTx(current, options, [
+        payTo(current.playerX, half),
+        payTo(current.playerO, current.balance - half),
+      ]);
+    }
+
+    next.isXTurn = !current.isXTurn;
+    return buildTx(current, options, [{ script: next.lockingScript, satoshis: current.balance }], next);
+  });
+
+  const { tx } = await instance.methods.move(
+    BigInt(position),
+    (sigResps: any) => findSig(sigResps, pubKey),
+    {
+      pubKeyOrAddrToSign: pubKey,
+      changeAddress: await signer.getDefaultAddress(),
+    } as MethodCallOptions<TicTacToe>,
+  );
+  return { txid: tx.id, rawTx: tx.toString(), outcome };
+}
+
+export default {
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  makeMove,
+};
+"#;
+
+pub(super) const TICTACTOE_REACT_COMPONENT: &str = r#"import { useEffect, useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  MARKS,
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  makeMove,
+  summarize,
+  isMyTurn,
+  getMyPubKey,
+} from "../services/ticTacToeService";
+import { MultiplayerSession } from "../services/multiplayerClient";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [game, setGame] = useState<any>(null);
+  const [me, setMe] = useState("");
+  const [txid, setTxid] = useState("");
+  const [opponent, setOpponent] = useState("");
+  const [stake, setStake] = useState("1000");
+  // Deploy txid - names the multiplayer room for the whole game
+  const [room, setRoom] = useState("");
+  const [session, setSession] = useState<MultiplayerSession | null>(null);
+  const [matchId, setMatchId] = useState<string | null>(null);
+  const [status, setStatus] = useState<string | null>(null);
+
+  useEffect(() => () => session?.close(), [session]);
+
+  // The action may return a message to keep on screen
+  const run = async (label: string, action: () => Promise<string | void>) => {
+    setStatus(label + "...");
+    try {
+      setStatus((await action()) || null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  // Opponent's moves arrive through the multiplayer server
+  const follow = (s: MultiplayerSession, gameTxid: string) => {
+    setRoom(gameTxid);
+    s.join(gameTxid);
+    s.onTurn((turn) =>
+      run("Opponent moved", async () => {
+        setTxid(turn.txid);
+        if (turn.note) {
+          setGame(null);
+          return turn.note;
+        }
+        setGame(turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid));
+      }),
+    );
+    s.onOpponentLeft(() => setStatus("Opponent disconnected - the game continues on-chain"));
+  };
+
+  const findOpponent = () =>
+    run("Looking for an opponent", async () => {
+      const s = session ?? (await MultiplayerSession.connect());
+      setSession(s);
+      const myPubKey = await getMyPubKey();
+      setMe(myPubKey);
+      const match = await s.findMatch("tictactoe", myPubKey, Number(stake));
+      if (match.role === "creator") {
+        setOpponent(match.opponent);
+        setMatchId(match.matchId);
+        return "Matched! Deploy the game to start";
+      }
+      s.onStart((gameTxid) =>
+        run("Loading game", async () => {
+          setTxid(gameTxid);
+          setGame(await restoreGame(gameTxid));
+          follow(s, gameTxid);
+        }),
+      );
+      return "Matched! Waiting for your opponent to deploy the game";
+    });
+
+  const create = () =>
+    run("Creating game", async () => {
+      const result = await createGame(opponent, Number(stake));
+      setMe(await getMyPubKey());
+      setTxid(result.txid);
+      setGame(result.instance);
+      if (session && matchId) {
+        session.startMatch(matchId, result.txid);
+        setMatchId(null);
+        follow(session, result.txid);
+      }
+    });
+
+  const load = () =>
+    run("Loading game", async () => {
+      setMe(await getMyPubKey());
+      setGame(await restoreGame(txid));
+    });
+
+  // Every move spends the game UTXO; continue from the new transaction
+  const play = (position: number) =>
+    run("Playing", async () => {
+      const result = await makeMove(game, position);
+      setTxid(result.txid);
+      if (result.outcome === "continue") {
+        setGame(await restoreGameFromRaw(result.rawTx));
+        session?.sendTurn(room, result);
+        return;
+      }
+      const note = "Game over (" + result.outcome + "), pot paid out in " + result.txid;
+      setGame(null);
+      session?.sendTurn(room, { txid: result.txid, note });
+      return note;
+    });
+
+  const summary = game ? summarize(game) : null;
+  const myTurn = game ? isMyTurn(game, me) : false;
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Tic-Tac-Toe</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to play</p>
+        </div>
+      ) : !summary ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">New game (you play X)</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Opponent public key (hex)"
+              value={opponent}
+              onChange={(e) => setOpponent(e.target.value)}
+            />
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Stake per player (sats)"
+              value={stake}
+              onChange={(e) => setStake(e.target.value)}
+            />
+            <div className="flex gap-2">
+              <button onClick={create} className="btn btn-primary">Deploy</button>
+              <button onClick={findOpponent} className="btn btn-secondary">Find opponent</button>
+            </div>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Open an existing game</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Latest game txid"
+              value={txid}
+              onChange={(e) => setTxid(e.target.value)}
+            />
+            <button onClick={load} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400 break-all">Latest txid: {txid}</p>
+          <p>
+            Pot: {summary.pot} sats · {summary.isXTurn ? "X" : "O"} to move
+            {myTurn ? " (you)" : ""}
+          </p>
+          <div className="grid grid-cols-3 gap-2 w-48">
+            {summary.board.map((cell, i) => (
+              <button
+                key={i}
+                className="h-16 rounded bg-slate-900 text-2xl font-bold"
+                disabled={cell !== 0 || !myTurn}
+                onClick={() => play(i)}
+              >
+                {MARKS[cell]}
+              </button>
+            ))}
+          </div>
+          <button onClick={load} className="btn btn-secondary">Refresh</button>
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const TICTACTOE_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { computed, inject, onUnmounted, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  MARKS,
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  makeMove,
+  summarize,
+  isMyTurn,
+  getMyPubKey,
+} from "../services/ticTacToeService";
+import { MultiplayerSession } from "../services/multiplayerClient";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const game = shallowRef<any>(null);
+const me = ref("");
+const txid = ref("");
+const opponent = ref("");
+const stake = ref("1000");
+// Deploy txid - names the multiplayer room for the whole game
+const room = ref("");
+const session = shallowRef<MultiplayerSession | null>(null);
+const matchId = ref<string | null>(null);
+const status = ref<string | null>(null);
+
+const summary = computed(() => (game.value ? summarize(toRaw(game.value)) : null));
+const myTurn = computed(() => (game.value ? isMyTurn(toRaw(game.value), me.value) : false));
+
+onUnmounted(() => session.value?.close());
+
+// The action may return a message to keep on screen
+async function run(label: string, action: () => Promise<string | void>) {
+  status.value = label + "...";
+  try {
+    status.value = (await action()) || null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+// Opponent's moves arrive through the multiplayer server
+function follow(s: MultiplayerSession, gameTxid: string) {
+  room.value = gameTxid;
+  s.join(gameTxid);
+  s.onTurn((turn) =>
+    run("Opponent moved", async () => {
+      txid.value = turn.txid;
+      if (turn.note) {
+        game.value = null;
+        return turn.note;
+      }
+      game.value = turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid);
+    }),
+  );
+  s.onOpponentLeft(() => (status.value = "Opponent disconnected - the game continues on-chain"));
+}
+
+const findOpponent = () =>
+  run("Looking for an opponent", async () => {
+    const s = session.value ?? (await MultiplayerSession.connect());
+    session.value = s;
+    me.value = await getMyPubKey();
+    const match = await s.findMatch("tictactoe", me.value, Number(stake.value));
+    if (match.role === "creator") {
+      opponent.value = match.opponent;
+      matchId.value = match.matchId;
+      return "Matched! Deploy the game to start";
+    }
+    s.onStart((gameTxid) =>
+      run("Loading game", async () => {
+        txid.value = gameTxid;
+        game.value = await restoreGame(gameTxid);
+        follow(s, gameTxid);
+      }),
+    );
+    return "Matched! Waiting for your opponent to deploy the game";
+  });
+
+const create = () =>
+  run("Creating game", async () => {
+    const result = await createGame(opponent.value, Number(stake.value));
+    me.value = await getMyPubKey();
+    txid.value = result.txid;
+    game.value = result.instance;
+    if (session.value && matchId.value) {
+      session.value.startMatch(matchId.value, result.txid);
+      matchId.value = null;
+      follow(session.value, result.txid);
+    }
+  });
+
+const load = () =>
+  run("Loading game", async () => {
+    me.value = await getMyPubKey();
+    game.value = await restoreGame(txid.value);
+  });
+
+// Every move spends the game UTXO; continue from the new transaction
+const play = (position: number) =>
+  run("Playing", async () => {
+    const result = await makeMove(toRaw(game.value), position);
+    txid.value = result.txid;
+    if (result.outcome === "continue") {
+      game.value = await restoreGameFromRaw(result.rawTx);
+      session.value?.sendTurn(room.value, result);
+      return;
+    }
+    const note = "Game over (" + result.outcome + "), pot paid out in " + result.txid;
+    game.value = null;
+    session.value?.sendTurn(room.value, { txid: result.txid, note });
+    return note;
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Tic-Tac-Toe</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+    <div v-else-if="!summary" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New game (you play X)</h3>
+        <input v-model="opponent" class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key (hex)" />
+        <input v-model="stake" class="w-full rounded bg-slate-900 p-2" placeholder="Stake per player (sats)" />
+        <div class="flex gap-2">
+          <button @click="create" class="btn btn-primary">Deploy</button>
+          <button @click="findOpponent" class="btn btn-secondary">Find opponent</button>
+        </div>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing game</h3>
+        <input v-model="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest game txid" />
+        <button @click="load" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+      <p>Pot: {{ summary.pot }} sats · {{ summary.isXTurn ? "X" : "O" }} to move{{ myTurn ? " (you)" : "" }}</p>
+      <div class="grid grid-cols-3 gap-2 w-48">
+        <button
+          v-for="(cell, i) in summary.board"
+          :key="i"
+          class="h-16 rounded bg-slate-900 text-2xl font-bold"
+          :disabled="cell !== 0 || !myTurn"
+          @click="play(i)"
+        >
+          {{ MARKS[cell] }}
+        </button>
+      </div>
+      <button @click="load" class="btn btn-secondary">Refresh</button>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const TICTACTOE_ANGULAR_COMPONENT: &str = r#"import { Component, Input, OnDestroy, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  MARKS,
+  createGame,
+  restoreGame,
+  restoreGameFromRaw,
+  makeMove,
+  summarize,
+  isMyTurn,
+  getMyPubKey,
+} from "../services/ticTacToeService";
+import { MultiplayerSession } from "../services/multiplayerClient";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Tic-Tac-Toe</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !game()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">New game (you play X)</h3>
+          <input [(ngModel)]="opponent" class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key (hex)" />
+          <input [(ngModel)]="stake" class="w-full rounded bg-slate-900 p-2" placeholder="Stake per player (sats)" />
+          <div class="flex gap-2">
+            <button (click)="create()" class="btn btn-primary">Deploy</button>
+            <button (click)="findOpponent()" class="btn btn-secondary">Find opponent</button>
+          </div>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Open an existing game</h3>
+          <input [(ngModel)]="txid" class="w-full rounded bg-slate-900 p-2" placeholder="Latest game txid" />
+          <button (click)="load()" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && game()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400 break-all">Latest txid: {{ txid }}</p>
+        <p>
+          Pot: {{ summary().pot }} sats · {{ summary().isXTurn ? "X" : "O" }} to move{{ myTurn() ? " (you)" : "" }}
+        </p>
+        <div class="grid grid-cols-3 gap-2 w-48">
+          <button
+            *ngFor="let cell of summary().board; let i = index"
+            class="h-16 rounded bg-slate-900 text-2xl font-bold"
+            [disabled]="cell !== 0 || !myTurn()"
+            (click)="play(i)"
+          >
+            {{ marks[cell] }}
+          </button>
+        </div>
+        <button (click)="load()" class="btn btn-secondary">Refresh</button>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent implements OnDestroy {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  readonly marks = MARKS;
+
+  game = signal<any>(null);
+  status = signal<string | null>(null);
+
+  me = "";
+  txid = "";
+  opponent = "";
+  stake = "1000";
+
+  // Deploy txid - names the multiplayer room for the whole game
+  private room = "";
+  private session: MultiplayerSession | null = null;
+  private matchId: string | null = null;
+
+  ngOnDestroy() {
+    this.session?.close();
+  }
+
+  summary() {
+    return summarize(this.game());
+  }
+
+  myTurn() {
+    return isMyTurn(this.game(), this.me);
+  }
+
+  // The action may return a message to keep on screen
+  private async run(label: string, action: () => Promise<string | void>) {
+    this.status.set(label + "...");
+    try {
+      this.status.set((await action()) || null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  // Opponent's moves arrive through the multiplayer server
+  private follow(session: MultiplayerSession, gameTxid: string) {
+    this.room = gameTxid;
+    session.join(gameTxid);
+    session.onTurn((turn) =>
+      this.run("Opponent moved", async () => {
+        this.txid = turn.txid;
+        if (turn.note) {
+          this.game.set(null);
+          return turn.note;
+        }
+        this.game.set(turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid));
+      }),
+    );
+    session.onOpponentLeft(() => this.status.set("Opponent disconnected - the game continues on-chain"));
+  }
+
+  findOpponent() {
+    return this.run("Looking for an opponent", async () => {
+      const session = this.session ?? (await MultiplayerSession.connect());
+      this.session = session;
+      this.me = await getMyPubKey();
+      const match = await session.findMatch("tictactoe", this.me, Number(this.stake));
+      if (match.role === "creator") {
+        this.opponent = match.opponent;
+        this.matchId = match.matchId;
+        return "Matched! Deploy the game to start";
+      }
+      session.onStart((gameTxid) =>
+        this.run("Loading game", async () => {
+          this.txid = gameTxid;
+          this.game.set(await restoreGame(gameTxid));
+          this.follow(session, gameTxid);
+        }),
+      );
+      return "Matched! Waiting for your opponent to deploy the game";
+    });
+  }
+
+  create() {
+    return this.run("Creating game", async () => {
+      const result = await createGame(this.opponent, Number(this.stake));
+      this.me = await getMyPubKey();
+      this.txid = result.txid;
+      this.game.set(result.instance);
+      if (this.session && this.matchId) {
+        this.session.startMatch(this.matchId, result.txid);
+        this.matchId = null;
+        this.follow(this.session, result.txid);
+      }
+    });
+  }
+
+  load() {
+    return this.run("Loading game", async () => {
+      this.me = await getMyPubKey();
+      this.game.set(await restoreGame(this.txid));
+    });
+  }
+
+  // Every move spends the game UTXO; continue from the new transaction
+  play(position: number) {
+    return this.run("Playing", async () => {
+      const result = await makeMove(this.game(), position);
+      this.txid = result.txid;
+      if (result.outcome === "continue") {
+        this.game.set(await restoreGameFromRaw(result.rawTx));
+        this.session?.sendTurn(this.room, result);
+        return;
+      }
+      const note = "Game over (" + result.outcome + "), pot paid out in " + result.txid;
+      this.game.set(null);
+      this.session?.sendTurn(this.room, { txid: result.txid, note });
+      return note;
+    });
+  }
+}
+"#;
+
+pub(super) const TICTACTOE_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { onDestroy } from "svelte";
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    MARKS,
+    createGame,
+    restoreGame,
+    restoreGameFromRaw,
+    makeMove,
+    summarize,
+    isMyTurn,
+    getMyPubKey,
+  } from "../services/ticTacToeService";
+  import { MultiplayerSession } from "../services/multiplayerClient";
+
+  export let contractName: string;
+
+  let game: any = null;
+  let me = "";
+  let txid = "";
+  let opponent = "";
+  let stake = "1000";
+  // Deploy txid - names the multiplayer room for the whole game
+  let room = "";
+  let session: MultiplayerSession | null = null;
+  let matchId: string | null = null;
+  let status: string | null = null;
+
+  $: summary = game ? summarize(game) : null;
+  $: myTurn = game !== null && isMyTurn(game, me);
+
+  onDestroy(() => session?.close());
+
+  // The action may return a message to keep on screen
+  async function run(label: string, action: () => Promise<string | void>) {
+    status = label + "...";
+    try {
+      status = (await action()) || null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  // Opponent's moves arrive through the multiplayer server
+  function follow(s: MultiplayerSession, gameTxid: string) {
+    room = gameTxid;
+    s.join(gameTxid);
+    s.onTurn((turn) =>
+      run("Opponent moved", async () => {
+        txid = turn.txid;
+        if (turn.note) {
+          game = null;
+          return turn.note;
+        }
+        game = turn.rawTx ? await restoreGameFromRaw(turn.rawTx) : await restoreGame(turn.txid);
+      }),
+    );
+    s.onOpponentLeft(() => (status = "Opponent disconnected - the game continues on-chain"));
+  }
+
+  const findOpponent = () =>
+    run("Looking for an opponent", async () => {
+      const s = session ?? (await MultiplayerSession.connect());
+      session = s;
+      me = await getMyPubKey();
+      const match = await s.findMatch("tictactoe", me, Number(stake));
+      if (match.role === "creator") {
+        opponent = match.opponent;
+        matchId = match.matchId;
+        return "Matched! Deploy the game to start";
+      }
+      s.onStart((gameTxid) =>
+        run("Loading game", async () => {
+          txid = gameTxid;
+          game = await restoreGame(gameTxid);
+          follow(s, gameTxid);
+        }),
+      );
+      return "Matched! Waiting for your opponent to deploy the game";
+    });
+
+  const create = () =>
+    run("Creating game", async () => {
+      const result = await createGame(opponent, Number(stake));
+      me = await getMyPubKey();
+      txid = result.txid;
+      game = result.instance;
+      if (session && matchId) {
+        session.startMatch(matchId, result.txid);
+        matchId = null;
+        follow(session, result.txid);
+      }
+    });
+
+  const load = () =>
+    run("Loading game", async () => {
+      me = await getMyPubKey();
+      game = await restoreGame(txid);
+    });
+
+  // Every move spends the game UTXO; continue from the new transaction
+  const play = (position: number) =>
+    run("Playing", async () => {
+      const result = await makeMove(game, position);
+      txid = result.txid;
+      if (result.outcome === "continue") {
+        game = await restoreGameFromRaw(result.rawTx);
+        session?.sendTurn(room, result);
+        return;
+      }
+      const note = "Game over (" + result.outcome + "), pot paid out in " + result.txid;
+      game = null;
+      session?.sendTurn(room, { txid: result.txid, note });
+      return note;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Tic-Tac-Toe</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+  {:else if !summary}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">New game (you play X)</h3>
+        <input bind:value={opponent} class="w-full rounded bg-slate-900 p-2" placeholder="Opponent public key (hex)" />
+        <input bind:value={stake} class="w-full rounded bg-slate-900 p-2" placeholder="Stake per player (sats)" />
+        <div class="flex gap-2">
+          <button on:click={create} class="btn btn-primary">Deploy</button>
+          <button on:click={findOpponent} class="btn btn-secondary">Find opponent</button>
+        </div>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Open an existing game</h3>
+        <input bind:value={txid} class="w-full rounded bg-slate-900 p-2" placeholder="Latest game txid" />
+        <button on:click={load} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400 break-all">Latest txid: {txid}</p>
+      <p>Pot: {summary.pot} sats · {summary.isXTurn ? "X" : "O"} to move{myTurn ? " (you)" : ""}</p>
+      <div class="grid grid-cols-3 gap-2 w-48">
+        {#each summary.board as cell, i}
+          <button
+            class="h-16 rounded bg-slate-900 text-2xl font-bold"
+            disabled={cell !== 0 || !myTurn}
+            on:click={() => play(i)}
+          >
+            {MARKS[cell]}
+          </button>
+        {/each}
+      </div>
+      <button on:click={load} class="btn btn-secondary">Refresh</button>
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
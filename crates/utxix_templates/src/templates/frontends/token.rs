@@ -0,0 +1,889 @@
+//! Token (BSV-21 mint + transfer): the template's service and its main
+//! component for each framework.
+
+pub(super) const TOKEN_SERVICE: &str = r#"/**
+ * Token Service - BSV-21 deploy, mint, transfer and balances
+ *
+ * Token UTXOs are 1-sat inscriptions, so this service uses scrypt-ord's
+ * OrdiProvider together with PandaSigner, which signs for the Yours Wallet
+ * ordinals address where tokens are held.
+ *
+ * Balances come from the GorillaPool 1Sat Ordinals indexer; use
+ * watchBalances() to poll it.
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import { bsv, toByteString, Addr, PandaSigner } from "scrypt-ts";
+import { OrdiProvider, BSV20V2P2PKH, type FTReceiver } from "scrypt-ord";
+import TokenMint from "__CONTRACTS_DIR__/TokenMint.scrypt";
+import { config } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/TokenMint.scrypt.json";
+const MINTS_KEY = "token_mints";
+const NETWORK = config.network;
+const ORD_API = NETWORK === "testnet"
+  ? "https://testnet.ordinals.gorillapool.io"
+  : "https://ordinals.gorillapool.io";
+const POLL_INTERVAL_MS = 15000;
+
+let artifactLoaded = false;
+let signer: PandaSigner | null = null;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await TokenMint.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+async function getOrdSigner(): Promise<PandaSigner> {
+  if (signer) return signer;
+  const network = NETWORK === "testnet" ? bsv.Networks.testnet : bsv.Networks.mainnet;
+  const candidate = new PandaSigner(new OrdiProvider(network));
+  const { isAuthenticated, error } = await candidate.requestAuth();
+  if (!isAuthenticated) {
+    throw new Error("Yours Wallet auth failed: " + error);
+  }
+  signer = candidate;
+  return signer;
+}
+
+export async function getOrdAddress(): Promise<string> {
+  return (await (await getOrdSigner()).getOrdAddress()).toString();
+}
+
+// ============================================================================
+// BALANCES (GorillaPool indexer)
+// ============================================================================
+
+export interface TokenBalance {
+  id: string;
+  sym: string;
+  dec: number;
+  // In base units, confirmed + pending
+  amount: bigint;
+}
+
+export async function fetchBalances(address: string): Promise<TokenBalance[]> {
+  const res = await fetch(ORD_API + "/api/bsv20/" + address + "/balance");
+  if (!res.ok) {
+    throw new Error("Token balance request failed: HTTP " + res.status);
+  }
+  const rows: any[] = await res.json();
+  // BSV-21 tokens carry an id; v1 (tick-based) tokens are skipped
+  return rows
+    .filter((row) => row.id)
+    .map((row) => ({
+      id: row.id,
+      sym: row.sym || row.id.slice(0, 8),
+      dec: Number(row.dec || 0),
+      amount: BigInt(row.all.confirmed) + BigInt(row.all.pending),
+    }));
+}
+
+/**
+ * Polls balances for the connected wallet; returns a function to stop
+ */
+export function watchBalances(
+  onUpdate: (balances: TokenBalance[]) => void,
+  onError: (err: Error) => void = console.error,
+): () => void {
+  let stopped = false;
+  const poll = async () => {
+    try {
+      const balances = await fetchBalances(await getOrdAddress());
+      if (!stopped) onUpdate(balances);
+    } catch (err: any) {
+      if (!stopped) onError(err);
+    }
+  };
+  poll();
+  const timer = setInterval(poll, POLL_INTERVAL_MS);
+  return () => {
+    stopped = true;
+    clearInterval(timer);
+  };
+}
+
+export function formatAmount(amount: bigint, dec: number): string {
+  if (dec === 0) return amount.toString();
+  const base = 10n ** BigInt(dec);
+  const fraction = (amount % base).toString().padStart(dec, "0").replace(/0+$/, "");
+  return (amount / base).toString() + (fraction ? "." + fraction : "");
+}
+
+export function parseAmount(value: string, dec: number): bigint {
+  const [whole, fraction = ""] = value.trim().split(".");
+  if (fraction.length > dec) {
+    throw new Error("Too many decimal places (max " + dec + ")");
+  }
+  return BigInt(whole || "0") * 10n ** BigInt(dec) + BigInt(fraction.padEnd(dec, "0") || "0");
+}
+
+// ============================================================================
+// MINT COVENANTS
+// ============================================================================
+
+// Latest covenant txid per token id - each mint() moves the covenant
+function loadMints(): Record<string, string> {
+  try {
+    return JSON.parse(localStorage.getItem(MINTS_KEY) || "{}");
+  } catch {
+    return {};
+  }
+}
+
+function saveMint(tokenId: string, txid: string) {
+  localStorage.setItem(MINTS_KEY, JSON.stringify({ ...loadMints(), [tokenId]: txid }));
+}
+
+export function knownMints(): Record<string, string> {
+  return loadMints();
+}
+
+export interface NewTokenParams {
+  sym: string;
+  max: bigint;
+  dec: number;
+  lim: bigint;
+}
+
+/**
+ * Deploys a new BSV-21 token with its whole supply held by TokenMint
+ */
+export async function deployToken(params: NewTokenParams): Promise<{ tokenId: string }> {
+  await loadArtifact();
+
+  const instance = new TokenMint(
+    toByteString(""),
+    toByteString(params.sym, true),
+    params.max,
+    BigInt(params.dec),
+    params.lim,
+  );
+  await instance.connect(await getOrdSigner());
+
+  const tokenId = await instance.deployToken();
+  saveMint(tokenId, tokenId.split("_")[0]);
+  return { tokenId };
+}
+
+/**
+ * Mints `amount` base units from the covenant to the connected wallet
+ */
+export async function mintTokens(tokenId: string, amount: bigint): Promise<{ txid: string }> {
+  const latestTxid = loadMints()[tokenId];
+  if (!latestTxid) {
+    throw new Error("Unknown mint covenant for " + tokenId + " - add its latest txid first");
+  }
+  await loadArtifact();
+
+  const ordSigner = await getOrdSigner();
+  const tx = await ordSigner.provider!.getTransaction(latestTxid);
+  const instance = TokenMint.fromTx(tx, 0);
+  await instance.connect(ordSigner);
+
+  const dest = bsv.Address.fromString(await getOrdAddress());
+  const { tx: mintTx } = await instance.methods.mint(Addr(dest.toByteString()), amount);
+
+  saveMint(tokenId, mintTx.id);
+  return { txid: mintTx.id };
+}
+
+export function trackMint(tokenId: string, latestTxid: string) {
+  saveMint(tokenId, latestTxid);
+}
+
+// ============================================================================
+// TRANSFER
+// ============================================================================
+
+/**
+ * Sends `amount` base units of a token to another ordinals address.
+ * Change in tokens returns to the sender automatically.
+ */
+export async function sendTokens(tokenId: string, recipient: string, amount: bigint): Promise<{ txid: string }> {
+  const ordSigner = await getOrdSigner();
+  const from = await getOrdAddress();
+
+  const p2pkhs = await BSV20V2P2PKH.getBSV20(tokenId, from);
+  if (p2pkhs.length === 0) {
+    throw new Error("No " + tokenId + " tokens in this wallet");
+  }
+  await Promise.all(p2pkhs.map((p) => p.connect(ordSigner)));
+
+  const recipients: Array<FTReceiver> = [
+    {
+      instance: new BSV20V2P2PKH(
+        toByteString(tokenId, true),
+        p2pkhs[0].sym,
+        p2pkhs[0].max,
+        p2pkhs[0].dec,
+        Addr(bsv.Address.fromString(recipient).toByteString()),
+      ),
+      amt: amount,
+    },
+  ];
+
+  const { tx } = await BSV20V2P2PKH.transfer(p2pkhs, ordSigner, recipients);
+  return { txid: tx.id };
+}
+
+export default {
+  fetchBalances,
+  watchBalances,
+  deployToken,
+  mintTokens,
+  sendTokens,
+};
+"#;
+
+pub(super) const TOKEN_REACT_COMPONENT: &str = r#"import { useEffect, useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  TokenBalance,
+  watchBalances,
+  formatAmount,
+  parseAmount,
+  deployToken,
+  mintTokens,
+  sendTokens,
+  knownMints,
+  trackMint,
+} from "../services/tokenService";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [balances, setBalances] = useState<TokenBalance[]>([]);
+  const [mints, setMints] = useState<Record<string, string>>(knownMints());
+  const [selected, setSelected] = useState("");
+  const [recipient, setRecipient] = useState("");
+  const [amount, setAmount] = useState("");
+  const [sym, setSym] = useState("");
+  const [max, setMax] = useState("21000000");
+  const [dec, setDec] = useState("0");
+  const [lim, setLim] = useState("1000");
+  const [mintId, setMintId] = useState("");
+  const [mintTxid, setMintTxid] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!wallet.connected) return;
+    return watchBalances(setBalances, (err) => setStatus(err.message));
+  }, [wallet.connected]);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const token = balances.find((b) => b.id === selected);
+
+  const send = () =>
+    run("Sending", async () => {
+      const { txid } = await sendTokens(selected, recipient, parseAmount(amount, token!.dec));
+      setAmount("");
+      setStatus("Sent: " + txid);
+    });
+
+  const deploy = () =>
+    run("Deploying token", async () => {
+      const { tokenId } = await deployToken({
+        sym,
+        max: parseAmount(max, Number(dec)),
+        dec: Number(dec),
+        lim: parseAmount(lim, Number(dec)),
+      });
+      setMints(knownMints());
+      setStatus("Token id: " + tokenId);
+    });
+
+  const track = () => {
+    trackMint(mintId, mintTxid);
+    setMints(knownMints());
+  };
+
+  const mint = (tokenId: string) =>
+    run("Minting", async () => {
+      // Mints the per-call limit; the indexer picks it up on the next poll
+      const { txid } = await mintTokens(tokenId, parseAmount(lim, Number(dec)));
+      setMints(knownMints());
+      setStatus("Minted: " + txid);
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Tokens</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to see your tokens</p>
+        </div>
+      ) : (
+        <div className="space-y-6">
+          <div className="bg-slate-800 rounded-lg p-6 space-y-2">
+            <h3 className="font-semibold">Balances</h3>
+            {balances.length === 0 && <p className="text-slate-400">No BSV-21 tokens yet</p>}
+            {balances.map((b) => (
+              <button
+                key={b.id}
+                onClick={() => setSelected(b.id)}
+                className={"w-full flex justify-between rounded p-2 " + (b.id === selected ? "bg-slate-700" : "bg-slate-900")}
+              >
+                <span>{b.sym}</span>
+                <span>{formatAmount(b.amount, b.dec)}</span>
+              </button>
+            ))}
+          </div>
+
+          {token && (
+            <div className="bg-slate-800 rounded-lg p-6 space-y-2">
+              <h3 className="font-semibold">Send {token.sym}</h3>
+              <input
+                className="w-full rounded bg-slate-900 p-2"
+                placeholder="Recipient ordinals address"
+                value={recipient}
+                onChange={(e) => setRecipient(e.target.value)}
+              />
+              <input
+                className="w-full rounded bg-slate-900 p-2"
+                placeholder={"Amount (max " + formatAmount(token.amount, token.dec) + ")"}
+                value={amount}
+                onChange={(e) => setAmount(e.target.value)}
+              />
+              <button onClick={send} className="btn btn-primary">Send</button>
+            </div>
+          )}
+
+          <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+            <h3 className="font-semibold">Mint covenants</h3>
+            <p className="text-slate-400">Contract: {contractName}</p>
+            {Object.keys(mints).map((tokenId) => (
+              <div key={tokenId} className="flex justify-between items-center gap-2">
+                <span className="text-sm break-all">{tokenId}</span>
+                <button onClick={() => mint(tokenId)} className="btn btn-secondary">Mint {lim}</button>
+              </div>
+            ))}
+            <div className="grid grid-cols-2 gap-2">
+              <input className="rounded bg-slate-900 p-2" placeholder="Symbol" value={sym} onChange={(e) => setSym(e.target.value)} />
+              <input className="rounded bg-slate-900 p-2" placeholder="Max supply" value={max} onChange={(e) => setMax(e.target.value)} />
+              <input className="rounded bg-slate-900 p-2" placeholder="Decimals" value={dec} onChange={(e) => setDec(e.target.value)} />
+              <input className="rounded bg-slate-900 p-2" placeholder="Per-mint limit" value={lim} onChange={(e) => setLim(e.target.value)} />
+            </div>
+            <button onClick={deploy} className="btn btn-primary">Deploy new token</button>
+            <div className="flex gap-2">
+              <input className="flex-1 rounded bg-slate-900 p-2" placeholder="Token id" value={mintId} onChange={(e) => setMintId(e.target.value)} />
+              <input className="flex-1 rounded bg-slate-900 p-2" placeholder="Latest covenant txid" value={mintTxid} onChange={(e) => setMintTxid(e.target.value)} />
+              <button onClick={track} className="btn btn-secondary">Track</button>
+            </div>
+          </div>
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const TOKEN_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { computed, inject, onUnmounted, ref, watch } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  type TokenBalance,
+  watchBalances,
+  formatAmount,
+  parseAmount,
+  deployToken,
+  mintTokens,
+  sendTokens,
+  knownMints,
+  trackMint,
+} from "../services/tokenService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+const balances = ref<TokenBalance[]>([]);
+const mints = ref<Record<string, string>>(knownMints());
+const selected = ref("");
+const recipient = ref("");
+const amount = ref("");
+const sym = ref("");
+const max = ref("21000000");
+const dec = ref("0");
+const lim = ref("1000");
+const mintId = ref("");
+const mintTxid = ref("");
+const status = ref<string | null>(null);
+
+const token = computed(() => balances.value.find((b) => b.id === selected.value));
+
+let stopWatching: (() => void) | null = null;
+watch(
+  () => wallet.value.connected,
+  (connected) => {
+    stopWatching?.();
+    stopWatching = connected
+      ? watchBalances((b) => (balances.value = b), (err) => (status.value = err.message))
+      : null;
+  },
+  { immediate: true },
+);
+onUnmounted(() => stopWatching?.());
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+const send = () =>
+  run("Sending", async () => {
+    const { txid } = await sendTokens(selected.value, recipient.value, parseAmount(amount.value, token.value!.dec));
+    amount.value = "";
+    status.value = "Sent: " + txid;
+  });
+
+const deploy = () =>
+  run("Deploying token", async () => {
+    const { tokenId } = await deployToken({
+      sym: sym.value,
+      max: parseAmount(max.value, Number(dec.value)),
+      dec: Number(dec.value),
+      lim: parseAmount(lim.value, Number(dec.value)),
+    });
+    mints.value = knownMints();
+    status.value = "Token id: " + tokenId;
+  });
+
+function track() {
+  trackMint(mintId.value, mintTxid.value);
+  mints.value = knownMints();
+}
+
+// Mints the per-call limit; the indexer picks it up on the next poll
+const mint = (tokenId: string) =>
+  run("Minting", async () => {
+    const { txid } = await mintTokens(tokenId, parseAmount(lim.value, Number(dec.value)));
+    mints.value = knownMints();
+    status.value = "Minted: " + txid;
+  });
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Tokens</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to see your tokens</p>
+    </div>
+    <div v-else class="space-y-6">
+      <div class="bg-slate-800 rounded-lg p-6 space-y-2">
+        <h3 class="font-semibold">Balances</h3>
+        <p v-if="balances.length === 0" class="text-slate-400">No BSV-21 tokens yet</p>
+        <button
+          v-for="b in balances"
+          :key="b.id"
+          @click="selected = b.id"
+          :class="'w-full flex justify-between rounded p-2 ' + (b.id === selected ? 'bg-slate-700' : 'bg-slate-900')"
+        >
+          <span>{{ b.sym }}</span>
+          <span>{{ formatAmount(b.amount, b.dec) }}</span>
+        </button>
+      </div>
+
+      <div v-if="token" class="bg-slate-800 rounded-lg p-6 space-y-2">
+        <h3 class="font-semibold">Send {{ token.sym }}</h3>
+        <input v-model="recipient" class="w-full rounded bg-slate-900 p-2" placeholder="Recipient ordinals address" />
+        <input
+          v-model="amount"
+          class="w-full rounded bg-slate-900 p-2"
+          :placeholder="'Amount (max ' + formatAmount(token.amount, token.dec) + ')'"
+        />
+        <button @click="send" class="btn btn-primary">Send</button>
+      </div>
+
+      <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+        <h3 class="font-semibold">Mint covenants</h3>
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div v-for="(_, tokenId) in mints" :key="tokenId" class="flex justify-between items-center gap-2">
+          <span class="text-sm break-all">{{ tokenId }}</span>
+          <button @click="mint(String(tokenId))" class="btn btn-secondary">Mint {{ lim }}</button>
+        </div>
+        <div class="grid grid-cols-2 gap-2">
+          <input v-model="sym" class="rounded bg-slate-900 p-2" placeholder="Symbol" />
+          <input v-model="max" class="rounded bg-slate-900 p-2" placeholder="Max supply" />
+          <input v-model="dec" class="rounded bg-slate-900 p-2" placeholder="Decimals" />
+          <input v-model="lim" class="rounded bg-slate-900 p-2" placeholder="Per-mint limit" />
+        </div>
+        <button @click="deploy" class="btn btn-primary">Deploy new token</button>
+        <div class="flex gap-2">
+          <input v-model="mintId" class="flex-1 rounded bg-slate-900 p-2" placeholder="Token id" />
+          <input v-model="mintTxid" class="flex-1 rounded bg-slate-900 p-2" placeholder="Latest covenant txid" />
+          <button @click="track" class="btn btn-secondary">Track</button>
+        </div>
+      </div>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const TOKEN_ANGULAR_COMPONENT: &str = r#"import { Component, Input, OnDestroy, effect, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  TokenBalance,
+  watchBalances,
+  formatAmount,
+  parseAmount,
+  deployToken,
+  mintTokens,
+  sendTokens,
+  knownMints,
+  trackMint,
+} from "../services/tokenService";
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Tokens</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to see your tokens</p>
+      </div>
+
+      <div *ngIf="walletService.wallet().connected" class="space-y-6">
+        <div class="bg-slate-800 rounded-lg p-6 space-y-2">
+          <h3 class="font-semibold">Balances</h3>
+          <p *ngIf="balances().length === 0" class="text-slate-400">No BSV-21 tokens yet</p>
+          <button
+            *ngFor="let b of balances()"
+            (click)="selected = b.id"
+            [class]="'w-full flex justify-between rounded p-2 ' + (b.id === selected ? 'bg-slate-700' : 'bg-slate-900')"
+          >
+            <span>{{ b.sym }}</span>
+            <span>{{ format(b) }}</span>
+          </button>
+        </div>
+
+        <div *ngIf="token() as t" class="bg-slate-800 rounded-lg p-6 space-y-2">
+          <h3 class="font-semibold">Send {{ t.sym }}</h3>
+          <input [(ngModel)]="recipient" class="w-full rounded bg-slate-900 p-2" placeholder="Recipient ordinals address" />
+          <input [(ngModel)]="amount" class="w-full rounded bg-slate-900 p-2" [placeholder]="'Amount (max ' + format(t) + ')'" />
+          <button (click)="send()" class="btn btn-primary">Send</button>
+        </div>
+
+        <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+          <h3 class="font-semibold">Mint covenants</h3>
+          <p class="text-slate-400">Contract: {{ contractName }}</p>
+          <div *ngFor="let tokenId of mintIds()" class="flex justify-between items-center gap-2">
+            <span class="text-sm break-all">{{ tokenId }}</span>
+            <button (click)="mint(tokenId)" class="btn btn-secondary">Mint {{ lim }}</button>
+          </div>
+          <div class="grid grid-cols-2 gap-2">
+            <input [(ngModel)]="sym" class="rounded bg-slate-900 p-2" placeholder="Symbol" />
+            <input [(ngModel)]="max" class="rounded bg-slate-900 p-2" placeholder="Max supply" />
+            <input [(ngModel)]="dec" class="rounded bg-slate-900 p-2" placeholder="Decimals" />
+            <input [(ngModel)]="lim" class="rounded bg-slate-900 p-2" placeholder="Per-mint limit" />
+          </div>
+          <button (click)="deploy()" class="btn btn-primary">Deploy new token</button>
+          <div class="flex gap-2">
+            <input [(ngModel)]="mintId" class="flex-1 rounded bg-slate-900 p-2" placeholder="Token id" />
+            <input [(ngModel)]="mintTxid" class="flex-1 rounded bg-slate-900 p-2" placeholder="Latest covenant txid" />
+            <button (click)="track()" class="btn btn-secondary">Track</button>
+          </div>
+        </div>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent implements OnDestroy {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  balances = signal<TokenBalance[]>([]);
+  mintIds = signal<string[]>(Object.keys(knownMints()));
+  status = signal<string | null>(null);
+
+  selected = "";
+  recipient = "";
+  amount = "";
+  sym = "";
+  max = "21000000";
+  dec = "0";
+  lim = "1000";
+  mintId = "";
+  mintTxid = "";
+
+  private stopWatching: (() => void) | null = null;
+
+  constructor() {
+    effect(() => {
+      const connected = this.walletService.wallet().connected;
+      this.stopWatching?.();
+      this.stopWatching = connected
+        ? watchBalances((b) => this.balances.set(b), (err) => this.status.set(err.message))
+        : null;
+    });
+  }
+
+  ngOnDestroy() {
+    this.stopWatching?.();
+  }
+
+  token() {
+    return this.balances().find((b) => b.id === this.selected);
+  }
+
+  format(balance: TokenBalance) {
+    return formatAmount(balance.amount, balance.dec);
+  }
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  send() {
+    return this.run("Sending", async () => {
+      const { txid } = await sendTokens(this.selected, this.recipient, parseAmount(this.amount, this.token()!.dec));
+      this.amount = "";
+      this.status.set("Sent: " + txid);
+    });
+  }
+
+  deploy() {
+    return this.run("Deploying token", async () => {
+      const dec = Number(this.dec);
+      const { tokenId } = await deployToken({
+        sym: this.sym,
+        max: parseAmount(this.max, dec),
+        dec,
+        lim: parseAmount(this.lim, dec),
+      });
+      this.mintIds.set(Object.keys(knownMints()));
+      this.status.set("Token id: " + tokenId);
+    });
+  }
+
+  track() {
+    trackMint(this.mintId, this.mintTxid);
+    this.mintIds.set(Object.keys(knownMints()));
+  }
+
+  // Mints the per-call limit; the indexer picks it up on the next poll
+  mint(tokenId: string) {
+    return this.run("Minting", async () => {
+      const { txid } = await mintTokens(tokenId, parseAmount(this.lim, Number(this.dec)));
+      this.status.set("Minted: " + txid);
+    });
+  }
+}
+"#;
+
+pub(super) const TOKEN_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { onDestroy } from "svelte";
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    type TokenBalance,
+    watchBalances,
+    formatAmount,
+    parseAmount,
+    deployToken,
+    mintTokens,
+    sendTokens,
+    knownMints,
+    trackMint,
+  } from "../services/tokenService";
+
+  export let contractName: string;
+
+  let balances: TokenBalance[] = [];
+  let mints: Record<string, string> = knownMints();
+  let selected = "";
+  let recipient = "";
+  let amount = "";
+  let sym = "";
+  let max = "21000000";
+  let dec = "0";
+  let lim = "1000";
+  let mintId = "";
+  let mintTxid = "";
+  let status: string | null = null;
+
+  $: token = balances.find((b) => b.id === selected);
+
+  let stopWatching: (() => void) | null = null;
+  $: {
+    stopWatching?.();
+    stopWatching = $wallet.connected
+      ? watchBalances((b) => (balances = b), (err) => (status = err.message))
+      : null;
+  }
+  onDestroy(() => stopWatching?.());
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  const send = () =>
+    run("Sending", async () => {
+      const result = await sendTokens(selected, recipient, parseAmount(amount, token!.dec));
+      amount = "";
+      status = "Sent: " + result.txid;
+    });
+
+  const deploy = () =>
+    run("Deploying token", async () => {
+      const { tokenId } = await deployToken({
+        sym,
+        max: parseAmount(max, Number(dec)),
+        dec: Number(dec),
+        lim: parseAmount(lim, Number(dec)),
+      });
+      mints = knownMints();
+      status = "Token id: " + tokenId;
+    });
+
+  function track() {
+    trackMint(mintId, mintTxid);
+    mints = knownMints();
+  }
+
+  // Mints the per-call limit; the indexer picks it up on the next poll
+  const mint = (tokenId: string) =>
+    run("Minting", async () => {
+      const result = await mintTokens(tokenId, parseAmount(lim, Number(dec)));
+      mints = knownMints();
+      status = "Minted: " + result.txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Tokens</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to see your tokens</p>
+    </div>
+  {:else}
+    <div class="space-y-6">
+      <div class="bg-slate-800 rounded-lg p-6 space-y-2">
+        <h3 class="font-semibold">Balances</h3>
+        {#if balances.length === 0}
+          <p class="text-slate-400">No BSV-21 tokens yet</p>
+        {/if}
+        {#each balances as b (b.id)}
+          <button
+            on:click={() => (selected = b.id)}
+            class={"w-full flex justify-between rounded p-2 " + (b.id === selected ? "bg-slate-700" : "bg-slate-900")}
+          >
+            <span>{b.sym}</span>
+            <span>{formatAmount(b.amount, b.dec)}</span>
+          </button>
+        {/each}
+      </div>
+
+      {#if token}
+        <div class="bg-slate-800 rounded-lg p-6 space-y-2">
+          <h3 class="font-semibold">Send {token.sym}</h3>
+          <input bind:value={recipient} class="w-full rounded bg-slate-900 p-2" placeholder="Recipient ordinals address" />
+          <input
+            bind:value={amount}
+            class="w-full rounded bg-slate-900 p-2"
+            placeholder={"Amount (max " + formatAmount(token.amount, token.dec) + ")"}
+          />
+          <button on:click={send} class="btn btn-primary">Send</button>
+        </div>
+      {/if}
+
+      <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+        <h3 class="font-semibold">Mint covenants</h3>
+        <p class="text-slate-400">Contract: {contractName}</p>
+        {#each Object.keys(mints) as tokenId (tokenId)}
+          <div class="flex justify-between items-center gap-2">
+            <span class="text-sm break-all">{tokenId}</span>
+            <button on:click={() => mint(tokenId)} class="btn btn-secondary">Mint {lim}</button>
+          </div>
+        {/each}
+        <div class="grid grid-cols-2 gap-2">
+          <input bind:value={sym} class="rounded bg-slate-900 p-2" placeholder="Symbol" />
+          <input bind:value={max} class="rounded bg-slate-900 p-2" placeholder="Max supply" />
+          <input bind:value={dec} class="rounded bg-slate-900 p-2" placeholder="Decimals" />
+          <input bind:value={lim} class="rounded bg-slate-900 p-2" placeholder="Per-mint limit" />
+        </div>
+        <button on:click={deploy} class="btn btn-primary">Deploy new token</button>
+        <div class="flex gap-2">
+          <input bind:value={mintId} class="flex-1 rounded bg-slate-900 p-2" placeholder="Token id" />
+          <input bind:value={mintTxid} class="flex-1 rounded bg-slate-900 p-2" placeholder="Latest covenant txid" />
+          <button on:click={track} class="btn btn-secondary">Track</button>
+        </div>
+      </div>
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
@@ -0,0 +1,1105 @@
+//! Treasury (M-of-N multisig with proposal/approval flow): the template's service and its main
+//! component for each framework.
+
+pub(super) const TREASURY_SERVICE: &str = r#"/**
+ * Treasury Service - proposal/approval flow for the multisig treasury
+ *
+ * Co-signers never share keys. Instead a spend travels as a proposal:
+ * 1. The proposer builds the spend tx (fee input + change fixed up front)
+ *    and shares it as a proposal code
+ * 2. Each co-signer imports the code, signs input 0 with their own wallet,
+ *    and passes the updated code on (or back to the proposer)
+ * 3. Once the spend tier's approval count is reached, the proposer executes
+ *    the proposal, which signs the fee input and broadcasts
+ *
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  bsv,
+  PubKey,
+  PubKeyHash,
+  Sig,
+  SigHash,
+  FixedArray,
+  ContractTransaction,
+  MethodCallOptions,
+  toByteString,
+} from "scrypt-ts";
+import Treasury, { SIGNER_COUNT } from "__CONTRACTS_DIR__/Treasury.scrypt";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config as appConfig } from "./config";
+
+const ARTIFACT_PATH = "/artifacts/contracts/Treasury.scrypt.json";
+const PROPOSALS_KEY = "treasury_proposals";
+const FEE_PER_KB = appConfig.feePerKb;
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const res = await fetch(ARTIFACT_PATH);
+  if (!res.ok) {
+    throw new Error(
+      "Contract artifact missing at " + ARTIFACT_PATH + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+  await Treasury.loadArtifact(await res.json());
+  artifactLoaded = true;
+}
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+export interface TreasuryConfig {
+  signers: string[];
+  threshold: number;
+  fastTrackLimit: number;
+  highValueLimit: number;
+}
+
+export interface TreasuryProposal {
+  id: string;
+  treasuryTxid: string;
+  treasuryOutputIndex: number;
+  recipient: string;
+  amount: number;
+  requiredApprovals: number;
+  feeUtxo: { txid: string; vout: number; script: string; satoshis: number };
+  changeAddress: string;
+  changeAmount: number;
+  // co-signer pubkey hex -> signature hex
+  signatures: Record<string, string>;
+}
+
+export function treasuryConfig(instance: Treasury): TreasuryConfig {
+  return {
+    signers: instance.signers.map((pubKey) => pubKey.toString()),
+    threshold: Number(instance.threshold),
+    fastTrackLimit: Number(instance.fastTrackLimit),
+    highValueLimit: Number(instance.highValueLimit),
+  };
+}
+
+/**
+ * Mirrors Treasury.requiredApprovals() so the UI can show the tier up front
+ */
+export function requiredApprovals(amount: number, config: TreasuryConfig): number {
+  if (amount <= config.fastTrackLimit) return 1;
+  if (amount > config.highValueLimit) return config.signers.length;
+  return config.threshold;
+}
+
+export function approvalCount(proposal: TreasuryProposal): number {
+  return Object.keys(proposal.signatures).length;
+}
+
+export function isReady(proposal: TreasuryProposal): boolean {
+  return approvalCount(proposal) >= proposal.requiredApprovals;
+}
+
+// ============================================================================
+// DEPLOYMENT / RESTORATION
+// ============================================================================
+
+export async function deployTreasury(
+  config: TreasuryConfig,
+  fundingSats: number,
+): Promise<{ txid: string; instance: Treasury }> {
+  if (config.signers.length !== SIGNER_COUNT) {
+    throw new Error("Treasury requires exactly " + SIGNER_COUNT + " co-signer public keys");
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const instance = new Treasury(
+    config.signers.map((hex) => PubKey(hex)) as FixedArray<PubKey, typeof SIGNER_COUNT>,
+    BigInt(config.threshold),
+    BigInt(config.fastTrackLimit),
+    BigInt(config.highValueLimit),
+  );
+  await instance.connect(signer);
+
+  const tx = await instance.deploy(fundingSats);
+  return { txid: tx.id, instance };
+}
+
+export async function restoreTreasury(txid: string, outputIndex: number = 0): Promise<Treasury> {
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  const tx = await signer.provider!.getTransaction(txid);
+  const instance = Treasury.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  return instance;
+}
+
+// ============================================================================
+// PROPOSAL TRANSACTION
+//
+// The same proposal must always produce the same transaction, otherwise
+// signatures collected earlier become invalid. Fee and change are therefore
+// stored in the proposal instead of being estimated on every build.
+// ============================================================================
+
+function addressToPkh(address: string): string {
+  return bsv.Address.fromString(address).hashBuffer.toString("hex");
+}
+
+function buildProposalTx(current: Treasury, proposal: TreasuryProposal): bsv.Transaction {
+  const tx = new bsv.Transaction();
+  tx.addInput(current.buildContractInput());
+  tx.from({
+    txId: proposal.feeUtxo.txid,
+    outputIndex: proposal.feeUtxo.vout,
+    script: proposal.feeUtxo.script,
+    satoshis: proposal.feeUtxo.satoshis,
+  });
+
+  tx.addOutput(new bsv.Transaction.Output({
+    script: bsv.Script.buildPublicKeyHashOut(proposal.recipient),
+    satoshis: proposal.amount,
+  }));
+
+  const remaining = current.balance - proposal.amount;
+  if (remaining > 0) {
+    const next = current.next();
+    next.executed = current.executed + 1n;
+    tx.addOutput(new bsv.Transaction.Output({
+      script: next.lockingScript,
+      satoshis: remaining,
+    }));
+  }
+
+  tx.addOutput(new bsv.Transaction.Output({
+    script: bsv.Script.buildPublicKeyHashOut(proposal.changeAddress),
+    satoshis: proposal.changeAmount,
+  }));
+
+  return tx;
+}
+
+export async function createProposal(
+  instance: Treasury,
+  recipient: string,
+  amount: number,
+): Promise<TreasuryProposal> {
+  if (amount <= 0 || amount > instance.balance) {
+    throw new Error("Amount must be between 1 and " + instance.balance + " sats");
+  }
+
+  const signer = await getYoursDirectSigner();
+  const changeAddress = (await signer.getDefaultAddress()).toString();
+
+  // Unlocking script carries the sighash preimage (~ locking script size)
+  // plus up to SIGNER_COUNT signatures
+  const scriptBytes = instance.lockingScript.toBuffer().length;
+  const estimatedBytes = scriptBytes * 3 + SIGNER_COUNT * 73 + 500;
+  const fee = Math.max(Math.ceil((estimatedBytes * FEE_PER_KB) / 1000), 100);
+
+  const feeUtxos = await getWalletUtxos();
+  const feeUtxo = feeUtxos.find((u) => u.satoshis >= fee + 1);
+  if (!feeUtxo) {
+    throw new Error("No wallet UTXO large enough to cover the " + fee + " sat fee");
+  }
+
+  const utxo = instance.utxo;
+  return {
+    id: utxo.txId + ":" + utxo.outputIndex + ":" + Date.now().toString(36),
+    treasuryTxid: utxo.txId,
+    treasuryOutputIndex: utxo.outputIndex,
+    recipient,
+    amount,
+    requiredApprovals: requiredApprovals(amount, treasuryConfig(instance)),
+    feeUtxo,
+    changeAddress,
+    changeAmount: feeUtxo.satoshis - fee,
+    signatures: {},
+  };
+}
+
+// ============================================================================
+// PARTIAL SIGNATURES
+// ============================================================================
+
+/**
+ * Sign input 0 of the proposal with the connected wallet and record the
+ * signature under the co-signer's public key
+ */
+export async function approveProposal(
+  instance: Treasury,
+  proposal: TreasuryProposal,
+): Promise<TreasuryProposal> {
+  const signer = await getYoursDirectSigner();
+  const pubKey = (await signer.getDefaultPubKey()).toHex();
+  if (!treasuryConfig(instance).signers.includes(pubKey)) {
+    throw new Error("Connected wallet is not a co-signer of this treasury");
+  }
+
+  const tx = buildProposalTx(instance, proposal);
+  const [sigResponse] = await signer.getSignatures(tx.toString(), [
+    {
+      prevTxId: proposal.treasuryTxid,
+      outputIndex: proposal.treasuryOutputIndex,
+      inputIndex: 0,
+      satoshis: instance.balance,
+      address: await signer.getDefaultAddress(),
+      scriptHex: instance.lockingScript.toHex(),
+      sigHashType: SigHash.ALL,
+    },
+  ]);
+
+  return {
+    ...proposal,
+    signatures: { ...proposal.signatures, [pubKey]: sigResponse.sig },
+  };
+}
+
+/**
+ * Combine signatures from two copies of the same proposal, so co-signers can
+ * approve in parallel and send their codes back independently
+ */
+export function mergeProposals(a: TreasuryProposal, b: TreasuryProposal): TreasuryProposal {
+  if (a.id !== b.id) {
+    throw new Error("Cannot merge different proposals");
+  }
+  return { ...a, signatures: { ...a.signatures, ...b.signatures } };
+}
+
+export function encodeProposal(proposal: TreasuryProposal): string {
+  return btoa(JSON.stringify(proposal));
+}
+
+export function decodeProposal(code: string): TreasuryProposal {
+  let parsed: any;
+  try {
+    parsed = JSON.parse(atob(code.trim()));
+  } catch {
+    throw new Error("Invalid proposal code");
+  }
+  if (!parsed.id || !parsed.treasuryTxid || typeof parsed.signatures !== "object") {
+    throw new Error("Invalid proposal code");
+  }
+  return parsed as TreasuryProposal;
+}
+
+export function loadProposals(): TreasuryProposal[] {
+  const raw = localStorage.getItem(PROPOSALS_KEY);
+  if (!raw) return [];
+  try {
+    return JSON.parse(raw);
+  } catch {
+    return [];
+  }
+}
+
+export function saveProposal(proposal: TreasuryProposal): TreasuryProposal[] {
+  const proposals = loadProposals();
+  const index = proposals.findIndex((p) => p.id === proposal.id);
+  if (index >= 0) {
+    proposals[index] = mergeProposals(proposals[index], proposal);
+  } else {
+    proposals.push(proposal);
+  }
+  localStorage.setItem(PROPOSALS_KEY, JSON.stringify(proposals));
+  return proposals;
+}
+
+export function removeProposal(id: string): TreasuryProposal[] {
+  const proposals = loadProposals().filter((p) => p.id !== id);
+  localStorage.setItem(PROPOSALS_KEY, JSON.stringify(proposals));
+  return proposals;
+}
+
+// ============================================================================
+// EXECUTION
+// ============================================================================
+
+export async function executeProposal(
+  instance: Treasury,
+  proposal: TreasuryProposal,
+): Promise<{ txid: string }> {
+  if (!isReady(proposal)) {
+    throw new Error(
+      "Proposal needs " + proposal.requiredApprovals + " approvals, has " + approvalCount(proposal),
+    );
+  }
+  await loadArtifact();
+
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+
+  const { signers } = treasuryConfig(instance);
+  const approved = signers.map((pubKey) => pubKey in proposal.signatures);
+  const sigs = signers.map((pubKey) => Sig(proposal.signatures[pubKey] ?? toByteString("")));
+
+  // CRITICAL: rebuild the exact transaction the co-signers approved
+  instance.bindTxBuilder(
+    "spend",
+    async (current: Treasury): Promise<ContractTransaction> => {
+      const tx = buildProposalTx(current, proposal);
+      const nexts = tx.outputs.length > 2
+        ? [{ instance: current.next(), atOutputIndex: 1, balance: tx.outputs[1].satoshis }]
+        : [];
+      return { tx, atInputIndex: 0, nexts };
+    },
+  );
+
+  const { tx } = await instance.methods.spend(
+    PubKeyHash(addressToPkh(proposal.recipient)),
+    BigInt(proposal.amount),
+    PubKeyHash(addressToPkh(proposal.changeAddress)),
+    BigInt(proposal.changeAmount),
+    sigs as FixedArray<Sig, typeof SIGNER_COUNT>,
+    approved as FixedArray<boolean, typeof SIGNER_COUNT>,
+    {} as MethodCallOptions<Treasury>,
+  );
+
+  removeProposal(proposal.id);
+  return { txid: tx.id };
+}
+
+export default {
+  deployTreasury,
+  restoreTreasury,
+  createProposal,
+  approveProposal,
+  executeProposal,
+  encodeProposal,
+  decodeProposal,
+};
+"#;
+
+pub(super) const TREASURY_REACT_COMPONENT: &str = r#"import { useState } from "react";
+import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+import {
+  TreasuryProposal,
+  restoreTreasury,
+  deployTreasury,
+  treasuryConfig,
+  createProposal,
+  approveProposal,
+  executeProposal,
+  encodeProposal,
+  decodeProposal,
+  loadProposals,
+  saveProposal,
+  approvalCount,
+  isReady,
+} from "../services/treasuryService";
+
+interface GameProps {
+  contractName: string;
+}
+
+// Default spending tiers (sats) used when deploying a new treasury
+const DEFAULT_TIERS = { threshold: 2, fastTrackLimit: 10_000, highValueLimit: 1_000_000 };
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+  const [treasury, setTreasury] = useState<any>(null);
+  const [treasuryTxid, setTreasuryTxid] = useState("");
+  const [signers, setSigners] = useState(["", "", ""]);
+  const [funding, setFunding] = useState("100000");
+  const [recipient, setRecipient] = useState("");
+  const [amount, setAmount] = useState("");
+  const [importCode, setImportCode] = useState("");
+  const [proposals, setProposals] = useState<TreasuryProposal[]>(loadProposals());
+  const [status, setStatus] = useState<string | null>(null);
+
+  const run = async (label: string, action: () => Promise<void>) => {
+    setStatus(label + "...");
+    try {
+      await action();
+      setStatus(null);
+    } catch (err: any) {
+      setStatus(err?.message || String(err));
+    }
+  };
+
+  const deploy = () =>
+    run("Deploying treasury", async () => {
+      const { txid, instance } = await deployTreasury(
+        { signers, ...DEFAULT_TIERS },
+        Number(funding),
+      );
+      setTreasuryTxid(txid);
+      setTreasury(instance);
+    });
+
+  const restore = () =>
+    run("Loading treasury", async () => {
+      setTreasury(await restoreTreasury(treasuryTxid));
+    });
+
+  const propose = () =>
+    run("Creating proposal", async () => {
+      const proposal = await createProposal(treasury, recipient, Number(amount));
+      setProposals(saveProposal(proposal));
+      setRecipient("");
+      setAmount("");
+    });
+
+  const importProposal = () =>
+    run("Importing proposal", async () => {
+      setProposals(saveProposal(decodeProposal(importCode)));
+      setImportCode("");
+    });
+
+  const approve = (proposal: TreasuryProposal) =>
+    run("Waiting for wallet signature", async () => {
+      setProposals(saveProposal(await approveProposal(treasury, proposal)));
+    });
+
+  const execute = (proposal: TreasuryProposal) =>
+    run("Broadcasting", async () => {
+      const { txid } = await executeProposal(treasury, proposal);
+      setProposals(loadProposals());
+      setStatus("Executed: " + txid);
+    });
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Treasury</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to manage the treasury</p>
+        </div>
+      ) : !treasury ? (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-4">
+          <p className="text-slate-400">Contract: {contractName}</p>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Deploy a new treasury</h3>
+            {signers.map((value, i) => (
+              <input
+                key={i}
+                className="w-full rounded bg-slate-900 p-2"
+                placeholder={"Co-signer " + (i + 1) + " public key"}
+                value={value}
+                onChange={(e) => setSigners(signers.map((s, j) => (j === i ? e.target.value : s)))}
+              />
+            ))}
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Funding (sats)"
+              value={funding}
+              onChange={(e) => setFunding(e.target.value)}
+            />
+            <button onClick={deploy} className="btn btn-primary">Deploy</button>
+          </div>
+          <div className="space-y-2">
+            <h3 className="font-semibold">Or load an existing one</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Treasury txid"
+              value={treasuryTxid}
+              onChange={(e) => setTreasuryTxid(e.target.value)}
+            />
+            <button onClick={restore} className="btn btn-secondary">Load</button>
+          </div>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6 space-y-6">
+          <p className="text-slate-400">
+            Balance: {treasury.balance} sats · {treasuryConfig(treasury).threshold}-of-
+            {treasuryConfig(treasury).signers.length} (≤{treasuryConfig(treasury).fastTrackLimit} sats: 1
+            approval, &gt;{treasuryConfig(treasury).highValueLimit} sats: all)
+          </p>
+
+          <div className="space-y-2">
+            <h3 className="font-semibold">New proposal</h3>
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Recipient address"
+              value={recipient}
+              onChange={(e) => setRecipient(e.target.value)}
+            />
+            <input
+              className="w-full rounded bg-slate-900 p-2"
+              placeholder="Amount (sats)"
+              value={amount}
+              onChange={(e) => setAmount(e.target.value)}
+            />
+            <button onClick={propose} className="btn btn-primary">Propose</button>
+          </div>
+
+          <div className="space-y-2">
+            <h3 className="font-semibold">Import a proposal code</h3>
+            <textarea
+              className="w-full rounded bg-slate-900 p-2"
+              value={importCode}
+              onChange={(e) => setImportCode(e.target.value)}
+            />
+            <button onClick={importProposal} className="btn btn-secondary">Import</button>
+          </div>
+
+          <ul className="space-y-2">
+            {proposals.map((proposal) => (
+              <li key={proposal.id} className="rounded bg-slate-900 p-3 space-y-2">
+                <p>
+                  {proposal.amount} sats → {proposal.recipient}
+                </p>
+                <p className="text-sm text-slate-400">
+                  Approvals: {approvalCount(proposal)}/{proposal.requiredApprovals}
+                </p>
+                <div className="flex gap-2">
+                  <button onClick={() => approve(proposal)} className="btn btn-secondary">Approve</button>
+                  <button
+                    onClick={() => navigator.clipboard.writeText(encodeProposal(proposal))}
+                    className="btn btn-secondary"
+                  >
+                    Copy code
+                  </button>
+                  {isReady(proposal) && (
+                    <button onClick={() => execute(proposal)} className="btn btn-primary">Execute</button>
+                  )}
+                </div>
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+
+      {status && <p className="text-sm text-slate-400">{status}</p>}
+    </div>
+  );
+}
+"#;
+
+pub(super) const TREASURY_VUE_COMPONENT: &str = r#"<script setup lang="ts">
+import { inject, ref, shallowRef, toRaw } from "vue";
+import WalletButton from "./WalletButton.vue";
+import {
+  TreasuryProposal,
+  restoreTreasury,
+  deployTreasury,
+  treasuryConfig,
+  createProposal,
+  approveProposal,
+  executeProposal,
+  encodeProposal,
+  decodeProposal,
+  loadProposals,
+  saveProposal,
+  approvalCount,
+  isReady,
+} from "../services/treasuryService";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+
+// Default spending tiers (sats) used when deploying a new treasury
+const DEFAULT_TIERS = { threshold: 2, fastTrackLimit: 10_000, highValueLimit: 1_000_000 };
+
+// shallowRef keeps the contract instance out of Vue's deep reactivity
+const treasury = shallowRef<any>(null);
+const treasuryTxid = ref("");
+const signers = ref(["", "", ""]);
+const funding = ref("100000");
+const recipient = ref("");
+const amount = ref("");
+const importCode = ref("");
+const proposals = ref<TreasuryProposal[]>(loadProposals());
+const status = ref<string | null>(null);
+
+async function run(label: string, action: () => Promise<void>) {
+  status.value = label + "...";
+  try {
+    await action();
+    status.value = null;
+  } catch (err: any) {
+    status.value = err?.message || String(err);
+  }
+}
+
+const deploy = () =>
+  run("Deploying treasury", async () => {
+    const { txid, instance } = await deployTreasury(
+      { signers: [...signers.value], ...DEFAULT_TIERS },
+      Number(funding.value),
+    );
+    treasuryTxid.value = txid;
+    treasury.value = instance;
+  });
+
+const restore = () =>
+  run("Loading treasury", async () => {
+    treasury.value = await restoreTreasury(treasuryTxid.value);
+  });
+
+const propose = () =>
+  run("Creating proposal", async () => {
+    const proposal = await createProposal(toRaw(treasury.value), recipient.value, Number(amount.value));
+    proposals.value = saveProposal(proposal);
+    recipient.value = "";
+    amount.value = "";
+  });
+
+const importProposal = () =>
+  run("Importing proposal", async () => {
+    proposals.value = saveProposal(decodeProposal(importCode.value));
+    importCode.value = "";
+  });
+
+const approve = (proposal: TreasuryProposal) =>
+  run("Waiting for wallet signature", async () => {
+    proposals.value = saveProposal(await approveProposal(toRaw(treasury.value), toRaw(proposal)));
+  });
+
+const execute = (proposal: TreasuryProposal) =>
+  run("Broadcasting", async () => {
+    const { txid } = await executeProposal(toRaw(treasury.value), toRaw(proposal));
+    proposals.value = loadProposals();
+    status.value = "Executed: " + txid;
+  });
+
+const copyCode = (proposal: TreasuryProposal) =>
+  navigator.clipboard.writeText(encodeProposal(toRaw(proposal)));
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Treasury</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to manage the treasury</p>
+    </div>
+    <div v-else-if="!treasury" class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {{ contractName }}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Deploy a new treasury</h3>
+        <input
+          v-for="(_, i) in signers"
+          :key="i"
+          v-model="signers[i]"
+          class="w-full rounded bg-slate-900 p-2"
+          :placeholder="'Co-signer ' + (i + 1) + ' public key'"
+        />
+        <input v-model="funding" class="w-full rounded bg-slate-900 p-2" placeholder="Funding (sats)" />
+        <button @click="deploy" class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Or load an existing one</h3>
+        <input v-model="treasuryTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Treasury txid" />
+        <button @click="restore" class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6 space-y-6">
+      <p class="text-slate-400">
+        Balance: {{ treasury.balance }} sats ·
+        {{ treasuryConfig(treasury).threshold }}-of-{{ treasuryConfig(treasury).signers.length }}
+        (≤{{ treasuryConfig(treasury).fastTrackLimit }} sats: 1 approval,
+        &gt;{{ treasuryConfig(treasury).highValueLimit }} sats: all)
+      </p>
+
+      <div class="space-y-2">
+        <h3 class="font-semibold">New proposal</h3>
+        <input v-model="recipient" class="w-full rounded bg-slate-900 p-2" placeholder="Recipient address" />
+        <input v-model="amount" class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+        <button @click="propose" class="btn btn-primary">Propose</button>
+      </div>
+
+      <div class="space-y-2">
+        <h3 class="font-semibold">Import a proposal code</h3>
+        <textarea v-model="importCode" class="w-full rounded bg-slate-900 p-2"></textarea>
+        <button @click="importProposal" class="btn btn-secondary">Import</button>
+      </div>
+
+      <ul class="space-y-2">
+        <li v-for="proposal in proposals" :key="proposal.id" class="rounded bg-slate-900 p-3 space-y-2">
+          <p>{{ proposal.amount }} sats → {{ proposal.recipient }}</p>
+          <p class="text-sm text-slate-400">
+            Approvals: {{ approvalCount(proposal) }}/{{ proposal.requiredApprovals }}
+          </p>
+          <div class="flex gap-2">
+            <button @click="approve(proposal)" class="btn btn-secondary">Approve</button>
+            <button @click="copyCode(proposal)" class="btn btn-secondary">Copy code</button>
+            <button v-if="isReady(proposal)" @click="execute(proposal)" class="btn btn-primary">
+              Execute
+            </button>
+          </div>
+        </li>
+      </ul>
+    </div>
+
+    <p v-if="status" class="text-sm text-slate-400">{{ status }}</p>
+  </div>
+</template>
+"#;
+
+pub(super) const TREASURY_ANGULAR_COMPONENT: &str = r#"import { Component, Input, inject, signal } from "@angular/core";
+import { CommonModule } from "@angular/common";
+import { FormsModule } from "@angular/forms";
+import { WalletService } from "../services/wallet.service";
+import { WalletButtonComponent } from "./wallet-button.component";
+import {
+  TreasuryProposal,
+  restoreTreasury,
+  deployTreasury,
+  treasuryConfig,
+  createProposal,
+  approveProposal,
+  executeProposal,
+  encodeProposal,
+  decodeProposal,
+  loadProposals,
+  saveProposal,
+  approvalCount,
+  isReady,
+} from "../services/treasuryService";
+
+// Default spending tiers (sats) used when deploying a new treasury
+const DEFAULT_TIERS = { threshold: 2, fastTrackLimit: 10_000, highValueLimit: 1_000_000 };
+
+@Component({
+  selector: "app-game",
+  standalone: true,
+  imports: [CommonModule, FormsModule, WalletButtonComponent],
+  template: `
+    <div class="space-y-8">
+      <div class="flex justify-between items-center">
+        <h2 class="text-xl font-semibold">Treasury</h2>
+        <app-wallet-button></app-wallet-button>
+      </div>
+
+      <div
+        *ngIf="!walletService.wallet().connected"
+        class="text-center py-12 bg-slate-800 rounded-lg"
+      >
+        <p class="text-slate-400 mb-4">Connect your wallet to manage the treasury</p>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && !treasury()"
+        class="bg-slate-800 rounded-lg p-6 space-y-4"
+      >
+        <p class="text-slate-400">Contract: {{ contractName }}</p>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Deploy a new treasury</h3>
+          <input
+            *ngFor="let _ of signers; let i = index; trackBy: trackIndex"
+            [(ngModel)]="signers[i]"
+            class="w-full rounded bg-slate-900 p-2"
+            [placeholder]="'Co-signer ' + (i + 1) + ' public key'"
+          />
+          <input [(ngModel)]="funding" class="w-full rounded bg-slate-900 p-2" placeholder="Funding (sats)" />
+          <button (click)="deploy()" class="btn btn-primary">Deploy</button>
+        </div>
+        <div class="space-y-2">
+          <h3 class="font-semibold">Or load an existing one</h3>
+          <input [(ngModel)]="treasuryTxid" class="w-full rounded bg-slate-900 p-2" placeholder="Treasury txid" />
+          <button (click)="restore()" class="btn btn-secondary">Load</button>
+        </div>
+      </div>
+
+      <div
+        *ngIf="walletService.wallet().connected && treasury()"
+        class="bg-slate-800 rounded-lg p-6 space-y-6"
+      >
+        <p class="text-slate-400">
+          Balance: {{ treasury().balance }} sats ·
+          {{ config().threshold }}-of-{{ config().signers.length }}
+          (≤{{ config().fastTrackLimit }} sats: 1 approval,
+          &gt;{{ config().highValueLimit }} sats: all)
+        </p>
+
+        <div class="space-y-2">
+          <h3 class="font-semibold">New proposal</h3>
+          <input [(ngModel)]="recipient" class="w-full rounded bg-slate-900 p-2" placeholder="Recipient address" />
+          <input [(ngModel)]="amount" class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+          <button (click)="propose()" class="btn btn-primary">Propose</button>
+        </div>
+
+        <div class="space-y-2">
+          <h3 class="font-semibold">Import a proposal code</h3>
+          <textarea [(ngModel)]="importCode" class="w-full rounded bg-slate-900 p-2"></textarea>
+          <button (click)="importProposal()" class="btn btn-secondary">Import</button>
+        </div>
+
+        <ul class="space-y-2">
+          <li *ngFor="let proposal of proposals()" class="rounded bg-slate-900 p-3 space-y-2">
+            <p>{{ proposal.amount }} sats → {{ proposal.recipient }}</p>
+            <p class="text-sm text-slate-400">
+              Approvals: {{ approvalCount(proposal) }}/{{ proposal.requiredApprovals }}
+            </p>
+            <div class="flex gap-2">
+              <button (click)="approve(proposal)" class="btn btn-secondary">Approve</button>
+              <button (click)="copyCode(proposal)" class="btn btn-secondary">Copy code</button>
+              <button *ngIf="isReady(proposal)" (click)="execute(proposal)" class="btn btn-primary">
+                Execute
+              </button>
+            </div>
+          </li>
+        </ul>
+      </div>
+
+      <p *ngIf="status()" class="text-sm text-slate-400">{{ status() }}</p>
+    </div>
+  `,
+})
+export class GameComponent {
+  @Input() contractName = "";
+  walletService = inject(WalletService);
+
+  treasury = signal<any>(null);
+  proposals = signal<TreasuryProposal[]>(loadProposals());
+  status = signal<string | null>(null);
+
+  treasuryTxid = "";
+  signers = ["", "", ""];
+  funding = "100000";
+  recipient = "";
+  amount = "";
+  importCode = "";
+
+  approvalCount = approvalCount;
+  isReady = isReady;
+
+  config() {
+    return treasuryConfig(this.treasury());
+  }
+
+  trackIndex(index: number) {
+    return index;
+  }
+
+  private async run(label: string, action: () => Promise<void>) {
+    this.status.set(label + "...");
+    try {
+      await action();
+      this.status.set(null);
+    } catch (err: any) {
+      this.status.set(err?.message || String(err));
+    }
+  }
+
+  deploy() {
+    return this.run("Deploying treasury", async () => {
+      const { txid, instance } = await deployTreasury(
+        { signers: this.signers, ...DEFAULT_TIERS },
+        Number(this.funding),
+      );
+      this.treasuryTxid = txid;
+      this.treasury.set(instance);
+    });
+  }
+
+  restore() {
+    return this.run("Loading treasury", async () => {
+      this.treasury.set(await restoreTreasury(this.treasuryTxid));
+    });
+  }
+
+  propose() {
+    return this.run("Creating proposal", async () => {
+      const proposal = await createProposal(this.treasury(), this.recipient, Number(this.amount));
+      this.proposals.set(saveProposal(proposal));
+      this.recipient = "";
+      this.amount = "";
+    });
+  }
+
+  importProposal() {
+    return this.run("Importing proposal", async () => {
+      this.proposals.set(saveProposal(decodeProposal(this.importCode)));
+      this.importCode = "";
+    });
+  }
+
+  approve(proposal: TreasuryProposal) {
+    return this.run("Waiting for wallet signature", async () => {
+      this.proposals.set(saveProposal(await approveProposal(this.treasury(), proposal)));
+    });
+  }
+
+  execute(proposal: TreasuryProposal) {
+    return this.run("Broadcasting", async () => {
+      const { txid } = await executeProposal(this.treasury(), proposal);
+      this.proposals.set(loadProposals());
+      this.status.set("Executed: " + txid);
+    });
+  }
+
+  copyCode(proposal: TreasuryProposal) {
+    navigator.clipboard.writeText(encodeProposal(proposal));
+  }
+}
+"#;
+
+pub(super) const TREASURY_SVELTE_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+  import {
+    type TreasuryProposal,
+    restoreTreasury,
+    deployTreasury,
+    treasuryConfig,
+    createProposal,
+    approveProposal,
+    executeProposal,
+    encodeProposal,
+    decodeProposal,
+    loadProposals,
+    saveProposal,
+    approvalCount,
+    isReady,
+  } from "../services/treasuryService";
+
+  export let contractName: string;
+
+  // Default spending tiers (sats) used when deploying a new treasury
+  const DEFAULT_TIERS = { threshold: 2, fastTrackLimit: 10_000, highValueLimit: 1_000_000 };
+
+  let treasury: any = null;
+  let treasuryTxid = "";
+  let signers = ["", "", ""];
+  let funding = "100000";
+  let recipient = "";
+  let amount = "";
+  let importCode = "";
+  let proposals: TreasuryProposal[] = loadProposals();
+  let status: string | null = null;
+
+  $: config = treasury ? treasuryConfig(treasury) : null;
+
+  async function run(label: string, action: () => Promise<void>) {
+    status = label + "...";
+    try {
+      await action();
+      status = null;
+    } catch (err: any) {
+      status = err?.message || String(err);
+    }
+  }
+
+  const deploy = () =>
+    run("Deploying treasury", async () => {
+      const result = await deployTreasury({ signers, ...DEFAULT_TIERS }, Number(funding));
+      treasuryTxid = result.txid;
+      treasury = result.instance;
+    });
+
+  const restore = () =>
+    run("Loading treasury", async () => {
+      treasury = await restoreTreasury(treasuryTxid);
+    });
+
+  const propose = () =>
+    run("Creating proposal", async () => {
+      proposals = saveProposal(await createProposal(treasury, recipient, Number(amount)));
+      recipient = "";
+      amount = "";
+    });
+
+  const importProposal = () =>
+    run("Importing proposal", async () => {
+      proposals = saveProposal(decodeProposal(importCode));
+      importCode = "";
+    });
+
+  const approve = (proposal: TreasuryProposal) =>
+    run("Waiting for wallet signature", async () => {
+      proposals = saveProposal(await approveProposal(treasury, proposal));
+    });
+
+  const execute = (proposal: TreasuryProposal) =>
+    run("Broadcasting", async () => {
+      const { txid } = await executeProposal(treasury, proposal);
+      proposals = loadProposals();
+      status = "Executed: " + txid;
+    });
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Treasury</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to manage the treasury</p>
+    </div>
+  {:else if !treasury || !config}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-4">
+      <p class="text-slate-400">Contract: {contractName}</p>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Deploy a new treasury</h3>
+        {#each signers as _, i}
+          <input
+            bind:value={signers[i]}
+            class="w-full rounded bg-slate-900 p-2"
+            placeholder={"Co-signer " + (i + 1) + " public key"}
+          />
+        {/each}
+        <input bind:value={funding} class="w-full rounded bg-slate-900 p-2" placeholder="Funding (sats)" />
+        <button on:click={deploy} class="btn btn-primary">Deploy</button>
+      </div>
+      <div class="space-y-2">
+        <h3 class="font-semibold">Or load an existing one</h3>
+        <input bind:value={treasuryTxid} class="w-full rounded bg-slate-900 p-2" placeholder="Treasury txid" />
+        <button on:click={restore} class="btn btn-secondary">Load</button>
+      </div>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6 space-y-6">
+      <p class="text-slate-400">
+        Balance: {treasury.balance} sats · {config.threshold}-of-{config.signers.length}
+        (≤{config.fastTrackLimit} sats: 1 approval, &gt;{config.highValueLimit} sats: all)
+      </p>
+
+      <div class="space-y-2">
+        <h3 class="font-semibold">New proposal</h3>
+        <input bind:value={recipient} class="w-full rounded bg-slate-900 p-2" placeholder="Recipient address" />
+        <input bind:value={amount} class="w-full rounded bg-slate-900 p-2" placeholder="Amount (sats)" />
+        <button on:click={propose} class="btn btn-primary">Propose</button>
+      </div>
+
+      <div class="space-y-2">
+        <h3 class="font-semibold">Import a proposal code</h3>
+        <textarea bind:value={importCode} class="w-full rounded bg-slate-900 p-2"></textarea>
+        <button on:click={importProposal} class="btn btn-secondary">Import</button>
+      </div>
+
+      <ul class="space-y-2">
+        {#each proposals as proposal (proposal.id)}
+          <li class="rounded bg-slate-900 p-3 space-y-2">
+            <p>{proposal.amount} sats → {proposal.recipient}</p>
+            <p class="text-sm text-slate-400">
+              Approvals: {approvalCount(proposal)}/{proposal.requiredApprovals}
+            </p>
+            <div class="flex gap-2">
+              <button on:click={() => approve(proposal)} class="btn btn-secondary">Approve</button>
+              <button
+                on:click={() => navigator.clipboard.writeText(encodeProposal(proposal))}
+                class="btn btn-secondary"
+              >
+                Copy code
+              </button>
+              {#if isReady(proposal)}
+                <button on:click={() => execute(proposal)} class="btn btn-primary">Execute</button>
+              {/if}
+            </div>
+          </li>
+        {/each}
+      </ul>
+    </div>
+  {/if}
+
+  {#if status}
+    <p class="text-sm text-slate-400">{status}</p>
+  {/if}
+</div>
+"#;
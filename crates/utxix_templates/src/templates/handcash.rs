@@ -0,0 +1,334 @@
+//! Optional HandCash Connect backend and its frontend client.
+
+/// Optional HandCash Connect backend, written to `handcash/`.
+pub fn handcash_files() -> Vec<(&'static str, String)> {
+    vec![
+        ("handcash/package.json", HANDCASH_PACKAGE_JSON.to_string()),
+        ("handcash/server.mjs", HANDCASH_SERVER.to_string()),
+        ("handcash/.gitignore", "node_modules/\n".to_string()),
+    ]
+}
+
+/// Frontend client for the HandCash backend, relative to `services_dir`.
+pub fn handcash_services() -> Vec<(&'static str, String)> {
+    vec![("handcashService.ts", HANDCASH_SERVICE.to_string())]
+}
+
+// HANDCASH CONNECT (optional, wizard toggle)
+// ============================================================================
+
+const HANDCASH_PACKAGE_JSON: &str = r#"{
+  "name": "bitcoin-app-handcash",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "start": "node server.mjs"
+  },
+  "dependencies": {
+    "@handcash/handcash-connect": "^0.8.0",
+    "dotenv": "^16.4.0"
+  }
+}
+"#;
+
+const HANDCASH_SERVER: &str = r#"/**
+ * HandCash Connect backend - OAuth login, payments and data signing
+ *
+ * HandCash is a custodial wallet: users approve the app once in HandCash,
+ * and the app then acts for them through an auth token. That token is as
+ * good as a password and the app secret must never ship to the browser, so
+ * both stay here. The frontend only ever holds an opaque session id.
+ *
+ * Setup:
+ *   1. Create an app at https://dashboard.handcash.io and put its App ID and
+ *      App Secret in .env (HANDCASH_APP_ID, HANDCASH_APP_SECRET) - only
+ *      there, never commit them
+ *   2. Set its Authorization Success URL to http://localhost:3005/auth/callback
+ *   3. cd handcash && npm install && npm start
+ *
+ * Endpoints (authenticated ones take "Authorization: Bearer <session>"):
+ *   GET  /auth/login       → redirects to HandCash to approve the app
+ *   GET  /auth/callback    HandCash redirects here with ?authToken=...;
+ *                          redirects back to APP_URL with ?handcashSession=...
+ *   GET  /profile          → { handle, paymail, displayName, avatarUrl }
+ *   GET  /balance          → { satoshis, fiat, currencyCode }
+ *   POST /pay              { to, amount, currencyCode?, description? } → { txid }
+ *                          to: handle, paymail or address; amount in currencyCode (default BSV)
+ *   POST /sign             { value } → { publicKey, signature } (signs a UTF-8 message)
+ *   POST /logout
+ *
+ * Env (read from the project's .env): HANDCASH_APP_ID, HANDCASH_APP_SECRET,
+ *      HANDCASH_PORT (3005), APP_URL (http://localhost:5173)
+ */
+
+import { createServer } from "node:http";
+import { randomBytes } from "node:crypto";
+import { fileURLToPath } from "node:url";
+import dotenv from "dotenv";
+import { HandCashConnect } from "@handcash/handcash-connect";
+
+dotenv.config({ path: fileURLToPath(new URL("../.env", import.meta.url)) });
+
+const PORT = Number(process.env.HANDCASH_PORT || 3005);
+const APP_URL = (process.env.APP_URL || "http://localhost:5173").replace(/\/$/, "");
+
+if (!process.env.HANDCASH_APP_ID || !process.env.HANDCASH_APP_SECRET) {
+  console.error("Set HANDCASH_APP_ID and HANDCASH_APP_SECRET in .env (see https://dashboard.handcash.io)");
+  process.exit(1);
+}
+
+const handCashConnect = new HandCashConnect({
+  appId: process.env.HANDCASH_APP_ID,
+  appSecret: process.env.HANDCASH_APP_SECRET,
+});
+
+// ============================================================================
+// SESSIONS
+// ============================================================================
+
+/** Session id → HandCash auth token. In memory: restarting logs everyone out. */
+const sessions = new Map();
+
+function accountFor(req) {
+  const session = (req.headers.authorization || "").replace(/^Bearer /, "");
+  const authToken = sessions.get(session);
+  if (!authToken) {
+    throw Object.assign(new Error("Not connected to HandCash - log in again"), { status: 401 });
+  }
+  return { session, account: handCashConnect.getAccountFromAuthToken(authToken) };
+}
+
+// ============================================================================
+// HTTP
+// ============================================================================
+
+async function readJson(req) {
+  let body = "";
+  for await (const chunk of req) body += chunk;
+  return body ? JSON.parse(body) : {};
+}
+
+function send(res, status, body) {
+  res.writeHead(status, {
+    "Content-Type": "application/json",
+    "Access-Control-Allow-Origin": APP_URL,
+    "Access-Control-Allow-Headers": "Content-Type, Authorization",
+  });
+  res.end(JSON.stringify(body));
+}
+
+function redirect(res, location) {
+  res.writeHead(302, { Location: location });
+  res.end();
+}
+
+async function handle(req, res) {
+  const url = new URL(req.url, "http://localhost");
+  const route = req.method + " " + url.pathname;
+
+  if (req.method === "OPTIONS") {
+    return send(res, 204, {});
+  }
+  if (route === "GET /auth/login") {
+    return redirect(res, handCashConnect.getRedirectionUrl());
+  }
+  if (route === "GET /auth/callback") {
+    const authToken = url.searchParams.get("authToken");
+    if (!authToken) {
+      return redirect(res, APP_URL + "/?handcashError=" + encodeURIComponent("HandCash sent no auth token"));
+    }
+    const session = randomBytes(24).toString("hex");
+    sessions.set(session, authToken);
+    return redirect(res, APP_URL + "/?handcashSession=" + session);
+  }
+  if (route === "GET /profile") {
+    const { account } = accountFor(req);
+    const { publicProfile } = await account.profile.getCurrentProfile();
+    return send(res, 200, {
+      handle: publicProfile.handle,
+      paymail: publicProfile.paymail,
+      displayName: publicProfile.displayName,
+      avatarUrl: publicProfile.avatarUrl,
+    });
+  }
+  if (route === "GET /balance") {
+    const { account } = accountFor(req);
+    const balance = await account.wallet.getSpendableBalance();
+    return send(res, 200, {
+      satoshis: balance.spendableSatoshiBalance,
+      fiat: balance.spendableFiatBalance,
+      currencyCode: balance.currencyCode,
+    });
+  }
+  if (route === "POST /pay") {
+    const { account } = accountFor(req);
+    const { to, amount, currencyCode = "BSV", description } = await readJson(req);
+    if (!to || !(Number(amount) > 0)) {
+      return send(res, 400, { error: "pay needs a destination (to) and a positive amount" });
+    }
+    const payment = await account.wallet.pay({
+      description,
+      payments: [{ destination: to, currencyCode, sendAmount: Number(amount) }],
+    });
+    return send(res, 200, { txid: payment.transactionId });
+  }
+  if (route === "POST /sign") {
+    const { account } = accountFor(req);
+    const { value } = await readJson(req);
+    if (typeof value !== "string" || !value) {
+      return send(res, 400, { error: "sign needs a non-empty string value" });
+    }
+    const { publicKey, signature } = await account.profile.signData({ value, format: "utf-8" });
+    return send(res, 200, { publicKey, signature });
+  }
+  if (route === "POST /logout") {
+    const session = (req.headers.authorization || "").replace(/^Bearer /, "");
+    sessions.delete(session);
+    return send(res, 200, {});
+  }
+  send(res, 404, { error: "Not found: " + route });
+}
+
+createServer((req, res) => {
+  handle(req, res).catch((err) => {
+    // HandCash SDK errors carry the API's message
+    const message = err?.message || String(err);
+    console.error(req.method, req.url, message);
+    send(res, err?.status || 500, { error: message });
+  });
+}).listen(PORT, () => {
+  console.log("HandCash Connect backend on http://localhost:" + PORT);
+});
+"#;
+
+const HANDCASH_SERVICE: &str = r#"/**
+ * HandCash Connect - log in with HandCash, pay and sign through handcash/
+ *
+ * HandCash users approve the app in HandCash instead of installing a browser
+ * extension. The handcash/ backend keeps their auth token; this service only
+ * stores the session id it hands back.
+ *
+ * HandCash signs payments and messages, not arbitrary transactions, so it
+ * can fund users' payments (tips, purchases, paying a contract's address) but
+ * contract calls still go through Yours Wallet.
+ *
+ * Flow:
+ *   1. connectHandCash() sends the user to HandCash
+ *   2. HandCash returns to the app with ?handcashSession=... - call
+ *      completeHandCashLogin() once on startup (e.g. in main.ts) to pick it up
+ *   3. getHandCashProfile(), payWithHandCash(), signWithHandCash()
+ *
+ * Start the backend with: cd handcash && npm install && npm start
+ */
+
+import { config } from "./config";
+
+const SESSION_KEY = "handcash_session";
+
+export interface HandCashProfile {
+  handle: string;
+  paymail: string;
+  displayName: string;
+  avatarUrl: string;
+}
+
+export interface HandCashBalance {
+  satoshis: number;
+  fiat: number;
+  currencyCode: string;
+}
+
+async function handcashRequest<T>(path: string, init?: RequestInit): Promise<T> {
+  const session = localStorage.getItem(SESSION_KEY);
+  if (!session) {
+    throw new Error("Not connected to HandCash");
+  }
+  const res = await fetch(config.handcashUrl + path, {
+    ...init,
+    headers: { "Content-Type": "application/json", Authorization: "Bearer " + session },
+  });
+  const body = await res.json().catch(() => ({}));
+  if (res.status === 401) {
+    // The backend restarted or the user revoked the app
+    localStorage.removeItem(SESSION_KEY);
+  }
+  if (!res.ok) {
+    throw new Error(body.error || "HandCash request " + path + " failed: HTTP " + res.status);
+  }
+  return body;
+}
+
+/**
+ * Sends the user to HandCash to approve the app
+ */
+export function connectHandCash(): void {
+  window.location.href = config.handcashUrl + "/auth/login";
+}
+
+/**
+ * Picks up the session HandCash redirected back with, and removes it from
+ * the URL. Returns the profile when connected (now or earlier).
+ */
+export async function completeHandCashLogin(): Promise<HandCashProfile | null> {
+  const params = new URLSearchParams(window.location.search);
+  const session = params.get("handcashSession");
+  const error = params.get("handcashError");
+  if (session || error) {
+    params.delete("handcashSession");
+    params.delete("handcashError");
+    const query = params.toString();
+    window.history.replaceState(null, "", window.location.pathname + (query ? "?" + query : ""));
+  }
+  if (error) {
+    throw new Error(error);
+  }
+  if (session) {
+    localStorage.setItem(SESSION_KEY, session);
+  }
+  return isHandCashConnected() ? getHandCashProfile() : null;
+}
+
+export function isHandCashConnected(): boolean {
+  return localStorage.getItem(SESSION_KEY) !== null;
+}
+
+export function getHandCashProfile(): Promise<HandCashProfile> {
+  return handcashRequest<HandCashProfile>("/profile");
+}
+
+export function getHandCashBalance(): Promise<HandCashBalance> {
+  return handcashRequest<HandCashBalance>("/balance");
+}
+
+/**
+ * Pays a HandCash handle, paymail or address. `amount` is in `currencyCode`
+ * (BSV by default; fiat codes like USD work too).
+ */
+export async function payWithHandCash(
+  to: string,
+  amount: number,
+  description?: string,
+  currencyCode: string = "BSV",
+): Promise<{ txid: string }> {
+  return handcashRequest<{ txid: string }>("/pay", {
+    method: "POST",
+    body: JSON.stringify({ to, amount, currencyCode, description }),
+  });
+}
+
+/**
+ * Signs a UTF-8 message with the user's HandCash identity key - e.g. to
+ * prove who is joining a game without a wallet extension
+ */
+export function signWithHandCash(message: string): Promise<{ publicKey: string; signature: string }> {
+  return handcashRequest("/sign", { method: "POST", body: JSON.stringify({ value: message }) });
+}
+
+export async function disconnectHandCash(): Promise<void> {
+  await handcashRequest("/logout", { method: "POST" }).catch(() => {});
+  localStorage.removeItem(SESSION_KEY);
+}
+"#;
+
+// ============================================================================
@@ -0,0 +1,409 @@
+//! Optional UTXO indexer backend and its frontend client.
+
+/// Optional UTXO indexer backend, written to `indexer/`.
+pub fn indexer_files() -> Vec<(&'static str, String)> {
+    vec![
+        ("indexer/package.json", INDEXER_PACKAGE_JSON.to_string()),
+        ("indexer/server.mjs", INDEXER_SERVER.to_string()),
+        ("indexer/.gitignore", "node_modules/\nindexer.db*\n".to_string()),
+    ]
+}
+
+/// Frontend client for the indexer, relative to `services_dir`.
+pub fn indexer_services() -> Vec<(&'static str, String)> {
+    vec![("indexerClient.ts", INDEXER_CLIENT.to_string())]
+}
+
+// UTXO INDEXER (optional backend, wizard toggle)
+// ============================================================================
+
+const INDEXER_PACKAGE_JSON: &str = r#"{
+  "name": "bitcoin-app-indexer",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "start": "node server.mjs"
+  },
+  "dependencies": {
+    "better-sqlite3": "^11.0.0"
+  }
+}
+"#;
+
+const INDEXER_SERVER: &str = r#"/**
+ * Contract UTXO indexer - follows deployed contracts from state to state
+ *
+ * Polls WhatsOnChain for spends of every tracked contract UTXO, records each
+ * state in SQLite and serves the current UTXO and the state history to the
+ * frontend, so the browser never queries explorers directly.
+ *
+ * A stateful sCrypt contract changes its locking script - and so its script
+ * hash - with every state. The continuation output is matched by the
+ * contract's code part instead: the locking script minus the OP_RETURN state
+ * trailer. Stateless contracts simply close when spent.
+ *
+ * Run:  cd indexer && npm install && npm start
+ *
+ * Endpoints (contract id = deploy outpoint, <txid>_<vout>):
+ *   POST /contracts               { txid, vout?, name? }  start tracking a deployment
+ *   GET  /contracts               → tracked contracts
+ *   GET  /contracts/:id           → contract with its current UTXO (null once closed)
+ *   GET  /contracts/:id/history   → every state, oldest first
+ *   GET  /chain/height            → current block height
+ *
+ * Env: PORT (3003), BSV_NETWORK (testnet | mainnet), WOC_API_KEY,
+ *      POLL_INTERVAL_MS (30000), DB_PATH (indexer.db)
+ */
+
+import { createServer } from "node:http";
+import Database from "better-sqlite3";
+
+const PORT = Number(process.env.PORT || 3003);
+const NETWORK = process.env.BSV_NETWORK || "testnet";
+const WOC_URL = "https://api.whatsonchain.com/v1/bsv/" + (NETWORK === "testnet" ? "test" : "main");
+const POLL_INTERVAL_MS = Number(process.env.POLL_INTERVAL_MS || 30_000);
+
+// ============================================================================
+// STORAGE
+// ============================================================================
+
+const db = new Database(process.env.DB_PATH || "indexer.db");
+db.pragma("journal_mode = WAL");
+db.exec(`
+  CREATE TABLE IF NOT EXISTS contracts (
+    id TEXT PRIMARY KEY,
+    name TEXT,
+    code TEXT NOT NULL,
+    closed INTEGER NOT NULL DEFAULT 0,
+    created_at INTEGER NOT NULL
+  );
+  CREATE TABLE IF NOT EXISTS states (
+    contract_id TEXT NOT NULL REFERENCES contracts(id),
+    seq INTEGER NOT NULL,
+    txid TEXT NOT NULL,
+    vout INTEGER NOT NULL,
+    satoshis INTEGER NOT NULL,
+    script TEXT NOT NULL,
+    height INTEGER,
+    spent_by TEXT,
+    PRIMARY KEY (contract_id, seq)
+  );
+`);
+
+const insertContract = db.prepare(
+  "INSERT INTO contracts (id, name, code, created_at) VALUES (?, ?, ?, ?)",
+);
+const insertState = db.prepare(
+  "INSERT INTO states (contract_id, seq, txid, vout, satoshis, script, height) VALUES (?, ?, ?, ?, ?, ?, ?)",
+);
+const markSpent = db.prepare("UPDATE states SET spent_by = ? WHERE contract_id = ? AND seq = ?");
+const setHeight = db.prepare("UPDATE states SET height = ? WHERE contract_id = ? AND seq = ?");
+const closeContract = db.prepare("UPDATE contracts SET closed = 1 WHERE id = ?");
+const selectContract = db.prepare("SELECT * FROM contracts WHERE id = ?");
+const selectContracts = db.prepare("SELECT * FROM contracts ORDER BY created_at DESC");
+const selectLive = db.prepare(
+  "SELECT s.*, c.code FROM states s JOIN contracts c ON c.id = s.contract_id " +
+  "WHERE c.closed = 0 AND s.spent_by IS NULL",
+);
+const selectCurrent = db.prepare(
+  "SELECT * FROM states WHERE contract_id = ? AND spent_by IS NULL ORDER BY seq DESC LIMIT 1",
+);
+const selectHistory = db.prepare("SELECT * FROM states WHERE contract_id = ? ORDER BY seq");
+
+function stateJson(row) {
+  return {
+    seq: row.seq,
+    txid: row.txid,
+    vout: row.vout,
+    satoshis: row.satoshis,
+    script: row.script,
+    height: row.height,
+    spentBy: row.spent_by,
+  };
+}
+
+function contractJson(row) {
+  const current = row.closed ? undefined : selectCurrent.get(row.id);
+  return {
+    id: row.id,
+    name: row.name,
+    closed: row.closed === 1,
+    createdAt: row.created_at,
+    utxo: current ? stateJson(current) : null,
+  };
+}
+
+// ============================================================================
+// WHATSONCHAIN
+// ============================================================================
+
+async function woc(path) {
+  const headers = process.env.WOC_API_KEY ? { "woc-api-key": process.env.WOC_API_KEY } : {};
+  const res = await fetch(WOC_URL + path, { headers });
+  if (res.status === 404) return null;
+  if (!res.ok) {
+    throw new Error("WhatsOnChain " + path + ": HTTP " + res.status);
+  }
+  return res.json();
+}
+
+async function getOutput(txid, vout) {
+  const tx = await woc("/tx/hash/" + txid);
+  const output = tx?.vout?.[vout];
+  if (!output) return null;
+  return {
+    satoshis: Math.round(output.value * 1e8),
+    script: output.scriptPubKey.hex,
+    height: tx.blockheight || null,
+  };
+}
+
+let heightCache = { height: 0, at: 0 };
+
+async function getBlockHeight() {
+  if (Date.now() - heightCache.at > 60_000) {
+    heightCache = { height: (await woc("/chain/info")).blocks, at: Date.now() };
+  }
+  return heightCache.height;
+}
+
+/**
+ * Locking script without the state trailer of a stateful contract:
+ * <code> OP_RETURN <state> <state length: 4 bytes LE> <version: 1 byte>
+ */
+function codePart(script) {
+  const size = script.length / 2;
+  if (size < 6) return script;
+  const stateLen = parseInt(script.slice(-10, -2).match(/../g).reverse().join(""), 16);
+  const opReturnAt = size - 5 - stateLen - 1;
+  if (opReturnAt <= 0 || script.slice(opReturnAt * 2, opReturnAt * 2 + 2) !== "6a") {
+    return script;
+  }
+  return script.slice(0, opReturnAt * 2);
+}
+
+// ============================================================================
+// POLLING
+// ============================================================================
+
+/**
+ * Walks one contract forward from its live state, as far as the chain goes
+ */
+async function follow(state) {
+  while (state) {
+    if (state.height === null) {
+      const output = await getOutput(state.txid, state.vout);
+      if (output?.height) setHeight.run(output.height, state.contract_id, state.seq);
+    }
+
+    const spend = await woc("/tx/" + state.txid + "/" + state.vout + "/spent");
+    if (!spend) return;
+
+    const tx = await woc("/tx/hash/" + spend.txid);
+    const vout = tx.vout.findIndex((o) => codePart(o.scriptPubKey.hex) === state.code);
+    const next = vout < 0 ? null : {
+      contract_id: state.contract_id,
+      code: state.code,
+      seq: state.seq + 1,
+      txid: spend.txid,
+      vout,
+      satoshis: Math.round(tx.vout[vout].value * 1e8),
+      script: tx.vout[vout].scriptPubKey.hex,
+      height: tx.blockheight || null,
+    };
+
+    db.transaction(() => {
+      markSpent.run(spend.txid, state.contract_id, state.seq);
+      if (next) {
+        insertState.run(next.contract_id, next.seq, next.txid, next.vout, next.satoshis, next.script, next.height);
+      } else {
+        closeContract.run(state.contract_id);
+      }
+    })();
+    state = next;
+  }
+}
+
+let polling = false;
+
+async function poll() {
+  if (polling) return;
+  polling = true;
+  try {
+    for (const state of selectLive.all()) {
+      await follow(state).catch((err) => console.error("Indexing " + state.contract_id + " failed:", err.message));
+    }
+  } finally {
+    polling = false;
+  }
+}
+
+async function track(txid, vout, name) {
+  const id = txid + "_" + vout;
+  const existing = selectContract.get(id);
+  if (existing) return existing;
+
+  const output = await getOutput(txid, vout);
+  if (!output) {
+    throw Object.assign(new Error("Output " + id + " not found on " + NETWORK), { status: 404 });
+  }
+  const code = codePart(output.script);
+  db.transaction(() => {
+    insertContract.run(id, name || null, code, Date.now());
+    insertState.run(id, 0, txid, vout, output.satoshis, output.script, output.height);
+  })();
+
+  // Catch up with any states since the deployment
+  await follow({ ...selectCurrent.get(id), code }).catch(() => {});
+  return selectContract.get(id);
+}
+
+// ============================================================================
+// HTTP API
+// ============================================================================
+
+function send(res, status, body) {
+  res.writeHead(status, {
+    "Content-Type": "application/json",
+    "Access-Control-Allow-Origin": "*",
+    "Access-Control-Allow-Headers": "Content-Type",
+    "Access-Control-Allow-Methods": "GET, POST, OPTIONS",
+  });
+  res.end(body === undefined ? "" : JSON.stringify(body));
+}
+
+async function readJson(req) {
+  let raw = "";
+  for await (const chunk of req) raw += chunk;
+  return raw ? JSON.parse(raw) : {};
+}
+
+const server = createServer(async (req, res) => {
+  try {
+    if (req.method === "OPTIONS") return send(res, 204);
+
+    const [, resource, id, sub] = new URL(req.url, "http://localhost").pathname.split("/");
+
+    if (resource === "chain" && id === "height" && req.method === "GET") {
+      return send(res, 200, { height: await getBlockHeight() });
+    }
+    if (resource !== "contracts") {
+      return send(res, 404, { error: "not found" });
+    }
+
+    if (!id) {
+      if (req.method === "GET") {
+        return send(res, 200, selectContracts.all().map(contractJson));
+      }
+      if (req.method === "POST") {
+        const { txid, vout = 0, name } = await readJson(req);
+        if (!/^[0-9a-f]{64}$/.test(txid || "") || !Number.isInteger(vout) || vout < 0) {
+          return send(res, 400, { error: "txid (hex) and vout are required" });
+        }
+        return send(res, 201, contractJson(await track(txid, vout, name)));
+      }
+    }
+
+    const contract = id && selectContract.get(id);
+    if (!contract || req.method !== "GET") {
+      return send(res, 404, { error: "not found" });
+    }
+    if (!sub) {
+      return send(res, 200, contractJson(contract));
+    }
+    if (sub === "history") {
+      return send(res, 200, selectHistory.all(id).map(stateJson));
+    }
+    send(res, 404, { error: "not found" });
+  } catch (err) {
+    send(res, err.status || 500, { error: String(err?.message || err) });
+  }
+});
+
+server.listen(PORT, () => {
+  console.log("Contract indexer (" + NETWORK + ") listening on http://localhost:" + PORT);
+  poll();
+  setInterval(poll, POLL_INTERVAL_MS);
+});
+"#;
+
+const INDEXER_CLIENT: &str = r#"/**
+ * Indexer client - contract state from the indexer/ backend
+ *
+ * Use this instead of querying explorers from the browser. The indexer
+ * follows every tracked contract from its deploy txid to its current UTXO,
+ * so users only ever need to share the deploy txid.
+ *
+ * Start it with: cd indexer && npm install && npm start
+ */
+
+import { config } from "./config";
+
+const INDEXER_URL = config.indexerUrl;
+
+export interface IndexedState {
+  seq: number;
+  txid: string;
+  vout: number;
+  satoshis: number;
+  script: string;
+  height: number | null;
+  spentBy: string | null;
+}
+
+export interface IndexedContract {
+  id: string;
+  name: string | null;
+  closed: boolean;
+  createdAt: number;
+  utxo: IndexedState | null;
+}
+
+async function indexerRequest<T>(path: string, init?: RequestInit): Promise<T> {
+  const res = await fetch(INDEXER_URL + path, {
+    ...init,
+    headers: { "Content-Type": "application/json" },
+  });
+  if (!res.ok) {
+    const body = await res.json().catch(() => ({}));
+    throw new Error(body.error || "Indexer request " + path + " failed: HTTP " + res.status);
+  }
+  return res.json();
+}
+
+/**
+ * Starts tracking a deployment - call it right after deploy()
+ */
+export function trackContract(txid: string, vout: number = 0, name?: string): Promise<IndexedContract> {
+  return indexerRequest<IndexedContract>("/contracts", {
+    method: "POST",
+    body: JSON.stringify({ txid, vout, name }),
+  });
+}
+
+export function getContract(deployTxid: string, vout: number = 0): Promise<IndexedContract> {
+  return indexerRequest<IndexedContract>("/contracts/" + deployTxid + "_" + vout);
+}
+
+export function getHistory(deployTxid: string, vout: number = 0): Promise<IndexedState[]> {
+  return indexerRequest<IndexedState[]>("/contracts/" + deployTxid + "_" + vout + "/history");
+}
+
+/**
+ * Current UTXO of a contract - pass it to the service's restore function
+ */
+export async function getLatestUtxo(deployTxid: string, vout: number = 0): Promise<IndexedState> {
+  const contract = await getContract(deployTxid, vout);
+  if (!contract.utxo) {
+    throw new Error("Contract " + contract.id + " has been closed");
+  }
+  return contract.utxo;
+}
+
+export async function getBlockHeight(): Promise<number> {
+  return (await indexerRequest<{ height: number }>("/chain/height")).height;
+}
+"#;
+
+// ============================================================================
@@ -0,0 +1,295 @@
+//! The Next.js frontend.
+
+use crate::Template;
+
+use super::{
+    contracts::contract_filename,
+    frontends::react_game_component,
+    react::REACT_WALLET_BUTTON,
+    shared::{POSTCSS_CONFIG, TAILWIND_CSS},
+    wallet::{CONFIG_SERVICE, PANDA_SIGNER_SERVICE, YOURS_DIRECT_SIGNER},
+};
+
+pub fn nextjs_files(template: Template) -> Vec<(&'static str, String)> {
+    let contract_name = contract_filename(template);
+    vec![
+        ("src/app/layout.tsx", NEXTJS_LAYOUT.to_string()),
+        ("src/app/page.tsx", format!(r#""use client";
+
+import {{ WalletProvider }} from "@/components/WalletProvider";
+import {{ Game }} from "@/components/Game";
+
+export default function Home() {{
+  return (
+    <WalletProvider>
+      <div className="min-h-screen bg-slate-900 text-slate-100">
+        <header className="border-b border-slate-700 p-4">
+          <div className="max-w-4xl mx-auto flex justify-between items-center">
+            <h1 className="text-2xl font-bold">Bitcoin App</h1>
+          </div>
+        </header>
+        <main className="max-w-4xl mx-auto p-4">
+          <Game contractName="{contract_name}" />
+        </main>
+      </div>
+    </WalletProvider>
+  );
+}}
+"#)),
+        ("src/app/globals.css", TAILWIND_CSS.to_string()),
+        ("src/components/WalletProvider.tsx", NEXTJS_WALLET_PROVIDER.to_string()),
+        ("src/components/WalletButton.tsx", REACT_WALLET_BUTTON.to_string()),
+        ("src/components/Game.tsx", react_game_component(template).to_string()),
+        ("src/lib/wallet.ts", NEXTJS_WALLET_UTILS.to_string()),
+        ("src/services/pandaSignerService.ts", PANDA_SIGNER_SERVICE.to_string()),
+        ("src/services/yoursWalletDirect.ts", YOURS_DIRECT_SIGNER.to_string()),
+        ("src/services/config.ts", CONFIG_SERVICE.to_string()),
+        ("next.config.js", NEXTJS_CONFIG.to_string()),
+        ("tsconfig.json", NEXTJS_TSCONFIG.to_string()),
+        ("tailwind.config.ts", NEXTJS_TAILWIND_CONFIG.to_string()),
+        ("postcss.config.js", POSTCSS_CONFIG.to_string()),
+        ("package.json", nextjs_package_json()),
+    ]
+}
+
+const NEXTJS_LAYOUT: &str = r#"import type { Metadata } from "next";
+import "./globals.css";
+
+export const metadata: Metadata = {
+  title: "Bitcoin App",
+  description: "Bitcoin smart contract application",
+};
+
+export default function RootLayout({
+  children,
+}: {
+  children: React.ReactNode;
+}) {
+  return (
+    <html lang="en">
+      <body className="bg-slate-900">{children}</body>
+    </html>
+  );
+}
+"#;
+
+
+const NEXTJS_WALLET_PROVIDER: &str = r#""use client";
+
+import { createContext, useContext, useState, useEffect, ReactNode } from "react";
+
+type WalletType = "yours" | null;
+
+interface WalletState {
+  connected: boolean;
+  address: string | null;
+  balance: number;
+  loading: boolean;
+  error: string | null;
+  walletType: WalletType;
+}
+
+interface WalletContextType {
+  wallet: WalletState;
+  connect: () => void;
+  disconnect: () => void;
+}
+
+const STORAGE_KEY = "bsv_wallet";
+const WalletContext = createContext<WalletContextType | null>(null);
+
+export function WalletProvider({ children }: { children: ReactNode }) {
+  const [wallet, setWallet] = useState<WalletState>({
+    connected: false,
+    address: null,
+    balance: 0,
+    loading: false,
+    error: null,
+    walletType: null,
+  });
+
+  useEffect(() => {
+    // Check for stored auth
+    const raw = localStorage.getItem(STORAGE_KEY);
+    if (raw) {
+      try {
+        const parsed = JSON.parse(raw);
+        if (parsed.walletType === "yours") {
+          setWallet({
+            connected: true,
+            address: parsed.address,
+            balance: 0,
+            loading: false,
+            error: null,
+            walletType: parsed.walletType,
+          });
+        }
+      } catch {}
+    }
+  }, []);
+
+  const connect = async () => {
+    setWallet(prev => ({ ...prev, loading: true, error: null }));
+    try {
+      const { getPandaSigner } = await import("@/services/pandaSignerService");
+      const signer = await getPandaSigner(true);
+      const addr = await signer.getDefaultAddress();
+      const address = addr.toString();
+      const walletType: WalletType = "yours";
+      localStorage.setItem(STORAGE_KEY, JSON.stringify({ address, walletType }));
+      setWallet({
+        connected: true,
+        address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType,
+      });
+    } catch (err: any) {
+      setWallet(prev => ({ 
+        ...prev, 
+        loading: false, 
+        error: err?.message || "Failed to connect Yours Wallet" 
+      }));
+    }
+  };
+
+  const disconnect = () => {
+    localStorage.removeItem(STORAGE_KEY);
+    setWallet({
+      connected: false,
+      address: null,
+      balance: 0,
+      loading: false,
+      error: null,
+      walletType: null,
+    });
+  };
+
+  return (
+    <WalletContext.Provider value={{ wallet, connect, disconnect }}>
+      {children}
+    </WalletContext.Provider>
+  );
+}
+
+export function useWallet() {
+  const context = useContext(WalletContext);
+  if (!context) throw new Error("useWallet must be used within WalletProvider");
+  return context;
+}
+"#;
+
+const NEXTJS_WALLET_UTILS: &str = r#"// Next.js uses server-side for sensitive operations
+// Client-side wallet utilities
+
+export interface WalletState {
+  connected: boolean;
+  address: string | null;
+  balance: number;
+  loading: boolean;
+  error: string | null;
+}
+
+export interface DotWalletUser {
+  user_open_id: string;
+  user_name: string;
+  user_avatar: string;
+  pay_mail: string;
+}
+"#;
+
+const NEXTJS_CONFIG: &str = r#"/** @type {import('next').NextConfig} */
+const nextConfig = {
+  reactStrictMode: true,
+};
+
+module.exports = nextConfig;
+"#;
+
+const NEXTJS_TSCONFIG: &str = r#"{
+  "compilerOptions": {
+    "target": "es5",
+    "lib": ["dom", "dom.iterable", "esnext"],
+    "allowJs": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "noEmit": true,
+    "esModuleInterop": true,
+    "module": "esnext",
+    "moduleResolution": "bundler",
+    "resolveJsonModule": true,
+    "isolatedModules": true,
+    "jsx": "preserve",
+    "incremental": true,
+    "plugins": [{ "name": "next" }],
+    "paths": { "@/*": ["./src/*"] }
+  },
+  "include": ["next-env.d.ts", "**/*.ts", "**/*.tsx", ".next/types/**/*.ts"],
+  "exclude": ["node_modules"]
+}
+"#;
+
+const NEXTJS_TAILWIND_CONFIG: &str = r#"import type { Config } from "tailwindcss";
+
+const config: Config = {
+  content: [
+    "./src/pages/**/*.{js,ts,jsx,tsx,mdx}",
+    "./src/components/**/*.{js,ts,jsx,tsx,mdx}",
+    "./src/app/**/*.{js,ts,jsx,tsx,mdx}",
+  ],
+  theme: {
+    extend: {},
+  },
+  plugins: [],
+};
+
+export default config;
+"#;
+
+fn nextjs_package_json() -> String {
+    r#"{
+  "name": "bitcoin-app",
+  "version": "0.1.0",
+  "private": true,
+  "scripts": {
+    "dev": "next dev",
+    "build": "next build",
+    "start": "next start",
+    "lint": "next lint",
+    "pretest": "npx scrypt-cli compile",
+    "test": "mocha",
+    "deploy": "npx scrypt-cli compile && ts-node --project tsconfig.test.json scripts/deploy.ts",
+    "use:testnet": "node scripts/use-network.mjs testnet",
+    "use:mainnet": "node scripts/use-network.mjs mainnet"
+  },
+  "dependencies": {
+    "next": "14.0.0",
+    "react": "^18.2.0",
+    "react-dom": "^18.2.0",
+    "scrypt-ts": "^1.3.0",
+    "scrypt-ts-lib": "^0.1.28",
+    "scrypt-ord": "^1.0.0"
+  },
+  "devDependencies": {
+    "@types/chai": "^4.3.0",
+    "@types/chai-as-promised": "^7.1.0",
+    "@types/mocha": "^10.0.0",
+    "@types/node": "^20.0.0",
+    "@types/react": "^18.2.0",
+    "@types/react-dom": "^18.2.0",
+    "autoprefixer": "^10.4.0",
+    "chai": "^4.3.0",
+    "chai-as-promised": "^7.1.0",
+    "dotenv": "^16.3.0",
+    "fast-check": "^3.15.0",
+    "mocha": "^10.2.0",
+    "postcss": "^8.4.0",
+    "rabinsig": "^1.0.0",
+    "tailwindcss": "^3.4.0",
+    "ts-node": "^10.9.0",
+    "typescript": "^5.3.0"
+  }
+}
+"#.to_string()
+}
@@ -0,0 +1,169 @@
+//! Optional paymail client for the frontend.
+
+/// Optional paymail client, relative to `services_dir`.
+pub fn paymail_services() -> Vec<(&'static str, String)> {
+    vec![("paymail.ts", PAYMAIL_SERVICE.to_string())]
+}
+
+// PAYMAIL (optional, wizard toggle)
+// ============================================================================
+
+const PAYMAIL_SERVICE: &str = r#"/**
+ * Paymail - pay name@domain handles instead of raw addresses
+ *
+ * resolvePaymail() reads the host's capabilities from
+ * https://<domain>/.well-known/bsvalias and asks it for outputs: P2P payment
+ * destinations when offered (the signed transaction then goes back to the
+ * host, which broadcasts it), otherwise the basic paymentDestination.
+ * sendToPaymail() funds and signs the payment with the connected wallet.
+ *
+ * Paymail is mainnet-only, and the host must allow CORS requests from the
+ * app's origin - if it doesn't, resolve handles from a backend instead.
+ * Check a handle first with the editor's /paymail command.
+ */
+
+import { bsv } from "scrypt-ts";
+import { config } from "./config";
+import { getPandaSigner } from "./pandaSignerService";
+
+const P2P_PAYMENT_DESTINATION = "2a40af698840";
+const P2P_RECEIVE_TRANSACTION = "5f1323cddf31";
+const SENDER_VALIDATION = "6745385c3fc0";
+
+export interface PaymailOutput {
+  script: string;
+  satoshis: number;
+}
+
+export interface PaymailDestination {
+  handle: string;
+  outputs: PaymailOutput[];
+  /** Set for P2P destinations; sent back with the transaction */
+  reference: string | null;
+  /** Where the signed transaction goes instead of being broadcast */
+  receiveUrl: string | null;
+}
+
+type Capabilities = Record<string, string | boolean>;
+
+const capabilityCache = new Map<string, Promise<Capabilities>>();
+
+export function isPaymail(value: string): boolean {
+  return /^[a-z0-9._+-]+@[a-z0-9-]+(\.[a-z0-9-]+)+$/i.test(value.trim());
+}
+
+function splitHandle(handle: string): { alias: string; domain: string } {
+  const normalized = handle.trim().toLowerCase();
+  if (!isPaymail(normalized)) {
+    throw new Error(handle + " is not a paymail handle (name@domain)");
+  }
+  const [alias, domain] = normalized.split("@");
+  return { alias, domain };
+}
+
+async function paymailRequest<T>(url: string, body?: unknown): Promise<T> {
+  const init: RequestInit | undefined =
+    body === undefined
+      ? undefined
+      : { method: "POST", headers: { "Content-Type": "application/json" }, body: JSON.stringify(body) };
+  const res = await fetch(url, init);
+  if (!res.ok) {
+    const error = await res.json().catch(() => ({}));
+    throw new Error(error.message || "Paymail request to " + url + " failed: HTTP " + res.status);
+  }
+  return res.json();
+}
+
+function capabilities(domain: string): Promise<Capabilities> {
+  let cached = capabilityCache.get(domain);
+  if (!cached) {
+    cached = paymailRequest<{ capabilities?: Capabilities }>("https://" + domain + "/.well-known/bsvalias").then(
+      (document) => {
+        if (!document.capabilities) {
+          throw new Error(domain + " does not serve paymail");
+        }
+        return document.capabilities;
+      },
+    );
+    // Retry hosts that were unreachable
+    cached.catch(() => capabilityCache.delete(domain));
+    capabilityCache.set(domain, cached);
+  }
+  return cached;
+}
+
+function capabilityUrl(template: string | boolean | undefined, alias: string, domain: string): string | null {
+  if (typeof template !== "string") return null;
+  return template.replace("{alias}", alias).replace("{domain.tld}", domain);
+}
+
+/**
+ * Outputs paying `satoshis` to a handle. Destinations are issued per
+ * payment - resolve again rather than reusing them.
+ */
+export async function resolvePaymail(handle: string, satoshis: number): Promise<PaymailDestination> {
+  const { alias, domain } = splitHandle(handle);
+  const caps = await capabilities(domain);
+
+  const p2pUrl = capabilityUrl(caps[P2P_PAYMENT_DESTINATION], alias, domain);
+  if (p2pUrl) {
+    const { outputs, reference } = await paymailRequest<{ outputs: PaymailOutput[]; reference: string }>(p2pUrl, {
+      satoshis,
+    });
+    return {
+      handle: alias + "@" + domain,
+      outputs,
+      reference,
+      receiveUrl: capabilityUrl(caps[P2P_RECEIVE_TRANSACTION], alias, domain),
+    };
+  }
+
+  const basicUrl = capabilityUrl(caps.paymentDestination, alias, domain);
+  if (!basicUrl) {
+    throw new Error(domain + " offers no payment destination");
+  }
+  if (caps[SENDER_VALIDATION] === true) {
+    throw new Error(domain + " only pays out to signed requests - resolve " + handle + " from a backend");
+  }
+  const { output } = await paymailRequest<{ output: string }>(basicUrl, {
+    senderHandle: "anonymous@" + location.hostname,
+    dt: new Date().toISOString(),
+    amount: satoshis,
+    purpose: "Payment from " + location.hostname,
+  });
+  return { handle: alias + "@" + domain, outputs: [{ script: output, satoshis }], reference: null, receiveUrl: null };
+}
+
+/**
+ * Pays a handle from the connected wallet and returns the txid
+ */
+export async function sendToPaymail(handle: string, satoshis: number, note?: string): Promise<{ txid: string }> {
+  if (config.network !== "mainnet") {
+    throw new Error("Paymail only works on mainnet - run npm run use:mainnet");
+  }
+  const destination = await resolvePaymail(handle, satoshis);
+
+  const signer = await getPandaSigner();
+  const address = await signer.getDefaultAddress();
+  const tx = new bsv.Transaction();
+  for (const output of destination.outputs) {
+    tx.addOutput(new bsv.Transaction.Output({ script: bsv.Script.fromHex(output.script), satoshis: output.satoshis }));
+  }
+  tx.from(await signer.listUnspent(address)).change(address).feePerKb(config.feePerKb);
+  const signed = await signer.signTransaction(tx);
+
+  // P2P hosts broadcast the transaction themselves, and match it to the
+  // destination they issued by its reference
+  if (!destination.receiveUrl) {
+    return { txid: await signer.provider!.sendTransaction(signed) };
+  }
+  const { txid } = await paymailRequest<{ txid: string }>(destination.receiveUrl, {
+    hex: signed.toString(),
+    reference: destination.reference,
+    metadata: { note },
+  });
+  return { txid };
+}
+"#;
+
+// ============================================================================
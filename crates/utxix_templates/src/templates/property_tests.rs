@@ -0,0 +1,1097 @@
+//! Optional fast-check property tests for each template's contract.
+
+pub(super) const PROPERTY_ARBITRARIES: &str = r#"/**
+ * fast-check arbitraries bounded like Bitcoin Script values
+ *
+ * Script numbers are little-endian sign-magnitude byte strings. sCrypt
+ * bigints may be longer than the legacy 4-byte limit, so scriptInt() covers
+ * the int64 range contract arithmetic usually sees - fast-check also biases
+ * toward its edges (0, +-1, min, max).
+ *
+ * PROPERTY_RUNS (25) sets how many cases each property tries.
+ */
+
+import fc from "fast-check";
+import { ByteString, toByteString } from "scrypt-ts";
+
+export const INT64_MIN = -(2n ** 63n);
+export const INT64_MAX = 2n ** 63n - 1n;
+
+// Push data limit of the original Script rules
+export const MAX_PUSH_BYTES = 520;
+
+// nLockTime values below this are block heights, above it timestamps
+export const LOCKTIME_THRESHOLD = 500_000_000;
+
+export const PROPERTY_RUNS = Number(process.env.PROPERTY_RUNS || 25);
+
+export function scriptInt(min = INT64_MIN, max = INT64_MAX): fc.Arbitrary<bigint> {
+  return fc.bigInt({ min, max });
+}
+
+// Script numbers outside [min, max]
+export function scriptIntOutside(min: bigint, max: bigint): fc.Arbitrary<bigint> {
+  return fc.oneof(scriptInt(INT64_MIN, min - 1n), scriptInt(max + 1n, INT64_MAX));
+}
+
+export function byteString(minLength = 0, maxLength = MAX_PUSH_BYTES): fc.Arbitrary<ByteString> {
+  return fc.uint8Array({ minLength, maxLength }).map((bytes) => toByteString(Buffer.from(bytes).toString("hex")));
+}
+
+export function blockHeight(min = 0, max = LOCKTIME_THRESHOLD - 1): fc.Arbitrary<number> {
+  return fc.integer({ min, max });
+}
+
+// Output value for a builder, whatever the (possibly invalid) argument was -
+// the contract's own asserts reject those before the outputs are compared
+export function clampSats(value: bigint, max: bigint | number): number {
+  const upper = BigInt(max);
+  return Number(value < 0n ? 0n : value > upper ? upper : value);
+}
+
+export function check<T>(arbitrary: fc.Arbitrary<T>, predicate: (value: T) => Promise<void>): Promise<void> {
+  return fc.assert(fc.asyncProperty(arbitrary, predicate), { numRuns: PROPERTY_RUNS });
+}
+"#;
+
+pub(super) const HELLOWORLD_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { sha256, ByteString } from "scrypt-ts";
+import HelloWorld from "../contracts/HelloWorld.scrypt";
+import { byteString, check } from "./utils/arbitraries";
+import { expectValid, getDefaultSigner, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+describe("HelloWorld properties", () => {
+  const owner = testKey();
+
+  before(async () => {
+    await HelloWorld.loadArtifact("artifacts/contracts/HelloWorld.scrypt.json");
+  });
+
+  async function deploy(message: ByteString) {
+    const instance = new HelloWorld(sha256(message));
+    await instance.connect(getDefaultSigner(owner));
+    await instance.deploy(1);
+    return instance;
+  }
+
+  it("unlock() accepts any committed message", async () => {
+    await check(byteString(), async (message) => {
+      expectValid(await (await deploy(message)).methods.unlock(message));
+    });
+  });
+
+  it("unlock() rejects every other message", async () => {
+    const pair = fc.tuple(byteString(), byteString()).filter(([message, guess]) => message !== guess);
+    await check(pair, async ([message, guess]) => {
+      await expect((await deploy(message)).methods.unlock(guess)).to.be.rejectedWith(/Hash mismatch/);
+    });
+  });
+});
+"#;
+
+pub(super) const COUNTER_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { MethodCallOptions } from "scrypt-ts";
+import Counter from "../contracts/Counter.scrypt";
+import { check, scriptInt } from "./utils/arbitraries";
+import { expectValid, getDefaultSigner, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+describe("Counter properties", () => {
+  const owner = testKey();
+
+  before(async () => {
+    await Counter.loadArtifact("artifacts/contracts/Counter.scrypt.json");
+  });
+
+  async function deploy(start: bigint) {
+    const counter = new Counter(start);
+    await counter.connect(getDefaultSigner(owner));
+    await counter.deploy(1);
+    return counter;
+  }
+
+  function increment(current: Counter, by: bigint) {
+    const next = current.next();
+    next.count = current.count + by;
+    return current.methods.increment({
+      next: { instance: next, atOutputIndex: 0, balance: current.balance },
+    } as MethodCallOptions<Counter>);
+  }
+
+  it("increment() moves any count forward by one", async () => {
+    await check(scriptInt(), async (start) => {
+      const result = await increment(await deploy(start), 1n);
+      expectValid(result);
+      expect(result.next!.instance.count).to.eq(start + 1n);
+    });
+  });
+
+  it("increment() rejects any other step", async () => {
+    const cases = fc.tuple(scriptInt(), scriptInt().filter((by) => by !== 1n));
+    await check(cases, async ([start, by]) => {
+      await expect(increment(await deploy(start), by)).to.be.rejectedWith(/hashOutputs mismatch/);
+    });
+  });
+});
+"#;
+
+pub(super) const TICTACTOE_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { MethodCallOptions } from "scrypt-ts";
+import TicTacToe from "../contracts/TicTacToe.scrypt";
+import { check, scriptIntOutside } from "./utils/arbitraries";
+import { TestKey, callTx, expectValid, getDefaultSigner, sigFrom, stateOutput, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+describe("TicTacToe properties", () => {
+  const playerX = testKey();
+  const playerO = testKey();
+
+  before(async () => {
+    await TicTacToe.loadArtifact("artifacts/contracts/TicTacToe.scrypt.json");
+  });
+
+  async function deploy() {
+    const game = new TicTacToe(playerX.pubKey, playerO.pubKey, 1000n);
+    await game.connect(getDefaultSigner(playerX, playerO));
+    await game.deploy(2000);
+    return game;
+  }
+
+  // Only opening moves here, so no move ends the game. Out-of-range
+  // positions leave the board untouched - the contract rejects them first
+  function move(current: TicTacToe, position: bigint, player: TestKey) {
+    current.bindTxBuilder("move", async (self: TicTacToe) => {
+      const next = self.next();
+      if (position >= 0n && position < 9n) {
+        next.board[Number(position)] = self.isXTurn ? 1n : 2n;
+      }
+      next.isXTurn = !self.isXTurn;
+      return {
+        tx: callTx(self, [stateOutput(next, self.balance)]),
+        atInputIndex: 0,
+        nexts: [{ instance: next, atOutputIndex: 0, balance: self.balance }],
+      };
+    });
+    return current.methods.move(position, sigFrom(player), {
+      pubKeyOrAddrToSign: player.publicKey,
+    } as MethodCallOptions<TicTacToe>);
+  }
+
+  it("move() accepts every free square", async () => {
+    await check(fc.bigInt({ min: 0n, max: 8n }), async (position) => {
+      const result = await move(await deploy(), position, playerX);
+      expectValid(result);
+      expect(result.next!.instance.board[Number(position)]).to.eq(1n);
+    });
+  });
+
+  it("move() rejects every position off the board", async () => {
+    await check(scriptIntOutside(0n, 8n), async (position) => {
+      await expect(move(await deploy(), position, playerX)).to.be.rejectedWith(/Invalid position/);
+    });
+  });
+
+  it("move() rejects every occupied square", async () => {
+    await check(fc.bigInt({ min: 0n, max: 8n }), async (position) => {
+      const next = (await move(await deploy(), position, playerX)).next!.instance;
+      await expect(move(next, position, playerO)).to.be.rejectedWith(/Position occupied/);
+    });
+  });
+});
+"#;
+
+pub(super) const AUCTION_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { hash160, Utils } from "scrypt-ts";
+import Auction from "../contracts/Auction.scrypt";
+import { INT64_MIN, check, clampSats, scriptInt } from "./utils/arbitraries";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  stateOutput,
+  testKey,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const DEADLINE = 800_000n;
+const MAX_BID = 5_000n;
+
+describe("Auction properties", () => {
+  const auctioneer = testKey();
+  const alice = testKey();
+  const bob = testKey();
+
+  before(async () => {
+    await Auction.loadArtifact("artifacts/contracts/Auction.scrypt.json");
+  });
+
+  async function deploy() {
+    const auction = new Auction(auctioneer.pubKey, DEADLINE);
+    await auction.connect(getDefaultSigner(auctioneer, alice, bob));
+    await auction.deploy(1);
+    return auction;
+  }
+
+  function bid(current: Auction, bidder: TestKey, amount: bigint) {
+    current.bindTxBuilder("bid", async (self: Auction) => {
+      const next = self.next();
+      next.highestBidder = bidder.pubKey;
+      next.highestBid = amount;
+
+      const satoshis = clampSats(amount, 2n * MAX_BID);
+      const outputs = [stateOutput(next, satoshis)];
+      if (self.highestBid > 0n) {
+        outputs.push(payTo(hash160(self.highestBidder), self.highestBid));
+      }
+      const tx = await addFunding(callTx(self, outputs), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: satoshis }] };
+    });
+    return current.methods.bid(bidder.pubKey, amount);
+  }
+
+  it("bid() accepts any raise and refunds the outbid bidder", async () => {
+    const cases = fc.tuple(fc.bigInt({ min: 1n, max: MAX_BID }), fc.bigInt({ min: 1n, max: MAX_BID }));
+    await check(cases, async ([first, raise]) => {
+      const current = (await bid(await deploy(), alice, first)).next!.instance;
+      const result = await bid(current, bob, first + raise);
+      expectValid(result);
+      expect(result.tx.outputs[1].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(alice.pkh));
+      expect(result.tx.outputs[1].satoshis).to.eq(Number(first));
+    });
+  });
+
+  it("bid() rejects anything not above the highest bid", async () => {
+    const cases = fc
+      .bigInt({ min: 1n, max: MAX_BID })
+      .chain((first) => fc.tuple(fc.constant(first), scriptInt(INT64_MIN, first)));
+    await check(cases, async ([first, second]) => {
+      const current = (await bid(await deploy(), alice, first)).next!.instance;
+      await expect(bid(current, bob, second)).to.be.rejectedWith(/Bid too low/);
+    });
+  });
+});
+"#;
+
+pub(super) const TREASURY_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import {
+  findSig,
+  FixedArray,
+  MethodCallOptions,
+  PubKey,
+  Sig,
+  SignatureResponse,
+  toByteString,
+} from "scrypt-ts";
+import Treasury, { SIGNER_COUNT } from "../contracts/Treasury.scrypt";
+import { check, clampSats, scriptIntOutside } from "./utils/arbitraries";
+import {
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  stateOutput,
+  testKey,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const THRESHOLD = 2;
+const FAST_TRACK_LIMIT = 1_000n;
+const HIGH_VALUE_LIMIT = 100_000n;
+const BALANCE = 200_000n;
+const CHANGE = 500n;
+
+// Same tiers as Treasury.requiredApprovals()
+function requiredApprovals(amount: bigint): number {
+  if (amount <= FAST_TRACK_LIMIT) return 1;
+  if (amount > HIGH_VALUE_LIMIT) return SIGNER_COUNT;
+  return THRESHOLD;
+}
+
+describe("Treasury properties", () => {
+  const signers = [testKey(), testKey(), testKey()];
+  const recipient = testKey();
+  const proposer = testKey();
+
+  before(async () => {
+    await Treasury.loadArtifact("artifacts/contracts/Treasury.scrypt.json");
+  });
+
+  async function deploy() {
+    const treasury = new Treasury(
+      signers.map((signer) => signer.pubKey) as FixedArray<PubKey, typeof SIGNER_COUNT>,
+      BigInt(THRESHOLD),
+      FAST_TRACK_LIMIT,
+      HIGH_VALUE_LIMIT,
+    );
+    await treasury.connect(getDefaultSigner(...signers, proposer));
+    await treasury.deploy(Number(BALANCE));
+    return treasury;
+  }
+
+  function spend(current: Treasury, amount: bigint, approvers: number[]) {
+    current.bindTxBuilder("spend", async (self: Treasury) => {
+      const paid = clampSats(amount, self.balance);
+      const remaining = self.balance - paid;
+      const next = self.next();
+      next.executed = self.executed + 1n;
+
+      const outputs = [payTo(recipient.pkh, paid)];
+      if (remaining > 0) {
+        outputs.push(stateOutput(next, remaining));
+      }
+      outputs.push(payTo(proposer.pkh, CHANGE));
+
+      const tx = await addFunding(callTx(self, outputs), self.signer);
+      const nexts = remaining > 0 ? [{ instance: next, atOutputIndex: 1, balance: remaining }] : [];
+      return { tx, atInputIndex: 0, nexts };
+    });
+
+    const approved = signers.map((_, i) => approvers.includes(i));
+    return current.methods.spend(
+      recipient.pkh,
+      amount,
+      proposer.pkh,
+      CHANGE,
+      (sigResps: SignatureResponse[]) =>
+        signers.map((signer, i) => (approved[i] ? findSig(sigResps, signer.publicKey) : Sig(toByteString("")))),
+      approved as FixedArray<boolean, typeof SIGNER_COUNT>,
+      { pubKeyOrAddrToSign: approvers.map((i) => signers[i].publicKey) } as MethodCallOptions<Treasury>,
+    );
+  }
+
+  const approverSets = fc.subarray([0, 1, 2], { minLength: 1 });
+
+  it("spend() verifies exactly when the approvals meet the amount's tier", async () => {
+    await check(fc.tuple(fc.bigInt({ min: 1n, max: BALANCE }), approverSets), async ([amount, approvers]) => {
+      const call = spend(await deploy(), amount, approvers);
+      if (approvers.length >= requiredApprovals(amount)) {
+        expectValid(await call);
+      } else {
+        await expect(call).to.be.rejectedWith(/Not enough approvals/);
+      }
+    });
+  });
+
+  it("spend() rejects amounts outside (0, balance]", async () => {
+    await check(scriptIntOutside(1n, BALANCE), async (amount) => {
+      await expect(spend(await deploy(), amount, [0, 1, 2])).to.be.rejectedWith(
+        /Amount must be positive|Insufficient treasury funds/,
+      );
+    });
+  });
+});
+"#;
+
+pub(super) const ROCK_PAPER_SCISSORS_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { hash160, hash256, int2ByteString, MethodCallOptions, toByteString, Utils } from "scrypt-ts";
+import RockPaperScissors from "../contracts/RockPaperScissors.scrypt";
+import { byteString, check, scriptIntOutside } from "./utils/arbitraries";
+import {
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  sigFrom,
+  stateOutput,
+  testKey,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const STAKE = 1000n;
+const move = fc.bigInt({ min: 1n, max: 3n });
+
+describe("RockPaperScissors properties", () => {
+  const player1 = testKey();
+  const player2 = testKey();
+
+  before(async () => {
+    await RockPaperScissors.loadArtifact("artifacts/contracts/RockPaperScissors.scrypt.json");
+  });
+
+  async function deploy(player1Move: bigint, nonce: string) {
+    const game = new RockPaperScissors(
+      player1.pubKey,
+      player2.pubKey,
+      hash256(toByteString(nonce) + int2ByteString(player1Move)),
+      STAKE,
+      800_000n,
+      800_144n,
+    );
+    await game.connect(getDefaultSigner(player1, player2));
+    await game.deploy(Number(STAKE));
+    return game;
+  }
+
+  function play(current: RockPaperScissors, player2Move: bigint) {
+    current.bindTxBuilder("play", async (self: RockPaperScissors) => {
+      const next = self.next();
+      next.player2Move = player2Move;
+      const pot = Number(self.stake * 2n);
+      const tx = await addFunding(callTx(self, [stateOutput(next, pot)]), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: pot }] };
+    });
+    return current.methods.play(player2Move, sigFrom(player2), {
+      pubKeyOrAddrToSign: player2.publicKey,
+    } as MethodCallOptions<RockPaperScissors>);
+  }
+
+  function reveal(current: RockPaperScissors, player1Move: bigint, nonce: string) {
+    current.bindTxBuilder("reveal", async (self: RockPaperScissors) => {
+      const pot = BigInt(self.balance);
+      const result = (player1Move - self.player2Move + 3n) % 3n;
+      const outputs = result === 0n
+        ? [payTo(hash160(self.player1), pot / 2n), payTo(hash160(self.player2), pot - pot / 2n)]
+        : [payTo(hash160(result === 1n ? self.player1 : self.player2), pot)];
+      return { tx: callTx(self, outputs), atInputIndex: 0, nexts: [] };
+    });
+    return current.methods.reveal(player1Move, toByteString(nonce), sigFrom(player1), {
+      pubKeyOrAddrToSign: player1.publicKey,
+    } as MethodCallOptions<RockPaperScissors>);
+  }
+
+  it("every pair of moves settles by the rules", async () => {
+    await check(fc.tuple(move, move, byteString(32, 32)), async ([move1, move2, nonce]) => {
+      const played = (await play(await deploy(move1, nonce), move2)).next!.instance;
+      const result = await reveal(played, move1, nonce);
+      expectValid(result);
+
+      const winner = (move1 - move2 + 3n) % 3n;
+      if (winner === 0n) {
+        expect(result.tx.outputs.map((output) => output.satoshis)).to.deep.eq([Number(STAKE), Number(STAKE)]);
+      } else {
+        const pkh = winner === 1n ? player1.pkh : player2.pkh;
+        expect(result.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(pkh));
+      }
+    });
+  });
+
+  it("play() rejects every move but rock, paper and scissors", async () => {
+    await check(scriptIntOutside(1n, 3n), async (player2Move) => {
+      await expect(play(await deploy(1n, "00".repeat(32)), player2Move)).to.be.rejectedWith(/Invalid move/);
+    });
+  });
+
+  it("reveal() rejects every move other than the committed one", async () => {
+    const cases = fc.tuple(move, move, byteString(32, 32)).filter(([committed, claimed]) => committed !== claimed);
+    await check(cases, async ([committed, claimed, nonce]) => {
+      const played = (await play(await deploy(committed, nonce), 1n)).next!.instance;
+      await expect(reveal(played, claimed, nonce)).to.be.rejectedWith(/Commitment mismatch/);
+    });
+  });
+});
+"#;
+
+pub(super) const LOTTERY_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { hash256, MethodCallOptions, ByteString } from "scrypt-ts";
+import Lottery from "../contracts/Lottery.scrypt";
+import { byteString, check, scriptIntOutside } from "./utils/arbitraries";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  sigFrom,
+  stateOutput,
+  testKey,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const secret = byteString(32, 32);
+
+describe("Lottery properties", () => {
+  const players = [testKey(), testKey()];
+
+  before(async () => {
+    await Lottery.loadArtifact("artifacts/contracts/Lottery.scrypt.json");
+  });
+
+  function enter(current: Lottery, entrant: TestKey, commitment: ByteString) {
+    current.bindTxBuilder("enter", async (self: Lottery) => {
+      const next = self.next();
+      next.entrants[Number(self.entryCount)] = entrant.pubKey;
+      next.commitments[Number(self.entryCount)] = commitment;
+      next.entryCount = self.entryCount + 1n;
+
+      const satoshis = self.balance + Number(self.ticketPrice);
+      const tx = await addFunding(callTx(self, [stateOutput(next, satoshis)]), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: satoshis }] };
+    });
+    return current.methods.enter(entrant.pubKey, commitment, sigFrom(entrant), {
+      pubKeyOrAddrToSign: entrant.publicKey,
+    } as MethodCallOptions<Lottery>);
+  }
+
+  // Out-of-range indexes leave the secrets untouched - the contract rejects
+  // them first
+  function reveal(current: Lottery, index: bigint, revealed: ByteString) {
+    current.bindTxBuilder("reveal", async (self: Lottery) => {
+      const next = self.next();
+      if (index >= 0n && index < self.entryCount) {
+        next.secrets[Number(index)] = revealed;
+      }
+      next.revealCount = self.revealCount + 1n;
+      return {
+        tx: callTx(self, [stateOutput(next, self.balance)]),
+        atInputIndex: 0,
+        nexts: [{ instance: next, atOutputIndex: 0, balance: self.balance }],
+      };
+    });
+    return current.methods.reveal(index, revealed);
+  }
+
+  // Two entrants, committed to `secrets`
+  async function entered(secrets: ByteString[]) {
+    let current = new Lottery(1000n, 800_000n);
+    await current.connect(getDefaultSigner(...players));
+    await current.deploy(1);
+    for (let i = 0; i < players.length; i++) {
+      current = (await enter(current, players[i], hash256(secrets[i]))).next!.instance;
+    }
+    return current;
+  }
+
+  it("reveal() opens any committed secret", async () => {
+    await check(fc.tuple(secret, secret, fc.bigInt({ min: 0n, max: 1n })), async ([first, second, index]) => {
+      const secrets = [first, second];
+      const result = await reveal(await entered(secrets), index, secrets[Number(index)]);
+      expectValid(result);
+      expect(result.next!.instance.secrets[Number(index)]).to.eq(secrets[Number(index)]);
+    });
+  });
+
+  it("reveal() rejects every other 32-byte secret", async () => {
+    const cases = fc.tuple(secret, secret, secret).filter(([first, , guess]) => guess !== first);
+    await check(cases, async ([first, second, guess]) => {
+      await expect(reveal(await entered([first, second]), 0n, guess)).to.be.rejectedWith(/Commitment mismatch/);
+    });
+  });
+
+  it("reveal() rejects secrets that aren't 32 bytes", async () => {
+    const wrongLength = fc.oneof(byteString(0, 31), byteString(33));
+    await check(fc.tuple(secret, secret, wrongLength), async ([first, second, guess]) => {
+      await expect(reveal(await entered([first, second]), 0n, guess)).to.be.rejectedWith(/Secret must be 32 bytes/);
+    });
+  });
+
+  it("reveal() rejects every index without an entrant", async () => {
+    await check(fc.tuple(secret, secret, scriptIntOutside(0n, 1n)), async ([first, second, index]) => {
+      await expect(reveal(await entered([first, second]), index, first)).to.be.rejectedWith(/Invalid index/);
+    });
+  });
+});
+"#;
+
+pub(super) const PREDICTION_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { MethodCallOptions, toByteString, ByteString, Utils } from "scrypt-ts";
+import { RabinSig } from "scrypt-ts-lib";
+import { Rabin } from "rabinsig";
+import PredictionMarket from "../contracts/PredictionMarket.scrypt";
+import { byteString, check } from "./utils/arbitraries";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  sigFrom,
+  stateOutput,
+  testKey,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const STAKE = 1000n;
+const eventId = byteString(32, 32);
+const outcome = fc.constantFrom("yes" as const, "no" as const);
+
+// Stands in for oracle/server.mjs
+const rabin = new Rabin();
+const oracleKey = rabin.generatePrivKey();
+
+function sign(msg: ByteString) {
+  const { signature, paddingByteCount } = rabin.sign(msg, oracleKey);
+  const sig: RabinSig = { s: signature, padding: toByteString("00".repeat(paddingByteCount)) };
+  return sig;
+}
+
+describe("PredictionMarket properties", () => {
+  const yesPlayer = testKey();
+  const noPlayer = testKey();
+
+  before(async () => {
+    await PredictionMarket.loadArtifact("artifacts/contracts/PredictionMarket.scrypt.json");
+  });
+
+  // A market on `id`, already accepted by the NO player
+  async function accepted(id: ByteString) {
+    const market = new PredictionMarket(
+      rabin.privKeyToPubKey(oracleKey),
+      id,
+      yesPlayer.pubKey,
+      noPlayer.pubKey,
+      STAKE,
+      800_000n,
+      801_000n,
+    );
+    await market.connect(getDefaultSigner(yesPlayer, noPlayer));
+    await market.deploy(Number(STAKE));
+
+    market.bindTxBuilder("accept", async (self: PredictionMarket) => {
+      const next = self.next();
+      next.accepted = true;
+      const pot = Number(self.stake * 2n);
+      const tx = await addFunding(callTx(self, [stateOutput(next, pot)]), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: pot }] };
+    });
+    const result = await market.methods.accept(sigFrom(noPlayer), {
+      pubKeyOrAddrToSign: noPlayer.publicKey,
+    } as MethodCallOptions<PredictionMarket>);
+    return result.next!.instance;
+  }
+
+  function settle(current: PredictionMarket, msg: ByteString, winner: TestKey) {
+    current.bindTxBuilder("settle", async (self: PredictionMarket) => ({
+      tx: callTx(self, [payTo(winner.pkh, self.balance)]),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return current.methods.settle(msg, sign(msg));
+  }
+
+  it("settle() pays the right player for any event", async () => {
+    await check(fc.tuple(eventId, outcome), async ([id, result]) => {
+      const msg = id + (result === "yes" ? "01" : "00");
+      const winner = result === "yes" ? yesPlayer : noPlayer;
+      const call = await settle(await accepted(id), msg, winner);
+      expectValid(call);
+      expect(call.tx.outputs[0].script.toHex()).to.eq(Utils.buildPublicKeyHashScript(winner.pkh));
+    });
+  });
+
+  it("settle() rejects attestations for any other event", async () => {
+    const cases = fc.tuple(eventId, eventId, outcome).filter(([id, other]) => id !== other);
+    await check(cases, async ([id, other, result]) => {
+      const msg = other + (result === "yes" ? "01" : "00");
+      await expect(settle(await accepted(id), msg, yesPlayer)).to.be.rejectedWith(/Attestation is for another event/);
+    });
+  });
+
+  it("settle() rejects oracle messages that aren't 33 bytes", async () => {
+    const wrongLength = fc.oneof(byteString(1, 32), byteString(34, 64));
+    await check(fc.tuple(eventId, wrongLength), async ([id, msg]) => {
+      await expect(settle(await accepted(id), msg, yesPlayer)).to.be.rejectedWith(/Invalid oracle message/);
+    });
+  });
+});
+"#;
+
+pub(super) const ORDINAL_LISTING_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { hash160 } from "scrypt-ts";
+import OrdinalListing from "../contracts/OrdinalListing.scrypt";
+import { check } from "./utils/arbitraries";
+import { addFunding, callTx, expectValid, getDefaultSigner, payTo, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const MAX_PRICE = 50_000n;
+const price = fc.bigInt({ min: 1n, max: MAX_PRICE });
+
+describe("OrdinalListing properties", () => {
+  const seller = testKey();
+  const buyer = testKey();
+
+  before(async () => {
+    await OrdinalListing.loadArtifact("artifacts/contracts/OrdinalListing.scrypt.json");
+  });
+
+  async function purchase(listed: bigint, paid: bigint) {
+    const listing = new OrdinalListing(seller.pubKey, listed);
+    await listing.connect(getDefaultSigner(seller, buyer));
+    await listing.deploy(1);
+
+    listing.bindTxBuilder("purchase", async (self: OrdinalListing) => {
+      const outputs = [payTo(buyer.pkh, 1), payTo(hash160(self.seller), paid)];
+      return { tx: await addFunding(callTx(self, outputs), self.signer), atInputIndex: 0, nexts: [] };
+    });
+    return listing.methods.purchase(buyer.pkh);
+  }
+
+  it("purchase() accepts paying exactly the listed price", async () => {
+    await check(price, async (listed) => {
+      expectValid(await purchase(listed, listed));
+    });
+  });
+
+  it("purchase() rejects paying any other amount", async () => {
+    const cases = fc.tuple(price, price).filter(([listed, paid]) => listed !== paid);
+    await check(cases, async ([listed, paid]) => {
+      await expect(purchase(listed, paid)).to.be.rejectedWith(/outputs mismatch/);
+    });
+  });
+});
+"#;
+
+pub(super) const TOKEN_MINT_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { Addr, toByteString } from "scrypt-ts";
+import { BSV20V2 } from "scrypt-ord";
+import TokenMint from "../contracts/TokenMint.scrypt";
+import { check, scriptIntOutside } from "./utils/arbitraries";
+import { addFunding, callTx, expectValid, getDefaultSigner, outputFromBytes, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const MAX_SUPPLY = 1000n;
+const LIMIT = 100n;
+
+describe("TokenMint properties", () => {
+  const minter = testKey();
+  const dest = Addr(minter.pkh);
+
+  before(async () => {
+    await TokenMint.loadArtifact("artifacts/contracts/TokenMint.scrypt.json");
+  });
+
+  async function mint(amount: bigint) {
+    const token = new TokenMint(toByteString(""), toByteString("TEST", true), MAX_SUPPLY, 0n, LIMIT);
+    await token.connect(getDefaultSigner(minter));
+    await token.deployToken();
+
+    // Non-positive amounts still build a 1-token transfer - the contract
+    // rejects them first
+    const minted = amount > 0n ? amount : 1n;
+    token.bindTxBuilder("mint", async (self: TokenMint) => {
+      const supply = self.supply - minted;
+      const outputs = [];
+      const nexts = [];
+      if (supply > 0n) {
+        const next = self.next();
+        next.supply = supply;
+        outputs.push(outputFromBytes(next.buildStateOutputFT(supply)));
+        nexts.push({ instance: next, atOutputIndex: 0, balance: 1 });
+      }
+      outputs.push(outputFromBytes(BSV20V2.buildTransferOutput(dest, self.id, minted)));
+
+      const tx = await addFunding(callTx(self, outputs), self.signer);
+      return { tx, atInputIndex: 0, nexts };
+    });
+    return token.methods.mint(dest, amount);
+  }
+
+  it("mint() releases any amount up to the limit", async () => {
+    await check(fc.bigInt({ min: 1n, max: LIMIT }), async (amount) => {
+      const result = await mint(amount);
+      expectValid(result);
+      expect(result.next!.instance.supply).to.eq(MAX_SUPPLY - amount);
+    });
+  });
+
+  it("mint() rejects every amount outside (0, limit]", async () => {
+    await check(scriptIntOutside(1n, LIMIT), async (amount) => {
+      await expect(mint(amount)).to.be.rejectedWith(/Mint amount must be positive|Mint amount exceeds limit/);
+    });
+  });
+});
+"#;
+
+pub(super) const PAYMENT_CHANNEL_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { MethodCallOptions } from "scrypt-ts";
+import PaymentChannel from "../contracts/PaymentChannel.scrypt";
+import { blockHeight, check, clampSats, scriptIntOutside } from "./utils/arbitraries";
+import { callTx, expectValid, getDefaultSigner, payTo, sigFrom, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const DEPOSIT = 10_000n;
+const FEE = 500n;
+const AVAILABLE = DEPOSIT - FEE;
+const EXPIRY = 800_000;
+
+describe("PaymentChannel properties", () => {
+  const sessionKey = testKey();
+  const sender = testKey();
+  const receiver = testKey();
+
+  before(async () => {
+    await PaymentChannel.loadArtifact("artifacts/contracts/PaymentChannel.scrypt.json");
+  });
+
+  async function deploy() {
+    const channel = new PaymentChannel(sessionKey.pubKey, sender.pkh, receiver.pubKey, FEE, BigInt(EXPIRY));
+    await channel.connect(getDefaultSigner(sessionKey, receiver));
+    await channel.deploy(Number(DEPOSIT));
+    return channel;
+  }
+
+  async function close(amount: bigint) {
+    const channel = await deploy();
+    channel.bindTxBuilder("close", async (self: PaymentChannel) => {
+      const paid = clampSats(amount, AVAILABLE);
+      const outputs = [payTo(receiver.pkh, paid)];
+      if (paid < AVAILABLE) {
+        outputs.push(payTo(self.senderPkh, Number(AVAILABLE) - paid));
+      }
+      return { tx: callTx(self, outputs), atInputIndex: 0, nexts: [] };
+    });
+    return channel.methods.close(amount, sigFrom(sessionKey), sigFrom(receiver), {
+      pubKeyOrAddrToSign: [sessionKey.publicKey, receiver.publicKey],
+    } as MethodCallOptions<PaymentChannel>);
+  }
+
+  async function refund(lockTime: number) {
+    const channel = await deploy();
+    channel.bindTxBuilder("refund", async (self: PaymentChannel) => ({
+      tx: callTx(self, [payTo(self.senderPkh, BigInt(self.balance) - self.fee)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return channel.methods.refund();
+  }
+
+  it("close() pays out any amount the deposit covers", async () => {
+    await check(fc.bigInt({ min: 1n, max: AVAILABLE }), async (amount) => {
+      const result = await close(amount);
+      expectValid(result);
+      expect(result.tx.outputs[0].satoshis).to.eq(Number(amount));
+    });
+  });
+
+  it("close() rejects every amount outside (0, deposit - fee]", async () => {
+    await check(scriptIntOutside(1n, AVAILABLE), async (amount) => {
+      await expect(close(amount)).to.be.rejectedWith(/Invalid amount/);
+    });
+  });
+
+  it("refund() opens exactly at the expiry height", async () => {
+    await check(blockHeight(), async (lockTime) => {
+      if (lockTime >= EXPIRY) {
+        expectValid(await refund(lockTime));
+      } else {
+        await expect(refund(lockTime)).to.be.rejectedWith(/Channel not expired/);
+      }
+    });
+  });
+});
+"#;
+
+pub(super) const ESCROW_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import { hash160, MethodCallOptions, toByteString } from "scrypt-ts";
+import Escrow from "../contracts/Escrow.scrypt";
+import { check, clampSats, scriptIntOutside } from "./utils/arbitraries";
+import {
+  TestKey,
+  addFunding,
+  callTx,
+  expectValid,
+  getDefaultSigner,
+  payTo,
+  sigFrom,
+  stateOutput,
+  testKey,
+} from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+const PRICE = 5000n;
+const CHECKOUT_HEIGHT = 800_000;
+// Listing sat + the buyer's payment
+const TOTAL = 1n + PRICE;
+
+describe("Escrow properties", () => {
+  const seller = testKey();
+  const buyer = testKey();
+  const arbiter = testKey();
+
+  before(async () => {
+    await Escrow.loadArtifact("artifacts/contracts/Escrow.scrypt.json");
+  });
+
+  const sign = (key: TestKey) => ({ pubKeyOrAddrToSign: key.publicKey }) as MethodCallOptions<Escrow>;
+
+  // A paid listing the buyer disputed
+  async function disputed() {
+    const listing = new Escrow(seller.pubKey, arbiter.pubKey, toByteString("Vintage camera", true), PRICE, 432n);
+    await listing.connect(getDefaultSigner(seller, buyer, arbiter));
+    await listing.deploy(1);
+
+    listing.bindTxBuilder("checkout", async (self: Escrow) => {
+      const next = self.next();
+      next.buyer = buyer.pubKey;
+      next.status = 1n;
+      next.deadline = BigInt(CHECKOUT_HEIGHT) + self.reviewBlocks;
+      const satoshis = self.balance + Number(self.price);
+      const tx = await addFunding(callTx(self, [stateOutput(next, satoshis)], CHECKOUT_HEIGHT), self.signer);
+      return { tx, atInputIndex: 0, nexts: [{ instance: next, atOutputIndex: 0, balance: satoshis }] };
+    });
+    const paid = (await listing.methods.checkout(buyer.pubKey, sigFrom(buyer), sign(buyer))).next!.instance;
+
+    paid.bindTxBuilder("dispute", async (self: Escrow) => {
+      const next = self.next();
+      next.status = 2n;
+      return {
+        tx: callTx(self, [stateOutput(next, self.balance)]),
+        atInputIndex: 0,
+        nexts: [{ instance: next, atOutputIndex: 0, balance: self.balance }],
+      };
+    });
+    return (await paid.methods.dispute(sigFrom(buyer), true, sign(buyer))).next!.instance;
+  }
+
+  function resolve(current: Escrow, sellerAmount: bigint) {
+    current.bindTxBuilder("resolve", async (self: Escrow) => {
+      const total = self.balance;
+      const toSeller = clampSats(sellerAmount, total);
+      const outputs = [];
+      if (toSeller > 0) {
+        outputs.push(payTo(hash160(self.seller), toSeller));
+      }
+      if (toSeller < total) {
+        outputs.push(payTo(hash160(self.buyer), total - toSeller));
+      }
+      return { tx: callTx(self, outputs), atInputIndex: 0, nexts: [] };
+    });
+    return current.methods.resolve(sigFrom(arbiter), sellerAmount, sign(arbiter));
+  }
+
+  it("resolve() accepts any split of the escrow", async () => {
+    await check(fc.bigInt({ min: 0n, max: TOTAL }), async (sellerAmount) => {
+      expectValid(await resolve(await disputed(), sellerAmount));
+    });
+  });
+
+  it("resolve() rejects every split outside [0, escrow]", async () => {
+    await check(scriptIntOutside(0n, TOTAL), async (sellerAmount) => {
+      await expect(resolve(await disputed(), sellerAmount)).to.be.rejectedWith(/Invalid split/);
+    });
+  });
+});
+"#;
+
+pub(super) const PLAYGROUND_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import fc from "fast-check";
+import Playground from "../contracts/Playground.scrypt";
+import { check, scriptInt } from "./utils/arbitraries";
+import { expectValid, getDefaultSigner, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+// Roots small enough that their squares stay in the int64 range
+const MAX_ROOT = 3_037_000_499n;
+
+describe("Playground properties", () => {
+  const owner = testKey();
+
+  before(async () => {
+    await Playground.loadArtifact("artifacts/contracts/Playground.scrypt.json");
+  });
+
+  async function deploy(square: bigint) {
+    const instance = new Playground(square);
+    await instance.connect(getDefaultSigner(owner));
+    await instance.deploy(1);
+    return instance;
+  }
+
+  it("unlock() accepts the root of any square", async () => {
+    await check(scriptInt(-MAX_ROOT, MAX_ROOT), async (root) => {
+      expectValid(await (await deploy(root * root)).methods.unlock(root));
+    });
+  });
+
+  it("unlock() rejects every other number", async () => {
+    const pair = fc
+      .tuple(scriptInt(0n, MAX_ROOT), scriptInt(-MAX_ROOT, MAX_ROOT))
+      .filter(([root, guess]) => guess !== root && guess !== -root);
+    await check(pair, async ([root, guess]) => {
+      await expect((await deploy(root * root)).methods.unlock(guess)).to.be.rejectedWith(/Not a square root/);
+    });
+  });
+});
+"#;
+
+pub(super) const CUSTOM_CONTRACT_PROPERTY_TEST: &str = r#"import { expect, use } from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { hash160, MethodCallOptions } from "scrypt-ts";
+import Contract from "../contracts/Contract.scrypt";
+import { blockHeight, check } from "./utils/arbitraries";
+import { callTx, expectValid, getDefaultSigner, payTo, sigFrom, testKey } from "./utils/txHelper";
+
+use(chaiAsPromised);
+
+// Starting point - add properties as contracts/Contract.scrypt.ts grows
+
+const TIMEOUT = 800_000;
+
+describe("Contract properties", () => {
+  const player1 = testKey();
+  const player2 = testKey();
+
+  before(async () => {
+    await Contract.loadArtifact("artifacts/contracts/Contract.scrypt.json");
+  });
+
+  async function claimAfterTimeout(lockTime: number) {
+    const instance = new Contract(player1.pubKey, player2.pubKey, BigInt(TIMEOUT));
+    await instance.connect(getDefaultSigner(player1, player2));
+    await instance.deploy(1000);
+
+    instance.bindTxBuilder("claimAfterTimeout", async (self: Contract) => ({
+      tx: callTx(self, [payTo(hash160(self.player1), self.balance)], lockTime),
+      atInputIndex: 0,
+      nexts: [],
+    }));
+    return instance.methods.claimAfterTimeout(sigFrom(player1), {
+      pubKeyOrAddrToSign: player1.publicKey,
+    } as MethodCallOptions<Contract>);
+  }
+
+  it("claimAfterTimeout() opens exactly at the timeout height", async () => {
+    await check(blockHeight(), async (lockTime) => {
+      if (lockTime >= TIMEOUT) {
+        expectValid(await claimAfterTimeout(lockTime));
+      } else {
+        await expect(claimAfterTimeout(lockTime)).to.be.rejectedWith(/timeout not reached/);
+      }
+    });
+  });
+});
+"#;
@@ -0,0 +1,298 @@
+//! The React frontend, built with Vite.
+
+use crate::Template;
+
+use super::{
+    contracts::contract_filename,
+    frontends::react_game_component,
+    shared::{POSTCSS_CONFIG, TAILWIND_CONFIG, TAILWIND_CSS, TSCONFIG_NODE, VITE_ENV_DTS},
+    wallet::{CONFIG_SERVICE, PANDA_SIGNER_SERVICE, WALLET_UTILS, YOURS_DIRECT_SIGNER},
+};
+
+pub fn react_files(template: Template) -> Vec<(&'static str, String)> {
+    let contract_name = contract_filename(template);
+    vec![
+        ("src/main.tsx", REACT_MAIN.to_string()),
+        ("src/App.tsx", format!(r#"import {{ useState, useEffect }} from "react";
+import {{ WalletProvider }} from "./components/WalletProvider";
+import {{ Game }} from "./components/Game";
+import "./index.css";
+
+export default function App() {{
+  return (
+    <WalletProvider>
+      <div className="min-h-screen bg-slate-900 text-slate-100">
+        <header className="border-b border-slate-700 p-4">
+          <div className="max-w-4xl mx-auto flex justify-between items-center">
+            <h1 className="text-2xl font-bold">Bitcoin App</h1>
+            <div id="wallet-button"></div>
+          </div>
+        </header>
+        <main className="max-w-4xl mx-auto p-4">
+          <Game contractName="{contract_name}" />
+        </main>
+      </div>
+    </WalletProvider>
+  );
+}}
+"#)),
+        ("src/components/WalletProvider.tsx", REACT_WALLET_PROVIDER.to_string()),
+        ("src/components/WalletButton.tsx", REACT_WALLET_BUTTON.to_string()),
+        ("src/components/Game.tsx", react_game_component(template).to_string()),
+        ("src/lib/wallet.ts", WALLET_UTILS.to_string()),
+        ("src/services/pandaSignerService.ts", PANDA_SIGNER_SERVICE.to_string()),
+        ("src/services/yoursWalletDirect.ts", YOURS_DIRECT_SIGNER.to_string()),
+        ("src/services/config.ts", CONFIG_SERVICE.to_string()),
+        ("src/index.css", TAILWIND_CSS.to_string()),
+        ("src/vite-env.d.ts", VITE_ENV_DTS.to_string()),
+        ("index.html", REACT_INDEX_HTML.to_string()),
+        ("vite.config.ts", REACT_VITE_CONFIG.to_string()),
+        ("tsconfig.json", REACT_TSCONFIG.to_string()),
+        ("tsconfig.node.json", TSCONFIG_NODE.to_string()),
+        ("tailwind.config.js", TAILWIND_CONFIG.to_string()),
+        ("postcss.config.js", POSTCSS_CONFIG.to_string()),
+        ("package.json", react_package_json()),
+    ]
+}
+
+const REACT_MAIN: &str = r#"import React from "react";
+import ReactDOM from "react-dom/client";
+import App from "./App";
+import "./index.css";
+
+ReactDOM.createRoot(document.getElementById("root")!).render(
+  <React.StrictMode>
+    <App />
+  </React.StrictMode>
+);
+"#;
+
+const REACT_WALLET_PROVIDER: &str = r#"import { createContext, useContext, useState, useEffect, ReactNode } from "react";
+import { WalletState, createInitialWalletState, getStoredAuth, disconnectWallet, connectYoursWallet } from "../lib/wallet";
+
+interface WalletContextType {
+  wallet: WalletState;
+  connect: () => void;
+  disconnect: () => void;
+}
+
+const WalletContext = createContext<WalletContextType | null>(null);
+
+export function WalletProvider({ children }: { children: ReactNode }) {
+  const [wallet, setWallet] = useState<WalletState>(createInitialWalletState());
+
+  useEffect(() => {
+    // Check for stored auth
+    const stored = getStoredAuth();
+    if (stored) {
+      setWallet({
+        connected: true,
+        address: stored.address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType: stored.walletType,
+      });
+      return;
+    }
+  }, []);
+
+  const connect = async () => {
+    setWallet(prev => ({ ...prev, loading: true, error: null }));
+    try {
+      const res = await connectYoursWallet();
+      setWallet({
+        connected: true,
+        address: res.address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType: res.walletType,
+      });
+    } catch (err: any) {
+      setWallet(prev => ({ 
+        ...prev, 
+        loading: false, 
+        error: err?.message || "Failed to connect Yours Wallet" 
+      }));
+    }
+  };
+
+  const disconnect = () => {
+    disconnectWallet();
+    setWallet(createInitialWalletState());
+  };
+
+  return (
+    <WalletContext.Provider value={{ wallet, connect, disconnect }}>
+      {children}
+    </WalletContext.Provider>
+  );
+}
+
+export function useWallet() {
+  const context = useContext(WalletContext);
+  if (!context) throw new Error("useWallet must be used within WalletProvider");
+  return context;
+}
+"#;
+
+pub(super) const REACT_WALLET_BUTTON: &str = r#"import { useWallet } from "./WalletProvider";
+
+export function WalletButton() {
+  const { wallet, connect, disconnect } = useWallet();
+
+  if (wallet.loading) {
+    return <button className="btn" disabled>Connecting...</button>;
+  }
+
+  if (wallet.connected) {
+    return (
+      <div className="flex items-center gap-2">
+        <span className="text-sm text-slate-400">{wallet.address}</span>
+        <button onClick={disconnect} className="btn btn-secondary">
+          Disconnect
+        </button>
+      </div>
+    );
+  }
+
+  return (
+    <button onClick={connect} className="btn btn-primary">
+      Connect Yours Wallet
+    </button>
+  );
+}
+"#;
+
+pub(super) const REACT_GAME_COMPONENT: &str = r#"import { useWallet } from "./WalletProvider";
+import { WalletButton } from "./WalletButton";
+
+interface GameProps {
+  contractName: string;
+}
+
+export function Game({ contractName }: GameProps) {
+  const { wallet } = useWallet();
+
+  return (
+    <div className="space-y-8">
+      <div className="flex justify-between items-center">
+        <h2 className="text-xl font-semibold">Game</h2>
+        <WalletButton />
+      </div>
+
+      {!wallet.connected ? (
+        <div className="text-center py-12 bg-slate-800 rounded-lg">
+          <p className="text-slate-400 mb-4">Connect your wallet to play</p>
+        </div>
+      ) : (
+        <div className="bg-slate-800 rounded-lg p-6">
+          <p className="text-slate-400 mb-4">Contract: {contractName}</p>
+          {/* TODO: Implement game UI here */}
+          <p className="text-center text-slate-500">
+            Game interface will be implemented here
+          </p>
+        </div>
+      )}
+    </div>
+  );
+}
+"#;
+
+const REACT_INDEX_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <link rel="icon" type="image/svg+xml" href="/vite.svg" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>Bitcoin App</title>
+  </head>
+  <body class="bg-slate-900">
+    <div id="root"></div>
+    <script type="module" src="/src/main.tsx"></script>
+  </body>
+</html>
+"#;
+
+const REACT_VITE_CONFIG: &str = r#"import { defineConfig } from "vite";
+import react from "@vitejs/plugin-react";
+
+export default defineConfig({
+  plugins: [react()],
+  server: {
+    port: 5173,
+  },
+});
+"#;
+
+const REACT_TSCONFIG: &str = r#"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "useDefineForClassFields": true,
+    "lib": ["ES2020", "DOM", "DOM.Iterable"],
+    "module": "ESNext",
+    "skipLibCheck": true,
+    "moduleResolution": "bundler",
+    "allowImportingTsExtensions": true,
+    "resolveJsonModule": true,
+    "isolatedModules": true,
+    "noEmit": true,
+    "jsx": "react-jsx",
+    "strict": true,
+    "noUnusedLocals": true,
+    "noUnusedParameters": true,
+    "noFallthroughCasesInSwitch": true
+  },
+  "include": ["src"],
+  "references": [{ "path": "./tsconfig.node.json" }]
+}
+"#;
+
+fn react_package_json() -> String {
+    r#"{
+  "name": "bitcoin-app",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "dev": "vite",
+    "build": "tsc && vite build",
+    "preview": "vite preview",
+    "pretest": "npx scrypt-cli compile",
+    "test": "mocha",
+    "deploy": "npx scrypt-cli compile && ts-node --project tsconfig.test.json scripts/deploy.ts",
+    "use:testnet": "node scripts/use-network.mjs testnet",
+    "use:mainnet": "node scripts/use-network.mjs mainnet"
+  },
+  "dependencies": {
+    "react": "^18.2.0",
+    "react-dom": "^18.2.0",
+    "scrypt-ts": "^1.3.0",
+    "scrypt-ts-lib": "^0.1.28",
+    "scrypt-ord": "^1.0.0"
+  },
+  "devDependencies": {
+    "@types/chai": "^4.3.0",
+    "@types/chai-as-promised": "^7.1.0",
+    "@types/mocha": "^10.0.0",
+    "@types/node": "^20.0.0",
+    "@types/react": "^18.2.0",
+    "@types/react-dom": "^18.2.0",
+    "@vitejs/plugin-react": "^4.2.0",
+    "autoprefixer": "^10.4.0",
+    "chai": "^4.3.0",
+    "chai-as-promised": "^7.1.0",
+    "dotenv": "^16.3.0",
+    "fast-check": "^3.15.0",
+    "mocha": "^10.2.0",
+    "postcss": "^8.4.0",
+    "rabinsig": "^1.0.0",
+    "tailwindcss": "^3.4.0",
+    "ts-node": "^10.9.0",
+    "typescript": "^5.3.0",
+    "vite": "^5.0.0"
+  }
+}
+"#.to_string()
+}
@@ -0,0 +1,82 @@
+//! Styling and TypeScript config shared by the frontends.
+
+// SHARED TEMPLATES
+// ============================================================================
+
+pub(super) const TAILWIND_CSS: &str = r#"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+
+@layer components {
+  .btn {
+    @apply px-4 py-2 rounded-lg font-medium transition-colors;
+  }
+  .btn-primary {
+    @apply bg-emerald-600 hover:bg-emerald-700 text-white;
+  }
+  .btn-secondary {
+    @apply bg-slate-700 hover:bg-slate-600 text-slate-100;
+  }
+}
+"#;
+
+pub(super) const TAILWIND_CONFIG: &str = r#"/** @type {import('tailwindcss').Config} */
+export default {
+  content: [
+    "./index.html",
+    "./src/**/*.{js,ts,jsx,tsx,vue,svelte}",
+  ],
+  theme: {
+    extend: {},
+  },
+  plugins: [],
+};
+"#;
+
+pub(super) const POSTCSS_CONFIG: &str = r#"export default {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+};
+"#;
+
+pub(super) const TSCONFIG_NODE: &str = r#"{
+  "compilerOptions": {
+    "composite": true,
+    "skipLibCheck": true,
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "allowSyntheticDefaultImports": true,
+    "strict": true
+  },
+  "include": ["vite.config.ts"]
+}
+"#;
+
+pub(super) const VITE_ENV_DTS: &str = r#"/// <reference types="vite/client" />
+
+interface ImportMetaEnv {
+  readonly VITE_DOTWALLET_CLIENT_ID: string;
+  readonly VITE_DOTWALLET_REDIRECT_URI: string;
+  readonly VITE_OAUTH_BACKEND_URL: string;
+  readonly VITE_BSV_NETWORK?: string;
+  readonly VITE_EXPLORER_URL?: string;
+  readonly VITE_WOC_API_URL?: string;
+  readonly VITE_FEE_PER_KB?: string;
+  readonly VITE_ARTIFACT_PATH?: string;
+  readonly VITE_ORACLE_URL?: string;
+  readonly VITE_RELAY_URL?: string;
+  readonly VITE_INDEXER_URL?: string;
+  readonly VITE_MULTIPLAYER_URL?: string;
+  readonly VITE_HANDCASH_URL?: string;
+  readonly VITE_PLAYGROUND_URL?: string;
+  readonly VITE_API_URL?: string;
+}
+
+interface ImportMeta {
+  readonly env: ImportMetaEnv;
+}
+"#;
+
+// ============================================================================
@@ -0,0 +1,259 @@
+//! The Svelte frontend, built with Vite.
+
+use crate::Template;
+
+use super::{
+    contracts::contract_filename,
+    frontends::svelte_game_component,
+    shared::{POSTCSS_CONFIG, TAILWIND_CONFIG, TAILWIND_CSS, TSCONFIG_NODE, VITE_ENV_DTS},
+    wallet::{CONFIG_SERVICE, PANDA_SIGNER_SERVICE, WALLET_UTILS, YOURS_DIRECT_SIGNER},
+};
+
+pub fn svelte_files(template: Template) -> Vec<(&'static str, String)> {
+    let contract_name = contract_filename(template);
+    vec![
+        ("src/main.ts", SVELTE_MAIN.to_string()),
+        ("src/App.svelte", format!(r#"<script lang="ts">
+  import {{ wallet, connect, disconnect }} from "./stores/wallet";
+  import WalletButton from "./components/WalletButton.svelte";
+  import Game from "./components/Game.svelte";
+</script>
+
+<div class="min-h-screen bg-slate-900 text-slate-100">
+  <header class="border-b border-slate-700 p-4">
+    <div class="max-w-4xl mx-auto flex justify-between items-center">
+      <h1 class="text-2xl font-bold">Bitcoin App</h1>
+      <WalletButton />
+    </div>
+  </header>
+  <main class="max-w-4xl mx-auto p-4">
+    <Game contractName="{contract_name}" />
+  </main>
+</div>
+"#)),
+        ("src/components/WalletButton.svelte", SVELTE_WALLET_BUTTON.to_string()),
+        ("src/components/Game.svelte", svelte_game_component(template).to_string()),
+        ("src/stores/wallet.ts", SVELTE_WALLET_STORE.to_string()),
+        ("src/lib/wallet.ts", WALLET_UTILS.to_string()),
+        ("src/services/pandaSignerService.ts", PANDA_SIGNER_SERVICE.to_string()),
+        ("src/services/yoursWalletDirect.ts", YOURS_DIRECT_SIGNER.to_string()),
+        ("src/services/config.ts", CONFIG_SERVICE.to_string()),
+        ("src/app.css", TAILWIND_CSS.to_string()),
+        ("src/vite-env.d.ts", VITE_ENV_DTS.to_string()),
+        ("index.html", SVELTE_INDEX_HTML.to_string()),
+        ("vite.config.ts", SVELTE_VITE_CONFIG.to_string()),
+        ("svelte.config.js", SVELTE_CONFIG.to_string()),
+        ("tsconfig.json", SVELTE_TSCONFIG.to_string()),
+        ("tsconfig.node.json", TSCONFIG_NODE.to_string()),
+        ("tailwind.config.js", TAILWIND_CONFIG.to_string()),
+        ("postcss.config.js", POSTCSS_CONFIG.to_string()),
+        ("package.json", svelte_package_json()),
+    ]
+}
+
+const SVELTE_MAIN: &str = r#"import "./app.css";
+import App from "./App.svelte";
+
+const app = new App({
+  target: document.getElementById("app")!,
+});
+
+export default app;
+"#;
+
+const SVELTE_WALLET_STORE: &str = r#"import { writable } from "svelte/store";
+import {
+  WalletState,
+  createInitialWalletState,
+  getStoredAuth,
+  disconnectWallet,
+  connectYoursWallet,
+} from "../lib/wallet";
+
+function createWalletStore() {
+  const { subscribe, set, update } = writable<WalletState>(createInitialWalletState());
+
+  // Initialize on load
+  if (typeof window !== "undefined") {
+    const stored = getStoredAuth();
+    if (stored) {
+      set({
+        connected: true,
+        address: stored.address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType: stored.walletType,
+      });
+    }
+  }
+
+  return {
+    subscribe,
+    connect: async () => {
+      update(w => ({ ...w, loading: true, error: null }));
+      try {
+        const res = await connectYoursWallet();
+        set({
+          connected: true,
+          address: res.address,
+          balance: 0,
+          loading: false,
+          error: null,
+          walletType: res.walletType,
+        });
+      } catch (err: any) {
+        update(w => ({ ...w, loading: false, error: err?.message || "Failed to connect" }));
+      }
+    },
+    disconnect: () => {
+      disconnectWallet();
+      set(createInitialWalletState());
+    },
+  };
+}
+
+export const wallet = createWalletStore();
+export const { connect, disconnect } = wallet;
+"#;
+
+const SVELTE_WALLET_BUTTON: &str = r#"<script lang="ts">
+  import { wallet, connect, disconnect } from "../stores/wallet";
+</script>
+
+{#if $wallet.loading}
+  <button class="btn" disabled>Connecting...</button>
+{:else if $wallet.connected}
+  <div class="flex items-center gap-2">
+    <span class="text-sm text-slate-400">{$wallet.address}</span>
+    <button on:click={disconnect} class="btn btn-secondary">Disconnect</button>
+  </div>
+{:else}
+  <button on:click={connect} class="btn btn-primary">Connect Yours Wallet</button>
+{/if}
+"#;
+
+pub(super) const SVELTE_GAME_COMPONENT: &str = r#"<script lang="ts">
+  import { wallet } from "../stores/wallet";
+  import WalletButton from "./WalletButton.svelte";
+
+  export let contractName: string;
+</script>
+
+<div class="space-y-8">
+  <div class="flex justify-between items-center">
+    <h2 class="text-xl font-semibold">Game</h2>
+    <WalletButton />
+  </div>
+
+  {#if !$wallet.connected}
+    <div class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+  {:else}
+    <div class="bg-slate-800 rounded-lg p-6">
+      <p class="text-slate-400 mb-4">Contract: {contractName}</p>
+      <!-- TODO: Implement game UI here -->
+      <p class="text-center text-slate-500">Game interface will be implemented here</p>
+    </div>
+  {/if}
+</div>
+"#;
+
+const SVELTE_INDEX_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <link rel="icon" type="image/svg+xml" href="/vite.svg" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>Bitcoin App</title>
+  </head>
+  <body class="bg-slate-900">
+    <div id="app"></div>
+    <script type="module" src="/src/main.ts"></script>
+  </body>
+</html>
+"#;
+
+const SVELTE_VITE_CONFIG: &str = r#"import { defineConfig } from "vite";
+import { svelte } from "@sveltejs/vite-plugin-svelte";
+
+export default defineConfig({
+  plugins: [svelte()],
+  server: {
+    port: 5173,
+  },
+});
+"#;
+
+const SVELTE_CONFIG: &str = r#"import { vitePreprocess } from "@sveltejs/vite-plugin-svelte";
+
+export default {
+  preprocess: vitePreprocess(),
+};
+"#;
+
+const SVELTE_TSCONFIG: &str = r#"{
+  "extends": "@tsconfig/svelte/tsconfig.json",
+  "compilerOptions": {
+    "target": "ESNext",
+    "useDefineForClassFields": true,
+    "module": "ESNext",
+    "resolveJsonModule": true,
+    "allowJs": true,
+    "checkJs": true,
+    "isolatedModules": true,
+    "moduleDetection": "force"
+  },
+  "include": ["src/**/*.ts", "src/**/*.js", "src/**/*.svelte"],
+  "references": [{ "path": "./tsconfig.node.json" }]
+}
+"#;
+
+fn svelte_package_json() -> String {
+    r#"{
+  "name": "bitcoin-app",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "dev": "vite",
+    "build": "vite build",
+    "preview": "vite preview",
+    "pretest": "npx scrypt-cli compile",
+    "test": "mocha",
+    "deploy": "npx scrypt-cli compile && ts-node --project tsconfig.test.json scripts/deploy.ts",
+    "use:testnet": "node scripts/use-network.mjs testnet",
+    "use:mainnet": "node scripts/use-network.mjs mainnet"
+  },
+  "dependencies": {
+    "scrypt-ts": "^1.3.0",
+    "scrypt-ts-lib": "^0.1.28",
+    "scrypt-ord": "^1.0.0"
+  },
+  "devDependencies": {
+    "@sveltejs/vite-plugin-svelte": "^3.0.0",
+    "@tsconfig/svelte": "^5.0.0",
+    "@types/chai": "^4.3.0",
+    "@types/chai-as-promised": "^7.1.0",
+    "@types/mocha": "^10.0.0",
+    "@types/node": "^20.0.0",
+    "autoprefixer": "^10.4.0",
+    "chai": "^4.3.0",
+    "chai-as-promised": "^7.1.0",
+    "dotenv": "^16.3.0",
+    "fast-check": "^3.15.0",
+    "mocha": "^10.2.0",
+    "postcss": "^8.4.0",
+    "rabinsig": "^1.0.0",
+    "svelte": "^4.2.0",
+    "svelte-check": "^3.6.0",
+    "tailwindcss": "^3.4.0",
+    "ts-node": "^10.9.0",
+    "tslib": "^2.6.0",
+    "typescript": "^5.3.0",
+    "vite": "^5.0.0"
+  }
+}
+"#.to_string()
+}
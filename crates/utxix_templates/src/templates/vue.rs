@@ -0,0 +1,259 @@
+//! The Vue frontend, built with Vite.
+
+use crate::Template;
+
+use super::{
+    contracts::contract_filename,
+    frontends::vue_game_component,
+    shared::{POSTCSS_CONFIG, TAILWIND_CONFIG, TAILWIND_CSS, TSCONFIG_NODE, VITE_ENV_DTS},
+    wallet::{CONFIG_SERVICE, PANDA_SIGNER_SERVICE, WALLET_UTILS, YOURS_DIRECT_SIGNER},
+};
+
+pub fn vue_files(template: Template) -> Vec<(&'static str, String)> {
+    let contract_name = contract_filename(template);
+    vec![
+        ("src/main.ts", VUE_MAIN.to_string()),
+        ("src/App.vue", format!(r#"<script setup lang="ts">
+import {{ provide }} from "vue";
+import {{ useWallet }} from "./composables/useWallet";
+import WalletButton from "./components/WalletButton.vue";
+import Game from "./components/Game.vue";
+
+const wallet = useWallet();
+provide("wallet", wallet);
+</script>
+
+<template>
+  <div class="min-h-screen bg-slate-900 text-slate-100">
+    <header class="border-b border-slate-700 p-4">
+      <div class="max-w-4xl mx-auto flex justify-between items-center">
+        <h1 class="text-2xl font-bold">Bitcoin App</h1>
+        <WalletButton />
+      </div>
+    </header>
+    <main class="max-w-4xl mx-auto p-4">
+      <Game contract-name="{contract_name}" />
+    </main>
+  </div>
+</template>
+"#)),
+        ("src/components/WalletButton.vue", VUE_WALLET_BUTTON.to_string()),
+        ("src/components/Game.vue", vue_game_component(template).to_string()),
+        ("src/composables/useWallet.ts", VUE_USE_WALLET.to_string()),
+        ("src/lib/wallet.ts", WALLET_UTILS.to_string()),
+        ("src/services/pandaSignerService.ts", PANDA_SIGNER_SERVICE.to_string()),
+        ("src/services/yoursWalletDirect.ts", YOURS_DIRECT_SIGNER.to_string()),
+        ("src/services/config.ts", CONFIG_SERVICE.to_string()),
+        ("src/style.css", TAILWIND_CSS.to_string()),
+        ("src/vite-env.d.ts", VITE_ENV_DTS.to_string()),
+        ("index.html", VUE_INDEX_HTML.to_string()),
+        ("vite.config.ts", VUE_VITE_CONFIG.to_string()),
+        ("tsconfig.json", VUE_TSCONFIG.to_string()),
+        ("tsconfig.node.json", TSCONFIG_NODE.to_string()),
+        ("tailwind.config.js", TAILWIND_CONFIG.to_string()),
+        ("postcss.config.js", POSTCSS_CONFIG.to_string()),
+        ("package.json", vue_package_json()),
+    ]
+}
+
+const VUE_MAIN: &str = r##"import { createApp } from "vue";
+import App from "./App.vue";
+import "./style.css";
+
+createApp(App).mount("#app");
+"##;
+
+const VUE_USE_WALLET: &str = r#"import { ref, onMounted } from "vue";
+import {
+  WalletState,
+  createInitialWalletState,
+  getStoredAuth,
+  disconnectWallet,
+  connectYoursWallet,
+} from "../lib/wallet";
+
+export function useWallet() {
+  const wallet = ref<WalletState>(createInitialWalletState());
+
+  onMounted(async () => {
+    // Check for stored auth
+    const stored = getStoredAuth();
+    if (stored) {
+      wallet.value = {
+        connected: true,
+        address: stored.address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType: stored.walletType,
+      };
+      return;
+    }
+  });
+
+  const connect = async () => {
+    wallet.value.loading = true;
+    wallet.value.error = null;
+    try {
+      const res = await connectYoursWallet();
+      wallet.value = {
+        connected: true,
+        address: res.address,
+        balance: 0,
+        loading: false,
+        error: null,
+        walletType: res.walletType,
+      };
+    } catch (err: any) {
+      wallet.value.loading = false;
+      wallet.value.error = err?.message || "Failed to connect Yours Wallet";
+    }
+  };
+
+  const disconnect = () => {
+    disconnectWallet();
+    wallet.value = createInitialWalletState();
+  };
+
+  return { wallet, connect, disconnect };
+}
+"#;
+
+const VUE_WALLET_BUTTON: &str = r#"<script setup lang="ts">
+import { inject } from "vue";
+
+const { wallet, connect, disconnect } = inject("wallet") as any;
+</script>
+
+<template>
+  <button v-if="wallet.loading" class="btn" disabled>Connecting...</button>
+  <div v-else-if="wallet.connected" class="flex items-center gap-2">
+    <span class="text-sm text-slate-400">{{ wallet.address }}</span>
+    <button @click="disconnect" class="btn btn-secondary">Disconnect</button>
+  </div>
+  <button v-else @click="connect" class="btn btn-primary">Connect Yours Wallet</button>
+</template>
+"#;
+
+pub(super) const VUE_GAME_COMPONENT: &str = r#"<script setup lang="ts">
+import { inject } from "vue";
+import WalletButton from "./WalletButton.vue";
+
+defineProps<{ contractName: string }>();
+
+const { wallet } = inject("wallet") as any;
+</script>
+
+<template>
+  <div class="space-y-8">
+    <div class="flex justify-between items-center">
+      <h2 class="text-xl font-semibold">Game</h2>
+      <WalletButton />
+    </div>
+
+    <div v-if="!wallet.connected" class="text-center py-12 bg-slate-800 rounded-lg">
+      <p class="text-slate-400 mb-4">Connect your wallet to play</p>
+    </div>
+    <div v-else class="bg-slate-800 rounded-lg p-6">
+      <p class="text-slate-400 mb-4">Contract: {{ contractName }}</p>
+      <!-- TODO: Implement game UI here -->
+      <p class="text-center text-slate-500">Game interface will be implemented here</p>
+    </div>
+  </div>
+</template>
+"#;
+
+const VUE_INDEX_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <link rel="icon" type="image/svg+xml" href="/vite.svg" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>Bitcoin App</title>
+  </head>
+  <body class="bg-slate-900">
+    <div id="app"></div>
+    <script type="module" src="/src/main.ts"></script>
+  </body>
+</html>
+"#;
+
+const VUE_VITE_CONFIG: &str = r#"import { defineConfig } from "vite";
+import vue from "@vitejs/plugin-vue";
+
+export default defineConfig({
+  plugins: [vue()],
+  server: {
+    port: 5173,
+  },
+});
+"#;
+
+const VUE_TSCONFIG: &str = r#"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "useDefineForClassFields": true,
+    "module": "ESNext",
+    "lib": ["ES2020", "DOM", "DOM.Iterable"],
+    "skipLibCheck": true,
+    "moduleResolution": "bundler",
+    "allowImportingTsExtensions": true,
+    "resolveJsonModule": true,
+    "isolatedModules": true,
+    "noEmit": true,
+    "jsx": "preserve",
+    "strict": true,
+    "noUnusedLocals": true,
+    "noUnusedParameters": true,
+    "noFallthroughCasesInSwitch": true
+  },
+  "include": ["src/**/*.ts", "src/**/*.tsx", "src/**/*.vue"],
+  "references": [{ "path": "./tsconfig.node.json" }]
+}
+"#;
+
+fn vue_package_json() -> String {
+    r#"{
+  "name": "bitcoin-app",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "dev": "vite",
+    "build": "vue-tsc && vite build",
+    "preview": "vite preview",
+    "pretest": "npx scrypt-cli compile",
+    "test": "mocha",
+    "deploy": "npx scrypt-cli compile && ts-node --project tsconfig.test.json scripts/deploy.ts",
+    "use:testnet": "node scripts/use-network.mjs testnet",
+    "use:mainnet": "node scripts/use-network.mjs mainnet"
+  },
+  "dependencies": {
+    "vue": "^3.4.0",
+    "scrypt-ts": "^1.3.0",
+    "scrypt-ts-lib": "^0.1.28",
+    "scrypt-ord": "^1.0.0"
+  },
+  "devDependencies": {
+    "@types/chai": "^4.3.0",
+    "@types/chai-as-promised": "^7.1.0",
+    "@types/mocha": "^10.0.0",
+    "@types/node": "^20.0.0",
+    "@vitejs/plugin-vue": "^5.0.0",
+    "autoprefixer": "^10.4.0",
+    "chai": "^4.3.0",
+    "chai-as-promised": "^7.1.0",
+    "dotenv": "^16.3.0",
+    "fast-check": "^3.15.0",
+    "mocha": "^10.2.0",
+    "postcss": "^8.4.0",
+    "rabinsig": "^1.0.0",
+    "tailwindcss": "^3.4.0",
+    "ts-node": "^10.9.0",
+    "typescript": "^5.3.0",
+    "vite": "^5.0.0",
+    "vue-tsc": "^1.8.0"
+  }
+}
+"#.to_string()
+}
@@ -0,0 +1,779 @@
+//! Wallet services shared by every framework's frontend, and the generic
+//! contract service the Custom template starts from.
+
+pub const WALLET_UTILS: &str = r#"/**
+ * Yours Wallet connection utilities for BSV blockchain
+ * 
+ * Yours Wallet (formerly Panda Wallet) is a browser extension that provides
+ * direct signing capabilities without requiring an OAuth backend.
+ */
+
+export type WalletType = "yours" | null;
+
+export interface WalletState {
+  connected: boolean;
+  address: string | null;
+  balance: number;
+  loading: boolean;
+  error: string | null;
+  walletType: WalletType;
+}
+
+const STORAGE_KEY = "bsv_wallet";
+
+/**
+ * Connect to Yours Wallet browser extension
+ */
+export async function connectYoursWallet(): Promise<{ address: string; walletType: WalletType }> {
+  // Dynamic import to avoid issues with SSR
+  const { getPandaSigner } = await import("../services/pandaSignerService");
+  const signer = await getPandaSigner(true);
+  const addr = await signer.getDefaultAddress();
+  const address = addr.toString();
+  const walletType: WalletType = "yours";
+  localStorage.setItem(STORAGE_KEY, JSON.stringify({ address, walletType }));
+  return { address, walletType };
+}
+
+/**
+ * Legacy connect function for backwards compatibility
+ */
+export async function connectWallet(): Promise<{ address: string }> {
+  return connectYoursWallet();
+}
+
+export function createInitialWalletState(): WalletState {
+  return {
+    connected: false,
+    address: null,
+    balance: 0,
+    loading: false,
+    error: null,
+    walletType: null,
+  };
+}
+
+export function getStoredAuth(): { address: string; walletType: WalletType } | null {
+  const raw = localStorage.getItem(STORAGE_KEY);
+  if (!raw) return null;
+  try {
+    const parsed = JSON.parse(raw);
+    if (parsed.walletType !== "yours") {
+      return null;
+    }
+    return parsed;
+  } catch {
+    return null;
+  }
+}
+
+export function getActiveWalletType(): WalletType {
+  const stored = getStoredAuth();
+  return stored?.walletType || null;
+}
+
+export function disconnectWallet(): void {
+  localStorage.removeItem(STORAGE_KEY);
+}
+"#;
+
+pub const CONFIG_SERVICE: &str = r#"/**
+ * Typed app configuration for the active network
+ *
+ * Values come from .env, written by `npm run use:testnet` or
+ * `npm run use:mainnet` (from .env.testnet / .env.mainnet). Import
+ * `config` instead of reading import.meta.env directly.
+ */
+
+export type Network = "testnet" | "mainnet";
+
+export interface AppConfig {
+  network: Network;
+  /** Block explorer base URL, e.g. https://test.whatsonchain.com */
+  explorerUrl: string;
+  /** WhatsOnChain API base URL for the network */
+  wocApiUrl: string;
+  /** Transaction fee rate in satoshis per kB */
+  feePerKb: number;
+  artifactPath: string;
+  oracleUrl: string;
+  relayUrl: string;
+  indexerUrl: string;
+  multiplayerUrl: string;
+  handcashUrl: string;
+  playgroundUrl: string;
+  apiUrl: string;
+}
+
+const env = import.meta.env;
+
+function parseNetwork(value: string | undefined): Network {
+  const network = (value || "testnet").trim().toLowerCase();
+  if (network !== "testnet" && network !== "mainnet") {
+    throw new Error(
+      "VITE_BSV_NETWORK must be testnet or mainnet (got " + value + ") - run npm run use:testnet",
+    );
+  }
+  return network;
+}
+
+function parseFeePerKb(value: string | undefined, fallback: number): number {
+  const fee = Number(value || fallback);
+  if (!Number.isFinite(fee) || fee <= 0) {
+    throw new Error("VITE_FEE_PER_KB must be a positive number (got " + value + ")");
+  }
+  return fee;
+}
+
+const network = parseNetwork(env.VITE_BSV_NETWORK);
+const mainnet = network === "mainnet";
+
+export const config: AppConfig = {
+  network,
+  explorerUrl: env.VITE_EXPLORER_URL || (mainnet ? "https://whatsonchain.com" : "https://test.whatsonchain.com"),
+  wocApiUrl: env.VITE_WOC_API_URL || "https://api.whatsonchain.com/v1/bsv/" + (mainnet ? "main" : "test"),
+  feePerKb: parseFeePerKb(env.VITE_FEE_PER_KB, mainnet ? 100 : 50),
+  artifactPath: env.VITE_ARTIFACT_PATH || "/artifacts/contracts/Contract.scrypt.json",
+  oracleUrl: env.VITE_ORACLE_URL || "http://localhost:3001",
+  relayUrl: env.VITE_RELAY_URL || "http://localhost:3002",
+  indexerUrl: env.VITE_INDEXER_URL || "http://localhost:3003",
+  multiplayerUrl: env.VITE_MULTIPLAYER_URL || "ws://localhost:3004",
+  handcashUrl: env.VITE_HANDCASH_URL || "http://localhost:3005",
+  playgroundUrl: env.VITE_PLAYGROUND_URL || "http://localhost:3006",
+  apiUrl: env.VITE_API_URL || "http://localhost:3007",
+};
+
+/** Explorer page for a transaction on the active network */
+export function txUrl(txid: string): string {
+  return config.explorerUrl + "/tx/" + txid;
+}
+"#;
+
+pub const PANDA_SIGNER_SERVICE: &str = r#"/**
+ * PandaSigner Service - SDK-level signer for Yours Wallet
+ * 
+ * This provides the basic PandaSigner from scrypt-ts SDK.
+ * For advanced use cases (custom tx building, bypassing proxy bugs),
+ * use YoursDirectSigner from yoursWalletDirect.ts instead.
+ */
+
+import {
+  PandaSigner,
+  DefaultProvider,
+  bsv,
+  type Provider,
+} from "scrypt-ts";
+import { config } from "./config";
+
+const NETWORK = config.network;
+
+let cachedSigner: PandaSigner | null = null;
+let cachedProvider: Provider | null = null;
+
+function assertWalletInjected() {
+  if (typeof window === "undefined") return;
+  const w = window as any;
+  const hasInjection = !!(w.panda || w.yours);
+  if (!hasInjection) {
+    throw new Error(
+      "Yours Wallet not detected. Please install the Yours Wallet extension from the Chrome Web Store and refresh the page.",
+    );
+  }
+}
+
+function getNetworkFromString(networkStr: string): bsv.Networks.Network {
+  const lowerNetwork = networkStr.toLowerCase();
+  if (lowerNetwork === "testnet" || lowerNetwork.includes("testnet")) {
+    return bsv.Networks.testnet;
+  }
+  return bsv.Networks.mainnet;
+}
+
+async function getProvider(): Promise<Provider> {
+  if (cachedProvider) return cachedProvider;
+  const network = getNetworkFromString(NETWORK as string);
+  const provider = new DefaultProvider({ network });
+  await provider.connect();
+  cachedProvider = provider;
+  return provider;
+}
+
+export async function getPandaSigner(auth: boolean = false): Promise<PandaSigner> {
+  assertWalletInjected();
+  if (cachedSigner) {
+    return cachedSigner;
+  }
+  const provider = await getProvider();
+  const signer = new PandaSigner(provider);
+  if (auth) {
+    const authResult = await signer.requestAuth();
+    if (!authResult.isAuthenticated) {
+      throw new Error("Yours Wallet authentication failed: " + authResult.error);
+    }
+  }
+  cachedSigner = signer;
+  return signer;
+}
+
+export function clearPandaSigner() {
+  cachedSigner = null;
+  cachedProvider = null;
+}
+
+export async function getFreshPandaSigner(auth: boolean = false): Promise<PandaSigner> {
+  clearPandaSigner();
+  return getPandaSigner(auth);
+}
+"#;
+
+pub const YOURS_DIRECT_SIGNER: &str = r#"/**
+ * YoursDirectSigner - Custom Signer implementation for Yours Wallet
+ * 
+ * This bypasses PandaSigner proxy bugs by implementing a clean Signer
+ * that routes signing through window.yours.getSignatures().
+ * 
+ * USE THIS for settlement transactions that require custom tx building.
+ * The standard PandaSigner can have issues with:
+ * - Proxy object interference
+ * - Incorrect sighash handling
+ * - Fee calculation problems
+ */
+
+import {
+  bsv,
+  Signer,
+  SignatureRequest,
+  SignatureResponse,
+  Provider,
+  AddressOption,
+  DefaultProvider,
+} from "scrypt-ts";
+import { config } from "./config";
+
+// Yours Wallet API types (from window.yours)
+interface YoursWalletAPI {
+  isConnected: () => Promise<boolean>;
+  connect: () => Promise<string>;
+  getAddresses: () => Promise<{ bsvAddress: string; ordAddress: string; identityAddress: string }>;
+  getPubKeys: () => Promise<{ bsvPubKey: string; ordPubKey: string; identityPubKey: string }>;
+  getBalance: () => Promise<{ bsv: number; satoshis: number; usdInCents: number }>;
+  getPaymentUtxos: () => Promise<Array<{ satoshis: number; script: string; txid: string; vout: number }>>;
+  getSignatures: (params: {
+    rawtx: string;
+    sigRequests: Array<{
+      prevTxid: string;
+      outputIndex: number;
+      inputIndex: number;
+      satoshis: number;
+      address: string | string[];
+      script?: string;
+      sigHashType?: number;
+      csIdx?: number;
+      data?: unknown;
+    }>;
+  }) => Promise<Array<{
+    inputIndex: number;
+    sig: string;
+    pubKey: string;
+    sigHashType: number;
+    csIdx?: number;
+  }>>;
+  broadcast: (params: { rawtx: string; format?: string }) => Promise<string>;
+  signMessage: (params: { message: string }) => Promise<{ sig: string }>;
+}
+
+const NETWORK = config.network;
+
+function getNetworkFromString(networkStr: string): bsv.Networks.Network {
+  const lowerNetwork = networkStr.toLowerCase();
+  if (lowerNetwork === "testnet" || lowerNetwork.includes("testnet")) {
+    return bsv.Networks.testnet;
+  }
+  return bsv.Networks.mainnet;
+}
+
+/**
+ * Custom Signer that uses Yours Wallet's direct API for signing.
+ */
+export class YoursDirectSigner extends Signer {
+  private _target: YoursWalletAPI | null = null;
+  private _network: bsv.Networks.Network;
+  
+  constructor(provider?: Provider) {
+    super(provider);
+    this._network = getNetworkFromString(NETWORK);
+  }
+  
+  private _initTarget(): void {
+    if (this._target) return;
+    if (typeof (window as any).yours !== 'undefined') {
+      this._target = (window as any).yours;
+    } else {
+      throw new Error('Yours Wallet is not installed. Please install the extension and refresh.');
+    }
+  }
+  
+  private async getConnectedTarget(): Promise<YoursWalletAPI> {
+    const isAuthenticated = await this.isAuthenticated();
+    if (!isAuthenticated) {
+      this._initTarget();
+      const res = await this._target!.connect();
+      if (res && res.includes("canceled")) {
+        throw new Error(res);
+      }
+    }
+    return this._target!;
+  }
+  
+  override async getNetwork(): Promise<bsv.Networks.Network> {
+    return this._network;
+  }
+  
+  override async isAuthenticated(): Promise<boolean> {
+    try {
+      this._initTarget();
+      return await this._target!.isConnected();
+    } catch {
+      return false;
+    }
+  }
+  
+  override async requestAuth(): Promise<{ isAuthenticated: boolean; error: string }> {
+    let isAuthenticated = false;
+    let error = '';
+    try {
+      await this.getConnectedTarget();
+      isAuthenticated = true;
+    } catch (e: any) {
+      error = e.message || String(e);
+    }
+    return { isAuthenticated, error };
+  }
+  
+  override setProvider(provider: Provider): void {
+    this.provider = provider;
+  }
+  
+  override async getDefaultAddress(): Promise<bsv.Address> {
+    const yours = await this.getConnectedTarget();
+    const addresses = await yours.getAddresses();
+    return bsv.Address.fromString(addresses.bsvAddress);
+  }
+  
+  override async getDefaultPubKey(): Promise<bsv.PublicKey> {
+    const yours = await this.getConnectedTarget();
+    const pubKeys = await yours.getPubKeys();
+    return new bsv.PublicKey(pubKeys.bsvPubKey);
+  }
+  
+  override async getPubKey(_address?: AddressOption): Promise<bsv.PublicKey> {
+    return this.getDefaultPubKey();
+  }
+  
+  override async signMessage(message: string, address?: AddressOption): Promise<string> {
+    if (address) {
+      throw new Error("signMessage with 'address' param is not supported");
+    }
+    const yours = await this.getConnectedTarget();
+    const res = await yours.signMessage({ message });
+    return res.sig;
+  }
+  
+  /**
+   * THE CRITICAL METHOD: Get signatures for transaction inputs
+   * 
+   * Converts scrypt-ts format to Yours Wallet format and back.
+   */
+  override async getSignatures(
+    rawTxHex: string,
+    sigRequests: SignatureRequest[]
+  ): Promise<SignatureResponse[]> {
+    const yours = await this.getConnectedTarget();
+    
+    // Convert scrypt-ts SignatureRequest to Yours Wallet format
+    const yoursRequests = sigRequests.map((sigReq) => {
+      let addressStrings: string[];
+      if (typeof sigReq.address === 'string') {
+        addressStrings = [sigReq.address];
+      } else if (Array.isArray(sigReq.address)) {
+        addressStrings = sigReq.address.map((addr) => 
+          typeof addr === 'string' ? addr : addr.toString()
+        );
+      } else {
+        addressStrings = [(sigReq.address as bsv.Address).toString()];
+      }
+      
+      return {
+        prevTxid: sigReq.prevTxId,
+        outputIndex: sigReq.outputIndex,
+        inputIndex: sigReq.inputIndex,
+        satoshis: sigReq.satoshis,
+        address: addressStrings.length === 1 ? addressStrings[0] : addressStrings,
+        script: sigReq.scriptHex,
+        sigHashType: sigReq.sigHashType,
+        csIdx: sigReq.csIdx,
+        data: sigReq.data,
+      };
+    });
+    
+    const sigResults = await yours.getSignatures({
+      rawtx: rawTxHex,
+      sigRequests: yoursRequests,
+    });
+    
+    // Convert back to scrypt-ts format (pubKey -> publicKey)
+    return sigResults.map((sigResult) => ({
+      inputIndex: sigResult.inputIndex,
+      sig: sigResult.sig,
+      publicKey: sigResult.pubKey,
+      sigHashType: sigResult.sigHashType,
+      csIdx: sigResult.csIdx,
+    }));
+  }
+  
+  override async getBalance(address?: AddressOption): Promise<{ confirmed: number; unconfirmed: number }> {
+    if (address) {
+      return this.connectedProvider.getBalance(address);
+    }
+    const yours = await this.getConnectedTarget();
+    const balance = await yours.getBalance();
+    return { confirmed: balance.satoshis, unconfirmed: 0 };
+  }
+}
+
+// Signer instance management
+let yoursSignerInstance: YoursDirectSigner | null = null;
+
+export async function getYoursDirectSigner(): Promise<YoursDirectSigner> {
+  if (yoursSignerInstance) {
+    return yoursSignerInstance;
+  }
+  
+  const network = getNetworkFromString(NETWORK);
+  const provider = new DefaultProvider({ network });
+  await provider.connect();
+  
+  const signer = new YoursDirectSigner(provider);
+  const authResult = await signer.requestAuth();
+  
+  if (!authResult.isAuthenticated) {
+    throw new Error("Yours Wallet auth failed: " + authResult.error);
+  }
+  
+  yoursSignerInstance = signer;
+  return signer;
+}
+
+export function clearYoursDirectSigner(): void {
+  yoursSignerInstance = null;
+}
+
+/**
+ * Get wallet UTXOs for fee payment
+ */
+export async function getWalletUtxos(): Promise<Array<{ satoshis: number; script: string; txid: string; vout: number }>> {
+  const yours = (window as any).yours;
+  if (!yours) {
+    throw new Error("Yours Wallet not available");
+  }
+  return yours.getPaymentUtxos();
+}
+"#;
+
+pub const CONTRACT_SERVICE: &str = r#"/**
+ * Contract Service - Production-ready sCrypt smart contract interactions
+ * 
+ * KEY PATTERNS IMPLEMENTED:
+ * 1. Dynamic artifact loading (no direct .scrypt.ts imports)
+ * 2. YoursDirectSigner for reliable signing
+ * 3. Custom tx builder with bindTxBuilder for full control
+ * 4. Manual fee UTXO handling with feePerKb
+ * 5. Proper change address handling
+ * 
+ * IMPORTANT: Do NOT import .scrypt.ts files directly!
+ * Compile first: npx scrypt-cli compile
+ */
+
+import {
+  hash256,
+  toByteString,
+  bsv,
+  PubKey,
+  ByteString,
+  MethodCallOptions,
+  findSig,
+  Utils,
+  hash160,
+  ContractTransaction,
+  type Signer,
+} from "scrypt-ts";
+import Contract from "__CONTRACTS_DIR__/Contract.scrypt";
+import { getPandaSigner, getFreshPandaSigner } from "./pandaSignerService";
+import { getYoursDirectSigner, getWalletUtxos } from "./yoursWalletDirect";
+import { config } from "./config";
+
+// ============================================================================
+// ARTIFACT LOADING
+// ============================================================================
+
+let artifactLoaded = false;
+
+async function loadArtifact() {
+  if (artifactLoaded) return;
+  const artifactPath = config.artifactPath;
+  try {
+    const res = await fetch(artifactPath);
+    if (!res.ok) {
+      throw new Error("HTTP " + res.status);
+    }
+    const artifact = await res.json();
+    await Contract.loadArtifact(artifact);
+    artifactLoaded = true;
+  } catch (err) {
+    throw new Error(
+      "Contract artifact missing at " + artifactPath + ". Run " +
+      "`npx scrypt-cli compile` to generate it.",
+    );
+  }
+}
+
+// ============================================================================
+// SIGNER HELPERS
+// ============================================================================
+
+// The wallet the user connected, as every frontend's wallet state stores it
+function getActiveWalletType(): "yours" | null {
+  try {
+    const stored = JSON.parse(localStorage.getItem("bsv_wallet") || "null");
+    return stored?.walletType === "yours" ? "yours" : null;
+  } catch {
+    return null;
+  }
+}
+
+async function getSigner(auth: boolean = true): Promise<Signer> {
+  const walletType = getActiveWalletType();
+  if (walletType === "yours") {
+    return getYoursDirectSigner();
+  }
+  return getPandaSigner(auth);
+}
+
+export async function getSignerPubKeyHex(): Promise<string> {
+  const signer = await getSigner(true);
+  const pub = await signer.getDefaultPubKey();
+  return pub.toHex();
+}
+
+// ============================================================================
+// COMMITMENT HASHING (for commit-reveal patterns)
+// ============================================================================
+
+export function hashCommitment(data: string, nonce: string): string {
+  return hash256(toByteString(nonce + data, true));
+}
+
+// ============================================================================
+// DEPLOYMENT
+// ============================================================================
+
+export interface DeployParams {
+  player1PubKeyHex: string;
+  player2PubKeyHex: string;
+  player1Commitment: string;
+  player2Commitment: string;
+  timeoutHeight: bigint;
+  fundingSats: number;
+}
+
+export async function deployContract(params: DeployParams) {
+  await loadArtifact();
+  
+  const signer = await getYoursDirectSigner();
+  
+  const contract = new Contract(
+    PubKey(params.player1PubKeyHex),
+    PubKey(params.player2PubKeyHex),
+    params.player1Commitment as ByteString,
+    params.player2Commitment as ByteString,
+    params.timeoutHeight,
+  );
+  
+  await contract.connect(signer);
+  
+  const pot = BigInt(params.fundingSats * 2);
+  const tx = await contract.deploy(Number(pot));
+  
+  return { txid: tx.id, instance: contract };
+}
+
+// ============================================================================
+// CUSTOM TRANSACTION BUILDER
+// 
+// This is the KEY pattern for controlling transaction construction.
+// Use bindTxBuilder to register this before calling contract methods.
+// ============================================================================
+
+async function buildSettleTx(
+  current: Contract,
+  options: MethodCallOptions<Contract>,
+  ...methodArgs: any[]
+): Promise<ContractTransaction> {
+  // Extract method arguments (customize based on your contract)
+  const [p1Move, _p1Nonce, p2Move, _p2Nonce, callerIsPlayer1, _sig] = methodArgs;
+  
+  // Get contract properties
+  const player1Hex = (current as any).player1 as string;
+  const player2Hex = (current as any).player2 as string;
+  
+  // Decode moves to determine outcome
+  const p1MoveStr = Buffer.from(p1Move, 'hex').toString('utf8');
+  const p2MoveStr = Buffer.from(p2Move, 'hex').toString('utf8');
+  
+  // Determine winner (customize this logic for your game)
+  let winnerPubKeyHex: string;
+  let isDraw = p1MoveStr === p2MoveStr;
+  
+  if (isDraw) {
+    winnerPubKeyHex = player1Hex;
+  } else {
+    // Example: rock-paper-scissors logic
+    const p1Wins = 
+      (p1MoveStr === "rock" && p2MoveStr === "scissors") ||
+      (p1MoveStr === "scissors" && p2MoveStr === "paper") ||
+      (p1MoveStr === "paper" && p2MoveStr === "rock");
+    winnerPubKeyHex = p1Wins ? player1Hex : player2Hex;
+  }
+  
+  // Create transaction with contract input at index 0
+  const unsignedTx: bsv.Transaction = new bsv.Transaction();
+  unsignedTx.addInput(current.buildContractInput());
+  
+  // Build output 0 using Utils.buildPublicKeyHashScript (matches contract)
+  const winnerPkh = hash160(winnerPubKeyHex as PubKey);
+  const script = Utils.buildPublicKeyHashScript(winnerPkh);
+  
+  if (isDraw) {
+    const halfAmount = Math.floor(current.balance / 2);
+    unsignedTx.addOutput(new bsv.Transaction.Output({
+      script: bsv.Script.fromHex(script),
+      satoshis: halfAmount,
+    }));
+    // Output 1 for player 2
+    const p2Pkh = hash160(player2Hex as PubKey);
+    const p2Script = Utils.buildPublicKeyHashScript(p2Pkh);
+    unsignedTx.addOutput(new bsv.Transaction.Output({
+      script: bsv.Script.fromHex(p2Script),
+      satoshis: halfAmount,
+    }));
+  } else {
+    unsignedTx.addOutput(new bsv.Transaction.Output({
+      script: bsv.Script.fromHex(script),
+      satoshis: current.balance,
+    }));
+  }
+  
+  // CRITICAL: Manual fee funding
+  // Pick one wallet UTXO to cover transaction fee
+  const feeUtxos = await getWalletUtxos();
+  if (!feeUtxos || feeUtxos.length === 0) {
+    throw new Error("No UTXOs available for fee payment");
+  }
+  
+  const MIN_FEE_SATS = 1000;
+  const feeUtxo = feeUtxos.find((u) => u.satoshis >= MIN_FEE_SATS) || feeUtxos[0];
+  
+  // Add fee input (contract stays at index 0)
+  unsignedTx.from({
+    txId: feeUtxo.txid,
+    outputIndex: feeUtxo.vout,
+    script: feeUtxo.script,
+    satoshis: feeUtxo.satoshis,
+  });
+  
+  // Set fee rate and add change output
+  unsignedTx.feePerKb(100); // 100 sat/KB - safe relay rate
+  if (options.changeAddress) {
+    unsignedTx.change(options.changeAddress);
+  }
+  
+  return {
+    tx: unsignedTx,
+    atInputIndex: 0,
+    nexts: [],
+  };
+}
+
+// ============================================================================
+// SETTLEMENT
+// ============================================================================
+
+export interface SettleParams {
+  p1Move: string;
+  p1Nonce: string;
+  p2Move: string;
+  p2Nonce: string;
+  callerIsPlayer1: boolean;
+  player1PubKeyHex: string;
+  player2PubKeyHex: string;
+}
+
+export async function settleContract(
+  instance: Contract,
+  params: SettleParams,
+): Promise<{ txid: string }> {
+  await loadArtifact();
+  
+  const signer = await getYoursDirectSigner();
+  await instance.connect(signer);
+  
+  // CRITICAL: Bind custom transaction builder
+  instance.bindTxBuilder('settle', buildSettleTx);
+  
+  const callerPubKey = bsv.PublicKey.fromHex(
+    params.callerIsPlayer1 ? params.player1PubKeyHex : params.player2PubKeyHex
+  );
+  
+  const changeAddress = await signer.getDefaultAddress();
+  
+  const { tx: builtTx } = await instance.methods.settle(
+    toByteString(params.p1Move, true),
+    toByteString(params.p1Nonce, true),
+    toByteString(params.p2Move, true),
+    toByteString(params.p2Nonce, true),
+    params.callerIsPlayer1,
+    (sigResps: any) => findSig(sigResps, callerPubKey),
+    {
+      pubKeyOrAddrToSign: callerPubKey,
+      changeAddress: changeAddress,
+    } as MethodCallOptions<Contract>,
+  );
+  
+  return { txid: builtTx.id };
+}
+
+// ============================================================================
+// CONTRACT RESTORATION (from deployed tx)
+// ============================================================================
+
+export async function restoreContractFromTx(txid: string, outputIndex: number = 0): Promise<Contract> {
+  await loadArtifact();
+  
+  const signer = await getYoursDirectSigner();
+  const provider = signer.provider!;
+  const tx = await provider.getTransaction(txid);
+  
+  const instance = Contract.fromTx(tx, outputIndex);
+  await instance.connect(signer);
+  
+  return instance;
+}
+
+export default {
+  deployContract,
+  settleContract,
+  restoreContractFromTx,
+  getSignerPubKeyHex,
+  hashCommitment,
+};
+"#;
@@ -0,0 +1,15 @@
+//! The project templates behind the Bitcoin app wizard: sCrypt contracts
+//! with their tests and deploy scripts, a frontend per framework and the
+//! optional backends, put together into a [`Manifest`] of files for a set of
+//! [`ScaffoldOptions`]. The wizard scaffolds from here and the extension's
+//! `/template` command describes the templates from here, so the two agree.
+
+mod descriptor;
+mod manifest;
+mod templates;
+mod variables;
+
+pub use descriptor::{Database, Framework, Template, TemplateDescriptor, TestFramework};
+pub use manifest::{FileSource, Manifest, ManifestFile, ScaffoldOptions, agent_prompt};
+pub use templates::{Branding, DEFAULT_PRIMARY_COLOR, logo_file_name, parse_color};
+pub use variables::Variables;
@@ -0,0 +1,37 @@
+//! The `__NAME__` placeholders in template text and their values.
+
+use std::collections::BTreeMap;
+
+/// Values for the `__NAME__` placeholders in template text, such as
+/// `__DATABASE_URL__` in the state API server. Names are given without the
+/// underscores.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Variables(BTreeMap<String, String>);
+
+impl Variables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `name`, replacing an earlier value.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// `text` with the placeholders of every set variable filled in. Others
+    /// are left as they are, so text can be filled in several passes.
+    pub fn apply(&self, text: &str) -> String {
+        self.0.iter().fold(text.to_string(), |text, (name, value)| {
+            text.replace(&format!("__{name}__"), value)
+        })
+    }
+}
@@ -638,6 +638,7 @@ pub fn main() {
         svg_preview::init(cx);
         onboarding::init(cx);
         bitcoin_app_wizard::init(app_state.clone(), cx);
+        bitcoin_tools::init(cx);
         settings_ui::init(cx);
         keymap_editor::init(cx);
         extensions_ui::init(cx);
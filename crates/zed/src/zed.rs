@@ -445,11 +445,13 @@ pub fn initialize_workspace(
             cx.new(|_| go_to_line::cursor_position::CursorPosition::new(workspace));
         let line_ending_indicator =
             cx.new(|_| line_ending_selector::LineEndingIndicator::default());
+        let bitcoin_network = cx.new(|cx| bitcoin_tools::NetworkIndicator::new(workspace, cx));
         workspace.status_bar().update(cx, |status_bar, cx| {
             status_bar.add_left_item(search_button, window, cx);
             status_bar.add_left_item(lsp_button, window, cx);
             status_bar.add_left_item(diagnostic_summary, window, cx);
             status_bar.add_left_item(activity_indicator, window, cx);
+            status_bar.add_right_item(bitcoin_network, window, cx);
             status_bar.add_right_item(edit_prediction_button, window, cx);
             status_bar.add_right_item(active_buffer_language, window, cx);
             status_bar.add_right_item(active_toolchain_language, window, cx);
@@ -654,6 +656,16 @@ fn initialize_panels(
             workspace_handle.clone(),
             cx.clone(),
         );
+        let utxo_panel = bitcoin_tools::UtxoPanel::load(workspace_handle.clone(), cx.clone());
+        let script_debugger_panel =
+            bitcoin_tools::ScriptDebuggerPanel::load(workspace_handle.clone(), cx.clone());
+        let deploy_panel = bitcoin_tools::DeployPanel::load(workspace_handle.clone(), cx.clone());
+        let wallet_panel = bitcoin_tools::WalletPanel::load(workspace_handle.clone(), cx.clone());
+        let deployment_history_panel =
+            bitcoin_tools::DeploymentHistoryPanel::load(workspace_handle.clone(), cx.clone());
+        let covenant_timeline_panel =
+            bitcoin_tools::CovenantTimelinePanel::load(workspace_handle.clone(), cx.clone());
+        let tasks_panel = bitcoin_tools::TasksPanel::load(workspace_handle.clone(), cx.clone());
         let debug_panel = DebugPanel::load(workspace_handle.clone(), cx);
 
         async fn add_panel_when_ready(
@@ -678,6 +690,13 @@ fn initialize_panels(
             add_panel_when_ready(git_panel, workspace_handle.clone(), cx.clone()),
             add_panel_when_ready(channels_panel, workspace_handle.clone(), cx.clone()),
             add_panel_when_ready(notification_panel, workspace_handle.clone(), cx.clone()),
+            add_panel_when_ready(utxo_panel, workspace_handle.clone(), cx.clone()),
+            add_panel_when_ready(script_debugger_panel, workspace_handle.clone(), cx.clone()),
+            add_panel_when_ready(deploy_panel, workspace_handle.clone(), cx.clone()),
+            add_panel_when_ready(wallet_panel, workspace_handle.clone(), cx.clone()),
+            add_panel_when_ready(deployment_history_panel, workspace_handle.clone(), cx.clone()),
+            add_panel_when_ready(covenant_timeline_panel, workspace_handle.clone(), cx.clone()),
+            add_panel_when_ready(tasks_panel, workspace_handle.clone(), cx.clone()),
             add_panel_when_ready(debug_panel, workspace_handle.clone(), cx.clone()),
             initialize_agent_panel(workspace_handle, prompt_builder, cx.clone()).map(|r| r.log_err())
         );
@@ -0,0 +1,623 @@
+//! `/paymail`: resolves a `name@domain` paymail handle to the outputs a
+//! payment to it should have, checking the host's capability discovery on
+//! the way.
+//!
+//! Hosts are found at `https://<domain>/.well-known/bsvalias`. Extensions
+//! can't look up the `_bsvalias._tcp` SRV record some domains delegate with,
+//! so those resolve only if the domain serves the document itself.
+//!
+//! Only the paymail host is queried - nothing is read from or broadcast to
+//! the chain - so the editor's chain provider doesn't apply here. Payments
+//! to outputs it returns go out through `/broadcast`, which uses it.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use zed_extension_api::http_client::{HttpMethod, HttpRequest};
+
+/// Paid when no amount is given; P2P destinations are issued per amount.
+const DEFAULT_SATOSHIS: u64 = 1000;
+
+/// Identifies the lookup in basic `paymentDestination` requests, which must
+/// name a sender even though nothing is being sent.
+const SENDER_HANDLE: &str = "utxix@localhost";
+
+/// Capabilities worth reporting, by BRFC id or name, and what they're for.
+const CAPABILITIES: [(&str, &str); 7] = [
+    ("pki", "Public key infrastructure"),
+    ("paymentDestination", "Basic payment destination"),
+    ("2a40af698840", "P2P payment destination"),
+    ("5f1323cddf31", "P2P receive transaction"),
+    ("a9f510c16bde", "Verify public key owner"),
+    ("f12f968c92d6", "Public profile"),
+    ("6745385c3fc0", "Sender validation required"),
+];
+
+const P2P_DESTINATION: &str = "2a40af698840";
+const RECEIVE_TRANSACTION: &str = "5f1323cddf31";
+const VERIFY_PUBLIC_KEY: &str = "a9f510c16bde";
+const PUBLIC_PROFILE: &str = "f12f968c92d6";
+const SENDER_VALIDATION: &str = "6745385c3fc0";
+
+struct Handle {
+    alias: String,
+    domain: String,
+}
+
+impl Handle {
+    fn as_string(&self) -> String {
+        format!("{}@{}", self.alias, self.domain)
+    }
+
+    /// Fills in a capability's URL template.
+    fn url(&self, template: &str) -> String {
+        template
+            .replace("{alias}", &self.alias)
+            .replace("{domain.tld}", &self.domain)
+    }
+}
+
+struct Output {
+    script: String,
+    satoshis: Option<u64>,
+}
+
+/// Parses `/paymail <name@domain> [satoshis]`.
+pub fn parse_args(args: &[String]) -> Result<(String, u64), String> {
+    let Some(handle) = args.first() else {
+        return Err(
+            "Please provide a paymail handle (e.g., /paymail alice@example.com 1000)".to_string(),
+        );
+    };
+    let satoshis = match args.get(1) {
+        Some(amount) => amount
+            .replace('_', "")
+            .parse::<u64>()
+            .ok()
+            .filter(|satoshis| *satoshis > 0)
+            .ok_or_else(|| format!("{amount} is not an amount of satoshis"))?,
+        None => DEFAULT_SATOSHIS,
+    };
+    Ok((handle.trim().to_string(), satoshis))
+}
+
+/// Resolves `handle` and returns the markdown report shown in the assistant
+/// panel.
+pub fn paymail(handle: &str, satoshis: u64) -> Result<String, String> {
+    let handle = parse_handle(handle)?;
+    let name = handle.as_string();
+
+    let discovery_url = format!("https://{}/.well-known/bsvalias", handle.domain);
+    let discovery = Discovery::parse(
+        &discovery_url,
+        &request_json(HttpMethod::Get, &discovery_url, None)?,
+    )?;
+    let version = &discovery.version;
+    let capability = |id: &str| discovery.capability(id);
+    let capability_rows = discovery.capability_rows();
+
+    // The identity key, checked against the host's own verification endpoint
+    let pki_url =
+        capability("pki").ok_or_else(|| format!("{} offers no pki capability", handle.domain))?;
+    let pki = request_json(HttpMethod::Get, &handle.url(pki_url), None)?;
+    let pubkey = identity_key(&handle, &pki)?;
+    let key_check = match capability(VERIFY_PUBLIC_KEY) {
+        Some(template) => {
+            let url = handle.url(template).replace("{pubkey}", &pubkey);
+            let verified = request_json(HttpMethod::Get, &url, None)?;
+            if verified["match"].as_bool() != Some(true) {
+                return Err(format!(
+                    "{} doesn't confirm {pubkey} belongs to {name}",
+                    handle.domain
+                ));
+            }
+            "confirmed by the host"
+        }
+        None => "not confirmed - the host can't verify key owners",
+    };
+
+    let profile = capability(PUBLIC_PROFILE)
+        .and_then(|template| request_json(HttpMethod::Get, &handle.url(template), None).ok())
+        .and_then(|profile| profile["name"].as_str().map(str::to_string))
+        .map(|name| format!("**Name:** {name}\n"))
+        .unwrap_or_default();
+
+    let (flow, outputs, reference) = if let Some(template) = capability(P2P_DESTINATION) {
+        let response = request_json(
+            HttpMethod::Post,
+            &handle.url(template),
+            Some(json!({ "satoshis": satoshis })),
+        )?;
+        let outputs = p2p_outputs(&name, &response)?;
+        let reference = response["reference"].as_str().map(str::to_string);
+        (P2P_DESTINATION, outputs, reference)
+    } else if let Some(template) = capability("paymentDestination") {
+        if discovery.requires(SENDER_VALIDATION) {
+            return Err(format!(
+                "{} requires signed sender details for basic payment destinations, which /paymail can't provide",
+                handle.domain
+            ));
+        }
+        let response = request_json(
+            HttpMethod::Post,
+            &handle.url(template),
+            Some(json!({
+                "senderName": "Utxix",
+                "senderHandle": SENDER_HANDLE,
+                "dt": iso_timestamp(),
+                "amount": satoshis,
+                "purpose": "Destination lookup",
+            })),
+        )?;
+        let script = response["output"]
+            .as_str()
+            .ok_or_else(|| format!("{name} returned no payment destination"))?
+            .to_ascii_lowercase();
+        let outputs = vec![Output {
+            script,
+            satoshis: Some(satoshis),
+        }];
+        ("paymentDestination", outputs, None)
+    } else {
+        return Err(format!(
+            "{} offers no way to get a payment destination",
+            handle.domain
+        ));
+    };
+
+    let mut output_rows = String::new();
+    for (ix, output) in outputs.iter().enumerate() {
+        let bytes = decode_hex(&output.script)
+            .ok_or_else(|| format!("{name} returned a malformed script"))?;
+        let address = p2pkh_hash(&bytes)
+            .map(|hash| format!("`{}`", p2pkh_address(&hash)))
+            .unwrap_or_else(|| format!("{}-byte script", bytes.len()));
+        let amount = output
+            .satoshis
+            .map(|satoshis| satoshis.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        output_rows.push_str(&format!(
+            "| {ix} | {amount} | {address} | `{}` |\n",
+            output.script
+        ));
+    }
+
+    let delivery = match (flow, capability(RECEIVE_TRANSACTION)) {
+        (P2P_DESTINATION, Some(template)) => format!(
+            "Send the signed transaction to `{}` as `{{ hex, reference, metadata }}` rather than broadcasting it - the host broadcasts it and credits the payment by its reference.",
+            handle.url(template)
+        ),
+        (P2P_DESTINATION, None) => {
+            "The host issued P2P outputs but has no receive-transaction endpoint; broadcast the transaction yourself with `/broadcast`.".to_string()
+        }
+        _ => "Broadcast the transaction paying this output with `/broadcast`.".to_string(),
+    };
+    let reference = reference
+        .map(|reference| format!("**Reference:** `{reference}`\n"))
+        .unwrap_or_default();
+    let total: u64 = outputs.iter().filter_map(|output| output.satoshis).sum();
+
+    Ok(format!(
+        r#"## Paymail {name}
+
+**Host:** {domain} (bsvalias {version})
+{profile}**Identity key:** `{pubkey}` ({key_check})
+
+### Capabilities
+
+| Capability | BRFC | Offered |
+| --- | --- | --- |
+{capability_rows}
+### Destination for {satoshis} sat
+
+Resolved with `{flow}`; the outputs total {total} sat.
+{reference}
+| Output | Satoshis | Pays | Script |
+| --- | --- | --- | --- |
+{output_rows}
+{delivery}
+
+Destinations are issued per request - resolve the handle again for each payment instead of reusing these outputs.
+"#,
+        domain = handle.domain,
+    ))
+}
+
+/// A host's `.well-known/bsvalias` capability document.
+struct Discovery {
+    version: String,
+    capabilities: Map<String, Value>,
+}
+
+impl Discovery {
+    fn parse(url: &str, document: &Value) -> Result<Self, String> {
+        let version = document["bsvalias"]
+            .as_str()
+            .ok_or_else(|| format!("{url} is not a bsvalias capability document"))?;
+        let capabilities = document["capabilities"]
+            .as_object()
+            .ok_or_else(|| format!("{url} lists no capabilities"))?;
+        Ok(Self {
+            version: version.to_string(),
+            capabilities: capabilities.clone(),
+        })
+    }
+
+    /// The URL template of a capability, by BRFC id or name.
+    fn capability(&self, id: &str) -> Option<&str> {
+        self.capabilities.get(id).and_then(Value::as_str)
+    }
+
+    /// Whether a flag capability, like sender validation, is switched on.
+    fn requires(&self, id: &str) -> bool {
+        self.capabilities.get(id) == Some(&Value::Bool(true))
+    }
+
+    /// The table rows saying which of [`CAPABILITIES`] the host offers.
+    fn capability_rows(&self) -> String {
+        let mut rows = String::new();
+        for (id, description) in CAPABILITIES {
+            let offered = if self.capability(id).is_some() || self.requires(id) {
+                "yes"
+            } else {
+                "no"
+            };
+            rows.push_str(&format!("| {description} | `{id}` | {offered} |\n"));
+        }
+        rows
+    }
+}
+
+/// The compressed public key a `pki` response publishes for `handle`.
+fn identity_key(handle: &Handle, pki: &Value) -> Result<String, String> {
+    let name = handle.as_string();
+    let pubkey = pki["pubkey"]
+        .as_str()
+        .ok_or_else(|| format!("{name} has no public key"))?
+        .to_ascii_lowercase();
+    if pubkey.len() != 66
+        || !(pubkey.starts_with("02") || pubkey.starts_with("03"))
+        || decode_hex(&pubkey).is_none()
+    {
+        return Err(format!("{name} published a malformed public key: {pubkey}"));
+    }
+    if let Some(returned) = pki["handle"]
+        .as_str()
+        .filter(|returned| !returned.eq_ignore_ascii_case(&name))
+    {
+        return Err(format!(
+            "{} answered for {returned} instead of {name}",
+            handle.domain
+        ));
+    }
+    Ok(pubkey)
+}
+
+/// The outputs of a P2P payment destination response.
+fn p2p_outputs(name: &str, response: &Value) -> Result<Vec<Output>, String> {
+    response["outputs"]
+        .as_array()
+        .filter(|outputs| !outputs.is_empty())
+        .ok_or_else(|| format!("{name} returned no P2P outputs"))?
+        .iter()
+        .map(|output| {
+            Some(Output {
+                script: output["script"].as_str()?.to_ascii_lowercase(),
+                satoshis: output["satoshis"].as_u64(),
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| format!("{name} returned a malformed P2P output"))
+}
+
+fn parse_handle(handle: &str) -> Result<Handle, String> {
+    let handle = handle.trim().trim_start_matches('$').to_ascii_lowercase();
+    let (alias, domain) = handle
+        .split_once('@')
+        .ok_or_else(|| format!("{handle} is not a paymail handle (name@domain)"))?;
+    let alias_valid = !alias.is_empty()
+        && alias
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '+'));
+    let domain_valid = domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if !alias_valid || !domain_valid {
+        return Err(format!("{handle} is not a paymail handle (name@domain)"));
+    }
+    Ok(Handle {
+        alias: alias.to_string(),
+        domain: domain.to_string(),
+    })
+}
+
+/// Sends a request with an optional JSON body and parses the JSON response.
+fn request_json(method: HttpMethod, url: &str, body: Option<Value>) -> Result<Value, String> {
+    let mut builder = HttpRequest::builder()
+        .method(method)
+        .url(url)
+        .header("Accept", "application/json");
+    if let Some(body) = body {
+        builder = builder
+            .header("Content-Type", "application/json")
+            .body(body.to_string());
+    }
+    // `fetch` drops the body of error responses, which says what went wrong
+    let response = builder.build()?.fetch_stream()?;
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.next_chunk()? {
+        bytes.extend(chunk);
+    }
+    let text = String::from_utf8_lossy(&bytes);
+    serde_json::from_str(&text).map_err(|_| format!("{url} returned: {}", text.trim()))
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|ix| u8::from_str_radix(hex.get(ix..ix + 2)?, 16).ok())
+        .collect()
+}
+
+fn p2pkh_hash(script: &[u8]) -> Option<[u8; 20]> {
+    match script {
+        [0x76, 0xa9, 0x14, hash @ .., 0x88, 0xac] => <[u8; 20]>::try_from(hash).ok(),
+        _ => None,
+    }
+}
+
+/// The mainnet address of a public key hash; paymail is mainnet-only.
+fn p2pkh_address(hash: &[u8; 20]) -> String {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    let mut payload = vec![0x00];
+    payload.extend(hash);
+    let checksum = Sha256::digest(Sha256::digest(&payload));
+    payload.extend(&checksum[..4]);
+
+    let mut digits: Vec<u8> = Vec::new();
+    for byte in &payload {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = payload.iter().take_while(|byte| **byte == 0).count();
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|digit| ALPHABET[*digit as usize] as char),
+        )
+        .collect()
+}
+
+/// The current UTC time as ISO 8601, for the `dt` of sender details.
+fn iso_timestamp() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    let (days, time) = (seconds / 86_400, seconds % 86_400);
+    // Howard Hinnant's civil-from-days
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBKEY: &str = "02b4632d08485ff1df2db55b9dafd23347d1c47a457072a1e87be26896549a8737";
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args(&["alice@example.com"])),
+            Ok(("alice@example.com".to_string(), DEFAULT_SATOSHIS))
+        );
+        assert_eq!(
+            parse_args(&args(&[" alice@example.com ", "10_000"])),
+            Ok(("alice@example.com".to_string(), 10_000))
+        );
+        assert!(parse_args(&[]).is_err());
+        assert!(parse_args(&args(&["alice@example.com", "0"])).is_err());
+        assert!(parse_args(&args(&["alice@example.com", "1.5"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_handle() {
+        let handle = parse_handle(" $Alice.B+tips@Pay.Example.com").unwrap();
+        assert_eq!(handle.alias, "alice.b+tips");
+        assert_eq!(handle.domain, "pay.example.com");
+        assert_eq!(handle.as_string(), "alice.b+tips@pay.example.com");
+        assert_eq!(
+            handle.url("https://{domain.tld}/api/v1/bsvalias/id/{alias}@{domain.tld}"),
+            "https://pay.example.com/api/v1/bsvalias/id/alice.b+tips@pay.example.com"
+        );
+
+        for invalid in [
+            "alice",
+            "@example.com",
+            "alice@",
+            "alice@localhost",
+            "alice@example..com",
+            "al ice@example.com",
+            "alice@exa_mple.com",
+        ] {
+            assert!(parse_handle(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_discovery() {
+        let discovery = Discovery::parse(
+            "https://example.com/.well-known/bsvalias",
+            &json!({
+                "bsvalias": "1.0",
+                "capabilities": {
+                    "pki": "https://example.com/id/{alias}@{domain.tld}",
+                    P2P_DESTINATION: "https://example.com/p2p/{alias}@{domain.tld}",
+                    SENDER_VALIDATION: true,
+                    VERIFY_PUBLIC_KEY: false,
+                },
+            }),
+        )
+        .unwrap();
+        assert_eq!(discovery.version, "1.0");
+        assert_eq!(
+            discovery.capability("pki"),
+            Some("https://example.com/id/{alias}@{domain.tld}")
+        );
+        assert!(discovery.capability(SENDER_VALIDATION).is_none());
+        assert!(discovery.requires(SENDER_VALIDATION));
+        assert!(!discovery.requires(VERIFY_PUBLIC_KEY));
+
+        let rows = discovery.capability_rows();
+        assert!(rows.contains("| Public key infrastructure | `pki` | yes |"));
+        assert!(rows.contains(&format!(
+            "| P2P payment destination | `{P2P_DESTINATION}` | yes |"
+        )));
+        assert!(rows.contains(&format!(
+            "| Sender validation required | `{SENDER_VALIDATION}` | yes |"
+        )));
+        assert!(rows.contains(&format!(
+            "| Verify public key owner | `{VERIFY_PUBLIC_KEY}` | no |"
+        )));
+        assert!(rows.contains("| Basic payment destination | `paymentDestination` | no |"));
+    }
+
+    #[test]
+    fn test_discovery_errors() {
+        let url = "https://example.com/.well-known/bsvalias";
+        let error = |document| Discovery::parse(url, &document).err().unwrap();
+        assert_eq!(
+            error(json!({ "capabilities": {} })),
+            format!("{url} is not a bsvalias capability document")
+        );
+        assert_eq!(
+            error(json!({ "bsvalias": "1.0" })),
+            format!("{url} lists no capabilities")
+        );
+    }
+
+    #[test]
+    fn test_identity_key() {
+        let handle = parse_handle("alice@example.com").unwrap();
+        assert_eq!(
+            identity_key(&handle, &json!({ "pubkey": PUBKEY.to_uppercase() })),
+            Ok(PUBKEY.to_string())
+        );
+        assert_eq!(
+            identity_key(
+                &handle,
+                &json!({ "handle": "Alice@Example.com", "pubkey": PUBKEY })
+            ),
+            Ok(PUBKEY.to_string())
+        );
+
+        assert_eq!(
+            identity_key(&handle, &json!({})),
+            Err("alice@example.com has no public key".to_string())
+        );
+        for malformed in [
+            &PUBKEY[..64],
+            &PUBKEY.replacen("02", "04", 1),
+            &PUBKEY.replacen('b', "x", 1),
+        ] {
+            assert!(
+                identity_key(&handle, &json!({ "pubkey": malformed }))
+                    .unwrap_err()
+                    .contains("malformed public key"),
+                "{malformed}"
+            );
+        }
+        assert_eq!(
+            identity_key(
+                &handle,
+                &json!({ "handle": "bob@example.com", "pubkey": PUBKEY })
+            ),
+            Err(
+                "example.com answered for bob@example.com instead of alice@example.com".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_p2p_outputs() {
+        let outputs = p2p_outputs(
+            "alice@example.com",
+            &json!({
+                "outputs": [
+                    { "script": "76A914", "satoshis": 600 },
+                    { "script": "006a" },
+                ],
+                "reference": "abc",
+            }),
+        )
+        .unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].script, "76a914");
+        assert_eq!(outputs[0].satoshis, Some(600));
+        assert_eq!(outputs[1].satoshis, None);
+
+        for response in [json!({}), json!({ "outputs": [] })] {
+            assert_eq!(
+                p2p_outputs("alice@example.com", &response).err(),
+                Some("alice@example.com returned no P2P outputs".to_string())
+            );
+        }
+        assert_eq!(
+            p2p_outputs(
+                "alice@example.com",
+                &json!({ "outputs": [{ "satoshis": 1 }] })
+            )
+            .err(),
+            Some("alice@example.com returned a malformed P2P output".to_string())
+        );
+    }
+
+    #[test]
+    fn test_p2pkh_outputs() {
+        let mut script = vec![0x76, 0xa9, 0x14];
+        script.extend([0; 20]);
+        script.extend([0x88, 0xac]);
+        let hash = p2pkh_hash(&script).unwrap();
+        assert_eq!(p2pkh_address(&hash), "1111111111111111111114oLvT2");
+        assert!(p2pkh_hash(&script[..script.len() - 1]).is_none());
+        assert!(p2pkh_hash(&[0x6a]).is_none());
+
+        assert_eq!(decode_hex("00ff7A"), Some(vec![0x00, 0xff, 0x7a]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+}
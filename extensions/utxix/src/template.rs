@@ -0,0 +1,129 @@
+//! `/template`: the contract templates the Bitcoin app wizard scaffolds, or
+//! what one of them puts in a project along with its contract, straight from
+//! `utxix_templates` so it matches what the wizard writes.
+
+use utxix_templates::{FileSource, Framework, Manifest, ManifestFile, ScaffoldOptions, Template};
+
+/// Parses `/template [name]`, matching names without regard to case, spaces
+/// or dashes (`tic-tac-toe`, `TicTacToe`); without an argument all templates
+/// are listed.
+pub fn parse_args(args: &[String]) -> Result<Option<Template>, String> {
+    let name = normalize(&args.concat());
+    if name.is_empty() {
+        return Ok(None);
+    }
+    Template::all()
+        .into_iter()
+        .find(|template| {
+            normalize(template.display_name()) == name
+                || normalize(&format!("{template:?}")) == name
+        })
+        .map(Some)
+        .ok_or_else(|| {
+            format!(
+                "Unknown template \"{}\" - use /template to list them",
+                args.concat().trim()
+            )
+        })
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Argument completions, one per template.
+pub fn names() -> Vec<String> {
+    Template::all()
+        .iter()
+        .map(|template| {
+            template
+                .display_name()
+                .to_ascii_lowercase()
+                .replace(' ', "-")
+        })
+        .collect()
+}
+
+/// The markdown shown in the assistant panel.
+pub fn template(choice: Option<Template>) -> String {
+    match choice {
+        Some(template) => describe(template),
+        None => list(),
+    }
+}
+
+fn list() -> String {
+    let mut text =
+        "## Contract Templates\n\n| Template | Contract | Companion server |\n|---|---|---|\n"
+            .to_string();
+    for template in Template::all() {
+        let descriptor = template.descriptor();
+        let servers = descriptor
+            .backend_files
+            .iter()
+            .filter(|path| path.ends_with("server.mjs"))
+            .map(|path| format!("`{path}`"))
+            .collect::<Vec<_>>();
+        text.push_str(&format!(
+            "| {} | `contracts/{}` | {} |\n",
+            descriptor.name,
+            descriptor.contract_file,
+            if servers.is_empty() {
+                "-".to_string()
+            } else {
+                servers.join(", ")
+            }
+        ));
+    }
+    text.push_str(
+        "\nShow one with `/template <name>`, or scaffold a project with **New Bitcoin App**.\n",
+    );
+    text
+}
+
+fn describe(template: Template) -> String {
+    let descriptor = template.descriptor();
+    let mut text = format!("## Template: {}\n\n", descriptor.name);
+    text.push_str(&format!(
+        "- Contract: `contracts/{}`\n- Test: `tests/{}`\n",
+        descriptor.contract_file, descriptor.test_file
+    ));
+    for framework in Framework::all() {
+        let services = descriptor
+            .services
+            .iter()
+            .map(|file| format!("`{}/{file}`", framework.services_dir()))
+            .collect::<Vec<_>>();
+        text.push_str(&format!(
+            "- {}: `{}`{}\n",
+            framework.display_name(),
+            framework.component_path(),
+            if services.is_empty() {
+                String::new()
+            } else {
+                format!(", {}", services.join(", "))
+            }
+        ));
+    }
+    for path in &descriptor.backend_files {
+        text.push_str(&format!("- Companion server: `{path}`\n"));
+    }
+
+    let manifest = Manifest::new(&ScaffoldOptions {
+        template,
+        ..ScaffoldOptions::default()
+    });
+    if let Some(ManifestFile {
+        source: FileSource::Text(contract),
+        ..
+    }) = manifest.file(format!("contracts/{}", descriptor.contract_file))
+    {
+        text.push_str(&format!(
+            "\n### Contract\n\n```typescript\n{contract}\n```\n"
+        ));
+    }
+    text
+}
@@ -0,0 +1,117 @@
+//! `/wallet`: how the generated apps connect users' wallets - Yours Wallet,
+//! HandCash Connect, or a comparison of the two.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wallet {
+    Yours,
+    HandCash,
+}
+
+impl Wallet {
+    pub fn name(self) -> &'static str {
+        match self {
+            Wallet::Yours => "Yours Wallet",
+            Wallet::HandCash => "HandCash Connect",
+        }
+    }
+}
+
+/// Parses `/wallet [yours|handcash]`; without an argument both are compared.
+pub fn parse_args(args: &[String]) -> Result<Option<Wallet>, String> {
+    match args.concat().trim().to_ascii_lowercase().as_str() {
+        "" => Ok(None),
+        "yours" | "yours wallet" | "panda" => Ok(Some(Wallet::Yours)),
+        "handcash" | "handcash connect" => Ok(Some(Wallet::HandCash)),
+        other => Err(format!(
+            "Unknown wallet \"{other}\" - use /wallet yours or /wallet handcash"
+        )),
+    }
+}
+
+/// The markdown guide shown in the assistant panel.
+pub fn wallet(choice: Option<Wallet>) -> String {
+    match choice {
+        Some(Wallet::Yours) => YOURS_GUIDE.to_string(),
+        Some(Wallet::HandCash) => HANDCASH_GUIDE.to_string(),
+        None => format!("{COMPARISON}\n{YOURS_GUIDE}\n{HANDCASH_GUIDE}"),
+    }
+}
+
+const COMPARISON: &str = r#"## Wallets for sCrypt Apps
+
+| | Yours Wallet | HandCash Connect |
+|---|---|---|
+| Install | Browser extension | None - users log in with their HandCash account |
+| Keys | Held by the user | Held by HandCash (custodial) |
+| Backend | None | Required - holds the app secret and users' auth tokens |
+| Contract calls | Yes - signs any transaction | No - pays and signs messages only |
+| Best for | Contract interactions, ordinals | Consumer payments, tips, identity |
+
+Apps that need both usually log users in with HandCash for payments and ask for
+Yours Wallet only when a contract call needs a signature.
+"#;
+
+const YOURS_GUIDE: &str = r#"## Yours Wallet
+
+The wizard's apps connect Yours Wallet out of the box:
+
+- `src/lib/wallet.ts` - `connectYoursWallet()`, stored connection state
+- `services/pandaSignerService.ts` - `PandaSigner` for `scrypt-ts` deploys and calls
+- `services/yoursWalletDirect.ts` - `YoursDirectSigner` for custom transactions
+  (`bindTxBuilder`, `ANYONECANPAY_SINGLE` settlements)
+
+```typescript
+import { connectYoursWallet } from "./lib/wallet";
+import { getPandaSigner } from "./services/pandaSignerService";
+
+const { address } = await connectYoursWallet();
+const signer = await getPandaSigner(true);
+await contract.connect(signer);
+```
+
+Users need the extension from the Chrome Web Store and testnet coins from a
+faucet while on testnet.
+"#;
+
+const HANDCASH_GUIDE: &str = r#"## HandCash Connect
+
+Check **Include HandCash Connect** in the New Bitcoin App wizard to scaffold
+`handcash/` (the backend) and `handcashService.ts` (its client).
+
+**Setup**
+1. Create an app at https://dashboard.handcash.io
+2. Set its Authorization Success URL to `http://localhost:3005/auth/callback`
+3. Put `HANDCASH_APP_ID` and `HANDCASH_APP_SECRET` in `.env` - never in frontend
+   code, never committed
+4. `cd handcash && npm install && npm start`
+
+**OAuth flow**
+1. `connectHandCash()` sends the user to HandCash (`GET /auth/login`)
+2. HandCash redirects to `/auth/callback?authToken=...`; the backend keeps the
+   token and sends the user back to the app with `?handcashSession=...`
+3. `completeHandCashLogin()` on startup stores that session id
+
+```typescript
+import {
+  completeHandCashLogin,
+  connectHandCash,
+  payWithHandCash,
+  signWithHandCash,
+} from "./services/handcashService";
+
+const profile = await completeHandCashLogin(); // null until the user logs in
+if (!profile) connectHandCash();
+
+// Pay a $handle, paymail or address - in BSV, or a fiat currency code
+const { txid } = await payWithHandCash("$alice", 0.001, "Game stake");
+
+// Prove who the user is, e.g. when joining a game
+const { publicKey, signature } = await signWithHandCash("join:" + gameTxid);
+```
+
+**Limits**
+- HandCash signs payments and messages, not arbitrary transactions, so
+  sCrypt contract calls still need Yours Wallet
+- Payments need the app's "pay" permission, set on the dashboard
+- Sessions live in the backend's memory; restarting it logs users out
+"#;